@@ -0,0 +1,271 @@
+#![no_std]
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, panic_with_error, symbol_short,
+    token::TokenInterface, Address, Env, String,
+};
+
+/// Ledgers a balance entry lives after it is touched (about 30 days)
+const BALANCE_BUMP_AMOUNT: u32 = 518_400;
+
+/// Remaining lifetime below which a touched balance entry is extended
+const BALANCE_LIFETIME_THRESHOLD: u32 = BALANCE_BUMP_AMOUNT - 17_280;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum TokenError {
+    NegativeAmount = 1,
+    InsufficientBalance = 2,
+    InsufficientAllowance = 3,
+    InvalidExpiration = 4,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum DataKey {
+    Admin,
+    Decimals,
+    Name,
+    Symbol,
+    Balance(Address),
+    Allowance(Address, Address),
+}
+
+/// Allowance granted by `from` to a spender, valid up to `expiration_ledger`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AllowanceValue {
+    pub amount: i128,
+    pub expiration_ledger: u32,
+}
+
+/// Fungible shares of one outcome of a Predictify market.
+///
+/// Implements the SEP-41 token interface so positions can be held in wallets
+/// and traded like any other Stellar token. Shares are minted by the market
+/// contract (the token admin) when a voter tokenizes part of their stake, and
+/// burned by it when the holder claims the payout or refund they represent.
+///
+/// Deploy one instance per market outcome with the Predictify contract as
+/// `admin`, then attach it with `set_position_token`.
+#[contract]
+pub struct PositionToken;
+
+#[contractimpl]
+impl PositionToken {
+    pub fn __constructor(env: Env, admin: Address, decimals: u32, name: String, symbol: String) {
+        let storage = env.storage().instance();
+        storage.set(&DataKey::Admin, &admin);
+        storage.set(&DataKey::Decimals, &decimals);
+        storage.set(&DataKey::Name, &name);
+        storage.set(&DataKey::Symbol, &symbol);
+    }
+
+    /// Market contract allowed to mint and burn shares
+    pub fn admin(env: Env) -> Address {
+        env.storage().instance().get(&DataKey::Admin).unwrap()
+    }
+
+    /// Mint `amount` shares to `to` (admin only).
+    pub fn mint(env: Env, to: Address, amount: i128) {
+        check_nonnegative(&env, amount);
+        let admin = Self::admin(env.clone());
+        admin.require_auth();
+
+        receive_balance(&env, &to, amount);
+        env.events()
+            .publish((symbol_short!("mint"), admin, to), amount);
+    }
+
+    /// Burn `amount` of `from`'s shares without their signature (admin only).
+    ///
+    /// Used by the market contract to retire shares against the payout it
+    /// credits to their holder.
+    pub fn clawback(env: Env, from: Address, amount: i128) {
+        check_nonnegative(&env, amount);
+        let admin = Self::admin(env.clone());
+        admin.require_auth();
+
+        spend_balance(&env, &from, amount);
+        env.events()
+            .publish((symbol_short!("clawback"), admin, from), amount);
+    }
+}
+
+#[contractimpl]
+impl TokenInterface for PositionToken {
+    fn allowance(env: Env, from: Address, spender: Address) -> i128 {
+        read_allowance(&env, &from, &spender).amount
+    }
+
+    fn approve(env: Env, from: Address, spender: Address, amount: i128, expiration_ledger: u32) {
+        from.require_auth();
+        check_nonnegative(&env, amount);
+
+        write_allowance(&env, &from, &spender, amount, expiration_ledger);
+        env.events().publish(
+            (symbol_short!("approve"), from, spender),
+            (amount, expiration_ledger),
+        );
+    }
+
+    fn balance(env: Env, id: Address) -> i128 {
+        read_balance(&env, &id)
+    }
+
+    fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        from.require_auth();
+        check_nonnegative(&env, amount);
+
+        spend_balance(&env, &from, amount);
+        receive_balance(&env, &to, amount);
+        env.events()
+            .publish((symbol_short!("transfer"), from, to), amount);
+    }
+
+    fn transfer_from(env: Env, spender: Address, from: Address, to: Address, amount: i128) {
+        spender.require_auth();
+        check_nonnegative(&env, amount);
+
+        spend_allowance(&env, &from, &spender, amount);
+        spend_balance(&env, &from, amount);
+        receive_balance(&env, &to, amount);
+        env.events()
+            .publish((symbol_short!("transfer"), from, to), amount);
+    }
+
+    fn burn(env: Env, from: Address, amount: i128) {
+        from.require_auth();
+        check_nonnegative(&env, amount);
+
+        spend_balance(&env, &from, amount);
+        env.events().publish((symbol_short!("burn"), from), amount);
+    }
+
+    fn burn_from(env: Env, spender: Address, from: Address, amount: i128) {
+        spender.require_auth();
+        check_nonnegative(&env, amount);
+
+        spend_allowance(&env, &from, &spender, amount);
+        spend_balance(&env, &from, amount);
+        env.events().publish((symbol_short!("burn"), from), amount);
+    }
+
+    fn decimals(env: Env) -> u32 {
+        env.storage().instance().get(&DataKey::Decimals).unwrap()
+    }
+
+    fn name(env: Env) -> String {
+        env.storage().instance().get(&DataKey::Name).unwrap()
+    }
+
+    fn symbol(env: Env) -> String {
+        env.storage().instance().get(&DataKey::Symbol).unwrap()
+    }
+}
+
+// ===== STORAGE HELPERS =====
+
+fn check_nonnegative(env: &Env, amount: i128) {
+    if amount < 0 {
+        panic_with_error!(env, TokenError::NegativeAmount);
+    }
+}
+
+fn read_balance(env: &Env, id: &Address) -> i128 {
+    let key = DataKey::Balance(id.clone());
+    match env.storage().persistent().get::<DataKey, i128>(&key) {
+        Some(balance) => {
+            env.storage().persistent().extend_ttl(
+                &key,
+                BALANCE_LIFETIME_THRESHOLD,
+                BALANCE_BUMP_AMOUNT,
+            );
+            balance
+        }
+        None => 0,
+    }
+}
+
+fn write_balance(env: &Env, id: &Address, amount: i128) {
+    let key = DataKey::Balance(id.clone());
+    env.storage().persistent().set(&key, &amount);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+fn receive_balance(env: &Env, id: &Address, amount: i128) {
+    let balance = read_balance(env, id);
+    write_balance(env, id, balance + amount);
+}
+
+fn spend_balance(env: &Env, id: &Address, amount: i128) {
+    let balance = read_balance(env, id);
+    if balance < amount {
+        panic_with_error!(env, TokenError::InsufficientBalance);
+    }
+    write_balance(env, id, balance - amount);
+}
+
+fn read_allowance(env: &Env, from: &Address, spender: &Address) -> AllowanceValue {
+    let key = DataKey::Allowance(from.clone(), spender.clone());
+    match env
+        .storage()
+        .temporary()
+        .get::<DataKey, AllowanceValue>(&key)
+    {
+        Some(allowance) if allowance.expiration_ledger >= env.ledger().sequence() => allowance,
+        Some(allowance) => AllowanceValue {
+            amount: 0,
+            expiration_ledger: allowance.expiration_ledger,
+        },
+        None => AllowanceValue {
+            amount: 0,
+            expiration_ledger: 0,
+        },
+    }
+}
+
+fn write_allowance(
+    env: &Env,
+    from: &Address,
+    spender: &Address,
+    amount: i128,
+    expiration_ledger: u32,
+) {
+    if amount > 0 && expiration_ledger < env.ledger().sequence() {
+        panic_with_error!(env, TokenError::InvalidExpiration);
+    }
+
+    let key = DataKey::Allowance(from.clone(), spender.clone());
+    let allowance = AllowanceValue {
+        amount,
+        expiration_ledger,
+    };
+    env.storage().temporary().set(&key, &allowance);
+    if amount > 0 {
+        let live_for = expiration_ledger - env.ledger().sequence();
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, live_for, live_for);
+    }
+}
+
+fn spend_allowance(env: &Env, from: &Address, spender: &Address, amount: i128) {
+    let allowance = read_allowance(env, from, spender);
+    if allowance.amount < amount {
+        panic_with_error!(env, TokenError::InsufficientAllowance);
+    }
+    if amount > 0 {
+        write_allowance(
+            env,
+            from,
+            spender,
+            allowance.amount - amount,
+            allowance.expiration_ledger,
+        );
+    }
+}
+
+mod test;
@@ -0,0 +1,69 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Env, String};
+
+fn setup(env: &Env) -> (Address, PositionTokenClient<'_>) {
+    let admin = Address::generate(env);
+    let contract_id = env.register(
+        PositionToken,
+        (
+            admin.clone(),
+            7u32,
+            String::from_str(env, "BTC_100K yes"),
+            String::from_str(env, "YES"),
+        ),
+    );
+    (admin, PositionTokenClient::new(env, &contract_id))
+}
+
+#[test]
+fn test_mint_transfer_and_clawback() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, token) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    assert_eq!(token.admin(), admin);
+    assert_eq!(token.decimals(), 7);
+    assert_eq!(token.symbol(), String::from_str(&env, "YES"));
+
+    token.mint(&alice, &1_000);
+    token.transfer(&alice, &bob, &400);
+    assert_eq!(token.balance(&alice), 600);
+    assert_eq!(token.balance(&bob), 400);
+    assert_eq!(
+        token.try_transfer(&alice, &bob, &601),
+        Err(Ok(TokenError::InsufficientBalance.into()))
+    );
+
+    token.clawback(&bob, &400);
+    assert_eq!(token.balance(&bob), 0);
+    token.burn(&alice, &100);
+    assert_eq!(token.balance(&alice), 500);
+}
+
+#[test]
+fn test_allowance_is_spent_and_expires() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, token) = setup(&env);
+    let alice = Address::generate(&env);
+    let spender = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    token.mint(&alice, &1_000);
+    token.approve(&alice, &spender, &300, &100);
+    token.transfer_from(&spender, &alice, &bob, &200);
+    assert_eq!(token.allowance(&alice, &spender), 100);
+    assert_eq!(token.balance(&bob), 200);
+    assert_eq!(
+        token.try_burn_from(&spender, &alice, &101),
+        Err(Ok(TokenError::InsufficientAllowance.into()))
+    );
+
+    env.ledger().set_sequence_number(101);
+    assert_eq!(token.allowance(&alice, &spender), 0);
+}
@@ -38,6 +38,8 @@ pub enum AdminRole {
     FeeAdmin,
     /// Read-only admin with view permissions only
     ReadOnlyAdmin,
+    /// Analyst with access to sensitive per-user analytics
+    Analyst,
 }
 
 /// Admin permission enumeration
@@ -68,6 +70,10 @@ pub enum AdminPermission {
     ViewAnalytics,
     /// Emergency actions
     EmergencyActions,
+    /// Moderate markets (e.g. assign tiers)
+    ModerateMarkets,
+    /// View sensitive analytics (other users' PnL, anomaly flags)
+    ViewSensitiveAnalytics,
 }
 
 /// Admin action record
@@ -830,6 +836,8 @@ impl AdminAccessControl {
     /// | `"manage_disputes"` | `AdminPermission::ManageDisputes` |
     /// | `"view_analytics"` | `AdminPermission::ViewAnalytics` |
     /// | `"emergency_actions"` | `AdminPermission::EmergencyActions` |
+    /// | `"moderate_markets"` | `AdminPermission::ModerateMarkets` |
+    /// | `"view_sensitive_analytics"` | `AdminPermission::ViewSensitiveAnalytics` |
     ///
     /// # Use Cases
     ///
@@ -858,6 +866,8 @@ impl AdminAccessControl {
             "manage_disputes" => Ok(AdminPermission::ManageDisputes),
             "view_analytics" => Ok(AdminPermission::ViewAnalytics),
             "emergency_actions" => Ok(AdminPermission::EmergencyActions),
+            "moderate_markets" => Ok(AdminPermission::ModerateMarkets),
+            "view_sensitive_analytics" => Ok(AdminPermission::ViewSensitiveAnalytics),
             _ => Err(Error::InvalidInput),
         }
     }
@@ -928,6 +938,7 @@ impl AdminRoleManager {
     /// - **ConfigAdmin**: Configuration updates and resets
     /// - **FeeAdmin**: Fee configuration and collection
     /// - **ReadOnlyAdmin**: View-only access to analytics
+    /// - **Analyst**: Analytics including sensitive per-user metrics
     ///
     /// # Assignment Process
     ///
@@ -984,6 +995,7 @@ impl AdminRoleManager {
             AdminRole::ConfigAdmin => crate::events::AdminRole::Admin,
             AdminRole::FeeAdmin => crate::events::AdminRole::Admin,
             AdminRole::ReadOnlyAdmin => crate::events::AdminRole::Moderator,
+            AdminRole::Analyst => crate::events::AdminRole::Moderator,
         };
         EventEmitter::emit_admin_role_assigned(env, admin, &events_role, assigned_by);
 
@@ -1142,6 +1154,7 @@ impl AdminRoleManager {
     /// | ConfigAdmin | ✗ | ✗ | ✗ | ✓ | ✗ |
     /// | FeeAdmin | ✗ | ✗ | ✓ | ✗ | ✗ |
     /// | ReadOnlyAdmin | ✗ | ✗ | ✗ | ✗ | ✗ |
+    /// | Analyst | ✗ | ✗ | ✗ | ✗ | ✗ |
     ///
     /// # Use Cases
     ///
@@ -1227,6 +1240,9 @@ impl AdminRoleManager {
     /// **ReadOnlyAdmin** (1 permission):
     /// - ViewAnalytics
     ///
+    /// **Analyst** (2 permissions):
+    /// - ViewAnalytics, ViewSensitiveAnalytics
+    ///
     /// # Use Cases
     ///
     /// - **Role Analysis**: Understand what each role can do
@@ -1258,6 +1274,8 @@ impl AdminRoleManager {
                 AdminPermission::ManageDisputes,
                 AdminPermission::ViewAnalytics,
                 AdminPermission::EmergencyActions,
+                AdminPermission::ModerateMarkets,
+                AdminPermission::ViewSensitiveAnalytics,
             ],
             AdminRole::MarketAdmin => soroban_sdk::vec![
                 env,
@@ -1266,6 +1284,7 @@ impl AdminRoleManager {
                 AdminPermission::FinalizeMarket,
                 AdminPermission::ExtendMarket,
                 AdminPermission::ViewAnalytics,
+                AdminPermission::ModerateMarkets,
             ],
             AdminRole::ConfigAdmin => soroban_sdk::vec![
                 env,
@@ -1280,6 +1299,11 @@ impl AdminRoleManager {
                 AdminPermission::ViewAnalytics,
             ],
             AdminRole::ReadOnlyAdmin => soroban_sdk::vec![env, AdminPermission::ViewAnalytics,],
+            AdminRole::Analyst => soroban_sdk::vec![
+                env,
+                AdminPermission::ViewAnalytics,
+                AdminPermission::ViewSensitiveAnalytics,
+            ],
         }
     }
 
@@ -1473,6 +1497,21 @@ impl AdminManager {
         Ok(())
     }
 
+    /// Validates that `viewer` may read sensitive analytics about `subject`.
+    ///
+    /// Users may always read their own metrics; anything else requires the
+    /// `ViewSensitiveAnalytics` permission (Analyst or SuperAdmin).
+    pub fn validate_sensitive_analytics_access(
+        env: &Env,
+        viewer: &Address,
+        subject: Option<&Address>,
+    ) -> Result<(), Error> {
+        if subject == Some(viewer) {
+            return Ok(());
+        }
+        Self::validate_admin_permission(env, viewer, AdminPermission::ViewSensitiveAnalytics)
+    }
+
     /// Gets all admin roles in the system
     pub fn get_admin_roles(env: &Env) -> Map<Address, AdminRole> {
         let mut roles = Map::new(env);
@@ -1545,10 +1584,9 @@ impl AdminManager {
     // ===== Helper Methods =====
 
     /// Generate a proper admin storage key using the correct environment
-    fn get_admin_key(env: &Env, admin: &Address) -> Symbol {
-        // Create a unique key based on admin address
-        let key_str = alloc::format!("MultiAdmin_{:?}", admin.to_string());
-        Symbol::new(env, &key_str)
+    fn get_admin_key(env: &Env, admin: &Address) -> (Symbol, Address) {
+        // Key by address directly; a formatted address exceeds symbol limits
+        (Symbol::new(env, "MultiAdmin"), admin.clone())
     }
 
     /// Check if an address is the original admin from single-admin system
@@ -3227,6 +3265,7 @@ impl AdminUtils {
             AdminRole::ReadOnlyAdmin => {
                 String::from_str(&soroban_sdk::Env::default(), "ReadOnlyAdmin")
             }
+            AdminRole::Analyst => String::from_str(&soroban_sdk::Env::default(), "Analyst"),
         }
     }
 
@@ -3269,6 +3308,12 @@ impl AdminUtils {
             AdminPermission::EmergencyActions => {
                 String::from_str(&soroban_sdk::Env::default(), "EmergencyActions")
             }
+            AdminPermission::ModerateMarkets => {
+                String::from_str(&soroban_sdk::Env::default(), "ModerateMarkets")
+            }
+            AdminPermission::ViewSensitiveAnalytics => {
+                String::from_str(&soroban_sdk::Env::default(), "ViewSensitiveAnalytics")
+            }
         }
     }
 }
@@ -3286,6 +3331,7 @@ impl AdminHierarchy {
             AdminRole::ConfigAdmin => 75,
             AdminRole::FeeAdmin => 75,
             AdminRole::ReadOnlyAdmin => 25,
+            AdminRole::Analyst => 25,
         }
     }
 
@@ -3360,6 +3406,7 @@ impl EnhancedAdminAnalytics {
         distribution.set(String::from_str(env, "ConfigAdmin"), 0);
         distribution.set(String::from_str(env, "FeeAdmin"), 0);
         distribution.set(String::from_str(env, "ReadOnlyAdmin"), 0);
+        distribution.set(String::from_str(env, "Analyst"), 0);
 
         // Count original admin as SuperAdmin
         if AdminManager::get_original_admin(env).is_some() {
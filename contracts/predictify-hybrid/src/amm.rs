@@ -0,0 +1,509 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::bets::BetValidator;
+use crate::config::{MAX_AMM_LIQUIDITY, MIN_AMM_LIQUIDITY};
+use crate::errors::Error;
+use crate::events::EventEmitter;
+use crate::markets::MarketStateManager;
+use crate::types::{Market, MarketState};
+use crate::voting::VotingUtils;
+
+/// Fixed-point scale (1.0)
+const WAD: i128 = 1_000_000_000_000_000_000;
+
+/// ln(2) in fixed point
+const LN2_WAD: i128 = 693_147_180_559_945_309;
+
+/// Below this exponent `exp` is smaller than one fixed-point unit
+const EXP_MIN_WAD: i128 = -42 * WAD;
+
+// ===== TYPES =====
+
+/// LMSR state of an AMM market
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AmmPool {
+    /// Liquidity parameter `b`; higher values mean deeper, slower-moving prices
+    pub liquidity: i128,
+    /// Outstanding shares per outcome, in market outcome order
+    pub shares: Vec<i128>,
+    /// Amount escrowed by the sponsor to cover the maker's worst-case loss
+    pub subsidy: i128,
+    /// Net amount traders have paid into the pool
+    pub collected: i128,
+    /// Sum of every trader's positive net spend, owed back if the market is cancelled
+    pub refundable: i128,
+    /// Address that funded the subsidy and receives what is left after settlement
+    pub sponsor: Address,
+    /// Whether the leftover has been returned to the sponsor
+    pub settled: bool,
+}
+
+/// A trader's holdings in an AMM market
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AmmPosition {
+    /// Shares held per outcome, in market outcome order
+    pub shares: Vec<i128>,
+    /// Paid for buys minus received from sells (refunded on cancellation when positive)
+    pub spent: i128,
+    /// Whether the position has been redeemed
+    pub redeemed: bool,
+}
+
+// ===== AMM MANAGER =====
+
+/// # Automated Market Maker
+///
+/// Optional pricing mode where the contract itself quotes share prices using
+/// Hanson's logarithmic market scoring rule (LMSR) instead of pooling stakes
+/// parimutuel-style. Each share of the winning outcome settles for one token
+/// unit, so prices read directly as implied probabilities.
+///
+/// The cost function is `C(q) = b * ln(sum(exp(q_i / b)))`; buying `x` shares
+/// of outcome `i` costs `C(q + x*e_i) - C(q)`. The liquidity parameter `b` is
+/// fixed at creation, and the creator escrows `b * ln(n)` up front, which is
+/// the most the maker can lose.
+///
+/// Math is done in 18-decimal fixed point. Costs round up and proceeds round
+/// down so rounding never leaves the pool short.
+pub struct AmmManager;
+
+impl AmmManager {
+    fn pool_key(market_id: &Symbol) -> (Symbol, Symbol) {
+        (symbol_short!("amm_pool"), market_id.clone())
+    }
+
+    fn position_key(market_id: &Symbol, user: &Address) -> (Symbol, Symbol, Address) {
+        (symbol_short!("amm_pos"), market_id.clone(), user.clone())
+    }
+
+    /// Pool of an AMM market, `None` for parimutuel markets
+    pub fn get_pool(env: &Env, market_id: &Symbol) -> Option<AmmPool> {
+        env.storage().persistent().get(&Self::pool_key(market_id))
+    }
+
+    pub fn is_amm(env: &Env, market_id: &Symbol) -> bool {
+        env.storage().persistent().has(&Self::pool_key(market_id))
+    }
+
+    /// Reject parimutuel staking on AMM markets
+    pub fn require_pool_market(env: &Env, market_id: &Symbol) -> Result<(), Error> {
+        if Self::is_amm(env, market_id) {
+            return Err(Error::InvalidState);
+        }
+        Ok(())
+    }
+
+    pub fn get_position(env: &Env, market_id: &Symbol, user: &Address) -> Option<AmmPosition> {
+        env.storage()
+            .persistent()
+            .get(&Self::position_key(market_id, user))
+    }
+
+    /// Switch a freshly created market to AMM pricing, escrowing the
+    /// `b * ln(n)` subsidy from `sponsor`.
+    pub fn open(
+        env: &Env,
+        market_id: &Symbol,
+        sponsor: &Address,
+        liquidity: i128,
+    ) -> Result<AmmPool, Error> {
+        if !(MIN_AMM_LIQUIDITY..=MAX_AMM_LIQUIDITY).contains(&liquidity) {
+            return Err(Error::InvalidInput);
+        }
+        if Self::is_amm(env, market_id) {
+            return Err(Error::InvalidState);
+        }
+        let market = MarketStateManager::get_market(env, market_id)?;
+        if market.total_staked > 0 {
+            return Err(Error::AlreadyVoted);
+        }
+
+        let mut shares = Vec::new(env);
+        for _ in 0..market.outcomes.len() {
+            shares.push_back(0);
+        }
+        let outcome_count = market.outcomes.len() as i128;
+        let subsidy = ceil_div(
+            liquidity
+                .checked_mul(ln_wad(outcome_count * WAD)?)
+                .ok_or(Error::InvalidInput)?,
+            WAD,
+        );
+
+        VotingUtils::transfer_stake(env, sponsor, subsidy)?;
+        let pool = AmmPool {
+            liquidity,
+            shares,
+            subsidy,
+            collected: 0,
+            refundable: 0,
+            sponsor: sponsor.clone(),
+            settled: false,
+        };
+        env.storage()
+            .persistent()
+            .set(&Self::pool_key(market_id), &pool);
+        Ok(pool)
+    }
+
+    /// Signed cost of changing `outcome`'s shares by `delta`: what a buy
+    /// pays (positive) or minus what a sell receives (negative).
+    pub fn quote(
+        env: &Env,
+        market_id: &Symbol,
+        outcome: &String,
+        delta: i128,
+    ) -> Result<i128, Error> {
+        let market = MarketStateManager::get_market(env, market_id)?;
+        let pool = Self::get_pool(env, market_id).ok_or(Error::InvalidState)?;
+        let index = Self::outcome_index(&market, outcome)?;
+        if delta == 0 {
+            return Err(Error::InvalidInput);
+        }
+        Self::trade_cost(&pool, index, delta)
+    }
+
+    /// Instantaneous price of each outcome in basis points of the settlement
+    /// value, in market outcome order. Prices sum to 10_000 (up to rounding).
+    pub fn prices(env: &Env, market_id: &Symbol) -> Result<Vec<i128>, Error> {
+        let pool = Self::get_pool(env, market_id).ok_or(Error::InvalidState)?;
+        let weights = Self::weights(&pool)?;
+        let total = weights.iter().sum::<i128>();
+
+        let mut prices = Vec::new(env);
+        for weight in weights.iter() {
+            prices.push_back(weight * 10_000 / total);
+        }
+        Ok(prices)
+    }
+
+    /// Buy `shares` of `outcome`, paying at most `max_cost`. Returns the cost.
+    pub fn buy(
+        env: &Env,
+        user: &Address,
+        market_id: &Symbol,
+        outcome: &String,
+        shares: i128,
+        max_cost: i128,
+    ) -> Result<i128, Error> {
+        if shares <= 0 {
+            return Err(Error::InvalidInput);
+        }
+        let (market, mut pool, index) = Self::load_open(env, market_id, outcome)?;
+        crate::market_access::MarketAccessManager::require_allowed(env, market_id, user)?;
+        let cost = Self::trade_cost(&pool, index, shares)?;
+        if cost > max_cost {
+            return Err(Error::InvalidInput);
+        }
+
+        VotingUtils::transfer_stake(env, user, cost)?;
+        Self::apply_trade(
+            env, &market, &mut pool, market_id, user, index, shares, cost,
+        )?;
+        EventEmitter::emit_amm_trade(env, market_id, user, outcome, shares, cost);
+        Ok(cost)
+    }
+
+    /// Sell `shares` of `outcome` back to the pool for at least
+    /// `min_proceeds`. Returns the proceeds.
+    pub fn sell(
+        env: &Env,
+        user: &Address,
+        market_id: &Symbol,
+        outcome: &String,
+        shares: i128,
+        min_proceeds: i128,
+    ) -> Result<i128, Error> {
+        if shares <= 0 {
+            return Err(Error::InvalidInput);
+        }
+        let (market, mut pool, index) = Self::load_open(env, market_id, outcome)?;
+        let held = Self::get_position(env, market_id, user)
+            .map(|p| p.shares.get(index).unwrap_or(0))
+            .unwrap_or(0);
+        if held < shares {
+            return Err(Error::InsufficientBalance);
+        }
+        let proceeds = -Self::trade_cost(&pool, index, -shares)?;
+        if proceeds < min_proceeds {
+            return Err(Error::InvalidInput);
+        }
+
+        Self::apply_trade(
+            env, &market, &mut pool, market_id, user, index, -shares, -proceeds,
+        )?;
+        if proceeds > 0 {
+            VotingUtils::transfer_winnings(env, user, proceeds)?;
+        }
+        EventEmitter::emit_amm_trade(env, market_id, user, outcome, -shares, -proceeds);
+        Ok(proceeds)
+    }
+
+    /// Pay out a trader's position once the market is settled: one token unit
+    /// per winning share (split evenly across tied winners), or the trader's
+    /// net spend if the market was cancelled. Resolved payouts wait for the
+    /// same dispute lock as parimutuel claims.
+    pub fn redeem(env: &Env, user: &Address, market_id: &Symbol) -> Result<i128, Error> {
+        let market = MarketStateManager::get_market(env, market_id)?;
+        if !Self::is_amm(env, market_id) {
+            return Err(Error::InvalidState);
+        }
+        let mut position = Self::get_position(env, market_id, user).ok_or(Error::NothingToClaim)?;
+        if position.redeemed {
+            return Err(Error::AlreadyClaimed);
+        }
+
+        let payout = if market.state == MarketState::Cancelled {
+            position.spent.max(0)
+        } else {
+            let winners = market
+                .winning_outcomes
+                .clone()
+                .ok_or(Error::MarketNotResolved)?;
+            crate::PredictifyHybrid::check_claim_lock(env, &market)?;
+            let mut payout = 0i128;
+            for winner in winners.iter() {
+                let index = Self::outcome_index(&market, &winner)?;
+                payout += position.shares.get(index).unwrap_or(0) / winners.len() as i128;
+            }
+            payout
+        };
+
+        position.redeemed = true;
+        env.storage()
+            .persistent()
+            .set(&Self::position_key(market_id, user), &position);
+        if payout > 0 {
+            VotingUtils::transfer_winnings(env, user, payout)?;
+        }
+        Ok(payout)
+    }
+
+    /// Return whatever the pool holds beyond its outstanding obligations to
+    /// the sponsor once the market is resolved or cancelled. A resolved pool
+    /// is only settled once its result can no longer be disputed.
+    pub fn settle(env: &Env, market_id: &Symbol) -> Result<i128, Error> {
+        let market = MarketStateManager::get_market(env, market_id)?;
+        let mut pool = Self::get_pool(env, market_id).ok_or(Error::InvalidState)?;
+        if pool.settled {
+            return Err(Error::AlreadyClaimed);
+        }
+
+        let owed = if market.state == MarketState::Cancelled {
+            pool.refundable
+        } else {
+            let winners = market
+                .winning_outcomes
+                .clone()
+                .ok_or(Error::MarketNotResolved)?;
+            crate::PredictifyHybrid::check_claim_lock(env, &market)?;
+            let mut owed = 0i128;
+            for winner in winners.iter() {
+                let index = Self::outcome_index(&market, &winner)?;
+                owed += pool.shares.get(index).unwrap_or(0) / winners.len() as i128;
+            }
+            owed
+        };
+        let leftover = (pool.subsidy + pool.collected - owed).max(0);
+
+        pool.settled = true;
+        env.storage()
+            .persistent()
+            .set(&Self::pool_key(market_id), &pool);
+        if leftover > 0 {
+            VotingUtils::transfer_winnings(env, &pool.sponsor, leftover)?;
+        }
+        Ok(leftover)
+    }
+
+    // ===== INTERNALS =====
+
+    fn load_open(
+        env: &Env,
+        market_id: &Symbol,
+        outcome: &String,
+    ) -> Result<(Market, AmmPool, u32), Error> {
+        crate::admin::ContractPauseManager::require_not_paused(env)?;
+        let market = MarketStateManager::get_market(env, market_id)?;
+        let pool = Self::get_pool(env, market_id).ok_or(Error::InvalidState)?;
+        BetValidator::validate_market_for_betting(env, &market)?;
+        let index = Self::outcome_index(&market, outcome)?;
+        Ok((market, pool, index))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn apply_trade(
+        env: &Env,
+        market: &Market,
+        pool: &mut AmmPool,
+        market_id: &Symbol,
+        user: &Address,
+        index: u32,
+        shares: i128,
+        amount: i128,
+    ) -> Result<(), Error> {
+        let mut position = Self::get_position(env, market_id, user).unwrap_or_else(|| {
+            let mut empty = Vec::new(env);
+            for _ in 0..market.outcomes.len() {
+                empty.push_back(0);
+            }
+            AmmPosition {
+                shares: empty,
+                spent: 0,
+                redeemed: false,
+            }
+        });
+
+        let outstanding = pool.shares.get(index).unwrap_or(0);
+        pool.shares.set(index, outstanding + shares);
+        pool.collected += amount;
+        pool.refundable += (position.spent + amount).max(0) - position.spent.max(0);
+
+        let held = position.shares.get(index).unwrap_or(0);
+        position.shares.set(index, held + shares);
+        position.spent += amount;
+
+        env.storage()
+            .persistent()
+            .set(&Self::pool_key(market_id), pool);
+        env.storage()
+            .persistent()
+            .set(&Self::position_key(market_id, user), &position);
+        Ok(())
+    }
+
+    fn outcome_index(market: &Market, outcome: &String) -> Result<u32, Error> {
+        market
+            .outcomes
+            .first_index_of(outcome)
+            .ok_or(Error::InvalidOutcome)
+    }
+
+    fn trade_cost(pool: &AmmPool, index: u32, delta: i128) -> Result<i128, Error> {
+        let held = pool.shares.get(index).ok_or(Error::InvalidOutcome)?;
+        let after = held.checked_add(delta).ok_or(Error::InvalidInput)?;
+        if after < 0 {
+            return Err(Error::InsufficientBalance);
+        }
+
+        let mut moved = pool.clone();
+        moved.shares.set(index, after);
+        let diff = Self::cost_wad(&moved)? - Self::cost_wad(pool)?;
+        if diff >= 0 {
+            Ok(ceil_div(diff, WAD))
+        } else {
+            Ok(-(-diff / WAD))
+        }
+    }
+
+    /// `exp((q_i - q_max) / b)` for each outcome
+    fn weights(pool: &AmmPool) -> Result<Vec<i128>, Error> {
+        let max = pool.shares.iter().max().unwrap_or(0);
+        let mut weights = Vec::new(pool.shares.env());
+        for q in pool.shares.iter() {
+            let x = (q - max).checked_mul(WAD).ok_or(Error::InvalidInput)? / pool.liquidity;
+            weights.push_back(exp_wad(x));
+        }
+        Ok(weights)
+    }
+
+    /// LMSR cost function in fixed point, using log-sum-exp for stability
+    fn cost_wad(pool: &AmmPool) -> Result<i128, Error> {
+        let max = pool.shares.iter().max().unwrap_or(0);
+        let sum = Self::weights(pool)?.iter().sum::<i128>();
+        let log = ln_wad(sum)?;
+        max.checked_mul(WAD)
+            .and_then(|m| {
+                pool.liquidity
+                    .checked_mul(log)
+                    .and_then(|l| m.checked_add(l))
+            })
+            .ok_or(Error::InvalidInput)
+    }
+}
+
+// ===== FIXED-POINT MATH =====
+
+fn ceil_div(value: i128, divisor: i128) -> i128 {
+    (value + divisor - 1) / divisor
+}
+
+/// `e^x` for `x <= 0` in fixed point
+fn exp_wad(x: i128) -> i128 {
+    if x < EXP_MIN_WAD {
+        return 0;
+    }
+    // e^x = 2^-k * e^r with r in (-ln2, 0]
+    let k = -x / LN2_WAD;
+    let r = x + k * LN2_WAD;
+
+    let mut sum = WAD;
+    let mut term = WAD;
+    let mut i = 1;
+    while term != 0 {
+        term = term * r / (i * WAD);
+        sum += term;
+        i += 1;
+    }
+    sum >> k
+}
+
+/// `ln(x)` for `x >= 1.0` in fixed point
+fn ln_wad(x: i128) -> Result<i128, Error> {
+    if x < WAD {
+        return Err(Error::InvalidInput);
+    }
+    // ln(x) = k*ln2 + ln(y) with y in [1, 2)
+    let mut k = 0i128;
+    let mut y = x;
+    while y >= 2 * WAD {
+        y >>= 1;
+        k += 1;
+    }
+
+    // ln(y) = 2 * atanh(z) with z = (y - 1) / (y + 1) in [0, 1/3)
+    let z = (y - WAD) * WAD / (y + WAD);
+    let z2 = z * z / WAD;
+    let mut sum = 0i128;
+    let mut term = z;
+    let mut i = 1;
+    while term != 0 {
+        sum += term / i;
+        term = term * z2 / WAD;
+        i += 2;
+    }
+    Ok(k * LN2_WAD + 2 * sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: i128, expected: i128) {
+        assert!(
+            (actual - expected).abs() <= 1_000,
+            "{} != {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_exp_wad() {
+        assert_eq!(exp_wad(0), WAD);
+        assert_close(exp_wad(-WAD), 367_879_441_171_442_321);
+        assert_close(exp_wad(-10 * WAD), 45_399_929_762_484);
+        assert_eq!(exp_wad(-50 * WAD), 0);
+    }
+
+    #[test]
+    fn test_ln_wad() {
+        assert_eq!(ln_wad(WAD).unwrap(), 0);
+        assert_close(ln_wad(2 * WAD).unwrap(), LN2_WAD);
+        assert_close(ln_wad(3 * WAD).unwrap(), 1_098_612_288_668_109_691);
+        assert_close(ln_wad(10 * WAD).unwrap(), 2_302_585_092_994_045_684);
+        assert_eq!(ln_wad(WAD - 1), Err(Error::InvalidInput));
+    }
+}
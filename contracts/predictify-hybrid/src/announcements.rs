@@ -0,0 +1,122 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Vec};
+
+use crate::admin::{AdminAccessControl, AdminPermission};
+use crate::errors::Error;
+
+// ===== ANNOUNCEMENTS =====
+
+/// How prominently frontends should surface an announcement.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AnnouncementSeverity {
+    Info,
+    /// Action may be needed soon (e.g. an upcoming upgrade)
+    Warning,
+    /// Affects users now (e.g. paused features)
+    Critical,
+}
+
+/// Official notice posted by contract governance.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Announcement {
+    pub id: u32,
+    pub author: Address,
+    pub severity: AnnouncementSeverity,
+    pub message: String,
+    pub posted_at: u64,
+    /// Time after which the announcement is no longer listed, if any
+    pub expires_at: Option<u64>,
+}
+
+/// On-chain announcements log.
+///
+/// Governance (admins holding `AdminPermission::UpdateConfig`) posts notices
+/// that frontends read straight from the contract. The log keeps the latest
+/// `MAX_ANNOUNCEMENTS` entries; older ones are dropped as new ones arrive.
+pub struct AnnouncementManager;
+
+impl AnnouncementManager {
+    fn load(env: &Env) -> Vec<Announcement> {
+        env.storage()
+            .persistent()
+            .get(&symbol_short!("ann_log"))
+            .unwrap_or(Vec::new(env))
+    }
+
+    fn save(env: &Env, announcements: &Vec<Announcement>) {
+        env.storage()
+            .persistent()
+            .set(&symbol_short!("ann_log"), announcements);
+    }
+
+    /// Post an announcement and return its ID
+    pub fn post(
+        env: &Env,
+        author: &Address,
+        severity: AnnouncementSeverity,
+        message: String,
+        expires_at: Option<u64>,
+    ) -> Result<u32, Error> {
+        AdminAccessControl::validate_permission(env, author, &AdminPermission::UpdateConfig)?;
+        if message.is_empty() || message.len() > crate::config::MAX_ANNOUNCEMENT_LENGTH {
+            return Err(Error::InvalidInput);
+        }
+        let now = env.ledger().timestamp();
+        if expires_at.is_some_and(|expiry| expiry <= now) {
+            return Err(Error::InvalidInput);
+        }
+
+        let id: u32 = env
+            .storage()
+            .persistent()
+            .get(&symbol_short!("ann_next"))
+            .unwrap_or(1);
+        env.storage()
+            .persistent()
+            .set(&symbol_short!("ann_next"), &(id + 1));
+
+        let announcement = Announcement {
+            id,
+            author: author.clone(),
+            severity,
+            message,
+            posted_at: now,
+            expires_at,
+        };
+        let mut announcements = Self::load(env);
+        if announcements.len() >= crate::config::MAX_ANNOUNCEMENTS {
+            announcements.pop_front();
+        }
+        announcements.push_back(announcement.clone());
+        Self::save(env, &announcements);
+
+        crate::events::EventEmitter::emit_announcement_posted(env, &announcement);
+        Ok(id)
+    }
+
+    /// Withdraw an announcement before it expires
+    pub fn retract(env: &Env, author: &Address, id: u32) -> Result<(), Error> {
+        AdminAccessControl::validate_permission(env, author, &AdminPermission::UpdateConfig)?;
+        let mut announcements = Self::load(env);
+        let index = announcements
+            .iter()
+            .position(|a| a.id == id)
+            .ok_or(Error::InvalidInput)?;
+        announcements.remove(index as u32);
+        Self::save(env, &announcements);
+        Ok(())
+    }
+
+    /// Unexpired announcements, oldest first
+    pub fn get_active(env: &Env) -> Vec<Announcement> {
+        let now = env.ledger().timestamp();
+        let mut active = Vec::new(env);
+        for announcement in Self::load(env).iter() {
+            if announcement.expires_at.is_none_or(|expiry| expiry > now) {
+                active.push_back(announcement);
+            }
+        }
+        active
+    }
+}
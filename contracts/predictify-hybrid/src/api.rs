@@ -0,0 +1,65 @@
+use soroban_sdk::{contracttype, Env, Symbol, Vec};
+
+use crate::config::{API_VERSION, MIN_SUPPORTED_API_VERSION};
+
+// ===== API VERSIONS =====
+
+/// Entry point kept for compatibility after a newer call superseded it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DeprecatedEntryPoint {
+    pub name: Symbol,
+    /// API version from which the entry point is deprecated
+    pub deprecated_since: u32,
+    /// Entry point to call instead
+    pub replacement: Symbol,
+}
+
+/// Versions of the public API this contract serves.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ApiVersions {
+    /// Latest API version; SDKs built against it can use every entry point
+    pub current: u32,
+    /// Oldest API version whose entry points are all still callable
+    pub min_supported: u32,
+    pub deprecated: Vec<DeprecatedEntryPoint>,
+}
+
+/// Registry of the public API's versions and deprecated entry points.
+///
+/// Entry points are never removed while their API version is still at or
+/// above `MIN_SUPPORTED_API_VERSION`; SDKs read `get_api_versions` to pick
+/// the call set that matches the deployed contract.
+pub struct ApiRegistry;
+
+impl ApiRegistry {
+    /// Deprecated entry points as (name, deprecated since, replacement)
+    const DEPRECATED: [(&'static str, u32, &'static str); 2] = [
+        ("create_market", 2, "create_market_v2"),
+        ("withdraw_collected_fees", 2, "withdraw_fees"),
+    ];
+
+    pub fn versions(env: &Env) -> ApiVersions {
+        let mut deprecated = Vec::new(env);
+        for (name, since, replacement) in Self::DEPRECATED {
+            deprecated.push_back(DeprecatedEntryPoint {
+                name: Symbol::new(env, name),
+                deprecated_since: since,
+                replacement: Symbol::new(env, replacement),
+            });
+        }
+        ApiVersions {
+            current: API_VERSION,
+            min_supported: MIN_SUPPORTED_API_VERSION,
+            deprecated,
+        }
+    }
+
+    /// Whether `name` is deprecated as of API version `version`
+    pub fn is_deprecated(env: &Env, name: &Symbol, version: u32) -> bool {
+        Self::DEPRECATED
+            .iter()
+            .any(|(entry, since, _)| Symbol::new(env, entry) == *name && *since <= version)
+    }
+}
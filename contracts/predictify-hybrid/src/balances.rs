@@ -3,6 +3,7 @@
 use crate::errors::Error;
 use crate::events::EventEmitter;
 use crate::markets::MarketUtils;
+use crate::reentrancy_guard::ReentrancyGuard;
 use crate::storage::BalanceStorage;
 use crate::types::{Balance, ReflectorAsset};
 use crate::validation::InputValidator;
@@ -51,7 +52,10 @@ impl BalanceManager {
         // but standard Soroban token interface uses transfer(from, to, amount) where 'from' must auth.
         // Since we called user.require_auth(), we can try to transfer.
         // Note: The token contract will check if 'user' signed the tx.
-        token_client.transfer(&user, &env.current_contract_address(), &amount);
+        ReentrancyGuard::guarded(env, || {
+            token_client.transfer(&user, &env.current_contract_address(), &amount);
+            Ok(())
+        })?;
 
         // Update balance
         let balance = BalanceStorage::add_balance(env, &user, &asset, amount)?;
@@ -169,7 +173,10 @@ impl BalanceManager {
         let balance = BalanceStorage::sub_balance(env, &user, &asset, amount)?;
 
         // Transfer funds from contract to user
-        token_client.transfer(&env.current_contract_address(), &user, &amount);
+        ReentrancyGuard::guarded(env, || {
+            token_client.transfer(&env.current_contract_address(), &user, &amount);
+            Ok(())
+        })?;
 
         // Emit event
         EventEmitter::emit_balance_changed(
@@ -1,6 +1,6 @@
 use alloc::format;
 use alloc::string::ToString;
-use soroban_sdk::{contracttype, vec, Address, Env, Map, String, Symbol, Vec};
+use soroban_sdk::{contracttype, panic_with_error, vec, Address, Env, Map, String, Symbol, Vec};
 
 use crate::errors::Error;
 use crate::types::*;
@@ -83,6 +83,9 @@ pub struct BatchResult {
     pub errors: Vec<BatchError>,
     pub gas_used: u64,
     pub execution_time: u64,
+    /// Index of the first operation left unprocessed because the resource
+    /// budget ran out; resubmit from here. `None` when the batch completed.
+    pub next_cursor: Option<u32>,
 }
 
 #[derive(Clone, Debug)]
@@ -216,7 +219,13 @@ impl BatchProcessor {
             return Err(Error::InvalidInput);
         }
 
+        let mut budget = ResourceBudget::new(config.gas_limit_per_batch);
+        let mut next_cursor = None;
         for (index, vote_data) in votes.iter().enumerate() {
+            if !budget.try_spend(BatchUtils::estimate_gas_cost(&BatchOperationType::Vote, 1)) {
+                next_cursor = Some(index as u32);
+                break;
+            }
             match Self::process_single_vote(env, &vote_data) {
                 Ok(_) => {
                     successful_operations += 1;
@@ -241,8 +250,9 @@ impl BatchProcessor {
             failed_operations,
             total_operations: votes.len() as u32,
             errors,
-            gas_used: 0, // Would be calculated in real implementation
+            gas_used: budget.spent(),
             execution_time,
+            next_cursor,
         };
 
         // Update statistics
@@ -275,6 +285,85 @@ impl BatchProcessor {
         Ok(())
     }
 
+    /// Vote on several markets for one user, all-or-nothing.
+    ///
+    /// Every `(market_id, outcome, stake)` is validated before anything is
+    /// written, then the combined stake is locked with a single token
+    /// transfer and the votes are recorded. Any invalid vote fails the whole
+    /// batch. Returns the total stake locked.
+    pub fn batch_vote_atomic(
+        env: &Env,
+        user: &Address,
+        votes: &Vec<(Symbol, String, i128)>,
+    ) -> Result<i128, Error> {
+        if votes.is_empty() || votes.len() > crate::config::MAX_BATCH_VOTES {
+            return Err(Error::InvalidInput);
+        }
+
+        let limiter = crate::rate_limiter::RateLimiter::new(env.clone());
+        let mut markets: Vec<Market> = Vec::new(env);
+        let mut stakes: Vec<i128> = Vec::new(env);
+        let mut seen: Vec<Symbol> = Vec::new(env);
+        let mut total_stake: i128 = 0;
+        for (market_id, outcome, stake) in votes.iter() {
+            if seen.contains(&market_id) {
+                return Err(Error::InvalidInput);
+            }
+            seen.push_back(market_id.clone());
+
+            // Each entry counts against the user's vote rate limit
+            limiter.enforce_action(user, crate::rate_limiter::RateLimitedAction::Vote);
+
+            let market = crate::markets::MarketStateManager::get_market(env, &market_id)?;
+            if market.state != MarketState::Active || market.has_ended(env) {
+                return Err(Error::MarketClosed);
+            }
+            crate::voting::VotingValidator::validate_outside_quiet_period(env, &market)?;
+            crate::markets::MarketStateManager::require_direct_staking(env, &market_id)?;
+            crate::market_access::MarketAccessManager::require_allowed(env, &market_id, user)?;
+            if !market.outcomes.contains(&outcome) {
+                return Err(Error::InvalidOutcome);
+            }
+            if stake <= 0 {
+                return Err(Error::InsufficientStake);
+            }
+            crate::validation::MarketValidator::validate_stake_for_tier(&market, stake)
+                .map_err(|e| e.to_contract_error())?;
+            let stake = market.apply_outcome_cap(&outcome, stake)?;
+            if crate::storage::VoteLedger::get_entry(env, &market_id, user).is_some() {
+                return Err(Error::AlreadyVoted);
+            }
+            if let Err(e) = crate::voting::StakeLimitManager::check(env, &market, stake, stake) {
+                panic_with_error!(env, e);
+            }
+            total_stake = total_stake.checked_add(stake).ok_or(Error::InvalidInput)?;
+            markets.push_back(market);
+            stakes.push_back(stake);
+        }
+
+        crate::bets::BetUtils::lock_funds(env, user, total_stake)?;
+
+        for (index, (market_id, outcome, _)) in votes.iter().enumerate() {
+            let mut market = markets.get(index as u32).unwrap();
+            let stake = stakes.get(index as u32).unwrap();
+            crate::storage::VoteLedger::set_position(
+                env,
+                &market_id,
+                &mut market,
+                user,
+                &outcome,
+                stake,
+            );
+            market.total_staked += stake;
+            crate::extensions::AntiSnipeManager::on_stake(env, &market_id, &mut market, stake);
+            crate::markets::MarketStateManager::update_market(env, &market_id, &market);
+            crate::statistics::StatisticsManager::record_creator_volume(env, &market.admin, stake);
+            crate::events::EventEmitter::emit_vote_cast(env, &market_id, user, &outcome, stake);
+        }
+
+        Ok(total_stake)
+    }
+
     // ===== BATCH CLAIM OPERATIONS =====
 
     /// Process batch claim operations
@@ -290,7 +379,13 @@ impl BatchProcessor {
             return Err(Error::InvalidInput);
         }
 
+        let mut budget = ResourceBudget::new(config.gas_limit_per_batch);
+        let mut next_cursor = None;
         for (index, claim_data) in claims.iter().enumerate() {
+            if !budget.try_spend(BatchUtils::estimate_gas_cost(&BatchOperationType::Claim, 1)) {
+                next_cursor = Some(index as u32);
+                break;
+            }
             match Self::process_single_claim(env, &claim_data) {
                 Ok(_) => {
                     successful_operations += 1;
@@ -315,8 +410,9 @@ impl BatchProcessor {
             failed_operations,
             total_operations: claims.len() as u32,
             errors,
-            gas_used: 0, // Would be calculated in real implementation
+            gas_used: budget.spent(),
             execution_time,
+            next_cursor,
         };
 
         // Update statistics
@@ -373,7 +469,16 @@ impl BatchProcessor {
             return Err(Error::InvalidInput);
         }
 
+        let mut budget = ResourceBudget::new(config.gas_limit_per_batch);
+        let mut next_cursor = None;
         for (index, market_data) in markets.iter().enumerate() {
+            if !budget.try_spend(BatchUtils::estimate_gas_cost(
+                &BatchOperationType::CreateMarket,
+                1,
+            )) {
+                next_cursor = Some(index as u32);
+                break;
+            }
             match Self::process_single_market_creation(env, admin, &market_data) {
                 Ok(_) => {
                     successful_operations += 1;
@@ -398,8 +503,9 @@ impl BatchProcessor {
             failed_operations,
             total_operations: markets.len() as u32,
             errors,
-            gas_used: 0, // Would be calculated in real implementation
+            gas_used: budget.spent(),
             execution_time,
+            next_cursor,
         };
 
         // Update statistics
@@ -445,7 +551,16 @@ impl BatchProcessor {
             return Err(Error::InvalidInput);
         }
 
+        let mut budget = ResourceBudget::new(config.gas_limit_per_batch);
+        let mut next_cursor = None;
         for (index, feed_data) in feeds.iter().enumerate() {
+            if !budget.try_spend(BatchUtils::estimate_gas_cost(
+                &BatchOperationType::OracleCall,
+                1,
+            )) {
+                next_cursor = Some(index as u32);
+                break;
+            }
             match Self::process_single_oracle_call(env, &feed_data) {
                 Ok(_) => {
                     successful_operations += 1;
@@ -470,8 +585,9 @@ impl BatchProcessor {
             failed_operations,
             total_operations: feeds.len() as u32,
             errors,
-            gas_used: 0, // Would be calculated in real implementation
+            gas_used: budget.spent(),
             execution_time,
+            next_cursor,
         };
 
         // Update statistics
@@ -505,6 +621,66 @@ impl BatchProcessor {
         Ok(())
     }
 
+    // ===== BATCH RESOLUTIONS =====
+
+    /// Resolve several ended markets from their fetched oracle results
+    pub fn batch_resolve(env: &Env, market_ids: &Vec<Symbol>) -> Result<BatchResult, Error> {
+        let config = Self::get_config(env)?;
+        let start_time = env.ledger().timestamp();
+        let mut successful_operations = 0;
+        let mut failed_operations = 0;
+        let mut errors = Vec::new(env);
+
+        // Validate batch size
+        if market_ids.len() > config.max_operations_per_batch {
+            return Err(Error::InvalidInput);
+        }
+
+        let mut budget = ResourceBudget::new(config.gas_limit_per_batch);
+        let mut next_cursor = None;
+        for (index, market_id) in market_ids.iter().enumerate() {
+            if !budget.try_spend(BatchUtils::estimate_gas_cost(
+                &BatchOperationType::Resolution,
+                1,
+            )) {
+                next_cursor = Some(index as u32);
+                break;
+            }
+            match crate::resolution::MarketResolutionManager::resolve_market(env, &market_id) {
+                Ok(_) => {
+                    successful_operations += 1;
+                }
+                Err(error) => {
+                    failed_operations += 1;
+                    errors.push_back(BatchError {
+                        operation_index: index as u32,
+                        error_code: error as u32,
+                        error_message: String::from_str(env, error.description()),
+                        operation_type: BatchOperationType::Resolution,
+                    });
+                }
+            }
+        }
+
+        let end_time = env.ledger().timestamp();
+        let execution_time = end_time - start_time;
+
+        let result = BatchResult {
+            successful_operations,
+            failed_operations,
+            total_operations: market_ids.len(),
+            errors,
+            gas_used: budget.spent(),
+            execution_time,
+            next_cursor,
+        };
+
+        // Update statistics
+        Self::update_batch_statistics(env, &result)?;
+
+        Ok(result)
+    }
+
     // ===== BATCH OPERATION VALIDATION =====
 
     /// Validate batch operations
@@ -742,6 +918,41 @@ impl BatchProcessor {
     }
 }
 
+// ===== RESOURCE BUDGET =====
+
+/// Iteration counter that keeps long loops inside the Soroban resource budget.
+///
+/// A transaction that exhausts its CPU or memory budget traps and loses all
+/// of its work, so batches spend estimated units from a fixed allowance per
+/// item and stop early once the next item would not fit, reporting where to
+/// resume instead.
+pub struct ResourceBudget {
+    limit: u64,
+    spent: u64,
+}
+
+impl ResourceBudget {
+    pub fn new(limit: u64) -> Self {
+        Self { limit, spent: 0 }
+    }
+
+    /// Spend `units` if they fit in what is left of the budget
+    pub fn try_spend(&mut self, units: u64) -> bool {
+        match self.spent.checked_add(units) {
+            Some(total) if total <= self.limit => {
+                self.spent = total;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Units spent so far
+    pub fn spent(&self) -> u64 {
+        self.spent
+    }
+}
+
 // ===== BATCH CONFIGURATION =====
 
 #[derive(Clone, Debug)]
@@ -921,6 +1132,7 @@ impl BatchTesting {
             errors,
             gas_used: BatchUtils::estimate_gas_cost(operation_type, operation_count),
             execution_time,
+            next_cursor: None,
         })
     }
 }
@@ -509,6 +509,42 @@ mod batch_operations_tests {
         };
     }
 
+    #[test]
+    fn test_batch_stops_at_resource_budget_with_resume_cursor() {
+        let env = Env::default();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+
+        env.as_contract(&contract_id, || {
+            BatchProcessor::initialize(&env).unwrap();
+            let mut config = BatchProcessor::get_config(&env).unwrap();
+            config.gas_limit_per_batch = 2_500; // room for two votes
+            env.storage()
+                .instance()
+                .set(&Symbol::new(&env, "batch_operation_config"), &config);
+
+            let market_id = Symbol::new(&env, "test_market");
+            let votes = vec![
+                &env,
+                BatchTesting::create_test_vote_data(&env, &market_id),
+                BatchTesting::create_test_vote_data(&env, &market_id),
+                BatchTesting::create_test_vote_data(&env, &market_id),
+            ];
+            let result = BatchProcessor::batch_vote(&env, &votes).unwrap();
+            assert_eq!(result.next_cursor, Some(2));
+            assert_eq!(result.successful_operations + result.failed_operations, 2);
+            assert_eq!(result.gas_used, 2_000);
+
+            let result = BatchProcessor::batch_vote(&env, &votes.slice(2..)).unwrap();
+            assert_eq!(result.next_cursor, None);
+        });
+
+        let mut budget = ResourceBudget::new(10);
+        assert!(budget.try_spend(6));
+        assert!(!budget.try_spend(5));
+        assert!(budget.try_spend(4));
+        assert_eq!(budget.spent(), 10);
+    }
+
     #[test]
     fn test_batch_statistics_update() {
         let env = Env::default();
@@ -525,6 +561,7 @@ mod batch_operations_tests {
                 errors: Vec::new(&env),
                 gas_used: 5000,
                 execution_time: 100,
+                next_cursor: None,
             };
 
             // Get initial statistics
@@ -25,6 +25,7 @@ use crate::errors::Error;
 use crate::events::EventEmitter;
 use crate::markets::{MarketStateManager, MarketUtils, MarketValidator};
 use crate::reentrancy_guard::ReentrancyGuard;
+use crate::statistics::StatisticsManager;
 use crate::types::{Bet, BetLimits, BetStats, BetStatus, Market, MarketState};
 use crate::validation;
 
@@ -256,9 +257,14 @@ impl BetManager {
         // Get and validate market
         let mut market = MarketStateManager::get_market(env, &market_id)?;
         BetValidator::validate_market_for_betting(env, &market)?;
+        MarketStateManager::require_direct_staking(env, &market_id)?;
+        crate::market_access::MarketAccessManager::require_allowed(env, &market_id, &user)?;
 
         // Validate bet parameters (uses configurable min/max limits per event or global)
         BetValidator::validate_bet_parameters(env, &market_id, &outcome, &market.outcomes, amount)?;
+        validation::MarketValidator::validate_stake_for_tier(&market, amount)
+            .map_err(|e| e.to_contract_error())?;
+        let amount = market.apply_outcome_cap(&outcome, amount)?;
 
         // Check if user has already bet on this market
         if Self::has_user_bet(env, &market_id, &user) {
@@ -285,11 +291,19 @@ impl BetManager {
 
         // Update market's total staked (for payout pool calculation)
         market.total_staked += amount;
+        StatisticsManager::record_creator_volume(env, &market.admin, amount);
 
         // Also update votes and stakes for backward compatibility with payout distribution
         // This allows distribute_payouts to work with both bets and votes
-        market.votes.set(user.clone(), outcome.clone());
-        market.stakes.set(user.clone(), amount);
+        crate::storage::VoteLedger::set_position(
+            env,
+            &market_id,
+            &mut market,
+            &user,
+            &outcome,
+            amount,
+        );
+        crate::extensions::AntiSnipeManager::on_stake(env, &market_id, &mut market, amount);
 
         MarketStateManager::update_market(env, &market_id, &market);
 
@@ -349,6 +363,7 @@ impl BetManager {
 
         // Phase 1: Validate all bets and collect data
         let mut markets = soroban_sdk::Vec::new(env);
+        let mut amounts = soroban_sdk::Vec::new(env);
         let mut total_amount: i128 = 0;
 
         for bet_data in bets.iter() {
@@ -357,6 +372,8 @@ impl BetManager {
             // Get and validate market
             let market = MarketStateManager::get_market(env, &market_id)?;
             BetValidator::validate_market_for_betting(env, &market)?;
+            MarketStateManager::require_direct_staking(env, &market_id)?;
+            crate::market_access::MarketAccessManager::require_allowed(env, &market_id, &user)?;
 
             // Validate bet parameters
             BetValidator::validate_bet_parameters(
@@ -366,6 +383,9 @@ impl BetManager {
                 &market.outcomes,
                 amount,
             )?;
+            validation::MarketValidator::validate_stake_for_tier(&market, amount)
+                .map_err(|e| e.to_contract_error())?;
+            let amount = market.apply_outcome_cap(&outcome, amount)?;
 
             // Check if user has already bet on this market
             if Self::has_user_bet(env, &market_id, &user) {
@@ -377,8 +397,9 @@ impl BetManager {
                 .checked_add(amount)
                 .ok_or(Error::InvalidInput)?;
 
-            // Store market for later use
+            // Store market and accepted amount for later use
             markets.push_back(market);
+            amounts.push_back(amount);
         }
 
         // Phase 2: Lock total funds once (more efficient than per-bet transfers)
@@ -388,8 +409,9 @@ impl BetManager {
         let mut placed_bets = soroban_sdk::Vec::new(env);
 
         for (i, bet_data) in bets.iter().enumerate() {
-            let (market_id, outcome, amount) = bet_data;
+            let (market_id, outcome, _) = bet_data;
             let mut market = markets.get(i as u32).unwrap();
+            let amount = amounts.get(i as u32).unwrap();
 
             // Create bet
             let bet = Bet::new(
@@ -411,10 +433,18 @@ impl BetManager {
                 .total_staked
                 .checked_add(amount)
                 .ok_or(Error::InvalidInput)?;
+            StatisticsManager::record_creator_volume(env, &market.admin, amount);
 
             // Update votes and stakes for backward compatibility
-            market.votes.set(user.clone(), outcome.clone());
-            market.stakes.set(user.clone(), amount);
+            crate::storage::VoteLedger::set_position(
+                env,
+                &market_id,
+                &mut market,
+                &user,
+                &outcome,
+                amount,
+            );
+            crate::extensions::AntiSnipeManager::on_stake(env, &market_id, &mut market, amount);
 
             MarketStateManager::update_market(env, &market_id, &market);
 
@@ -630,23 +660,12 @@ impl BetManager {
             return Ok(0);
         }
 
-        // Get platform fee percentage from config (with fallback to legacy storage)
-        let fee_percentage = crate::config::ConfigManager::get_config(env)
-            .map(|cfg| cfg.fees.platform_fee_percentage)
-            .unwrap_or_else(|_| {
-                // Fallback to legacy storage for backward compatibility
-                env.storage()
-                    .persistent()
-                    .get(&Symbol::new(env, "platform_fee"))
-                    .unwrap_or(200) // Default 2% if not set
-            });
-
-        // Calculate payout
+        // Calculate payout with the platform fee pinned on the market at creation
         let payout = MarketUtils::calculate_payout(
             bet.amount,
             winning_total,
             stats.total_amount_locked,
-            fee_percentage,
+            market.platform_fee_percentage,
         )?;
 
         Ok(payout)
@@ -717,7 +736,7 @@ impl BetManager {
         }
 
         // Refund the locked funds
-        BetUtils::unlock_funds(env, &user, bet.amount)?;
+        ReentrancyGuard::guarded(env, || BetUtils::unlock_funds(env, &user, bet.amount))?;
 
         // Mark bet as cancelled
         bet.status = BetStatus::Cancelled;
@@ -767,6 +786,32 @@ impl BetManager {
 
         Ok(())
     }
+
+    /// Align per-outcome bet statistics with an amended outcome set.
+    ///
+    /// Drops totals recorded under labels that are no longer part of the market.
+    /// Only valid before participation, when every remaining total is zero.
+    pub fn sync_outcome_set(
+        env: &Env,
+        market_id: &Symbol,
+        outcomes: &Vec<String>,
+    ) -> Result<(), Error> {
+        let mut stats = BetStorage::get_market_bet_stats(env, market_id);
+        let mut changed = false;
+
+        for (outcome, _) in stats.outcome_totals.clone().iter() {
+            if !outcomes.contains(&outcome) {
+                stats.outcome_totals.remove(outcome);
+                changed = true;
+            }
+        }
+
+        if changed {
+            BetStorage::store_market_bet_stats(env, market_id, &stats)?;
+        }
+
+        Ok(())
+    }
 }
 
 // ===== BET STORAGE =====
@@ -933,6 +978,7 @@ impl BetValidator {
         if current_time >= deadline {
             return Err(Error::MarketClosed);
         }
+        crate::voting::VotingValidator::validate_outside_quiet_period(env, market)?;
 
         // Check if market is not already resolved
         if market.winning_outcomes.is_some() {
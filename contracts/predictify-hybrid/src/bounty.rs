@@ -0,0 +1,188 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::admin::{AdminAccessControl, AdminPermission};
+use crate::bets::BetUtils;
+use crate::config;
+use crate::events::EventEmitter;
+use crate::reentrancy_guard::ReentrancyGuard;
+use crate::Error;
+
+/// Record of a bounty paid to a vulnerability reporter.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BountyPayout {
+    /// Sequential payout ID
+    pub id: u32,
+    /// Reporter who received the bounty
+    pub reporter: Address,
+    /// Amount transferred
+    pub amount: i128,
+    /// Reference of the disclosed vulnerability report
+    pub report_id: String,
+    /// Emergency council member who approved the payout
+    pub approved_by: Address,
+    /// Payout timestamp
+    pub paid_at: u64,
+}
+
+// ===== BOUNTY STORAGE =====
+
+pub struct BountyStorage;
+impl BountyStorage {
+    #[inline(always)]
+    fn balance_key(env: &Env) -> Symbol {
+        Symbol::new(env, "bounty_escrow")
+    }
+
+    #[inline(always)]
+    fn payouts_key(env: &Env) -> Symbol {
+        Symbol::new(env, "bounty_payouts")
+    }
+
+    pub fn balance(env: &Env) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&Self::balance_key(env))
+            .unwrap_or(0)
+    }
+
+    pub fn set_balance(env: &Env, amount: i128) {
+        env.storage()
+            .persistent()
+            .set(&Self::balance_key(env), &amount);
+    }
+
+    pub fn payouts(env: &Env) -> Vec<BountyPayout> {
+        env.storage()
+            .persistent()
+            .get(&Self::payouts_key(env))
+            .unwrap_or(Vec::new(env))
+    }
+
+    pub fn add_payout(env: &Env, payout: &BountyPayout) {
+        let mut payouts = Self::payouts(env);
+        payouts.push_back(payout.clone());
+        env.storage()
+            .persistent()
+            .set(&Self::payouts_key(env), &payouts);
+        env.storage().persistent().set(
+            &(symbol_short!("bnty_rpt"), payout.report_id.clone()),
+            &payout.id,
+        );
+    }
+
+    /// Whether a bounty was already paid for `report_id`
+    pub fn is_report_paid(env: &Env, report_id: &String) -> bool {
+        env.storage()
+            .persistent()
+            .has(&(symbol_short!("bnty_rpt"), report_id.clone()))
+    }
+
+    /// Total paid out during a cap period
+    pub fn period_paid(env: &Env, period: u64) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("bnty_per"), period))
+            .unwrap_or(0)
+    }
+
+    pub fn set_period_paid(env: &Env, period: u64, amount: i128) {
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("bnty_per"), period), &amount);
+    }
+}
+
+// ===== BUG BOUNTY ESCROW =====
+
+/// Bug bounty escrow.
+///
+/// The treasury locks funds in the escrow and members of the emergency
+/// council (admins holding `AdminPermission::EmergencyActions`) approve
+/// payouts to reporters of disclosed vulnerabilities. Each report is paid
+/// at most once, a single payout is capped at `MAX_BOUNTY_PAYOUT` and total
+/// payouts are capped at `MAX_BOUNTY_PAYOUTS_PER_PERIOD` per
+/// `BOUNTY_CAP_PERIOD_SECONDS`. Every payout is kept as a public record.
+pub struct BountyEscrow;
+
+impl BountyEscrow {
+    /// Lock `amount` from the treasury in the escrow. Returns the new balance.
+    pub fn fund(env: &Env, treasury: &Address, amount: i128) -> Result<i128, Error> {
+        let stored: Option<Address> = env
+            .storage()
+            .persistent()
+            .get(&Symbol::new(env, "Treasury"));
+        if stored.as_ref() != Some(treasury) {
+            return Err(Error::Unauthorized);
+        }
+        if amount <= 0 {
+            return Err(Error::InvalidInput);
+        }
+
+        BetUtils::lock_funds(env, treasury, amount)?;
+        let balance = BountyStorage::balance(env) + amount;
+        BountyStorage::set_balance(env, balance);
+
+        EventEmitter::emit_bounty_escrow_funded(env, treasury, amount, balance);
+        Ok(balance)
+    }
+
+    /// Pay a bounty from the escrow to `reporter` for `report_id`.
+    pub fn approve_payout(
+        env: &Env,
+        council_member: &Address,
+        reporter: &Address,
+        amount: i128,
+        report_id: String,
+    ) -> Result<BountyPayout, Error> {
+        AdminAccessControl::validate_permission(
+            env,
+            council_member,
+            &AdminPermission::EmergencyActions,
+        )?;
+        if amount <= 0 || amount > config::MAX_BOUNTY_PAYOUT || report_id.is_empty() {
+            return Err(Error::InvalidInput);
+        }
+        if BountyStorage::is_report_paid(env, &report_id) {
+            return Err(Error::AlreadyClaimed);
+        }
+
+        let period = env.ledger().timestamp() / config::BOUNTY_CAP_PERIOD_SECONDS;
+        let period_paid = BountyStorage::period_paid(env, period) + amount;
+        if period_paid > config::MAX_BOUNTY_PAYOUTS_PER_PERIOD {
+            return Err(Error::InvalidInput);
+        }
+        let balance = BountyStorage::balance(env);
+        if amount > balance {
+            return Err(Error::InvalidState);
+        }
+
+        BountyStorage::set_balance(env, balance - amount);
+        BountyStorage::set_period_paid(env, period, period_paid);
+        let payout = BountyPayout {
+            id: BountyStorage::payouts(env).len(),
+            reporter: reporter.clone(),
+            amount,
+            report_id,
+            approved_by: council_member.clone(),
+            paid_at: env.ledger().timestamp(),
+        };
+        BountyStorage::add_payout(env, &payout);
+        ReentrancyGuard::guarded(env, || BetUtils::unlock_funds(env, reporter, amount))?;
+
+        EventEmitter::emit_bounty_paid(env, &payout);
+        Ok(payout)
+    }
+
+    /// Page through the payout records, oldest first.
+    pub fn get_payouts(env: &Env, offset: u32, limit: u32) -> Result<Vec<BountyPayout>, Error> {
+        let payouts = BountyStorage::payouts(env);
+        let (start, take) =
+            crate::queries::QueryManager::page_bounds(payouts.len(), offset, limit)?;
+        let mut page = Vec::new(env);
+        for payout in payouts.iter().skip(start).take(take) {
+            page.push_back(payout);
+        }
+        Ok(page)
+    }
+}
@@ -394,3 +394,70 @@ fn test_update_category_after_bets_forbidden() {
         &Some(String::from_str(&setup.env, "Blocked")),
     );
 }
+
+#[test]
+fn test_markets_are_indexed_by_category_and_tag() {
+    let (env, client, admin) = setup_test();
+    let crypto = String::from_str(&env, "Crypto");
+    let sports = String::from_str(&env, "Sports");
+    let majors = String::from_str(&env, "majors");
+
+    let params = crate::types::MarketBuilder::new(
+        &env,
+        String::from_str(&env, "Will ETH close above $3,000 this month?"),
+        vec![
+            &env,
+            String::from_str(&env, "yes"),
+            String::from_str(&env, "no"),
+        ],
+        30,
+        OracleConfig {
+            provider: OracleProvider::Reflector,
+            oracle_address: Address::generate(&env),
+            feed_id: String::from_str(&env, "ETH/USD"),
+            threshold: 300000,
+            comparison: String::from_str(&env, "gt"),
+        },
+    )
+    .category(crypto.clone())
+    .tags(vec![&env, majors.clone(), String::from_str(&env, "eth")])
+    .build();
+    let m1 = client.create_market_v2(&admin, &params);
+    let m2 = create_test_market(&env, &client, &admin, "Bitcoin");
+    let m3 = create_test_market(&env, &client, &admin, "Lakers");
+    client.update_event_category(&admin, &m2, &Some(crypto.clone()));
+    client.update_event_category(&admin, &m3, &Some(sports.clone()));
+    client.update_event_tags(&admin, &m2, &vec![&env, majors.clone()]);
+
+    assert_eq!(
+        client.get_markets_by_category(&crypto, &0, &10),
+        vec![&env, m1.clone(), m2.clone()]
+    );
+    assert_eq!(
+        client.get_markets_by_category(&crypto, &1, &1),
+        vec![&env, m2.clone()]
+    );
+    assert_eq!(
+        client.get_markets_by_category(&sports, &0, &10),
+        vec![&env, m3.clone()]
+    );
+    assert_eq!(
+        client.get_markets_by_tag(&majors, &0, &10),
+        vec![&env, m1.clone(), m2.clone()]
+    );
+    assert!(client.try_get_markets_by_category(&crypto, &0, &0).is_err());
+
+    // Moving a market clears its old slot and appends it to the new category
+    client.update_event_category(&admin, &m1, &Some(sports.clone()));
+    client.update_event_tags(&admin, &m1, &Vec::new(&env));
+    assert_eq!(
+        client.get_markets_by_category(&crypto, &0, &10),
+        vec![&env, m2.clone()]
+    );
+    assert_eq!(client.get_category_market_count(&crypto), 2);
+    assert_eq!(
+        client.get_markets_by_category(&sports, &0, &10),
+        vec![&env, m3, m1]
+    );
+    assert_eq!(client.get_markets_by_tag(&majors, &0, &10), vec![&env, m2]);
+}
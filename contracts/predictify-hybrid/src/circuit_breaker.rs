@@ -1,8 +1,11 @@
-use soroban_sdk::{contracttype, Address, Env, Map, String, Symbol, Vec};
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Map, String, Symbol, Vec};
 
 use crate::admin::AdminAccessControl;
 use crate::errors::Error;
 use crate::events::{CircuitBreakerEvent, EventEmitter};
+use crate::market_id_generator::MarketIdGenerator;
+use crate::markets::MarketStateManager;
+use crate::types::MarketState;
 use alloc::format;
 use alloc::string::ToString;
 
@@ -65,6 +68,14 @@ pub struct CircuitBreakerState {
     pub error_count: u32,
 }
 
+/// Interval during which the circuit breaker was open or half-open
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct FreezePeriod {
+    pub start: u64,
+    pub end: u64,
+}
+
 // ===== CIRCUIT BREAKER IMPLEMENTATION =====
 
 /// Circuit Breaker Pattern for Emergency Pause and Safety
@@ -374,6 +385,7 @@ impl CircuitBreaker {
         state.half_open_requests = 0;
         state.last_success_time = env.ledger().timestamp();
         Self::update_state(env, &state)?;
+        MarketFreezeManager::record_freeze(env, state.opened_time, state.last_success_time);
 
         // Emit recovery event
         Self::emit_circuit_breaker_event(
@@ -404,6 +416,7 @@ impl CircuitBreaker {
                 state.state = BreakerState::Closed;
                 state.failure_count = 0;
                 state.half_open_requests = 0;
+                MarketFreezeManager::record_freeze(env, state.opened_time, current_time);
 
                 Self::emit_circuit_breaker_event(
                     env,
@@ -688,6 +701,128 @@ impl CircuitBreaker {
     }
 }
 
+// ===== MARKET FREEZE EXTENSIONS =====
+
+/// Compensates markets for circuit breaker downtime.
+///
+/// Every time the breaker closes again, the interval since it opened is
+/// recorded as a freeze period. Markets that were live when a freeze began
+/// have their end time pushed back by the frozen interval. Extensions are
+/// queued rather than applied to every market on resumption: they are
+/// applied per market, or in bounded batches walking the market registry.
+pub struct MarketFreezeManager;
+
+impl MarketFreezeManager {
+    const FREEZES_KEY: &'static str = "circuit_breaker_freezes";
+    const QUEUE_CURSOR_KEY: &'static str = "freeze_queue_cursor";
+
+    /// All recorded freeze periods, oldest first
+    pub fn get_freeze_periods(env: &Env) -> Vec<FreezePeriod> {
+        env.storage()
+            .persistent()
+            .get(&Symbol::new(env, Self::FREEZES_KEY))
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Record a completed freeze and restart the extension queue
+    pub fn record_freeze(env: &Env, start: u64, end: u64) {
+        if end <= start {
+            return;
+        }
+        let mut periods = Self::get_freeze_periods(env);
+        periods.push_back(FreezePeriod { start, end });
+        env.storage()
+            .persistent()
+            .set(&Symbol::new(env, Self::FREEZES_KEY), &periods);
+        env.storage()
+            .persistent()
+            .set(&Symbol::new(env, Self::QUEUE_CURSOR_KEY), &0u32);
+    }
+
+    /// Number of freeze periods already accounted for by a market
+    fn applied_count(env: &Env, market_id: &Symbol) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("frz_appl"), market_id.clone()))
+            .unwrap_or(0)
+    }
+
+    fn set_applied_count(env: &Env, market_id: &Symbol, count: u32) {
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("frz_appl"), market_id.clone()), &count);
+    }
+
+    /// Exclude freezes that ended before a market was created
+    pub fn mark_created(env: &Env, market_id: &Symbol) {
+        Self::set_applied_count(env, market_id, Self::get_freeze_periods(env).len());
+    }
+
+    /// Extend a market by every pending freeze that began before its end
+    /// time. Returns the number of seconds added.
+    pub fn apply_to_market(env: &Env, market_id: &Symbol) -> Result<u64, Error> {
+        let mut market = MarketStateManager::get_market(env, market_id)?;
+        let periods = Self::get_freeze_periods(env);
+        let applied = Self::applied_count(env, market_id);
+        if applied >= periods.len() {
+            return Ok(0);
+        }
+        Self::set_applied_count(env, market_id, periods.len());
+        if market.state != MarketState::Active {
+            return Ok(0);
+        }
+
+        let mut frozen_seconds = 0u64;
+        for period in periods.iter().skip(applied as usize) {
+            if period.start < market.end_time {
+                frozen_seconds += period.end - period.start;
+            }
+        }
+        if frozen_seconds == 0 {
+            return Ok(0);
+        }
+
+        let old_end_time = market.end_time;
+        market.end_time += frozen_seconds;
+        if market.bet_deadline > 0 {
+            market.bet_deadline += frozen_seconds;
+        }
+        MarketStateManager::update_market(env, market_id, &market);
+
+        EventEmitter::emit_market_freeze_extended(
+            env,
+            market_id,
+            frozen_seconds,
+            old_end_time,
+            market.end_time,
+        );
+        Ok(frozen_seconds)
+    }
+
+    /// Apply pending extensions to up to `limit` markets, continuing from
+    /// where the previous batch stopped. Returns the number of markets
+    /// extended.
+    pub fn process_queue(env: &Env, limit: u32) -> Result<u32, Error> {
+        if limit == 0 || limit > crate::config::MAX_FREEZE_EXTENSION_BATCH {
+            return Err(Error::InvalidInput);
+        }
+        let cursor_key = Symbol::new(env, Self::QUEUE_CURSOR_KEY);
+        let cursor: u32 = env.storage().persistent().get(&cursor_key).unwrap_or(0);
+        let market_ids = MarketIdGenerator::get_all_market_ids(env);
+
+        let mut extended = 0;
+        let mut next = cursor;
+        for market_id in market_ids.iter().skip(cursor as usize).take(limit as usize) {
+            next += 1;
+            if Self::apply_to_market(env, &market_id).unwrap_or(0) > 0 {
+                extended += 1;
+            }
+        }
+        env.storage().persistent().set(&cursor_key, &next);
+        Ok(extended)
+    }
+}
+
 // ===== CIRCUIT BREAKER UTILITIES =====
 
 /// Circuit breaker utilities for common operations
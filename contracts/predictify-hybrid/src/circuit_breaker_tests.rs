@@ -484,4 +484,60 @@ mod circuit_breaker_tests {
             assert!(events.len() >= 2); // At least pause and recovery events
         });
     }
+
+    #[test]
+    fn test_markets_live_during_freeze_are_extended_on_resumption() {
+        use soroban_sdk::testutils::{Ledger, LedgerInfo};
+
+        let test = crate::test::PredictifyTest::setup();
+        let client = crate::PredictifyHybridClient::new(&test.env, &test.contract_id);
+        let live = test.create_test_market();
+        let live_end = client.get_market(&live).unwrap().end_time;
+
+        let set_time = |timestamp: u64| {
+            test.env.ledger().set(LedgerInfo {
+                timestamp,
+                protocol_version: 22,
+                sequence_number: test.env.ledger().sequence(),
+                network_id: Default::default(),
+                base_reserve: 10,
+                min_temp_entry_ttl: 1,
+                min_persistent_entry_ttl: 1,
+                max_entry_ttl: 10000,
+            });
+        };
+        let start = test.env.ledger().timestamp();
+        test.env.as_contract(&test.contract_id, || {
+            CircuitBreaker::initialize(&test.env).unwrap();
+            let reason = String::from_str(&test.env, "oracle outage");
+            CircuitBreaker::emergency_pause(&test.env, &test.admin, &reason).unwrap();
+        });
+        set_time(start + 3600);
+        test.env.as_contract(&test.contract_id, || {
+            CircuitBreaker::circuit_breaker_recovery(&test.env, &test.admin).unwrap();
+        });
+        assert_eq!(
+            client.get_freeze_periods(),
+            vec![
+                &test.env,
+                FreezePeriod {
+                    start,
+                    end: start + 3600
+                }
+            ]
+        );
+
+        // Markets created after the freeze are not compensated
+        let later = test.create_test_market();
+        let later_end = client.get_market(&later).unwrap().end_time;
+
+        assert!(client.try_process_freeze_extensions(&0).is_err());
+        assert_eq!(client.process_freeze_extensions(&10), 1);
+        assert_eq!(client.get_market(&live).unwrap().end_time, live_end + 3600);
+        assert_eq!(client.get_market(&later).unwrap().end_time, later_end);
+
+        // Each freeze is applied once
+        assert_eq!(client.apply_market_freeze_extension(&live), 0);
+        assert_eq!(client.get_market(&live).unwrap().end_time, live_end + 3600);
+    }
 }
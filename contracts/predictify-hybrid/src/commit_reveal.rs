@@ -0,0 +1,261 @@
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{contracttype, symbol_short, Address, Bytes, BytesN, Env, String, Symbol};
+
+use crate::bets::BetUtils;
+use crate::config::{
+    MAX_REVEAL_WINDOW_SECONDS, MIN_REVEAL_WINDOW_SECONDS, UNREVEALED_COMMIT_PENALTY_BPS,
+};
+use crate::errors::Error;
+use crate::markets::MarketStateManager;
+use crate::reentrancy_guard::ReentrancyGuard;
+use crate::storage::VoteLedger;
+use crate::treasury::{TreasuryManager, TreasurySource};
+use crate::types::MarketState;
+use crate::validation::MarketValidator;
+
+/// Phases of a commit-reveal market.
+///
+/// Sealed votes are committed until `commit_deadline` (the market's original
+/// end time) and revealed until `reveal_deadline`, which becomes the market's
+/// end time so nothing resolves before the reveals are in.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CommitRevealConfig {
+    pub commit_deadline: u64,
+    pub reveal_deadline: u64,
+    /// Number of sealed votes committed
+    pub committed: u32,
+    /// Number of sealed votes revealed
+    pub revealed: u32,
+}
+
+/// A voter's sealed vote.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoteCommitment {
+    /// `sha256(user || outcome || salt)`, with `user` as its XDR encoding,
+    /// `outcome` as UTF-8 and a 32-byte salt. Binding the voter stops others
+    /// from resubmitting the commitment as their own.
+    pub commitment: BytesN<32>,
+    /// Stake escrowed with the commitment
+    pub stake: i128,
+    pub revealed: bool,
+    /// Whether the stake of an unrevealed commitment was reclaimed
+    pub reclaimed: bool,
+}
+
+// ===== COMMIT-REVEAL VOTING =====
+
+/// Sealed votes for markets where public tallies would distort voting.
+///
+/// Voters commit to a hash of their outcome and a secret salt, escrowing
+/// their stake, so nobody can copy or front-run the running tally. Once the
+/// voting period is over each voter reveals the outcome and salt, turning
+/// the commitment into a normal position. Commitments left unrevealed get
+/// their stake back after the reveal window, minus
+/// `UNREVEALED_COMMIT_PENALTY_BPS` credited to the treasury.
+pub struct CommitRevealManager;
+
+impl CommitRevealManager {
+    fn config_key(market_id: &Symbol) -> (Symbol, Symbol) {
+        (symbol_short!("cr_cfg"), market_id.clone())
+    }
+
+    fn commitment_key(market_id: &Symbol, user: &Address) -> (Symbol, Symbol, Address) {
+        (symbol_short!("vote_cmt"), market_id.clone(), user.clone())
+    }
+
+    pub fn get_config(env: &Env, market_id: &Symbol) -> Option<CommitRevealConfig> {
+        env.storage().persistent().get(&Self::config_key(market_id))
+    }
+
+    pub fn get_commitment(env: &Env, market_id: &Symbol, user: &Address) -> Option<VoteCommitment> {
+        env.storage()
+            .persistent()
+            .get(&Self::commitment_key(market_id, user))
+    }
+
+    /// Reject public votes and bets on commit-reveal markets
+    pub fn require_public_votes(env: &Env, market_id: &Symbol) -> Result<(), Error> {
+        if env.storage().persistent().has(&Self::config_key(market_id)) {
+            return Err(Error::InvalidState);
+        }
+        Ok(())
+    }
+
+    /// Hash `user` commits to for `outcome` and `salt`.
+    pub fn commitment_hash(
+        env: &Env,
+        user: &Address,
+        outcome: &String,
+        salt: &BytesN<32>,
+    ) -> BytesN<32> {
+        let mut raw = alloc::vec![0u8; outcome.len() as usize];
+        outcome.copy_into_slice(&mut raw);
+        let mut preimage = user.clone().to_xdr(env);
+        preimage.append(&Bytes::from_slice(env, &raw));
+        preimage.extend_from_array(&salt.to_array());
+        env.crypto().sha256(&preimage).into()
+    }
+
+    /// Switch a market without stakes to sealed voting (admin only).
+    ///
+    /// The market's end time moves back by `reveal_window` seconds; votes
+    /// are committed until the current end time and revealed after it.
+    pub fn enable(
+        env: &Env,
+        admin: &Address,
+        market_id: &Symbol,
+        reveal_window: u64,
+    ) -> Result<CommitRevealConfig, Error> {
+        crate::recovery::RecoveryManager::assert_is_admin(env, admin)?;
+        if !(MIN_REVEAL_WINDOW_SECONDS..=MAX_REVEAL_WINDOW_SECONDS).contains(&reveal_window) {
+            return Err(Error::InvalidInput);
+        }
+        let mut market = MarketStateManager::get_market(env, market_id)?;
+        if market.state != MarketState::Active || market.has_ended(env) {
+            return Err(Error::MarketClosed);
+        }
+        if market.total_staked > 0 {
+            return Err(Error::AlreadyVoted);
+        }
+        Self::require_public_votes(env, market_id)?;
+        crate::amm::AmmManager::require_pool_market(env, market_id)?;
+
+        let config = CommitRevealConfig {
+            commit_deadline: market.end_time,
+            reveal_deadline: market.end_time + reveal_window,
+            committed: 0,
+            revealed: 0,
+        };
+        market.end_time = config.reveal_deadline;
+        MarketStateManager::update_market(env, market_id, &market);
+        env.storage()
+            .persistent()
+            .set(&Self::config_key(market_id), &config);
+        Ok(config)
+    }
+
+    /// Commit a sealed vote, escrowing `stake`.
+    pub fn commit(
+        env: &Env,
+        user: &Address,
+        market_id: &Symbol,
+        commitment: BytesN<32>,
+        stake: i128,
+    ) -> Result<(), Error> {
+        let mut config = Self::get_config(env, market_id).ok_or(Error::InvalidState)?;
+        let market = MarketStateManager::get_market(env, market_id)?;
+        if market.state != MarketState::Active || env.ledger().timestamp() >= config.commit_deadline
+        {
+            return Err(Error::MarketClosed);
+        }
+        crate::market_access::MarketAccessManager::require_allowed(env, market_id, user)?;
+        if stake <= 0 {
+            return Err(Error::InvalidInput);
+        }
+        MarketValidator::validate_stake_for_tier(&market, stake)
+            .map_err(|e| e.to_contract_error())?;
+        if Self::get_commitment(env, market_id, user).is_some() {
+            return Err(Error::AlreadyVoted);
+        }
+
+        BetUtils::lock_funds(env, user, stake)?;
+        let sealed = VoteCommitment {
+            commitment,
+            stake,
+            revealed: false,
+            reclaimed: false,
+        };
+        env.storage()
+            .persistent()
+            .set(&Self::commitment_key(market_id, user), &sealed);
+        config.committed += 1;
+        env.storage()
+            .persistent()
+            .set(&Self::config_key(market_id), &config);
+
+        crate::events::EventEmitter::emit_vote_committed(env, market_id, user, stake);
+        Ok(())
+    }
+
+    /// Reveal a sealed vote, recording it as a normal position.
+    pub fn reveal(
+        env: &Env,
+        user: &Address,
+        market_id: &Symbol,
+        outcome: &String,
+        salt: &BytesN<32>,
+    ) -> Result<(), Error> {
+        let mut config = Self::get_config(env, market_id).ok_or(Error::InvalidState)?;
+        let mut sealed = Self::get_commitment(env, market_id, user).ok_or(Error::NothingToClaim)?;
+        if sealed.revealed {
+            return Err(Error::AlreadyVoted);
+        }
+        let now = env.ledger().timestamp();
+        if now < config.commit_deadline {
+            return Err(Error::InvalidState);
+        }
+        let mut market = MarketStateManager::get_market(env, market_id)?;
+        if market.state != MarketState::Active || now >= config.reveal_deadline {
+            return Err(Error::MarketClosed);
+        }
+        if !market.outcomes.contains(outcome) {
+            return Err(Error::InvalidOutcome);
+        }
+        if Self::commitment_hash(env, user, outcome, salt) != sealed.commitment {
+            return Err(Error::InvalidInput);
+        }
+
+        VoteLedger::set_position(env, market_id, &mut market, user, outcome, sealed.stake);
+        market.total_staked += sealed.stake;
+        MarketStateManager::update_market(env, market_id, &market);
+        sealed.revealed = true;
+        env.storage()
+            .persistent()
+            .set(&Self::commitment_key(market_id, user), &sealed);
+        config.revealed += 1;
+        env.storage()
+            .persistent()
+            .set(&Self::config_key(market_id), &config);
+
+        crate::events::EventEmitter::emit_vote_revealed(
+            env,
+            market_id,
+            user,
+            outcome,
+            sealed.stake,
+        );
+        Ok(())
+    }
+
+    /// Return an unrevealed commitment's stake once the reveal window is
+    /// over, minus the penalty. Returns the amount refunded.
+    pub fn reclaim_unrevealed(
+        env: &Env,
+        user: &Address,
+        market_id: &Symbol,
+    ) -> Result<i128, Error> {
+        let config = Self::get_config(env, market_id).ok_or(Error::InvalidState)?;
+        let mut sealed = Self::get_commitment(env, market_id, user).ok_or(Error::NothingToClaim)?;
+        if sealed.revealed || env.ledger().timestamp() < config.reveal_deadline {
+            return Err(Error::InvalidState);
+        }
+        if sealed.reclaimed {
+            return Err(Error::AlreadyClaimed);
+        }
+
+        let penalty = sealed.stake * UNREVEALED_COMMIT_PENALTY_BPS / 10_000;
+        let refund = sealed.stake - penalty;
+        sealed.reclaimed = true;
+        env.storage()
+            .persistent()
+            .set(&Self::commitment_key(market_id, user), &sealed);
+
+        if penalty > 0 {
+            TreasuryManager::deposit(env, TreasurySource::CommitPenalty, penalty)?;
+        }
+        ReentrancyGuard::guarded(env, || BetUtils::unlock_funds(env, user, refund))?;
+        Ok(refund)
+    }
+}
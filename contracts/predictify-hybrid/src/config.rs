@@ -29,6 +29,9 @@ pub const MAX_MARKET_OUTCOMES: u32 = 10;
 /// Minimum number of outcomes per market
 pub const MIN_MARKET_OUTCOMES: u32 = 2;
 
+/// Buckets on either side of a scalar market's resolved bucket that still share the pool
+pub const SCALAR_PAYOUT_SPREAD_BUCKETS: u32 = 2;
+
 /// Maximum question length in characters
 pub const MAX_QUESTION_LENGTH: u32 = 500;
 
@@ -62,6 +65,9 @@ pub const MAX_CATEGORY_LENGTH: u32 = 100;
 /// Minimum category name length in characters
 pub const MIN_CATEGORY_LENGTH: u32 = 2;
 
+/// Maximum length of a market's resolution source URL
+pub const MAX_RESOLUTION_SOURCE_URL_LENGTH: u32 = 256;
+
 // ===== FEE CONSTANTS =====
 
 /// Default platform fee percentage (2%)
@@ -70,6 +76,9 @@ pub const DEFAULT_PLATFORM_FEE_PERCENTAGE: i128 = 2;
 /// Default market creation fee (1 XLM)
 pub const DEFAULT_MARKET_CREATION_FEE: i128 = 10_000_000;
 
+/// Bond posted by non-admin creators of public markets (10 XLM)
+pub const DEFAULT_MARKET_CREATION_BOND: i128 = 100_000_000;
+
 /// Minimum fee amount (0.1 XLM)
 pub const MIN_FEE_AMOUNT: i128 = 1_000_000;
 
@@ -85,6 +94,9 @@ pub const MAX_PLATFORM_FEE_PERCENTAGE: i128 = 10;
 /// Minimum platform fee percentage
 pub const MIN_PLATFORM_FEE_PERCENTAGE: i128 = 0;
 
+/// Maximum share of a market's platform fee paid to its creator (50%)
+pub const MAX_CREATOR_FEE_BPS: u32 = 5_000;
+
 // ===== VOTING CONSTANTS =====
 
 /// Minimum vote stake (0.1 XLM)
@@ -108,6 +120,77 @@ pub const HIGH_ACTIVITY_THRESHOLD: u32 = 100;
 /// Dispute extension hours
 pub const DISPUTE_EXTENSION_HOURS: u32 = 24;
 
+/// Share of dispute vote stake one side needs to settle a dispute early (80%)
+pub const DEFAULT_DISPUTE_SUPERMAJORITY_BPS: u32 = 8_000;
+
+/// Total dispute vote stake needed before a dispute can settle early (100 XLM)
+pub const DEFAULT_DISPUTE_EARLY_RESOLUTION_MIN_STAKE: i128 = 1_000_000_000;
+
+/// Share of a losing dispute vote's stake forfeited to the winning side (50%)
+pub const DEFAULT_DISPUTE_SLASH_BPS: u32 = 5_000;
+
+/// Minimum number of arbitrators on a market's dispute panel
+pub const MIN_ARBITRATION_PANEL_SIZE: u32 = 3;
+
+/// Maximum number of arbitrators on a market's dispute panel
+pub const MAX_ARBITRATION_PANEL_SIZE: u32 = 15;
+
+/// Time after dispute voting closes during which its ruling can be appealed (48 hours)
+pub const DEFAULT_DISPUTE_APPEAL_WINDOW_SECONDS: u64 = 172_800;
+
+/// Dispute rounds including the initial dispute; the last is decided by the arbitration council
+pub const DEFAULT_MAX_DISPUTE_ROUNDS: u32 = 3;
+
+/// Upper bound on the configurable number of dispute rounds
+pub const MAX_DISPUTE_ROUNDS: u32 = 10;
+
+/// Bond of each appeal as a multiple of the previous round's bond
+pub const DISPUTE_APPEAL_BOND_MULTIPLIER: i128 = 2;
+
+/// Maximum number of members on the arbitration council
+pub const MAX_ARBITRATION_COUNCIL_SIZE: u32 = 15;
+
+/// Maximum number of evidence attachments on a dispute
+pub const MAX_DISPUTE_EVIDENCE: u32 = 20;
+
+/// Maximum length of a dispute evidence URI
+pub const MAX_EVIDENCE_URI_LENGTH: u32 = 256;
+
+/// Share of council members who must rule before a council decision (50%)
+pub const DEFAULT_COUNCIL_QUORUM_BPS: u32 = 5_000;
+
+/// Share of cast council rulings the decided outcome must exceed (50%)
+pub const DEFAULT_COUNCIL_MAJORITY_BPS: u32 = 5_000;
+
+/// Hours before the market end during which votes can no longer be changed
+pub const VOTE_CHANGE_FREEZE_HOURS: u32 = 1;
+
+/// Hours before the market end during which new votes and stake increases
+/// are rejected (0 = stakes accepted until the end time)
+pub const VOTE_QUIET_PERIOD_HOURS: u32 = 0;
+
+/// Vote change penalty far from the market end (1%), in basis points
+pub const VOTE_CHANGE_MIN_PENALTY_BPS: i128 = 100;
+
+/// Vote change penalty at the market end (10%), in basis points
+pub const VOTE_CHANGE_MAX_PENALTY_BPS: i128 = 1_000;
+
+/// Time before the market end over which the vote change penalty rises
+/// from the minimum to the maximum (7 days)
+pub const VOTE_CHANGE_PENALTY_DECAY_SECONDS: u64 = 604_800;
+
+/// Shortest reveal window of a commit-reveal market (1 hour)
+pub const MIN_REVEAL_WINDOW_SECONDS: u64 = 3_600;
+
+/// Longest reveal window of a commit-reveal market (7 days)
+pub const MAX_REVEAL_WINDOW_SECONDS: u64 = 604_800;
+
+/// Share of an unrevealed sealed vote's stake forfeited to the treasury (5%), in basis points
+pub const UNREVEALED_COMMIT_PENALTY_BPS: i128 = 500;
+
+/// Fee on early position exits (2%), in basis points, retained in the market pool
+pub const POSITION_EXIT_FEE_BPS: i128 = 200;
+
 // ===== EXTENSION CONSTANTS =====
 
 /// Maximum extension days
@@ -122,11 +205,145 @@ pub const EXTENSION_FEE_PER_DAY: i128 = 100_000_000;
 /// Maximum total extensions per market
 pub const MAX_TOTAL_EXTENSIONS: u32 = 3;
 
+/// Default stake that triggers an anti-snipe extension (1,000 XLM)
+pub const DEFAULT_ANTI_SNIPE_THRESHOLD: i128 = 10_000_000_000;
+
+/// Default final window in which large stakes extend a market (10 minutes)
+pub const DEFAULT_ANTI_SNIPE_WINDOW_SECONDS: u64 = 600;
+
+/// Default end time extension per anti-snipe trigger (10 minutes)
+pub const DEFAULT_ANTI_SNIPE_EXTENSION_SECONDS: u64 = 600;
+
 // ===== POOL SIZE CONSTANTS =====
 
 /// Default minimum pool size (0 = no minimum)
 pub const DEFAULT_MIN_POOL_SIZE: i128 = 0;
 
+// ===== MARKET DUPLICATION CONSTANTS =====
+
+/// Markets with matching fingerprints whose end times are within this window are duplicates (1 day)
+pub const DUPLICATE_MARKET_END_WINDOW_SECONDS: u64 = 86_400;
+
+// ===== PAYOUT CONSTANTS =====
+
+/// Default maximum payout multiple (no single claim may exceed 1000x the claimant's stake)
+pub const DEFAULT_MAX_PAYOUT_MULTIPLE: i128 = 1_000;
+
+/// Default payout size above which winnings vest instead of paying out at once (10,000 XLM)
+pub const DEFAULT_VESTING_THRESHOLD: i128 = 100_000_000_000;
+
+/// Default number of days jumbo payouts vest over
+pub const DEFAULT_VESTING_DAYS: u32 = 7;
+
+// ===== TREASURY CONSTANTS =====
+
+/// Delay between proposing and executing a treasury withdrawal (2 days)
+pub const TREASURY_WITHDRAWAL_TIMELOCK_SECONDS: u64 = 172_800;
+
+// ===== REWARD EMISSION CONSTANTS =====
+
+/// Flat emission paid to each dispute participant aligned with the final outcome (0.5 XLM)
+pub const DISPUTE_PARTICIPATION_REWARD: i128 = 5_000_000;
+
+/// Maximum number of participants rewarded for a single dispute
+pub const MAX_DISPUTE_REWARD_RECIPIENTS: u32 = 50;
+
+// ===== BUG BOUNTY CONSTANTS =====
+
+/// Maximum amount of a single bug bounty payout (10,000 XLM)
+pub const MAX_BOUNTY_PAYOUT: i128 = 100_000_000_000;
+
+/// Maximum total bug bounty payouts within one cap period (25,000 XLM)
+pub const MAX_BOUNTY_PAYOUTS_PER_PERIOD: i128 = 250_000_000_000;
+
+/// Length of the bug bounty payout cap period (30 days)
+pub const BOUNTY_CAP_PERIOD_SECONDS: u64 = 30 * 24 * 60 * 60;
+
+// ===== CIRCUIT BREAKER CONSTANTS =====
+
+/// Maximum number of markets a single freeze extension batch may visit
+pub const MAX_FREEZE_EXTENSION_BATCH: u32 = 50;
+
+// ===== AMM CONSTANTS =====
+
+/// Minimum LMSR liquidity parameter for AMM markets (1 XLM)
+pub const MIN_AMM_LIQUIDITY: i128 = 10_000_000;
+
+/// Maximum LMSR liquidity parameter for AMM markets (1B XLM)
+pub const MAX_AMM_LIQUIDITY: i128 = 10_000_000_000_000_000;
+
+// ===== QUERY CONSTANTS =====
+
+/// Maximum number of entries returned by a single paginated getter
+pub const MAX_QUERY_PAGE_SIZE: u32 = 100;
+
+/// Maximum number of markets returned by `get_active_markets`
+pub const MAX_ACTIVE_MARKETS_LISTED: u32 = 100;
+
+/// Maximum number of markets voted on in one `batch_vote` call
+pub const MAX_BATCH_VOTES: u32 = 20;
+
+/// Maximum number of markets on a user's watchlist
+pub const MAX_WATCHLIST_SIZE: u32 = 50;
+
+/// Resource budget of one `sweep_unclaimed_winnings` call, in the units of
+/// `BatchUtils::estimate_gas_cost` (100 voters per call)
+pub const SWEEP_RESOURCE_BUDGET: u64 = 150_000;
+
+/// Default time a market may stay listed without a single vote (3 days)
+pub const DEFAULT_MARKET_LISTING_WINDOW_SECONDS: u64 = 259_200;
+
+/// Default cap on one user's total stake in a market (0 = no cap)
+pub const DEFAULT_MAX_STAKE_PER_USER: i128 = 0;
+
+/// Default cap on one user's share of a market's pool, in bps (0 = no cap)
+pub const DEFAULT_MAX_USER_POOL_SHARE_BPS: u32 = 0;
+
+/// Default pool size from which the per-user share cap applies (100 XLM)
+pub const DEFAULT_USER_SHARE_MIN_POOL: i128 = 1_000_000_000;
+
+// ===== API CONSTANTS =====
+
+/// Current version of the public API (bumped when entry points are superseded)
+pub const API_VERSION: u32 = 2;
+
+/// Oldest API version whose entry points are still served
+pub const MIN_SUPPORTED_API_VERSION: u32 = 1;
+
+// ===== ANNOUNCEMENT CONSTANTS =====
+
+/// Maximum number of announcements kept in the on-chain log
+pub const MAX_ANNOUNCEMENTS: u32 = 20;
+
+/// Maximum length of an announcement message
+pub const MAX_ANNOUNCEMENT_LENGTH: u32 = 500;
+
+// ===== KEEPER CONSTANTS =====
+
+/// Minimum stake a keeper must bond to register (10 XLM)
+pub const MIN_KEEPER_STAKE: i128 = 100_000_000;
+
+/// Maximum number of entries a single `bump_ttls` call may extend
+pub const MAX_TTL_BUMP_BATCH: u32 = 25;
+
+/// Maximum number of markets a single `audit_storage` call may check
+pub const MAX_STORAGE_AUDIT_BATCH: u32 = 25;
+
+/// Extend an entry's TTL once it falls below this many ledgers (~30 days)
+pub const MARKET_TTL_THRESHOLD_LEDGERS: u32 = 518_400;
+
+/// Ledgers to extend an entry's TTL to (~180 days)
+pub const MARKET_TTL_EXTEND_TO_LEDGERS: u32 = 3_110_400;
+
+/// Minimum ledgers between paid bumps of the same entry (~1 day)
+pub const TTL_BUMP_MIN_INTERVAL_LEDGERS: u32 = 17_280;
+
+/// Keeper reward per entry bumped (0.01 XLM)
+pub const KEEPER_TTL_BUMP_REWARD: i128 = 100_000;
+
+/// Keeper reward for publishing a daily settlement report (0.01 XLM)
+pub const KEEPER_REPORT_REWARD: i128 = 100_000;
+
 // ===== RESOLUTION CONSTANTS =====
 
 /// Minimum confidence score
@@ -144,10 +361,25 @@ pub const COMMUNITY_WEIGHT_PERCENTAGE: u32 = 30;
 /// Minimum votes for community consensus
 pub const MIN_VOTES_FOR_CONSENSUS: u32 = 5;
 
+/// Wait after the first failed oracle resolution attempt (5 minutes), doubled per failure
+pub const RESOLUTION_RETRY_BASE_BACKOFF_SECONDS: u64 = 300;
+
+/// Upper bound on the wait between oracle resolution attempts (6 hours)
+pub const RESOLUTION_RETRY_MAX_BACKOFF_SECONDS: u64 = 21_600;
+
+/// Minimum bond to assert a market outcome optimistically (10 XLM)
+pub const DEFAULT_ASSERTION_MIN_BOND: i128 = 100_000_000;
+
+/// Time an optimistic assertion stays open to challenge (2 hours)
+pub const DEFAULT_ASSERTION_CHALLENGE_WINDOW_SECONDS: u64 = 7_200;
+
 /// Default resolution timeout in seconds (7 days). After market end_time + this period
 /// with no oracle result, anyone may trigger refund on oracle failure.
 pub const DEFAULT_RESOLUTION_TIMEOUT_SECONDS: u64 = 604_800;
 
+/// Number of most recent end-to-resolution times kept for SLA statistics
+pub const MAX_RESOLUTION_TIME_SAMPLES: u32 = 500;
+
 // ===== ORACLE CONSTANTS =====
 
 /// Maximum oracle price age (1 hour)
@@ -159,6 +391,18 @@ pub const ORACLE_RETRY_ATTEMPTS: u32 = 3;
 /// Oracle timeout seconds
 pub const ORACLE_TIMEOUT_SECONDS: u64 = 30;
 
+/// Maximum number of feeds aggregated for one market
+pub const MAX_AGGREGATED_ORACLE_SOURCES: u32 = 5;
+
+/// Maximum number of terms in a composite oracle condition
+pub const MAX_COMPOSITE_CONDITION_TERMS: u32 = 5;
+
+/// Widest Pyth confidence interval accepted, in basis points of the price (1%)
+pub const PYTH_MAX_CONFIDENCE_BPS: u32 = 100;
+
+/// Maximum number of whitelisted push oracle reporters
+pub const MAX_ORACLE_REPORTERS: u32 = 20;
+
 // ===== STORAGE CONSTANTS =====
 
 /// Storage key for admin address
@@ -493,6 +737,8 @@ pub struct FeeConfig {
 ///     large_market_threshold: 1_000_000_000, // 100 XLM = large market
 ///     high_activity_threshold: 100,     // 100+ votes = high activity
 ///     dispute_extension_hours: 24,      // 24 hour dispute window
+///     vote_change_freeze_hours: 1,      // no vote changes in the last hour
+///     quiet_period_hours: 2,            // no new stakes in the last 2 hours
 /// };
 ///
 /// // Check if market qualifies as large
@@ -554,6 +800,19 @@ pub struct VotingConfig {
     /// - Oracle re-evaluation
     /// - Consensus building
     pub dispute_extension_hours: u32,
+
+    /// Hours before a market's end time after which votes are frozen.
+    ///
+    /// `change_vote` is rejected inside this window so positions settle
+    /// before the close. Zero allows changes until the end time.
+    pub vote_change_freeze_hours: u32,
+
+    /// Hours before a market's end time during which new stakes are rejected.
+    ///
+    /// New votes, stake increases and bets are rejected inside this quiet
+    /// period, so outcomes that become observable shortly before the close
+    /// cannot be bet on once effectively known. Zero disables it.
+    pub quiet_period_hours: u32,
 }
 
 /// Market creation and structure configuration parameters.
@@ -1593,6 +1852,8 @@ impl ConfigManager {
             large_market_threshold: LARGE_MARKET_THRESHOLD,
             high_activity_threshold: HIGH_ACTIVITY_THRESHOLD,
             dispute_extension_hours: DISPUTE_EXTENSION_HOURS,
+            vote_change_freeze_hours: VOTE_CHANGE_FREEZE_HOURS,
+            quiet_period_hours: VOTE_QUIET_PERIOD_HOURS,
         }
     }
 
@@ -1612,6 +1873,7 @@ impl ConfigManager {
     /// - **Large Market Threshold**: 200 XLM (vs 100 XLM default)
     /// - **High Activity Threshold**: 200 votes (vs 100 votes default)
     /// - **Dispute Extension**: 48 hours (vs 24 hours default)
+    /// - **Vote Change Freeze**: 6 hours (vs 1 hour default)
     ///
     /// # Returns
     ///
@@ -1660,6 +1922,8 @@ impl ConfigManager {
             large_market_threshold: 2_000_000_000, // 200 XLM for mainnet
             high_activity_threshold: 200,          // 200 votes for mainnet
             dispute_extension_hours: 48,           // 48 hours for mainnet
+            vote_change_freeze_hours: 6,           // 6 hours for mainnet
+            quiet_period_hours: 2,                 // 2 hours for mainnet
         }
     }
 
@@ -2285,6 +2549,23 @@ impl ConfigManager {
             .unwrap_or_else(|| soroban_sdk::Vec::new(env)))
     }
 
+    /// Page through the configuration update history, oldest first.
+    ///
+    /// `limit` must be between 1 and `MAX_QUERY_PAGE_SIZE`.
+    pub fn get_configuration_history_page(
+        env: &Env,
+        offset: u32,
+        limit: u32,
+    ) -> Result<soroban_sdk::Vec<ConfigUpdateRecord>, Error> {
+        let history = Self::get_configuration_history(env)?;
+        let (skip, take) = crate::queries::QueryManager::page_bounds(history.len(), offset, limit)?;
+        let mut page = soroban_sdk::Vec::new(env);
+        for record in history.iter().skip(skip).take(take) {
+            page.push_back(record);
+        }
+        Ok(page)
+    }
+
     /// Validate a set of configuration changes without persisting them
     pub fn validate_configuration_changes(env: &Env, changes: &ConfigChanges) -> Result<(), Error> {
         let mut cfg = Self::get_config(env)?;
@@ -2426,6 +2707,7 @@ impl ConfigManager {
         limits: MarketLimits,
     ) -> Result<ContractConfig, Error> {
         crate::admin::AdminAccessControl::validate_admin_for_action(env, &admin, "update_config")?;
+        ConfigValidator::validate_market_limits(&limits)?;
 
         let mut cfg = Self::get_config(env)?;
         // Old value snapshot (condensed)
@@ -2479,6 +2761,36 @@ impl ConfigManager {
 
         Ok(cfg)
     }
+
+    /// Default market limits profile for a deployment environment
+    pub fn get_market_limits_profile_for_environment(
+        environment: &Environment,
+    ) -> MarketLimitsProfile {
+        match environment {
+            Environment::Development => MarketLimitsProfile::Degen,
+            Environment::Testnet | Environment::Custom => MarketLimitsProfile::Standard,
+            Environment::Mainnet => MarketLimitsProfile::Conservative,
+        }
+    }
+
+    /// Apply a named market limits profile (requires admin with update_config permission)
+    pub fn apply_market_limits_profile(
+        env: &Env,
+        admin: Address,
+        profile: MarketLimitsProfile,
+    ) -> Result<ContractConfig, Error> {
+        Self::update_market_limits(env, admin, MarketLimits::for_profile(profile))
+    }
+
+    /// Apply the default market limits profile for the configured environment
+    pub fn apply_environment_market_limits(
+        env: &Env,
+        admin: Address,
+    ) -> Result<ContractConfig, Error> {
+        let cfg = Self::get_config(env)?;
+        let profile = Self::get_market_limits_profile_for_environment(&cfg.network.environment);
+        Self::apply_market_limits_profile(env, admin, profile)
+    }
 }
 
 // ===== CONFIGURATION VALIDATOR =====
@@ -2573,6 +2885,33 @@ impl ConfigValidator {
         Ok(())
     }
 
+    /// Validate market limits for cross-field consistency and global bounds
+    pub fn validate_market_limits(limits: &MarketLimits) -> Result<(), Error> {
+        if limits.min_duration_days < MIN_MARKET_DURATION_DAYS
+            || limits.max_duration_days > MAX_MARKET_DURATION_DAYS
+            || limits.max_duration_days < limits.min_duration_days
+        {
+            return Err(Error::InvalidInput);
+        }
+
+        if limits.min_outcomes < MIN_MARKET_OUTCOMES
+            || limits.max_outcomes > MAX_MARKET_OUTCOMES
+            || limits.max_outcomes < limits.min_outcomes
+        {
+            return Err(Error::InvalidInput);
+        }
+
+        if limits.max_question_length == 0 || limits.max_question_length > MAX_QUESTION_LENGTH {
+            return Err(Error::InvalidInput);
+        }
+
+        if limits.max_outcome_length == 0 || limits.max_outcome_length > MAX_OUTCOME_LENGTH {
+            return Err(Error::InvalidInput);
+        }
+
+        Ok(())
+    }
+
     /// Validate extension configuration
     pub fn validate_extension_config(config: &ExtensionConfig) -> Result<(), Error> {
         if config.max_extension_days < config.min_extension_days {
@@ -2723,6 +3062,50 @@ pub struct MarketLimits {
     pub max_outcome_length: u32,
 }
 
+/// Named market limits presets, selectable per environment or per market tier
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum MarketLimitsProfile {
+    /// Short, simple markets with few outcomes
+    Conservative,
+    /// Balanced limits for general use
+    Standard,
+    /// Most permissive limits allowed by the contract
+    Degen,
+}
+
+impl MarketLimits {
+    /// Build the limits for a named profile
+    pub fn for_profile(profile: MarketLimitsProfile) -> Self {
+        match profile {
+            MarketLimitsProfile::Conservative => MarketLimits {
+                max_duration_days: 90,
+                min_duration_days: 3,
+                max_outcomes: 4,
+                min_outcomes: MIN_MARKET_OUTCOMES,
+                max_question_length: 300,
+                max_outcome_length: 50,
+            },
+            MarketLimitsProfile::Standard => MarketLimits {
+                max_duration_days: 180,
+                min_duration_days: MIN_MARKET_DURATION_DAYS,
+                max_outcomes: 6,
+                min_outcomes: MIN_MARKET_OUTCOMES,
+                max_question_length: 400,
+                max_outcome_length: 80,
+            },
+            MarketLimitsProfile::Degen => MarketLimits {
+                max_duration_days: MAX_MARKET_DURATION_DAYS,
+                min_duration_days: MIN_MARKET_DURATION_DAYS,
+                max_outcomes: MAX_MARKET_OUTCOMES,
+                min_outcomes: MIN_MARKET_OUTCOMES,
+                max_question_length: MAX_QUESTION_LENGTH,
+                max_outcome_length: MAX_OUTCOME_LENGTH,
+            },
+        }
+    }
+}
+
 /// Partial configuration changes for validation and bulk updates
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[contracttype]
@@ -2799,6 +3182,8 @@ impl ConfigTesting {
                 large_market_threshold: 500_000_000,
                 high_activity_threshold: 50,
                 dispute_extension_hours: 12,
+                vote_change_freeze_hours: 1,
+                quiet_period_hours: 0,
             },
             market: MarketConfig {
                 max_duration_days: 30,
@@ -2993,4 +3378,75 @@ mod tests {
         assert_eq!(ORACLE_RETRY_ATTEMPTS, 3);
         assert_eq!(ORACLE_TIMEOUT_SECONDS, 30);
     }
+
+    #[test]
+    fn test_market_limits_profiles() {
+        let conservative = MarketLimits::for_profile(MarketLimitsProfile::Conservative);
+        let standard = MarketLimits::for_profile(MarketLimitsProfile::Standard);
+        let degen = MarketLimits::for_profile(MarketLimitsProfile::Degen);
+
+        // Every preset is internally consistent
+        assert!(ConfigValidator::validate_market_limits(&conservative).is_ok());
+        assert!(ConfigValidator::validate_market_limits(&standard).is_ok());
+        assert!(ConfigValidator::validate_market_limits(&degen).is_ok());
+
+        // Profiles widen from conservative to degen
+        assert!(conservative.max_duration_days < standard.max_duration_days);
+        assert!(standard.max_outcomes < degen.max_outcomes);
+
+        assert_eq!(
+            ConfigManager::get_market_limits_profile_for_environment(&Environment::Mainnet),
+            MarketLimitsProfile::Conservative
+        );
+        assert_eq!(
+            ConfigManager::get_market_limits_profile_for_environment(&Environment::Development),
+            MarketLimitsProfile::Degen
+        );
+
+        // Cross-field violations are rejected
+        let mut inverted = standard.clone();
+        inverted.min_duration_days = inverted.max_duration_days + 1;
+        assert_eq!(
+            ConfigValidator::validate_market_limits(&inverted),
+            Err(Error::InvalidInput)
+        );
+
+        let mut too_many = standard.clone();
+        too_many.max_outcomes = MAX_MARKET_OUTCOMES + 1;
+        assert_eq!(
+            ConfigValidator::validate_market_limits(&too_many),
+            Err(Error::InvalidInput)
+        );
+    }
+
+    #[test]
+    fn test_apply_market_limits_profile() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let client = crate::PredictifyHybridClient::new(&env, &contract_id);
+        let admin = <Address as soroban_sdk::testutils::Address>::generate(&env);
+        client.initialize(&admin, &None);
+
+        env.as_contract(&contract_id, || {
+            let config = ConfigManager::get_development_config(&env);
+            ConfigManager::store_config(&env, &config).unwrap();
+
+            let cfg = ConfigManager::apply_market_limits_profile(
+                &env,
+                admin.clone(),
+                MarketLimitsProfile::Conservative,
+            )
+            .unwrap();
+            assert_eq!(cfg.market.max_duration_days, 90);
+            assert_eq!(cfg.market.max_outcomes, 4);
+        });
+
+        // Development deployments fall back to the degen preset
+        env.as_contract(&contract_id, || {
+            let cfg = ConfigManager::apply_environment_market_limits(&env, admin.clone()).unwrap();
+            assert_eq!(cfg.market.max_duration_days, MAX_MARKET_DURATION_DAYS);
+            assert_eq!(cfg.market.max_outcomes, MAX_MARKET_OUTCOMES);
+        });
+    }
 }
@@ -0,0 +1,305 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Map, String, Symbol, Vec};
+
+use crate::disputes::{DisputeAppealManager, DisputeUtils};
+use crate::governance::GovernanceContract;
+use crate::markets::MarketStateManager;
+use crate::Error;
+
+// ===== ARBITRATION COUNCIL =====
+
+/// Voting rules of the arbitration council.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CouncilPolicy {
+    /// Share of members who must rule before a decision, in basis points
+    pub quorum_bps: u32,
+    /// Share of cast rulings the decided outcome must exceed, in basis points
+    pub majority_bps: u32,
+}
+
+/// Pending change to the council membership, decided by a governance proposal.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CouncilChange {
+    pub proposal_id: Symbol,
+    pub member: Address,
+    /// `true` to add the member, `false` to remove them
+    pub add: bool,
+}
+
+/// Governance-elected council that decides contested disputes.
+///
+/// Unlike per-market arbitration panels, the council is contract-wide. A
+/// dispute is contested once it is escalated or appealed into its final
+/// round; while the council has members, such disputes are settled by its
+/// ruling instead of the admin's `resolve_dispute`. A ruling is decided once
+/// a quorum of members has voted and one outcome holds a majority of the
+/// votes cast.
+///
+/// The admin seats the first council; after that members are only added or
+/// removed through governance proposals.
+pub struct ArbitrationCouncil;
+
+impl ArbitrationCouncil {
+    fn votes_key(market_id: &Symbol) -> (Symbol, Symbol) {
+        (symbol_short!("cncl_vote"), market_id.clone())
+    }
+
+    fn change_key(proposal_id: &Symbol) -> (Symbol, Symbol) {
+        (symbol_short!("cncl_chg"), proposal_id.clone())
+    }
+
+    /// Current council members
+    pub fn get_members(env: &Env) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&symbol_short!("cncl_mbrs"))
+            .unwrap_or(Vec::new(env))
+    }
+
+    fn store_members(env: &Env, members: &Vec<Address>) {
+        env.storage()
+            .persistent()
+            .set(&symbol_short!("cncl_mbrs"), members);
+    }
+
+    /// Seat the first council (admin only).
+    ///
+    /// Members must be distinct, and there must be between one and
+    /// `MAX_ARBITRATION_COUNCIL_SIZE` of them. Fails with `InvalidState`
+    /// once a council is seated.
+    pub fn set_members(env: &Env, admin: &Address, members: Vec<Address>) -> Result<(), Error> {
+        crate::recovery::RecoveryManager::assert_is_admin(env, admin)?;
+        if !Self::get_members(env).is_empty() {
+            return Err(Error::InvalidState);
+        }
+        if members.is_empty() || members.len() > crate::config::MAX_ARBITRATION_COUNCIL_SIZE {
+            return Err(Error::InvalidInput);
+        }
+        for (i, member) in members.iter().enumerate() {
+            if members.first_index_of(&member) != Some(i as u32) {
+                return Err(Error::InvalidInput);
+            }
+        }
+        Self::store_members(env, &members);
+        Ok(())
+    }
+
+    /// Get the voting rules in force
+    pub fn get_policy(env: &Env) -> CouncilPolicy {
+        env.storage()
+            .persistent()
+            .get(&symbol_short!("cncl_pol"))
+            .unwrap_or(CouncilPolicy {
+                quorum_bps: crate::config::DEFAULT_COUNCIL_QUORUM_BPS,
+                majority_bps: crate::config::DEFAULT_COUNCIL_MAJORITY_BPS,
+            })
+    }
+
+    /// Set the voting rules (admin only)
+    pub fn set_policy(env: &Env, admin: &Address, policy: &CouncilPolicy) -> Result<(), Error> {
+        crate::recovery::RecoveryManager::assert_is_admin(env, admin)?;
+        if policy.quorum_bps == 0
+            || policy.quorum_bps > 10_000
+            || policy.majority_bps < 5_000
+            || policy.majority_bps >= 10_000
+        {
+            return Err(Error::InvalidInput);
+        }
+        env.storage()
+            .persistent()
+            .set(&symbol_short!("cncl_pol"), policy);
+        Ok(())
+    }
+
+    /// Whether a market's dispute is contested and decided by the council
+    pub fn handles(env: &Env, market_id: &Symbol) -> bool {
+        !Self::get_members(env).is_empty()
+            && (DisputeAppealManager::is_final_round(env, market_id)
+                || DisputeUtils::get_dispute_escalation(env, market_id).is_some())
+    }
+
+    // ===== RULINGS =====
+
+    /// Council rulings on a market's contested dispute, by member
+    pub fn get_votes(env: &Env, market_id: &Symbol) -> Map<Address, String> {
+        env.storage()
+            .persistent()
+            .get(&Self::votes_key(market_id))
+            .unwrap_or(Map::new(env))
+    }
+
+    /// Record a member's ruling on a contested dispute.
+    ///
+    /// Returns the council's decision once the policy's quorum and majority
+    /// are met.
+    pub fn cast_vote(
+        env: &Env,
+        member: &Address,
+        market_id: &Symbol,
+        outcome: &String,
+    ) -> Result<Option<String>, Error> {
+        if !Self::get_members(env).contains(member) {
+            return Err(Error::DisputeVoteDenied);
+        }
+
+        let market = MarketStateManager::get_market(env, market_id)?;
+        if market.dispute_stakes.is_empty()
+            || market.winning_outcomes.is_some()
+            || !Self::handles(env, market_id)
+        {
+            return Err(Error::DisputeCondNotMet);
+        }
+        if !market.outcomes.contains(outcome) {
+            return Err(Error::InvalidOutcome);
+        }
+        let mut votes = Self::get_votes(env, market_id);
+        if votes.contains_key(member.clone()) {
+            return Err(Error::DisputeAlreadyVoted);
+        }
+
+        votes.set(member.clone(), outcome.clone());
+        env.storage()
+            .persistent()
+            .set(&Self::votes_key(market_id), &votes);
+
+        crate::events::EventEmitter::emit_arbitrator_vote_cast(env, market_id, member, outcome);
+        Ok(Self::decision(env, market_id))
+    }
+
+    /// Outcome decided by the council under the policy, if any.
+    ///
+    /// Rulings of members who have since left the council are not counted.
+    pub fn decision(env: &Env, market_id: &Symbol) -> Option<String> {
+        let members = Self::get_members(env);
+        let policy = Self::get_policy(env);
+        let mut tally: Map<String, u32> = Map::new(env);
+        let mut cast = 0u32;
+        for (member, outcome) in Self::get_votes(env, market_id).iter() {
+            if members.contains(&member) {
+                tally.set(outcome.clone(), tally.get(outcome).unwrap_or(0) + 1);
+                cast += 1;
+            }
+        }
+        if (cast as u64) * 10_000 < (members.len() as u64) * policy.quorum_bps as u64 {
+            return None;
+        }
+        tally.iter().find_map(|(outcome, count)| {
+            ((count as u64) * 10_000 > (cast as u64) * policy.majority_bps as u64)
+                .then_some(outcome)
+        })
+    }
+
+    // ===== MEMBERSHIP PROPOSALS =====
+
+    /// Open a governance proposal to add or remove a council member.
+    ///
+    /// Governance must be initialized; the proposal is voted on like any
+    /// other and applied with `apply_change` once it passes.
+    pub fn propose_change(
+        env: &Env,
+        proposer: &Address,
+        proposal_id: &Symbol,
+        member: &Address,
+        add: bool,
+    ) -> Result<CouncilChange, Error> {
+        if !GovernanceContract::is_initialized(env) {
+            return Err(Error::InvalidState);
+        }
+        Self::validate_change(env, member, add)?;
+
+        let (title, description) = if add {
+            (
+                "Add arbitration council member",
+                "Seat a new member on the arbitration council",
+            )
+        } else {
+            (
+                "Remove arbitration council member",
+                "Remove a member from the arbitration council",
+            )
+        };
+        GovernanceContract::create_proposal(
+            env.clone(),
+            proposer.clone(),
+            proposal_id.clone(),
+            String::from_str(env, title),
+            String::from_str(env, description),
+            None,
+            None,
+        )
+        .map_err(|_| Error::InvalidInput)?;
+
+        let change = CouncilChange {
+            proposal_id: proposal_id.clone(),
+            member: member.clone(),
+            add,
+        };
+        env.storage()
+            .persistent()
+            .set(&Self::change_key(proposal_id), &change);
+        Ok(change)
+    }
+
+    /// Pending membership change of a proposal, if any
+    pub fn get_change(env: &Env, proposal_id: &Symbol) -> Option<CouncilChange> {
+        env.storage()
+            .persistent()
+            .get(&Self::change_key(proposal_id))
+    }
+
+    /// Apply a membership change whose proposal passed. Callable by anyone.
+    ///
+    /// Fails with `MarketNotReady` while the proposal is still being voted
+    /// on, and with `InvalidState` if it was rejected.
+    pub fn apply_change(env: &Env, proposal_id: &Symbol) -> Result<Vec<Address>, Error> {
+        let change = Self::get_change(env, proposal_id).ok_or(Error::InvalidInput)?;
+        let passed = GovernanceContract::proposal_passed(env.clone(), proposal_id.clone())
+            .map_err(|_| Error::InvalidInput)?
+            .ok_or(Error::MarketNotReady)?;
+        if !passed {
+            return Err(Error::InvalidState);
+        }
+        Self::validate_change(env, &change.member, change.add)?;
+
+        let mut members = Self::get_members(env);
+        if change.add {
+            members.push_back(change.member.clone());
+        } else if let Some(index) = members.first_index_of(&change.member) {
+            members.remove(index);
+        }
+        Self::store_members(env, &members);
+        env.storage()
+            .persistent()
+            .remove(&Self::change_key(proposal_id));
+        GovernanceContract::execute_proposal(
+            env.clone(),
+            env.current_contract_address(),
+            proposal_id.clone(),
+        )
+        .map_err(|_| Error::InvalidState)?;
+
+        crate::events::EventEmitter::emit_council_member_changed(
+            env,
+            proposal_id,
+            &change.member,
+            change.add,
+        );
+        Ok(members)
+    }
+
+    /// A member can only be added if absent and removed if present, within
+    /// the council size bounds.
+    fn validate_change(env: &Env, member: &Address, add: bool) -> Result<(), Error> {
+        let members = Self::get_members(env);
+        let valid = if add {
+            !members.contains(member) && members.len() < crate::config::MAX_ARBITRATION_COUNCIL_SIZE
+        } else {
+            members.contains(member) && members.len() > 1
+        };
+        if !valid {
+            return Err(Error::InvalidInput);
+        }
+        Ok(())
+    }
+}
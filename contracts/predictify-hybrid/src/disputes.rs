@@ -3,10 +3,13 @@
 use crate::{
     errors::Error,
     markets::MarketStateManager,
-    types::Market,
+    rewards::RewardsManager,
+    types::{Market, MarketState},
     voting::{VotingUtils, DISPUTE_EXTENSION_HOURS, MIN_DISPUTE_STAKE},
 };
-use soroban_sdk::{contracttype, symbol_short, Address, Env, Map, String, Symbol, Vec};
+use soroban_sdk::{
+    contracttype, symbol_short, Address, Bytes, BytesN, Env, Map, String, Symbol, Vec,
+};
 
 // ===== DISPUTE STRUCTURES =====
 
@@ -24,6 +27,7 @@ use soroban_sdk::{contracttype, symbol_short, Address, Env, Map, String, Symbol,
 /// * `timestamp` - When the dispute was created (ledger timestamp)
 /// * `reason` - Optional explanation for why the dispute was raised
 /// * `status` - Current status of the dispute (Active, Resolved, etc.)
+/// * `required_stake` - Minimum dispute stake that applied when it was opened
 ///
 /// # Example
 ///
@@ -41,6 +45,7 @@ use soroban_sdk::{contracttype, symbol_short, Address, Env, Map, String, Symbol,
 ///     timestamp: env.ledger().timestamp(),
 ///     reason: Some(String::from_str(&env, "Oracle data appears incorrect")),
 ///     status: DisputeStatus::Active,
+///     required_stake: 10_000_000,
 /// };
 ///
 /// // Dispute is now active and awaiting community voting
@@ -61,6 +66,7 @@ use soroban_sdk::{contracttype, symbol_short, Address, Env, Map, String, Symbol,
 /// - Winners receive their stake back plus rewards
 /// - Losers forfeit their stake to the winning side
 #[contracttype]
+#[derive(Clone)]
 pub struct Dispute {
     pub user: Address,
     pub market_id: Symbol,
@@ -68,6 +74,7 @@ pub struct Dispute {
     pub timestamp: u64,
     pub reason: Option<String>,
     pub status: DisputeStatus,
+    pub required_stake: i128,
 }
 
 /// Represents the current lifecycle status of a dispute.
@@ -117,6 +124,7 @@ pub struct Dispute {
 /// - **Rejected**: Oracle result upheld, original outcome stands
 /// - **Expired**: Insufficient community engagement, original outcome stands
 #[contracttype]
+#[derive(Clone)]
 pub enum DisputeStatus {
     Active,
     Resolved,
@@ -244,6 +252,7 @@ pub struct DisputeStats {
 /// - Timestamp for regulatory compliance
 /// - Outcome justification for participants
 #[contracttype]
+#[derive(Clone)]
 pub struct DisputeResolution {
     pub market_id: Symbol,
     pub final_outcome: String,
@@ -603,6 +612,7 @@ pub struct DisputeEscalation {
 /// - **Quality Control**: Penalties for incorrect dispute judgments
 /// - **Platform Sustainability**: Fees support ongoing operations
 #[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct DisputeFeeDistribution {
     pub dispute_id: Symbol,
     pub total_fees: i128,
@@ -669,6 +679,71 @@ pub struct TimeoutAnalytics {
     pub total_extensions: u32,
 }
 
+/// Kind of lifecycle event in a dispute timeline.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DisputeTimelineEventKind {
+    /// A dispute was opened against the oracle result
+    Opened,
+    /// A community member voted in support of the dispute
+    VotedFor,
+    /// A community member voted against the dispute
+    VotedAgainst,
+    /// The dispute was escalated for admin review
+    Escalated,
+    /// Dispute fees were distributed
+    FeesDistributed,
+    /// The dispute was resolved with a final outcome
+    Resolved,
+}
+
+/// Single entry of a dispute case history, reconstructed from stored dispute records.
+///
+/// `actor` is the disputer, voter or escalating user (`None` for fee
+/// distribution and resolution). `detail` carries the dispute or escalation
+/// reason, or the final outcome for resolution entries.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeTimelineEntry {
+    pub kind: DisputeTimelineEventKind,
+    pub actor: Option<Address>,
+    pub stake: i128,
+    pub timestamp: u64,
+    pub detail: Option<String>,
+}
+
+/// Panel of arbitrators designated at market creation to decide its disputes.
+///
+/// The composition is fixed once set. `votes` maps each arbitrator who has
+/// ruled on the market's dispute to the outcome they chose.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ArbitrationPanel {
+    pub market_id: Symbol,
+    pub arbitrators: Vec<Address>,
+    pub votes: Map<Address, String>,
+    pub created_at: u64,
+}
+
+/// Commitment to the randomness of an arbitration panel draw.
+///
+/// `commitment` is the SHA-256 of a secret seed fixed, together with the
+/// candidate pool and panel size, before the draw. Once `revealed`, `seed`
+/// lets anyone recompute the drawn panel and check it against the
+/// commitment; until then it is all zeros.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PanelDraw {
+    pub market_id: Symbol,
+    pub candidates: Vec<Address>,
+    pub panel_size: u32,
+    pub commitment: BytesN<32>,
+    /// Ledger sequence of the commitment; the reveal must come later
+    pub committed_ledger: u32,
+    pub revealed: bool,
+    pub seed: BytesN<32>,
+}
+
 // ===== DISPUTE MANAGER =====
 
 /// Central manager for all dispute-related operations in the prediction market system.
@@ -812,13 +887,23 @@ impl DisputeManager {
 
         // Get and validate market
         let mut market = MarketStateManager::get_market(env, &market_id)?;
+        crate::market_access::MarketAccessManager::require_allowed(env, &market_id, &user)?;
         DisputeValidator::validate_market_for_dispute(env, &market)?;
 
         // Validate dispute parameters
         DisputeValidator::validate_dispute_parameters(env, &user, &market, stake)?;
+        crate::validation::MarketValidator::validate_dispute_stake_for_tier(&market, stake)
+            .map_err(|e| e.to_contract_error())?;
+        let required_stake = DisputeStakePolicyManager::required_stake(env, &market);
+        if stake < required_stake {
+            return Err(Error::InsufficientStake);
+        }
 
-        // Process stake transfer
-        VotingUtils::transfer_stake(env, &user, stake)?;
+        // Process stake transfer, drawing on the market's dispute insurance first
+        let covered = DisputeInsuranceManager::cover(env, &market_id, &market, &user, stake);
+        if stake > covered {
+            VotingUtils::transfer_stake(env, &user, stake - covered)?;
+        }
 
         // Prepare reason for event emission before moving dispute
         let reason_for_event = if reason.is_some() {
@@ -835,16 +920,29 @@ impl DisputeManager {
             timestamp: env.ledger().timestamp(),
             reason,
             status: DisputeStatus::Active,
+            required_stake,
         };
+        DisputeUtils::record_dispute_opened(env, &dispute);
 
         // Add dispute to market
+        let first_dispute = market.dispute_stakes.is_empty();
         DisputeUtils::add_dispute_to_market(&mut market, dispute)?;
 
         // Extend market for dispute period
         DisputeUtils::extend_market_for_dispute(&mut market, env)?;
 
+        // Open community voting on the dispute, keyed by the market id
+        DisputeUtils::open_dispute_voting(env, &market_id)?;
+
         // Update market in storage
         MarketStateManager::update_market(env, &market_id, &market);
+        crate::statistics::StatisticsManager::record_daily_dispute_opened(env);
+        if first_dispute {
+            crate::statistics::StatisticsManager::record_creator_market_disputed(
+                env,
+                &market.admin,
+            );
+        }
 
         // Emit dispute created event
         crate::events::EventEmitter::emit_dispute_created(
@@ -952,15 +1050,51 @@ impl DisputeManager {
         let mut market = MarketStateManager::get_market(env, &market_id)?;
         DisputeValidator::validate_market_for_resolution(env, &market)?;
 
-        // Calculate dispute impact
-        let dispute_impact = DisputeAnalytics::calculate_dispute_impact(&market);
+        // The ruling must be past appeal, and contested disputes are the council's
+        if DisputeAppealManager::is_appealable(env, &market_id)
+            || crate::council::ArbitrationCouncil::handles(env, &market_id)
+        {
+            return Err(Error::DisputeCondNotMet);
+        }
+
+        // Determine final outcome with dispute consideration; markets with an
+        // arbitration panel are decided by the panel majority instead
+        let final_outcome = match ArbitrationPanelManager::get_panel(env, &market_id) {
+            Some(panel) => {
+                ArbitrationPanelManager::panel_decision(&panel).ok_or(Error::DisputeCondNotMet)?
+            }
+            None => DisputeUtils::determine_final_outcome_with_disputes(env, &market)?,
+        };
+
+        Self::settle_dispute(env, &market_id, &mut market, final_outcome)
+    }
 
-        // Determine final outcome with dispute consideration
-        let final_outcome = DisputeUtils::determine_final_outcome_with_disputes(env, &market)?;
+    /// Settle a contested dispute with the arbitration council's ruling.
+    pub fn resolve_by_council(
+        env: &Env,
+        market_id: &Symbol,
+        final_outcome: String,
+    ) -> Result<DisputeResolution, Error> {
+        let mut market = MarketStateManager::get_market(env, market_id)?;
+        DisputeValidator::validate_market_for_resolution(env, &market)?;
+        if !crate::council::ArbitrationCouncil::handles(env, market_id) {
+            return Err(Error::DisputeCondNotMet);
+        }
+        Self::settle_dispute(env, market_id, &mut market, final_outcome)
+    }
+
+    /// Set a disputed market's final outcome and record the dispute resolution.
+    fn settle_dispute(
+        env: &Env,
+        market_id: &Symbol,
+        market: &mut Market,
+        final_outcome: String,
+    ) -> Result<DisputeResolution, Error> {
+        let dispute_impact = DisputeAnalytics::calculate_dispute_impact(market);
 
         // Calculate weights
-        let oracle_weight = DisputeAnalytics::calculate_oracle_weight(&market);
-        let community_weight = DisputeAnalytics::calculate_community_weight(&market);
+        let oracle_weight = DisputeAnalytics::calculate_oracle_weight(market);
+        let community_weight = DisputeAnalytics::calculate_community_weight(market);
 
         // Create resolution record
         let resolution = DisputeResolution {
@@ -973,9 +1107,72 @@ impl DisputeManager {
         };
 
         // Update market with final outcome
-        DisputeUtils::finalize_market_with_resolution(&mut market, final_outcome)?;
-        MarketStateManager::update_market(env, &market_id, &market);
+        DisputeUtils::finalize_market_with_resolution(market, final_outcome.clone())?;
+        MarketStateManager::update_market(env, market_id, market);
+        DisputeAppealManager::settle_bonds(env, market_id, market, &final_outcome)?;
+        DisputeUtils::store_dispute_resolution(env, market_id, &resolution);
+        crate::resolution::ResolutionSlaTracker::record_resolution(env, market_id, market);
+
+        // Reward honest participants on top of stake redistribution
+        RewardsManager::emit_dispute_participation_rewards(env, market, market_id, &final_outcome)?;
+
+        Ok(resolution)
+    }
+
+    /// Settle a dispute before its voting window ends once the vote is overwhelming.
+    ///
+    /// Anyone may call this while dispute voting is open. It succeeds when
+    /// the total vote stake reaches the policy's `min_total_stake` and one
+    /// side holds at least `supermajority_bps` of it. Voting is closed as
+    /// completed, and the market resolves to the oracle result if the
+    /// dispute is rejected, or to the best-backed other outcome if upheld.
+    pub fn resolve_dispute_early(
+        env: &Env,
+        market_id: Symbol,
+        dispute_id: Symbol,
+    ) -> Result<DisputeResolution, Error> {
+        let mut market = MarketStateManager::get_market(env, &market_id)?;
+        DisputeValidator::validate_market_for_resolution(env, &market)?;
+        if ArbitrationPanelManager::get_panel(env, &market_id).is_some()
+            || crate::council::ArbitrationCouncil::handles(env, &market_id)
+        {
+            return Err(Error::DisputeCondNotMet);
+        }
+
+        let mut voting = DisputeUtils::get_dispute_voting(env, &dispute_id)?;
+        let now = env.ledger().timestamp();
+        if !matches!(voting.status, DisputeVotingStatus::Active) || now > voting.voting_end {
+            return Err(Error::DisputeCondNotMet);
+        }
+        let policy = DisputeSupermajorityManager::get_policy(env);
+        if !DisputeSupermajorityManager::is_reached(&policy, &voting) {
+            return Err(Error::DisputeCondNotMet);
+        }
 
+        let oracle_result = market
+            .oracle_result
+            .clone()
+            .ok_or(Error::OracleUnavailable)?;
+        let upheld = DisputeUtils::calculate_stake_weighted_outcome(&voting);
+        let final_outcome = if upheld {
+            DisputeUtils::strongest_alternative_outcome(&market, &oracle_result)
+                .ok_or(Error::DisputeCondNotMet)?
+        } else {
+            oracle_result
+        };
+
+        voting.status = DisputeVotingStatus::Completed;
+        voting.voting_end = now;
+        DisputeUtils::store_dispute_voting(env, &dispute_id, &voting)?;
+
+        let resolution = Self::settle_dispute(env, &market_id, &mut market, final_outcome)?;
+        crate::events::EventEmitter::emit_dispute_auto_resolved(
+            env,
+            &dispute_id,
+            &market_id,
+            &resolution.final_outcome,
+            &String::from_str(env, "supermajority"),
+        );
         Ok(resolution)
     }
 
@@ -1356,6 +1553,11 @@ impl DisputeManager {
         // Require authentication from the user
         user.require_auth();
 
+        // Disputes on markets with an arbitration panel are not open to the community
+        if ArbitrationPanelManager::get_panel(env, &market_id).is_some() {
+            return Err(Error::DisputeVoteDenied);
+        }
+
         // Validate dispute voting conditions
         DisputeValidator::validate_dispute_voting_conditions(env, &market_id, &dispute_id)?;
 
@@ -1535,6 +1737,15 @@ impl DisputeManager {
         env: &Env,
         dispute_id: Symbol,
     ) -> Result<DisputeFeeDistribution, Error> {
+        // Close voting whose window has run out
+        let mut voting = DisputeUtils::get_dispute_voting(env, &dispute_id)?;
+        if matches!(voting.status, DisputeVotingStatus::Active)
+            && env.ledger().timestamp() > voting.voting_end
+        {
+            voting.status = DisputeVotingStatus::Completed;
+            DisputeUtils::store_dispute_voting(env, &dispute_id, &voting)?;
+        }
+
         // Validate dispute resolution conditions
         DisputeValidator::validate_dispute_resolution_conditions(env, &dispute_id)?;
 
@@ -1672,6 +1883,97 @@ impl DisputeManager {
         DisputeUtils::get_dispute_votes(env, dispute_id)
     }
 
+    /// Get the chronological case history of a market's dispute.
+    ///
+    /// Reconstructs openings, community votes, escalation, fee distribution
+    /// and resolution from stored dispute records, ordered by timestamp.
+    /// Voting, escalation and fee records are looked up under the market ID,
+    /// which doubles as the dispute ID of the market's dispute case.
+    pub fn get_dispute_timeline(
+        env: &Env,
+        market_id: Symbol,
+    ) -> Result<Vec<DisputeTimelineEntry>, Error> {
+        // Ensure the market exists
+        MarketStateManager::get_market(env, &market_id)?;
+
+        let mut timeline = Vec::new(env);
+
+        for dispute in DisputeUtils::get_opened_disputes(env, &market_id).iter() {
+            DisputeUtils::insert_chronologically(
+                &mut timeline,
+                DisputeTimelineEntry {
+                    kind: DisputeTimelineEventKind::Opened,
+                    actor: Some(dispute.user),
+                    stake: dispute.stake,
+                    timestamp: dispute.timestamp,
+                    detail: dispute.reason,
+                },
+            );
+        }
+
+        if let Ok(votes) = DisputeUtils::get_dispute_votes(env, &market_id) {
+            for vote in votes.iter() {
+                let kind = if vote.vote {
+                    DisputeTimelineEventKind::VotedFor
+                } else {
+                    DisputeTimelineEventKind::VotedAgainst
+                };
+                DisputeUtils::insert_chronologically(
+                    &mut timeline,
+                    DisputeTimelineEntry {
+                        kind,
+                        actor: Some(vote.user),
+                        stake: vote.stake,
+                        timestamp: vote.timestamp,
+                        detail: vote.reason,
+                    },
+                );
+            }
+        }
+
+        if let Some(escalation) = DisputeUtils::get_dispute_escalation(env, &market_id) {
+            DisputeUtils::insert_chronologically(
+                &mut timeline,
+                DisputeTimelineEntry {
+                    kind: DisputeTimelineEventKind::Escalated,
+                    actor: Some(escalation.escalated_by),
+                    stake: 0,
+                    timestamp: escalation.escalation_timestamp,
+                    detail: Some(escalation.escalation_reason),
+                },
+            );
+        }
+
+        let distribution = DisputeUtils::get_dispute_fee_distribution(env, &market_id)?;
+        if distribution.fees_distributed {
+            DisputeUtils::insert_chronologically(
+                &mut timeline,
+                DisputeTimelineEntry {
+                    kind: DisputeTimelineEventKind::FeesDistributed,
+                    actor: None,
+                    stake: distribution.total_fees,
+                    timestamp: distribution.distribution_timestamp,
+                    detail: None,
+                },
+            );
+        }
+
+        if let Some(resolution) = DisputeUtils::get_dispute_resolution(env, &market_id) {
+            DisputeUtils::insert_chronologically(
+                &mut timeline,
+                DisputeTimelineEntry {
+                    kind: DisputeTimelineEventKind::Resolved,
+                    actor: None,
+                    stake: 0,
+                    timestamp: resolution.resolution_timestamp,
+                    detail: Some(resolution.final_outcome),
+                },
+            );
+        }
+
+        Ok(timeline)
+    }
+
     /// Validate dispute resolution conditions
     pub fn validate_dispute_resolution_conditions(
         env: &Env,
@@ -1879,6 +2181,835 @@ impl DisputeManager {
     }
 }
 
+// ===== DISPUTE STAKE POLICY =====
+
+/// Minimum dispute stake expressed as a share of the market pool.
+///
+/// The required stake is `pool_bps` of the market's total stake, clamped to
+/// `[floor, ceiling]`, and is computed when a dispute is opened.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeStakePolicy {
+    /// Share of the market pool in basis points
+    pub pool_bps: u32,
+    /// Absolute minimum stake
+    pub floor: i128,
+    /// Absolute maximum stake
+    pub ceiling: i128,
+}
+
+/// Pool-relative minimum dispute stake.
+///
+/// Without a policy the fixed `MIN_DISPUTE_STAKE` applies to every market.
+pub struct DisputeStakePolicyManager;
+
+impl DisputeStakePolicyManager {
+    fn storage_key(env: &Env) -> Symbol {
+        Symbol::new(env, "dispute_stake_policy")
+    }
+
+    /// Get the configured policy, if any
+    pub fn get_policy(env: &Env) -> Option<DisputeStakePolicy> {
+        env.storage().persistent().get(&Self::storage_key(env))
+    }
+
+    /// Set the policy (admin only)
+    pub fn set_policy(
+        env: &Env,
+        admin: &Address,
+        policy: &DisputeStakePolicy,
+    ) -> Result<(), Error> {
+        crate::recovery::RecoveryManager::assert_is_admin(env, admin)?;
+        if policy.pool_bps == 0
+            || policy.pool_bps > 10_000
+            || policy.floor < MIN_DISPUTE_STAKE
+            || policy.ceiling < policy.floor
+        {
+            return Err(Error::InvalidInput);
+        }
+        env.storage()
+            .persistent()
+            .set(&Self::storage_key(env), policy);
+
+        crate::events::EventEmitter::emit_config_updated(
+            env,
+            admin,
+            &String::from_str(env, "dispute_stake_policy"),
+            &String::from_str(env, ""),
+            &String::from_str(
+                env,
+                &alloc::format!(
+                    "{{bps:{},floor:{},ceiling:{}}}",
+                    policy.pool_bps,
+                    policy.floor,
+                    policy.ceiling
+                ),
+            ),
+        );
+        Ok(())
+    }
+
+    /// Minimum stake required to open a dispute on `market` right now
+    pub fn required_stake(env: &Env, market: &Market) -> i128 {
+        match Self::get_policy(env) {
+            Some(policy) => (market.total_staked * policy.pool_bps as i128 / 10_000)
+                .clamp(policy.floor, policy.ceiling),
+            None => MIN_DISPUTE_STAKE,
+        }
+    }
+}
+
+// ===== DISPUTE EARLY RESOLUTION =====
+
+/// Vote required to settle a dispute before its voting window ends.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeSupermajorityPolicy {
+    /// Share of the vote stake the leading side needs, in basis points
+    pub supermajority_bps: u32,
+    /// Total vote stake required before early settlement
+    pub min_total_stake: i128,
+}
+
+/// Supermajority policy for early dispute settlement.
+///
+/// Defaults to `DEFAULT_DISPUTE_SUPERMAJORITY_BPS` and
+/// `DEFAULT_DISPUTE_EARLY_RESOLUTION_MIN_STAKE` until the admin sets one.
+pub struct DisputeSupermajorityManager;
+
+impl DisputeSupermajorityManager {
+    /// Get the policy in force
+    pub fn get_policy(env: &Env) -> DisputeSupermajorityPolicy {
+        env.storage()
+            .persistent()
+            .get(&symbol_short!("disp_smaj"))
+            .unwrap_or(DisputeSupermajorityPolicy {
+                supermajority_bps: crate::config::DEFAULT_DISPUTE_SUPERMAJORITY_BPS,
+                min_total_stake: crate::config::DEFAULT_DISPUTE_EARLY_RESOLUTION_MIN_STAKE,
+            })
+    }
+
+    /// Set the policy (admin only); the supermajority must exceed half the stake
+    pub fn set_policy(
+        env: &Env,
+        admin: &Address,
+        policy: &DisputeSupermajorityPolicy,
+    ) -> Result<(), Error> {
+        crate::recovery::RecoveryManager::assert_is_admin(env, admin)?;
+        if policy.supermajority_bps <= 5_000
+            || policy.supermajority_bps > 10_000
+            || policy.min_total_stake <= 0
+        {
+            return Err(Error::InvalidInput);
+        }
+        env.storage()
+            .persistent()
+            .set(&symbol_short!("disp_smaj"), policy);
+        Ok(())
+    }
+
+    /// Whether a dispute vote is overwhelming enough to settle early
+    pub fn is_reached(policy: &DisputeSupermajorityPolicy, voting: &DisputeVoting) -> bool {
+        let total = voting.total_support_stake + voting.total_against_stake;
+        if total <= 0 || total < policy.min_total_stake {
+            return false;
+        }
+        let leading = voting.total_support_stake.max(voting.total_against_stake);
+        leading * 10_000 >= total * policy.supermajority_bps as i128
+    }
+}
+
+// ===== DISPUTE STAKE SLASHING =====
+
+/// Share of losing dispute vote stake forfeited to the winning voters.
+///
+/// Defaults to `DEFAULT_DISPUTE_SLASH_BPS` until the admin sets one.
+pub struct DisputeSlashManager;
+
+impl DisputeSlashManager {
+    /// Get the slash share in force, in basis points
+    pub fn get_slash_bps(env: &Env) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&symbol_short!("disp_slsh"))
+            .unwrap_or(crate::config::DEFAULT_DISPUTE_SLASH_BPS)
+    }
+
+    /// Set the slash share (admin only); at most 10000
+    pub fn set_slash_bps(env: &Env, admin: &Address, slash_bps: u32) -> Result<(), Error> {
+        crate::recovery::RecoveryManager::assert_is_admin(env, admin)?;
+        if slash_bps > 10_000 {
+            return Err(Error::InvalidInput);
+        }
+        env.storage()
+            .persistent()
+            .set(&symbol_short!("disp_slsh"), &slash_bps);
+        Ok(())
+    }
+}
+
+// ===== APPEAL LADDER =====
+
+/// Appeal window and number of rounds of the dispute escalation ladder.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeAppealPolicy {
+    /// Time after a round's voting closes during which it can be appealed
+    pub window_seconds: u64,
+    /// Rounds including the initial dispute; the last is decided by the council
+    pub max_rounds: u32,
+}
+
+/// One appeal of a dispute ruling.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeAppeal {
+    /// Round opened by the appeal
+    pub round: u32,
+    pub appellant: Address,
+    /// Bond posted, returned if the final ruling goes the appellant's way
+    pub bond: i128,
+    /// Ruling appealed against (`true` if the dispute was upheld)
+    pub appealed_ruling: bool,
+    pub timestamp: u64,
+}
+
+/// Escalation state of a market's dispute.
+///
+/// The initial dispute is round 1, bonded by the dispute stakes. Each appeal
+/// opens the next round and doubles the bond.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeLadder {
+    pub market_id: Symbol,
+    pub round: u32,
+    /// Bond of the current round
+    pub bond: i128,
+    pub appeals: Vec<DisputeAppeal>,
+}
+
+/// Multi-round dispute escalation.
+///
+/// Once a round's community voting closes, its stake-weighted ruling can be
+/// appealed within the policy window by posting twice the previous round's
+/// bond. Appeals reopen community voting for another round until the last
+/// round, which the arbitration council decides instead of the admin.
+/// Bonds of appeals the final ruling vindicates are refunded at settlement;
+/// the others are forfeited to the platform fee vault.
+pub struct DisputeAppealManager;
+
+impl DisputeAppealManager {
+    /// Get the policy in force
+    pub fn get_policy(env: &Env) -> DisputeAppealPolicy {
+        env.storage()
+            .persistent()
+            .get(&symbol_short!("appl_pol"))
+            .unwrap_or(DisputeAppealPolicy {
+                window_seconds: crate::config::DEFAULT_DISPUTE_APPEAL_WINDOW_SECONDS,
+                max_rounds: crate::config::DEFAULT_MAX_DISPUTE_ROUNDS,
+            })
+    }
+
+    /// Set the policy (admin only)
+    pub fn set_policy(
+        env: &Env,
+        admin: &Address,
+        policy: &DisputeAppealPolicy,
+    ) -> Result<(), Error> {
+        crate::recovery::RecoveryManager::assert_is_admin(env, admin)?;
+        if policy.window_seconds == 0
+            || policy.max_rounds == 0
+            || policy.max_rounds > crate::config::MAX_DISPUTE_ROUNDS
+        {
+            return Err(Error::InvalidInput);
+        }
+        env.storage()
+            .persistent()
+            .set(&symbol_short!("appl_pol"), policy);
+        Ok(())
+    }
+
+    pub fn get_ladder(env: &Env, market_id: &Symbol) -> Option<DisputeLadder> {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("appl_lad"), market_id.clone()))
+    }
+
+    /// Current round of a market's dispute (1 until appealed)
+    pub fn current_round(env: &Env, market_id: &Symbol) -> u32 {
+        Self::get_ladder(env, market_id).map_or(1, |ladder| ladder.round)
+    }
+
+    /// Whether the market's dispute is in the council-decided final round
+    pub fn is_final_round(env: &Env, market_id: &Symbol) -> bool {
+        Self::current_round(env, market_id) >= Self::get_policy(env).max_rounds
+    }
+
+    /// Whether the closed voting of the current round can still be appealed
+    pub fn is_appealable(env: &Env, market_id: &Symbol) -> bool {
+        let Ok(voting) = DisputeUtils::get_dispute_voting(env, market_id) else {
+            return false;
+        };
+        let now = env.ledger().timestamp();
+        now > voting.voting_end
+            && now
+                <= voting
+                    .voting_end
+                    .saturating_add(Self::get_policy(env).window_seconds)
+    }
+
+    /// Appeal the current round's ruling by posting the next round's bond.
+    pub fn appeal(env: &Env, user: &Address, market_id: &Symbol) -> Result<DisputeLadder, Error> {
+        let market = MarketStateManager::get_market(env, market_id)?;
+        if market.dispute_stakes.is_empty() || market.winning_outcomes.is_some() {
+            return Err(Error::DisputeCondNotMet);
+        }
+        if !Self::is_appealable(env, market_id) {
+            return Err(Error::DisputeCondNotMet);
+        }
+        let policy = Self::get_policy(env);
+        let mut ladder = Self::get_ladder(env, market_id).unwrap_or(DisputeLadder {
+            market_id: market_id.clone(),
+            round: 1,
+            bond: market.total_dispute_stakes(),
+            appeals: Vec::new(env),
+        });
+        if ladder.round >= policy.max_rounds {
+            return Err(Error::DisputeNoEscalate);
+        }
+
+        let bond = ladder
+            .bond
+            .checked_mul(crate::config::DISPUTE_APPEAL_BOND_MULTIPLIER)
+            .ok_or(Error::InvalidInput)?;
+        VotingUtils::transfer_stake(env, user, bond)?;
+
+        let mut voting = DisputeUtils::get_dispute_voting(env, market_id)?;
+        let now = env.ledger().timestamp();
+        ladder.round += 1;
+        ladder.bond = bond;
+        ladder.appeals.push_back(DisputeAppeal {
+            round: ladder.round,
+            appellant: user.clone(),
+            bond,
+            appealed_ruling: DisputeUtils::calculate_stake_weighted_outcome(&voting),
+            timestamp: now,
+        });
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("appl_lad"), market_id.clone()), &ladder);
+
+        // Reopen community voting, unless the council takes the last round
+        let final_round = ladder.round >= policy.max_rounds;
+        if final_round {
+            voting.status = DisputeVotingStatus::Completed;
+        } else {
+            voting.status = DisputeVotingStatus::Active;
+            voting.voting_end = now + DISPUTE_EXTENSION_HOURS as u64 * 3600;
+        }
+        DisputeUtils::store_dispute_voting(env, market_id, &voting)?;
+
+        crate::events::EventEmitter::emit_dispute_appealed(
+            env,
+            market_id,
+            user,
+            ladder.round,
+            bond,
+            final_round,
+        );
+        Ok(ladder)
+    }
+
+    /// Refund vindicated appeal bonds and forfeit the rest once the dispute settles.
+    pub fn settle_bonds(
+        env: &Env,
+        market_id: &Symbol,
+        market: &Market,
+        final_outcome: &String,
+    ) -> Result<(), Error> {
+        let Some(ladder) = Self::get_ladder(env, market_id) else {
+            return Ok(());
+        };
+        let upheld = market.oracle_result.as_ref() != Some(final_outcome);
+        for appeal in ladder.appeals.iter() {
+            if appeal.appealed_ruling != upheld {
+                VotingUtils::transfer_winnings(env, &appeal.appellant, appeal.bond)?;
+            } else {
+                crate::fees::FeeTracker::record_fee_collection(
+                    env,
+                    market_id,
+                    appeal.bond,
+                    &appeal.appellant,
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// ===== DISPUTE EVIDENCE =====
+
+/// Evidence attached to a dispute: a content hash and where to fetch it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeEvidence {
+    pub submitter: Address,
+    /// SHA-256 of the evidence document
+    pub content_hash: BytesN<32>,
+    /// Location of the document, e.g. an IPFS or HTTPS URI
+    pub uri: String,
+    pub timestamp: u64,
+}
+
+/// Evidence attachments for off-chain arbitration.
+///
+/// Disputers of the market and voters on the dispute can attach up to
+/// `MAX_DISPUTE_EVIDENCE` documents while the market is unresolved. Only the
+/// hash and URI are stored; arbitration UIs fetch the document and check it
+/// against the hash.
+pub struct DisputeEvidenceManager;
+
+impl DisputeEvidenceManager {
+    fn key(market_id: &Symbol, dispute_id: &Symbol) -> (Symbol, Symbol, Symbol) {
+        (
+            symbol_short!("disp_evd"),
+            market_id.clone(),
+            dispute_id.clone(),
+        )
+    }
+
+    /// Evidence attached to a dispute, in submission order
+    pub fn get_evidence(
+        env: &Env,
+        market_id: &Symbol,
+        dispute_id: &Symbol,
+    ) -> Vec<DisputeEvidence> {
+        env.storage()
+            .persistent()
+            .get(&Self::key(market_id, dispute_id))
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Attach evidence to a dispute. Returns its index.
+    pub fn submit(
+        env: &Env,
+        submitter: &Address,
+        market_id: &Symbol,
+        dispute_id: &Symbol,
+        content_hash: BytesN<32>,
+        uri: String,
+    ) -> Result<u32, Error> {
+        let market = MarketStateManager::get_market(env, market_id)?;
+        if market.winning_outcomes.is_some() {
+            return Err(Error::MarketResolved);
+        }
+        let voted = env.storage().persistent().has(&(
+            symbol_short!("vote"),
+            dispute_id.clone(),
+            submitter.clone(),
+        ));
+        if !market.dispute_stakes.contains_key(submitter.clone()) && !voted {
+            return Err(Error::Unauthorized);
+        }
+        if uri.is_empty() || uri.len() > crate::config::MAX_EVIDENCE_URI_LENGTH {
+            return Err(Error::InvalidInput);
+        }
+        let mut evidence = Self::get_evidence(env, market_id, dispute_id);
+        if evidence.len() >= crate::config::MAX_DISPUTE_EVIDENCE {
+            return Err(Error::InvalidInput);
+        }
+
+        evidence.push_back(DisputeEvidence {
+            submitter: submitter.clone(),
+            content_hash: content_hash.clone(),
+            uri,
+            timestamp: env.ledger().timestamp(),
+        });
+        env.storage()
+            .persistent()
+            .set(&Self::key(market_id, dispute_id), &evidence);
+
+        crate::events::EventEmitter::emit_dispute_evidence_submitted(
+            env,
+            market_id,
+            dispute_id,
+            submitter,
+            &content_hash,
+        );
+        Ok(evidence.len() - 1)
+    }
+}
+
+// ===== DISPUTE INSURANCE =====
+
+/// Creator-funded deposit that pays dispute bonds for a market's voters.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeInsurance {
+    /// Market creator who funded the deposit
+    pub creator: Address,
+    /// Amount still available to cover disputes
+    pub balance: i128,
+    /// Stake covered for each disputing voter
+    pub covered: Map<Address, i128>,
+}
+
+/// Per-market dispute insurance.
+///
+/// A market creator may opt in by depositing funds that pay the dispute
+/// stake of any voter challenging the resolution, lowering the barrier to
+/// disputing genuine errors. Stakes are covered up to the remaining balance;
+/// the voter pays any shortfall. Unused funds are refundable once the
+/// market is resolved or cancelled.
+pub struct DisputeInsuranceManager;
+
+impl DisputeInsuranceManager {
+    fn storage_key(market_id: &Symbol) -> (Symbol, Symbol) {
+        (symbol_short!("dsp_ins"), market_id.clone())
+    }
+
+    /// Get a market's insurance, if its creator opted in
+    pub fn get_insurance(env: &Env, market_id: &Symbol) -> Option<DisputeInsurance> {
+        env.storage()
+            .persistent()
+            .get(&Self::storage_key(market_id))
+    }
+
+    fn store(env: &Env, market_id: &Symbol, insurance: &DisputeInsurance) {
+        env.storage()
+            .persistent()
+            .set(&Self::storage_key(market_id), insurance);
+    }
+
+    /// Add `amount` to a market's insurance deposit. Returns the new balance.
+    ///
+    /// Only the market creator may fund, and only before resolution.
+    pub fn fund(
+        env: &Env,
+        creator: &Address,
+        market_id: &Symbol,
+        amount: i128,
+    ) -> Result<i128, Error> {
+        let market = MarketStateManager::get_market(env, market_id)?;
+        if market.admin != *creator {
+            return Err(Error::Unauthorized);
+        }
+        if market.winning_outcomes.is_some() || market.state == MarketState::Cancelled {
+            return Err(Error::MarketResolved);
+        }
+        if amount <= 0 {
+            return Err(Error::InvalidInput);
+        }
+
+        VotingUtils::transfer_stake(env, creator, amount)?;
+        let mut insurance = Self::get_insurance(env, market_id).unwrap_or(DisputeInsurance {
+            creator: creator.clone(),
+            balance: 0,
+            covered: Map::new(env),
+        });
+        insurance.balance += amount;
+        Self::store(env, market_id, &insurance);
+
+        crate::events::EventEmitter::emit_dispute_insurance_updated(
+            env,
+            market_id,
+            creator,
+            amount,
+            insurance.balance,
+        );
+        Ok(insurance.balance)
+    }
+
+    /// Cover as much of a voter's dispute stake as the deposit allows.
+    ///
+    /// Returns the amount covered; non-voters are never covered.
+    pub fn cover(
+        env: &Env,
+        market_id: &Symbol,
+        market: &Market,
+        user: &Address,
+        stake: i128,
+    ) -> i128 {
+        let Some(mut insurance) = Self::get_insurance(env, market_id) else {
+            return 0;
+        };
+        if crate::storage::VoteLedger::get_entry(env, market_id, user).is_none() {
+            return 0;
+        }
+        let covered = stake.min(insurance.balance);
+        if covered <= 0 {
+            return 0;
+        }
+
+        insurance.balance -= covered;
+        let total = insurance.covered.get(user.clone()).unwrap_or(0) + covered;
+        insurance.covered.set(user.clone(), total);
+        Self::store(env, market_id, &insurance);
+
+        crate::events::EventEmitter::emit_dispute_insurance_updated(
+            env,
+            market_id,
+            user,
+            -covered,
+            insurance.balance,
+        );
+        covered
+    }
+
+    /// Refund the unused deposit to the creator once the market is settled.
+    pub fn withdraw(env: &Env, creator: &Address, market_id: &Symbol) -> Result<i128, Error> {
+        let mut insurance = Self::get_insurance(env, market_id).ok_or(Error::NothingToClaim)?;
+        if insurance.creator != *creator {
+            return Err(Error::Unauthorized);
+        }
+        let market = MarketStateManager::get_market(env, market_id)?;
+        if market.winning_outcomes.is_none() && market.state != MarketState::Cancelled {
+            return Err(Error::MarketNotResolved);
+        }
+        let refund = insurance.balance;
+        if refund == 0 {
+            return Err(Error::NothingToClaim);
+        }
+
+        insurance.balance = 0;
+        Self::store(env, market_id, &insurance);
+        VotingUtils::transfer_winnings(env, creator, refund)?;
+
+        crate::events::EventEmitter::emit_dispute_insurance_updated(
+            env, market_id, creator, -refund, 0,
+        );
+        Ok(refund)
+    }
+}
+
+// ===== ARBITRATION PANELS =====
+
+/// Per-market dispute arbitration panels.
+///
+/// High-value markets can name a panel of arbitrators when they are created.
+/// Disputes on such a market are decided by a majority of the panel instead
+/// of open community dispute voting, which is closed for the market.
+pub struct ArbitrationPanelManager;
+
+impl ArbitrationPanelManager {
+    /// Attach a panel to a newly created market. Panels cannot be replaced.
+    pub fn set_panel(
+        env: &Env,
+        market_id: &Symbol,
+        creator: &Address,
+        arbitrators: Vec<Address>,
+    ) -> Result<ArbitrationPanel, Error> {
+        if Self::get_panel(env, market_id).is_some() {
+            return Err(Error::InvalidState);
+        }
+        Self::validate_panel(creator, &arbitrators)?;
+
+        let panel = ArbitrationPanel {
+            market_id: market_id.clone(),
+            arbitrators,
+            votes: Map::new(env),
+            created_at: env.ledger().timestamp(),
+        };
+        Self::store_panel(env, &panel);
+
+        crate::events::EventEmitter::emit_arbitration_panel_set(env, market_id, &panel.arbitrators);
+        Ok(panel)
+    }
+
+    /// Validate panel size, uniqueness and independence from the creator.
+    pub fn validate_panel(creator: &Address, arbitrators: &Vec<Address>) -> Result<(), Error> {
+        let size = arbitrators.len();
+        if !(crate::config::MIN_ARBITRATION_PANEL_SIZE..=crate::config::MAX_ARBITRATION_PANEL_SIZE)
+            .contains(&size)
+        {
+            return Err(Error::InvalidInput);
+        }
+        for (i, arbitrator) in arbitrators.iter().enumerate() {
+            if arbitrator == *creator {
+                return Err(Error::InvalidInput);
+            }
+            if arbitrators.first_index_of(&arbitrator) != Some(i as u32) {
+                return Err(Error::InvalidInput);
+            }
+        }
+        Ok(())
+    }
+
+    /// Record an arbitrator's ruling on the market's open dispute.
+    pub fn cast_vote(
+        env: &Env,
+        arbitrator: &Address,
+        market_id: &Symbol,
+        outcome: &String,
+    ) -> Result<(), Error> {
+        let mut panel = Self::get_panel(env, market_id).ok_or(Error::DisputeVoteDenied)?;
+        if !panel.arbitrators.contains(arbitrator) {
+            return Err(Error::DisputeVoteDenied);
+        }
+
+        let market = MarketStateManager::get_market(env, market_id)?;
+        if market.dispute_stakes.is_empty() || market.winning_outcomes.is_some() {
+            return Err(Error::DisputeCondNotMet);
+        }
+        if !market.outcomes.contains(outcome) {
+            return Err(Error::InvalidOutcome);
+        }
+        if panel.votes.contains_key(arbitrator.clone()) {
+            return Err(Error::DisputeAlreadyVoted);
+        }
+
+        panel.votes.set(arbitrator.clone(), outcome.clone());
+        Self::store_panel(env, &panel);
+
+        crate::events::EventEmitter::emit_arbitrator_vote_cast(env, market_id, arbitrator, outcome);
+        Ok(())
+    }
+
+    /// Outcome backed by a strict majority of the whole panel, if any.
+    pub fn panel_decision(panel: &ArbitrationPanel) -> Option<String> {
+        let env = panel.votes.env();
+        let mut tally: Map<String, u32> = Map::new(env);
+        for (_, outcome) in panel.votes.iter() {
+            let count = tally.get(outcome.clone()).unwrap_or(0) + 1;
+            if count * 2 > panel.arbitrators.len() {
+                return Some(outcome);
+            }
+            tally.set(outcome, count);
+        }
+        None
+    }
+
+    pub fn get_panel(env: &Env, market_id: &Symbol) -> Option<ArbitrationPanel> {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("arb_panel"), market_id.clone()))
+    }
+
+    fn store_panel(env: &Env, panel: &ArbitrationPanel) {
+        env.storage().persistent().set(
+            &(symbol_short!("arb_panel"), panel.market_id.clone()),
+            panel,
+        );
+    }
+}
+
+// ===== PANEL DRAWS =====
+
+/// Verifiable random selection of arbitration panels.
+///
+/// The admin commits to `sha256(seed)` along with the candidate pool and
+/// panel size. The seed can only be revealed in a later ledger, so the
+/// candidates are fixed before anyone can see which of them the seed picks.
+/// The panel is a Fisher-Yates shuffle of the candidates driven by
+/// `sha256(seed || round)`, keeping the first `panel_size` entries.
+pub struct PanelDrawManager;
+
+impl PanelDrawManager {
+    /// Commit to the randomness of a market's panel draw (admin only).
+    pub fn commit(
+        env: &Env,
+        admin: &Address,
+        market_id: &Symbol,
+        candidates: Vec<Address>,
+        panel_size: u32,
+        commitment: BytesN<32>,
+    ) -> Result<PanelDraw, Error> {
+        crate::recovery::RecoveryManager::assert_is_admin(env, admin)?;
+        let market = MarketStateManager::get_market(env, market_id)?;
+        if ArbitrationPanelManager::get_panel(env, market_id).is_some()
+            || Self::get_draw(env, market_id).is_some()
+        {
+            return Err(Error::InvalidState);
+        }
+        if !(crate::config::MIN_ARBITRATION_PANEL_SIZE..=crate::config::MAX_ARBITRATION_PANEL_SIZE)
+            .contains(&panel_size)
+            || panel_size > candidates.len()
+        {
+            return Err(Error::InvalidInput);
+        }
+        for (i, candidate) in candidates.iter().enumerate() {
+            if candidate == market.admin || candidates.first_index_of(&candidate) != Some(i as u32)
+            {
+                return Err(Error::InvalidInput);
+            }
+        }
+
+        let draw = PanelDraw {
+            market_id: market_id.clone(),
+            candidates,
+            panel_size,
+            commitment,
+            committed_ledger: env.ledger().sequence(),
+            revealed: false,
+            seed: BytesN::from_array(env, &[0u8; 32]),
+        };
+        Self::store_draw(env, &draw);
+
+        crate::events::EventEmitter::emit_panel_draw_committed(env, &draw);
+        Ok(draw)
+    }
+
+    /// Reveal the committed seed and seat the drawn panel.
+    ///
+    /// Callable by anyone holding the seed, in a ledger after the commitment.
+    pub fn reveal(env: &Env, market_id: &Symbol, seed: BytesN<32>) -> Result<Vec<Address>, Error> {
+        let mut draw = Self::get_draw(env, market_id).ok_or(Error::InvalidState)?;
+        if draw.revealed || env.ledger().sequence() <= draw.committed_ledger {
+            return Err(Error::InvalidState);
+        }
+        let seed_bytes = Bytes::from_array(env, &seed.to_array());
+        if BytesN::from(env.crypto().sha256(&seed_bytes)) != draw.commitment {
+            return Err(Error::InvalidInput);
+        }
+
+        let arbitrators = Self::draw_panel(env, &seed, &draw.candidates, draw.panel_size);
+        let market = MarketStateManager::get_market(env, market_id)?;
+        ArbitrationPanelManager::set_panel(env, market_id, &market.admin, arbitrators.clone())?;
+
+        draw.revealed = true;
+        draw.seed = seed;
+        Self::store_draw(env, &draw);
+        Ok(arbitrators)
+    }
+
+    /// Deterministically pick `panel_size` candidates from `seed`.
+    pub fn draw_panel(
+        env: &Env,
+        seed: &BytesN<32>,
+        candidates: &Vec<Address>,
+        panel_size: u32,
+    ) -> Vec<Address> {
+        let mut pool = candidates.clone();
+        let mut panel = Vec::new(env);
+        let mut round: u32 = 0;
+        while panel.len() < panel_size && !pool.is_empty() {
+            let mut input = Bytes::from_array(env, &seed.to_array());
+            input.extend_from_array(&round.to_be_bytes());
+            let word = env.crypto().sha256(&input).to_array();
+            let value = u64::from_be_bytes([
+                word[0], word[1], word[2], word[3], word[4], word[5], word[6], word[7],
+            ]);
+            let index = (value % pool.len() as u64) as u32;
+            panel.push_back(pool.get(index).unwrap());
+            pool.remove(index);
+            round += 1;
+        }
+        panel
+    }
+
+    pub fn get_draw(env: &Env, market_id: &Symbol) -> Option<PanelDraw> {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("arb_draw"), market_id.clone()))
+    }
+
+    fn store_draw(env: &Env, draw: &PanelDraw) {
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("arb_draw"), draw.market_id.clone()), draw);
+    }
+}
+
 // ===== DISPUTE VALIDATOR =====
 
 /// Validates dispute-related operations
@@ -1953,10 +3084,7 @@ impl DisputeValidator {
             return Err(Error::AlreadyDisputed);
         }
 
-        // Check if user has voted (optional requirement)
-        if !market.votes.contains_key(user.clone()) {
-            // Allow disputes even from non-voters, but could be made optional
-        }
+        // Disputes are open to non-voters as well, so voting is not checked here
 
         Ok(())
     }
@@ -2153,7 +3281,6 @@ impl DisputeUtils {
             .as_ref()
             .ok_or(Error::OracleUnavailable)?;
 
-        // If there are significant disputes, consider community consensus more heavily
         let dispute_impact = DisputeAnalytics::calculate_dispute_impact(market);
 
         if dispute_impact > 30 {
@@ -2179,9 +3306,15 @@ impl DisputeUtils {
         DisputeValidator::validate_resolution_parameters(market, &final_outcome)?;
 
         // Set the winning outcome(s) - convert single outcome to vector
-        let mut winning_outcomes = Vec::new(market.votes.env());
+        let mut winning_outcomes = Vec::new(market.outcomes.env());
         winning_outcomes.push_back(final_outcome);
         market.winning_outcomes = Some(winning_outcomes);
+        market.precompute_outcome_totals();
+
+        // The dispute is settled, so the market leaves the Disputed state
+        if market.state == MarketState::Disputed {
+            market.state = MarketState::Resolved;
+        }
 
         Ok(())
     }
@@ -2193,9 +3326,15 @@ impl DisputeUtils {
         market_id: Symbol,
     ) -> Vec<Dispute> {
         let mut disputes = Vec::new(env);
+        let opened = Self::get_opened_disputes(env, &market_id);
 
         for (user, stake) in market.dispute_stakes.iter() {
             if stake > 0 {
+                let required_stake = opened
+                    .iter()
+                    .find(|d| d.user == user)
+                    .map(|d| d.required_stake)
+                    .unwrap_or(MIN_DISPUTE_STAKE);
                 let dispute = Dispute {
                     user: user.clone(),
                     market_id: market_id.clone(),
@@ -2203,6 +3342,7 @@ impl DisputeUtils {
                     timestamp: env.ledger().timestamp(),
                     reason: None,
                     status: DisputeStatus::Active,
+                    required_stake,
                 };
                 disputes.push_back(dispute);
             }
@@ -2255,6 +3395,16 @@ impl DisputeUtils {
         // Store updated voting data
         Self::store_dispute_voting(env, dispute_id, &voting_data)?;
 
+        // Track the voter so individual votes can be enumerated
+        let voters_key = (symbol_short!("dispute_l"), dispute_id.clone());
+        let mut voters: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&voters_key)
+            .unwrap_or(Vec::new(env));
+        voters.push_back(vote.user.clone());
+        env.storage().persistent().set(&voters_key, &voters);
+
         // Store the vote
         Self::store_dispute_vote(env, dispute_id, &vote)?;
 
@@ -2270,6 +3420,28 @@ impl DisputeUtils {
             .ok_or(Error::InvalidInput)
     }
 
+    /// Open voting on a market's dispute for the dispute extension period.
+    ///
+    /// Later disputes on the same market join the voting already open.
+    pub fn open_dispute_voting(env: &Env, dispute_id: &Symbol) -> Result<(), Error> {
+        if Self::get_dispute_voting(env, dispute_id).is_ok() {
+            return Ok(());
+        }
+        let now = env.ledger().timestamp();
+        let voting = DisputeVoting {
+            dispute_id: dispute_id.clone(),
+            voting_start: now,
+            voting_end: now + DISPUTE_EXTENSION_HOURS as u64 * 3600,
+            total_votes: 0,
+            support_votes: 0,
+            against_votes: 0,
+            total_support_stake: 0,
+            total_against_stake: 0,
+            status: DisputeVotingStatus::Active,
+        };
+        Self::store_dispute_voting(env, dispute_id, &voting)
+    }
+
     /// Store dispute voting data
     pub fn store_dispute_voting(
         env: &Env,
@@ -2292,32 +3464,60 @@ impl DisputeUtils {
         Ok(())
     }
 
-    /// Get dispute votes
+    /// Get dispute votes in the order they were cast
     pub fn get_dispute_votes(env: &Env, dispute_id: &Symbol) -> Result<Vec<DisputeVote>, Error> {
-        // This is a simplified implementation - in a real system you'd need to track all votes
-        let votes = Vec::new(env);
+        let mut votes = Vec::new(env);
 
-        // Get the voting data to access stored votes
+        // Ensure the dispute has voting data
         let _voting_data = Self::get_dispute_voting(env, dispute_id)?;
 
-        // In a real implementation, you would iterate through stored vote keys
-        // For now, return empty vector as this would require tracking vote keys separately
+        let voters: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("dispute_l"), dispute_id.clone()))
+            .unwrap_or(Vec::new(env));
+        for voter in voters.iter() {
+            let key = (symbol_short!("vote"), dispute_id.clone(), voter);
+            if let Some(vote) = env.storage().persistent().get::<_, DisputeVote>(&key) {
+                votes.push_back(vote);
+            }
+        }
+
         Ok(votes)
     }
 
+    /// Best-backed outcome other than `excluded`, if any outcome has stake
+    pub fn strongest_alternative_outcome(market: &Market, excluded: &String) -> Option<String> {
+        let mut best: Option<(String, i128)> = None;
+        for outcome in market.outcomes.iter() {
+            if outcome == *excluded {
+                continue;
+            }
+            let stake = market.outcome_total(&outcome);
+            if stake > 0 && best.as_ref().is_none_or(|(_, s)| stake > *s) {
+                best = Some((outcome, stake));
+            }
+        }
+        best.map(|(outcome, _)| outcome)
+    }
+
     /// Calculate stake-weighted outcome
     pub fn calculate_stake_weighted_outcome(voting_data: &DisputeVoting) -> bool {
         voting_data.total_support_stake > voting_data.total_against_stake
     }
 
-    /// Distribute fees based on outcome
+    /// Settle dispute vote stakes based on outcome.
+    ///
+    /// Each losing voter forfeits the slash share of their stake and is
+    /// refunded the rest. The forfeited total is shared among winning voters
+    /// pro rata to their stake, on top of their own stake; rounding dust goes
+    /// to the last winner. With no winning stake nothing is slashed.
     pub fn distribute_fees_based_on_outcome(
         env: &Env,
         dispute_id: &Symbol,
         voting_data: &DisputeVoting,
         outcome: bool,
     ) -> Result<DisputeFeeDistribution, Error> {
-        let total_fees = voting_data.total_support_stake + voting_data.total_against_stake;
         let winner_stake = if outcome {
             voting_data.total_support_stake
         } else {
@@ -2328,14 +3528,55 @@ impl DisputeUtils {
         } else {
             voting_data.total_support_stake
         };
+        let slash_bps = if winner_stake > 0 {
+            DisputeSlashManager::get_slash_bps(env)
+        } else {
+            0
+        };
+
+        let votes = Self::get_dispute_votes(env, dispute_id)?;
+        let mut winners: Vec<DisputeVote> = Vec::new(env);
+        let mut slashed = 0i128;
+        let mut refunded = 0i128;
+        for vote in votes.iter() {
+            if vote.vote == outcome {
+                winners.push_back(vote);
+                continue;
+            }
+            let slash = vote.stake * slash_bps as i128 / 10_000;
+            let refund = vote.stake - slash;
+            if refund > 0 {
+                VotingUtils::transfer_winnings(env, &vote.user, refund)?;
+            }
+            slashed += slash;
+            refunded += refund;
+        }
+
+        let mut winner_addresses = Vec::new(env);
+        let mut rewarded = 0i128;
+        let mut shared = 0i128;
+        for (i, vote) in winners.iter().enumerate() {
+            let share = if i as u32 + 1 == winners.len() {
+                slashed - shared
+            } else {
+                slashed * vote.stake / winner_stake
+            };
+            shared += share;
+            let payout = vote.stake + share;
+            if payout > 0 {
+                VotingUtils::transfer_winnings(env, &vote.user, payout)?;
+            }
+            rewarded += payout;
+            winner_addresses.push_back(vote.user);
+        }
 
         // Create fee distribution record
         let fee_distribution = DisputeFeeDistribution {
             dispute_id: dispute_id.clone(),
-            total_fees,
+            total_fees: slashed,
             winner_stake,
             loser_stake,
-            winner_addresses: Vec::new(env), // Would be populated with actual winner addresses
+            winner_addresses,
             distribution_timestamp: env.ledger().timestamp(),
             fees_distributed: true,
         };
@@ -2343,6 +3584,17 @@ impl DisputeUtils {
         // Store fee distribution
         Self::store_dispute_fee_distribution(env, dispute_id, &fee_distribution)?;
 
+        crate::events::EventEmitter::emit_dispute_settled(
+            env,
+            dispute_id,
+            outcome,
+            slash_bps,
+            slashed,
+            refunded,
+            rewarded,
+            winners.len(),
+        );
+
         Ok(fee_distribution)
     }
 
@@ -2395,6 +3647,51 @@ impl DisputeUtils {
         env.storage().persistent().get(&key)
     }
 
+    /// Append an opened dispute to the market's dispute history
+    pub fn record_dispute_opened(env: &Env, dispute: &Dispute) {
+        let key = (symbol_short!("dispute_o"), dispute.market_id.clone());
+        let mut disputes = Self::get_opened_disputes(env, &dispute.market_id);
+        disputes.push_back(dispute.clone());
+        env.storage().persistent().set(&key, &disputes);
+    }
+
+    /// Get the disputes opened on a market, in opening order
+    pub fn get_opened_disputes(env: &Env, market_id: &Symbol) -> Vec<Dispute> {
+        let key = (symbol_short!("dispute_o"), market_id.clone());
+        env.storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Store dispute resolution record
+    pub fn store_dispute_resolution(env: &Env, market_id: &Symbol, resolution: &DisputeResolution) {
+        let key = (symbol_short!("dispute_r"), market_id.clone());
+        env.storage().persistent().set(&key, resolution);
+    }
+
+    /// Get dispute resolution record
+    pub fn get_dispute_resolution(env: &Env, market_id: &Symbol) -> Option<DisputeResolution> {
+        let key = (symbol_short!("dispute_r"), market_id.clone());
+        env.storage().persistent().get(&key)
+    }
+
+    /// Insert a timeline entry after all entries with an equal or earlier timestamp
+    pub fn insert_chronologically(
+        timeline: &mut Vec<DisputeTimelineEntry>,
+        entry: DisputeTimelineEntry,
+    ) {
+        let mut index = timeline.len();
+        while index > 0 {
+            let previous = timeline.get(index - 1).unwrap();
+            if previous.timestamp <= entry.timestamp {
+                break;
+            }
+            index -= 1;
+        }
+        timeline.insert(index, entry);
+    }
+
     /// Emit dispute vote event
 
     pub fn emit_dispute_vote_event(
@@ -2558,22 +3855,15 @@ impl DisputeAnalytics {
 
     /// Calculate community consensus
     pub fn calculate_community_consensus(env: &Env, market: &Market) -> CommunityConsensus {
-        let mut outcome_totals = Map::new(env);
         let mut total_votes = 0;
 
-        // Calculate total stakes for each outcome
-        for (user, outcome) in market.votes.iter() {
-            let stake = market.stakes.get(user).unwrap_or(0);
-            let current_total = outcome_totals.get(outcome.clone()).unwrap_or(0);
-            outcome_totals.set(outcome, current_total + stake);
-            total_votes += stake;
-        }
-
         // Find the outcome with highest stake
         let mut winning_outcome = String::from_str(env, "");
         let mut max_stake = 0;
 
-        for (outcome, stake) in outcome_totals.iter() {
+        for outcome in market.outcome_totals.keys().iter() {
+            let stake = market.consensus_weight(&outcome);
+            total_votes += stake;
             if stake > max_stake {
                 max_stake = stake;
                 winning_outcome = outcome;
@@ -2610,7 +3900,7 @@ impl DisputeAnalytics {
 
     /// Calculate dispute participation rate
     pub fn calculate_dispute_participation_rate(market: &Market) -> f64 {
-        let total_voters = market.votes.len();
+        let total_voters = market.voter_count;
         let total_disputers = market.dispute_stakes.len();
 
         if total_voters == 0 {
@@ -2687,6 +3977,7 @@ pub mod testing {
             timestamp: env.ledger().timestamp(),
             reason: Some(String::from_str(env, "Test dispute")),
             status: DisputeStatus::Active,
+            required_stake: MIN_DISPUTE_STAKE,
         }
     }
 
@@ -2982,4 +4273,107 @@ mod tests {
         assert_eq!(analytics.is_expired, false);
         assert_eq!(analytics.status, DisputeTimeoutStatus::Active);
     }
+
+    #[test]
+    fn test_dispute_timeline_is_chronological() {
+        let env = Env::default();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let market_id = Symbol::new(&env, "market");
+        let disputer = Address::generate(&env);
+        let voter = Address::generate(&env);
+        let escalator = Address::generate(&env);
+
+        env.as_contract(&contract_id, || {
+            let market = create_test_market(&env, 50);
+            MarketStateManager::update_market(&env, &market_id, &market);
+
+            DisputeUtils::record_dispute_opened(
+                &env,
+                &testing::create_test_dispute(&env, disputer.clone(), market_id.clone(), 1000),
+            );
+            DisputeUtils::store_dispute_voting(
+                &env,
+                &market_id,
+                &DisputeVoting {
+                    dispute_id: market_id.clone(),
+                    voting_start: 0,
+                    voting_end: 1000,
+                    total_votes: 0,
+                    support_votes: 0,
+                    against_votes: 0,
+                    total_support_stake: 0,
+                    total_against_stake: 0,
+                    status: DisputeVotingStatus::Active,
+                },
+            )
+            .unwrap();
+            DisputeUtils::add_vote_to_dispute(
+                &env,
+                &market_id,
+                DisputeVote {
+                    user: voter.clone(),
+                    dispute_id: market_id.clone(),
+                    vote: false,
+                    stake: 500,
+                    timestamp: 300,
+                    reason: None,
+                },
+            )
+            .unwrap();
+            // Stored after the vote but happened before it
+            DisputeUtils::store_dispute_escalation(
+                &env,
+                &market_id,
+                &DisputeEscalation {
+                    dispute_id: market_id.clone(),
+                    escalated_by: escalator.clone(),
+                    escalation_reason: String::from_str(&env, "oracle feed stale"),
+                    escalation_timestamp: 200,
+                    escalation_level: 1,
+                    requires_admin_review: true,
+                },
+            )
+            .unwrap();
+            DisputeUtils::store_dispute_resolution(
+                &env,
+                &market_id,
+                &DisputeResolution {
+                    market_id: market_id.clone(),
+                    final_outcome: String::from_str(&env, "no"),
+                    oracle_weight: 70,
+                    community_weight: 30,
+                    dispute_impact: 10,
+                    resolution_timestamp: 400,
+                },
+            );
+
+            let timeline = DisputeManager::get_dispute_timeline(&env, market_id.clone()).unwrap();
+            assert_eq!(timeline.len(), 4);
+
+            let opened = timeline.get(0).unwrap();
+            assert_eq!(opened.kind, DisputeTimelineEventKind::Opened);
+            assert_eq!(opened.actor, Some(disputer.clone()));
+            assert_eq!(opened.stake, 1000);
+
+            let escalated = timeline.get(1).unwrap();
+            assert_eq!(escalated.kind, DisputeTimelineEventKind::Escalated);
+            assert_eq!(escalated.actor, Some(escalator.clone()));
+
+            let voted = timeline.get(2).unwrap();
+            assert_eq!(voted.kind, DisputeTimelineEventKind::VotedAgainst);
+            assert_eq!(voted.actor, Some(voter.clone()));
+            assert_eq!(voted.stake, 500);
+
+            let resolved = timeline.get(3).unwrap();
+            assert_eq!(resolved.kind, DisputeTimelineEventKind::Resolved);
+            assert_eq!(resolved.detail, Some(String::from_str(&env, "no")));
+
+            assert_eq!(
+                DisputeUtils::get_dispute_votes(&env, &market_id)
+                    .unwrap()
+                    .len(),
+                1
+            );
+        });
+    }
 }
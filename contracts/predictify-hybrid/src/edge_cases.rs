@@ -1,6 +1,6 @@
 #![allow(dead_code)]
 
-use soroban_sdk::{contracttype, vec, Env, Map, String, Symbol, Vec};
+use soroban_sdk::{contracttype, symbol_short, vec, Env, Map, String, Symbol, Vec};
 
 use crate::errors::Error;
 use crate::markets::MarketStateManager;
@@ -45,6 +45,23 @@ pub enum EdgeCaseScenario {
     DisputeTimeout,
     /// Insufficient participation for reliable resolution
     LowParticipation,
+    /// Every stake backs the same outcome, so there is no counterparty
+    OneSidedMarket,
+}
+
+/// Which degenerate markets are cancelled for refunds instead of resolved.
+///
+/// Markets that end with no stake, a single voter, or all stake on one
+/// outcome have no real counterparty, so resolving them only moves the
+/// platform fee. With a flag set, resolution of a matching market turns it
+/// `Cancelled` instead and participants take their stake back with
+/// `claim_refund`. All flags are off until an admin opts in.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct DegenerateMarketPolicy {
+    pub cancel_zero_stake: bool,
+    pub cancel_single_voter: bool,
+    pub cancel_one_sided: bool,
 }
 
 /// Comprehensive data structure for partial resolution scenarios.
@@ -187,6 +204,91 @@ impl EdgeCaseHandler {
         }
     }
 
+    /// Degenerate-market policy in force (everything resolves normally by default)
+    pub fn get_degenerate_policy(env: &Env) -> DegenerateMarketPolicy {
+        env.storage()
+            .persistent()
+            .get(&symbol_short!("degen_pol"))
+            .unwrap_or(DegenerateMarketPolicy {
+                cancel_zero_stake: false,
+                cancel_single_voter: false,
+                cancel_one_sided: false,
+            })
+    }
+
+    pub fn set_degenerate_policy(env: &Env, policy: &DegenerateMarketPolicy) {
+        env.storage()
+            .persistent()
+            .set(&symbol_short!("degen_pol"), policy);
+    }
+
+    /// Degenerate participation pattern of a market the policy cancels, if any
+    pub fn degenerate_scenario(env: &Env, market: &Market) -> Option<EdgeCaseScenario> {
+        let policy = Self::get_degenerate_policy(env);
+        let backed_outcomes = market
+            .outcome_totals
+            .values()
+            .iter()
+            .filter(|total| *total > 0)
+            .count();
+        if market.total_staked == 0 {
+            policy
+                .cancel_zero_stake
+                .then_some(EdgeCaseScenario::ZeroStakes)
+        } else if market.voter_count == 1 {
+            policy
+                .cancel_single_voter
+                .then_some(EdgeCaseScenario::LowParticipation)
+        } else if backed_outcomes <= 1 {
+            policy
+                .cancel_one_sided
+                .then_some(EdgeCaseScenario::OneSidedMarket)
+        } else {
+            None
+        }
+    }
+
+    /// Cancel an ended, unresolved market instead of resolving it when its
+    /// participation is degenerate under the policy.
+    ///
+    /// Called by the resolution entry points before any winner is picked.
+    /// Returns whether the market was cancelled.
+    pub fn cancel_if_degenerate(env: &Env, market_id: &Symbol) -> Result<bool, Error> {
+        let mut market = MarketStateManager::get_market(env, market_id)?;
+        // AMM markets hold no parimutuel stake, so their totals say nothing
+        // about participation
+        if !market.has_ended(env)
+            || market.winning_outcomes.is_some()
+            || !matches!(market.state, MarketState::Active | MarketState::Ended)
+            || crate::amm::AmmManager::is_amm(env, market_id)
+        {
+            return Ok(false);
+        }
+        let Some(scenario) = Self::degenerate_scenario(env, &market) else {
+            return Ok(false);
+        };
+
+        let old_state = market.state;
+        market.state = MarketState::Cancelled;
+        MarketStateManager::update_market(env, market_id, &market);
+        crate::statistics::StatisticsManager::record_creator_market_invalidated(env, &market.admin);
+        crate::storage::CreatorLimitsManager::decrement_active_events(env, &market.admin);
+
+        let reason = match scenario {
+            EdgeCaseScenario::ZeroStakes => "Cancelled: no stake placed",
+            EdgeCaseScenario::LowParticipation => "Cancelled: single voter",
+            _ => "Cancelled: all stake on one outcome",
+        };
+        crate::events::EventEmitter::emit_state_change_event(
+            env,
+            market_id,
+            &old_state,
+            &MarketState::Cancelled,
+            &String::from_str(env, reason),
+        );
+        Ok(true)
+    }
+
     /// Implement tie-breaking mechanism for outcomes with equal stakes.
     ///
     /// This function resolves ties when multiple outcomes have identical
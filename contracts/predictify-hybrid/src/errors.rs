@@ -120,6 +120,26 @@ pub enum Error {
     CBOpen = 503,
 }
 
+/// Error codes that no longer fit in `Error`.
+///
+/// The contract spec caps an error enum at 50 variants and `Error` is full,
+/// so newer errors are declared here and raised with `panic_with_error!`.
+/// Codes continue `Error`'s numbering within each category.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ExtendedError {
+    // ===== USER OPERATION ERRORS =====
+    /// Payout exceeds the market's maximum payout multiple
+    PayoutGuardTripped = 113,
+    /// Position exceeds the per-user stake limit
+    StakeAboveUserLimit = 114,
+    /// Position exceeds the per-user share of the pool
+    PoolShareLimitExceeded = 115,
+    /// User has used up the action's rate limit for the current window
+    RateLimitExceeded = 116,
+}
+
 // ===== ERROR CATEGORIZATION AND RECOVERY SYSTEM =====
 
 /// Error severity levels for categorization and prioritization
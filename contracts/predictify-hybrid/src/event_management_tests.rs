@@ -533,6 +533,60 @@ fn test_update_event_outcomes_unauthorized() {
     assert_eq!(result, Err(Ok(Error::Unauthorized)));
 }
 
+#[test]
+fn test_update_event_outcomes_rejects_duplicates() {
+    let setup = TestSetup::new();
+    let client = PredictifyHybridClient::new(&setup.env, &setup.contract_id);
+
+    let initial_outcomes = vec![
+        &setup.env,
+        String::from_str(&setup.env, "Yes"),
+        String::from_str(&setup.env, "No"),
+    ];
+
+    let market_id = setup.create_market("Test question?", initial_outcomes, 30);
+
+    let new_outcomes = vec![
+        &setup.env,
+        String::from_str(&setup.env, "Yes"),
+        String::from_str(&setup.env, "No"),
+        String::from_str(&setup.env, "Yes"),
+    ];
+
+    let result = client.try_update_event_outcomes(&setup.admin, &market_id, &new_outcomes);
+
+    assert_eq!(result, Err(Ok(Error::InvalidOutcomes)));
+}
+
+#[test]
+fn test_update_event_outcomes_after_end_time() {
+    let setup = TestSetup::new();
+    let client = PredictifyHybridClient::new(&setup.env, &setup.contract_id);
+
+    let initial_outcomes = vec![
+        &setup.env,
+        String::from_str(&setup.env, "Yes"),
+        String::from_str(&setup.env, "No"),
+    ];
+
+    let market_id = setup.create_market("Test question?", initial_outcomes, 1);
+    let market = client.get_market(&market_id).unwrap();
+    setup.env.ledger().with_mut(|li| {
+        li.timestamp = market.end_time;
+    });
+
+    let new_outcomes = vec![
+        &setup.env,
+        String::from_str(&setup.env, "Yes"),
+        String::from_str(&setup.env, "No"),
+        String::from_str(&setup.env, "Maybe"),
+    ];
+
+    let result = client.try_update_event_outcomes(&setup.admin, &market_id, &new_outcomes);
+
+    assert_eq!(result, Err(Ok(Error::MarketClosed)));
+}
+
 #[test]
 fn test_update_event_outcomes_resolved_market() {
     let setup = TestSetup::new();
@@ -1,11 +1,17 @@
 extern crate alloc;
 
 // use alloc::string::ToString; // Removed to fix Display/ToString trait errors
-use soroban_sdk::{contracttype, symbol_short, vec, Address, Env, Map, String, Symbol, Vec};
+use soroban_sdk::{
+    contracttype, symbol_short, vec, Address, BytesN, Env, Map, String, Symbol, Vec,
+};
 
 use crate::config::Environment;
 use crate::errors::Error;
-use crate::types::OracleProvider;
+use crate::keepers::KeeperRole;
+use crate::types::{
+    DailySettlementStats, DuplicateMarketPolicy, MarketTier, OracleProvider, ZeroWinnerPolicy,
+    ZeroWinnerSettlement,
+};
 
 // Define AdminRole locally since it's not available in the crate root
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -198,6 +204,115 @@ pub struct VoteCastEvent {
     pub timestamp: u64,
 }
 
+/// Event emitted when a voter switches outcome before the market ends.
+///
+/// The penalty is deducted from the voter's stake and stays in the market
+/// pool, so `stake_moved` is the stake now backing `new_outcome`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoteChangedEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Voter address
+    pub voter: Address,
+    /// Previously voted outcome
+    pub old_outcome: String,
+    /// Newly voted outcome
+    pub new_outcome: String,
+    /// Stake moved to the new outcome after the penalty
+    pub stake_moved: i128,
+    /// Penalty retained in the pool
+    pub penalty: i128,
+    /// Change timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when a voter adds stake to an existing vote.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StakeIncreasedEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Voter address
+    pub voter: Address,
+    /// Outcome the stake backs
+    pub outcome: String,
+    /// Stake added
+    pub added: i128,
+    /// Voter's stake after the top-up
+    pub new_stake: i128,
+    /// Top-up timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when a voter commits a sealed vote.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoteCommittedEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Voter address
+    pub voter: Address,
+    /// Stake escrowed with the commitment
+    pub stake: i128,
+    /// Commit timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when a voter reveals a sealed vote.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoteRevealedEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Voter address
+    pub voter: Address,
+    /// Outcome revealed
+    pub outcome: String,
+    /// Stake backing the outcome
+    pub stake: i128,
+    /// Reveal timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when a voter converts stake into position token shares.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PositionTokenizedEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Voter address
+    pub voter: Address,
+    /// Outcome the shares back
+    pub outcome: String,
+    /// Stake converted, equal to the shares minted
+    pub amount: i128,
+    /// Share token of the outcome
+    pub token: Address,
+    /// Tokenization timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when a voter sells part or all of a position back to the pool.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PositionExitedEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Voter address
+    pub voter: Address,
+    /// Outcome the stake backed
+    pub outcome: String,
+    /// Stake sold back
+    pub amount: i128,
+    /// Amount paid out to the voter
+    pub proceeds: i128,
+    /// Voter's stake after the exit
+    pub remaining: i128,
+    /// Exit timestamp
+    pub timestamp: u64,
+}
+
 /// Event emitted when a user places a bet on a prediction market event.
 ///
 /// This event captures all details of bet placement activity, including bettor identity,
@@ -1280,6 +1395,88 @@ pub struct FallbackUsedEvent {
     pub timestamp: u64,
 }
 
+/// Event emitted when aggregated oracle feeds are attached to a market.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MultiOracleConfigSetEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Admin who set the feeds
+    pub admin: Address,
+    /// Number of feeds aggregated
+    pub source_count: u32,
+    /// Outlier threshold in basis points
+    pub max_deviation_bps: u32,
+    /// Event timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when a composite (AND/OR) oracle condition is attached to a market.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CompositeConditionSetEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Admin who set the condition
+    pub admin: Address,
+    /// How the terms are combined
+    pub operator: crate::types::ConditionOperator,
+    /// Number of feed conditions combined
+    pub term_count: u32,
+    /// Event timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when price buckets are attached to a categorical market.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PriceBucketsSetEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Admin who set the buckets
+    pub admin: Address,
+    /// Ascending price boundaries between the outcomes
+    pub boundaries: Vec<i128>,
+    /// Event timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when a reporter pushes a result for a push oracle market.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleResultPushedEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Reporter who pushed the result
+    pub reporter: Address,
+    /// Reported outcome
+    pub result: String,
+    /// Number of reports agreeing on this outcome so far
+    pub agreeing_reports: u32,
+    /// Event timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when a cross-rate condition is attached to a market.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CrossRateConditionSetEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Admin who set the condition
+    pub admin: Address,
+    /// Numerator feed of the ratio
+    pub base_feed_id: String,
+    /// Denominator feed of the ratio
+    pub quote_feed_id: String,
+    /// Ratio threshold (scaled by 10^8)
+    pub threshold: i128,
+    /// Comparison operator
+    pub comparison: String,
+    /// Event timestamp
+    pub timestamp: u64,
+}
+
 /// Event emitted when a market resolution timeout is reached.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -1457,6 +1654,134 @@ pub struct WinningsClaimedEvent {
     pub timestamp: u64,
 }
 
+/// Event emitted when a losing voter claims their share of a market's fee rebate.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LoserRebateClaimedEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Losing voter receiving the rebate
+    pub user: Address,
+    /// Rebate credited
+    pub amount: i128,
+    /// Event timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when a participant takes back their stake from a cancelled market.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundClaimedEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Participant refunded
+    pub user: Address,
+    /// Stake returned
+    pub amount: i128,
+    /// Event timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when a dispute ruling is appealed into a new round.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeAppealedEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// User who posted the appeal bond
+    pub appellant: Address,
+    /// Round opened by the appeal
+    pub round: u32,
+    /// Bond posted
+    pub bond: i128,
+    /// Whether the new round is decided by the arbitration council
+    pub final_round: bool,
+    /// Event timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when a governance proposal changes the arbitration council.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CouncilMemberChangedEvent {
+    /// Proposal that made the change
+    pub proposal_id: Symbol,
+    /// Member added or removed
+    pub member: Address,
+    /// Whether the member was added
+    pub added: bool,
+    /// Event timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when evidence is attached to a dispute.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeEvidenceSubmittedEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Dispute ID
+    pub dispute_id: Symbol,
+    /// Disputer or dispute voter who attached it
+    pub submitter: Address,
+    /// SHA-256 of the evidence document
+    pub content_hash: BytesN<32>,
+    /// Event timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when the vote stakes of a dispute are settled.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeSettledEvent {
+    /// Dispute ID
+    pub dispute_id: Symbol,
+    /// Whether the dispute was upheld
+    pub upheld: bool,
+    /// Share of losing stake forfeited, in basis points
+    pub slash_bps: u32,
+    /// Losing stake forfeited to the winners
+    pub slashed: i128,
+    /// Losing stake refunded to the losers
+    pub refunded: i128,
+    /// Stake plus rewards paid to the winners
+    pub rewarded: i128,
+    /// Number of winning voters paid
+    pub winners: u32,
+    /// Event timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when a market's metadata is set or changed
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarketMetadataUpdatedEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Market creator or admin who set the metadata
+    pub updated_by: Address,
+    /// Update timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when a trader buys or sells shares on an AMM market
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AmmTradeEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Trader address
+    pub trader: Address,
+    /// Outcome traded
+    pub outcome: String,
+    /// Shares bought (positive) or sold (negative)
+    pub shares: i128,
+    /// Amount paid (positive) or received (negative)
+    pub amount: i128,
+    /// Trade timestamp
+    pub timestamp: u64,
+}
+
 /// Event emitted when a user claims winnings from multiple resolved markets in a batch operation.
 ///
 /// Provides information about batch winnings claims including each market claim
@@ -1531,364 +1856,1486 @@ pub struct UnclaimedWinningsSweptEvent {
     pub timestamp: u64,
 }
 
-/// Contract upgraded event - emitted when contract Wasm is upgraded
+/// Event emitted when a zero-winner market pool is settled.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct ContractUpgradedEvent {
-    /// Previous Wasm hash
-    pub old_wasm_hash: soroban_sdk::BytesN<32>,
-    /// New Wasm hash
-    pub new_wasm_hash: soroban_sdk::BytesN<32>,
-    /// Upgrade ID
-    pub upgrade_id: Symbol,
-    /// Upgrade timestamp
+pub struct ZeroWinnerSettledEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Caller performing the settlement
+    pub caller: Address,
+    /// Policy applied
+    pub policy: ZeroWinnerPolicy,
+    /// Total refunded to participants
+    pub refunded_total: i128,
+    /// Platform fee retained from refunds
+    pub fee_total: i128,
+    /// Total sent to the treasury
+    pub treasury_total: i128,
+    /// Event timestamp
     pub timestamp: u64,
 }
 
-/// Event emitted when market deadline is extended
-///
-/// This event tracks market deadline extensions, providing transparency
-/// for extension requests and their impact on market timeline.
-///
-/// # Event Data
-///
-/// - Market identifier
-/// - Previous end time
-/// - New end time after extension
-/// - Additional days added
-/// - Admin who performed the extension
-/// - Reason for extension
-/// - Extension fee (if applicable)
-/// - Timestamp of extension
+/// Event emitted when the zero-winner policy is updated.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct MarketDeadlineExtendedEvent {
-    /// Market ID
-    pub market_id: Symbol,
-    /// Previous end time
-    pub old_end_time: u64,
-    /// New end time
-    pub new_end_time: u64,
-    /// Additional days
-    pub additional_days: u32,
-    /// Admin who extended
+pub struct ZeroWinnerPolicyUpdatedEvent {
+    /// Admin who updated the policy
     pub admin: Address,
-    /// Reason for extension
-    pub reason: String,
-    /// Extension fee
-    pub fee: i128,
-    /// Extension timestamp
+    /// New policy
+    pub policy: ZeroWinnerPolicy,
+    /// Event timestamp
     pub timestamp: u64,
 }
 
-/// Event emitted when market description is updated
-///
-/// This event tracks market description updates, providing transparency
-/// for changes to market questions and parameters.
-///
-/// # Event Data
-///
-/// - Market identifier
-/// - Previous description
-/// - New description
-/// - Admin who performed the update
-/// - Update timestamp
+/// Event emitted when the duplicate market policy is updated.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct MarketDescriptionUpdatedEvent {
-    /// Market ID
-    pub market_id: Symbol,
-    /// Old description
-    pub old_description: String,
-    /// New description
-    pub new_description: String,
-    /// Admin who updated
+pub struct DuplicateMarketPolicyUpdatedEvent {
+    /// Admin who updated the policy
     pub admin: Address,
-    /// Update timestamp
+    /// New policy
+    pub policy: DuplicateMarketPolicy,
+    /// Event timestamp
     pub timestamp: u64,
 }
 
-/// Event emitted when market outcomes are updated
-///
-/// This event tracks market outcome updates, providing transparency
-/// for changes to available market outcomes.
-///
-/// # Event Data
-///
-/// - Market identifier
-/// - Previous outcomes
-/// - New outcomes
-/// - Admin who performed the update
-/// - Update timestamp
+/// Event emitted when a market is created as a near-duplicate of an active market.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct MarketOutcomesUpdatedEvent {
-    /// Market ID
+pub struct DuplicateMarketDetectedEvent {
+    /// Newly created market
     pub market_id: Symbol,
-    /// Old outcomes
-    pub old_outcomes: Vec<String>,
-    /// New outcomes
-    pub new_outcomes: Vec<String>,
-    /// Admin who updated
-    pub admin: Address,
-    /// Update timestamp
+    /// Active market it duplicates
+    pub duplicate_of: Symbol,
+    /// Event timestamp
     pub timestamp: u64,
 }
 
-/// Event emitted when market category is updated
-///
-/// This event tracks market category updates, providing transparency
-/// for changes to market categorization.
-///
-/// # Event Data
-///
-/// - Market identifier
-/// - Previous category (None if not set)
-/// - New category (None to clear)
-/// - Admin who performed the update
-/// - Update timestamp
+/// Event emitted when a moderator assigns a market tier.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct CategoryUpdatedEvent {
+pub struct MarketTierAssignedEvent {
     /// Market ID
     pub market_id: Symbol,
-    /// Old category (None if not previously set)
-    pub old_category: Option<String>,
-    /// New category (None to clear category)
-    pub new_category: Option<String>,
-    /// Admin who updated
+    /// Moderator who assigned the tier
+    pub moderator: Address,
+    /// Assigned tier
+    pub tier: MarketTier,
+    /// Platform fee percentage pinned by the tier
+    pub platform_fee_percentage: i128,
+    /// Event timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when a keeper registers in the keeper registry.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeeperRegisteredEvent {
+    /// Keeper address
+    pub keeper: Address,
+    /// Roles the keeper registered for
+    pub roles: Vec<KeeperRole>,
+    /// Stake bonded
+    pub stake: i128,
+    /// Event timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when a keeper deregisters and withdraws its stake.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeeperDeregisteredEvent {
+    /// Keeper address
+    pub keeper: Address,
+    /// Stake returned to the keeper
+    pub returned_stake: i128,
+    /// Event timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when governance slashes a keeper.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeeperSlashedEvent {
+    /// Keeper address
+    pub keeper: Address,
+    /// Admin who executed the slash
     pub admin: Address,
-    /// Update timestamp
+    /// Amount slashed into the keeper reward pool
+    pub amount: i128,
+    /// Stake remaining after the slash
+    pub remaining_stake: i128,
+    /// Reason for the slash
+    pub reason: String,
+    /// Event timestamp
     pub timestamp: u64,
 }
 
-/// Event emitted when market tags are updated
-///
-/// This event tracks market tags updates, providing transparency
-/// for changes to market tagging.
-///
-/// # Event Data
-///
-/// - Market identifier
-/// - Previous tags
-/// - New tags
-/// - Admin who performed the update
-/// - Update timestamp
+/// Event emitted when a keeper is paid a reward.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct TagsUpdatedEvent {
+pub struct KeeperRewardedEvent {
+    /// Keeper address
+    pub keeper: Address,
+    /// Role the reward was paid for
+    pub role: KeeperRole,
+    /// Amount paid
+    pub amount: i128,
+    /// Event timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when a keeper extends storage TTLs.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TtlsBumpedEvent {
+    /// Keeper that performed the bump
+    pub keeper: Address,
+    /// Number of entries bumped
+    pub bumped: u32,
+    /// Reward paid to the keeper
+    pub reward: i128,
+    /// Event timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted after each run of the rolling storage integrity audit.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StorageAuditedEvent {
+    /// Keeper that ran the audit
+    pub keeper: Address,
+    /// Number of markets checked
+    pub checked: u32,
+    /// Markets that failed the integrity check
+    pub corrupted: Vec<Symbol>,
+    /// Whether this run finished a pass over every market
+    pub pass_complete: bool,
+    /// Event timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted once per day with aggregated settlement activity.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DailySettlementReportEvent {
+    /// Day index (ledger timestamp / 86400)
+    pub day: u64,
+    /// Number of markets resolved
+    pub markets_resolved: u32,
+    /// Total stake of the markets resolved
+    pub volume_settled: i128,
+    /// Platform fees collected
+    pub fees_collected: i128,
+    /// Number of disputes opened
+    pub disputes_opened: u32,
+    /// Keeper that published the report
+    pub reporter: Address,
+    /// Reward paid to the keeper
+    pub reward: i128,
+    /// Event timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when dispute participation rewards are emitted.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeRewardsEmittedEvent {
+    /// Market whose dispute was resolved
+    pub market_id: Symbol,
+    /// Number of rewarded participants
+    pub recipients: u32,
+    /// Reward accrued to each participant
+    pub reward_per_recipient: i128,
+    /// Total emitted from the pool
+    pub total: i128,
+    /// Event timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when a user claims accrued protocol rewards.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RewardsClaimedEvent {
+    /// Claiming user
+    pub user: Address,
+    /// Amount transferred
+    pub amount: i128,
+    /// Event timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when an oracle is added to or removed from the blocklist.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleBlocklistUpdatedEvent {
+    /// Oracle contract address
+    pub oracle_address: Address,
+    /// Emergency council member who made the change
+    pub council_member: Address,
+    /// Whether the oracle is now blocked
+    pub blocked: bool,
+    /// Reason for the change
+    pub reason: String,
+    /// Event timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when a market is extended to compensate for circuit breaker downtime.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarketFreezeExtendedEvent {
     /// Market ID
     pub market_id: Symbol,
-    /// Old tags
-    pub old_tags: Vec<String>,
-    /// New tags
-    pub new_tags: Vec<String>,
-    /// Admin who updated
-    pub admin: Address,
-    /// Update timestamp
+    /// Seconds the market was frozen
+    pub frozen_seconds: u64,
+    /// End time before the extension
+    pub old_end_time: u64,
+    /// End time after the extension
+    pub new_end_time: u64,
+    /// Event timestamp
     pub timestamp: u64,
 }
 
-/// Contract rollback event - emitted when contract is rolled back to previous version
+/// Event emitted when the treasury funds the bug bounty escrow.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct ContractRollbackEvent {
-    /// Current Wasm hash (before rollback)
-    pub current_wasm_hash: soroban_sdk::BytesN<32>,
-    /// Rollback Wasm hash (after rollback)
-    pub rollback_wasm_hash: soroban_sdk::BytesN<32>,
-    /// Rollback timestamp
+pub struct BountyEscrowFundedEvent {
+    /// Treasury address
+    pub treasury: Address,
+    /// Amount added
+    pub amount: i128,
+    /// Escrow balance after funding
+    pub balance: i128,
+    /// Event timestamp
     pub timestamp: u64,
 }
 
-/// Upgrade proposal created event - emitted when a new upgrade proposal is created
+/// Event emitted when a bug bounty is paid to a reporter.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct UpgradeProposalCreatedEvent {
-    /// Proposal ID
-    pub proposal_id: Symbol,
-    /// Proposer address
-    pub proposer: Address,
-    /// Target version
-    pub target_version: String,
-    /// Proposal timestamp
+pub struct BountyPaidEvent {
+    /// Payout ID
+    pub payout_id: u32,
+    /// Reporter who received the bounty
+    pub reporter: Address,
+    /// Amount transferred
+    pub amount: i128,
+    /// Reference of the disclosed vulnerability report
+    pub report_id: String,
+    /// Emergency council member who approved the payout
+    pub approved_by: Address,
+    /// Event timestamp
     pub timestamp: u64,
 }
 
-/// Event emitted when circuit breaker state changes
-///
-/// This event provides comprehensive information about circuit breaker
-/// state changes, including the action taken, condition that triggered
-/// it, reason for the action, and administrative details.
-///
-/// # Event Data
-///
-/// Contains all critical circuit breaker information:
-/// - Action taken (pause, resume, trigger, reset)
-/// - Condition that triggered the action (if automatic)
-/// - Reason for the action
-/// - Timestamp and admin information
-///
-/// # Example Usage
-///
-/// ```rust
-/// # use soroban_sdk::{Env, Address, String};
-/// # use predictify_hybrid::events::CircuitBreakerEvent;
-/// # use predictify_hybrid::circuit_breaker::{BreakerAction, BreakerCondition};
-/// # let env = Env::default();
-/// # let admin = Address::generate(&env);
-///
-/// // Circuit breaker event data
-/// let event = CircuitBreakerEvent {
-///     action: BreakerAction::Pause,
-///     condition: Some(BreakerCondition::HighErrorRate),
-///     reason: String::from_str(&env, "Error rate exceeded 10% threshold"),
-///     timestamp: env.ledger().timestamp(),
-///     admin: Some(admin.clone()),
-/// };
-///
-/// // Event provides complete circuit breaker context
-/// println!("Circuit breaker action: {:?}", event.action);
-/// println!("Trigger condition: {:?}", event.condition);
-/// println!("Reason: {}", event.reason.to_string());
-/// ```
-///
-/// # Integration Points
-///
-/// - **Monitoring**: Track circuit breaker state changes
-/// - **Alerting**: Notify administrators of circuit breaker actions
-/// - **Analytics**: Analyze circuit breaker patterns and triggers
-/// - **Audit Trails**: Maintain complete record of safety actions
-/// - **Recovery Tracking**: Monitor recovery attempts and success rates
-///
-/// # Event Timing
-///
-/// Emitted immediately when circuit breaker state changes, providing:
-/// - Real-time notification of safety actions
-/// - Immediate availability for monitoring systems
-/// - Historical record for analysis and reporting
+/// Event emitted when a public market's creation bond is refunded or slashed.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct CircuitBreakerEvent {
-    /// Action taken by circuit breaker
-    pub action: crate::circuit_breaker::BreakerAction,
-    /// Condition that triggered the action (if automatic)
-    pub condition: crate::circuit_breaker::BreakerCondition,
-    /// Reason for the action
-    pub reason: String,
+pub struct CreationBondSettledEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Creator who posted the bond
+    pub creator: Address,
+    /// Bond amount
+    pub amount: i128,
+    /// Where the bond went (the creator on refund, the treasury on slash)
+    pub recipient: Address,
+    /// Whether the bond was slashed
+    pub slashed: bool,
     /// Event timestamp
     pub timestamp: u64,
-    /// Admin who triggered the action (if manual)
-    pub admin: Option<Address>,
 }
 
-/// Event emitted when a market's total pool size does not meet the required minimum.
+/// Event emitted when a market that never received a vote is delisted.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct MinPoolSizeNotMetEvent {
+pub struct MarketDelistedEvent {
     /// Market ID
     pub market_id: Symbol,
-    /// Current total pool size
-    pub current_pool: i128,
-    /// Required minimum pool size
-    pub required_min: i128,
+    /// Market creator
+    pub creator: Address,
+    /// Creation bond returned to the creator (0 for admin-created markets)
+    pub bond_refunded: i128,
+    /// Event timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when a jumbo payout starts (or adds to) a vesting schedule.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WinningsVestingEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Recipient of the vested winnings
+    pub beneficiary: Address,
+    /// Total winnings under the schedule
+    pub total: i128,
+    /// Vesting end timestamp
+    pub vesting_end: u64,
+    /// Event timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when vested winnings are released.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestedWinningsClaimedEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Recipient of the vested winnings
+    pub beneficiary: Address,
+    /// Amount released by this claim
+    pub amount: i128,
+    /// Total released so far
+    pub total_released: i128,
+    /// Event timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when funds are credited to the treasury.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TreasuryDepositEvent {
+    /// Where the funds came from
+    pub source: crate::treasury::TreasurySource,
+    /// Amount credited
+    pub amount: i128,
+    /// Treasury balance after the deposit
+    pub balance: i128,
+    /// Event timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when a treasury withdrawal is proposed, executed or cancelled.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TreasuryWithdrawalEvent {
+    /// Withdrawal ID
+    pub id: u32,
+    /// Recipient of the withdrawal
+    pub to: Address,
+    /// Withdrawal amount
+    pub amount: i128,
+    /// Earliest execution time
+    pub executable_at: u64,
     /// Event timestamp
     pub timestamp: u64,
 }
 
-// ===== EVENT EMISSION UTILITIES =====
+/// Event emitted when an oracle resolution attempt fails and is scheduled for retry.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResolutionAttemptFailedEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Failed attempts so far
+    pub attempt: u32,
+    /// Error code of the failure
+    pub error_code: u32,
+    /// Earliest time of the next attempt
+    pub next_attempt_at: u64,
+    /// Event timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when a reporter optimistically asserts a market outcome.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OutcomeAssertedEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Reporter making the assertion
+    pub asserter: Address,
+    /// Asserted outcome
+    pub outcome: String,
+    /// Bond posted
+    pub bond: i128,
+    /// End of the challenge window
+    pub challenge_deadline: u64,
+    /// Event timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when an optimistic assertion is challenged.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AssertionChallengedEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Challenger
+    pub challenger: Address,
+    /// Dispute stake of the challenger
+    pub stake: i128,
+    /// Event timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when an optimistic assertion's bond is paid out.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AssertionSettledEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Recipient of the bond
+    pub recipient: Address,
+    /// Bond paid out
+    pub bond: i128,
+    /// Event timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when governance posts an announcement.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AnnouncementPostedEvent {
+    /// Announcement ID
+    pub id: u32,
+    /// Poster
+    pub author: Address,
+    /// Severity level
+    pub severity: crate::announcements::AnnouncementSeverity,
+    /// Announcement text
+    pub message: String,
+    /// Event timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when a market creator claims their share of the platform fee.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CreatorFeesClaimedEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Market creator
+    pub creator: Address,
+    /// Amount paid out
+    pub amount: i128,
+    /// Event timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when a market's dispute insurance deposit changes.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeInsuranceUpdatedEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Creator funding or withdrawing, or the voter whose stake was covered
+    pub account: Address,
+    /// Change in the deposit (negative when drawn or withdrawn)
+    pub delta: i128,
+    /// Deposit remaining
+    pub balance: i128,
+    /// Event timestamp
+    pub timestamp: u64,
+}
+
+/// Event carrying the encrypted memo attached to a vote.
+///
+/// The memo is opaque to the contract; only holders of the key can read it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoteMemoEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Voter
+    pub voter: Address,
+    /// Encrypted memo, padded by the client to the fixed length
+    pub memo: BytesN<64>,
+    /// Event timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when a claim ticket changes hands.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimTicketTransferredEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Staker whose winnings the ticket represents
+    pub staker: Address,
+    /// Previous holder
+    pub from: Address,
+    /// New holder
+    pub to: Address,
+    /// Ticket payout
+    pub payout: i128,
+    /// Event timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when a market is created with a dispute arbitration panel.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ArbitrationPanelSetEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Designated arbitrators
+    pub arbitrators: Vec<Address>,
+    /// Event timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when the randomness of an arbitration panel draw is committed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PanelDrawCommittedEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// SHA-256 of the secret seed
+    pub commitment: BytesN<32>,
+    /// Candidate pool the panel will be drawn from
+    pub candidates: Vec<Address>,
+    /// Number of arbitrators to draw
+    pub panel_size: u32,
+    /// Event timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when a panel arbitrator rules on a market's dispute.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ArbitratorVoteCastEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Arbitrator address
+    pub arbitrator: Address,
+    /// Outcome chosen by the arbitrator
+    pub outcome: String,
+    /// Event timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when a market result becomes final after its dispute window.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResultFinalizedEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Final winning outcome(s)
+    pub outcomes: Vec<String>,
+    /// Finalization timestamp
+    pub timestamp: u64,
+}
+
+/// Contract upgraded event - emitted when contract Wasm is upgraded
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractUpgradedEvent {
+    /// Previous Wasm hash
+    pub old_wasm_hash: soroban_sdk::BytesN<32>,
+    /// New Wasm hash
+    pub new_wasm_hash: soroban_sdk::BytesN<32>,
+    /// Upgrade ID
+    pub upgrade_id: Symbol,
+    /// Upgrade timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when market deadline is extended
+///
+/// This event tracks market deadline extensions, providing transparency
+/// for extension requests and their impact on market timeline.
+///
+/// # Event Data
+///
+/// - Market identifier
+/// - Previous end time
+/// - New end time after extension
+/// - Additional days added
+/// - Admin who performed the extension
+/// - Reason for extension
+/// - Extension fee (if applicable)
+/// - Timestamp of extension
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarketDeadlineExtendedEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Previous end time
+    pub old_end_time: u64,
+    /// New end time
+    pub new_end_time: u64,
+    /// Additional days
+    pub additional_days: u32,
+    /// Admin who extended
+    pub admin: Address,
+    /// Reason for extension
+    pub reason: String,
+    /// Extension fee
+    pub fee: i128,
+    /// Extension timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when market description is updated
+///
+/// This event tracks market description updates, providing transparency
+/// for changes to market questions and parameters.
+///
+/// # Event Data
+///
+/// - Market identifier
+/// - Previous description
+/// - New description
+/// - Admin who performed the update
+/// - Update timestamp
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarketDescriptionUpdatedEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Old description
+    pub old_description: String,
+    /// New description
+    pub new_description: String,
+    /// Admin who updated
+    pub admin: Address,
+    /// Update timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when market outcomes are updated
+///
+/// This event tracks market outcome updates, providing transparency
+/// for changes to available market outcomes.
+///
+/// # Event Data
+///
+/// - Market identifier
+/// - Previous outcomes
+/// - New outcomes
+/// - Admin who performed the update
+/// - Update timestamp
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarketOutcomesUpdatedEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Old outcomes
+    pub old_outcomes: Vec<String>,
+    /// New outcomes
+    pub new_outcomes: Vec<String>,
+    /// Admin who updated
+    pub admin: Address,
+    /// Update timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when market category is updated
+///
+/// This event tracks market category updates, providing transparency
+/// for changes to market categorization.
+///
+/// # Event Data
+///
+/// - Market identifier
+/// - Previous category (None if not set)
+/// - New category (None to clear)
+/// - Admin who performed the update
+/// - Update timestamp
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CategoryUpdatedEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Old category (None if not previously set)
+    pub old_category: Option<String>,
+    /// New category (None to clear category)
+    pub new_category: Option<String>,
+    /// Admin who updated
+    pub admin: Address,
+    /// Update timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when market tags are updated
+///
+/// This event tracks market tags updates, providing transparency
+/// for changes to market tagging.
+///
+/// # Event Data
+///
+/// - Market identifier
+/// - Previous tags
+/// - New tags
+/// - Admin who performed the update
+/// - Update timestamp
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TagsUpdatedEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Old tags
+    pub old_tags: Vec<String>,
+    /// New tags
+    pub new_tags: Vec<String>,
+    /// Admin who updated
+    pub admin: Address,
+    /// Update timestamp
+    pub timestamp: u64,
+}
+
+/// Contract rollback event - emitted when contract is rolled back to previous version
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractRollbackEvent {
+    /// Current Wasm hash (before rollback)
+    pub current_wasm_hash: soroban_sdk::BytesN<32>,
+    /// Rollback Wasm hash (after rollback)
+    pub rollback_wasm_hash: soroban_sdk::BytesN<32>,
+    /// Rollback timestamp
+    pub timestamp: u64,
+}
+
+/// Upgrade proposal created event - emitted when a new upgrade proposal is created
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UpgradeProposalCreatedEvent {
+    /// Proposal ID
+    pub proposal_id: Symbol,
+    /// Proposer address
+    pub proposer: Address,
+    /// Target version
+    pub target_version: String,
+    /// Proposal timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when circuit breaker state changes
+///
+/// This event provides comprehensive information about circuit breaker
+/// state changes, including the action taken, condition that triggered
+/// it, reason for the action, and administrative details.
+///
+/// # Event Data
+///
+/// Contains all critical circuit breaker information:
+/// - Action taken (pause, resume, trigger, reset)
+/// - Condition that triggered the action (if automatic)
+/// - Reason for the action
+/// - Timestamp and admin information
+///
+/// # Example Usage
+///
+/// ```rust
+/// # use soroban_sdk::{Env, Address, String};
+/// # use predictify_hybrid::events::CircuitBreakerEvent;
+/// # use predictify_hybrid::circuit_breaker::{BreakerAction, BreakerCondition};
+/// # let env = Env::default();
+/// # let admin = Address::generate(&env);
+///
+/// // Circuit breaker event data
+/// let event = CircuitBreakerEvent {
+///     action: BreakerAction::Pause,
+///     condition: Some(BreakerCondition::HighErrorRate),
+///     reason: String::from_str(&env, "Error rate exceeded 10% threshold"),
+///     timestamp: env.ledger().timestamp(),
+///     admin: Some(admin.clone()),
+/// };
+///
+/// // Event provides complete circuit breaker context
+/// println!("Circuit breaker action: {:?}", event.action);
+/// println!("Trigger condition: {:?}", event.condition);
+/// println!("Reason: {}", event.reason.to_string());
+/// ```
+///
+/// # Integration Points
+///
+/// - **Monitoring**: Track circuit breaker state changes
+/// - **Alerting**: Notify administrators of circuit breaker actions
+/// - **Analytics**: Analyze circuit breaker patterns and triggers
+/// - **Audit Trails**: Maintain complete record of safety actions
+/// - **Recovery Tracking**: Monitor recovery attempts and success rates
+///
+/// # Event Timing
+///
+/// Emitted immediately when circuit breaker state changes, providing:
+/// - Real-time notification of safety actions
+/// - Immediate availability for monitoring systems
+/// - Historical record for analysis and reporting
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CircuitBreakerEvent {
+    /// Action taken by circuit breaker
+    pub action: crate::circuit_breaker::BreakerAction,
+    /// Condition that triggered the action (if automatic)
+    pub condition: crate::circuit_breaker::BreakerCondition,
+    /// Reason for the action
+    pub reason: String,
+    /// Event timestamp
+    pub timestamp: u64,
+    /// Admin who triggered the action (if manual)
+    pub admin: Option<Address>,
+}
+
+/// Event emitted when a market's total pool size does not meet the required minimum.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MinPoolSizeNotMetEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Current total pool size
+    pub current_pool: i128,
+    /// Required minimum pool size
+    pub required_min: i128,
+    /// Event timestamp
+    pub timestamp: u64,
+}
+
+// ===== EVENT EMISSION UTILITIES =====
+
+/// Event emission utilities
+pub struct EventEmitter;
+
+impl EventEmitter {
+    /// Emit market created event
+    pub fn emit_market_created(
+        env: &Env,
+        market_id: &Symbol,
+        question: &String,
+        outcomes: &Vec<String>,
+        admin: &Address,
+        end_time: u64,
+    ) {
+        let event = MarketCreatedEvent {
+            market_id: market_id.clone(),
+            question: question.clone(),
+            outcomes: outcomes.clone(),
+            admin: admin.clone(),
+            end_time,
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::store_event(env, &symbol_short!("mkt_crt"), &event);
+    }
+
+    /// Emit fallback used event
+    pub fn emit_fallback_used(
+        env: &Env,
+        market_id: &Symbol,
+        primary_oracle: &Address,
+        fallback_oracle: &Address,
+    ) {
+        let event = FallbackUsedEvent {
+            market_id: market_id.clone(),
+            primary_oracle: primary_oracle.clone(),
+            fallback_oracle: fallback_oracle.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::store_event(env, &symbol_short!("fbk_used"), &event);
+    }
+
+    /// Emit oracle blocklist updated event
+    pub fn emit_oracle_blocklist_updated(
+        env: &Env,
+        oracle_address: &Address,
+        council_member: &Address,
+        blocked: bool,
+        reason: &String,
+    ) {
+        let event = OracleBlocklistUpdatedEvent {
+            oracle_address: oracle_address.clone(),
+            council_member: council_member.clone(),
+            blocked,
+            reason: reason.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::store_event(env, &symbol_short!("orc_block"), &event);
+    }
+
+    /// Emit market freeze extended event
+    pub fn emit_market_freeze_extended(
+        env: &Env,
+        market_id: &Symbol,
+        frozen_seconds: u64,
+        old_end_time: u64,
+        new_end_time: u64,
+    ) {
+        let event = MarketFreezeExtendedEvent {
+            market_id: market_id.clone(),
+            frozen_seconds,
+            old_end_time,
+            new_end_time,
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("frz_ext"), &event);
+    }
+
+    /// Emit bug bounty escrow funded event
+    pub fn emit_bounty_escrow_funded(env: &Env, treasury: &Address, amount: i128, balance: i128) {
+        let event = BountyEscrowFundedEvent {
+            treasury: treasury.clone(),
+            amount,
+            balance,
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("bnty_fund"), &event);
+    }
+
+    /// Emit bug bounty paid event
+    pub fn emit_bounty_paid(env: &Env, payout: &crate::bounty::BountyPayout) {
+        let event = BountyPaidEvent {
+            payout_id: payout.id,
+            reporter: payout.reporter.clone(),
+            amount: payout.amount,
+            report_id: payout.report_id.clone(),
+            approved_by: payout.approved_by.clone(),
+            timestamp: payout.paid_at,
+        };
+        Self::store_event(env, &symbol_short!("bnty_paid"), &event);
+    }
+
+    /// Emit dispute insurance updated event
+    pub fn emit_dispute_insurance_updated(
+        env: &Env,
+        market_id: &Symbol,
+        account: &Address,
+        delta: i128,
+        balance: i128,
+    ) {
+        let event = DisputeInsuranceUpdatedEvent {
+            market_id: market_id.clone(),
+            account: account.clone(),
+            delta,
+            balance,
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("dsp_ins"), &event);
+    }
+
+    /// Emit vote memo event.
+    ///
+    /// Published to the ledger event stream rather than stored, so every memo
+    /// stays retrievable from event history.
+    pub fn emit_vote_memo(env: &Env, market_id: &Symbol, voter: &Address, memo: &BytesN<64>) {
+        let event = VoteMemoEvent {
+            market_id: market_id.clone(),
+            voter: voter.clone(),
+            memo: memo.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+        env.events()
+            .publish((symbol_short!("vote_memo"), market_id.clone()), event);
+    }
+
+    /// Emit claim ticket transferred event
+    pub fn emit_claim_ticket_transferred(
+        env: &Env,
+        ticket: &crate::tickets::ClaimTicket,
+        from: &Address,
+    ) {
+        let event = ClaimTicketTransferredEvent {
+            market_id: ticket.market_id.clone(),
+            staker: ticket.staker.clone(),
+            from: from.clone(),
+            to: ticket.holder.clone(),
+            payout: ticket.payout,
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("tkt_xfer"), &event);
+    }
+
+    /// Emit creation bond settled event
+    pub fn emit_creation_bond_settled(
+        env: &Env,
+        market_id: &Symbol,
+        bond: &crate::markets::CreationBond,
+        recipient: &Address,
+    ) {
+        let event = CreationBondSettledEvent {
+            market_id: market_id.clone(),
+            creator: bond.creator.clone(),
+            amount: bond.amount,
+            recipient: recipient.clone(),
+            slashed: bond.status == crate::markets::CreationBondStatus::Slashed,
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("bond_set"), &event);
+    }
+
+    /// Emit market delisted event
+    pub fn emit_market_delisted(
+        env: &Env,
+        market_id: &Symbol,
+        creator: &Address,
+        bond_refunded: i128,
+    ) {
+        let event = MarketDelistedEvent {
+            market_id: market_id.clone(),
+            creator: creator.clone(),
+            bond_refunded,
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("mkt_dlst"), &event);
+    }
+
+    /// Emit winnings vesting event
+    pub fn emit_winnings_vesting(env: &Env, schedule: &crate::vesting::VestingSchedule) {
+        let event = WinningsVestingEvent {
+            market_id: schedule.market_id.clone(),
+            beneficiary: schedule.beneficiary.clone(),
+            total: schedule.total,
+            vesting_end: schedule.start.saturating_add(schedule.duration_seconds),
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("vest_new"), &event);
+    }
+
+    /// Emit treasury deposit event
+    pub fn emit_treasury_deposit(
+        env: &Env,
+        source: crate::treasury::TreasurySource,
+        amount: i128,
+        balance: i128,
+    ) {
+        let event = TreasuryDepositEvent {
+            source,
+            amount,
+            balance,
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("trs_dep"), &event);
+    }
+
+    /// Emit treasury withdrawal event under `key` (`trs_prop`, `trs_exec` or `trs_cncl`)
+    pub fn emit_treasury_withdrawal(
+        env: &Env,
+        key: &Symbol,
+        withdrawal: &crate::treasury::TreasuryWithdrawal,
+    ) {
+        let event = TreasuryWithdrawalEvent {
+            id: withdrawal.id,
+            to: withdrawal.to.clone(),
+            amount: withdrawal.amount,
+            executable_at: withdrawal.executable_at,
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, key, &event);
+    }
+
+    /// Emit resolution attempt failed event
+    pub fn emit_resolution_attempt_failed(
+        env: &Env,
+        market_id: &Symbol,
+        attempts: &crate::resolution::ResolutionAttempts,
+    ) {
+        let event = ResolutionAttemptFailedEvent {
+            market_id: market_id.clone(),
+            attempt: attempts.attempts,
+            error_code: attempts.last_error,
+            next_attempt_at: attempts.next_attempt_at,
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("res_retry"), &event);
+    }
+
+    /// Emit outcome asserted event
+    pub fn emit_outcome_asserted(
+        env: &Env,
+        market_id: &Symbol,
+        assertion: &crate::resolution::OptimisticAssertion,
+    ) {
+        let event = OutcomeAssertedEvent {
+            market_id: market_id.clone(),
+            asserter: assertion.asserter.clone(),
+            outcome: assertion.outcome.clone(),
+            bond: assertion.bond,
+            challenge_deadline: assertion.challenge_deadline,
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("asserted"), &event);
+    }
+
+    /// Emit assertion challenged event
+    pub fn emit_assertion_challenged(
+        env: &Env,
+        market_id: &Symbol,
+        challenger: &Address,
+        stake: i128,
+    ) {
+        let event = AssertionChallengedEvent {
+            market_id: market_id.clone(),
+            challenger: challenger.clone(),
+            stake,
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("asrt_chal"), &event);
+    }
+
+    /// Emit assertion settled event
+    pub fn emit_assertion_settled(env: &Env, market_id: &Symbol, recipient: &Address, bond: i128) {
+        let event = AssertionSettledEvent {
+            market_id: market_id.clone(),
+            recipient: recipient.clone(),
+            bond,
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("asrt_set"), &event);
+    }
+
+    /// Emit announcement posted event
+    pub fn emit_announcement_posted(env: &Env, announcement: &crate::announcements::Announcement) {
+        let event = AnnouncementPostedEvent {
+            id: announcement.id,
+            author: announcement.author.clone(),
+            severity: announcement.severity,
+            message: announcement.message.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("announce"), &event);
+    }
+
+    /// Emit creator fees claimed event
+    pub fn emit_creator_fees_claimed(
+        env: &Env,
+        market_id: &Symbol,
+        creator: &Address,
+        amount: i128,
+    ) {
+        let event = CreatorFeesClaimedEvent {
+            market_id: market_id.clone(),
+            creator: creator.clone(),
+            amount,
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("crfee_clm"), &event);
+    }
+
+    /// Emit vested winnings claimed event
+    pub fn emit_vested_winnings_claimed(
+        env: &Env,
+        market_id: &Symbol,
+        beneficiary: &Address,
+        amount: i128,
+        total_released: i128,
+    ) {
+        let event = VestedWinningsClaimedEvent {
+            market_id: market_id.clone(),
+            beneficiary: beneficiary.clone(),
+            amount,
+            total_released,
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("vest_clm"), &event);
+    }
+
+    /// Emit cross-rate condition set event
+    pub fn emit_cross_rate_condition_set(
+        env: &Env,
+        market_id: &Symbol,
+        admin: &Address,
+        condition: &crate::types::CrossRateCondition,
+    ) {
+        let event = CrossRateConditionSetEvent {
+            market_id: market_id.clone(),
+            admin: admin.clone(),
+            base_feed_id: condition.base_feed_id.clone(),
+            quote_feed_id: condition.quote_feed_id.clone(),
+            threshold: condition.threshold,
+            comparison: condition.comparison.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::store_event(env, &symbol_short!("xrate_set"), &event);
+    }
+
+    /// Emit multi-oracle config set event
+    pub fn emit_multi_oracle_config_set(
+        env: &Env,
+        market_id: &Symbol,
+        admin: &Address,
+        config: &crate::types::MultiOracleConfig,
+    ) {
+        let event = MultiOracleConfigSetEvent {
+            market_id: market_id.clone(),
+            admin: admin.clone(),
+            source_count: config.sources.len(),
+            max_deviation_bps: config.max_deviation_bps,
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::store_event(env, &symbol_short!("moracle"), &event);
+    }
+
+    /// Emit composite condition set event
+    pub fn emit_composite_condition_set(
+        env: &Env,
+        market_id: &Symbol,
+        admin: &Address,
+        condition: &crate::types::CompositeCondition,
+    ) {
+        let event = CompositeConditionSetEvent {
+            market_id: market_id.clone(),
+            admin: admin.clone(),
+            operator: condition.operator.clone(),
+            term_count: condition.terms.len(),
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::store_event(env, &symbol_short!("comp_set"), &event);
+    }
+
+    /// Emit price buckets set event
+    pub fn emit_price_buckets_set(
+        env: &Env,
+        market_id: &Symbol,
+        admin: &Address,
+        buckets: &crate::types::PriceBuckets,
+    ) {
+        let event = PriceBucketsSetEvent {
+            market_id: market_id.clone(),
+            admin: admin.clone(),
+            boundaries: buckets.boundaries.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::store_event(env, &symbol_short!("bkt_set"), &event);
+    }
+
+    /// Emit oracle result pushed event
+    pub fn emit_oracle_result_pushed(
+        env: &Env,
+        market_id: &Symbol,
+        reporter: &Address,
+        result: &String,
+        agreeing_reports: u32,
+    ) {
+        let event = OracleResultPushedEvent {
+            market_id: market_id.clone(),
+            reporter: reporter.clone(),
+            result: result.clone(),
+            agreeing_reports,
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::store_event(env, &symbol_short!("orc_push"), &event);
+    }
+
+    /// Emit resolution timeout event
+    pub fn emit_resolution_timeout(env: &Env, market_id: &Symbol, timeout_timestamp: u64) {
+        let event = ResolutionTimeoutEvent {
+            market_id: market_id.clone(),
+            timeout_timestamp,
+        };
+
+        Self::store_event(env, &symbol_short!("res_tmo"), &event);
+    }
+
+    /// Emit event created event
+    pub fn emit_event_created(
+        env: &Env,
+        event_id: &Symbol,
+        description: &String,
+        outcomes: &Vec<String>,
+        admin: &Address,
+        end_time: u64,
+    ) {
+        let event = EventCreatedEvent {
+            event_id: event_id.clone(),
+            description: description.clone(),
+            outcomes: outcomes.clone(),
+            creation_fee_amount: crate::fees::MARKET_CREATION_FEE,
+            admin: admin.clone(),
+            end_time,
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::store_event(env, &symbol_short!("evt_crt"), &event);
+    }
+
+    /// Emit vote cast event
+    pub fn emit_vote_cast(
+        env: &Env,
+        market_id: &Symbol,
+        voter: &Address,
+        outcome: &String,
+        stake: i128,
+    ) {
+        let event = VoteCastEvent {
+            market_id: market_id.clone(),
+            voter: voter.clone(),
+            outcome: outcome.clone(),
+            stake,
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::store_event(env, &symbol_short!("vote"), &event);
+    }
+
+    /// Emit vote changed event
+    pub fn emit_vote_changed(
+        env: &Env,
+        market_id: &Symbol,
+        voter: &Address,
+        old_outcome: &String,
+        new_outcome: &String,
+        stake_moved: i128,
+        penalty: i128,
+    ) {
+        let event = VoteChangedEvent {
+            market_id: market_id.clone(),
+            voter: voter.clone(),
+            old_outcome: old_outcome.clone(),
+            new_outcome: new_outcome.clone(),
+            stake_moved,
+            penalty,
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::store_event(env, &symbol_short!("vote_chg"), &event);
+    }
+
+    /// Emit stake increased event
+    pub fn emit_stake_increased(
+        env: &Env,
+        market_id: &Symbol,
+        voter: &Address,
+        outcome: &String,
+        added: i128,
+        new_stake: i128,
+    ) {
+        let event = StakeIncreasedEvent {
+            market_id: market_id.clone(),
+            voter: voter.clone(),
+            outcome: outcome.clone(),
+            added,
+            new_stake,
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::store_event(env, &symbol_short!("stake_inc"), &event);
+    }
 
-/// Event emission utilities
-pub struct EventEmitter;
+    /// Emit vote committed event
+    pub fn emit_vote_committed(env: &Env, market_id: &Symbol, voter: &Address, stake: i128) {
+        let event = VoteCommittedEvent {
+            market_id: market_id.clone(),
+            voter: voter.clone(),
+            stake,
+            timestamp: env.ledger().timestamp(),
+        };
 
-impl EventEmitter {
-    /// Emit market created event
-    pub fn emit_market_created(
+        Self::store_event(env, &symbol_short!("vote_cmt"), &event);
+    }
+
+    /// Emit vote revealed event
+    pub fn emit_vote_revealed(
         env: &Env,
         market_id: &Symbol,
-        question: &String,
-        outcomes: &Vec<String>,
-        admin: &Address,
-        end_time: u64,
+        voter: &Address,
+        outcome: &String,
+        stake: i128,
     ) {
-        let event = MarketCreatedEvent {
+        let event = VoteRevealedEvent {
             market_id: market_id.clone(),
-            question: question.clone(),
-            outcomes: outcomes.clone(),
-            admin: admin.clone(),
-            end_time,
+            voter: voter.clone(),
+            outcome: outcome.clone(),
+            stake,
             timestamp: env.ledger().timestamp(),
         };
 
-        Self::store_event(env, &symbol_short!("mkt_crt"), &event);
+        Self::store_event(env, &symbol_short!("vote_rvl"), &event);
     }
 
-    /// Emit fallback used event
-    pub fn emit_fallback_used(
+    /// Emit position tokenized event
+    pub fn emit_position_tokenized(
         env: &Env,
         market_id: &Symbol,
-        primary_oracle: &Address,
-        fallback_oracle: &Address,
+        voter: &Address,
+        outcome: &String,
+        amount: i128,
+        token: &Address,
     ) {
-        let event = FallbackUsedEvent {
+        let event = PositionTokenizedEvent {
             market_id: market_id.clone(),
-            primary_oracle: primary_oracle.clone(),
-            fallback_oracle: fallback_oracle.clone(),
+            voter: voter.clone(),
+            outcome: outcome.clone(),
+            amount,
+            token: token.clone(),
             timestamp: env.ledger().timestamp(),
         };
 
-        Self::store_event(env, &symbol_short!("fbk_used"), &event);
+        Self::store_event(env, &symbol_short!("pos_tok"), &event);
     }
 
-    /// Emit resolution timeout event
-    pub fn emit_resolution_timeout(env: &Env, market_id: &Symbol, timeout_timestamp: u64) {
-        let event = ResolutionTimeoutEvent {
+    /// Emit position exited event
+    pub fn emit_position_exited(
+        env: &Env,
+        market_id: &Symbol,
+        voter: &Address,
+        outcome: &String,
+        amount: i128,
+        proceeds: i128,
+        remaining: i128,
+    ) {
+        let event = PositionExitedEvent {
             market_id: market_id.clone(),
-            timeout_timestamp,
+            voter: voter.clone(),
+            outcome: outcome.clone(),
+            amount,
+            proceeds,
+            remaining,
+            timestamp: env.ledger().timestamp(),
         };
 
-        Self::store_event(env, &symbol_short!("res_tmo"), &event);
+        Self::store_event(env, &symbol_short!("pos_exit"), &event);
     }
 
-    /// Emit event created event
-    pub fn emit_event_created(
-        env: &Env,
-        event_id: &Symbol,
-        description: &String,
-        outcomes: &Vec<String>,
-        admin: &Address,
-        end_time: u64,
-    ) {
-        let event = EventCreatedEvent {
-            event_id: event_id.clone(),
-            description: description.clone(),
+    /// Emit result finalized event
+    pub fn emit_result_finalized(env: &Env, market_id: &Symbol, outcomes: &Vec<String>) {
+        let event = ResultFinalizedEvent {
+            market_id: market_id.clone(),
             outcomes: outcomes.clone(),
-            creation_fee_amount: crate::fees::MARKET_CREATION_FEE,
-            admin: admin.clone(),
-            end_time,
             timestamp: env.ledger().timestamp(),
         };
 
-        Self::store_event(env, &symbol_short!("evt_crt"), &event);
+        Self::store_event(env, &symbol_short!("res_final"), &event);
     }
 
-    /// Emit vote cast event
-    pub fn emit_vote_cast(
+    /// Emit arbitration panel set event
+    pub fn emit_arbitration_panel_set(env: &Env, market_id: &Symbol, arbitrators: &Vec<Address>) {
+        let event = ArbitrationPanelSetEvent {
+            market_id: market_id.clone(),
+            arbitrators: arbitrators.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::store_event(env, &symbol_short!("arb_panel"), &event);
+    }
+
+    /// Emit panel draw committed event
+    pub fn emit_panel_draw_committed(env: &Env, draw: &crate::disputes::PanelDraw) {
+        let event = PanelDrawCommittedEvent {
+            market_id: draw.market_id.clone(),
+            commitment: draw.commitment.clone(),
+            candidates: draw.candidates.clone(),
+            panel_size: draw.panel_size,
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::store_event(env, &symbol_short!("arb_cmt"), &event);
+    }
+
+    /// Emit arbitrator vote cast event
+    pub fn emit_arbitrator_vote_cast(
         env: &Env,
         market_id: &Symbol,
-        voter: &Address,
+        arbitrator: &Address,
         outcome: &String,
-        stake: i128,
     ) {
-        let event = VoteCastEvent {
+        let event = ArbitratorVoteCastEvent {
             market_id: market_id.clone(),
-            voter: voter.clone(),
+            arbitrator: arbitrator.clone(),
             outcome: outcome.clone(),
-            stake,
             timestamp: env.ledger().timestamp(),
         };
 
-        Self::store_event(env, &symbol_short!("vote"), &event);
+        Self::store_event(env, &symbol_short!("arb_vote"), &event);
     }
 
     /// Emit statistics updated event
@@ -2876,6 +4323,137 @@ impl EventEmitter {
         Self::store_event(env, &symbol_short!("win_clm"), &event);
     }
 
+    /// Emit loser rebate claimed event
+    pub fn emit_loser_rebate_claimed(env: &Env, market_id: &Symbol, user: &Address, amount: i128) {
+        let event = LoserRebateClaimedEvent {
+            market_id: market_id.clone(),
+            user: user.clone(),
+            amount,
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("rebt_clm"), &event);
+    }
+
+    /// Emit refund claimed event
+    pub fn emit_refund_claimed(env: &Env, market_id: &Symbol, user: &Address, amount: i128) {
+        let event = RefundClaimedEvent {
+            market_id: market_id.clone(),
+            user: user.clone(),
+            amount,
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("rfnd_clm"), &event);
+    }
+
+    /// Emit dispute appealed event
+    pub fn emit_dispute_appealed(
+        env: &Env,
+        market_id: &Symbol,
+        appellant: &Address,
+        round: u32,
+        bond: i128,
+        final_round: bool,
+    ) {
+        let event = DisputeAppealedEvent {
+            market_id: market_id.clone(),
+            appellant: appellant.clone(),
+            round,
+            bond,
+            final_round,
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("dsp_appl"), &event);
+    }
+
+    /// Emit council member changed event
+    pub fn emit_council_member_changed(
+        env: &Env,
+        proposal_id: &Symbol,
+        member: &Address,
+        added: bool,
+    ) {
+        let event = CouncilMemberChangedEvent {
+            proposal_id: proposal_id.clone(),
+            member: member.clone(),
+            added,
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("cncl_upd"), &event);
+    }
+
+    /// Emit dispute evidence submitted event
+    pub fn emit_dispute_evidence_submitted(
+        env: &Env,
+        market_id: &Symbol,
+        dispute_id: &Symbol,
+        submitter: &Address,
+        content_hash: &BytesN<32>,
+    ) {
+        let event = DisputeEvidenceSubmittedEvent {
+            market_id: market_id.clone(),
+            dispute_id: dispute_id.clone(),
+            submitter: submitter.clone(),
+            content_hash: content_hash.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("dsp_evd"), &event);
+    }
+
+    /// Emit dispute settled event
+    #[allow(clippy::too_many_arguments)]
+    pub fn emit_dispute_settled(
+        env: &Env,
+        dispute_id: &Symbol,
+        upheld: bool,
+        slash_bps: u32,
+        slashed: i128,
+        refunded: i128,
+        rewarded: i128,
+        winners: u32,
+    ) {
+        let event = DisputeSettledEvent {
+            dispute_id: dispute_id.clone(),
+            upheld,
+            slash_bps,
+            slashed,
+            refunded,
+            rewarded,
+            winners,
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("dsp_setl"), &event);
+    }
+
+    /// Emit market metadata updated event
+    pub fn emit_market_metadata_updated(env: &Env, market_id: &Symbol, updated_by: &Address) {
+        let event = MarketMetadataUpdatedEvent {
+            market_id: market_id.clone(),
+            updated_by: updated_by.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("meta_upd"), &event);
+    }
+
+    /// Emit AMM trade event
+    pub fn emit_amm_trade(
+        env: &Env,
+        market_id: &Symbol,
+        trader: &Address,
+        outcome: &String,
+        shares: i128,
+        amount: i128,
+    ) {
+        let event = AmmTradeEvent {
+            market_id: market_id.clone(),
+            trader: trader.clone(),
+            outcome: outcome.clone(),
+            shares,
+            amount,
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("amm_trd"), &event);
+    }
+
     /// Emit winnings claimed batch event
     ///
     /// Emits an event when a user claims winnings from multiple markets in a batch.
@@ -2937,6 +4515,211 @@ impl EventEmitter {
         Self::store_event(env, &symbol_short!("treas_up"), &event);
     }
 
+    /// Emit zero-winner policy updated event.
+    pub fn emit_zero_winner_policy_updated(env: &Env, admin: &Address, policy: &ZeroWinnerPolicy) {
+        let event = ZeroWinnerPolicyUpdatedEvent {
+            admin: admin.clone(),
+            policy: *policy,
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("zw_policy"), &event);
+    }
+
+    /// Emit duplicate market policy updated event.
+    pub fn emit_duplicate_market_policy_updated(
+        env: &Env,
+        admin: &Address,
+        policy: &DuplicateMarketPolicy,
+    ) {
+        let event = DuplicateMarketPolicyUpdatedEvent {
+            admin: admin.clone(),
+            policy: *policy,
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("dup_pol"), &event);
+    }
+
+    /// Emit duplicate market detected event.
+    pub fn emit_duplicate_market_detected(env: &Env, market_id: &Symbol, duplicate_of: &Symbol) {
+        let event = DuplicateMarketDetectedEvent {
+            market_id: market_id.clone(),
+            duplicate_of: duplicate_of.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("mkt_dup"), &event);
+    }
+
+    /// Emit keeper registered event.
+    pub fn emit_keeper_registered(
+        env: &Env,
+        keeper: &Address,
+        roles: &Vec<KeeperRole>,
+        stake: i128,
+    ) {
+        let event = KeeperRegisteredEvent {
+            keeper: keeper.clone(),
+            roles: roles.clone(),
+            stake,
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("kpr_reg"), &event);
+    }
+
+    /// Emit keeper deregistered event.
+    pub fn emit_keeper_deregistered(env: &Env, keeper: &Address, returned_stake: i128) {
+        let event = KeeperDeregisteredEvent {
+            keeper: keeper.clone(),
+            returned_stake,
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("kpr_exit"), &event);
+    }
+
+    /// Emit keeper slashed event.
+    pub fn emit_keeper_slashed(
+        env: &Env,
+        keeper: &Address,
+        admin: &Address,
+        amount: i128,
+        remaining_stake: i128,
+        reason: &String,
+    ) {
+        let event = KeeperSlashedEvent {
+            keeper: keeper.clone(),
+            admin: admin.clone(),
+            amount,
+            remaining_stake,
+            reason: reason.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("kpr_slash"), &event);
+    }
+
+    /// Emit keeper rewarded event.
+    pub fn emit_keeper_rewarded(env: &Env, keeper: &Address, role: KeeperRole, amount: i128) {
+        let event = KeeperRewardedEvent {
+            keeper: keeper.clone(),
+            role,
+            amount,
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("kpr_rwd"), &event);
+    }
+
+    /// Emit TTLs bumped event.
+    pub fn emit_ttls_bumped(env: &Env, keeper: &Address, bumped: u32, reward: i128) {
+        let event = TtlsBumpedEvent {
+            keeper: keeper.clone(),
+            bumped,
+            reward,
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("ttl_bump"), &event);
+    }
+
+    /// Emit storage audited event.
+    pub fn emit_storage_audited(
+        env: &Env,
+        keeper: &Address,
+        checked: u32,
+        corrupted: &Vec<Symbol>,
+        pass_complete: bool,
+    ) {
+        let event = StorageAuditedEvent {
+            keeper: keeper.clone(),
+            checked,
+            corrupted: corrupted.clone(),
+            pass_complete,
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("stg_audt"), &event);
+    }
+
+    /// Emit daily settlement report event.
+    pub fn emit_daily_settlement_report(
+        env: &Env,
+        reporter: &Address,
+        report: &DailySettlementStats,
+        reward: i128,
+    ) {
+        let event = DailySettlementReportEvent {
+            day: report.day,
+            markets_resolved: report.markets_resolved,
+            volume_settled: report.volume_settled,
+            fees_collected: report.fees_collected,
+            disputes_opened: report.disputes_opened,
+            reporter: reporter.clone(),
+            reward,
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("day_rpt"), &event);
+    }
+
+    /// Emit dispute participation rewards emitted event.
+    pub fn emit_dispute_rewards_emitted(
+        env: &Env,
+        market_id: &Symbol,
+        recipients: u32,
+        reward_per_recipient: i128,
+        total: i128,
+    ) {
+        let event = DisputeRewardsEmittedEvent {
+            market_id: market_id.clone(),
+            recipients,
+            reward_per_recipient,
+            total,
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("dsp_rwd"), &event);
+    }
+
+    /// Emit rewards claimed event.
+    pub fn emit_rewards_claimed(env: &Env, user: &Address, amount: i128) {
+        let event = RewardsClaimedEvent {
+            user: user.clone(),
+            amount,
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("rwd_claim"), &event);
+    }
+
+    /// Emit market tier assigned event.
+    pub fn emit_market_tier_assigned(
+        env: &Env,
+        market_id: &Symbol,
+        moderator: &Address,
+        tier: &MarketTier,
+        platform_fee_percentage: i128,
+    ) {
+        let event = MarketTierAssignedEvent {
+            market_id: market_id.clone(),
+            moderator: moderator.clone(),
+            tier: *tier,
+            platform_fee_percentage,
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("tier_set"), &event);
+    }
+
+    /// Emit zero-winner market settled event.
+    pub fn emit_zero_winner_settled(
+        env: &Env,
+        market_id: &Symbol,
+        caller: &Address,
+        settlement: &ZeroWinnerSettlement,
+    ) {
+        let event = ZeroWinnerSettledEvent {
+            market_id: market_id.clone(),
+            caller: caller.clone(),
+            policy: settlement.policy,
+            refunded_total: settlement.refunded_total,
+            fee_total: settlement.fee_total,
+            treasury_total: settlement.treasury_total,
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("zw_settle"), &event);
+    }
+
     /// Emit unclaimed winnings swept event.
     pub fn emit_unclaimed_winnings_swept(
         env: &Env,
@@ -599,8 +599,11 @@ impl ExtensionValidator {
     ) -> Result<(), Error> {
         let market = MarketStateManager::get_market(env, market_id)?;
 
-        // Check total extension days limit
-        if market.total_extension_days + additional_days > market.max_extension_days {
+        // Check total extension limit, counting time added by anti-sniping
+        let requested_seconds = (market.total_extension_days as u64 + additional_days as u64)
+            * 86_400
+            + AntiSnipeManager::get_extended_seconds(env, market_id);
+        if requested_seconds > market.max_extension_days as u64 * 86_400 {
             return Err(Error::ExtensionDenied);
         }
 
@@ -625,6 +628,89 @@ impl ExtensionValidator {
     }
 }
 
+// ===== ANTI-SNIPING =====
+
+/// Automatic end time extensions against last-second stakes.
+///
+/// A stake of at least `threshold` landing within the final `window_seconds`
+/// of a market pushes its end time back by `extension_seconds`, so others get
+/// to react. Extensions stop once the market has used up its
+/// `max_extension_days`, counting admin extensions too. Configured by the
+/// admin; a zero threshold disables it.
+pub struct AntiSnipeManager;
+
+impl AntiSnipeManager {
+    fn extended_key(market_id: &Symbol) -> (Symbol, Symbol) {
+        (symbol_short!("anti_snp"), market_id.clone())
+    }
+
+    /// Current anti-snipe settings
+    pub fn get_config(env: &Env) -> AntiSnipeConfig {
+        env.storage()
+            .persistent()
+            .get(&symbol_short!("anti_snp"))
+            .unwrap_or(AntiSnipeConfig {
+                threshold: crate::config::DEFAULT_ANTI_SNIPE_THRESHOLD,
+                window_seconds: crate::config::DEFAULT_ANTI_SNIPE_WINDOW_SECONDS,
+                extension_seconds: crate::config::DEFAULT_ANTI_SNIPE_EXTENSION_SECONDS,
+            })
+    }
+
+    /// Set the anti-snipe settings (admin only).
+    pub fn set_config(env: &Env, admin: &Address, config: &AntiSnipeConfig) -> Result<(), Error> {
+        crate::recovery::RecoveryManager::assert_is_admin(env, admin)?;
+        if config.threshold < 0
+            || (config.threshold > 0
+                && (config.window_seconds == 0 || config.extension_seconds == 0))
+        {
+            return Err(Error::InvalidInput);
+        }
+        env.storage()
+            .persistent()
+            .set(&symbol_short!("anti_snp"), config);
+        Ok(())
+    }
+
+    /// Seconds a market has been extended by anti-sniping so far
+    pub fn get_extended_seconds(env: &Env, market_id: &Symbol) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&Self::extended_key(market_id))
+            .unwrap_or(0)
+    }
+
+    /// Extend `market` if `stake` is a snipe; the caller persists `market`.
+    ///
+    /// Returns the seconds added, 0 if the stake did not trigger an extension.
+    pub fn on_stake(env: &Env, market_id: &Symbol, market: &mut Market, stake: i128) -> u64 {
+        let config = Self::get_config(env);
+        let now = env.ledger().timestamp();
+        if config.threshold == 0
+            || stake < config.threshold
+            || now >= market.end_time
+            || market.end_time - now > config.window_seconds
+        {
+            return 0;
+        }
+
+        let extended = Self::get_extended_seconds(env, market_id);
+        let budget = (market.max_extension_days as u64 * 86_400)
+            .saturating_sub(market.total_extension_days as u64 * 86_400)
+            .saturating_sub(extended);
+        let added = config.extension_seconds.min(budget);
+        if added == 0 {
+            return 0;
+        }
+
+        market.end_time += added;
+        env.storage()
+            .persistent()
+            .set(&Self::extended_key(market_id), &(extended + added));
+        ExtensionUtils::emit_anti_snipe_event(env, market_id, stake, added, market.end_time);
+        added
+    }
+}
+
 // ===== EXTENSION UTILITIES =====
 
 /// Extension utility functions
@@ -673,6 +759,27 @@ impl ExtensionUtils {
         env.storage().persistent().set(&event_key, &event_data);
     }
 
+    /// Emit anti-snipe extension event
+    pub fn emit_anti_snipe_event(
+        env: &Env,
+        market_id: &Symbol,
+        stake: i128,
+        added_seconds: u64,
+        new_end_time: u64,
+    ) {
+        let event_data = AntiSnipeExtension {
+            market_id: market_id.clone(),
+            stake,
+            added_seconds,
+            new_end_time,
+            timestamp: env.ledger().timestamp(),
+        };
+
+        env.storage()
+            .persistent()
+            .set(&symbol_short!("snipe_ext"), &event_data);
+    }
+
     /// Get extension events
     pub fn get_extension_events(env: &Env) -> Vec<ExtensionEvent> {
         let event_key = symbol_short!("ext_event");
@@ -699,6 +806,34 @@ pub struct ExtensionEvent {
     pub timestamp: u64,
 }
 
+/// Anti-sniping settings
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AntiSnipeConfig {
+    /// Smallest stake that triggers an extension (0 = disabled)
+    pub threshold: i128,
+    /// Final part of a market, in seconds, in which large stakes extend it
+    pub window_seconds: u64,
+    /// Seconds added to the end time per trigger
+    pub extension_seconds: u64,
+}
+
+/// Anti-snipe extension event data
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AntiSnipeExtension {
+    /// Market ID that was extended
+    pub market_id: Symbol,
+    /// Stake that triggered the extension
+    pub stake: i128,
+    /// Seconds added to the end time
+    pub added_seconds: u64,
+    /// End time after the extension
+    pub new_end_time: u64,
+    /// Extension timestamp
+    pub timestamp: u64,
+}
+
 // ===== EXTENSION TEST HELPERS =====
 
 /// Extension testing utilities
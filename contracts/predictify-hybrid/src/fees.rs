@@ -2,6 +2,7 @@ use soroban_sdk::{contracttype, symbol_short, vec, Address, Env, Map, String, Sy
 
 use crate::errors::Error;
 use crate::markets::{MarketStateManager, MarketUtils};
+use crate::reentrancy_guard::ReentrancyGuard;
 use crate::types::Market;
 
 /// Fee management system for Predictify Hybrid contract
@@ -127,6 +128,49 @@ pub struct FeeConfig {
     pub fees_enabled: bool,
 }
 
+/// How fee collection treats markets whose pool is below the collection threshold.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SmallMarketFeeMode {
+    /// Refuse collection (the market cannot be marked as fee-processed)
+    Reject,
+    /// Collect a reduced flat fee, capped at the fee withheld from payouts
+    Flat,
+    /// Collect nothing but still mark the market as fee-processed; winners
+    /// are paid the whole pool unless claims have already started
+    Waive,
+}
+
+/// Policy applied by `collect_fees` to markets below `FEE_COLLECTION_THRESHOLD`.
+///
+/// The default policy is `Reject`, which preserves the original behaviour of
+/// refusing small markets. `Flat` and `Waive` let sub-threshold markets be
+/// settled and marked processed so they can be archived.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SmallMarketFeePolicy {
+    /// Treatment of sub-threshold markets
+    pub mode: SmallMarketFeeMode,
+    /// Flat fee charged in `Flat` mode (in stroops)
+    pub flat_fee: i128,
+}
+
+/// Creator's cut of a market's platform fee.
+///
+/// `creator_fee_bps` of every fee collected from the market is accrued to
+/// the market creator instead of the fee vault or treasury, and paid out
+/// through `claim_creator_fees`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CreatorFeeShare {
+    /// Share of the platform fee owed to the creator, in basis points
+    pub creator_fee_bps: u32,
+    /// Total creator fees accrued from collections
+    pub accrued: i128,
+    /// Creator fees already paid out
+    pub claimed: i128,
+}
+
 /// Dynamic fee tier configuration based on market size
 ///
 /// This structure defines fee tiers for different market sizes, allowing
@@ -724,22 +768,21 @@ impl FeeManager {
 
         // Get and validate market
         let mut market = MarketStateManager::get_market(env, &market_id)?;
-        FeeValidator::validate_market_for_fee_collection(&market)?;
-
-        // Calculate fee amount
-        let fee_amount = FeeCalculator::calculate_platform_fee(&market)?;
-
-        // Validate fee amount
-        FeeValidator::validate_fee_amount(fee_amount)?;
+        FeeValidator::validate_market_ready_for_fees(&market)?;
+        let (fee_amount, fee_type) = Self::fee_due(env, &market)?;
 
         // Record fee collection into the contract fee vault.
         //
         // NOTE: This intentionally does NOT transfer fees out of the contract.
         // Fees remain in the contract and must be withdrawn via the admin
         // fee withdrawal function which enforces a timelock/schedule.
-        FeeTracker::record_fee_collection(env, &market_id, fee_amount, &admin)?;
+        let creator_cut = Self::accrue_creator_share(env, &market_id, fee_amount);
+        if fee_amount > creator_cut {
+            FeeTracker::record_fee_collection(env, &market_id, fee_amount - creator_cut, &admin)?;
+        }
 
-        // Mark fees as collected
+        // Mark fees as collected (also for waived fees, so the market counts as processed)
+        Self::apply_waiver(&mut market, fee_type);
         MarketStateManager::mark_fees_collected(&mut market, Some(&market_id));
         MarketStateManager::update_market(env, &market_id, &market);
 
@@ -749,12 +792,219 @@ impl FeeManager {
             &market_id,
             &admin,
             fee_amount,
-            &soroban_sdk::String::from_str(env, "platform_fee"),
+            &soroban_sdk::String::from_str(env, fee_type),
         );
 
         Ok(fee_amount)
     }
 
+    /// Fee owed by a resolved market and the label it is collected under.
+    fn fee_due(env: &Env, market: &Market) -> Result<(i128, &'static str), Error> {
+        // Zero-winner pools are settled whole by `settle_zero_winner_market`,
+        // which takes the fee itself under `RefundMinusFee`
+        if market.get_winning_total() == 0 {
+            return Err(Error::NoFeesToCollect);
+        }
+
+        // Sub-threshold markets follow the small market policy
+        if market.total_staked < FEE_COLLECTION_THRESHOLD {
+            let policy = FeeConfigManager::get_small_market_fee_policy(env);
+            // Payouts are made net of the pinned fee, so no more than that can be taken
+            let withheld = (market.total_staked * market.platform_fee_percentage / 100
+                - market.loser_rebate_pool())
+            .max(0);
+            return match policy.mode {
+                SmallMarketFeeMode::Reject => Err(Error::InsufficientStake),
+                SmallMarketFeeMode::Flat => Ok((policy.flat_fee.min(withheld), "small_market_fee")),
+                // Once winners have been paid net of the fee it can no longer
+                // be handed back, so it is collected as usual
+                SmallMarketFeeMode::Waive if market.claimed_count > 0 => {
+                    Ok((withheld, "platform_fee"))
+                }
+                SmallMarketFeeMode::Waive => Ok((0, "fee_waived")),
+            };
+        }
+
+        // The loser rebate stays in the contract until losing voters claim it
+        let fee_amount =
+            FeeCalculator::calculate_platform_fee(market)? - market.loser_rebate_pool();
+        FeeValidator::validate_fee_amount(fee_amount)?;
+        Ok((fee_amount, "platform_fee"))
+    }
+
+    /// Pay winners of a market whose fee is waived the whole pool, including
+    /// the loser rebate funded from the fee.
+    fn apply_waiver(market: &mut Market, fee_type: &str) {
+        if fee_type == "fee_waived" {
+            market.platform_fee_percentage = 0;
+        }
+    }
+
+    /// Collect the platform fee of a just-resolved market into the treasury.
+    ///
+    /// Called from resolution. Does nothing when automatic collection is
+    /// disabled for the market, fees were already collected, or the small
+    /// market policy rejects collection; `collect_fees` remains available
+    /// for those markets. Markets without winning stake are skipped, as
+    /// their zero-winner settlement handles the fee. The fee is credited to the contract treasury
+    /// rather than the fee vault.
+    pub fn collect_fees_on_resolution(env: &Env, market_id: &Symbol) -> Result<i128, Error> {
+        if !Self::is_auto_collect_enabled(env, market_id) {
+            return Ok(0);
+        }
+        let mut market = MarketStateManager::get_market(env, market_id)?;
+        if FeeValidator::validate_market_ready_for_fees(&market).is_err() {
+            return Ok(0);
+        }
+        let (fee_amount, fee_type) = match Self::fee_due(env, &market) {
+            Ok(due) => due,
+            Err(Error::InsufficientStake) | Err(Error::NoFeesToCollect) => return Ok(0),
+            Err(e) => return Err(e),
+        };
+
+        let creator_cut = Self::accrue_creator_share(env, market_id, fee_amount);
+        if fee_amount > creator_cut {
+            crate::treasury::TreasuryManager::deposit(
+                env,
+                crate::treasury::TreasurySource::PlatformFee,
+                fee_amount - creator_cut,
+            )?;
+        }
+
+        // The market stays Resolved so winners can still claim and dispute
+        Self::apply_waiver(&mut market, fee_type);
+        market.fee_collected = true;
+        MarketStateManager::update_market(env, market_id, &market);
+        crate::events::EventEmitter::emit_fee_collected(
+            env,
+            market_id,
+            &env.current_contract_address(),
+            fee_amount,
+            &soroban_sdk::String::from_str(env, fee_type),
+        );
+
+        Ok(fee_amount)
+    }
+
+    /// Whether the platform fee is collected automatically when the market resolves.
+    pub fn is_auto_collect_enabled(env: &Env, market_id: &Symbol) -> bool {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("auto_fee"), market_id.clone()))
+            .unwrap_or(true)
+    }
+
+    /// Enable or disable automatic fee collection at resolution for a market (admin only).
+    pub fn set_auto_collect(
+        env: &Env,
+        admin: &Address,
+        market_id: &Symbol,
+        enabled: bool,
+    ) -> Result<(), Error> {
+        FeeValidator::validate_admin_permissions(env, admin)?;
+        MarketStateManager::get_market(env, market_id)?;
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("auto_fee"), market_id.clone()), &enabled);
+        Ok(())
+    }
+
+    fn creator_share_key(market_id: &Symbol) -> (Symbol, Symbol) {
+        (symbol_short!("cr_fee"), market_id.clone())
+    }
+
+    /// Creator fee share of a market (zero share by default).
+    pub fn get_creator_fee_share(env: &Env, market_id: &Symbol) -> CreatorFeeShare {
+        env.storage()
+            .persistent()
+            .get(&Self::creator_share_key(market_id))
+            .unwrap_or(CreatorFeeShare {
+                creator_fee_bps: 0,
+                accrued: 0,
+                claimed: 0,
+            })
+    }
+
+    /// Set the creator's share of a market's platform fee (admin only).
+    ///
+    /// Must be set before the market's fee is collected and is capped at
+    /// `MAX_CREATOR_FEE_BPS`.
+    pub fn set_creator_fee_bps(
+        env: &Env,
+        admin: &Address,
+        market_id: &Symbol,
+        creator_fee_bps: u32,
+    ) -> Result<(), Error> {
+        FeeValidator::validate_admin_permissions(env, admin)?;
+        if creator_fee_bps > crate::config::MAX_CREATOR_FEE_BPS {
+            return Err(Error::InvalidInput);
+        }
+        let market = MarketStateManager::get_market(env, market_id)?;
+        if market.fee_collected {
+            return Err(Error::FeeAlreadyCollected);
+        }
+        let mut share = Self::get_creator_fee_share(env, market_id);
+        share.creator_fee_bps = creator_fee_bps;
+        env.storage()
+            .persistent()
+            .set(&Self::creator_share_key(market_id), &share);
+        Ok(())
+    }
+
+    /// Accrue the creator's cut of a collected fee and return it.
+    fn accrue_creator_share(env: &Env, market_id: &Symbol, fee_amount: i128) -> i128 {
+        let mut share = Self::get_creator_fee_share(env, market_id);
+        if share.creator_fee_bps == 0 || fee_amount <= 0 {
+            return 0;
+        }
+        let cut = fee_amount * share.creator_fee_bps as i128 / 10_000;
+        if cut > 0 {
+            share.accrued += cut;
+            env.storage()
+                .persistent()
+                .set(&Self::creator_share_key(market_id), &share);
+        }
+        cut
+    }
+
+    /// Pay out the creator fees accrued on a market to its creator.
+    pub fn claim_creator_fees(
+        env: &Env,
+        creator: &Address,
+        market_id: &Symbol,
+    ) -> Result<i128, Error> {
+        let market = MarketStateManager::get_market(env, market_id)?;
+        if market.admin != *creator {
+            return Err(Error::Unauthorized);
+        }
+        let mut share = Self::get_creator_fee_share(env, market_id);
+        let amount = share.accrued - share.claimed;
+        if amount <= 0 {
+            return Err(Error::NothingToClaim);
+        }
+        share.claimed = share.accrued;
+        env.storage()
+            .persistent()
+            .set(&Self::creator_share_key(market_id), &share);
+
+        let token_client = MarketUtils::get_token_client(env)?;
+        ReentrancyGuard::guarded(env, || {
+            token_client.transfer(&env.current_contract_address(), creator, &amount);
+            Ok(())
+        })?;
+        crate::events::EventEmitter::emit_creator_fees_claimed(env, market_id, creator, amount);
+        Ok(amount)
+    }
+
+    /// Current platform fee percentage, pinned onto markets at creation.
+    ///
+    /// Falls back to the default when no contract configuration is stored.
+    pub fn get_platform_fee_percentage(env: &Env) -> i128 {
+        crate::config::ConfigManager::get_config(env)
+            .map(|cfg| cfg.fees.platform_fee_percentage)
+            .unwrap_or(PLATFORM_FEE_PERCENTAGE)
+    }
+
     /// Process market/event creation fee and return the charged amount.
     pub fn process_creation_fee(env: &Env, admin: &Address) -> Result<i128, Error> {
         // Read configured fee (fallback to default constant if config is missing)
@@ -777,7 +1027,10 @@ impl FeeManager {
         let token_client = MarketUtils::get_token_client(env)?;
 
         // Transfer creation fee from admin to contract
-        token_client.transfer(admin, &env.current_contract_address(), &creation_fee);
+        ReentrancyGuard::guarded(env, || {
+            token_client.transfer(admin, &env.current_contract_address(), &creation_fee);
+            Ok(())
+        })?;
 
         // Record creation fee
         FeeTracker::record_creation_fee(env, admin, creation_fee)?;
@@ -884,7 +1137,7 @@ impl FeeCalculator {
             return Err(Error::NoFeesToCollect);
         }
 
-        let fee_amount = (market.total_staked * PLATFORM_FEE_PERCENTAGE) / 100;
+        let fee_amount = (market.total_staked * market.platform_fee_percentage) / 100;
 
         if fee_amount < MIN_FEE_AMOUNT {
             return Err(Error::InsufficientStake);
@@ -912,7 +1165,7 @@ impl FeeCalculator {
     /// Calculate fee breakdown for a market
     pub fn calculate_fee_breakdown(market: &Market) -> Result<FeeBreakdown, Error> {
         let total_staked = market.total_staked;
-        let fee_percentage = PLATFORM_FEE_PERCENTAGE;
+        let fee_percentage = market.platform_fee_percentage;
         let fee_amount = Self::calculate_platform_fee(market)?;
         let platform_fee = fee_amount;
         let user_payout_amount = total_staked - fee_amount;
@@ -1078,7 +1331,7 @@ impl FeeCalculator {
         let tier = Self::get_fee_tier_by_market_size(env, market.total_staked)?;
 
         // Calculate activity level
-        let vote_count = market.votes.len() as u32;
+        let vote_count = market.voter_count;
         let activity_level = if vote_count >= ACTIVITY_LEVEL_HIGH {
             String::from_str(env, "High")
         } else if vote_count >= ACTIVITY_LEVEL_MEDIUM {
@@ -1166,6 +1419,18 @@ impl FeeValidator {
 
     /// Validate market for fee collection
     pub fn validate_market_for_fee_collection(market: &Market) -> Result<(), Error> {
+        Self::validate_market_ready_for_fees(market)?;
+
+        // Check if there are sufficient stakes
+        if market.total_staked < FEE_COLLECTION_THRESHOLD {
+            return Err(Error::InsufficientStake);
+        }
+
+        Ok(())
+    }
+
+    /// Validate market is resolved and its fees not yet processed (ignores pool size)
+    pub fn validate_market_ready_for_fees(market: &Market) -> Result<(), Error> {
         // Check if market is resolved
         if market.winning_outcomes.is_none() {
             return Err(Error::MarketNotResolved);
@@ -1176,9 +1441,17 @@ impl FeeValidator {
             return Err(Error::FeeAlreadyCollected);
         }
 
-        // Check if there are sufficient stakes
-        if market.total_staked < FEE_COLLECTION_THRESHOLD {
-            return Err(Error::InsufficientStake);
+        Ok(())
+    }
+
+    /// Validate small market fee policy
+    pub fn validate_small_market_fee_policy(policy: &SmallMarketFeePolicy) -> Result<(), Error> {
+        if policy.flat_fee < 0 || policy.flat_fee > MAX_FEE_AMOUNT {
+            return Err(Error::InvalidFeeConfig);
+        }
+
+        if policy.mode == SmallMarketFeeMode::Flat && policy.flat_fee == 0 {
+            return Err(Error::InvalidFeeConfig);
         }
 
         Ok(())
@@ -1273,8 +1546,10 @@ impl FeeUtils {
     /// Transfer fees to admin
     pub fn transfer_fees_to_admin(env: &Env, admin: &Address, amount: i128) -> Result<(), Error> {
         let token_client = MarketUtils::get_token_client(env)?;
-        token_client.transfer(&env.current_contract_address(), admin, &amount);
-        Ok(())
+        ReentrancyGuard::guarded(env, || {
+            token_client.transfer(&env.current_contract_address(), admin, &amount);
+            Ok(())
+        })
     }
 
     /// Get fee statistics for a market
@@ -1690,6 +1965,29 @@ impl FeeConfigManager {
             }))
     }
 
+    /// Store the policy for markets below the fee collection threshold
+    pub fn store_small_market_fee_policy(
+        env: &Env,
+        policy: &SmallMarketFeePolicy,
+    ) -> Result<(), Error> {
+        FeeValidator::validate_small_market_fee_policy(policy)?;
+        env.storage()
+            .persistent()
+            .set(&symbol_short!("sm_fee"), policy);
+        Ok(())
+    }
+
+    /// Get the policy for markets below the fee collection threshold (defaults to `Reject`)
+    pub fn get_small_market_fee_policy(env: &Env) -> SmallMarketFeePolicy {
+        env.storage()
+            .persistent()
+            .get(&symbol_short!("sm_fee"))
+            .unwrap_or(SmallMarketFeePolicy {
+                mode: SmallMarketFeeMode::Reject,
+                flat_fee: 0,
+            })
+    }
+
     /// Reset fee configuration to defaults
     pub fn reset_to_defaults(env: &Env) -> Result<FeeConfig, Error> {
         let default_config = FeeConfig {
@@ -2022,6 +2320,127 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_collect_fees_small_market_policy() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let client = crate::PredictifyHybridClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.initialize(&admin, &None);
+        let market_id = Symbol::new(&env, "small_mkt");
+
+        let mut market = Market::new(
+            &env,
+            admin.clone(),
+            String::from_str(&env, "Test Market"),
+            soroban_sdk::vec![
+                &env,
+                String::from_str(&env, "yes"),
+                String::from_str(&env, "no"),
+            ],
+            env.ledger().timestamp() + 86400,
+            crate::types::OracleConfig::new(
+                crate::types::OracleProvider::Reflector,
+                Address::generate(&env),
+                String::from_str(&env, "BTC/USD"),
+                2_500_000,
+                String::from_str(&env, "gt"),
+            ),
+            None,
+            86400,
+            crate::types::MarketState::Resolved,
+        );
+        market.winning_outcomes = Some(soroban_sdk::vec![&env, String::from_str(&env, "yes")]);
+        market.total_staked = FEE_COLLECTION_THRESHOLD / 4;
+        market
+            .outcome_totals
+            .set(String::from_str(&env, "yes"), market.total_staked);
+        let store_market = || {
+            env.as_contract(&contract_id, || {
+                env.storage().persistent().set(&market_id, &market);
+            })
+        };
+        store_market();
+
+        // Default policy rejects sub-threshold markets
+        assert_eq!(
+            client.try_collect_fees(&admin, &market_id),
+            Err(Ok(Error::InsufficientStake))
+        );
+
+        // Flat mode without a fee is not a valid policy
+        let mut policy = SmallMarketFeePolicy {
+            mode: SmallMarketFeeMode::Flat,
+            flat_fee: 0,
+        };
+        assert_eq!(
+            client.try_set_small_market_fee_policy(&admin, &policy),
+            Err(Ok(Error::InvalidFeeConfig))
+        );
+
+        // Flat fee is capped at the fee withheld from winners' payouts
+        let withheld = market.total_staked * market.platform_fee_percentage / 100;
+        policy.flat_fee = FEE_COLLECTION_THRESHOLD;
+        client.set_small_market_fee_policy(&admin, &policy);
+        assert_eq!(client.collect_fees(&admin, &market_id), withheld);
+        assert!(client.get_market(&market_id).unwrap().fee_collected);
+
+        // Waived fees still mark the market as processed and pay winners the whole pool
+        store_market();
+        policy.mode = SmallMarketFeeMode::Waive;
+        client.set_small_market_fee_policy(&admin, &policy);
+        assert_eq!(client.get_small_market_fee_policy(), policy);
+        assert_eq!(client.collect_fees(&admin, &market_id), 0);
+        let waived = client.get_market(&market_id).unwrap();
+        assert!(waived.fee_collected);
+        assert_eq!(waived.platform_fee_percentage, 0);
+        env.as_contract(&contract_id, || {
+            assert_eq!(
+                FeeTracker::get_total_fees_collected(&env).unwrap(),
+                withheld
+            );
+        });
+    }
+
+    #[test]
+    fn test_platform_fee_pinned_at_market_creation() {
+        let test = crate::test::PredictifyTest::setup();
+        let client = crate::PredictifyHybridClient::new(&test.env, &test.contract_id);
+        let market_id = test.create_test_market();
+
+        // Raise the live platform fee after the market was created
+        test.env.as_contract(&test.contract_id, || {
+            let mut cfg = crate::config::ConfigManager::get_config(&test.env).unwrap();
+            assert_eq!(
+                FeeManager::get_platform_fee_percentage(&test.env),
+                cfg.fees.platform_fee_percentage
+            );
+            cfg.fees.platform_fee_percentage = 5;
+            crate::config::ConfigManager::store_config(&test.env, &cfg).unwrap();
+            assert_eq!(FeeManager::get_platform_fee_percentage(&test.env), 5);
+        });
+
+        // The open market keeps the schedule it was created with
+        let mut market = client.get_market(&market_id).unwrap();
+        assert_eq!(market.platform_fee_percentage, PLATFORM_FEE_PERCENTAGE);
+        market.total_staked = 1_000_000_000;
+        assert_eq!(
+            FeeCalculator::calculate_platform_fee(&market).unwrap(),
+            20_000_000
+        );
+
+        // New markets pick up the updated schedule
+        let new_market_id = test.create_test_market();
+        assert_eq!(
+            client
+                .get_market(&new_market_id)
+                .unwrap()
+                .platform_fee_percentage,
+            5
+        );
+    }
+
     #[test]
     fn test_fee_analytics_calculation() {
         let env = Env::default();
@@ -294,7 +294,7 @@ fn test_gas_vote_single_user() {
         ctx.env.storage().persistent().get::<Symbol, Market>(&market_id).unwrap()
     });
     assert_eq!(market.total_staked, 100_0000000);
-    assert_eq!(market.votes.len(), 1);
+    assert_eq!(market.voter_count, 1);
 }
 
 #[test]
@@ -321,7 +321,7 @@ fn test_gas_vote_multiple_users() {
         ctx.env.storage().persistent().get::<Symbol, Market>(&market_id).unwrap()
     });
     assert_eq!(market.total_staked, 250_0000000);
-    assert_eq!(market.votes.len(), 5);
+    assert_eq!(market.voter_count, 5);
 }
 
 #[test]
@@ -348,7 +348,7 @@ fn test_gas_tracking_does_not_alter_results() {
     // Verify: State unchanged by read operations
     assert_eq!(market_before.total_staked, market_after.total_staked);
     assert_eq!(market_before.state, market_after.state);
-    assert_eq!(market_before.votes.len(), market_after.votes.len());
+    assert_eq!(market_before.voter_count, market_after.voter_count);
 }
 
 #[test]
@@ -380,9 +380,9 @@ fn test_gas_storage_efficiency() {
     });
     
     // New market should have empty collections
-    assert_eq!(market.votes.len(), 0);
-    assert_eq!(market.stakes.len(), 0);
-    assert_eq!(market.claimed.len(), 0);
+    assert_eq!(market.voter_count, 0);
+    assert_eq!(market.outcome_totals.len(), 0);
+    assert_eq!(market.claimed_count, 0);
     assert_eq!(market.total_staked, 0);
 }
 
@@ -72,6 +72,11 @@ impl GovernanceContract {
             .set(&StorageKey::ProposalList, &empty);
     }
 
+    /// Whether governance has been initialized
+    pub fn is_initialized(env: &Env) -> bool {
+        env.storage().persistent().has(&StorageKey::Admin)
+    }
+
     /// Create a proposal. Returns the proposal id (Symbol).
     /// The contract uses ledger timestamp for start and end times.
     pub fn create_proposal(
@@ -229,6 +234,16 @@ impl GovernanceContract {
         Ok((true, String::from_str(&env, "passed")))
     }
 
+    /// Whether a proposal passed, or `None` while its voting is still open
+    pub fn proposal_passed(env: Env, proposal_id: Symbol) -> Result<Option<bool>, GovernanceError> {
+        let p = Self::get_proposal(env.clone(), proposal_id.clone())?;
+        if env.ledger().timestamp() <= p.end_time {
+            return Ok(None);
+        }
+        let (passed, _reason) = Self::validate_proposal(env, proposal_id)?;
+        Ok(Some(passed))
+    }
+
     /// Execute governance proposal. If `target` and `call_fn` are None -> treated as no-op,
     /// mark executed and emit event. If `target` is contract address and `call_fn` is present,
     /// we attempt to invoke that function on the target with no args. (Extend as needed.)
@@ -190,7 +190,7 @@ fn test_complete_market_lifecycle() {
     let market = test_suite.get_market(&market_id);
     assert_eq!(market.total_staked, 310_0000000); // 310 XLM total
     assert_eq!(market.state, MarketState::Active);
-    assert_eq!(market.votes.len(), 5);
+    assert_eq!(market.voter_count, 5);
 
     // Step 4: Advance time to market end
     test_suite.advance_time(31); // Past 30-day duration
@@ -261,8 +261,8 @@ fn test_multi_user_market_scenarios() {
     let market_1_data = test_suite.get_market(&market_1);
     let market_2_data = test_suite.get_market(&market_2);
 
-    assert_eq!(market_1_data.votes.len(), 10);
-    assert_eq!(market_2_data.votes.len(), 10);
+    assert_eq!(market_1_data.voter_count, 10);
+    assert_eq!(market_2_data.voter_count, 10);
 
     // Advance time and resolve markets
     test_suite.advance_time(31);
@@ -345,7 +345,7 @@ fn test_stress_test_multiple_markets() {
     // Verify all markets have votes
     for market_id in market_ids.iter() {
         let market = test_suite.get_market(&market_id);
-        assert_eq!(market.votes.len(), 20);
+        assert_eq!(market.voter_count, 20);
         assert!(market.total_staked > 0);
     }
 
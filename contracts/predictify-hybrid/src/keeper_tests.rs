@@ -0,0 +1,230 @@
+#![cfg(test)]
+use crate::keepers::KeeperRole;
+use crate::{test::PredictifyTest, PredictifyHybridClient};
+use soroban_sdk::{token, vec, String};
+
+#[test]
+fn test_register_and_deregister_keeper() {
+    let test_ctx = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test_ctx.env, &test_ctx.contract_id);
+    let token_client = token::Client::new(&test_ctx.env, &test_ctx.token_test.token_id);
+    let keeper = test_ctx.create_funded_user();
+    let roles = vec![&test_ctx.env, KeeperRole::Resolver, KeeperRole::Sweeper];
+
+    test_ctx.env.mock_all_auths();
+    // Below the minimum bond
+    assert!(client
+        .try_register_keeper(&keeper, &roles, &(crate::config::MIN_KEEPER_STAKE - 1))
+        .is_err());
+
+    let record = client.register_keeper(&keeper, &roles, &crate::config::MIN_KEEPER_STAKE);
+    assert!(record.active);
+    assert_eq!(record.stake, crate::config::MIN_KEEPER_STAKE);
+    assert_eq!(
+        token_client.balance(&keeper),
+        1000_0000000 - crate::config::MIN_KEEPER_STAKE
+    );
+
+    // Already registered
+    assert!(client
+        .try_register_keeper(&keeper, &roles, &crate::config::MIN_KEEPER_STAKE)
+        .is_err());
+
+    let returned = client.deregister_keeper(&keeper);
+    assert_eq!(returned, crate::config::MIN_KEEPER_STAKE);
+    assert_eq!(token_client.balance(&keeper), 1000_0000000);
+    assert!(!client.get_keeper(&keeper).unwrap().active);
+}
+
+#[test]
+fn test_slash_keeper_funds_reward_pool_and_deactivates() {
+    let test_ctx = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test_ctx.env, &test_ctx.contract_id);
+    let keeper = test_ctx.create_funded_user();
+    let stake = 2 * crate::config::MIN_KEEPER_STAKE;
+
+    test_ctx.env.mock_all_auths();
+    client.register_keeper(&keeper, &vec![&test_ctx.env, KeeperRole::TtlBumper], &stake);
+
+    // Only the admin can slash
+    assert!(client
+        .try_slash_keeper(
+            &test_ctx.user,
+            &keeper,
+            &1,
+            &String::from_str(&test_ctx.env, "spam")
+        )
+        .is_err());
+
+    let slashed = client.slash_keeper(
+        &test_ctx.admin,
+        &keeper,
+        &(crate::config::MIN_KEEPER_STAKE / 2),
+        &String::from_str(&test_ctx.env, "submitted bad data"),
+    );
+    assert_eq!(slashed, crate::config::MIN_KEEPER_STAKE / 2);
+    assert_eq!(client.get_keeper_reward_pool(), slashed);
+
+    // Still above the minimum bond
+    let record = client.get_keeper(&keeper).unwrap();
+    assert!(record.active);
+    assert_eq!(record.slashed_total, slashed);
+
+    // Slashing more than the remaining stake takes what is left and deactivates
+    let slashed = client.slash_keeper(
+        &test_ctx.admin,
+        &keeper,
+        &stake,
+        &String::from_str(&test_ctx.env, "repeat offence"),
+    );
+    assert_eq!(slashed, stake - crate::config::MIN_KEEPER_STAKE / 2);
+    let record = client.get_keeper(&keeper).unwrap();
+    assert!(!record.active);
+    assert_eq!(record.stake, 0);
+    assert_eq!(client.get_keeper_reward_pool(), stake);
+}
+
+#[test]
+fn test_bump_ttls_prioritises_unsettled_markets_and_pays_reward() {
+    use soroban_sdk::testutils::storage::Persistent as _;
+
+    let test_ctx = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test_ctx.env, &test_ctx.contract_id);
+    let keeper = test_ctx.create_funded_user();
+    let settled = test_ctx.create_test_market();
+    let live = test_ctx.create_test_market();
+
+    // Settle the first market so the live one is bumped first
+    test_ctx.env.as_contract(&test_ctx.contract_id, || {
+        let mut market: crate::types::Market =
+            test_ctx.env.storage().persistent().get(&settled).unwrap();
+        market.state = crate::types::MarketState::Resolved;
+        test_ctx.env.storage().persistent().set(&settled, &market);
+    });
+
+    test_ctx.env.mock_all_auths();
+    // Unregistered callers cannot bump
+    assert!(client.try_bump_ttls(&keeper, &1).is_err());
+
+    client.register_keeper(
+        &keeper,
+        &vec![&test_ctx.env, KeeperRole::TtlBumper],
+        &crate::config::MIN_KEEPER_STAKE,
+    );
+    client.fund_keeper_rewards(&test_ctx.admin, &1_0000000);
+    assert!(client.try_bump_ttls(&keeper, &0).is_err());
+
+    let ttl_of = |id: &soroban_sdk::Symbol| {
+        test_ctx.env.as_contract(&test_ctx.contract_id, || {
+            test_ctx.env.storage().persistent().get_ttl(id)
+        })
+    };
+    let settled_ttl = ttl_of(&settled);
+
+    assert_eq!(client.bump_ttls(&keeper, &1), 1);
+    assert!(ttl_of(&live) > settled_ttl);
+    assert_eq!(ttl_of(&settled), settled_ttl);
+
+    assert_eq!(client.bump_ttls(&keeper, &5), 1);
+    assert!(ttl_of(&settled) > settled_ttl);
+
+    // Both entries were just bumped, so nothing is due
+    assert_eq!(client.bump_ttls(&keeper, &5), 0);
+
+    let record = client.get_keeper(&keeper).unwrap();
+    assert_eq!(
+        record.rewards_earned,
+        2 * crate::config::KEEPER_TTL_BUMP_REWARD
+    );
+    assert_eq!(
+        client.get_keeper_reward_pool(),
+        1_0000000 - 2 * crate::config::KEEPER_TTL_BUMP_REWARD
+    );
+}
+
+#[test]
+fn test_publish_daily_settlement_report_once_per_completed_day() {
+    use soroban_sdk::testutils::{Ledger, LedgerInfo};
+
+    let test_ctx = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test_ctx.env, &test_ctx.contract_id);
+    let keeper = test_ctx.create_funded_user();
+    let market_id = test_ctx.create_test_market();
+
+    test_ctx.env.mock_all_auths();
+    client.vote(
+        &test_ctx.user,
+        &market_id,
+        &String::from_str(&test_ctx.env, "yes"),
+        &1_0000000,
+    );
+
+    let set_time = |timestamp: u64| {
+        test_ctx.env.ledger().set(LedgerInfo {
+            timestamp,
+            protocol_version: 22,
+            sequence_number: test_ctx.env.ledger().sequence(),
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 1,
+            min_persistent_entry_ttl: 1,
+            max_entry_ttl: 10000,
+        });
+    };
+    let market = client.get_market(&market_id).unwrap();
+    let resolved_at = market.end_time + market.dispute_window_seconds + 1;
+    set_time(resolved_at);
+    client.resolve_market_manual(
+        &test_ctx.admin,
+        &market_id,
+        &String::from_str(&test_ctx.env, "yes"),
+    );
+
+    let day = resolved_at / 86_400;
+    let stats = client.get_daily_settlement_stats(&day);
+    assert_eq!(stats.markets_resolved, 1);
+    assert_eq!(stats.volume_settled, 1_0000000);
+
+    client.register_keeper(
+        &keeper,
+        &vec![&test_ctx.env, KeeperRole::Reporter],
+        &crate::config::MIN_KEEPER_STAKE,
+    );
+    client.fund_keeper_rewards(&test_ctx.admin, &1_0000000);
+
+    // The day has not ended yet
+    assert!(client
+        .try_publish_daily_settlement_report(&keeper, &day)
+        .is_err());
+
+    set_time((day + 1) * 86_400);
+    // Only reporters can publish
+    assert!(client
+        .try_publish_daily_settlement_report(&test_ctx.user, &day)
+        .is_err());
+
+    let report = client.publish_daily_settlement_report(&keeper, &day);
+    assert_eq!(report, stats);
+    assert!(client
+        .try_publish_daily_settlement_report(&keeper, &day)
+        .is_err());
+
+    let event = test_ctx.env.as_contract(&test_ctx.contract_id, || {
+        test_ctx
+            .env
+            .storage()
+            .persistent()
+            .get::<soroban_sdk::Symbol, crate::events::DailySettlementReportEvent>(
+                &soroban_sdk::symbol_short!("day_rpt"),
+            )
+            .unwrap()
+    });
+    assert_eq!(event.day, day);
+    assert_eq!(event.markets_resolved, 1);
+    assert_eq!(event.reporter, keeper);
+    assert_eq!(event.reward, crate::config::KEEPER_REPORT_REWARD);
+    assert_eq!(
+        client.get_keeper(&keeper).unwrap().rewards_earned,
+        crate::config::KEEPER_REPORT_REWARD
+    );
+}
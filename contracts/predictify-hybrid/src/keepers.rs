@@ -0,0 +1,520 @@
+use soroban_sdk::{
+    contracttype, symbol_short, Address, Env, Map, String, Symbol, TryFromVal, Val, Vec,
+};
+
+use crate::bets::BetUtils;
+use crate::config;
+use crate::events::EventEmitter;
+use crate::market_id_generator::MarketIdGenerator;
+use crate::monitoring::{AlertSeverity, ContractMonitor, MonitoringAlertType, MonitoringUtils};
+use crate::reentrancy_guard::ReentrancyGuard;
+use crate::statistics::StatisticsManager;
+use crate::storage::{StorageIntegrityResult, StorageOptimizer};
+use crate::types::{DailySettlementStats, Market, MarketState};
+use crate::Error;
+
+// ===== KEEPER TYPES =====
+
+/// Job a keeper is registered to perform.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum KeeperRole {
+    /// Triggers oracle resolution of ended markets
+    Resolver,
+    /// Extends storage TTLs so live state is never archived
+    TtlBumper,
+    /// Pushes winnings and sweeps settled markets
+    Sweeper,
+    /// Publishes the daily settlement report
+    Reporter,
+    /// Runs the rolling storage integrity audit
+    Auditor,
+}
+
+/// Registry entry of a staked keeper.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Keeper {
+    pub address: Address,
+    pub roles: Vec<KeeperRole>,
+    /// Stake currently bonded (reduced by slashing)
+    pub stake: i128,
+    pub registered_at: u64,
+    /// Cumulative amount slashed
+    pub slashed_total: i128,
+    /// Cumulative rewards paid
+    pub rewards_earned: i128,
+    /// Inactive keepers (deregistered or slashed below the minimum) cannot act or earn
+    pub active: bool,
+}
+
+/// Progress of the rolling storage integrity audit.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StorageAuditState {
+    /// Index into the market ID registry the next run starts from
+    pub cursor: u32,
+    /// Corrupted markets found so far in the current pass
+    pub corrupted_in_pass: u32,
+    /// When the last pass over every market completed without corruption (0 = never)
+    pub last_clean_at: u64,
+    /// When the audit last ran (0 = never)
+    pub last_run_at: u64,
+}
+
+// ===== KEEPER STORAGE =====
+
+pub struct KeeperStorage;
+impl KeeperStorage {
+    #[inline(always)]
+    fn registry_key(env: &Env) -> Symbol {
+        Symbol::new(env, "keeper_registry")
+    }
+    #[inline(always)]
+    fn reward_pool_key(env: &Env) -> Symbol {
+        Symbol::new(env, "keeper_reward_pool")
+    }
+    #[inline(always)]
+    fn ttl_bumps_key(env: &Env) -> Symbol {
+        Symbol::new(env, "keeper_ttl_bumps")
+    }
+    #[inline(always)]
+    fn audit_state_key(env: &Env) -> Symbol {
+        Symbol::new(env, "keeper_audit_state")
+    }
+
+    pub fn load(env: &Env, keeper: &Address) -> Option<Keeper> {
+        let registry: Map<Address, Keeper> = env
+            .storage()
+            .persistent()
+            .get(&Self::registry_key(env))
+            .unwrap_or(Map::new(env));
+        registry.get(keeper.clone())
+    }
+
+    pub fn save(env: &Env, keeper: &Keeper) {
+        let mut registry: Map<Address, Keeper> = env
+            .storage()
+            .persistent()
+            .get(&Self::registry_key(env))
+            .unwrap_or(Map::new(env));
+        registry.set(keeper.address.clone(), keeper.clone());
+        env.storage()
+            .persistent()
+            .set(&Self::registry_key(env), &registry);
+    }
+
+    pub fn reward_pool(env: &Env) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&Self::reward_pool_key(env))
+            .unwrap_or(0)
+    }
+
+    pub fn set_reward_pool(env: &Env, amount: i128) {
+        env.storage()
+            .persistent()
+            .set(&Self::reward_pool_key(env), &amount);
+    }
+
+    /// Ledger sequence at which each entry was last bumped by a keeper
+    pub fn ttl_bumps(env: &Env) -> Map<Symbol, u32> {
+        env.storage()
+            .persistent()
+            .get(&Self::ttl_bumps_key(env))
+            .unwrap_or(Map::new(env))
+    }
+
+    pub fn set_ttl_bumps(env: &Env, bumps: &Map<Symbol, u32>) {
+        env.storage()
+            .persistent()
+            .set(&Self::ttl_bumps_key(env), bumps);
+    }
+
+    pub fn audit_state(env: &Env) -> StorageAuditState {
+        env.storage()
+            .persistent()
+            .get(&Self::audit_state_key(env))
+            .unwrap_or(StorageAuditState {
+                cursor: 0,
+                corrupted_in_pass: 0,
+                last_clean_at: 0,
+                last_run_at: 0,
+            })
+    }
+
+    pub fn set_audit_state(env: &Env, state: &StorageAuditState) {
+        env.storage()
+            .persistent()
+            .set(&Self::audit_state_key(env), state);
+    }
+}
+
+// ===== KEEPER REGISTRY =====
+
+/// Registry of staked keepers.
+///
+/// Keepers bond at least `config::MIN_KEEPER_STAKE` to register for one or
+/// more roles. Governance (the admin) can slash misbehaving keepers; slashed
+/// stake is added to the keeper reward pool, which pays out only to active
+/// registered keepers.
+pub struct KeeperRegistry;
+
+impl KeeperRegistry {
+    /// Register (or re-register) a keeper, bonding `stake` from the keeper.
+    pub fn register(
+        env: &Env,
+        keeper: &Address,
+        roles: Vec<KeeperRole>,
+        stake: i128,
+    ) -> Result<Keeper, Error> {
+        if roles.is_empty() {
+            return Err(Error::InvalidInput);
+        }
+        if stake < config::MIN_KEEPER_STAKE {
+            return Err(Error::InsufficientStake);
+        }
+
+        let existing = KeeperStorage::load(env, keeper);
+        if existing.as_ref().map(|k| k.active).unwrap_or(false) {
+            return Err(Error::InvalidState);
+        }
+
+        BetUtils::lock_funds(env, keeper, stake)?;
+
+        // Slashing history is kept across re-registration
+        let (slashed_total, rewards_earned) = existing
+            .map(|k| (k.slashed_total, k.rewards_earned))
+            .unwrap_or((0, 0));
+        let record = Keeper {
+            address: keeper.clone(),
+            roles,
+            stake,
+            registered_at: env.ledger().timestamp(),
+            slashed_total,
+            rewards_earned,
+            active: true,
+        };
+        KeeperStorage::save(env, &record);
+
+        EventEmitter::emit_keeper_registered(env, keeper, &record.roles, stake);
+        Ok(record)
+    }
+
+    /// Deregister a keeper and return its remaining stake.
+    pub fn deregister(env: &Env, keeper: &Address) -> Result<i128, Error> {
+        let mut record = KeeperStorage::load(env, keeper).ok_or(Error::Unauthorized)?;
+        if !record.active && record.stake == 0 {
+            return Err(Error::InvalidState);
+        }
+
+        let returned = record.stake;
+        record.stake = 0;
+        record.active = false;
+        KeeperStorage::save(env, &record);
+
+        if returned > 0 {
+            ReentrancyGuard::guarded(env, || BetUtils::unlock_funds(env, keeper, returned))?;
+        }
+
+        EventEmitter::emit_keeper_deregistered(env, keeper, returned);
+        Ok(returned)
+    }
+
+    /// Slash up to `amount` of a keeper's stake into the reward pool.
+    ///
+    /// A keeper left below the minimum stake is deactivated. Returns the
+    /// amount actually slashed.
+    pub fn slash(
+        env: &Env,
+        admin: &Address,
+        keeper: &Address,
+        amount: i128,
+        reason: String,
+    ) -> Result<i128, Error> {
+        if amount <= 0 {
+            return Err(Error::InvalidInput);
+        }
+        let mut record = KeeperStorage::load(env, keeper).ok_or(Error::Unauthorized)?;
+        if record.stake == 0 {
+            return Err(Error::InsufficientStake);
+        }
+
+        let slashed = amount.min(record.stake);
+        record.stake -= slashed;
+        record.slashed_total += slashed;
+        if record.stake < config::MIN_KEEPER_STAKE {
+            record.active = false;
+        }
+        KeeperStorage::save(env, &record);
+        KeeperStorage::set_reward_pool(env, KeeperStorage::reward_pool(env) + slashed);
+
+        EventEmitter::emit_keeper_slashed(env, keeper, admin, slashed, record.stake, &reason);
+        Ok(slashed)
+    }
+
+    /// Add funds from `funder` to the keeper reward pool.
+    pub fn fund_rewards(env: &Env, funder: &Address, amount: i128) -> Result<i128, Error> {
+        if amount <= 0 {
+            return Err(Error::InvalidInput);
+        }
+        BetUtils::lock_funds(env, funder, amount)?;
+        let pool = KeeperStorage::reward_pool(env) + amount;
+        KeeperStorage::set_reward_pool(env, pool);
+        Ok(pool)
+    }
+
+    /// Pay a keeper reward for a job, capped by the reward pool balance.
+    ///
+    /// Only active keepers registered for `role` can be paid. Returns the
+    /// amount paid (0 when the pool is empty).
+    pub fn pay_reward(
+        env: &Env,
+        keeper: &Address,
+        role: KeeperRole,
+        amount: i128,
+    ) -> Result<i128, Error> {
+        Self::require_keeper(env, keeper, role)?;
+
+        let pool = KeeperStorage::reward_pool(env);
+        let paid = amount.min(pool);
+        if paid <= 0 {
+            return Ok(0);
+        }
+
+        KeeperStorage::set_reward_pool(env, pool - paid);
+        let mut record = KeeperStorage::load(env, keeper).ok_or(Error::Unauthorized)?;
+        record.rewards_earned += paid;
+        KeeperStorage::save(env, &record);
+
+        ReentrancyGuard::guarded(env, || BetUtils::unlock_funds(env, keeper, paid))?;
+        EventEmitter::emit_keeper_rewarded(env, keeper, role, paid);
+        Ok(paid)
+    }
+
+    /// Whether `keeper` is active and registered for `role`.
+    pub fn is_keeper(env: &Env, keeper: &Address, role: KeeperRole) -> bool {
+        KeeperStorage::load(env, keeper)
+            .map(|k| k.active && k.roles.contains(role))
+            .unwrap_or(false)
+    }
+
+    /// Require `keeper` to be active and registered for `role`.
+    pub fn require_keeper(env: &Env, keeper: &Address, role: KeeperRole) -> Result<(), Error> {
+        if !Self::is_keeper(env, keeper, role) {
+            return Err(Error::Unauthorized);
+        }
+        Ok(())
+    }
+}
+
+// ===== KEEPER JOBS =====
+
+/// Keeper job that keeps market state from being archived.
+pub struct TtlKeeper;
+
+impl TtlKeeper {
+    /// Extend the TTLs of up to `limit` entries closest to expiry.
+    ///
+    /// Entries are ranked by the ledger they were last bumped at (never-bumped
+    /// entries first), with unsettled markets ahead of everything else.
+    /// Entries bumped within `TTL_BUMP_MIN_INTERVAL_LEDGERS` are skipped so
+    /// rewards cannot be farmed. Pays `KEEPER_TTL_BUMP_REWARD` per bump from
+    /// the keeper reward pool. Returns `(bumped, reward_paid)`.
+    pub fn bump_ttls(env: &Env, keeper: &Address, limit: u32) -> Result<(u32, i128), Error> {
+        KeeperRegistry::require_keeper(env, keeper, KeeperRole::TtlBumper)?;
+        if limit == 0 || limit > config::MAX_TTL_BUMP_BATCH {
+            return Err(Error::InvalidInput);
+        }
+
+        let current = env.ledger().sequence();
+        let mut bumps = KeeperStorage::ttl_bumps(env);
+
+        // Collect due entries as (unsettled, staleness rank, id)
+        let mut due: Vec<(bool, u32, Symbol)> = Vec::new(env);
+        for entry_id in MarketIdGenerator::get_all_market_ids(env).iter() {
+            let last_bumped = bumps.get(entry_id.clone());
+            if let Some(ledger) = last_bumped {
+                if current.saturating_sub(ledger) < config::TTL_BUMP_MIN_INTERVAL_LEDGERS {
+                    continue;
+                }
+            }
+            let Some(raw) = env.storage().persistent().get::<_, Val>(&entry_id) else {
+                continue;
+            };
+            // Events share the ID space with markets, so decode leniently
+            let unsettled = Market::try_from_val(env, &raw)
+                .map(|market| {
+                    matches!(
+                        market.state,
+                        MarketState::Active | MarketState::Ended | MarketState::Disputed
+                    )
+                })
+                .unwrap_or(false);
+            // Never-bumped entries rank ahead of any bumped one
+            let rank = last_bumped.map(|ledger| ledger + 1).unwrap_or(0);
+            due.push_back((unsettled, rank, entry_id));
+        }
+
+        let mut bumped = 0u32;
+        while bumped < limit && !due.is_empty() {
+            let mut best = 0u32;
+            for i in 1..due.len() {
+                let (unsettled, rank, _) = due.get(i).unwrap();
+                let (best_unsettled, best_rank, _) = due.get(best).unwrap();
+                if (unsettled && !best_unsettled)
+                    || (unsettled == best_unsettled && rank < best_rank)
+                {
+                    best = i;
+                }
+            }
+            let (_, _, entry_id) = due.get(best).unwrap();
+            due.remove(best);
+
+            env.storage().persistent().extend_ttl(
+                &entry_id,
+                config::MARKET_TTL_THRESHOLD_LEDGERS,
+                config::MARKET_TTL_EXTEND_TO_LEDGERS,
+            );
+            bumps.set(entry_id, current);
+            bumped += 1;
+        }
+
+        if bumped == 0 {
+            return Ok((0, 0));
+        }
+        KeeperStorage::set_ttl_bumps(env, &bumps);
+
+        let reward = KeeperRegistry::pay_reward(
+            env,
+            keeper,
+            KeeperRole::TtlBumper,
+            config::KEEPER_TTL_BUMP_REWARD * bumped as i128,
+        )?;
+        EventEmitter::emit_ttls_bumped(env, keeper, bumped, reward);
+        Ok((bumped, reward))
+    }
+}
+
+/// Keeper job that publishes the protocol-level daily settlement report.
+pub struct SettlementReporter;
+
+impl SettlementReporter {
+    /// Emit the aggregated settlement report for a completed `day`.
+    ///
+    /// Each day is reported once, after it has ended, so lightweight
+    /// consumers can follow settlement activity from one event per day.
+    /// Pays `KEEPER_REPORT_REWARD` from the keeper reward pool.
+    pub fn publish_daily_report(
+        env: &Env,
+        keeper: &Address,
+        day: u64,
+    ) -> Result<DailySettlementStats, Error> {
+        KeeperRegistry::require_keeper(env, keeper, KeeperRole::Reporter)?;
+        if day >= StatisticsManager::current_day(env) {
+            return Err(Error::InvalidState);
+        }
+        let published_key = (symbol_short!("rpt_pub"), day);
+        if env.storage().persistent().has(&published_key) {
+            return Err(Error::InvalidState);
+        }
+        env.storage().persistent().set(&published_key, &true);
+
+        let report = StatisticsManager::get_daily_stats(env, day);
+        let reward = KeeperRegistry::pay_reward(
+            env,
+            keeper,
+            KeeperRole::Reporter,
+            config::KEEPER_REPORT_REWARD,
+        )?;
+        EventEmitter::emit_daily_settlement_report(env, keeper, &report, reward);
+        Ok(report)
+    }
+}
+
+/// Keeper job that audits stored markets for corruption, a few at a time.
+pub struct StorageAuditor;
+
+impl StorageAuditor {
+    /// Audit up to `limit` markets, resuming from the persisted cursor.
+    ///
+    /// Each market is checked with
+    /// `StorageOptimizer::audit_market_integrity`. The first corruption found
+    /// in a pass raises a critical `DataIntegrity` monitoring alert; later
+    /// ones in the same pass are only reported in the audit event. When the
+    /// cursor reaches the end of the registry the pass ends, `last_clean_at`
+    /// is set if nothing was found, and the next run starts over. Returns the
+    /// IDs of the corrupted markets found by this run.
+    pub fn run(env: &Env, keeper: &Address, limit: u32) -> Result<Vec<Symbol>, Error> {
+        KeeperRegistry::require_keeper(env, keeper, KeeperRole::Auditor)?;
+        if limit == 0 || limit > config::MAX_STORAGE_AUDIT_BATCH {
+            return Err(Error::InvalidInput);
+        }
+
+        let ids = MarketIdGenerator::get_all_market_ids(env);
+        let mut state = KeeperStorage::audit_state(env);
+        if state.cursor >= ids.len() {
+            state.cursor = 0;
+        }
+
+        let mut checked = 0u32;
+        let mut corrupted = Vec::new(env);
+        while checked < limit && state.cursor < ids.len() {
+            let entry_id = ids.get(state.cursor).unwrap();
+            state.cursor += 1;
+            let Some(raw) = env.storage().persistent().get::<_, Val>(&entry_id) else {
+                continue;
+            };
+            // Events share the ID space with markets, so skip anything else
+            if Market::try_from_val(env, &raw).is_err() {
+                continue;
+            }
+            checked += 1;
+
+            let result = StorageOptimizer::audit_market_integrity(env, &entry_id);
+            if result.is_valid {
+                continue;
+            }
+            if state.corrupted_in_pass == 0 {
+                Self::raise_alert(env, &entry_id, &result)?;
+            }
+            state.corrupted_in_pass += 1;
+            corrupted.push_back(entry_id);
+        }
+
+        let now = env.ledger().timestamp();
+        let pass_complete = state.cursor >= ids.len();
+        if pass_complete {
+            if state.corrupted_in_pass == 0 {
+                state.last_clean_at = now;
+            }
+            state.cursor = 0;
+            state.corrupted_in_pass = 0;
+        }
+        state.last_run_at = now;
+        KeeperStorage::set_audit_state(env, &state);
+
+        EventEmitter::emit_storage_audited(env, keeper, checked, &corrupted, pass_complete);
+        Ok(corrupted)
+    }
+
+    fn raise_alert(
+        env: &Env,
+        market_id: &Symbol,
+        result: &StorageIntegrityResult,
+    ) -> Result<(), Error> {
+        let reason = result
+            .errors
+            .first()
+            .unwrap_or(String::from_str(env, "Integrity check failed"));
+        let alert = MonitoringUtils::create_alert(
+            env,
+            MonitoringAlertType::DataIntegrity,
+            AlertSeverity::Critical,
+            String::from_str(env, "Storage corruption detected"),
+            reason,
+            String::from_str(env, &alloc::format!("{:?}", market_id)),
+        );
+        ContractMonitor::emit_monitoring_alert(env, alert)
+    }
+}
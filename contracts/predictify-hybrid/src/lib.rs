@@ -14,11 +14,17 @@ static ALLOC: wee_alloc::WeeAlloc = wee_alloc::WeeAlloc::INIT;
 
 // Module declarations - all modules enabled
 mod admin;
+mod amm;
+mod announcements;
+mod api;
 mod balances;
 mod batch_operations;
 mod bets;
+mod bounty;
 mod circuit_breaker;
+mod commit_reveal;
 mod config;
+mod council;
 mod disputes;
 mod edge_cases;
 pub mod errors;
@@ -29,26 +35,36 @@ mod fees;
 pub mod gas;
 mod governance;
 mod graceful_degradation;
+mod keepers;
+mod market_access;
 mod market_analytics;
 mod market_id_generator;
 mod markets;
 mod monitoring;
+mod nonces;
 mod oracles;
 mod performance_benchmarks;
+mod position_tokens;
 mod queries;
 mod rate_limiter;
 mod recovery;
 mod reentrancy_guard;
 mod resolution;
+mod rewards;
+mod simulation;
 mod statistics;
 mod storage;
+mod tickets;
+mod treasury;
 mod types;
 mod upgrade_manager;
 mod utils;
 mod validation;
 mod validation_tests;
 mod versioning;
+mod vesting;
 mod voting;
+mod watchlist;
 // THis is the band protocol wasm std_reference.wasm
 mod bandprotocol {
     soroban_sdk::contractimport!(file = "./std_reference.wasm");
@@ -70,6 +86,9 @@ mod integration_test;
 #[cfg(test)]
 mod recovery_tests;
 
+#[cfg(test)]
+mod keeper_tests;
+
 #[cfg(test)]
 mod property_based_tests;
 
@@ -110,6 +129,9 @@ mod unclaimed_winnings_timeout_tests;
 #[cfg(test)]
 mod metadata_validation_tests;
 
+#[cfg(test)]
+mod reentrancy_tests;
+
 // Re-export commonly used items
 use admin::{AdminAnalyticsResult, AdminInitializer, AdminManager, AdminPermission, AdminRole};
 pub use errors::Error;
@@ -124,9 +146,10 @@ use crate::graceful_degradation::{OracleBackup, OracleHealth};
 use crate::market_id_generator::MarketIdGenerator;
 use crate::reentrancy_guard::ReentrancyGuard;
 use crate::resolution::OracleResolution;
+use crate::storage::{VoteEntry, VoteLedger};
 use alloc::format;
 use soroban_sdk::{
-    contract, contractimpl, panic_with_error, Address, Env, Map, String, Symbol, Vec,
+    contract, contractimpl, panic_with_error, Address, BytesN, Env, Map, String, Symbol, Vec,
 };
 
 #[contract]
@@ -138,6 +161,9 @@ const GLOBAL_CLAIM_PERIOD_KEY: &str = "claim_timeout";
 const MARKET_CLAIM_PERIODS_KEY: &str = "claim_overrides";
 const TREASURY_STORAGE_KEY: &str = "Treasury";
 const GLOBAL_MIN_POOL_SIZE_KEY: &str = "global_min_pool";
+const ZERO_WINNER_POLICY_KEY: &str = "zero_win_policy";
+const ZERO_WINNER_SETTLEMENTS_KEY: &str = "zero_win_settle";
+const DUPLICATE_MARKET_POLICY_KEY: &str = "dup_mkt_policy";
 
 #[contractimpl]
 impl PredictifyHybrid {
@@ -359,6 +385,48 @@ impl PredictifyHybrid {
         EventEmitter::emit_treasury_updated(&env, &admin, &treasury);
     }
 
+    /// Treasury balance and cumulative inflows (platform fees, slashed
+    /// creation bonds, slashed dispute stakes) and withdrawals.
+    pub fn get_treasury_balance(env: Env) -> treasury::TreasuryBalance {
+        treasury::TreasuryManager::get_balance(&env)
+    }
+
+    /// Proposes a treasury withdrawal to an external address (admin only).
+    ///
+    /// The withdrawal can be executed once
+    /// `TREASURY_WITHDRAWAL_TIMELOCK_SECONDS` have passed.
+    pub fn propose_treasury_withdrawal(
+        env: Env,
+        admin: Address,
+        to: Address,
+        amount: i128,
+    ) -> Result<treasury::TreasuryWithdrawal, Error> {
+        admin.require_auth();
+        treasury::TreasuryManager::propose_withdrawal(&env, &admin, &to, amount)
+    }
+
+    /// Executes a proposed treasury withdrawal after its timelock (admin only).
+    pub fn execute_treasury_withdrawal(env: Env, admin: Address, id: u32) -> Result<i128, Error> {
+        admin.require_auth();
+        treasury::TreasuryManager::execute_withdrawal(&env, &admin, id)
+    }
+
+    /// Cancels a pending treasury withdrawal (admin only).
+    pub fn cancel_treasury_withdrawal(env: Env, admin: Address, id: u32) -> Result<(), Error> {
+        admin.require_auth();
+        treasury::TreasuryManager::cancel_withdrawal(&env, &admin, id)
+    }
+
+    /// Returns a proposed treasury withdrawal.
+    pub fn get_treasury_withdrawal(env: Env, id: u32) -> Option<treasury::TreasuryWithdrawal> {
+        treasury::TreasuryManager::get_withdrawal(&env, id)
+    }
+
+    /// Returns executed treasury withdrawals, oldest first.
+    pub fn get_treasury_withdrawal_history(env: Env) -> Vec<treasury::TreasuryWithdrawal> {
+        treasury::TreasuryManager::get_history(&env)
+    }
+
     /// Returns current treasury address if configured.
     pub fn get_treasury(env: Env) -> Option<Address> {
         env.storage()
@@ -366,6 +434,98 @@ impl PredictifyHybrid {
             .get(&Symbol::new(&env, TREASURY_STORAGE_KEY))
     }
 
+    /// Get the active contract configuration, including fee rates and
+    /// market limits, so integrators can verify them before interacting.
+    pub fn get_contract_config(env: Env) -> Result<config::ContractConfig, Error> {
+        config::ConfigManager::get_config(&env)
+    }
+
+    /// Page through the configuration change history, oldest first.
+    ///
+    /// Only the most recent 100 changes are retained. `limit` must be
+    /// between 1 and `MAX_QUERY_PAGE_SIZE`.
+    pub fn get_config_history(
+        env: Env,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<config::ConfigUpdateRecord>, Error> {
+        config::ConfigManager::get_configuration_history_page(&env, offset, limit)
+    }
+
+    /// Sets where the pool goes when a market resolves with no winning stake.
+    ///
+    /// Admin-only. Applies to markets settled after the update.
+    pub fn set_zero_winner_policy(env: Env, admin: Address, policy: ZeroWinnerPolicy) {
+        admin.require_auth();
+
+        let stored_admin: Address = env
+            .storage()
+            .persistent()
+            .get(&Symbol::new(&env, "Admin"))
+            .unwrap_or_else(|| panic_with_error!(env, Error::Unauthorized));
+
+        if admin != stored_admin {
+            panic_with_error!(env, Error::Unauthorized);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&Symbol::new(&env, ZERO_WINNER_POLICY_KEY), &policy);
+
+        EventEmitter::emit_zero_winner_policy_updated(&env, &admin, &policy);
+    }
+
+    /// Returns the zero-winner policy (defaults to refunding stakes minus fee).
+    pub fn get_zero_winner_policy(env: Env) -> ZeroWinnerPolicy {
+        env.storage()
+            .persistent()
+            .get(&Symbol::new(&env, ZERO_WINNER_POLICY_KEY))
+            .unwrap_or(ZeroWinnerPolicy::RefundMinusFee)
+    }
+
+    /// Sets how market creation treats near-duplicates of active markets.
+    ///
+    /// Admin-only. Duplicates share a normalized question, oracle feed and
+    /// threshold, and end within a day of each other.
+    pub fn set_duplicate_market_policy(env: Env, admin: Address, policy: DuplicateMarketPolicy) {
+        admin.require_auth();
+
+        let stored_admin: Address = env
+            .storage()
+            .persistent()
+            .get(&Symbol::new(&env, "Admin"))
+            .unwrap_or_else(|| panic_with_error!(env, Error::Unauthorized));
+
+        if admin != stored_admin {
+            panic_with_error!(env, Error::Unauthorized);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&Symbol::new(&env, DUPLICATE_MARKET_POLICY_KEY), &policy);
+
+        EventEmitter::emit_duplicate_market_policy_updated(&env, &admin, &policy);
+    }
+
+    /// Returns the duplicate market policy (defaults to warn-only).
+    pub fn get_duplicate_market_policy(env: Env) -> DuplicateMarketPolicy {
+        env.storage()
+            .persistent()
+            .get(&Symbol::new(&env, DUPLICATE_MARKET_POLICY_KEY))
+            .unwrap_or(DuplicateMarketPolicy::WarnOnly)
+    }
+
+    /// Returns the settlement record of a zero-winner market, if it has been settled.
+    pub fn get_zero_winner_settlement(env: Env, market_id: Symbol) -> Option<ZeroWinnerSettlement> {
+        let settlements: Map<Symbol, ZeroWinnerSettlement> = env
+            .storage()
+            .persistent()
+            .get(&Symbol::new(&env, ZERO_WINNER_SETTLEMENTS_KEY))
+            .unwrap_or(Map::new(&env));
+
+        settlements.get(market_id)
+    }
+
     /// Deposits funds into the user's balance.
     ///
     /// # Parameters
@@ -379,6 +539,7 @@ impl PredictifyHybrid {
         asset: ReflectorAsset,
         amount: i128,
     ) -> Result<Balance, Error> {
+        ReentrancyGuard::check_reentrancy_state(&env).map_err(|_| Error::InvalidState)?;
         balances::BalanceManager::deposit(&env, user, asset, amount)
     }
 
@@ -395,6 +556,7 @@ impl PredictifyHybrid {
         asset: ReflectorAsset,
         amount: i128,
     ) -> Result<Balance, Error> {
+        ReentrancyGuard::check_reentrancy_state(&env).map_err(|_| Error::InvalidState)?;
         balances::BalanceManager::withdraw(&env, user, asset, amount)
     }
 
@@ -410,6 +572,9 @@ impl PredictifyHybrid {
 
     /// Creates a new prediction market with specified parameters and oracle configuration.
     ///
+    /// **Deprecated since API v2:** use `create_market_v2`, which takes its
+    /// settings as one `CreateMarketParams` struct. See `get_api_versions`.
+    ///
     /// This function allows authorized administrators to create prediction markets
     /// with custom questions, possible outcomes, duration, and oracle integration.
     /// Each market gets a unique identifier and is stored in persistent contract storage.
@@ -524,6 +689,252 @@ impl PredictifyHybrid {
         bet_deadline_mins_before_end: Option<u32>,
         dispute_window_seconds: Option<u64>,
     ) -> Symbol {
+        let (has_fallback, fallback_oracle_config) = match fallback_oracle_config {
+            Some(config) => (true, config),
+            None => (false, OracleConfig::none_sentinel(&env)),
+        };
+        let params = CreateMarketParams {
+            question,
+            outcomes,
+            duration_days,
+            oracle_config,
+            has_fallback,
+            fallback_oracle_config,
+            resolution_timeout,
+            min_pool_size,
+            bet_deadline_mins_before_end,
+            dispute_window_seconds,
+            category: None,
+            tags: Vec::new(&env),
+        };
+        Self::create_market_from_params(env, admin, params)
+    }
+
+    /// Creates a market from a single `CreateMarketParams` struct (admin only).
+    ///
+    /// Behaves like `create_market` but takes its settings as one struct,
+    /// usually built with `MarketBuilder`, so optional fields such as the
+    /// category and tags can be set at creation and new ones added without
+    /// breaking the signature.
+    ///
+    /// # Panics
+    ///
+    /// Everything `create_market` panics on, plus `Error::InvalidInput` for
+    /// an invalid category or tag list.
+    pub fn create_market_v2(env: Env, admin: Address, params: CreateMarketParams) -> Symbol {
+        Self::create_market_from_params(env, admin, params)
+    }
+
+    /// Creates a scalar market asking for a number, such as the BTC price at expiry (admin only).
+    ///
+    /// `range` is split into equal buckets that become the market's outcomes
+    /// (labelled `"<from>-<to>"`). At resolution `resolve_scalar_market` reads
+    /// `oracle_config`'s feed; its `threshold` and `comparison` are not used.
+    /// Positions in buckets near the value share the pool, the closest
+    /// bucket weighing most.
+    ///
+    /// # Panics
+    ///
+    /// Everything `create_market` panics on, plus `Error::InvalidOutcomes` or
+    /// `Error::InvalidInput` for an invalid range.
+    pub fn create_scalar_market(
+        env: Env,
+        admin: Address,
+        params: CreateScalarMarketParams,
+    ) -> Symbol {
+        let CreateScalarMarketParams {
+            question,
+            range,
+            duration_days,
+            oracle_config,
+            has_fallback,
+            fallback_oracle_config,
+            resolution_timeout,
+        } = params;
+        if let Err(e) = range.validate() {
+            panic_with_error!(env, e);
+        }
+        let market_params = CreateMarketParams {
+            question,
+            outcomes: range.bucket_labels(&env),
+            duration_days,
+            oracle_config,
+            has_fallback,
+            fallback_oracle_config,
+            resolution_timeout,
+            min_pool_size: None,
+            bet_deadline_mins_before_end: None,
+            dispute_window_seconds: None,
+            category: None,
+            tags: Vec::new(&env),
+        };
+        let market_id = Self::create_market_from_params(env.clone(), admin, market_params);
+
+        let mut market = markets::MarketStateManager::get_market(&env, &market_id)
+            .unwrap_or_else(|e| panic_with_error!(env, e));
+        market.scalar_range = range;
+        markets::MarketStateManager::update_market(&env, &market_id, &market);
+        market_id
+    }
+
+    /// Creates a market priced by an LMSR automated market maker (admin only).
+    ///
+    /// Instead of pooling stakes, traders buy and sell outcome shares from the
+    /// contract with `buy_shares` / `sell_shares`, and each winning share
+    /// redeems for one token unit. `liquidity` is the LMSR `b` parameter; the
+    /// admin escrows `b * ln(outcomes)` as the maker's subsidy, and whatever
+    /// is left after settlement is returned by `settle_amm_pool`.
+    ///
+    /// # Panics
+    ///
+    /// Everything `create_market` panics on, plus `Error::InvalidInput` if
+    /// `liquidity` is outside the configured range.
+    pub fn create_amm_market(
+        env: Env,
+        admin: Address,
+        question: String,
+        outcomes: Vec<String>,
+        duration_days: u32,
+        oracle_config: OracleConfig,
+        liquidity: i128,
+    ) -> Symbol {
+        let market_id = Self::create_market(
+            env.clone(),
+            admin.clone(),
+            question,
+            outcomes,
+            duration_days,
+            oracle_config,
+            None,
+            0,
+            None,
+            None,
+            None,
+        );
+        if let Err(e) = amm::AmmManager::open(&env, &market_id, &admin, liquidity) {
+            panic_with_error!(env, e);
+        }
+        market_id
+    }
+
+    /// Buys `shares` of `outcome` on an AMM market at the current LMSR price.
+    ///
+    /// Returns the amount charged, which may not exceed `max_cost`.
+    ///
+    /// # Errors
+    ///
+    /// * `InvalidState` - Not an AMM market, or the contract is locked
+    /// * `MarketClosed` - Market is no longer trading
+    /// * `InvalidOutcome` - `outcome` is not one of the market's outcomes
+    /// * `InvalidInput` - `shares` is not positive or the cost exceeds `max_cost`
+    pub fn buy_shares(
+        env: Env,
+        user: Address,
+        market_id: Symbol,
+        outcome: String,
+        shares: i128,
+        max_cost: i128,
+    ) -> Result<i128, Error> {
+        user.require_auth();
+        amm::AmmManager::buy(&env, &user, &market_id, &outcome, shares, max_cost)
+    }
+
+    /// Sells `shares` of `outcome` back to an AMM market's maker.
+    ///
+    /// Returns the proceeds, which must be at least `min_proceeds`.
+    ///
+    /// # Errors
+    ///
+    /// * `InsufficientBalance` - `user` holds fewer shares
+    /// * `InvalidInput` - `shares` is not positive or proceeds fall below `min_proceeds`
+    /// * Otherwise as `buy_shares`
+    pub fn sell_shares(
+        env: Env,
+        user: Address,
+        market_id: Symbol,
+        outcome: String,
+        shares: i128,
+        min_proceeds: i128,
+    ) -> Result<i128, Error> {
+        user.require_auth();
+        amm::AmmManager::sell(&env, &user, &market_id, &outcome, shares, min_proceeds)
+    }
+
+    /// Quotes changing `outcome`'s outstanding shares by `shares` on an AMM
+    /// market: the cost of a buy (positive) or minus the proceeds of a sell
+    /// (negative `shares`).
+    pub fn quote_shares(
+        env: Env,
+        market_id: Symbol,
+        outcome: String,
+        shares: i128,
+    ) -> Result<i128, Error> {
+        amm::AmmManager::quote(&env, &market_id, &outcome, shares)
+    }
+
+    /// Current AMM price of each outcome in basis points of one token unit,
+    /// in market outcome order.
+    pub fn get_amm_prices(env: Env, market_id: Symbol) -> Result<Vec<i128>, Error> {
+        amm::AmmManager::prices(&env, &market_id)
+    }
+
+    /// LMSR pool state, or `None` if the market is parimutuel
+    pub fn get_amm_pool(env: Env, market_id: Symbol) -> Option<amm::AmmPool> {
+        amm::AmmManager::get_pool(&env, &market_id)
+    }
+
+    /// A trader's share holdings on an AMM market
+    pub fn get_amm_position(
+        env: Env,
+        market_id: Symbol,
+        user: Address,
+    ) -> Option<amm::AmmPosition> {
+        amm::AmmManager::get_position(&env, &market_id, &user)
+    }
+
+    /// Redeems `user`'s AMM shares: one token unit per winning share once
+    /// resolved, or their net spend back if the market was cancelled.
+    ///
+    /// # Errors
+    ///
+    /// * `MarketNotResolved` - Market is neither resolved nor cancelled
+    /// * `AlreadyDisputed` - The result is under dispute
+    /// * `MarketNotReady` - The dispute window is still open
+    /// * `NothingToClaim` - `user` never traded on the market
+    /// * `AlreadyClaimed` - Position was already redeemed
+    pub fn redeem_amm_shares(env: Env, user: Address, market_id: Symbol) -> Result<i128, Error> {
+        user.require_auth();
+        amm::AmmManager::redeem(&env, &user, &market_id)
+    }
+
+    /// Returns an AMM market's unused subsidy and trading surplus to its
+    /// sponsor once the market is resolved or cancelled. Callable by anyone.
+    ///
+    /// # Errors
+    ///
+    /// * `MarketNotResolved` - Market is neither resolved nor cancelled
+    /// * `AlreadyDisputed` - The result is under dispute
+    /// * `MarketNotReady` - The dispute window is still open
+    /// * `AlreadyClaimed` - Pool was already settled
+    pub fn settle_amm_pool(env: Env, market_id: Symbol) -> Result<i128, Error> {
+        amm::AmmManager::settle(&env, &market_id)
+    }
+
+    fn create_market_from_params(env: Env, admin: Address, params: CreateMarketParams) -> Symbol {
+        let CreateMarketParams {
+            question,
+            outcomes,
+            duration_days,
+            oracle_config,
+            has_fallback,
+            fallback_oracle_config,
+            resolution_timeout,
+            min_pool_size,
+            bet_deadline_mins_before_end,
+            dispute_window_seconds,
+            category,
+            tags,
+        } = params;
         if let Err(e) = admin::ContractPauseManager::require_not_paused(&env) {
             panic_with_error!(env, e);
         }
@@ -570,6 +981,15 @@ impl PredictifyHybrid {
             panic_with_error!(env, Error::InvalidQuestion);
         }
 
+        if let Some(category) = &category {
+            if crate::validation::InputValidator::validate_category_length(category).is_err() {
+                panic_with_error!(env, Error::InvalidInput);
+            }
+        }
+        if crate::validation::InputValidator::validate_tags(&tags).is_err() {
+            panic_with_error!(env, Error::InvalidInput);
+        }
+
         // Generate a unique collision-resistant market ID
         let market_id = MarketIdGenerator::generate_market_id(&env, &admin);
 
@@ -578,6 +998,16 @@ impl PredictifyHybrid {
         let duration_seconds: u64 = (duration_days as u64) * seconds_per_day;
         let end_time: u64 = env.ledger().timestamp() + duration_seconds;
 
+        // Near-duplicates of active markets fragment liquidity
+        let duplicate_of = markets::MarketDuplicateDetector::check(
+            &env,
+            Self::get_duplicate_market_policy(env.clone()),
+            &question,
+            &oracle_config,
+            end_time,
+        )
+        .unwrap_or_else(|e| panic_with_error!(env, e));
+
         // Bet deadline: if set, must be before end_time
         let bet_deadline: u64 = match bet_deadline_mins_before_end {
             Some(mins) => {
@@ -591,10 +1021,6 @@ impl PredictifyHybrid {
         };
         let dispute_win = dispute_window_seconds.unwrap_or(86400u64); // 24h default
 
-        let (has_fallback, fallback_cfg) = match &fallback_oracle_config {
-            Some(c) => (true, c.clone()),
-            None => (false, OracleConfig::none_sentinel(&env)),
-        };
         // Create a new market
         let market = Market {
             admin: admin.clone(),
@@ -603,38 +1029,65 @@ impl PredictifyHybrid {
             end_time,
             oracle_config,
             has_fallback,
-            fallback_oracle_config: fallback_cfg,
+            fallback_oracle_config,
             resolution_timeout,
             oracle_result: None,
-            votes: Map::new(&env),
+            voter_count: 0,
+            claimed_count: 0,
+            outcome_voters: Map::new(&env),
             total_staked: 0,
             dispute_stakes: Map::new(&env),
-            stakes: Map::new(&env),
-            claimed: Map::new(&env),
             winning_outcomes: None,
             fee_collected: false,
             state: MarketState::Active,
             total_extension_days: 0,
             max_extension_days: 30,
             extension_history: Vec::new(&env),
-            category: None,
-            tags: Vec::new(&env),
+            category,
+            tags,
             min_pool_size,
             bet_deadline,
             dispute_window_seconds: dispute_win,
+            platform_fee_percentage: fees::FeeManager::get_platform_fee_percentage(&env),
+            has_resolution_snapshot: false,
+            resolution_snapshot: OracleSnapshot::none_sentinel(&env),
+            outcome_totals: Map::new(&env),
+            winning_total: 0,
+            max_payout_multiple: config::DEFAULT_MAX_PAYOUT_MULTIPLE,
+            tier: MarketTier::Community,
+            has_consensus_snapshot: false,
+            consensus_snapshot: ConsensusSnapshot::none_sentinel(&env),
+            outcome_cap: OutcomeCap::disabled(),
+            loser_rebate: false,
+            scalar_range: ScalarRange::none(),
+            scalar_value: None,
+            weighting_mode: WeightingMode::Linear,
+            outcome_weights: Map::new(&env),
         };
 
         // Store the market
         env.storage().persistent().set(&market_id, &market);
+        circuit_breaker::MarketFreezeManager::mark_created(&env, &market_id);
+        markets::MarketDuplicateDetector::register(
+            &env,
+            &market_id,
+            &question,
+            &market.oracle_config,
+        );
+        markets::MarketLabelIndex::index_market(&env, &market_id, &market.category, &market.tags);
 
         // Increment active event count for this creator
         crate::storage::CreatorLimitsManager::increment_active_events(&env, &admin);
 
         // Emit market created event
         EventEmitter::emit_market_created(&env, &market_id, &question, &outcomes, &admin, end_time);
+        if let Some(duplicate_of) = duplicate_of {
+            EventEmitter::emit_duplicate_market_detected(&env, &market_id, &duplicate_of);
+        }
 
         // Record statistics
         statistics::StatisticsManager::record_market_created(&env);
+        statistics::StatisticsManager::record_creator_market_created(&env, &admin);
 
         crate::gas::GasTracker::end_tracking(
             &env,
@@ -645,6 +1098,103 @@ impl PredictifyHybrid {
         market_id
     }
 
+    /// Creates a market without admin rights by posting a creation bond.
+    ///
+    /// Any user may call this. `DEFAULT_MARKET_CREATION_BOND` is transferred
+    /// from `creator` in the staking token and held until the market
+    /// settles: it is refunded once the market's result is final (see
+    /// `refund_creation_bond`) and slashed to the treasury if governance
+    /// flags the market invalid (see `propose_market_invalidation`).
+    ///
+    /// Markets use the default resolution timeout, bet deadline and dispute
+    /// window, and are subject to the same validation, duplicate detection
+    /// and per-creator active market limit as `create_market`.
+    pub fn create_market_public(
+        env: Env,
+        creator: Address,
+        question: String,
+        outcomes: Vec<String>,
+        duration_days: u32,
+        oracle_config: OracleConfig,
+    ) -> Result<Symbol, Error> {
+        admin::ContractPauseManager::require_not_paused(&env)?;
+        rate_limiter::RateLimiter::new(env.clone())
+            .enforce_action(&creator, rate_limiter::RateLimitedAction::CreateMarket);
+        let policy = Self::get_duplicate_market_policy(env.clone());
+        markets::PublicMarketCreator::create_market_public(
+            &env,
+            creator,
+            question,
+            outcomes,
+            duration_days,
+            oracle_config,
+            policy,
+        )
+    }
+
+    /// Refund a public market's creation bond to its creator.
+    ///
+    /// Callable by anyone once the market's result is final (see
+    /// `finalize_result`). Returns the amount refunded.
+    ///
+    /// # Errors
+    ///
+    /// * `MarketNotResolved` - Market has not resolved
+    /// * `InvalidState` - Result is not final, or the market was flagged invalid
+    pub fn refund_creation_bond(env: Env, market_id: Symbol) -> Result<i128, Error> {
+        markets::PublicMarketCreator::refund_bond(&env, &market_id)
+    }
+
+    /// Open a governance proposal to flag a public market invalid.
+    ///
+    /// # Errors
+    ///
+    /// * `InvalidState` - Governance is not initialized, or the market is already flagged
+    /// * `NothingToClaim` - Market holds no creation bond
+    /// * `InvalidInput` - The proposal ID is taken
+    pub fn propose_market_invalidation(
+        env: Env,
+        proposer: Address,
+        proposal_id: Symbol,
+        market_id: Symbol,
+    ) -> Result<(), Error> {
+        proposer.require_auth();
+        markets::PublicMarketCreator::propose_invalidation(
+            &env,
+            &proposer,
+            &proposal_id,
+            &market_id,
+        )
+    }
+
+    /// Flag the market of an invalidation proposal that passed. Callable by anyone.
+    ///
+    /// Returns the flagged market. Fails with `MarketNotReady` while the
+    /// proposal is being voted on and `InvalidState` if it was rejected.
+    pub fn apply_market_invalidation(env: Env, proposal_id: Symbol) -> Result<Symbol, Error> {
+        markets::PublicMarketCreator::apply_invalidation(&env, &proposal_id)
+    }
+
+    /// Whether governance has flagged a market invalid
+    pub fn is_market_flagged_invalid(env: Env, market_id: Symbol) -> bool {
+        markets::PublicMarketCreator::is_flagged_invalid(&env, &market_id)
+    }
+
+    /// Slash the creation bond of a public market governance flagged invalid.
+    ///
+    /// Admin-only. The bond is credited to the contract treasury (see
+    /// `get_treasury_balance`). Returns the amount slashed, or fails with
+    /// `InvalidState` if the market is not flagged.
+    pub fn slash_creation_bond(env: Env, admin: Address, market_id: Symbol) -> Result<i128, Error> {
+        admin.require_auth();
+        markets::PublicMarketCreator::slash_bond(&env, &admin, &market_id)
+    }
+
+    /// Get the creation bond of a public market, if it has one
+    pub fn get_creation_bond(env: Env, market_id: Symbol) -> Option<markets::CreationBond> {
+        markets::PublicMarketCreator::get_bond(&env, &market_id)
+    }
+
     /// Creates a new prediction event with specified parameters.
     ///
     /// This function allows authorized admins to create prediction events
@@ -944,11 +1494,76 @@ impl PredictifyHybrid {
         Ok(())
     }
 
-    /// Allows users to vote on a market outcome by staking tokens.
+    /// Make a market private (invite-only) or public again.
+    ///
+    /// Private markets only accept votes, bets and disputes from addresses
+    /// on the market's allowlist. Callable by the admin or the market's creator.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::MarketNotFound` - Market doesn't exist
+    /// - `Error::Unauthorized` - Caller is neither the admin nor the market's creator
+    pub fn set_market_visibility(
+        env: Env,
+        caller: Address,
+        market_id: Symbol,
+        visibility: EventVisibility,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        market_access::MarketAccessManager::set_visibility(&env, &caller, &market_id, visibility)
+    }
+
+    /// Add addresses to a private market's allowlist (admin or market creator).
+    ///
+    /// # Errors
+    ///
+    /// - `Error::MarketNotFound` - Market doesn't exist
+    /// - `Error::Unauthorized` - Caller is neither the admin nor the market's creator
+    pub fn add_to_market_allowlist(
+        env: Env,
+        caller: Address,
+        market_id: Symbol,
+        addresses: Vec<Address>,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        market_access::MarketAccessManager::add_members(&env, &caller, &market_id, &addresses)
+    }
+
+    /// Remove addresses from a private market's allowlist (admin or market creator).
+    ///
+    /// Positions already taken by removed addresses stay claimable.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::MarketNotFound` - Market doesn't exist
+    /// - `Error::Unauthorized` - Caller is neither the admin nor the market's creator
+    pub fn remove_from_market_allowlist(
+        env: Env,
+        caller: Address,
+        market_id: Symbol,
+        addresses: Vec<Address>,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        market_access::MarketAccessManager::remove_members(&env, &caller, &market_id, &addresses)
+    }
+
+    /// Visibility of a market (public unless made private)
+    pub fn get_market_visibility(env: Env, market_id: Symbol) -> EventVisibility {
+        market_access::MarketAccessManager::get_visibility(&env, &market_id)
+    }
+
+    /// Whether `user` may vote, bet and dispute on a market
+    pub fn can_participate(env: Env, market_id: Symbol, user: Address) -> bool {
+        market_access::MarketAccessManager::is_allowed(&env, &market_id, &user)
+    }
+
+    /// Allows users to vote on a market outcome by staking tokens.
     ///
     /// This function enables users to participate in prediction markets by voting
     /// for their predicted outcome and staking tokens to back their prediction.
-    /// Users can only vote once per market, and votes cannot be changed after submission.
+    /// Users can only vote once per market; a vote can be switched to another
+    /// outcome with `change_vote` for a penalty, or topped up with `add_stake`,
+    /// until the market ends.
     ///
     /// # Parameters
     ///
@@ -962,9 +1577,13 @@ impl PredictifyHybrid {
     ///
     /// This function will panic with specific errors if:
     /// - `Error::MarketNotFound` - Market with given ID doesn't exist
-    /// - `Error::MarketClosed` - Market voting period has ended
+    /// - `Error::MarketClosed` - Market voting period has ended or is in its quiet period
     /// - `Error::InvalidOutcome` - Outcome doesn't match any market outcomes
     /// - `Error::AlreadyVoted` - User has already voted on this market
+    /// - `Error::Unauthorized` - Market is private and user is not on its allowlist
+    /// - `ExtendedError::StakeAboveUserLimit` - Stake exceeds the per-user stake limit
+    /// - `ExtendedError::PoolShareLimitExceeded` - Stake exceeds the per-user share of the pool
+    /// - `ExtendedError::RateLimitExceeded` - User has used up their vote rate limit
     ///
     /// # Example
     ///
@@ -1002,6 +1621,8 @@ impl PredictifyHybrid {
         }
         let gas_marker = crate::gas::GasTracker::start_tracking(&env);
         user.require_auth();
+        rate_limiter::RateLimiter::new(env.clone())
+            .enforce_action(&user, rate_limiter::RateLimitedAction::Vote);
 
         let mut market: Market = env
             .storage()
@@ -1011,22 +1632,48 @@ impl PredictifyHybrid {
                 panic_with_error!(env, Error::MarketNotFound);
             });
 
-        // Check if the market is still active
+        // Check if the market is still active and outside its quiet period
         if market.has_ended(&env) {
             panic_with_error!(env, Error::MarketClosed);
         }
+        if let Err(e) = voting::VotingValidator::validate_outside_quiet_period(&env, &market) {
+            panic_with_error!(env, e);
+        }
 
         // Validate outcome
         let outcome_exists = market.outcomes.iter().any(|o| o == outcome);
         if !outcome_exists {
             panic_with_error!(env, Error::InvalidOutcome);
         }
+        if let Err(e) = markets::MarketStateManager::require_direct_staking(&env, &market_id) {
+            panic_with_error!(env, e);
+        }
+        if let Err(e) = market_access::MarketAccessManager::require_allowed(&env, &market_id, &user)
+        {
+            panic_with_error!(env, e);
+        }
+
+        // Enforce the market tier's stake cap
+        if let Err(e) = crate::validation::MarketValidator::validate_stake_for_tier(&market, stake)
+        {
+            panic_with_error!(env, e.to_contract_error());
+        }
+
+        // Enforce the market's outcome cap (may clamp the stake)
+        let stake = market
+            .apply_outcome_cap(&outcome, stake)
+            .unwrap_or_else(|e| panic_with_error!(env, e));
 
         // Check if user already voted
-        if market.votes.get(user.clone()).is_some() {
+        if VoteLedger::get_entry(&env, &market_id, &user).is_some() {
             panic_with_error!(env, Error::AlreadyVoted);
         }
 
+        // Enforce the per-user stake limits
+        if let Err(e) = voting::StakeLimitManager::check(&env, &market, stake, stake) {
+            panic_with_error!(env, e);
+        }
+
         // Lock funds (transfer from user to contract)
         match bets::BetUtils::lock_funds(&env, &user, stake) {
             Ok(_) => {}
@@ -1034,11 +1681,12 @@ impl PredictifyHybrid {
         }
 
         // Store the vote and stake
-        market.votes.set(user.clone(), outcome.clone());
-        market.stakes.set(user.clone(), stake);
+        VoteLedger::set_position(&env, &market_id, &mut market, &user, &outcome, stake);
         market.total_staked += stake;
+        extensions::AntiSnipeManager::on_stake(&env, &market_id, &mut market, stake);
 
         env.storage().persistent().set(&market_id, &market);
+        statistics::StatisticsManager::record_creator_volume(&env, &market.admin, stake);
 
         // Emit vote cast event
         EventEmitter::emit_vote_cast(&env, &market_id, &user, &outcome, stake);
@@ -1046,102 +1694,560 @@ impl PredictifyHybrid {
         crate::gas::GasTracker::end_tracking(&env, soroban_sdk::symbol_short!("vote"), gas_marker);
     }
 
-    /// Places a bet on a prediction market event by locking user funds.
+    /// Get a user's position (outcome, stake and claim status) in a market, if any
+    pub fn get_vote_entry(env: Env, market_id: Symbol, user: Address) -> Option<VoteEntry> {
+        VoteLedger::get_entry(&env, &market_id, &user)
+    }
+
+    /// Vote on a market outcome by its canonical outcome ID.
     ///
-    /// This function enables users to place bets on active prediction markets,
-    /// selecting an outcome they predict will occur and locking funds as their wager.
-    /// Bets are distinct from votes - bets represent financial wagers while votes
-    /// participate in community resolution consensus.
+    /// Outcome IDs are the indices of the market's outcomes and never change
+    /// once stake is placed, so clients can vote without matching labels.
+    /// Behaves exactly like `vote` otherwise.
+    pub fn vote_by_outcome_id(
+        env: Env,
+        user: Address,
+        market_id: Symbol,
+        outcome_id: u32,
+        stake: i128,
+    ) {
+        let market = markets::MarketStateManager::get_market(&env, &market_id)
+            .unwrap_or_else(|e| panic_with_error!(env, e));
+        let outcome = market
+            .outcome_label(outcome_id)
+            .unwrap_or_else(|| panic_with_error!(env, Error::InvalidOutcome));
+        Self::vote(env, user, market_id, outcome, stake);
+    }
+
+    /// Vote with a client-supplied nonce.
     ///
-    /// # Parameters
+    /// `nonce` must be greater than the last nonce the user consumed (see
+    /// `get_user_nonce`), so a retried transaction cannot vote twice.
+    /// Behaves exactly like `vote` otherwise.
+    pub fn vote_with_nonce(
+        env: Env,
+        user: Address,
+        market_id: Symbol,
+        outcome: String,
+        stake: i128,
+        nonce: Option<u64>,
+    ) {
+        // Authentication is required by `vote`; a failed vote reverts the nonce
+        nonces::NonceManager::consume(&env, &user, nonce)
+            .unwrap_or_else(|e| panic_with_error!(env, e));
+        Self::vote(env, user, market_id, outcome, stake);
+    }
+
+    /// Vote with an encrypted memo attached.
     ///
-    /// * `env` - The Soroban environment for blockchain operations
-    /// * `user` - The address of the user placing the bet (must be authenticated)
-    /// * `market_id` - Unique identifier of the market to bet on
-    /// * `outcome` - The outcome the user predicts will occur
-    /// * `amount` - Amount of tokens to lock for this bet (in base token units)
+    /// The memo is never interpreted or stored on-chain; it is published in a
+    /// `vote_memo` event so the holder of the key can retrieve the voter's
+    /// rationale or tags later. Behaves exactly like `vote` otherwise.
+    pub fn vote_with_memo(
+        env: Env,
+        user: Address,
+        market_id: Symbol,
+        outcome: String,
+        stake: i128,
+        memo: BytesN<64>,
+    ) {
+        Self::vote(env.clone(), user.clone(), market_id.clone(), outcome, stake);
+        EventEmitter::emit_vote_memo(&env, &market_id, &user, &memo);
+    }
+
+    /// Get the last nonce consumed by a user (0 when none has been used)
+    pub fn get_user_nonce(env: Env, user: Address) -> u64 {
+        nonces::NonceManager::get_nonce(&env, &user)
+    }
+
+    /// Follow a market. The watchlist holds at most `MAX_WATCHLIST_SIZE` markets.
+    pub fn watch_market(env: Env, user: Address, market_id: Symbol) -> Result<(), Error> {
+        user.require_auth();
+        watchlist::WatchlistManager::watch(&env, &user, &market_id)
+    }
+
+    /// Stop following a market
+    pub fn unwatch_market(env: Env, user: Address, market_id: Symbol) {
+        user.require_auth();
+        watchlist::WatchlistManager::unwatch(&env, &user, &market_id);
+    }
+
+    /// Set the per-user limits on voting, disputing and public market
+    /// creation (admin only). A limit of zero disables it.
     ///
-    /// # Returns
+    /// Calls past a limit fail with `ExtendedError::RateLimitExceeded`
+    /// until the window rolls over; exempt addresses are never limited.
+    pub fn set_rate_limits(
+        env: Env,
+        admin: Address,
+        limits: rate_limiter::ActionRateLimits,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        rate_limiter::RateLimiter::new(env).set_action_limits(&admin, &limits)
+    }
+
+    /// Get the per-user action limits
+    pub fn get_rate_limits(env: Env) -> rate_limiter::ActionRateLimits {
+        rate_limiter::RateLimiter::new(env).get_action_limits()
+    }
+
+    /// Exempt an address from the action limits, or lift its exemption (admin only)
+    pub fn set_rate_limit_exemption(
+        env: Env,
+        admin: Address,
+        user: Address,
+        exempt: bool,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        rate_limiter::RateLimiter::new(env).set_exemption(&admin, &user, exempt)
+    }
+
+    /// Get the addresses exempt from the action limits
+    pub fn get_rate_limit_exemptions(env: Env) -> Vec<Address> {
+        rate_limiter::RateLimiter::new(env).get_exemptions()
+    }
+
+    /// Get the markets a user follows, oldest first
+    pub fn get_watchlist(env: Env, user: Address) -> Vec<Symbol> {
+        watchlist::WatchlistManager::get_watchlist(&env, &user)
+    }
+
+    /// Post an official announcement (governance only).
     ///
-    /// Returns the created `Bet` struct containing bet details on success.
+    /// Callers need `AdminPermission::UpdateConfig`. `expires_at`, if set,
+    /// must be in the future. Returns the announcement ID.
+    pub fn post_announcement(
+        env: Env,
+        author: Address,
+        severity: announcements::AnnouncementSeverity,
+        message: String,
+        expires_at: Option<u64>,
+    ) -> Result<u32, Error> {
+        author.require_auth();
+        announcements::AnnouncementManager::post(&env, &author, severity, message, expires_at)
+    }
+
+    /// Retract an announcement (governance only)
+    pub fn retract_announcement(env: Env, author: Address, id: u32) -> Result<(), Error> {
+        author.require_auth();
+        announcements::AnnouncementManager::retract(&env, &author, id)
+    }
+
+    /// Get the unexpired announcements, oldest first
+    pub fn get_announcements(env: Env) -> Vec<announcements::Announcement> {
+        announcements::AnnouncementManager::get_active(&env)
+    }
+
+    /// Get the canonical ID of an outcome label
+    pub fn get_outcome_id(env: Env, market_id: Symbol, outcome: String) -> Result<u32, Error> {
+        let market = markets::MarketStateManager::get_market(&env, &market_id)?;
+        market.outcome_id(&outcome).ok_or(Error::InvalidOutcome)
+    }
+
+    /// Get the canonical label of an outcome ID
+    pub fn get_outcome_label(
+        env: Env,
+        market_id: Symbol,
+        outcome_id: u32,
+    ) -> Result<String, Error> {
+        let market = markets::MarketStateManager::get_market(&env, &market_id)?;
+        market
+            .outcome_label(outcome_id)
+            .ok_or(Error::InvalidOutcome)
+    }
+
+    /// Set localized display labels for a market's outcomes (admin only).
     ///
-    /// # Panics
+    /// `labels` holds one label per outcome ID, in ID order. Display labels
+    /// never affect votes, stakes or resolution, which use canonical IDs.
+    pub fn set_outcome_labels(
+        env: Env,
+        admin: Address,
+        market_id: Symbol,
+        locale: String,
+        labels: Vec<String>,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        markets::OutcomeLabels::set_localized_labels(&env, &admin, &market_id, locale, labels)
+    }
+
+    /// Get a market's outcome labels for `locale` in ID order, falling back
+    /// to the canonical labels
+    pub fn get_outcome_labels(
+        env: Env,
+        market_id: Symbol,
+        locale: String,
+    ) -> Result<Vec<String>, Error> {
+        markets::OutcomeLabels::get_localized_labels(&env, &market_id, &locale)
+    }
+
+    /// Switch the caller's vote to another outcome before votes freeze.
     ///
-    /// This function will panic with specific errors if:
-    /// - `Error::MarketNotFound` - Market with given ID doesn't exist
-    /// - `Error::MarketClosed` - Market betting period has ended or market is not active
-    /// - `Error::MarketResolved` - Market has already been resolved
-    /// - `Error::InvalidOutcome` - Outcome doesn't match any market outcomes
-    /// - `Error::AlreadyBet` - User has already placed a bet on this market
-    /// - `Error::InsufficientStake` - Bet amount is below minimum (0.1 XLM)
-    /// - `Error::InvalidInput` - Bet amount exceeds maximum (10,000 XLM)
+    /// Votes can be changed until `vote_change_freeze_hours` (voting config)
+    /// before the end time. The switch costs a penalty on the voter's stake
+    /// that is smaller the earlier it happens (1% a week or more before the
+    /// end time, rising towards 10% at the deadline). The penalty stays in
+    /// the market pool and is paid out to the winners. Returns the penalty
+    /// charged.
     ///
-    /// # Example
+    /// # Errors
     ///
-    /// ```rust
-    /// # use soroban_sdk::{Env, Address, String, Symbol};
-    /// # use predictify_hybrid::PredictifyHybrid;
-    /// # let env = Env::default();
-    /// # let user = Address::generate(&env);
-    /// # let market_id = Symbol::new(&env, "btc_50k");
+    /// - `Error::MarketClosed` - Market is no longer active or votes are frozen
+    /// - `Error::InvalidOutcome` - Outcome doesn't match any market outcomes
+    /// - `Error::InvalidState` - User has no vote, or holds a bet instead
+    /// - `Error::InvalidInput` - User already votes for `new_outcome`
+    pub fn change_vote(
+        env: Env,
+        user: Address,
+        market_id: Symbol,
+        new_outcome: String,
+    ) -> Result<i128, Error> {
+        admin::ContractPauseManager::require_not_paused(&env)?;
+        user.require_auth();
+        voting::VoteChangeManager::change_vote(&env, &user, &market_id, &new_outcome)
+    }
+
+    /// Add stake to the caller's existing vote, keeping the same outcome.
     ///
-    /// // Place a bet of 1 XLM on "Yes" outcome
-    /// let bet = PredictifyHybrid::place_bet(
-    ///     env.clone(),
-    ///     user,
-    ///     market_id,
-    ///     String::from_str(&env, "Yes"),
-    ///     10_000_000 // 1.0 XLM in stroops
-    /// );
-    /// ```
+    /// Returns the voter's new stake.
     ///
-    /// # Fund Locking
+    /// # Errors
     ///
-    /// When a bet is placed:
-    /// 1. User's funds (XLM or Stellar tokens) are transferred to the contract
-    /// 2. Funds remain locked until market resolution
-    /// 3. Upon resolution:
-    ///    - Winners receive proportional share of total bet pool (minus fees)
-    ///    - Losers forfeit their locked funds
-    ///    - Refunds issued if market is cancelled
+    /// - `Error::MarketClosed` - Market is no longer active, has ended or is in its quiet period
+    /// - `Error::InvalidState` - User has no vote, or holds a bet instead
+    /// - `Error::InvalidInput` - Amount is not positive, or the position would exceed
+    ///   the tier's stake cap
     ///
-    /// # Double Betting Prevention
+    /// # Panics
     ///
-    /// Users can only place ONE bet per market. Attempting to bet again will
-    /// result in an `Error::AlreadyBet` error. This ensures fair distribution
-    /// of rewards and prevents manipulation.
+    /// - `ExtendedError::StakeAboveUserLimit` - Position exceeds the per-user stake limit
+    /// - `ExtendedError::PoolShareLimitExceeded` - Position exceeds the per-user share of the pool
+    pub fn add_stake(
+        env: Env,
+        user: Address,
+        market_id: Symbol,
+        amount: i128,
+    ) -> Result<i128, Error> {
+        admin::ContractPauseManager::require_not_paused(&env)?;
+        user.require_auth();
+        voting::VotingManager::add_stake(&env, &user, &market_id, amount)
+    }
+
+    /// Switch a market to commit-reveal (sealed) voting (admin only).
     ///
-    /// # Market State Requirements
+    /// Only possible before anyone stakes. Votes are then committed with
+    /// `commit_vote` until the market's current end time and revealed with
+    /// `reveal_vote` during the following `reveal_window` seconds; the
+    /// market's end time moves to the end of the reveal window. Public votes
+    /// and bets are rejected on the market.
     ///
-    /// - Market must be in `Active` state
-    /// - Current time must be before market end time
-    /// - Market must not be resolved or cancelled
+    /// # Errors
     ///
-    /// # Security
+    /// - `Error::Unauthorized` - Caller is not the admin
+    /// - `Error::InvalidInput` - Reveal window outside the configured range
+    /// - `Error::MarketClosed` - Market is no longer active or has ended
+    /// - `Error::AlreadyVoted` - The market already has stakes
+    /// - `Error::InvalidState` - Already a commit-reveal or AMM market
+    pub fn enable_commit_reveal(
+        env: Env,
+        admin: Address,
+        market_id: Symbol,
+        reveal_window: u64,
+    ) -> Result<commit_reveal::CommitRevealConfig, Error> {
+        admin.require_auth();
+        commit_reveal::CommitRevealManager::enable(&env, &admin, &market_id, reveal_window)
+    }
+
+    /// Commit a sealed vote, escrowing `stake`.
     ///
-    /// - User authentication via `require_auth()`
-    /// - Balance validation before fund transfer
-    /// - Atomic fund locking with bet creation
-    /// - Reentrancy protection via reentrancy guard (guard flag in storage)
-    /// Places a bet on a specific outcome in a prediction market.
+    /// `commitment` is `sha256(user || outcome || salt)`: the voter's XDR
+    /// encoded address, the outcome's UTF-8 bytes and a secret 32-byte salt,
+    /// which `reveal_vote` needs later.
     ///
-    /// This function allows users to place bets on markets with 2 or more outcomes.
-    /// The outcome must be one of the valid outcomes defined when the market was created.
-    /// Users can only place one bet per market.
+    /// # Errors
     ///
-    /// # Multi-Outcome Support
+    /// - `Error::InvalidState` - Market does not use commit-reveal voting
+    /// - `Error::MarketClosed` - The commit phase is over
+    /// - `Error::InvalidInput` - Stake is not positive or exceeds the tier's stake cap
+    /// - `Error::AlreadyVoted` - User already committed
+    pub fn commit_vote(
+        env: Env,
+        user: Address,
+        market_id: Symbol,
+        commitment: BytesN<32>,
+        stake: i128,
+    ) -> Result<(), Error> {
+        admin::ContractPauseManager::require_not_paused(&env)?;
+        user.require_auth();
+        commit_reveal::CommitRevealManager::commit(&env, &user, &market_id, commitment, stake)
+    }
+
+    /// Reveal a sealed vote after the commit phase, turning it into a normal position.
     ///
-    /// - Validates that the selected outcome exists in the market's outcome list
-    /// - Works with binary (2 outcomes) and multi-outcome (N outcomes) markets
-    /// - Rejects invalid outcomes that don't match any market outcome
+    /// # Errors
     ///
-    /// # Parameters
+    /// - `Error::NothingToClaim` - User has no commitment
+    /// - `Error::AlreadyVoted` - Commitment already revealed
+    /// - `Error::InvalidState` - The commit phase is still running
+    /// - `Error::MarketClosed` - The reveal window is over
+    /// - `Error::InvalidOutcome` - Outcome doesn't match any market outcomes
+    /// - `Error::InvalidInput` - Outcome and salt don't match the commitment
+    pub fn reveal_vote(
+        env: Env,
+        user: Address,
+        market_id: Symbol,
+        outcome: String,
+        salt: BytesN<32>,
+    ) -> Result<(), Error> {
+        user.require_auth();
+        commit_reveal::CommitRevealManager::reveal(&env, &user, &market_id, &outcome, &salt)
+    }
+
+    /// Take back the stake of a sealed vote that was never revealed.
     ///
-    /// * `env` - The Soroban environment for blockchain operations
-    /// * `user` - The address of the user placing the bet (must be authenticated)
-    /// * `market_id` - Unique identifier of the market to bet on
-    /// * `outcome` - The outcome to bet on (must match one of the market's outcomes)
-    /// * `amount` - Amount of tokens to bet (must meet minimum/maximum bet limits)
+    /// Available once the reveal window is over; 5% of the stake is
+    /// forfeited to the treasury. Returns the amount refunded.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::NothingToClaim` - User has no commitment
+    /// - `Error::InvalidState` - Commitment was revealed, or the reveal window is still open
+    /// - `Error::AlreadyClaimed` - Stake already reclaimed
+    pub fn reclaim_unrevealed_vote(
+        env: Env,
+        user: Address,
+        market_id: Symbol,
+    ) -> Result<i128, Error> {
+        user.require_auth();
+        commit_reveal::CommitRevealManager::reclaim_unrevealed(&env, &user, &market_id)
+    }
+
+    /// Phases and counts of a commit-reveal market
+    pub fn get_commit_reveal_config(
+        env: Env,
+        market_id: Symbol,
+    ) -> Option<commit_reveal::CommitRevealConfig> {
+        commit_reveal::CommitRevealManager::get_config(&env, &market_id)
+    }
+
+    /// A voter's sealed vote on a commit-reveal market
+    pub fn get_vote_commitment(
+        env: Env,
+        market_id: Symbol,
+        user: Address,
+    ) -> Option<commit_reveal::VoteCommitment> {
+        commit_reveal::CommitRevealManager::get_commitment(&env, &market_id, &user)
+    }
+
+    /// Attach the SEP-41 share token of one market outcome (admin only).
+    ///
+    /// `token` must be a position token contract that names this contract as
+    /// its admin; see the `position-token` contract.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Unauthorized` - Caller is not the admin
+    /// - `Error::InvalidOutcome` - Outcome doesn't match any market outcomes
+    /// - `Error::InvalidState` - The outcome already has a token
+    /// - `Error::InvalidInput` - Token is not administered by this contract
+    pub fn set_position_token(
+        env: Env,
+        admin: Address,
+        market_id: Symbol,
+        outcome: String,
+        token: Address,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        position_tokens::PositionTokenManager::set_token(&env, &admin, &market_id, &outcome, &token)
+    }
+
+    /// Share token of a market outcome, if one is attached
+    pub fn get_position_token(env: Env, market_id: Symbol, outcome: String) -> Option<Address> {
+        position_tokens::PositionTokenManager::get_token(&env, &market_id, &outcome)
+    }
+
+    /// Convert `amount` of the caller's stake into transferable shares of
+    /// their outcome's token, one share per unit of stake.
+    ///
+    /// Shares can be held in any wallet and traded. Whoever holds winning
+    /// shares at resolution collects their payout through `claim_winnings`,
+    /// which burns them; if the market is cancelled, `claim_refund` redeems
+    /// every share at face value. Returns the token address.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::MarketClosed` - Market is no longer active or has ended
+    /// - `Error::InvalidState` - User has no open vote, holds a bet instead, or
+    ///   the outcome has no token
+    /// - `Error::InvalidInput` - Amount is not positive
+    /// - `Error::InsufficientStake` - Amount exceeds the user's stake
+    pub fn tokenize_position(
+        env: Env,
+        user: Address,
+        market_id: Symbol,
+        amount: i128,
+    ) -> Result<Address, Error> {
+        admin::ContractPauseManager::require_not_paused(&env)?;
+        user.require_auth();
+        position_tokens::PositionTokenManager::tokenize(&env, &user, &market_id, amount)
+    }
+
+    /// Sell `amount` of the caller's stake back to the pool before the market ends.
+    ///
+    /// The stake is valued at the current implied odds (its share of the
+    /// pool net of the platform fee) minus a 2% exit fee; what is not paid
+    /// out stays in the pool for the remaining voters. Selling the whole
+    /// stake closes the position. Returns the amount paid out.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::MarketClosed` - Market is no longer active or has ended
+    /// - `Error::InvalidState` - User has no open vote, or holds a bet instead
+    /// - `Error::InvalidInput` - Amount is not positive
+    /// - `Error::InsufficientStake` - Amount exceeds the user's stake
+    pub fn exit_position(
+        env: Env,
+        user: Address,
+        market_id: Symbol,
+        amount: i128,
+    ) -> Result<i128, Error> {
+        admin::ContractPauseManager::require_not_paused(&env)?;
+        user.require_auth();
+        voting::VotingManager::exit_position(&env, &user, &market_id, amount)
+    }
+
+    /// Set how many hours before a market's end votes are frozen (admin only).
+    pub fn set_vote_change_freeze_hours(env: Env, admin: Address, hours: u32) -> Result<(), Error> {
+        admin.require_auth();
+        voting::VoteChangeManager::set_freeze_hours(&env, &admin, hours)
+    }
+
+    /// Set how many hours before a market's end new votes, stake increases
+    /// and bets are rejected (admin only). Zero disables the quiet period.
+    pub fn set_vote_quiet_period_hours(env: Env, admin: Address, hours: u32) -> Result<(), Error> {
+        admin.require_auth();
+        voting::VotingValidator::set_quiet_period_hours(&env, &admin, hours)
+    }
+
+    /// Time from which a market rejects new votes, stake increases and bets.
+    pub fn get_vote_quiet_period_start(env: Env, market_id: Symbol) -> Result<u64, Error> {
+        let market = markets::MarketStateManager::get_market(&env, &market_id)?;
+        Ok(voting::VotingValidator::quiet_period_start(&env, &market))
+    }
+
+    /// Time after which votes on a market can no longer be changed.
+    pub fn get_vote_change_freeze_time(env: Env, market_id: Symbol) -> Result<u64, Error> {
+        let market = markets::MarketStateManager::get_market(&env, &market_id)?;
+        Ok(voting::VoteChangeManager::freeze_time(&env, &market))
+    }
+
+    /// Current penalty, in basis points of stake, for switching a vote on a market.
+    pub fn get_vote_change_penalty_bps(env: Env, market_id: Symbol) -> Result<i128, Error> {
+        let market = markets::MarketStateManager::get_market(&env, &market_id)?;
+        Ok(voting::VoteChangeManager::penalty_bps(&env, &market))
+    }
+
+    /// Vote change accounting for a market: switches, stake moved and penalties retained.
+    pub fn get_vote_change_stats(env: Env, market_id: Symbol) -> voting::VoteChangeStats {
+        voting::VoteChangeManager::get_stats(&env, &market_id)
+    }
+
+    /// Places a bet on a prediction market event by locking user funds.
+    ///
+    /// This function enables users to place bets on active prediction markets,
+    /// selecting an outcome they predict will occur and locking funds as their wager.
+    /// Bets are distinct from votes - bets represent financial wagers while votes
+    /// participate in community resolution consensus.
+    ///
+    /// # Parameters
+    ///
+    /// * `env` - The Soroban environment for blockchain operations
+    /// * `user` - The address of the user placing the bet (must be authenticated)
+    /// * `market_id` - Unique identifier of the market to bet on
+    /// * `outcome` - The outcome the user predicts will occur
+    /// * `amount` - Amount of tokens to lock for this bet (in base token units)
+    ///
+    /// # Returns
+    ///
+    /// Returns the created `Bet` struct containing bet details on success.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic with specific errors if:
+    /// - `Error::MarketNotFound` - Market with given ID doesn't exist
+    /// - `Error::MarketClosed` - Market betting period has ended or market is not active
+    /// - `Error::MarketResolved` - Market has already been resolved
+    /// - `Error::InvalidOutcome` - Outcome doesn't match any market outcomes
+    /// - `Error::AlreadyBet` - User has already placed a bet on this market
+    /// - `Error::InsufficientStake` - Bet amount is below minimum (0.1 XLM)
+    /// - `Error::InvalidInput` - Bet amount exceeds maximum (10,000 XLM)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use soroban_sdk::{Env, Address, String, Symbol};
+    /// # use predictify_hybrid::PredictifyHybrid;
+    /// # let env = Env::default();
+    /// # let user = Address::generate(&env);
+    /// # let market_id = Symbol::new(&env, "btc_50k");
+    ///
+    /// // Place a bet of 1 XLM on "Yes" outcome
+    /// let bet = PredictifyHybrid::place_bet(
+    ///     env.clone(),
+    ///     user,
+    ///     market_id,
+    ///     String::from_str(&env, "Yes"),
+    ///     10_000_000 // 1.0 XLM in stroops
+    /// );
+    /// ```
+    ///
+    /// # Fund Locking
+    ///
+    /// When a bet is placed:
+    /// 1. User's funds (XLM or Stellar tokens) are transferred to the contract
+    /// 2. Funds remain locked until market resolution
+    /// 3. Upon resolution:
+    ///    - Winners receive proportional share of total bet pool (minus fees)
+    ///    - Losers forfeit their locked funds
+    ///    - Refunds issued if market is cancelled
+    ///
+    /// # Double Betting Prevention
+    ///
+    /// Users can only place ONE bet per market. Attempting to bet again will
+    /// result in an `Error::AlreadyBet` error. This ensures fair distribution
+    /// of rewards and prevents manipulation.
+    ///
+    /// # Market State Requirements
+    ///
+    /// - Market must be in `Active` state
+    /// - Current time must be before market end time
+    /// - Market must not be resolved or cancelled
+    ///
+    /// # Security
+    ///
+    /// - User authentication via `require_auth()`
+    /// - Balance validation before fund transfer
+    /// - Atomic fund locking with bet creation
+    /// - Reentrancy protection via reentrancy guard (guard flag in storage)
+    /// Places a bet on a specific outcome in a prediction market.
+    ///
+    /// This function allows users to place bets on markets with 2 or more outcomes.
+    /// The outcome must be one of the valid outcomes defined when the market was created.
+    /// Users can only place one bet per market.
+    ///
+    /// # Multi-Outcome Support
+    ///
+    /// - Validates that the selected outcome exists in the market's outcome list
+    /// - Works with binary (2 outcomes) and multi-outcome (N outcomes) markets
+    /// - Rejects invalid outcomes that don't match any market outcome
+    ///
+    /// # Parameters
+    ///
+    /// * `env` - The Soroban environment for blockchain operations
+    /// * `user` - The address of the user placing the bet (must be authenticated)
+    /// * `market_id` - Unique identifier of the market to bet on
+    /// * `outcome` - The outcome to bet on (must match one of the market's outcomes)
+    /// * `amount` - Amount of tokens to bet (must meet minimum/maximum bet limits)
     ///
     /// # Returns
     ///
@@ -1606,6 +2712,75 @@ impl PredictifyHybrid {
         bets::BetAnalytics::calculate_payout_multiplier(&env, &market_id, &outcome)
     }
 
+    /// Dry run of `vote`: validates the vote and previews its effect without persisting it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error `vote` would panic with, including
+    /// `Error::InsufficientBalance` when the user cannot cover the stake.
+    pub fn simulate_vote(
+        env: Env,
+        user: Address,
+        market_id: Symbol,
+        outcome: String,
+        stake: i128,
+    ) -> Result<simulation::StakeSimulation, Error> {
+        simulation::SimulationManager::simulate_vote(&env, &user, &market_id, &outcome, stake)
+    }
+
+    /// Quote a prospective vote: how `stake` on `outcome` would shift the
+    /// pool and what it would pay if that outcome wins.
+    ///
+    /// Unlike `simulate_vote` this needs no voter, so it skips the balance
+    /// and already-voted checks. The payout assumes nobody else stakes
+    /// afterwards.
+    ///
+    /// # Errors
+    ///
+    /// * `MarketNotFound` - Market does not exist
+    /// * `MarketClosed` - Market has ended
+    /// * `InvalidOutcome` - `outcome` is not one of the market's outcomes
+    /// * `InvalidInput` - `stake` is not positive
+    /// * Tier stake limit and outcome cap errors, as `vote` would raise
+    pub fn quote_vote(
+        env: Env,
+        market_id: Symbol,
+        outcome: String,
+        stake: i128,
+    ) -> Result<simulation::StakeSimulation, Error> {
+        simulation::SimulationManager::quote_vote(&env, &market_id, &outcome, stake)
+    }
+
+    /// Dry run of `place_bet`: validates the bet and previews its effect without persisting it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error `place_bet` would panic with, including
+    /// `Error::InsufficientBalance` when the user cannot cover the amount.
+    pub fn simulate_place_bet(
+        env: Env,
+        user: Address,
+        market_id: Symbol,
+        outcome: String,
+        amount: i128,
+    ) -> Result<simulation::StakeSimulation, Error> {
+        simulation::SimulationManager::simulate_bet(&env, &user, &market_id, &outcome, amount)
+    }
+
+    /// Dry run of `claim_winnings`: previews the payout, fee and vesting without claiming.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error `claim_winnings` would panic with, or
+    /// `Error::InvalidState` when the payout would be held back for recovery.
+    pub fn simulate_claim_winnings(
+        env: Env,
+        user: Address,
+        market_id: Symbol,
+    ) -> Result<simulation::ClaimSimulation, Error> {
+        simulation::SimulationManager::simulate_claim(&env, &user, &market_id)
+    }
+
     /// Allows users to claim their winnings from resolved prediction markets.
     ///
     /// This function enables users who voted for the winning outcome to claim
@@ -1624,7 +2799,10 @@ impl PredictifyHybrid {
     /// - `Error::MarketNotFound` - Market with given ID doesn't exist
     /// - `Error::AlreadyClaimed` - User has already claimed winnings from this market
     /// - `Error::MarketNotResolved` - Market hasn't been resolved yet
+    /// - `Error::AlreadyDisputed` - A dispute on the market is still active
+    /// - `Error::MarketNotReady` - The market's challenge (dispute) window is still open
     /// - `Error::NothingToClaim` - User didn't vote or voted for losing outcome
+    /// - `ExtendedError::PayoutGuardTripped` - Payout exceeds the market's maximum payout multiple
     ///
     /// # Example
     ///
@@ -1652,13 +2830,14 @@ impl PredictifyHybrid {
     ///
     /// Where:
     /// - `user_stake` - Amount the user staked on the winning outcome
-    /// - `fee_percentage` - Platform fee (currently 2%)
+    /// - `fee_percentage` - Platform fee pinned on the market at creation
     /// - `total_pool` - Sum of all stakes in the market
     /// - `winning_total` - Sum of stakes on the winning outcome
     ///
     /// # Market State Requirements
     ///
     /// - Market must be in `Resolved` state with a winning outcome set
+    /// - No dispute may be active and the challenge window must have closed
     /// - User must have voted for the winning outcome
     /// - User must not have previously claimed winnings
     pub fn claim_winnings(env: Env, user: Address, market_id: Symbol) {
@@ -1667,10 +2846,124 @@ impl PredictifyHybrid {
         }
         let gas_marker = crate::gas::GasTracker::start_tracking(&env);
         user.require_auth();
-        Self::claim_winnings_internal(&env, &user, &market_id);
+        Self::claim_winnings_internal(&env, &user, &market_id, &user);
         crate::gas::GasTracker::end_tracking(&env, soroban_sdk::symbol_short!("claim"), gas_marker);
     }
 
+    /// Preview the amount `claim_winnings` would credit `user` now, after fees.
+    ///
+    /// Read-only, so wallets can show pending winnings before the user pays
+    /// for a claim. Losing positions preview the market's loser rebate, if
+    /// any. See `simulate_claim_winnings` for the fee and vesting breakdown.
+    ///
+    /// # Errors
+    ///
+    /// Same as `simulate_claim_winnings`.
+    pub fn preview_claim(env: Env, user: Address, market_id: Symbol) -> Result<i128, Error> {
+        let simulation = simulation::SimulationManager::simulate_claim(&env, &user, &market_id)?;
+        Ok(simulation.payout + simulation.rebate)
+    }
+
+    /// Claim winnings with a client-supplied nonce.
+    ///
+    /// `nonce` must be greater than the last nonce the user consumed.
+    /// Behaves exactly like `claim_winnings` otherwise.
+    pub fn claim_winnings_with_nonce(
+        env: Env,
+        user: Address,
+        market_id: Symbol,
+        nonce: Option<u64>,
+    ) {
+        // Authentication is required by `claim_winnings`
+        nonces::NonceManager::consume(&env, &user, nonce)
+            .unwrap_or_else(|e| panic_with_error!(env, e));
+        Self::claim_winnings(env, user, market_id);
+    }
+
+    /// Issue the claim ticket for a winning staker's pending payout.
+    ///
+    /// Callable by anyone once the market resolves and its dispute window
+    /// has passed; the ticket is initially held by the staker. Returns the
+    /// outstanding ticket if one exists.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::MarketNotResolved` - Market has no winning outcome yet
+    /// - `Error::MarketNotReady` - Dispute window has not passed
+    /// - `Error::AlreadyDisputed` - Market is under dispute
+    /// - `Error::NothingToClaim` - Staker has no winning payout
+    /// - `Error::AlreadyClaimed` - Staker was already paid
+    pub fn issue_claim_ticket(
+        env: Env,
+        market_id: Symbol,
+        staker: Address,
+    ) -> Result<tickets::ClaimTicket, Error> {
+        tickets::ClaimTicketManager::issue(&env, &market_id, &staker)
+    }
+
+    /// Transfer a claim ticket to another address.
+    ///
+    /// Only the current holder may transfer, and each ticket can be
+    /// transferred once. After the transfer only `to` can claim the payout.
+    pub fn transfer_claim_ticket(
+        env: Env,
+        holder: Address,
+        market_id: Symbol,
+        staker: Address,
+        to: Address,
+    ) -> Result<tickets::ClaimTicket, Error> {
+        holder.require_auth();
+        tickets::ClaimTicketManager::transfer(&env, &holder, &market_id, &staker, &to)
+    }
+
+    /// Claim the winnings represented by a claim ticket.
+    ///
+    /// The payout is credited to `holder`, who must hold the staker's
+    /// ticket. The ticket is consumed.
+    pub fn claim_with_ticket(env: Env, holder: Address, market_id: Symbol, staker: Address) {
+        if let Err(e) = admin::ContractPauseManager::require_not_paused(&env) {
+            panic_with_error!(env, e);
+        }
+        holder.require_auth();
+        if tickets::ClaimTicketManager::get_ticket(&env, &market_id, &staker).is_none() {
+            panic_with_error!(env, Error::NothingToClaim);
+        }
+        Self::claim_winnings_internal(&env, &staker, &market_id, &holder);
+    }
+
+    /// Get a staker's outstanding claim ticket in a market, if any
+    pub fn get_claim_ticket(
+        env: Env,
+        market_id: Symbol,
+        staker: Address,
+    ) -> Option<tickets::ClaimTicket> {
+        tickets::ClaimTicketManager::get_ticket(&env, &market_id, &staker)
+    }
+
+    /// Votes on several markets at once, all-or-nothing.
+    ///
+    /// `votes` holds up to `MAX_BATCH_VOTES` `(market_id, outcome, stake)`
+    /// entries on distinct markets. Each vote is checked like `vote`; if any
+    /// is invalid the whole batch fails. The combined stake is taken in one
+    /// token transfer. Returns the total stake locked.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::InvalidInput` - Empty or oversized batch, or a repeated market
+    /// - `ExtendedError::RateLimitExceeded` - The batch exceeds the user's vote rate limit
+    /// - `ExtendedError::StakeAboveUserLimit` / `ExtendedError::PoolShareLimitExceeded` -
+    ///   An entry exceeds the per-user stake limits
+    /// - Any error `vote` would raise for an individual entry
+    pub fn batch_vote(
+        env: Env,
+        user: Address,
+        votes: Vec<(Symbol, String, i128)>,
+    ) -> Result<i128, Error> {
+        admin::ContractPauseManager::require_not_paused(&env)?;
+        user.require_auth();
+        batch_operations::BatchProcessor::batch_vote_atomic(&env, &user, &votes)
+    }
+
     /// Claims winnings across multiple markets atomically for a single user.
     ///
     /// This function validates every claim first, then executes all claims in one transaction.
@@ -1706,7 +2999,8 @@ impl PredictifyHybrid {
                 .get(&market_id)
                 .unwrap_or_else(|| panic_with_error!(env, Error::MarketNotFound));
 
-            if market.claimed.get(user.clone()).unwrap_or(false) {
+            let entry = VoteLedger::get_entry(&env, &market_id, &user);
+            if entry.as_ref().is_some_and(|entry| entry.claimed) {
                 panic_with_error!(env, Error::AlreadyClaimed);
             }
 
@@ -1715,9 +3009,8 @@ impl PredictifyHybrid {
                 None => panic_with_error!(env, Error::MarketNotResolved),
             };
 
-            let user_outcome = market
-                .votes
-                .get(user.clone())
+            let user_outcome = entry
+                .map(|entry| entry.outcome)
                 .unwrap_or_else(|| panic_with_error!(env, Error::NothingToClaim));
 
             if !winning_outcomes.contains(&user_outcome) {
@@ -1726,15 +3019,131 @@ impl PredictifyHybrid {
         }
 
         for market_id in market_ids.iter() {
-            Self::claim_winnings_internal(&env, &user, &market_id);
+            Self::claim_winnings_internal(&env, &user, &market_id, &user);
         }
     }
 
-    fn claim_winnings_internal(env: &Env, user: &Address, market_id: &Symbol) {
+    /// Pushes winnings to a bounded list of users of a single market.
+    ///
+    /// Callable by the admin or a registered `Sweeper` keeper. Lets operators
+    /// settle small markets proactively instead of waiting for every winner to
+    /// claim. Users who already claimed, did not vote, or backed a losing
+    /// outcome are skipped so the same list can be resubmitted safely.
+    ///
+    /// # Returns
+    ///
+    /// Number of users credited a payout in this call.
+    ///
+    /// # Panics
+    ///
+    /// Panics with:
+    /// - `Error::Unauthorized` if the caller is neither the admin nor a sweeper keeper
+    /// - `Error::InvalidInput` for an empty or oversized user list
+    /// - `Error::MarketNotResolved` if the market has no winning outcome yet
+    /// - `Error::AlreadyDisputed` / `Error::MarketNotReady` while claims are locked
+    pub fn distribute_winnings(
+        env: Env,
+        caller: Address,
+        market_id: Symbol,
+        users: Vec<Address>,
+    ) -> u32 {
+        const MAX_DISTRIBUTE_BATCH: u32 = 50;
+
+        if let Err(e) = admin::ContractPauseManager::require_not_paused(&env) {
+            panic_with_error!(env, e);
+        }
+        caller.require_auth();
+
+        let stored_admin: Address = env
+            .storage()
+            .persistent()
+            .get(&Symbol::new(&env, "Admin"))
+            .unwrap_or_else(|| panic_with_error!(env, Error::Unauthorized));
+        if caller != stored_admin
+            && !keepers::KeeperRegistry::is_keeper(&env, &caller, keepers::KeeperRole::Sweeper)
+        {
+            panic_with_error!(env, Error::Unauthorized);
+        }
+
+        if users.is_empty() || users.len() > MAX_DISTRIBUTE_BATCH {
+            panic_with_error!(env, Error::InvalidInput);
+        }
+
+        let market: Market = env
+            .storage()
+            .persistent()
+            .get(&market_id)
+            .unwrap_or_else(|| panic_with_error!(env, Error::MarketNotFound));
+
+        let winning_outcomes = match &market.winning_outcomes {
+            Some(outcomes) => outcomes.clone(),
+            None => panic_with_error!(env, Error::MarketNotResolved),
+        };
+
+        if let Err(e) = Self::check_claim_lock(&env, &market) {
+            panic_with_error!(env, e);
+        }
+
+        let mut paid = 0u32;
+        for user in users.iter() {
+            // Claims made earlier in this batch are visible in the vote ledger
+            let Some(entry) = VoteLedger::get_entry(&env, &market_id, &user) else {
+                continue;
+            };
+            if entry.claimed || !winning_outcomes.contains(&entry.outcome) {
+                continue;
+            }
+
+            // A tripped payout guard flags the market instead of failing the batch
+            let payout = Self::net_payout(&market, &entry.outcome, entry.stake).unwrap_or(0);
+            if market.exceeds_max_payout(entry.stake, payout) {
+                crate::recovery::RecoveryManager::flag_payout_anomaly(&env, &market_id, payout);
+                continue;
+            }
+
+            // Transferred tickets are paid to their holder
+            let recipient = tickets::ClaimTicketManager::payout_recipient(&env, &market_id, &user);
+            if Self::claim_winnings_internal(&env, &user, &market_id, &recipient) > 0 {
+                paid += 1;
+            }
+        }
+
+        paid
+    }
+
+    /// Reject claims while a dispute is active or the challenge window is still open.
+    fn check_claim_lock(env: &Env, market: &Market) -> Result<(), Error> {
+        if market.state == MarketState::Disputed {
+            return Err(Error::AlreadyDisputed);
+        }
+
+        let window_end = market.end_time.saturating_add(market.dispute_window_seconds);
+        if env.ledger().timestamp() < window_end {
+            return Err(Error::MarketNotReady);
+        }
+
+        Ok(())
+    }
+
+    /// Settle `user`'s claim in a market, crediting the payout to `recipient`.
+    ///
+    /// `recipient` must be the holder of the user's outstanding claim ticket,
+    /// or the user when no ticket was transferred; the ticket is consumed.
+    /// Returns the amount credited to `recipient`.
+    fn claim_winnings_internal(
+        env: &Env,
+        user: &Address,
+        market_id: &Symbol,
+        recipient: &Address,
+    ) -> i128 {
         if ReentrancyGuard::check_reentrancy_state(env).is_err() {
             panic_with_error!(env, Error::InvalidState);
         }
 
+        if tickets::ClaimTicketManager::payout_recipient(env, market_id, user) != *recipient {
+            panic_with_error!(env, Error::Unauthorized);
+        }
+
         let mut market: Market = env
             .storage()
             .persistent()
@@ -1748,43 +3157,65 @@ impl PredictifyHybrid {
             panic_with_error!(env, Error::ResolutionTimeoutReached);
         }
 
-        // Check if user has claimed already
-        if market.claimed.get(user.clone()).unwrap_or(false) {
-            panic_with_error!(env, Error::AlreadyClaimed);
-        }
+        let entry = VoteLedger::get_entry(env, market_id, user);
 
         // Check if market is resolved
-        let winning_outcomes = match &market.winning_outcomes {
+        let winning_outcomes = match market.winning_outcomes.clone() {
             Some(outcomes) => outcomes,
             None => panic_with_error!(env, Error::MarketNotResolved),
         };
 
-        // Get user's vote
-        let user_outcome = market
-            .votes
-            .get(user.clone())
-            .unwrap_or_else(|| panic_with_error!(env, Error::NothingToClaim));
+        // Claims stay locked while the result can still be challenged
+        if let Err(e) = Self::check_claim_lock(env, &market) {
+            panic_with_error!(env, e);
+        }
+
+        // Zero-winner pools are settled through settle_zero_winner_market
+        if market.get_winning_total() == 0 {
+            panic_with_error!(env, Error::NothingToClaim);
+        }
 
-        let user_stake = market.stakes.get(user.clone()).unwrap_or(0);
+        // Winning position tokens held by the user are burned against their payout
+        let token_payout =
+            position_tokens::PositionTokenManager::redeem(env, market_id, &market, user)
+                .unwrap_or_else(|e| panic_with_error!(env, e));
+        if token_payout > 0 {
+            statistics::StatisticsManager::record_winnings_claimed(env, recipient, token_payout);
+            EventEmitter::emit_winnings_claimed(env, market_id, recipient, token_payout);
+            if let Err(e) =
+                vesting::VestingManager::credit_winnings(env, market_id, recipient, token_payout)
+            {
+                panic_with_error!(env, e);
+            }
+        }
+
+        // Get user's vote
+        let VoteEntry {
+            outcome: user_outcome,
+            stake: user_stake,
+            claimed,
+        } = match entry {
+            Some(entry) => entry,
+            None if token_payout > 0 => return token_payout,
+            None => panic_with_error!(env, Error::NothingToClaim),
+        };
+        if claimed {
+            if token_payout > 0 {
+                return token_payout;
+            }
+            panic_with_error!(env, Error::AlreadyClaimed);
+        }
 
         // Calculate payout if user won (check if outcome is in winning outcomes)
         if winning_outcomes.contains(&user_outcome) {
-            // Calculate total winning stakes across all winning outcomes
-            let mut winning_total = 0;
-            for (voter, outcome) in market.votes.iter() {
-                if winning_outcomes.contains(&outcome) {
-                    winning_total += market.stakes.get(voter.clone()).unwrap_or(0);
-                }
-            }
+            // Scalar markets weight each winning position by how close its bucket came
+            let winning_total = market.weighted_winning_total();
+            let weighted_stake = user_stake * market.payout_weight(&user_outcome);
 
             if winning_total > 0 {
-                // Retrieve dynamic platform fee percentage from configuration
-                let cfg = match crate::config::ConfigManager::get_config(env) {
-                    Ok(c) => c,
-                    Err(_) => panic_with_error!(env, Error::ConfigNotFound),
-                };
-                let fee_percent = cfg.fees.platform_fee_percentage;
-                let user_share = (user_stake
+                // Use the platform fee pinned on the market at creation
+                let fee_percent = market.platform_fee_percentage;
+                let user_share = (weighted_stake
                     .checked_mul(PERCENTAGE_DENOMINATOR - fee_percent)
                     .unwrap_or_else(|| panic_with_error!(env, Error::InvalidInput)))
                     / PERCENTAGE_DENOMINATOR;
@@ -1794,48 +3225,74 @@ impl PredictifyHybrid {
                     .unwrap_or_else(|| panic_with_error!(env, Error::InvalidInput));
                 let payout = product / winning_total;
 
-                let product_gross = user_stake
+                let product_gross = weighted_stake
                     .checked_mul(total_pool)
                     .unwrap_or_else(|| panic_with_error!(env, Error::InvalidInput));
                 let gross_payout = product_gross / winning_total;
                 let fee_amount = gross_payout - payout;
 
-                statistics::StatisticsManager::record_winnings_claimed(env, user, payout);
+                // Absurd payouts point at corrupted accounting. The panic rolls
+                // back any write, so batch payouts flag the market for recovery.
+                if market.exceeds_max_payout(user_stake, payout) {
+                    panic_with_error!(env, errors::ExtendedError::PayoutGuardTripped);
+                }
+
+                statistics::StatisticsManager::record_winnings_claimed(env, recipient, payout);
                 statistics::StatisticsManager::record_fees_collected(env, fee_amount);
+                statistics::StatisticsManager::record_creator_fees(env, &market.admin, fee_amount);
 
                 // Mark as claimed
-                market.claimed.set(user.clone(), true);
+                VoteLedger::mark_claimed(env, market_id, &mut market, user);
                 env.storage().persistent().set(market_id, &market);
+                tickets::ClaimTicketManager::consume(env, market_id, user);
 
                 // Emit winnings claimed event
-                EventEmitter::emit_winnings_claimed(env, market_id, user, payout);
-
-                // Credit tokens to user balance
-                match storage::BalanceStorage::add_balance(
-                    env,
-                    user,
-                    &types::ReflectorAsset::Stellar,
-                    payout,
-                ) {
-                    Ok(_) => {}
-                    Err(e) => panic_with_error!(env, e),
+                EventEmitter::emit_winnings_claimed(env, market_id, recipient, payout);
+
+                // Credit tokens to the recipient's balance (jumbo payouts vest)
+                if let Err(e) =
+                    vesting::VestingManager::credit_winnings(env, market_id, recipient, payout)
+                {
+                    panic_with_error!(env, e);
                 }
 
-                return;
+                return token_payout + payout;
             }
         }
 
         // If no winnings (user didn't win or zero payout), still mark as claimed to prevent re-attempts
-        market.claimed.set(user.clone(), true);
+        VoteLedger::mark_claimed(env, market_id, &mut market, user);
         env.storage().persistent().set(&market_id, &market);
-
+        tickets::ClaimTicketManager::consume(env, market_id, user);
+
+        // Losing voters collect their share of the fee rebate, if the market offers one
+        let rebate = market.loser_rebate_for(user_stake);
+        if rebate > 0 {
+            EventEmitter::emit_loser_rebate_claimed(env, market_id, recipient, rebate);
+            if let Err(e) = storage::BalanceStorage::add_balance(
+                env,
+                recipient,
+                &types::ReflectorAsset::Stellar,
+                rebate,
+            ) {
+                panic_with_error!(env, e);
+            }
+        }
+        token_payout + rebate
     }
 
     /// Sweeps unclaimed winning payouts after claim timeout to treasury or burns them.
     ///
+    /// Each call walks voters from where the previous call stopped until
+    /// `SWEEP_RESOURCE_BUDGET` is spent, so large markets are swept over
+    /// several calls instead of trapping on resource limits. See
+    /// `get_sweep_cursor` for progress. The call that reaches the last voter
+    /// also sweeps winnings still held as position token shares. Swept funds
+    /// are deposited to the contract treasury (`get_treasury_balance`).
+    ///
     /// Authorization: caller must be contract admin or configured treasury address.
     ///
-    /// Returns swept amount.
+    /// Returns the amount swept by this call.
     pub fn sweep_unclaimed_winnings(
         env: Env,
         caller: Address,
@@ -1874,7 +3331,7 @@ impl PredictifyHybrid {
             .get(&market_id)
             .unwrap_or_else(|| panic_with_error!(env, Error::MarketNotFound));
 
-        let winning_outcomes = match &market.winning_outcomes {
+        let winning_outcomes = match market.winning_outcomes.clone() {
             Some(outcomes) => outcomes,
             None => panic_with_error!(env, Error::MarketNotResolved),
         };
@@ -1885,72 +3342,78 @@ impl PredictifyHybrid {
             panic_with_error!(env, Error::InvalidState);
         }
 
-        let cfg = match crate::config::ConfigManager::get_config(&env) {
-            Ok(c) => c,
-            Err(_) => panic_with_error!(env, Error::ConfigNotFound),
-        };
-        let fee_percent = cfg.fees.platform_fee_percentage;
-
-        // Calculate total winning stake across all winning outcomes
-        let mut winning_total = 0i128;
-        for (voter, outcome) in market.votes.iter() {
-            if winning_outcomes.contains(&outcome) {
-                winning_total += market.stakes.get(voter).unwrap_or(0);
-            }
-        }
-
-        if winning_total <= 0 {
+        // Total winning stake was precomputed once at resolution
+        if market.get_winning_total() <= 0 {
             panic_with_error!(env, Error::NothingToClaim);
         }
 
-        let total_pool = market.total_staked;
         let mut sweep_total = 0i128;
 
-        for (voter, outcome) in market.votes.iter() {
-            if !winning_outcomes.contains(&outcome) {
+        let cursor_key = (soroban_sdk::symbol_short!("sweep_cur"), market_id.clone());
+        let mut cursor: u32 = env.storage().persistent().get(&cursor_key).unwrap_or(0);
+        let mut budget = batch_operations::ResourceBudget::new(config::SWEEP_RESOURCE_BUDGET);
+        while cursor < market.voter_count
+            && budget.try_spend(batch_operations::BatchUtils::estimate_gas_cost(
+                &batch_operations::BatchOperationType::Claim,
+                1,
+            ))
+        {
+            let index = cursor;
+            cursor += 1;
+            let Some(voter) = VoteLedger::get_voter(&env, &market_id, index) else {
+                continue;
+            };
+            let Some(entry) = VoteLedger::get_entry(&env, &market_id, &voter) else {
+                continue;
+            };
+            if !winning_outcomes.contains(&entry.outcome) {
                 continue;
             }
 
-            if market.claimed.get(voter.clone()).unwrap_or(false) {
+            if entry.claimed {
                 continue;
             }
 
-            let user_stake = market.stakes.get(voter.clone()).unwrap_or(0);
-            if user_stake <= 0 {
+            if entry.stake <= 0 {
                 continue;
             }
 
-            let user_share = (user_stake
-                .checked_mul(PERCENTAGE_DENOMINATOR - fee_percent)
-                .unwrap_or_else(|| panic_with_error!(env, Error::InvalidInput)))
-                / PERCENTAGE_DENOMINATOR;
-
-            let payout = user_share
-                .checked_mul(total_pool)
-                .unwrap_or_else(|| panic_with_error!(env, Error::InvalidInput))
-                / winning_total;
+            let payout = Self::net_payout(&market, &entry.outcome, entry.stake)
+                .unwrap_or_else(|| panic_with_error!(env, Error::InvalidInput));
 
             if payout > 0 {
                 sweep_total += payout;
             }
 
-            market.claimed.set(voter, true);
+            VoteLedger::mark_claimed(&env, &market_id, &mut market, &voter);
+        }
+        env.storage().persistent().set(&cursor_key, &cursor);
+
+        // Winnings held as position token shares go with the last stretch
+        if cursor >= market.voter_count {
+            match position_tokens::PositionTokenManager::sweep_unredeemed(&env, &market_id, &market)
+            {
+                Ok(unredeemed) => sweep_total += unredeemed,
+                Err(e) => panic_with_error!(env, e),
+            }
         }
 
         if sweep_total <= 0 {
-            panic_with_error!(env, Error::NothingToClaim);
+            if cursor >= market.voter_count {
+                panic_with_error!(env, Error::NothingToClaim);
+            }
+            // Nothing in this stretch of voters; keep the progress
+            env.storage().persistent().set(&market_id, &market);
+            return 0;
         }
 
         if !burn {
-            let recipient = treasury_opt.clone().unwrap_or(admin.clone());
-            match storage::BalanceStorage::add_balance(
+            if let Err(e) = treasury::TreasuryManager::deposit(
                 &env,
-                &recipient,
-                &types::ReflectorAsset::Stellar,
+                treasury::TreasurySource::UnclaimedWinnings,
                 sweep_total,
             ) {
-                Ok(_) => {}
-                Err(e) => panic_with_error!(env, e),
+                panic_with_error!(env, e);
             }
         }
 
@@ -1959,11 +3422,7 @@ impl PredictifyHybrid {
         let recipient_for_event = if burn {
             None
         } else {
-            let treasury_for_event: Option<Address> = env
-                .storage()
-                .persistent()
-                .get(&Symbol::new(&env, TREASURY_STORAGE_KEY));
-            Some(treasury_for_event.unwrap_or(admin.clone()))
+            Some(env.current_contract_address())
         };
         EventEmitter::emit_unclaimed_winnings_swept(
             &env,
@@ -1977,6 +3436,234 @@ impl PredictifyHybrid {
         sweep_total
     }
 
+    /// Net payout owed to a winning position, as `claim_winnings` computes it
+    fn net_payout(market: &Market, outcome: &String, stake: i128) -> Option<i128> {
+        let weighted_stake = stake.checked_mul(market.payout_weight(outcome))?;
+        let user_share = weighted_stake
+            .checked_mul(PERCENTAGE_DENOMINATOR - market.platform_fee_percentage)?
+            / PERCENTAGE_DENOMINATOR;
+        user_share
+            .checked_mul(market.total_staked)?
+            .checked_div(market.weighted_winning_total())
+    }
+
+    /// Time after which a market's winnings can no longer be claimed and
+    /// may be swept to the treasury.
+    ///
+    /// The deadline is the market's end time plus its effective claim period
+    /// (90 days unless overridden with `set_global_claim_period` or
+    /// `set_market_claim_period`).
+    pub fn get_claim_deadline(env: Env, market_id: Symbol) -> Result<u64, Error> {
+        let market = markets::MarketStateManager::get_market(&env, &market_id)?;
+        let claim_period = Self::get_effective_claim_period(env, market_id);
+        Ok(market.end_time.saturating_add(claim_period))
+    }
+
+    /// Total winnings of a resolved market that are still unclaimed.
+    ///
+    /// This is what the winners and holders of winning position token shares
+    /// can still claim before the claim deadline, and what `sweep_unclaimed`
+    /// moves to the treasury after it.
+    ///
+    /// # Errors
+    ///
+    /// * `MarketNotFound` - Market does not exist
+    /// * `MarketNotResolved` - Market has no winning outcome yet
+    pub fn get_unclaimed_winnings(env: Env, market_id: Symbol) -> Result<i128, Error> {
+        let market = markets::MarketStateManager::get_market(&env, &market_id)?;
+        let winning_outcomes = market
+            .winning_outcomes
+            .clone()
+            .ok_or(Error::MarketNotResolved)?;
+        if market.get_winning_total() <= 0 {
+            return Ok(0);
+        }
+
+        let mut unclaimed = 0i128;
+        for index in 0..market.voter_count {
+            let Some(voter) = VoteLedger::get_voter(&env, &market_id, index) else {
+                continue;
+            };
+            let Some(entry) = VoteLedger::get_entry(&env, &market_id, &voter) else {
+                continue;
+            };
+            if entry.claimed || entry.stake <= 0 || !winning_outcomes.contains(&entry.outcome) {
+                continue;
+            }
+            let payout = Self::net_payout(&market, &entry.outcome, entry.stake)
+                .ok_or(Error::InvalidInput)?;
+            unclaimed = unclaimed.checked_add(payout).ok_or(Error::InvalidInput)?;
+        }
+        let unredeemed =
+            position_tokens::PositionTokenManager::unredeemed_winnings(&env, &market_id, &market)?;
+        unclaimed.checked_add(unredeemed).ok_or(Error::InvalidInput)
+    }
+
+    /// Index of the next voter `sweep_unclaimed_winnings` will visit for a market.
+    ///
+    /// The sweep is complete once this reaches the market's `voter_count`.
+    pub fn get_sweep_cursor(env: Env, market_id: Symbol) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&(soroban_sdk::symbol_short!("sweep_cur"), market_id))
+            .unwrap_or(0)
+    }
+
+    /// Settles a market that resolved with no stake on any winning outcome.
+    ///
+    /// Applies the configured [`ZeroWinnerPolicy`]: participants are refunded their
+    /// stake minus the market's pinned platform fee, or the whole pool is credited to
+    /// the treasury (admin when no treasury is set). Participants are marked as
+    /// claimed and a [`ZeroWinnerSettlement`] record is stored for accounting.
+    ///
+    /// Authorization: caller must be contract admin or configured treasury address.
+    ///
+    /// Returns the total amount credited (refunds plus treasury share).
+    pub fn settle_zero_winner_market(env: Env, caller: Address, market_id: Symbol) -> i128 {
+        caller.require_auth();
+
+        if ReentrancyGuard::check_reentrancy_state(&env).is_err() {
+            panic_with_error!(env, Error::InvalidState);
+        }
+
+        let admin: Address = env
+            .storage()
+            .persistent()
+            .get(&Symbol::new(&env, "Admin"))
+            .unwrap_or_else(|| panic_with_error!(env, Error::Unauthorized));
+
+        let treasury_opt: Option<Address> = env
+            .storage()
+            .persistent()
+            .get(&Symbol::new(&env, TREASURY_STORAGE_KEY));
+
+        let is_treasury = treasury_opt
+            .as_ref()
+            .map(|treasury| treasury == &caller)
+            .unwrap_or(false);
+
+        if caller != admin && !is_treasury {
+            panic_with_error!(env, Error::Unauthorized);
+        }
+
+        let mut market: Market = env
+            .storage()
+            .persistent()
+            .get(&market_id)
+            .unwrap_or_else(|| panic_with_error!(env, Error::MarketNotFound));
+
+        if market.winning_outcomes.is_none() {
+            panic_with_error!(env, Error::MarketNotResolved);
+        }
+
+        if let Err(e) = Self::check_claim_lock(&env, &market) {
+            panic_with_error!(env, e);
+        }
+
+        // Only markets where nobody backed a winning outcome qualify
+        if market.get_winning_total() > 0 {
+            panic_with_error!(env, Error::InvalidState);
+        }
+
+        let mut settlements: Map<Symbol, ZeroWinnerSettlement> = env
+            .storage()
+            .persistent()
+            .get(&Symbol::new(&env, ZERO_WINNER_SETTLEMENTS_KEY))
+            .unwrap_or(Map::new(&env));
+
+        if settlements.contains_key(market_id.clone()) {
+            panic_with_error!(env, Error::AlreadyClaimed);
+        }
+
+        let policy = Self::get_zero_winner_policy(env.clone());
+        let fee_percent = market.platform_fee_percentage;
+
+        let mut settlement = ZeroWinnerSettlement {
+            policy,
+            refunded_total: 0,
+            fee_total: 0,
+            treasury_total: 0,
+            participants: 0,
+            settled_at: env.ledger().timestamp(),
+        };
+
+        for (voter, entry) in VoteLedger::get_entries(&env, &market_id, &market).iter() {
+            let stake = entry.stake;
+            if entry.claimed || stake <= 0 {
+                continue;
+            }
+
+            match policy {
+                ZeroWinnerPolicy::RefundMinusFee => {
+                    let fee = stake
+                        .checked_mul(fee_percent)
+                        .unwrap_or_else(|| panic_with_error!(env, Error::InvalidInput))
+                        / PERCENTAGE_DENOMINATOR;
+                    let refund = stake - fee;
+
+                    if refund > 0 {
+                        match storage::BalanceStorage::add_balance(
+                            &env,
+                            &voter,
+                            &types::ReflectorAsset::Stellar,
+                            refund,
+                        ) {
+                            Ok(_) => {}
+                            Err(e) => panic_with_error!(env, e),
+                        }
+                    }
+
+                    settlement.refunded_total += refund;
+                    settlement.fee_total += fee;
+                }
+                ZeroWinnerPolicy::Treasury => {
+                    settlement.treasury_total += stake;
+                }
+            }
+
+            VoteLedger::mark_claimed(&env, &market_id, &mut market, &voter);
+            settlement.participants += 1;
+        }
+
+        if settlement.participants == 0 {
+            panic_with_error!(env, Error::NothingToClaim);
+        }
+
+        if settlement.treasury_total > 0 {
+            let recipient = treasury_opt.unwrap_or(admin);
+            match storage::BalanceStorage::add_balance(
+                &env,
+                &recipient,
+                &types::ReflectorAsset::Stellar,
+                settlement.treasury_total,
+            ) {
+                Ok(_) => {}
+                Err(e) => panic_with_error!(env, e),
+            }
+        }
+
+        if settlement.fee_total > 0 {
+            statistics::StatisticsManager::record_fees_collected(&env, settlement.fee_total);
+            statistics::StatisticsManager::record_creator_fees(
+                &env,
+                &market.admin,
+                settlement.fee_total,
+            );
+        }
+
+        env.storage().persistent().set(&market_id, &market);
+
+        settlements.set(market_id.clone(), settlement.clone());
+        env.storage().persistent().set(
+            &Symbol::new(&env, ZERO_WINNER_SETTLEMENTS_KEY),
+            &settlements,
+        );
+
+        EventEmitter::emit_zero_winner_settled(&env, &market_id, &caller, &settlement);
+
+        settlement.refunded_total + settlement.treasury_total
+    }
+
     /// Claims winnings for multiple resolved markets in a single atomic transaction.
     ///
     /// Allows users to claim winnings from multiple resolved markets efficiently in one call.
@@ -2004,6 +3691,7 @@ impl PredictifyHybrid {
     ///
     /// - `MarketNotFound` - Any market doesn't exist
     /// - `MarketNotResolved` - Any market not resolved
+    /// - `AlreadyDisputed` / `MarketNotReady` - Any market still disputed or in its challenge window
     /// - `AlreadyClaimed` - User already claimed from any market
     /// - `NothingToClaim` - User didn't vote on any market
     /// - `InvalidInput` - Empty market vector
@@ -2021,13 +3709,6 @@ impl PredictifyHybrid {
             panic_with_error!(env, Error::InvalidInput);
         }
 
-        // Retrieve configuration once for all market calculations
-        let cfg = match crate::config::ConfigManager::get_config(&env) {
-            Ok(c) => c,
-            Err(_) => panic_with_error!(env, Error::ConfigNotFound),
-        };
-        let fee_percent = cfg.fees.platform_fee_percentage;
-
         // First pass: Validate all markets before making any state changes
         // This ensures atomicity - if any market is invalid, we revert without changing state
         for i in 0..market_ids.len() {
@@ -2042,7 +3723,8 @@ impl PredictifyHybrid {
                 });
 
             // Check if user has already claimed from this market
-            if market.claimed.get(user.clone()).unwrap_or(false) {
+            let entry = VoteLedger::get_entry(&env, &market_id, &user);
+            if entry.as_ref().is_some_and(|entry| entry.claimed) {
                 panic_with_error!(env, Error::AlreadyClaimed);
             }
 
@@ -2051,14 +3733,25 @@ impl PredictifyHybrid {
                 panic_with_error!(env, Error::MarketNotResolved);
             }
 
+            // Check no dispute is active and the challenge window has closed
+            if let Err(e) = Self::check_claim_lock(&env, &market) {
+                panic_with_error!(env, e);
+            }
+
             // Check if user participated in this market
-            if !market.votes.contains_key(user.clone()) {
+            if entry.is_none() {
+                panic_with_error!(env, Error::NothingToClaim);
+            }
+
+            // Zero-winner pools are settled through settle_zero_winner_market
+            if market.get_winning_total() == 0 {
                 panic_with_error!(env, Error::NothingToClaim);
             }
         }
 
         // Second pass: Process all markets and calculate total winnings
         let mut total_payout: i128 = 0;
+        let mut immediate_payout: i128 = 0;
         let mut batch_claims: Vec<(Symbol, i128)> = Vec::new(&env);
 
         for i in 0..market_ids.len() {
@@ -2067,21 +3760,22 @@ impl PredictifyHybrid {
             let mut market: Market = env.storage().persistent().get(&market_id).unwrap();
 
             let winning_outcomes = market.winning_outcomes.clone().unwrap();
-            let user_outcome = market.votes.get(user.clone()).unwrap();
-            let user_stake = market.stakes.get(user.clone()).unwrap_or(0);
+            let VoteEntry {
+                outcome: user_outcome,
+                stake: user_stake,
+                ..
+            } = VoteLedger::get_entry(&env, &market_id, &user).unwrap();
 
             // Calculate payout if user won
             let market_payout = if winning_outcomes.contains(&user_outcome) {
-                // Calculate total winning stakes
-                let mut winning_total = 0;
-                for (voter, outcome) in market.votes.iter() {
-                    if winning_outcomes.contains(&outcome) {
-                        winning_total += market.stakes.get(voter.clone()).unwrap_or(0);
-                    }
-                }
+                // Scalar markets weight each winning position by how close its bucket came
+                let winning_total = market.weighted_winning_total();
+                let weighted_stake = user_stake * market.payout_weight(&user_outcome);
 
                 if winning_total > 0 {
-                    let user_share = (user_stake
+                    // Each market uses its own pinned platform fee
+                    let fee_percent = market.platform_fee_percentage;
+                    let user_share = (weighted_stake
                         .checked_mul(PERCENTAGE_DENOMINATOR - fee_percent)
                         .unwrap_or_else(|| panic_with_error!(env, Error::InvalidInput)))
                         / PERCENTAGE_DENOMINATOR;
@@ -2092,23 +3786,41 @@ impl PredictifyHybrid {
                     let payout = product / winning_total;
 
                     // Calculate fee for statistics
-                    let product_gross = user_stake
+                    let product_gross = weighted_stake
                         .checked_mul(total_pool)
                         .unwrap_or_else(|| panic_with_error!(env, Error::InvalidInput));
                     let gross_payout = product_gross / winning_total;
                     let fee_amount = gross_payout - payout;
 
-                    statistics::StatisticsManager::record_fees_collected(&env, fee_amount);
-                    payout
+                    // Leave the market unclaimed and flag it for recovery
+                    if market.exceeds_max_payout(user_stake, payout) {
+                        crate::recovery::RecoveryManager::flag_payout_anomaly(
+                            &env, &market_id, payout,
+                        );
+                        continue;
+                    }
+
+                    statistics::StatisticsManager::record_fees_collected(&env, fee_amount);
+                    statistics::StatisticsManager::record_creator_fees(
+                        &env,
+                        &market.admin,
+                        fee_amount,
+                    );
+                    payout
                 } else {
                     0
                 }
             } else {
-                0
+                // Losing voters collect their share of the fee rebate, if any
+                let rebate = market.loser_rebate_for(user_stake);
+                if rebate > 0 {
+                    EventEmitter::emit_loser_rebate_claimed(&env, &market_id, &user, rebate);
+                }
+                rebate
             };
 
             // Update market state: mark as claimed
-            market.claimed.set(user.clone(), true);
+            VoteLedger::mark_claimed(&env, &market_id, &mut market, &user);
             env.storage().persistent().set(&market_id, &market);
 
             // Track claim for event emission
@@ -2116,6 +3828,17 @@ impl PredictifyHybrid {
             total_payout = total_payout
                 .checked_add(market_payout)
                 .unwrap_or_else(|| panic_with_error!(env, Error::InvalidInput));
+
+            // Jumbo payouts vest per market; the rest is credited below
+            if market_payout > vesting::VestingManager::get_policy(&env).threshold {
+                if let Err(e) =
+                    vesting::VestingManager::credit_winnings(&env, &market_id, &user, market_payout)
+                {
+                    panic_with_error!(env, e);
+                }
+            } else {
+                immediate_payout += market_payout;
+            }
         }
 
         // Record total winnings claimed in statistics
@@ -2125,12 +3848,12 @@ impl PredictifyHybrid {
         EventEmitter::emit_winnings_claimed_batch(&env, &user, &batch_claims, total_payout);
 
         // Credit total tokens to user balance (single operation)
-        if total_payout > 0 {
+        if immediate_payout > 0 {
             match storage::BalanceStorage::add_balance(
                 &env,
                 &user,
                 &types::ReflectorAsset::Stellar,
-                total_payout,
+                immediate_payout,
             ) {
                 Ok(_) => {}
                 Err(e) => panic_with_error!(env, e),
@@ -2195,6 +3918,87 @@ impl PredictifyHybrid {
         env.storage().persistent().get(&market_id)
     }
 
+    /// Retrieves a lightweight summary of a market.
+    ///
+    /// Returns only scalar fields and aggregates (voter, claim and disputer
+    /// counts, per-outcome totals), so the response size does not grow with
+    /// participation. Use `get_voters`, `get_market_claims` and
+    /// `get_market_dispute_stakes` to page through per-user data.
+    ///
+    /// Returns `None` if the market does not exist.
+    pub fn get_market_summary(env: Env, market_id: Symbol) -> Option<MarketSummary> {
+        QueryManager::query_market_summary(&env, market_id).ok()
+    }
+
+    /// Page through a market's voters as `(address, outcome, stake)` triples.
+    ///
+    /// Lets explorers list participants of large markets without loading the
+    /// full `Market`. `limit` must be between 1 and `MAX_QUERY_PAGE_SIZE`.
+    pub fn get_voters(
+        env: Env,
+        market_id: Symbol,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<(Address, String, i128)>, Error> {
+        QueryManager::query_market_voters(&env, market_id, offset, limit)
+    }
+
+    /// Page through the addresses that have claimed from a market.
+    ///
+    /// `limit` must be between 1 and `MAX_QUERY_PAGE_SIZE`.
+    pub fn get_market_claims(
+        env: Env,
+        market_id: Symbol,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<Address>, Error> {
+        QueryManager::query_market_claims(&env, market_id, offset, limit)
+    }
+
+    /// Page through a market's dispute stakes as `(address, stake)` pairs.
+    ///
+    /// `limit` must be between 1 and `MAX_QUERY_PAGE_SIZE`.
+    pub fn get_market_dispute_stakes(
+        env: Env,
+        market_id: Symbol,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<(Address, i128)>, Error> {
+        QueryManager::query_market_dispute_stakes(&env, market_id, offset, limit)
+    }
+
+    /// Page through a user's positions across markets, in the order they
+    /// joined them.
+    ///
+    /// Each position carries the outcome backed, the stake, the amount a
+    /// claim would credit now and whether it was claimed. `limit` must be
+    /// between 1 and `MAX_QUERY_PAGE_SIZE`.
+    pub fn get_user_positions(
+        env: Env,
+        user: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<types::UserPosition>, Error> {
+        QueryManager::query_user_positions(&env, &user, offset, limit)
+    }
+
+    /// Get the live implied probability and payout multiplier of every
+    /// outcome of a market, derived from the stake on each outcome.
+    ///
+    /// Probabilities and multipliers are in basis points; multipliers are
+    /// net of the platform fee.
+    pub fn get_market_odds(env: Env, market_id: Symbol) -> Result<Vec<types::OutcomeOdds>, Error> {
+        QueryManager::query_market_odds(&env, &market_id)
+    }
+
+    /// Get a user's net directional exposure per underlying oracle feed.
+    ///
+    /// Aggregates open positions across unsettled markets so that risk
+    /// dashboards can see long and short stakes on correlated markets net out.
+    pub fn get_net_exposure(env: Env, user: Address) -> NetExposureQuery {
+        QueryManager::query_net_exposure(&env, user)
+    }
+
     /// Manually resolves a prediction market by setting the winning outcome (admin only).
     ///
     /// This function allows contract administrators to manually resolve markets
@@ -2291,6 +4095,13 @@ impl PredictifyHybrid {
             panic_with_error!(env, Error::MarketClosed);
         }
 
+        // Degenerate markets are cancelled for refunds instead
+        match edge_cases::EdgeCaseHandler::cancel_if_degenerate(&env, &market_id) {
+            Ok(true) => return,
+            Ok(false) => {}
+            Err(e) => panic_with_error!(env, e),
+        }
+
         // Validate winning outcome
         let outcome_exists = market.outcomes.iter().any(|o| o == winning_outcome);
         if !outcome_exists {
@@ -2303,9 +4114,12 @@ impl PredictifyHybrid {
         // Set winning outcome(s) as a vector (single outcome for now, supports future multi-winner)
         let mut winning_outcomes_vec = Vec::new(&env);
         winning_outcomes_vec.push_back(winning_outcome.clone());
+        markets::MarketStateManager::snapshot_consensus(&env, &mut market);
         market.winning_outcomes = Some(winning_outcomes_vec.clone());
+        market.precompute_outcome_totals();
         market.state = MarketState::Resolved;
         env.storage().persistent().set(&market_id, &market);
+        resolution::ResolutionSlaTracker::record_resolution(&env, &market_id, &market);
 
         // Decrement active event count for the creator since the market is no longer active
         crate::storage::CreatorLimitsManager::decrement_active_events(&env, &market.admin);
@@ -2342,6 +4156,10 @@ impl PredictifyHybrid {
             &reason,
         );
 
+        if let Err(e) = fees::FeeManager::collect_fees_on_resolution(&env, &market_id) {
+            panic_with_error!(env, e);
+        }
+
         // Distribute payouts only after dispute window closes (or skip and allow finalize_after_window later)
         let now = env.ledger().timestamp();
         let payout_allowed = now >= market.end_time.saturating_add(market.dispute_window_seconds);
@@ -2450,6 +4268,13 @@ impl PredictifyHybrid {
             panic_with_error!(env, Error::MarketClosed);
         }
 
+        // Degenerate markets are cancelled for refunds instead
+        match edge_cases::EdgeCaseHandler::cancel_if_degenerate(&env, &market_id) {
+            Ok(true) => return,
+            Ok(false) => {}
+            Err(e) => panic_with_error!(env, e),
+        }
+
         // Validate all winning outcomes exist in market outcomes
         for outcome in winning_outcomes.iter() {
             let outcome_exists = market.outcomes.iter().any(|o| o == outcome);
@@ -2461,10 +4286,13 @@ impl PredictifyHybrid {
         // Capture old state for event
         let old_state = market.state.clone();
 
+        markets::MarketStateManager::snapshot_consensus(&env, &mut market);
         // Set winning outcome(s) - supports multiple winners for ties
         market.winning_outcomes = Some(winning_outcomes.clone());
+        market.precompute_outcome_totals();
         market.state = MarketState::Resolved;
         env.storage().persistent().set(&market_id, &market);
+        resolution::ResolutionSlaTracker::record_resolution(&env, &market_id, &market);
 
         // Decrement active event count for the creator since the market is no longer active
         crate::storage::CreatorLimitsManager::decrement_active_events(&env, &market.admin);
@@ -2804,6 +4632,23 @@ impl PredictifyHybrid {
         oracles::OracleIntegrationManager::get_oracle_result(&env, &market_id)
     }
 
+    /// Retrieves the oracle payload pinned to a market at resolution.
+    ///
+    /// Disputes and audits should use this snapshot instead of re-querying
+    /// the oracle, since the live feed keeps moving after resolution.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(OracleSnapshot)` - Price, threshold, feed and ledger data used
+    /// * `None` - Market not found or not yet resolved via oracle
+    pub fn get_resolution_snapshot(env: Env, market_id: Symbol) -> Option<OracleSnapshot> {
+        env.storage()
+            .persistent()
+            .get::<Symbol, Market>(&market_id)
+            .filter(|market| market.has_resolution_snapshot)
+            .map(|market| market.resolution_snapshot)
+    }
+
     /// Checks if a market's result has been verified via oracle.
     ///
     /// # Parameters
@@ -2871,7 +4716,8 @@ impl PredictifyHybrid {
     /// # Returns
     ///
     /// Returns `Result<(), Error>` where:
-    /// - `Ok(())` - Market resolved successfully
+    /// - `Ok(())` - Market resolved successfully, or an oracle outage was
+    ///   recorded for retry (see `get_resolution_attempts`)
     /// - `Err(Error)` - Specific error if resolution fails
     ///
     /// # Errors
@@ -2880,9 +4726,16 @@ impl PredictifyHybrid {
     /// - `Error::MarketNotFound` - Market with given ID doesn't exist
     /// - `Error::MarketNotEnded` - Market hasn't reached its end time
     /// - `Error::MarketResolved` - Market is already resolved
+    /// - `Error::MarketNotReady` - Retried before the backoff of a failed attempt elapsed
     /// - `Error::InsufficientData` - Not enough data for resolution
     /// - Resolution-specific errors from the resolution module
     ///
+    /// # Retries
+    ///
+    /// A failure caused by the oracle being unavailable is recorded rather
+    /// than returned, so the attempt survives the call. The next attempt is
+    /// allowed after a backoff that doubles with every failure.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -2927,9 +4780,22 @@ impl PredictifyHybrid {
     /// - Users can claim winnings
     /// - Market statistics are finalized
     pub fn resolve_market(env: Env, market_id: Symbol) -> Result<(), Error> {
+        resolution::ResolutionRetryTracker::check_backoff(&env, &market_id)?;
+        // Degenerate markets are cancelled for refunds instead
+        if edge_cases::EdgeCaseHandler::cancel_if_degenerate(&env, &market_id)? {
+            return Ok(());
+        }
         let gas_marker = crate::gas::GasTracker::start_tracking(&env);
         // Use the resolution module to resolve the market
-        let _resolution = resolution::MarketResolutionManager::resolve_market(&env, &market_id)?;
+        match resolution::MarketResolutionManager::resolve_market(&env, &market_id) {
+            Ok(_) => {}
+            Err(e) if resolution::ResolutionRetryTracker::is_retryable(e) => {
+                resolution::ResolutionRetryTracker::record_failure(&env, &market_id, e);
+                return Ok(());
+            }
+            Err(e) => return Err(e),
+        }
+        fees::FeeManager::collect_fees_on_resolution(&env, &market_id)?;
 
         statistics::StatisticsManager::record_market_resolved(&env);
 
@@ -2941,6 +4807,90 @@ impl PredictifyHybrid {
         Ok(())
     }
 
+    /// Optimistically assert the outcome of an ended market.
+    ///
+    /// The asserter locks `bond` (at least the configured minimum). If nobody
+    /// challenges within the challenge window, `settle_assertion` resolves the
+    /// market to `outcome` and returns the bond. Fails with `InvalidState` if
+    /// the market already has an oracle result or an assertion.
+    pub fn assert_outcome(
+        env: Env,
+        asserter: Address,
+        market_id: Symbol,
+        outcome: String,
+        bond: i128,
+    ) -> Result<resolution::OptimisticAssertion, Error> {
+        admin::ContractPauseManager::require_not_paused(&env)?;
+        asserter.require_auth();
+        resolution::OptimisticAssertionManager::assert_outcome(
+            &env, &asserter, &market_id, &outcome, bond,
+        )
+    }
+
+    /// Challenge a pending assertion, escalating the market to a dispute.
+    ///
+    /// `stake` becomes the challenger's dispute stake and must be at least
+    /// the asserter's bond. The dispute then runs as for any oracle result.
+    pub fn challenge_assertion(
+        env: Env,
+        challenger: Address,
+        market_id: Symbol,
+        stake: i128,
+        reason: Option<String>,
+    ) -> Result<(), Error> {
+        admin::ContractPauseManager::require_not_paused(&env)?;
+        // Authentication is required by `process_dispute`
+        resolution::OptimisticAssertionManager::challenge(
+            &env,
+            &challenger,
+            &market_id,
+            stake,
+            reason,
+        )
+    }
+
+    /// Settle a market's assertion and pay out its bond. Callable by anyone.
+    ///
+    /// Unchallenged assertions resolve the market once the challenge window
+    /// has closed. Challenged ones settle after the dispute is resolved; the
+    /// bond goes to the asserter if their outcome won, else to the challenger.
+    pub fn settle_assertion(env: Env, market_id: Symbol) -> Result<i128, Error> {
+        let was_resolved = markets::MarketStateManager::get_market(&env, &market_id)?
+            .winning_outcomes
+            .is_some();
+        let bond = resolution::OptimisticAssertionManager::settle(&env, &market_id)?;
+        if !was_resolved {
+            fees::FeeManager::collect_fees_on_resolution(&env, &market_id)?;
+            statistics::StatisticsManager::record_market_resolved(&env);
+        }
+        Ok(bond)
+    }
+
+    /// Get a market's optimistic assertion, if one was made
+    pub fn get_assertion(env: Env, market_id: Symbol) -> Option<resolution::OptimisticAssertion> {
+        resolution::OptimisticAssertionManager::get_assertion(&env, &market_id)
+    }
+
+    /// Set the assertion bond and challenge window (admin only)
+    pub fn set_assertion_config(
+        env: Env,
+        admin: Address,
+        config: resolution::AssertionConfig,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        resolution::OptimisticAssertionManager::set_config(&env, &admin, &config)
+    }
+
+    /// Get the assertion bond and challenge window in force
+    pub fn get_assertion_config(env: Env) -> resolution::AssertionConfig {
+        resolution::OptimisticAssertionManager::get_config(&env)
+    }
+
+    /// Get the failed oracle resolution attempts of a market and when it may be retried.
+    pub fn get_resolution_attempts(env: Env, market_id: Symbol) -> resolution::ResolutionAttempts {
+        resolution::ResolutionRetryTracker::get_attempts(&env, &market_id)
+    }
+
     /// Retrieves comprehensive analytics about market resolution performance.
     ///
     /// This function provides detailed statistics about how markets are being
@@ -2962,7 +4912,7 @@ impl PredictifyHybrid {
     /// - Resolution method breakdown (manual vs automatic)
     /// - Oracle accuracy statistics
     /// - Community consensus metrics
-    /// - Average resolution time
+    /// - Median and p95 time from market end to resolution
     /// - Dispute frequency and outcomes
     ///
     /// # Errors
@@ -3021,6 +4971,58 @@ impl PredictifyHybrid {
         resolution::MarketResolutionAnalytics::calculate_resolution_analytics(&env)
     }
 
+    /// Returns the confidence components of a pending resolution.
+    ///
+    /// Lets frontends show how contested a market is before it is resolved:
+    /// oracle certainty, community consensus strength and participation, plus
+    /// the blended hybrid score. Fails with `MarketResolved` once winners are set.
+    pub fn get_resolution_confidence(
+        env: Env,
+        market_id: Symbol,
+    ) -> Result<resolution::ResolutionConfidence, Error> {
+        resolution::MarketResolutionAnalytics::calculate_resolution_confidence(&env, &market_id)
+    }
+
+    /// Returns how a market's hybrid resolution is weighted and scored.
+    ///
+    /// Includes the oracle result, the community consensus, the oracle and
+    /// community weights applied to the market, its winning outcomes once
+    /// resolved and the blended confidence score.
+    pub fn get_resolution_breakdown(
+        env: Env,
+        market_id: Symbol,
+    ) -> Result<resolution::ResolutionBreakdown, Error> {
+        resolution::MarketResolutionAnalytics::get_resolution_breakdown(&env, &market_id)
+    }
+
+    /// Set the default oracle/community weights of the hybrid resolution (admin only).
+    ///
+    /// Weights are percentages and must sum to 100; 70/30 until changed.
+    pub fn set_resolution_weights(
+        env: Env,
+        admin: Address,
+        weights: resolution::ResolutionWeights,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        resolution::ResolutionWeightManager::set_global_weights(&env, &admin, &weights)
+    }
+
+    /// Override the oracle/community weights of an unresolved market (admin only).
+    pub fn set_market_resolution_weights(
+        env: Env,
+        admin: Address,
+        market_id: Symbol,
+        weights: resolution::ResolutionWeights,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        resolution::ResolutionWeightManager::set_market_weights(&env, &admin, &market_id, &weights)
+    }
+
+    /// Get the oracle/community weights applied to a market.
+    pub fn get_resolution_weights(env: Env, market_id: Symbol) -> resolution::ResolutionWeights {
+        resolution::ResolutionWeightManager::get_weights(&env, &market_id)
+    }
+
     /// Retrieves comprehensive analytics and statistics for a specific market.
     ///
     /// This function provides detailed statistical analysis of a market including
@@ -3128,10 +5130,64 @@ impl PredictifyHybrid {
         stake: i128,
         reason: Option<String>,
     ) -> Result<(), Error> {
-        user.require_auth();
+        // Authentication is required by `process_dispute`
+        ReentrancyGuard::check_reentrancy_state(&env).map_err(|_| Error::InvalidState)?;
+        rate_limiter::RateLimiter::new(env.clone())
+            .enforce_action(&user, rate_limiter::RateLimitedAction::Dispute);
         disputes::DisputeManager::process_dispute(&env, user, market_id, stake, reason)
     }
 
+    /// Fund a market's dispute insurance (market creator only).
+    ///
+    /// The deposit pays the dispute stake of any voter who challenges the
+    /// resolution, up to the remaining balance. Can be topped up until the
+    /// market resolves. Returns the new balance.
+    pub fn fund_dispute_insurance(
+        env: Env,
+        creator: Address,
+        market_id: Symbol,
+        amount: i128,
+    ) -> Result<i128, Error> {
+        creator.require_auth();
+        disputes::DisputeInsuranceManager::fund(&env, &creator, &market_id, amount)
+    }
+
+    /// Withdraw the unused dispute insurance once the market is resolved or
+    /// cancelled. Returns the amount refunded.
+    pub fn withdraw_dispute_insurance(
+        env: Env,
+        creator: Address,
+        market_id: Symbol,
+    ) -> Result<i128, Error> {
+        creator.require_auth();
+        disputes::DisputeInsuranceManager::withdraw(&env, &creator, &market_id)
+    }
+
+    /// Get a market's dispute insurance, if its creator opted in
+    pub fn get_dispute_insurance(
+        env: Env,
+        market_id: Symbol,
+    ) -> Option<disputes::DisputeInsurance> {
+        disputes::DisputeInsuranceManager::get_insurance(&env, &market_id)
+    }
+
+    /// Dispute a market resolution with a client-supplied nonce.
+    ///
+    /// `nonce` must be greater than the last nonce the user consumed.
+    /// Behaves exactly like `dispute_market` otherwise.
+    pub fn dispute_market_with_nonce(
+        env: Env,
+        user: Address,
+        market_id: Symbol,
+        stake: i128,
+        reason: Option<String>,
+        nonce: Option<u64>,
+    ) -> Result<(), Error> {
+        // Authentication is required by `dispute_market`; a failed dispute reverts the nonce
+        nonces::NonceManager::consume(&env, &user, nonce)?;
+        Self::dispute_market(env, user, market_id, stake, reason)
+    }
+
     /// Vote on a dispute
     pub fn vote_on_dispute(
         env: Env,
@@ -3142,39 +5198,117 @@ impl PredictifyHybrid {
         stake: i128,
         reason: Option<String>,
     ) -> Result<(), Error> {
-        user.require_auth();
+        // Authorization is required by DisputeManager::vote_on_dispute
+        ReentrancyGuard::check_reentrancy_state(&env).map_err(|_| Error::InvalidState)?;
         disputes::DisputeManager::vote_on_dispute(
             &env, user, market_id, dispute_id, vote, stake, reason,
         )
     }
 
-    /// Resolve a dispute (admin only)
-    pub fn resolve_dispute(
+    /// Create a market whose disputes are decided by a panel of arbitrators.
+    ///
+    /// The panel must have between `MIN_ARBITRATION_PANEL_SIZE` and
+    /// `MAX_ARBITRATION_PANEL_SIZE` distinct members, none of them the
+    /// creator, and cannot be changed afterwards. Open community dispute
+    /// voting is disabled for the market; arbitrators rule with
+    /// `arbitrate_dispute` and the panel majority becomes the final outcome.
+    pub fn create_market_with_arbitrators(
+        env: Env,
+        admin: Address,
+        question: String,
+        outcomes: Vec<String>,
+        duration_days: u32,
+        oracle_config: OracleConfig,
+        arbitrators: Vec<Address>,
+    ) -> Symbol {
+        // Validate before creating so an invalid panel leaves no market behind
+        if let Err(e) = disputes::ArbitrationPanelManager::validate_panel(&admin, &arbitrators) {
+            panic_with_error!(env, e);
+        }
+        let market_id = Self::create_market(
+            env.clone(),
+            admin.clone(),
+            question,
+            outcomes,
+            duration_days,
+            oracle_config,
+            None,
+            config::DEFAULT_RESOLUTION_TIMEOUT_SECONDS,
+            None,
+            None,
+            None,
+        );
+        if let Err(e) =
+            disputes::ArbitrationPanelManager::set_panel(&env, &market_id, &admin, arbitrators)
+        {
+            panic_with_error!(env, e);
+        }
+        market_id
+    }
+
+    /// Commit to the randomness of a market's arbitration panel draw (admin only).
+    ///
+    /// `commitment` is the SHA-256 of a secret 32-byte seed. The candidate
+    /// pool and panel size are fixed with it, and the seed can only be
+    /// revealed in a later ledger with `reveal_arbitration_draw`.
+    pub fn commit_arbitration_draw(
         env: Env,
         admin: Address,
         market_id: Symbol,
-    ) -> Result<disputes::DisputeResolution, Error> {
+        candidates: Vec<Address>,
+        panel_size: u32,
+        commitment: BytesN<32>,
+    ) -> Result<disputes::PanelDraw, Error> {
         admin.require_auth();
+        disputes::PanelDrawManager::commit(
+            &env, &admin, &market_id, candidates, panel_size, commitment,
+        )
+    }
 
-        // Verify admin
-        let stored_admin: Address = env
-            .storage()
-            .persistent()
-            .get(&Symbol::new(&env, "Admin"))
-            .unwrap_or_else(|| {
-                panic_with_error!(env, Error::Unauthorized);
-            });
+    /// Reveal the committed seed and seat the randomly drawn arbitration panel.
+    ///
+    /// Anyone can recompute the draw from the revealed seed (see
+    /// `get_arbitration_draw`). Returns the drawn arbitrators.
+    pub fn reveal_arbitration_draw(
+        env: Env,
+        market_id: Symbol,
+        seed: BytesN<32>,
+    ) -> Result<Vec<Address>, Error> {
+        disputes::PanelDrawManager::reveal(&env, &market_id, seed)
+    }
 
-        if admin != stored_admin {
-            panic_with_error!(env, Error::Unauthorized);
-        }
+    /// Get a market's arbitration panel draw commitment, if any.
+    pub fn get_arbitration_draw(env: Env, market_id: Symbol) -> Option<disputes::PanelDraw> {
+        disputes::PanelDrawManager::get_draw(&env, &market_id)
+    }
 
-        disputes::DisputeManager::resolve_dispute(&env, market_id, admin)
+    /// Rule on the open dispute of a market as one of its panel arbitrators.
+    pub fn arbitrate_dispute(
+        env: Env,
+        arbitrator: Address,
+        market_id: Symbol,
+        outcome: String,
+    ) -> Result<(), Error> {
+        arbitrator.require_auth();
+        disputes::ArbitrationPanelManager::cast_vote(&env, &arbitrator, &market_id, &outcome)
     }
 
-    /// Collect fees from a market (admin only)
-    pub fn collect_fees(env: Env, admin: Address, market_id: Symbol) -> Result<i128, Error> {
-        admin.require_auth();
+    /// Get the arbitration panel of a market, if it has one.
+    pub fn get_arbitration_panel(
+        env: Env,
+        market_id: Symbol,
+    ) -> Option<disputes::ArbitrationPanel> {
+        disputes::ArbitrationPanelManager::get_panel(&env, &market_id)
+    }
+
+    /// Resolve a dispute (admin only)
+    pub fn resolve_dispute(
+        env: Env,
+        admin: Address,
+        market_id: Symbol,
+    ) -> Result<disputes::DisputeResolution, Error> {
+        // Authorization is required by DisputeManager::resolve_dispute
+        ReentrancyGuard::check_reentrancy_state(&env).map_err(|_| Error::InvalidState)?;
 
         // Verify admin
         let stored_admin: Address = env
@@ -3189,29 +5323,422 @@ impl PredictifyHybrid {
             panic_with_error!(env, Error::Unauthorized);
         }
 
-        fees::FeeManager::collect_fees(&env, admin, market_id)
+        disputes::DisputeManager::resolve_dispute(&env, market_id, admin)
     }
 
-    /// Automatically distribute payouts to all winners after market resolution.
+    /// Attach evidence to a dispute as one of its disputers or voters.
     ///
-    /// This function automatically calculates and distributes winnings to all users
-    /// who bet on the winning outcome, eliminating the need for manual claiming.
-    /// It handles edge cases like no winners, all winners, and prevents double payouts.
+    /// Stores the SHA-256 `content_hash` of the document and the `uri` it
+    /// can be fetched from. Returns the attachment's index.
     ///
-    /// # Parameters
+    /// # Errors
     ///
-    /// * `env` - The Soroban environment for blockchain operations
-    /// * `market_id` - Unique identifier of the resolved market
+    /// * `MarketResolved` - The market is already resolved
+    /// * `Unauthorized` - Caller neither disputed the market nor voted on the dispute
+    /// * `InvalidInput` - Empty or overlong URI, or the dispute has
+    ///   `MAX_DISPUTE_EVIDENCE` attachments already
+    pub fn submit_dispute_evidence(
+        env: Env,
+        submitter: Address,
+        market_id: Symbol,
+        dispute_id: Symbol,
+        content_hash: BytesN<32>,
+        uri: String,
+    ) -> Result<u32, Error> {
+        submitter.require_auth();
+        disputes::DisputeEvidenceManager::submit(
+            &env,
+            &submitter,
+            &market_id,
+            &dispute_id,
+            content_hash,
+            uri,
+        )
+    }
+
+    /// Get the evidence attached to a dispute, in submission order
+    pub fn get_dispute_evidence(
+        env: Env,
+        market_id: Symbol,
+        dispute_id: Symbol,
+    ) -> Vec<disputes::DisputeEvidence> {
+        disputes::DisputeEvidenceManager::get_evidence(&env, &market_id, &dispute_id)
+    }
+
+    /// Appeal a dispute ruling into the next round (escalation ladder).
     ///
-    /// # Returns
+    /// Allowed within the appeal window after the current round's voting
+    /// closes. The appellant posts twice the previous round's bond; the
+    /// initial dispute's bond is its total dispute stake. Each appeal
+    /// reopens community voting, except the last round, which the
+    /// arbitration council decides. Returns the updated ladder.
     ///
-    /// Returns `Result<i128, Error>` where:
-    /// - `Ok(total_distributed)` - Total amount distributed to winners
-    /// - `Err(Error)` - Error if distribution fails
+    /// # Errors
     ///
-    /// # Panics
+    /// * `DisputeCondNotMet` - No open dispute, or outside the appeal window
+    /// * `DisputeNoEscalate` - The dispute is already in its final round
+    pub fn appeal_dispute(
+        env: Env,
+        user: Address,
+        market_id: Symbol,
+    ) -> Result<disputes::DisputeLadder, Error> {
+        user.require_auth();
+        ReentrancyGuard::check_reentrancy_state(&env).map_err(|_| Error::InvalidState)?;
+        disputes::DisputeAppealManager::appeal(&env, &user, &market_id)
+    }
+
+    /// Get the escalation ladder of a market's dispute, if it was appealed
+    pub fn get_dispute_ladder(env: Env, market_id: Symbol) -> Option<disputes::DisputeLadder> {
+        disputes::DisputeAppealManager::get_ladder(&env, &market_id)
+    }
+
+    /// Set the appeal window and number of dispute rounds (admin only).
     ///
-    /// This function will panic with specific errors if:
+    /// `max_rounds` counts the initial dispute and must be between 1 and
+    /// `MAX_DISPUTE_ROUNDS`; `window_seconds` must be positive.
+    pub fn set_dispute_appeal_policy(
+        env: Env,
+        admin: Address,
+        policy: disputes::DisputeAppealPolicy,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        disputes::DisputeAppealManager::set_policy(&env, &admin, &policy)
+    }
+
+    /// Get the appeal window and number of dispute rounds
+    pub fn get_dispute_appeal_policy(env: Env) -> disputes::DisputeAppealPolicy {
+        disputes::DisputeAppealManager::get_policy(&env)
+    }
+
+    /// Seat the first arbitration council (admin only).
+    ///
+    /// Later membership changes go through `propose_council_change`.
+    pub fn set_arbitration_council(
+        env: Env,
+        admin: Address,
+        members: Vec<Address>,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        council::ArbitrationCouncil::set_members(&env, &admin, members)
+    }
+
+    /// Get the arbitration council members
+    pub fn get_arbitration_council(env: Env) -> Vec<Address> {
+        council::ArbitrationCouncil::get_members(&env)
+    }
+
+    /// Set the arbitration council's quorum and majority (admin only).
+    ///
+    /// `quorum_bps` must be between 1 and 10000, and `majority_bps` at least
+    /// 5000 and below 10000.
+    pub fn set_council_policy(
+        env: Env,
+        admin: Address,
+        policy: council::CouncilPolicy,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        council::ArbitrationCouncil::set_policy(&env, &admin, &policy)
+    }
+
+    /// Get the arbitration council's quorum and majority
+    pub fn get_council_policy(env: Env) -> council::CouncilPolicy {
+        council::ArbitrationCouncil::get_policy(&env)
+    }
+
+    /// Open a governance proposal to add (`add = true`) or remove a council member.
+    ///
+    /// # Errors
+    ///
+    /// * `InvalidState` - Governance is not initialized
+    /// * `InvalidInput` - The proposal ID is taken, the member is already
+    ///   (or not) seated, or the council would leave its size bounds
+    pub fn propose_council_change(
+        env: Env,
+        proposer: Address,
+        proposal_id: Symbol,
+        member: Address,
+        add: bool,
+    ) -> Result<council::CouncilChange, Error> {
+        proposer.require_auth();
+        council::ArbitrationCouncil::propose_change(&env, &proposer, &proposal_id, &member, add)
+    }
+
+    /// Apply a council membership proposal that passed. Callable by anyone.
+    ///
+    /// Returns the new council. Fails with `MarketNotReady` while the
+    /// proposal is being voted on and `InvalidState` if it was rejected.
+    pub fn apply_council_change(env: Env, proposal_id: Symbol) -> Result<Vec<Address>, Error> {
+        council::ArbitrationCouncil::apply_change(&env, &proposal_id)
+    }
+
+    /// Rule on a contested dispute as an arbitration council member.
+    ///
+    /// Disputes are contested once escalated or appealed into their final
+    /// round. The vote that meets the council's quorum and majority settles
+    /// the dispute and returns its resolution.
+    pub fn council_vote(
+        env: Env,
+        member: Address,
+        market_id: Symbol,
+        outcome: String,
+    ) -> Result<Option<disputes::DisputeResolution>, Error> {
+        member.require_auth();
+        ReentrancyGuard::check_reentrancy_state(&env).map_err(|_| Error::InvalidState)?;
+        match council::ArbitrationCouncil::cast_vote(&env, &member, &market_id, &outcome)? {
+            Some(decision) => {
+                disputes::DisputeManager::resolve_by_council(&env, &market_id, decision).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Settle a dispute early once its vote reaches the supermajority policy.
+    ///
+    /// Callable by anyone while dispute voting is open. Fails with
+    /// `DisputeCondNotMet` unless the vote stake reaches the policy minimum
+    /// and one side holds the supermajority.
+    pub fn resolve_dispute_early(
+        env: Env,
+        market_id: Symbol,
+        dispute_id: Symbol,
+    ) -> Result<disputes::DisputeResolution, Error> {
+        disputes::DisputeManager::resolve_dispute_early(&env, market_id, dispute_id)
+    }
+
+    /// Pay out the vote stakes of a dispute once its voting has closed.
+    ///
+    /// Callable by anyone after the voting window ends. Losing voters forfeit
+    /// the slash share of their stake (see `set_dispute_slash_bps`) and get
+    /// the rest back; winning voters get their stake plus a pro-rata share of
+    /// the forfeited total. Emits `DisputeSettledEvent`.
+    ///
+    /// # Errors
+    ///
+    /// * `DisputeCondNotMet` - Voting is still open
+    /// * `DisputeFeeFailed` - The stakes were already settled
+    pub fn settle_dispute_stakes(
+        env: Env,
+        dispute_id: Symbol,
+    ) -> Result<disputes::DisputeFeeDistribution, Error> {
+        ReentrancyGuard::check_reentrancy_state(&env).map_err(|_| Error::InvalidState)?;
+        disputes::DisputeManager::distribute_dispute_fees(&env, dispute_id)
+    }
+
+    /// Set the share of losing dispute vote stake forfeited to the winners
+    /// (admin only), in basis points of at most 10000.
+    pub fn set_dispute_slash_bps(env: Env, admin: Address, slash_bps: u32) -> Result<(), Error> {
+        admin.require_auth();
+        disputes::DisputeSlashManager::set_slash_bps(&env, &admin, slash_bps)
+    }
+
+    /// Get the share of losing dispute vote stake forfeited to the winners
+    pub fn get_dispute_slash_bps(env: Env) -> u32 {
+        disputes::DisputeSlashManager::get_slash_bps(&env)
+    }
+
+    /// Set the vote needed to settle disputes early (admin only).
+    ///
+    /// `supermajority_bps` must be above 5000 and at most 10000.
+    pub fn set_dispute_supermajority_policy(
+        env: Env,
+        admin: Address,
+        policy: disputes::DisputeSupermajorityPolicy,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        disputes::DisputeSupermajorityManager::set_policy(&env, &admin, &policy)
+    }
+
+    /// Get the vote needed to settle disputes early
+    pub fn get_dispute_supermajority_policy(env: Env) -> disputes::DisputeSupermajorityPolicy {
+        disputes::DisputeSupermajorityManager::get_policy(&env)
+    }
+
+    /// Require dispute stakes to be a share of the market pool (admin only).
+    ///
+    /// The minimum stake to open a dispute becomes `pool_bps` of the market's
+    /// total stake, clamped to `[floor, ceiling]`. It is computed when the
+    /// dispute is opened and recorded on the dispute. `floor` may not be
+    /// below `MIN_DISPUTE_STAKE`.
+    pub fn set_dispute_stake_policy(
+        env: Env,
+        admin: Address,
+        pool_bps: u32,
+        floor: i128,
+        ceiling: i128,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        let policy = disputes::DisputeStakePolicy {
+            pool_bps,
+            floor,
+            ceiling,
+        };
+        disputes::DisputeStakePolicyManager::set_policy(&env, &admin, &policy)
+    }
+
+    /// Get the pool-relative dispute stake policy, if configured
+    pub fn get_dispute_stake_policy(env: Env) -> Option<disputes::DisputeStakePolicy> {
+        disputes::DisputeStakePolicyManager::get_policy(&env)
+    }
+
+    /// Get the minimum stake currently required to dispute a market
+    pub fn get_required_dispute_stake(env: Env, market_id: Symbol) -> Result<i128, Error> {
+        let market = markets::MarketStateManager::get_market(&env, &market_id)?;
+        Ok(disputes::DisputeStakePolicyManager::required_stake(
+            &env, &market,
+        ))
+    }
+
+    /// Fund the protocol reward emission pool. Returns the new pool balance.
+    pub fn fund_reward_emissions(env: Env, funder: Address, amount: i128) -> i128 {
+        funder.require_auth();
+        rewards::RewardsManager::fund_emissions(&env, &funder, amount)
+            .unwrap_or_else(|e| panic_with_error!(env, e))
+    }
+
+    /// Get the protocol reward emission pool balance
+    pub fn get_reward_emission_pool(env: Env) -> i128 {
+        rewards::RewardsStorage::emission_pool(&env)
+    }
+
+    /// Get the rewards accrued to `user` and not yet claimed
+    pub fn get_accrued_rewards(env: Env, user: Address) -> i128 {
+        rewards::RewardsStorage::accrued(&env, &user)
+    }
+
+    /// Claim accrued protocol rewards, such as dispute participation
+    /// rewards. Returns the amount transferred.
+    pub fn claim_rewards(env: Env, user: Address) -> Result<i128, Error> {
+        user.require_auth();
+        rewards::RewardsManager::claim(&env, &user)
+    }
+
+    /// Get the chronological dispute case history of a market
+    ///
+    /// Returns openings, community votes, escalation, fee distribution and
+    /// resolution entries ordered by timestamp, so clients can render the
+    /// full case history without an indexer.
+    pub fn get_dispute_timeline(
+        env: Env,
+        market_id: Symbol,
+    ) -> Result<Vec<disputes::DisputeTimelineEntry>, Error> {
+        disputes::DisputeManager::get_dispute_timeline(&env, market_id)
+    }
+
+    /// Collect fees from a market (admin only)
+    pub fn collect_fees(env: Env, admin: Address, market_id: Symbol) -> Result<i128, Error> {
+        // Authentication is performed by FeeManager::collect_fees
+        ReentrancyGuard::check_reentrancy_state(&env).map_err(|_| Error::InvalidState)?;
+
+        // Verify admin
+        let stored_admin: Address = env
+            .storage()
+            .persistent()
+            .get(&Symbol::new(&env, "Admin"))
+            .unwrap_or_else(|| {
+                panic_with_error!(env, Error::Unauthorized);
+            });
+
+        if admin != stored_admin {
+            panic_with_error!(env, Error::Unauthorized);
+        }
+
+        fees::FeeManager::collect_fees(&env, admin, market_id)
+    }
+
+    /// Enable or disable automatic fee collection when a market resolves (admin only).
+    ///
+    /// Enabled by default: `resolve_market` and `resolve_market_manual` credit
+    /// the platform fee to the contract treasury. When disabled, fees are left
+    /// for `collect_fees`.
+    pub fn set_auto_fee_collection(
+        env: Env,
+        admin: Address,
+        market_id: Symbol,
+        enabled: bool,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        fees::FeeManager::set_auto_collect(&env, &admin, &market_id, enabled)
+    }
+
+    /// Whether a market's platform fee is collected automatically at resolution.
+    pub fn is_auto_fee_collection_enabled(env: Env, market_id: Symbol) -> bool {
+        fees::FeeManager::is_auto_collect_enabled(&env, &market_id)
+    }
+
+    /// Give a market's creator a cut of its platform fee, in basis points (admin only).
+    ///
+    /// The cut is taken from every fee collected from the market, whether by
+    /// `collect_fees` or at resolution, and accrues until the creator calls
+    /// `claim_creator_fees`. Capped at 50% and fixed once fees are collected.
+    pub fn set_creator_fee_bps(
+        env: Env,
+        admin: Address,
+        market_id: Symbol,
+        creator_fee_bps: u32,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        fees::FeeManager::set_creator_fee_bps(&env, &admin, &market_id, creator_fee_bps)
+    }
+
+    /// Claim the platform fee share accrued to a market's creator.
+    ///
+    /// Returns the amount transferred; fails with `NothingToClaim` when
+    /// nothing is outstanding.
+    pub fn claim_creator_fees(
+        env: Env,
+        creator: Address,
+        market_id: Symbol,
+    ) -> Result<i128, Error> {
+        creator.require_auth();
+        fees::FeeManager::claim_creator_fees(&env, &creator, &market_id)
+    }
+
+    /// Get a market's creator fee share and how much of it was accrued and claimed.
+    pub fn get_creator_fee_share(env: Env, market_id: Symbol) -> fees::CreatorFeeShare {
+        fees::FeeManager::get_creator_fee_share(&env, &market_id)
+    }
+
+    /// Set how `collect_fees` treats markets below the fee collection threshold (admin only).
+    ///
+    /// With `Flat` a reduced fee (capped at the fee withheld from winners'
+    /// payouts) is collected and with `Waive` nothing is collected and the
+    /// winners are paid the whole pool; in both cases the market is marked
+    /// as fee-processed so it can be archived. `Reject` restores the default.
+    pub fn set_small_market_fee_policy(
+        env: Env,
+        admin: Address,
+        policy: fees::SmallMarketFeePolicy,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        fees::FeeValidator::validate_admin_permissions(&env, &admin)?;
+        fees::FeeConfigManager::store_small_market_fee_policy(&env, &policy)
+    }
+
+    /// Get the fee policy applied to markets below the fee collection threshold.
+    pub fn get_small_market_fee_policy(env: Env) -> fees::SmallMarketFeePolicy {
+        fees::FeeConfigManager::get_small_market_fee_policy(&env)
+    }
+
+    /// Automatically distribute payouts to all winners after market resolution.
+    ///
+    /// This function automatically calculates and distributes winnings to all users
+    /// who bet on the winning outcome, eliminating the need for manual claiming.
+    /// It handles edge cases like no winners, all winners, and prevents double payouts.
+    ///
+    /// # Parameters
+    ///
+    /// * `env` - The Soroban environment for blockchain operations
+    /// * `market_id` - Unique identifier of the resolved market
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, Error>` where:
+    /// - `Ok(total_distributed)` - Total amount distributed to winners
+    /// - `Err(Error)` - Error if distribution fails
+    ///
+    /// # Panics
+    ///
+    /// This function will panic with specific errors if:
     /// - `Error::MarketNotFound` - Market with given ID doesn't exist
     /// - `Error::MarketNotResolved` - Market hasn't been resolved yet
     /// - `Error::MarketResolved` - Payouts have already been distributed
@@ -3261,7 +5788,7 @@ impl PredictifyHybrid {
             });
 
         // Check if market is resolved
-        let winning_outcomes = match &market.winning_outcomes {
+        let winning_outcomes = match market.winning_outcomes.clone() {
             Some(outcomes) => outcomes,
             None => return Err(Error::MarketNotResolved),
         };
@@ -3282,20 +5809,19 @@ impl PredictifyHybrid {
             .get(&Symbol::new(&env, "platform_fee"))
             .unwrap_or(200); // Default 2% if not set
 
-        // Since place_bet now updates market.votes and market.stakes,
+        // Since place_bet now records bets in the vote ledger,
         // we can use the vote-based payout system for both bets and votes
         let _total_distributed = 0;
+        let entries = VoteLedger::get_entries(&env, &market_id, &market);
 
         // Check if payouts have already been distributed
         let mut has_unclaimed_winners = false;
 
         // Check voters
-        for (user, outcome) in market.votes.iter() {
-            if winning_outcomes.contains(&outcome) {
-                if !market.claimed.get(user.clone()).unwrap_or(false) {
-                    has_unclaimed_winners = true;
-                    break;
-                }
+        for (_, entry) in entries.iter() {
+            if winning_outcomes.contains(&entry.outcome) && !entry.claimed {
+                has_unclaimed_winners = true;
+                break;
             }
         }
 
@@ -3304,7 +5830,7 @@ impl PredictifyHybrid {
             for user in bettors.iter() {
                 if let Some(bet) = bets::BetStorage::get_bet(&env, &market_id, &user) {
                     if winning_outcomes.contains(&bet.outcome)
-                        && !market.claimed.get(user.clone()).unwrap_or(false)
+                        && !VoteLedger::has_claimed(&env, &market_id, &user)
                     {
                         has_unclaimed_winners = true;
                         break;
@@ -3319,19 +5845,12 @@ impl PredictifyHybrid {
 
         // Calculate total winning stakes across all winning outcomes (for split pool calculation)
         // Supports both single winner and multi-winner (tie) scenarios
-        let mut winning_total = 0;
-
-        // Sum voter stakes
-        for (voter, outcome) in market.votes.iter() {
-            if winning_outcomes.contains(&outcome) {
-                winning_total += market.stakes.get(voter.clone()).unwrap_or(0);
-            }
-        }
+        let mut winning_total = market.weighted_winning_total();
 
         // Sum bet amounts (check if bet outcome is in winning outcomes for multi-outcome support)
         for user in bettors.iter() {
             // Avoid double counting if user is already in votes (legacy support)
-            if market.votes.contains_key(user.clone()) {
+            if VoteLedger::get_entry(&env, &market_id, &user).is_some() {
                 continue;
             }
 
@@ -3354,16 +5873,17 @@ impl PredictifyHybrid {
         // 1. Distribute to Voters
         // Distribute payouts to all winners (handles both single and multi-winner cases)
         // For multi-winner (ties), pool is split proportionally among all winners
-        for (user, outcome) in market.votes.iter() {
-            if winning_outcomes.contains(&outcome) {
-                if market.claimed.get(user.clone()).unwrap_or(false) {
+        for (user, entry) in entries.iter() {
+            if winning_outcomes.contains(&entry.outcome) {
+                if entry.claimed {
                     continue;
                 }
 
-                let user_stake = market.stakes.get(user.clone()).unwrap_or(0);
+                let user_stake = entry.stake;
                 if user_stake > 0 {
                     let fee_denominator = 10000i128;
-                    let user_share = (user_stake
+                    let weighted_stake = user_stake * market.payout_weight(&entry.outcome);
+                    let user_share = (weighted_stake
                         .checked_mul(fee_denominator - fee_percent)
                         .ok_or(Error::InvalidInput)?)
                         / fee_denominator;
@@ -3374,23 +5894,33 @@ impl PredictifyHybrid {
                         .ok_or(Error::InvalidInput)?)
                         / winning_total;
 
+                    if market.exceeds_max_payout(user_stake, payout) {
+                        crate::recovery::RecoveryManager::flag_payout_anomaly(
+                            &env, &market_id, payout,
+                        );
+                        continue;
+                    }
+
                     if payout >= 0 {
                         // Allow 0 payout but mark as claimed
-                        market.claimed.set(user.clone(), true);
+                        VoteLedger::mark_claimed(&env, &market_id, &mut market, &user);
+                        // Transferred claim tickets are paid to their holder
+                        let recipient =
+                            tickets::ClaimTicketManager::payout_recipient(&env, &market_id, &user);
+                        tickets::ClaimTicketManager::consume(&env, &market_id, &user);
                         if payout > 0 {
                             total_distributed = total_distributed
                                 .checked_add(payout)
                                 .ok_or(Error::InvalidInput)?;
 
-                            // Credit winnings to user balance
-                            storage::BalanceStorage::add_balance(
-                                &env,
-                                &user,
-                                &types::ReflectorAsset::Stellar,
-                                payout,
+                            // Credit winnings to user balance (jumbo payouts vest)
+                            vesting::VestingManager::credit_winnings(
+                                &env, &market_id, &recipient, payout,
                             )?;
 
-                            EventEmitter::emit_winnings_claimed(&env, &market_id, &user, payout);
+                            EventEmitter::emit_winnings_claimed(
+                                &env, &market_id, &recipient, payout,
+                            );
                         }
                     }
                 }
@@ -3402,7 +5932,7 @@ impl PredictifyHybrid {
         for user in bettors.iter() {
             if let Some(mut bet) = bets::BetStorage::get_bet(&env, &market_id, &user) {
                 if winning_outcomes.contains(&bet.outcome) {
-                    if market.claimed.get(user.clone()).unwrap_or(false) {
+                    if VoteLedger::has_claimed(&env, &market_id, &user) {
                         // Already claimed (perhaps as a voter or double check)
                         bet.status = BetStatus::Won;
                         let _ = bets::BetStorage::store_bet(&env, &bet);
@@ -3414,25 +5944,35 @@ impl PredictifyHybrid {
                             (bet.amount * (fee_denominator - fee_percent)) / fee_denominator;
                         let payout = (user_share * total_pool) / winning_total;
 
+                        if market.exceeds_max_payout(bet.amount, payout) {
+                            crate::recovery::RecoveryManager::flag_payout_anomaly(
+                                &env, &market_id, payout,
+                            );
+                            continue;
+                        }
+
                         if payout > 0 {
-                            market.claimed.set(user.clone(), true);
+                            VoteLedger::mark_claimed(&env, &market_id, &mut market, &user);
                             total_distributed += payout;
 
                             // Update bet status
                             bet.status = BetStatus::Won;
                             let _ = bets::BetStorage::store_bet(&env, &bet);
 
+                            let recipient = tickets::ClaimTicketManager::payout_recipient(
+                                &env, &market_id, &user,
+                            );
+                            tickets::ClaimTicketManager::consume(&env, &market_id, &user);
+
                             // Credit winnings to user balance instead of direct transfer
-                            match storage::BalanceStorage::add_balance(
-                                &env,
-                                &user,
-                                &types::ReflectorAsset::Stellar,
-                                payout,
+                            if let Err(e) = vesting::VestingManager::credit_winnings(
+                                &env, &market_id, &recipient, payout,
                             ) {
-                                Ok(_) => {}
-                                Err(e) => panic_with_error!(env, e),
+                                panic_with_error!(env, e);
                             }
-                            EventEmitter::emit_winnings_claimed(&env, &market_id, &user, payout);
+                            EventEmitter::emit_winnings_claimed(
+                                &env, &market_id, &recipient, payout,
+                            );
                         }
                     }
                 } else {
@@ -3459,10 +5999,56 @@ impl PredictifyHybrid {
 
     /// Finalize payouts after the dispute window has closed. Callable by anyone once
     /// market is resolved and current time >= end_time + dispute_window_seconds.
+    ///
+    /// Also marks the result final (see `finalize_result`) if it is not yet.
     pub fn finalize_after_window(env: Env, market_id: Symbol) -> Result<i128, Error> {
+        if !resolution::ResultFinality::is_final(&env, &market_id) {
+            resolution::ResultFinality::finalize(&env, &market_id)?;
+        }
         Self::distribute_payouts(env, market_id)
     }
 
+    /// Mark a resolved market's result final once its dispute window has closed.
+    ///
+    /// Callable by anyone. Moves the market from `MarketResolved` to
+    /// `Finalized` and returns the finalization timestamp.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::MarketNotResolved` - Market is not resolved (or is disputed)
+    /// - `Error::InvalidState` - Dispute window still open, or already final
+    pub fn finalize_result(env: Env, market_id: Symbol) -> Result<u64, Error> {
+        resolution::ResultFinality::finalize(&env, &market_id)
+    }
+
+    /// Whether a market's result is final and can no longer be overturned.
+    ///
+    /// Integrating contracts should only consume outcomes for which this
+    /// returns `true`.
+    pub fn is_final(env: Env, market_id: Symbol) -> bool {
+        resolution::ResultFinality::is_final(&env, &market_id)
+    }
+
+    /// Hash-committed attestation of a finalized market result.
+    ///
+    /// Designed to be relayed by bridges so other chains can consume
+    /// Predictify results. Fails with `MarketNotResolved` before resolution
+    /// and `InvalidState` until the result is finalized.
+    pub fn get_resolution_attestation(
+        env: Env,
+        market_id: Symbol,
+    ) -> Result<resolution::ResolutionAttestation, Error> {
+        resolution::ResultFinality::attestation(&env, &market_id)
+    }
+
+    /// Current resolution state of a market, including `Finalized`.
+    pub fn get_finality_state(
+        env: Env,
+        market_id: Symbol,
+    ) -> Result<resolution::ResolutionState, Error> {
+        resolution::ResultFinality::get_state(&env, &market_id)
+    }
+
     // ===== EVENT ARCHIVE AND HISTORICAL QUERY =====
 
     /// Mark a resolved or cancelled event (market) as archived. Admin only.
@@ -3472,6 +6058,118 @@ impl PredictifyHybrid {
         crate::event_archive::EventArchive::archive_event(&env, &admin, &market_id)
     }
 
+    /// Page through all market IDs in creation order.
+    ///
+    /// `limit` must be between 1 and `MAX_QUERY_PAGE_SIZE`.
+    pub fn get_markets(env: Env, offset: u32, limit: u32) -> Result<Vec<Symbol>, Error> {
+        markets::MarketIndex::get_markets(&env, offset, limit)
+    }
+
+    /// Market IDs in `state` among the markets at index `offset..offset + limit`.
+    ///
+    /// A page may hold fewer than `limit` markets; keep paging until `offset`
+    /// reaches `get_market_count`.
+    pub fn get_markets_by_state(
+        env: Env,
+        state: MarketState,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<Symbol>, Error> {
+        markets::MarketIndex::get_markets_by_state(&env, state, offset, limit)
+    }
+
+    /// Markets in `category`, in the order they joined it.
+    ///
+    /// `offset` and `limit` page through the category's index, which keeps
+    /// a cleared slot when a market leaves the category, so a page may hold
+    /// fewer than `limit` markets. `limit` must be between 1 and
+    /// `MAX_QUERY_PAGE_SIZE`.
+    pub fn get_markets_by_category(
+        env: Env,
+        category: String,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<Symbol>, Error> {
+        markets::MarketLabelIndex::get_markets_by_category(&env, &category, offset, limit)
+    }
+
+    /// Number of index slots used by `category`, for paging with
+    /// `get_markets_by_category`
+    pub fn get_category_market_count(env: Env, category: String) -> u32 {
+        markets::MarketLabelIndex::category_count(&env, &category)
+    }
+
+    /// Markets tagged `tag`, paged like `get_markets_by_category`
+    pub fn get_markets_by_tag(
+        env: Env,
+        tag: String,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<Symbol>, Error> {
+        markets::MarketLabelIndex::get_markets_by_tag(&env, &tag, offset, limit)
+    }
+
+    /// Most recent markets still accepting votes, newest first (at most
+    /// `MAX_ACTIVE_MARKETS_LISTED`)
+    pub fn get_active_markets(env: Env) -> Vec<Symbol> {
+        markets::MarketIndex::get_active_markets(&env)
+    }
+
+    /// Total number of markets ever created
+    pub fn get_market_count(env: Env) -> u32 {
+        markets::MarketIndex::count(&env)
+    }
+
+    /// Delist a market that received no votes within the listing window.
+    ///
+    /// Callable by anyone. The market is dropped from the index and from
+    /// storage, and a public market's creation bond is refunded to its
+    /// creator. Returns the bond refunded (0 for admin-created markets).
+    ///
+    /// # Errors
+    ///
+    /// - `Error::MarketNotFound` - Market doesn't exist
+    /// - `Error::InvalidState` - Market is not active, has votes, or its
+    ///   listing window has not passed
+    pub fn delist_expired_market(env: Env, market_id: Symbol) -> Result<i128, Error> {
+        markets::MarketListingExpiry::delist(&env, &market_id)
+    }
+
+    /// Set how long a market may stay listed without a vote (admin only)
+    pub fn set_listing_window(env: Env, admin: Address, seconds: u64) -> Result<(), Error> {
+        admin.require_auth();
+        markets::MarketListingExpiry::set_window(&env, &admin, seconds)
+    }
+
+    /// Current listing window in seconds
+    pub fn get_listing_window(env: Env) -> u64 {
+        markets::MarketListingExpiry::get_window(&env)
+    }
+
+    /// Set the per-user stake limits applied to votes and top-ups (admin only).
+    ///
+    /// `max_stake_per_user` caps one user's total stake in a market and
+    /// `max_pool_share_bps` their share of its pool once it holds at least
+    /// `min_pool`; 0 disables a limit.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Unauthorized` - Caller is not the admin
+    /// - `Error::InvalidInput` - Negative amount or share above 10000 bps
+    pub fn set_stake_limits(
+        env: Env,
+        admin: Address,
+        limits: voting::StakeLimits,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        voting::StakeLimitManager::set_limits(&env, &admin, &limits)
+    }
+
+    /// Current per-user stake limits
+    pub fn get_stake_limits(env: Env) -> voting::StakeLimits {
+        voting::StakeLimitManager::get_limits(&env)
+    }
+
     /// Query events by creation time range. Returns public metadata only (no votes/stakes).
     /// Paginated: cursor is start index, limit capped at 30. Returns (entries, next_cursor).
     pub fn query_events_history(
@@ -3672,6 +6370,8 @@ impl PredictifyHybrid {
 
     /// Withdraw collected platform fees (admin only).
     ///
+    /// **Deprecated since API v2:** use `withdraw_fees`. See `get_api_versions`.
+    ///
     /// This function allows the admin to withdraw fees that have been collected
     /// from market payouts. Fees are accumulated across all markets and can be
     /// withdrawn by the admin.
@@ -4085,8 +6785,10 @@ impl PredictifyHybrid {
     /// - `Error::Unauthorized` - Caller is not the contract admin
     /// - `Error::MarketNotFound` - Market with given ID doesn't exist
     /// - `Error::MarketResolved` - Cannot update a resolved market
+    /// - `Error::MarketClosed` - Market has already reached its end time
     /// - `Error::BetsAlreadyPlaced` - Cannot update after bets have been placed
-    /// - `Error::InvalidOutcomes` - New outcomes list is invalid (< 2 outcomes or empty strings)
+    /// - `Error::AlreadyVoted` - Cannot update after votes or dispute stakes exist
+    /// - `Error::InvalidOutcomes` - New outcomes list is invalid (< 2 outcomes or duplicates)
     ///
     /// # Example
     ///
@@ -4117,11 +6819,13 @@ impl PredictifyHybrid {
     ///
     /// # Update Rules
     ///
-    /// - Market must be in Active state
-    /// - No bets can have been placed yet
+    /// - Market must be in Active state and before its end time
+    /// - No bets, votes, or dispute stakes can have been placed yet
     /// - Market must not be resolved
-    /// - New outcomes must have at least 2 options
+    /// - New outcomes must have at least 2 options and no duplicates
     /// - All outcome strings must be non-empty
+    /// - Per-outcome totals are reset to match the new outcome set
+    /// - Outcome IDs are reassigned and localized labels are cleared
     ///
     /// # Security
     ///
@@ -4159,8 +6863,20 @@ impl PredictifyHybrid {
             }
         }
 
-        // Get market
-        let mut market: Market = env
+        // Apply the same limits as market creation and reject duplicate labels
+        if crate::validation::InputValidator::validate_outcomes(&new_outcomes).is_err() {
+            return Err(Error::InvalidOutcomes);
+        }
+        let mut seen: Vec<String> = Vec::new(&env);
+        for outcome in new_outcomes.iter() {
+            if seen.contains(&outcome) {
+                return Err(Error::InvalidOutcomes);
+            }
+            seen.push_back(outcome);
+        }
+
+        // Get market
+        let mut market: Market = env
             .storage()
             .persistent()
             .get(&market_id)
@@ -4171,22 +6887,32 @@ impl PredictifyHybrid {
             return Err(Error::MarketResolved);
         }
 
+        // Amendments are only allowed while the market is still open
+        if env.ledger().timestamp() >= market.end_time {
+            return Err(Error::MarketClosed);
+        }
+
         // Check if any bets have been placed
         let bet_stats = bets::BetManager::get_market_bet_stats(&env, &market_id);
         if bet_stats.total_bets > 0 {
             return Err(Error::BetsAlreadyPlaced);
         }
 
-        // Check if any votes have been placed
-        if market.total_staked > 0 {
+        // Check if any votes or dispute stakes have been placed
+        if market.total_staked > 0 || market.voter_count > 0 || !market.dispute_stakes.is_empty() {
             return Err(Error::AlreadyVoted);
         }
 
         // Store old outcomes for event
         let old_outcomes = market.outcomes.clone();
 
-        // Update market outcomes
+        // Update market outcomes and reset structures keyed by outcome label
         market.outcomes = new_outcomes.clone();
+        market.outcome_totals = Map::new(&env);
+        market.winning_total = 0;
+        bets::BetManager::sync_outcome_set(&env, &market_id, &new_outcomes)?;
+        // Outcome IDs are reassigned, so localized labels no longer apply
+        markets::OutcomeLabels::clear(&env, &market_id);
 
         // Save market
         env.storage().persistent().set(&market_id, &market);
@@ -4203,6 +6929,32 @@ impl PredictifyHybrid {
         Ok(())
     }
 
+    /// Set a market's description hash, image hash and resolution source URL.
+    ///
+    /// Callable by the market creator or the admin while the market is
+    /// active and nobody has staked on it.
+    ///
+    /// # Errors
+    ///
+    /// * `Unauthorized` - Caller is neither the creator nor the admin
+    /// * `MarketResolved` - Market is no longer active
+    /// * `AlreadyVoted` - Stake has already been placed
+    /// * `InvalidInput` - Resolution source URL is empty or too long
+    pub fn set_market_metadata(
+        env: Env,
+        caller: Address,
+        market_id: Symbol,
+        metadata: markets::MarketMetadata,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        markets::MarketMetadataManager::set(&env, &caller, &market_id, &metadata)
+    }
+
+    /// Get a market's metadata, if any was set
+    pub fn get_market_metadata(env: Env, market_id: Symbol) -> Option<markets::MarketMetadata> {
+        markets::MarketMetadataManager::get(&env, &market_id)
+    }
+
     /// Updates the category of a market (admin only, before betting starts).
     ///
     /// This function allows contract administrators to set or update the category
@@ -4300,6 +7052,7 @@ impl PredictifyHybrid {
 
         // Save market
         env.storage().persistent().set(&market_id, &market);
+        markets::MarketLabelIndex::set_category(&env, &market_id, &old_category, &category);
 
         // Emit category update event
         EventEmitter::emit_category_updated(&env, &market_id, &old_category, &category, &admin);
@@ -4419,6 +7172,7 @@ impl PredictifyHybrid {
 
         // Save market
         env.storage().persistent().set(&market_id, &market);
+        markets::MarketLabelIndex::set_tags(&env, &market_id, &old_tags, &tags);
 
         // Emit tags update event
         EventEmitter::emit_tags_updated(&env, &market_id, &old_tags, &tags, &admin);
@@ -4561,6 +7315,7 @@ impl PredictifyHybrid {
         // Update market state to cancelled
         market.state = MarketState::Cancelled;
         env.storage().persistent().set(&market_id, &market);
+        statistics::StatisticsManager::record_creator_market_invalidated(&env, &market.admin);
 
         // Decrement active event count for the creator since the market is no longer active
         crate::storage::CreatorLimitsManager::decrement_active_events(&env, &market.admin);
@@ -4594,6 +7349,100 @@ impl PredictifyHybrid {
         Ok(total_refunded)
     }
 
+    /// Cancel a market so every participant can take their stake back (admin only).
+    ///
+    /// Unlike `cancel_event`, nothing is transferred here: each voter or
+    /// bettor pulls their stake with `claim_refund`, so markets of any size
+    /// can be cancelled in one call.
+    ///
+    /// # Errors
+    ///
+    /// * `Unauthorized` - Caller is not the contract admin
+    /// * `MarketNotFound` - Market does not exist
+    /// * `MarketResolved` - Market has already been resolved
+    /// * `InvalidState` - Market is disputed or already cancelled
+    pub fn cancel_market(
+        env: Env,
+        admin: Address,
+        market_id: Symbol,
+        reason: String,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        recovery::RecoveryManager::assert_is_admin(&env, &admin)?;
+
+        let mut market = markets::MarketStateManager::get_market(&env, &market_id)?;
+        if market.winning_outcomes.is_some()
+            || matches!(market.state, MarketState::Resolved | MarketState::Closed)
+        {
+            return Err(Error::MarketResolved);
+        }
+        if !matches!(market.state, MarketState::Active | MarketState::Ended) {
+            return Err(Error::InvalidState);
+        }
+
+        let old_state = market.state;
+        market.state = MarketState::Cancelled;
+        markets::MarketStateManager::update_market(&env, &market_id, &market);
+        statistics::StatisticsManager::record_creator_market_invalidated(&env, &market.admin);
+        crate::storage::CreatorLimitsManager::decrement_active_events(&env, &market.admin);
+
+        EventEmitter::emit_state_change_event(
+            &env,
+            &market_id,
+            &old_state,
+            &MarketState::Cancelled,
+            &reason,
+        );
+        EventEmitter::emit_market_closed(&env, &market_id, &admin);
+        Ok(())
+    }
+
+    /// Take back your full stake from a cancelled market.
+    ///
+    /// Works for every way a market ends up cancelled (`cancel_market`,
+    /// resolution timeout, ...). Bets already refunded in bulk by
+    /// `cancel_event` have nothing left to claim. Position token shares the
+    /// user holds are burned and refunded at face value too. Returns the
+    /// amount refunded.
+    ///
+    /// # Errors
+    ///
+    /// * `MarketNotFound` - Market does not exist
+    /// * `InvalidState` - Market is not cancelled, or a reentrant call was detected
+    /// * `NothingToClaim` - User has no stake or shares left in the market
+    /// * `AlreadyClaimed` - User has already been refunded
+    pub fn claim_refund(env: Env, user: Address, market_id: Symbol) -> Result<i128, Error> {
+        user.require_auth();
+        ReentrancyGuard::check_reentrancy_state(&env).map_err(|_| Error::InvalidState)?;
+
+        let mut market = markets::MarketStateManager::get_market(&env, &market_id)?;
+        if market.state != MarketState::Cancelled {
+            return Err(Error::InvalidState);
+        }
+
+        // Position tokens are refunded at face value
+        let token_refund =
+            position_tokens::PositionTokenManager::redeem(&env, &market_id, &market, &user)?;
+        if token_refund > 0 {
+            ReentrancyGuard::guarded(&env, || {
+                bets::BetUtils::unlock_funds(&env, &user, token_refund)
+            })?;
+        }
+
+        ReentrancyGuard::before_external_call(&env).map_err(|_| Error::InvalidState)?;
+        let refunded =
+            recovery::RecoveryManager::refund_position(&env, &market_id, &mut market, &user);
+        ReentrancyGuard::after_external_call(&env);
+        let refunded = match refunded {
+            Err(Error::NothingToClaim) | Err(Error::AlreadyClaimed) if token_refund > 0 => 0,
+            other => other?,
+        } + token_refund;
+
+        markets::MarketStateManager::update_market(&env, &market_id, &market);
+        EventEmitter::emit_refund_claimed(&env, &market_id, &user, refunded);
+        Ok(refunded)
+    }
+
     /// Cancel and refund an event that has ended but did not meet its minimum pool size.
     ///
     /// Callable by admin at any time after market ends, or by anyone once the
@@ -4650,6 +7499,7 @@ impl PredictifyHybrid {
         let old_state = market.state.clone();
         market.state = MarketState::Cancelled;
         env.storage().persistent().set(&market_id, &market);
+        statistics::StatisticsManager::record_creator_market_invalidated(&env, &market.admin);
 
         // Refund all bets
         if ReentrancyGuard::check_reentrancy_state(&env).is_err() {
@@ -4722,6 +7572,7 @@ impl PredictifyHybrid {
         let old_state = market.state.clone();
         market.state = MarketState::Cancelled;
         env.storage().persistent().set(&market_id, &market);
+        statistics::StatisticsManager::record_creator_market_invalidated(&env, &market.admin);
 
         // Decrement active event count for the creator since the market is no longer active
         crate::storage::CreatorLimitsManager::decrement_active_events(&env, &market.admin);
@@ -4782,6 +7633,35 @@ impl PredictifyHybrid {
         )
     }
 
+    /// Set the automatic anti-snipe extension (admin only).
+    ///
+    /// A stake of at least `threshold` within the final `window_seconds` of
+    /// a market extends its end time by `extension_seconds`, within the
+    /// market's `max_extension_days`. A zero threshold disables it.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Unauthorized` - Caller is not the admin
+    /// - `Error::InvalidInput` - Negative threshold, or zero window or extension
+    pub fn set_anti_snipe_config(
+        env: Env,
+        admin: Address,
+        config: extensions::AntiSnipeConfig,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        extensions::AntiSnipeManager::set_config(&env, &admin, &config)
+    }
+
+    /// Current anti-snipe settings
+    pub fn get_anti_snipe_config(env: Env) -> extensions::AntiSnipeConfig {
+        extensions::AntiSnipeManager::get_config(&env)
+    }
+
+    /// Seconds a market's end time was pushed back by anti-sniping
+    pub fn get_anti_snipe_extension(env: Env, market_id: Symbol) -> u64 {
+        extensions::AntiSnipeManager::get_extended_seconds(&env, &market_id)
+    }
+
     // ===== STORAGE OPTIMIZATION FUNCTIONS =====
 
     /// Compress market data for storage optimization
@@ -4811,6 +7691,26 @@ impl PredictifyHybrid {
         storage::StorageOptimizer::migrate_storage_format(&env, from_format, to_format)
     }
 
+    /// Migrate a single market between the legacy and optimized storage
+    /// layouts (admin only).
+    ///
+    /// Unlike `migrate_storage_format`, this converts one market at a time
+    /// and verifies its integrity before recording the new format.
+    pub fn migrate_market(
+        env: Env,
+        admin: Address,
+        market_id: Symbol,
+        to_format: storage::StorageFormat,
+    ) -> Result<storage::StorageMigration, Error> {
+        admin.require_auth();
+        storage::StorageOptimizer::migrate_market(&env, &admin, &market_id, to_format)
+    }
+
+    /// Get the storage format of a market
+    pub fn get_market_storage_format(env: Env, market_id: Symbol) -> storage::StorageFormat {
+        storage::StorageOptimizer::get_market_format(&env, &market_id)
+    }
+
     /// Monitor storage usage and return statistics
     pub fn monitor_storage_usage(env: Env) -> Result<storage::StorageUsageStats, Error> {
         storage::StorageOptimizer::monitor_storage_usage(&env)
@@ -4958,6 +7858,29 @@ impl PredictifyHybrid {
         edge_cases::EdgeCaseHandler::test_edge_case_scenarios(&env)
     }
 
+    /// Choose which degenerate markets are cancelled for refunds instead of
+    /// resolved (admin only).
+    ///
+    /// When `resolve_market`, `resolve_market_manual` or
+    /// `resolve_market_with_ties` is called on an ended market with no stake,
+    /// a single voter, or all stake on one outcome (per the flags set), the
+    /// market becomes `Cancelled` and participants use `claim_refund`.
+    pub fn set_degenerate_market_policy(
+        env: Env,
+        admin: Address,
+        policy: edge_cases::DegenerateMarketPolicy,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        recovery::RecoveryManager::assert_is_admin(&env, &admin)?;
+        edge_cases::EdgeCaseHandler::set_degenerate_policy(&env, &policy);
+        Ok(())
+    }
+
+    /// Get the degenerate-market cancellation policy.
+    pub fn get_degenerate_market_policy(env: Env) -> edge_cases::DegenerateMarketPolicy {
+        edge_cases::EdgeCaseHandler::get_degenerate_policy(&env)
+    }
+
     /// Get comprehensive edge case statistics
     pub fn get_edge_case_statistics(env: Env) -> Result<edge_cases::EdgeCaseStats, Error> {
         edge_cases::EdgeCaseHandler::get_edge_case_statistics(&env)
@@ -4991,210 +7914,1008 @@ impl PredictifyHybrid {
             Ok(total_refunded) => total_refunded,
             Err(e) => panic_with_error!(env, e),
         }
-    }
-
-    /// Validates market state integrity; returns true if consistent.
-    pub fn validate_market_state_integrity(env: Env, market_id: Symbol) -> bool {
-        match crate::recovery::RecoveryValidator::validate_market_state_integrity(&env, &market_id)
+    }
+
+    /// Validates market state integrity; returns true if consistent.
+    pub fn validate_market_state_integrity(env: Env, market_id: Symbol) -> bool {
+        match crate::recovery::RecoveryValidator::validate_market_state_integrity(&env, &market_id)
+        {
+            Ok(_) => true,
+            Err(_) => false,
+        }
+    }
+
+    /// Returns recovery status for a market.
+    pub fn get_recovery_status(env: Env, market_id: Symbol) -> String {
+        crate::recovery::RecoveryManager::get_recovery_status(&env, &market_id)
+            .unwrap_or_else(|_| String::from_str(&env, "unknown"))
+    }
+
+    /// Returns the anomalies flagged on a market (e.g. tripped payout guards).
+    ///
+    /// Requires the `ViewSensitiveAnalytics` permission held by the Analyst role.
+    pub fn get_market_anomalies(
+        env: Env,
+        viewer: Address,
+        market_id: Symbol,
+    ) -> Result<Vec<String>, Error> {
+        viewer.require_auth();
+        AdminManager::validate_sensitive_analytics_access(&env, &viewer, None)?;
+        Ok(crate::recovery::RecoveryStorage::load(&env, &market_id)
+            .map(|rec| rec.issues_detected)
+            .unwrap_or_else(|| Vec::new(&env)))
+    }
+
+    /// Sets the maximum payout of a single claim as a multiple of stake. Only admin.
+    ///
+    /// Claims above the cap fail with `ExtendedError::PayoutGuardTripped`, and
+    /// `distribute_winnings` flags the market for recovery instead of paying.
+    pub fn set_max_payout_multiple(env: Env, admin: Address, market_id: Symbol, multiple: i128) {
+        admin.require_auth();
+        if let Err(e) = crate::recovery::RecoveryManager::assert_is_admin(&env, &admin) {
+            panic_with_error!(env, e);
+        }
+        if multiple < 1 {
+            panic_with_error!(env, Error::InvalidInput);
+        }
+
+        let mut market: Market = env
+            .storage()
+            .persistent()
+            .get(&market_id)
+            .unwrap_or_else(|| panic_with_error!(env, Error::MarketNotFound));
+        market.max_payout_multiple = multiple;
+        env.storage().persistent().set(&market_id, &market);
+    }
+
+    /// Caps the share of the pool any single outcome of a market may attract. Only admin.
+    ///
+    /// Once the pool holds `cap.min_pool`, stakes that would take an outcome above
+    /// `cap.max_share_bps` are rejected or clamped according to `cap.mode`.
+    /// A `max_share_bps` of 0 removes the cap.
+    pub fn set_outcome_cap(
+        env: Env,
+        admin: Address,
+        market_id: Symbol,
+        cap: OutcomeCap,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        crate::recovery::RecoveryManager::assert_is_admin(&env, &admin)?;
+
+        let mut market = markets::MarketStateManager::get_market(&env, &market_id)?;
+        if market.state != MarketState::Active {
+            return Err(Error::MarketClosed);
+        }
+        cap.validate(market.outcomes.len())?;
+
+        market.outcome_cap = cap;
+        markets::MarketStateManager::update_market(&env, &market_id, &market);
+        Ok(())
+    }
+
+    /// Enable or disable the loser rebate of a market. Only admin.
+    ///
+    /// When enabled, `TierRules::loser_rebate_bps` of the market's fee is set
+    /// aside at resolution and shared pro-rata among losing voters when they
+    /// claim. Winners' payouts are unaffected; the platform collects less.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Unauthorized` - Caller is not the contract admin
+    /// * `Error::MarketNotFound` - Market does not exist
+    /// * `Error::MarketClosed` - Market is no longer active
+    pub fn set_loser_rebate(
+        env: Env,
+        admin: Address,
+        market_id: Symbol,
+        enabled: bool,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        crate::recovery::RecoveryManager::assert_is_admin(&env, &admin)?;
+
+        let mut market = markets::MarketStateManager::get_market(&env, &market_id)?;
+        if market.state != MarketState::Active {
+            return Err(Error::MarketClosed);
+        }
+
+        market.loser_rebate = enabled;
+        markets::MarketStateManager::update_market(&env, &market_id, &market);
+        Ok(())
+    }
+
+    /// Set how voters are weighted in a market's community consensus. Only admin.
+    ///
+    /// Under `WeightingMode::Quadratic` each voter counts with the square
+    /// root of their stake in the hybrid resolution's consensus, reducing
+    /// whale dominance. Payouts stay linear. Only possible before anyone
+    /// stakes, since weights are tracked as positions change.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Unauthorized` - Caller is not the contract admin
+    /// * `Error::MarketNotFound` - Market does not exist
+    /// * `Error::MarketClosed` - Market is no longer active
+    /// * `Error::AlreadyVoted` - The market already has positions
+    pub fn set_weighting_mode(
+        env: Env,
+        admin: Address,
+        market_id: Symbol,
+        mode: WeightingMode,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        crate::recovery::RecoveryManager::assert_is_admin(&env, &admin)?;
+
+        let mut market = markets::MarketStateManager::get_market(&env, &market_id)?;
+        if market.state != MarketState::Active {
+            return Err(Error::MarketClosed);
+        }
+        if market.voter_count > 0 || market.total_staked > 0 {
+            return Err(Error::AlreadyVoted);
+        }
+
+        market.weighting_mode = mode;
+        markets::MarketStateManager::update_market(&env, &market_id, &market);
+        Ok(())
+    }
+
+    /// Largest stake currently accepted on `outcome`, or `None` if the market's outcome cap does not apply.
+    pub fn get_max_stake_for_outcome(
+        env: Env,
+        market_id: Symbol,
+        outcome: String,
+    ) -> Result<Option<i128>, Error> {
+        let market = markets::MarketStateManager::get_market(&env, &market_id)?;
+        if !market.outcomes.contains(&outcome) {
+            return Err(Error::InvalidOutcome);
+        }
+        Ok(market.max_stake_for_outcome(&outcome))
+    }
+
+    /// Assign a moderation tier to a market (moderators only).
+    ///
+    /// The market must satisfy the tier's rules (see [`TierRules`]) and must not
+    /// have any participation yet, since the tier's platform fee is pinned on
+    /// the market. Stake caps and dispute bonds are enforced from then on.
+    ///
+    /// # Panics
+    ///
+    /// * `Error::Unauthorized` - Caller lacks the `ModerateMarkets` permission
+    /// * `Error::MarketNotFound` - Market does not exist or is no longer active
+    /// * Tier validation errors from `MarketValidator::validate_market_for_tier`
+    pub fn set_market_tier(env: Env, moderator: Address, market_id: Symbol, tier: MarketTier) {
+        moderator.require_auth();
+        if let Err(e) = admin::AdminAccessControl::validate_permission(
+            &env,
+            &moderator,
+            &AdminPermission::ModerateMarkets,
+        ) {
+            panic_with_error!(env, e);
+        }
+
+        let mut market: Market = env
+            .storage()
+            .persistent()
+            .get(&market_id)
+            .unwrap_or_else(|| panic_with_error!(env, Error::MarketNotFound));
+        if let Err(e) =
+            crate::validation::MarketValidator::validate_market_for_tier(&env, &market, &tier)
+        {
+            panic_with_error!(env, e.to_contract_error());
+        }
+
+        let rules = TierRules::for_tier(&tier);
+        market.tier = tier;
+        market.platform_fee_percentage = rules.platform_fee_percentage;
+        env.storage().persistent().set(&market_id, &market);
+
+        EventEmitter::emit_market_tier_assigned(
+            &env,
+            &market_id,
+            &moderator,
+            &tier,
+            rules.platform_fee_percentage,
+        );
+    }
+
+    /// Get the rule set applied to markets of a tier.
+    pub fn get_tier_rules(env: Env, tier: MarketTier) -> TierRules {
+        TierRules::for_tier(&tier)
+    }
+
+    // ===== KEEPER REGISTRY =====
+
+    /// Register as a keeper for the given roles, bonding `stake` (at least
+    /// `MIN_KEEPER_STAKE`). Only registered keepers can perform keeper jobs
+    /// or receive keeper rewards.
+    pub fn register_keeper(
+        env: Env,
+        keeper: Address,
+        roles: Vec<keepers::KeeperRole>,
+        stake: i128,
+    ) -> keepers::Keeper {
+        keeper.require_auth();
+        keepers::KeeperRegistry::register(&env, &keeper, roles, stake)
+            .unwrap_or_else(|e| panic_with_error!(env, e))
+    }
+
+    /// Deregister a keeper and return its remaining stake.
+    pub fn deregister_keeper(env: Env, keeper: Address) -> i128 {
+        keeper.require_auth();
+        keepers::KeeperRegistry::deregister(&env, &keeper)
+            .unwrap_or_else(|e| panic_with_error!(env, e))
+    }
+
+    /// Slash a misbehaving keeper (admin only).
+    ///
+    /// Moves up to `amount` of the keeper's stake into the keeper reward pool
+    /// and deactivates the keeper if its stake drops below the minimum.
+    /// Returns the amount slashed.
+    pub fn slash_keeper(
+        env: Env,
+        admin: Address,
+        keeper: Address,
+        amount: i128,
+        reason: String,
+    ) -> i128 {
+        admin.require_auth();
+        if let Err(e) = crate::recovery::RecoveryManager::assert_is_admin(&env, &admin) {
+            panic_with_error!(env, e);
+        }
+        keepers::KeeperRegistry::slash(&env, &admin, &keeper, amount, reason)
+            .unwrap_or_else(|e| panic_with_error!(env, e))
+    }
+
+    /// Fund the keeper reward pool. Returns the new pool balance.
+    pub fn fund_keeper_rewards(env: Env, funder: Address, amount: i128) -> i128 {
+        funder.require_auth();
+        keepers::KeeperRegistry::fund_rewards(&env, &funder, amount)
+            .unwrap_or_else(|e| panic_with_error!(env, e))
+    }
+
+    /// Extend storage TTLs for up to `limit` entries closest to expiry
+    /// (registered `TtlBumper` keepers only).
+    ///
+    /// Unsettled markets are bumped first. The keeper is paid a micro-reward
+    /// per bump from the keeper reward pool, which the treasury tops up via
+    /// `fund_keeper_rewards`. Returns the number of entries bumped.
+    pub fn bump_ttls(env: Env, keeper: Address, limit: u32) -> u32 {
+        keeper.require_auth();
+        let (bumped, _reward) = keepers::TtlKeeper::bump_ttls(&env, &keeper, limit)
+            .unwrap_or_else(|e| panic_with_error!(env, e));
+        bumped
+    }
+
+    /// Publish the aggregated settlement report for a completed day
+    /// (registered `Reporter` keepers only).
+    ///
+    /// Emits one event with the markets resolved, volume settled, fees
+    /// collected and disputes opened that day. `day` is the ledger timestamp
+    /// divided by 86400 and can be reported once, after it has ended.
+    pub fn publish_daily_settlement_report(
+        env: Env,
+        keeper: Address,
+        day: u64,
+    ) -> DailySettlementStats {
+        keeper.require_auth();
+        keepers::SettlementReporter::publish_daily_report(&env, &keeper, day)
+            .unwrap_or_else(|e| panic_with_error!(env, e))
+    }
+
+    /// Audit up to `limit` stored markets for corruption (registered
+    /// `Auditor` keepers only).
+    ///
+    /// The audit resumes where the previous run stopped and wraps around
+    /// after the last market. The first corrupted market in each pass raises
+    /// a critical monitoring alert. Returns the corrupted market IDs found.
+    ///
+    /// # Errors
+    ///
+    /// * `Unauthorized` - Caller is not an active `Auditor` keeper
+    /// * `InvalidInput` - `limit` is 0 or above `MAX_STORAGE_AUDIT_BATCH`
+    pub fn audit_storage(env: Env, keeper: Address, limit: u32) -> Result<Vec<Symbol>, Error> {
+        keeper.require_auth();
+        keepers::StorageAuditor::run(&env, &keeper, limit)
+    }
+
+    /// Get the rolling storage audit's cursor and last clean pass time.
+    pub fn get_storage_audit_state(env: Env) -> keepers::StorageAuditState {
+        keepers::KeeperStorage::audit_state(&env)
+    }
+
+    /// Get the settlement counters recorded so far for a day.
+    pub fn get_daily_settlement_stats(env: Env, day: u64) -> DailySettlementStats {
+        statistics::StatisticsManager::get_daily_stats(&env, day)
+    }
+
+    /// Get a keeper's registry entry.
+    pub fn get_keeper(env: Env, keeper: Address) -> Option<keepers::Keeper> {
+        keepers::KeeperStorage::load(&env, &keeper)
+    }
+
+    /// Get the keeper reward pool balance.
+    pub fn get_keeper_reward_pool(env: Env) -> i128 {
+        keepers::KeeperStorage::reward_pool(&env)
+    }
+
+    // ===== VERSIONING FUNCTIONS =====
+
+    /// Track contract version for versioning system
+    pub fn track_contract_version(env: Env, version: versioning::Version) -> Result<(), Error> {
+        versioning::VersionManager::new(&env).track_contract_version(&env, version)
+    }
+
+    /// Migrate data between contract versions
+    pub fn migrate_data_between_versions(
+        env: Env,
+        old_version: versioning::Version,
+        new_version: versioning::Version,
+    ) -> Result<versioning::VersionMigration, Error> {
+        versioning::VersionManager::new(&env).migrate_data_between_versions(
+            &env,
+            old_version,
+            new_version,
+        )
+    }
+
+    /// Validate version compatibility
+    pub fn validate_version_compatibility(
+        env: Env,
+        old_version: versioning::Version,
+        new_version: versioning::Version,
+    ) -> Result<bool, Error> {
+        versioning::VersionManager::new(&env).validate_version_compatibility(
+            &env,
+            &old_version,
+            &new_version,
+        )
+    }
+
+    /// Upgrade to a specific version
+    pub fn upgrade_to_version(env: Env, target_version: versioning::Version) -> Result<(), Error> {
+        versioning::VersionManager::new(&env).upgrade_to_version(&env, target_version)
+    }
+
+    /// Rollback to a specific version
+    pub fn rollback_to_version(env: Env, target_version: versioning::Version) -> Result<(), Error> {
+        versioning::VersionManager::new(&env).rollback_to_version(&env, target_version)
+    }
+
+    /// Get version history
+    pub fn get_version_history(env: Env) -> Result<versioning::VersionHistory, Error> {
+        versioning::VersionManager::new(&env).get_version_history(&env)
+    }
+
+    /// Test version migration
+    pub fn test_version_migration(
+        env: Env,
+        migration: versioning::VersionMigration,
+    ) -> Result<bool, Error> {
+        versioning::VersionManager::new(&env).test_version_migration(&env, migration)
+    }
+
+    // ===== MONITORING FUNCTIONS =====
+
+    /// Monitor market health for a specific market
+    pub fn monitor_market_health(
+        env: Env,
+        market_id: Symbol,
+    ) -> Result<monitoring::MarketHealthMetrics, Error> {
+        monitoring::ContractMonitor::monitor_market_health(&env, market_id)
+    }
+
+    /// Monitor oracle health for a specific oracle provider
+    pub fn monitor_oracle_health(
+        env: Env,
+        oracle: OracleProvider,
+    ) -> Result<monitoring::OracleHealthMetrics, Error> {
+        monitoring::ContractMonitor::monitor_oracle_health(&env, oracle)
+    }
+
+    /// Monitor fee collection performance
+    pub fn monitor_fee_collection(
+        env: Env,
+        timeframe: monitoring::TimeFrame,
+    ) -> Result<monitoring::FeeCollectionMetrics, Error> {
+        monitoring::ContractMonitor::monitor_fee_collection(&env, timeframe)
+    }
+
+    /// Monitor dispute resolution performance
+    pub fn monitor_dispute_resolution(
+        env: Env,
+        market_id: Symbol,
+    ) -> Result<monitoring::DisputeResolutionMetrics, Error> {
+        monitoring::ContractMonitor::monitor_dispute_resolution(&env, market_id)
+    }
+
+    /// Get comprehensive contract performance metrics
+    pub fn get_contract_performance_metrics(
+        env: Env,
+        timeframe: monitoring::TimeFrame,
+    ) -> Result<monitoring::PerformanceMetrics, Error> {
+        monitoring::ContractMonitor::get_contract_performance_metrics(&env, timeframe)
+    }
+
+    /// Emit monitoring alert
+    pub fn emit_monitoring_alert(
+        env: Env,
+        alert: monitoring::MonitoringAlert,
+    ) -> Result<(), Error> {
+        monitoring::ContractMonitor::emit_monitoring_alert(&env, alert)
+    }
+
+    /// Validate monitoring data integrity
+    pub fn validate_monitoring_data(
+        env: Env,
+        data: monitoring::MonitoringData,
+    ) -> Result<bool, Error> {
+        monitoring::ContractMonitor::validate_monitoring_data(&env, &data)
+    }
+
+    // ===== ORACLE FALLBACK FUNCTIONS =====
+
+    /// Get oracle data with backup if primary fails
+    pub fn get_oracle_with_backup(
+        env: Env,
+        market_id: Symbol,
+        oracle_contract: Address,
+        primary_oracle: OracleProvider,
+        backup_oracle: OracleProvider,
+    ) -> Result<String, Error> {
+        // Get market info
+        let market = env
+            .storage()
+            .persistent()
+            .get::<Symbol, Market>(&market_id)
+            .ok_or(Error::MarketNotFound)?;
+
+        // Check if market ended
+        let current_time = env.ledger().timestamp();
+        if current_time < market.end_time {
+            return Err(Error::MarketClosed);
+        }
+
+        // Try to get price with backup
+        let backup = OracleBackup::new(primary_oracle, backup_oracle);
+        match backup.get_price(&env, &oracle_contract, &market.oracle_config.feed_id) {
+            Ok(price) => {
+                // Simple comparison logic
+                let threshold = market.oracle_config.threshold;
+                let comparison = &market.oracle_config.comparison;
+
+                let result = if comparison == &String::from_str(&env, "gt") {
+                    if price > threshold {
+                        "yes"
+                    } else {
+                        "no"
+                    }
+                } else if comparison == &String::from_str(&env, "lt") {
+                    if price < threshold {
+                        "yes"
+                    } else {
+                        "no"
+                    }
+                } else {
+                    if price == threshold {
+                        "yes"
+                    } else {
+                        "no"
+                    }
+                };
+
+                Ok(String::from_str(&env, result))
+            }
+            Err(_) => {
+                // Both oracles failed
+                let reason = String::from_str(&env, "All oracles failed");
+                events::EventEmitter::emit_manual_resolution_required(&env, &market_id, &reason);
+                Err(Error::OracleUnavailable)
+            }
+        }
+    }
+
+    /// Check if oracle is working
+    pub fn check_oracle_status(
+        env: Env,
+        oracle: OracleProvider,
+        oracle_contract: Address,
+    ) -> String {
+        let health = graceful_degradation::monitor_oracle_health(&env, oracle, &oracle_contract);
+        match health {
+            OracleHealth::Working => String::from_str(&env, "working"),
+            OracleHealth::Broken => String::from_str(&env, "broken"),
+        }
+    }
+
+    // ===== ORACLE BLOCKLIST FUNCTIONS =====
+
+    /// Block a compromised oracle contract (emergency council only).
+    ///
+    /// Takes effect immediately: markets using the oracle resolve through
+    /// their fallback oracle, or require manual resolution when none is
+    /// usable. Requires the `EmergencyActions` admin permission.
+    pub fn block_oracle(
+        env: Env,
+        council_member: Address,
+        oracle_address: Address,
+        reason: String,
+    ) -> Result<(), Error> {
+        council_member.require_auth();
+        oracles::OracleBlocklist::block_oracle(&env, &council_member, &oracle_address, reason)
+    }
+
+    /// Lift the block on an oracle contract (emergency council only)
+    pub fn unblock_oracle(
+        env: Env,
+        council_member: Address,
+        oracle_address: Address,
+        reason: String,
+    ) -> Result<(), Error> {
+        council_member.require_auth();
+        oracles::OracleBlocklist::unblock_oracle(&env, &council_member, &oracle_address, reason)
+    }
+
+    /// Check whether an oracle contract is blocked
+    pub fn is_oracle_blocked(env: Env, oracle_address: Address) -> bool {
+        oracles::OracleBlocklist::is_blocked(&env, &oracle_address)
+    }
+
+    /// Get all blocked oracle contracts
+    pub fn get_blocked_oracles(env: Env) -> Vec<oracles::BlockedOracle> {
+        oracles::OracleBlocklist::get_blocked_oracles(&env)
+    }
+
+    // ===== CIRCUIT BREAKER FREEZE FUNCTIONS =====
+
+    /// Extend a market by any circuit breaker downtime it has not yet been
+    /// compensated for. Anyone can call this. Returns the seconds added.
+    pub fn apply_market_freeze_extension(env: Env, market_id: Symbol) -> Result<u64, Error> {
+        circuit_breaker::MarketFreezeManager::apply_to_market(&env, &market_id)
+    }
+
+    /// Apply pending circuit breaker extensions to up to `limit` markets,
+    /// continuing from the previous batch. Anyone can call this. Returns
+    /// the number of markets extended.
+    pub fn process_freeze_extensions(env: Env, limit: u32) -> Result<u32, Error> {
+        circuit_breaker::MarketFreezeManager::process_queue(&env, limit)
+    }
+
+    /// Get the recorded circuit breaker freeze periods, oldest first
+    pub fn get_freeze_periods(env: Env) -> Vec<circuit_breaker::FreezePeriod> {
+        circuit_breaker::MarketFreezeManager::get_freeze_periods(&env)
+    }
+
+    // ===== BUG BOUNTY FUNCTIONS =====
+
+    /// Lock treasury funds in the bug bounty escrow. Returns the new balance.
+    ///
+    /// Only the configured treasury address can fund the escrow.
+    pub fn fund_bounty_escrow(env: Env, treasury: Address, amount: i128) -> i128 {
+        treasury.require_auth();
+        bounty::BountyEscrow::fund(&env, &treasury, amount)
+            .unwrap_or_else(|e| panic_with_error!(env, e))
+    }
+
+    /// Pay a bug bounty to the reporter of a disclosed vulnerability
+    /// (emergency council only).
+    ///
+    /// Each `report_id` is paid once. Payouts are capped per payout and per
+    /// period, and recorded publicly. Requires the `EmergencyActions` admin
+    /// permission.
+    pub fn approve_bounty_payout(
+        env: Env,
+        council_member: Address,
+        reporter: Address,
+        amount: i128,
+        report_id: String,
+    ) -> Result<bounty::BountyPayout, Error> {
+        council_member.require_auth();
+        bounty::BountyEscrow::approve_payout(&env, &council_member, &reporter, amount, report_id)
+    }
+
+    /// Get the bug bounty escrow balance
+    pub fn get_bounty_escrow_balance(env: Env) -> i128 {
+        bounty::BountyStorage::balance(&env)
+    }
+
+    /// Page through bug bounty payout records, oldest first.
+    ///
+    /// `limit` must be between 1 and `MAX_QUERY_PAGE_SIZE`.
+    pub fn get_bounty_payouts(
+        env: Env,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<bounty::BountyPayout>, Error> {
+        bounty::BountyEscrow::get_payouts(&env, offset, limit)
+    }
+
+    // ===== CROSS-RATE CONDITION FUNCTIONS =====
+
+    /// Attach a cross-rate condition to a market so it resolves on the ratio
+    /// of two feeds (e.g. ETH/BTC above 0.06) instead of a single price.
+    ///
+    /// The condition can only be set by the admin before any stake has been
+    /// placed, since it changes what participants are betting on.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Unauthorized` - Caller is not the contract admin
+    /// * `Error::MarketNotFound` - Market does not exist
+    /// * `Error::MarketClosed` - Market has ended or is no longer active
+    /// * `Error::BetsAlreadyPlaced` - Market already has stakes
+    /// * `Error::InvalidOracleConfig` / `Error::InvalidThreshold` /
+    ///   `Error::InvalidComparison` - Condition failed validation, or the
+    ///   market resolves against aggregated feeds or a composite condition
+    pub fn set_cross_rate_condition(
+        env: Env,
+        admin: Address,
+        market_id: Symbol,
+        condition: CrossRateCondition,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+
+        let stored_admin: Address = env
+            .storage()
+            .persistent()
+            .get(&Symbol::new(&env, "Admin"))
+            .ok_or(Error::Unauthorized)?;
+        if admin != stored_admin {
+            return Err(Error::Unauthorized);
+        }
+
+        let market: Market = env
+            .storage()
+            .persistent()
+            .get(&market_id)
+            .ok_or(Error::MarketNotFound)?;
+
+        if market.state != MarketState::Active || env.ledger().timestamp() >= market.end_time {
+            return Err(Error::MarketClosed);
+        }
+        if market.total_staked > 0 {
+            return Err(Error::BetsAlreadyPlaced);
+        }
+
+        if oracles::MultiOracleAggregator::get_config(&env, &market_id).is_some()
+            || resolution::CompositeConditionResolver::get_condition(&env, &market_id).is_some()
+        {
+            return Err(Error::InvalidOracleConfig);
+        }
+
+        oracles::CrossRateOracle::set_condition(&env, &market_id, &condition)?;
+        events::EventEmitter::emit_cross_rate_condition_set(&env, &market_id, &admin, &condition);
+
+        Ok(())
+    }
+
+    /// Get the cross-rate condition attached to a market, if any.
+    pub fn get_cross_rate_condition(env: Env, market_id: Symbol) -> Option<CrossRateCondition> {
+        oracles::CrossRateOracle::get_condition(&env, &market_id)
+    }
+
+    /// Resolve a market against several oracle feeds instead of one (admin only).
+    ///
+    /// At resolution the feeds are queried, outliers beyond
+    /// `max_deviation_bps` of the median are discarded and the median or mean
+    /// of the rest is compared with the market's threshold. Like cross-rate
+    /// conditions, this can only be set before any stake is placed.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Unauthorized` - Caller is not the contract admin
+    /// * `Error::MarketNotFound` - Market does not exist
+    /// * `Error::MarketClosed` - Market has ended or is no longer active
+    /// * `Error::BetsAlreadyPlaced` - Market already has stakes
+    /// * `Error::InvalidOracleConfig` - Config failed validation, or the
+    ///   market has a cross-rate or composite condition
+    pub fn set_multi_oracle_config(
+        env: Env,
+        admin: Address,
+        market_id: Symbol,
+        config: MultiOracleConfig,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        recovery::RecoveryManager::assert_is_admin(&env, &admin)?;
+
+        let market = markets::MarketStateManager::get_market(&env, &market_id)?;
+        if market.state != MarketState::Active || env.ledger().timestamp() >= market.end_time {
+            return Err(Error::MarketClosed);
+        }
+        if market.total_staked > 0 {
+            return Err(Error::BetsAlreadyPlaced);
+        }
+        if oracles::CrossRateOracle::get_condition(&env, &market_id).is_some()
+            || resolution::CompositeConditionResolver::get_condition(&env, &market_id).is_some()
+        {
+            return Err(Error::InvalidOracleConfig);
+        }
+
+        oracles::MultiOracleAggregator::set_config(&env, &market_id, &config)?;
+        events::EventEmitter::emit_multi_oracle_config_set(&env, &market_id, &admin, &config);
+
+        Ok(())
+    }
+
+    /// Get the aggregated oracle feeds of a market, if any.
+    pub fn get_multi_oracle_config(env: Env, market_id: Symbol) -> Option<MultiOracleConfig> {
+        oracles::MultiOracleAggregator::get_config(&env, &market_id)
+    }
+
+    /// Resolve a market on several feed conditions combined with AND/OR (admin only).
+    ///
+    /// For example "BTC > $100k AND ETH > $5k". The condition replaces the
+    /// market's single-feed oracle config and fallback at resolution, and can
+    /// only be set before any stake is placed.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Unauthorized` - Caller is not the contract admin
+    /// * `Error::MarketNotFound` - Market does not exist
+    /// * `Error::MarketClosed` - Market has ended or is no longer active
+    /// * `Error::BetsAlreadyPlaced` - Market already has stakes
+    /// * `Error::InvalidState` - Market is resolved by a referendum or reporters
+    /// * `Error::InvalidOracleConfig` / `Error::InvalidThreshold` /
+    ///   `Error::InvalidComparison` - Condition failed validation, or the
+    ///   market has a cross-rate condition, aggregated feeds or price buckets
+    pub fn set_composite_condition(
+        env: Env,
+        admin: Address,
+        market_id: Symbol,
+        condition: CompositeCondition,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        recovery::RecoveryManager::assert_is_admin(&env, &admin)?;
+
+        let market = markets::MarketStateManager::get_market(&env, &market_id)?;
+        if market.state != MarketState::Active || env.ledger().timestamp() >= market.end_time {
+            return Err(Error::MarketClosed);
+        }
+        if market.total_staked > 0 {
+            return Err(Error::BetsAlreadyPlaced);
+        }
+        if resolution::ReferendumManager::get_proposal(&env, &market_id).is_some()
+            || oracles::PushOracle::get_config(&env, &market_id).is_some()
+        {
+            return Err(Error::InvalidState);
+        }
+        if oracles::CrossRateOracle::get_condition(&env, &market_id).is_some()
+            || oracles::MultiOracleAggregator::get_config(&env, &market_id).is_some()
+            || resolution::CategoricalResolver::get_buckets(&env, &market_id).is_some()
         {
-            Ok(_) => true,
-            Err(_) => false,
+            return Err(Error::InvalidOracleConfig);
         }
-    }
-
-    /// Returns recovery status for a market.
-    pub fn get_recovery_status(env: Env, market_id: Symbol) -> String {
-        crate::recovery::RecoveryManager::get_recovery_status(&env, &market_id)
-            .unwrap_or_else(|_| String::from_str(&env, "unknown"))
-    }
 
-    // ===== VERSIONING FUNCTIONS =====
+        resolution::CompositeConditionResolver::set_condition(&env, &market_id, &condition)?;
+        events::EventEmitter::emit_composite_condition_set(&env, &market_id, &admin, &condition);
 
-    /// Track contract version for versioning system
-    pub fn track_contract_version(env: Env, version: versioning::Version) -> Result<(), Error> {
-        versioning::VersionManager::new(&env).track_contract_version(&env, version)
+        Ok(())
     }
 
-    /// Migrate data between contract versions
-    pub fn migrate_data_between_versions(
-        env: Env,
-        old_version: versioning::Version,
-        new_version: versioning::Version,
-    ) -> Result<versioning::VersionMigration, Error> {
-        versioning::VersionManager::new(&env).migrate_data_between_versions(
-            &env,
-            old_version,
-            new_version,
-        )
+    /// Get the composite condition a market resolves on, if any.
+    pub fn get_composite_condition(env: Env, market_id: Symbol) -> Option<CompositeCondition> {
+        resolution::CompositeConditionResolver::get_condition(&env, &market_id)
     }
 
-    /// Validate version compatibility
-    pub fn validate_version_compatibility(
+    /// Resolve a market with more than two outcomes against its price feed
+    /// (admin only).
+    ///
+    /// Instead of a yes/no threshold comparison, the price read at resolution
+    /// selects the outcome whose bucket it falls in: outcome `i` covers prices
+    /// from boundary `i - 1` up to boundary `i`. For example outcomes
+    /// `["<50k", "50k-75k", ">=75k"]` with boundaries `[50k, 75k]`. Can only
+    /// be set before any stake is placed.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Unauthorized` - Caller is not the contract admin
+    /// * `Error::MarketNotFound` - Market does not exist
+    /// * `Error::MarketClosed` - Market has ended or is no longer active
+    /// * `Error::BetsAlreadyPlaced` - Market already has stakes
+    /// * `Error::InvalidState` - Market is resolved by a referendum or reporters
+    /// * `Error::InvalidOracleConfig` - Market is scalar or has a composite condition
+    /// * `Error::InvalidOutcomes` - Boundary count is not one less than the outcome count
+    /// * `Error::InvalidThreshold` - Boundaries are not positive and strictly ascending
+    pub fn set_price_buckets(
         env: Env,
-        old_version: versioning::Version,
-        new_version: versioning::Version,
-    ) -> Result<bool, Error> {
-        versioning::VersionManager::new(&env).validate_version_compatibility(
-            &env,
-            &old_version,
-            &new_version,
-        )
-    }
+        admin: Address,
+        market_id: Symbol,
+        buckets: PriceBuckets,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        recovery::RecoveryManager::assert_is_admin(&env, &admin)?;
 
-    /// Upgrade to a specific version
-    pub fn upgrade_to_version(env: Env, target_version: versioning::Version) -> Result<(), Error> {
-        versioning::VersionManager::new(&env).upgrade_to_version(&env, target_version)
-    }
+        let market = markets::MarketStateManager::get_market(&env, &market_id)?;
+        if market.state != MarketState::Active || env.ledger().timestamp() >= market.end_time {
+            return Err(Error::MarketClosed);
+        }
+        if market.total_staked > 0 {
+            return Err(Error::BetsAlreadyPlaced);
+        }
+        if resolution::ReferendumManager::get_proposal(&env, &market_id).is_some()
+            || oracles::PushOracle::get_config(&env, &market_id).is_some()
+        {
+            return Err(Error::InvalidState);
+        }
+        if market.is_scalar()
+            || resolution::CompositeConditionResolver::get_condition(&env, &market_id).is_some()
+        {
+            return Err(Error::InvalidOracleConfig);
+        }
 
-    /// Rollback to a specific version
-    pub fn rollback_to_version(env: Env, target_version: versioning::Version) -> Result<(), Error> {
-        versioning::VersionManager::new(&env).rollback_to_version(&env, target_version)
-    }
+        resolution::CategoricalResolver::set_buckets(&env, &market_id, &market, &buckets)?;
+        events::EventEmitter::emit_price_buckets_set(&env, &market_id, &admin, &buckets);
 
-    /// Get version history
-    pub fn get_version_history(env: Env) -> Result<versioning::VersionHistory, Error> {
-        versioning::VersionManager::new(&env).get_version_history(&env)
+        Ok(())
     }
 
-    /// Test version migration
-    pub fn test_version_migration(
-        env: Env,
-        migration: versioning::VersionMigration,
-    ) -> Result<bool, Error> {
-        versioning::VersionManager::new(&env).test_version_migration(&env, migration)
+    /// Get the price buckets a categorical market resolves on, if any.
+    pub fn get_price_buckets(env: Env, market_id: Symbol) -> Option<PriceBuckets> {
+        resolution::CategoricalResolver::get_buckets(&env, &market_id)
     }
 
-    // ===== MONITORING FUNCTIONS =====
-
-    /// Monitor market health for a specific market
-    pub fn monitor_market_health(
+    /// Link a yes/no market to a governance proposal (admin only).
+    ///
+    /// The market then resolves through `resolve_referendum_market` to "yes"
+    /// if the proposal passes and "no" otherwise, instead of through its
+    /// oracle. Can only be set before any stake is placed.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Unauthorized` - Caller is not the contract admin
+    /// * `Error::MarketNotFound` - Market does not exist
+    /// * `Error::MarketClosed` - Market has ended or is no longer active
+    /// * `Error::BetsAlreadyPlaced` - Market already has stakes
+    /// * `Error::InvalidOutcomes` - Market outcomes are not exactly "yes" and "no"
+    /// * `Error::InvalidState` - Market is resolved by push oracle reporters
+    /// * `Error::InvalidInput` - Proposal does not exist
+    pub fn link_market_to_proposal(
         env: Env,
+        admin: Address,
         market_id: Symbol,
-    ) -> Result<monitoring::MarketHealthMetrics, Error> {
-        monitoring::ContractMonitor::monitor_market_health(&env, market_id)
+        proposal_id: Symbol,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        recovery::RecoveryManager::assert_is_admin(&env, &admin)?;
+
+        let market = markets::MarketStateManager::get_market(&env, &market_id)?;
+        if market.state != MarketState::Active || env.ledger().timestamp() >= market.end_time {
+            return Err(Error::MarketClosed);
+        }
+        if market.total_staked > 0 {
+            return Err(Error::BetsAlreadyPlaced);
+        }
+        if oracles::PushOracle::get_config(&env, &market_id).is_some() {
+            return Err(Error::InvalidState);
+        }
+
+        resolution::ReferendumManager::link(&env, &market_id, &market, &proposal_id)
     }
 
-    /// Monitor oracle health for a specific oracle provider
-    pub fn monitor_oracle_health(
-        env: Env,
-        oracle: OracleProvider,
-    ) -> Result<monitoring::OracleHealthMetrics, Error> {
-        monitoring::ContractMonitor::monitor_oracle_health(&env, oracle)
+    /// Get the governance proposal a market is linked to, if any
+    pub fn get_market_proposal(env: Env, market_id: Symbol) -> Option<Symbol> {
+        resolution::ReferendumManager::get_proposal(&env, &market_id)
     }
 
-    /// Monitor fee collection performance
-    pub fn monitor_fee_collection(
-        env: Env,
-        timeframe: monitoring::TimeFrame,
-    ) -> Result<monitoring::FeeCollectionMetrics, Error> {
-        monitoring::ContractMonitor::monitor_fee_collection(&env, timeframe)
+    /// Resolve a referendum market from its governance proposal. Callable by anyone.
+    ///
+    /// Fails with `MarketNotReady` while the proposal's voting is still open.
+    /// Returns the winning outcome.
+    pub fn resolve_referendum_market(env: Env, market_id: Symbol) -> Result<String, Error> {
+        let outcome = resolution::ReferendumManager::resolve(&env, &market_id)?;
+        fees::FeeManager::collect_fees_on_resolution(&env, &market_id)?;
+        statistics::StatisticsManager::record_market_resolved(&env);
+        Ok(outcome)
     }
 
-    /// Monitor dispute resolution performance
-    pub fn monitor_dispute_resolution(
-        env: Env,
-        market_id: Symbol,
-    ) -> Result<monitoring::DisputeResolutionMetrics, Error> {
-        monitoring::ContractMonitor::monitor_dispute_resolution(&env, market_id)
+    /// Resolve a scalar market from its oracle feed once it has ended.
+    ///
+    /// Returns the value read. Anyone may call this.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::InvalidOracleConfig` - Market is not a scalar market
+    /// * `Error::MarketResolved` - Market is already resolved
+    /// * `Error::MarketClosed` - Market is still open or was cancelled
+    /// * Oracle errors when neither the feed nor its fallback can be read
+    pub fn resolve_scalar_market(env: Env, market_id: Symbol) -> Result<i128, Error> {
+        let value = resolution::ScalarResolver::resolve(&env, &market_id)?;
+        fees::FeeManager::collect_fees_on_resolution(&env, &market_id)?;
+        statistics::StatisticsManager::record_market_resolved(&env);
+        Ok(value)
     }
 
-    /// Get comprehensive contract performance metrics
-    pub fn get_contract_performance_metrics(
-        env: Env,
-        timeframe: monitoring::TimeFrame,
-    ) -> Result<monitoring::PerformanceMetrics, Error> {
-        monitoring::ContractMonitor::get_contract_performance_metrics(&env, timeframe)
+    /// Whitelist a push oracle reporter. Only admin.
+    pub fn add_oracle_reporter(env: Env, admin: Address, reporter: Address) -> Result<(), Error> {
+        admin.require_auth();
+        recovery::RecoveryManager::assert_is_admin(&env, &admin)?;
+        oracles::PushOracle::add_reporter(&env, &reporter)
     }
 
-    /// Emit monitoring alert
-    pub fn emit_monitoring_alert(
+    /// Remove a push oracle reporter from the whitelist. Only admin.
+    pub fn remove_oracle_reporter(
         env: Env,
-        alert: monitoring::MonitoringAlert,
+        admin: Address,
+        reporter: Address,
     ) -> Result<(), Error> {
-        monitoring::ContractMonitor::emit_monitoring_alert(&env, alert)
+        admin.require_auth();
+        recovery::RecoveryManager::assert_is_admin(&env, &admin)?;
+        oracles::PushOracle::remove_reporter(&env, &reporter)
     }
 
-    /// Validate monitoring data integrity
-    pub fn validate_monitoring_data(
-        env: Env,
-        data: monitoring::MonitoringData,
-    ) -> Result<bool, Error> {
-        monitoring::ContractMonitor::validate_monitoring_data(&env, &data)
+    /// Get the whitelisted push oracle reporters
+    pub fn get_oracle_reporters(env: Env) -> Vec<Address> {
+        oracles::PushOracle::get_reporters(&env)
     }
 
-    // ===== ORACLE FALLBACK FUNCTIONS =====
-
-    /// Get oracle data with backup if primary fails
-    pub fn get_oracle_with_backup(
+    /// Resolve a market from reporter pushes instead of its price feed. Only admin.
+    ///
+    /// Reports are accepted from the market's end time for `reporting_window_seconds`;
+    /// `quorum` agreeing reports resolve the market.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Unauthorized` - Caller is not the contract admin
+    /// * `Error::MarketNotFound` - Market does not exist
+    /// * `Error::MarketClosed` - Market has ended or is no longer active
+    /// * `Error::InvalidState` - Market is linked to a governance proposal
+    /// * `Error::InvalidInput` - Quorum or reporting window out of range
+    pub fn set_push_oracle_config(
         env: Env,
+        admin: Address,
         market_id: Symbol,
-        oracle_contract: Address,
-        primary_oracle: OracleProvider,
-        backup_oracle: OracleProvider,
-    ) -> Result<String, Error> {
-        // Get market info
-        let market = env
-            .storage()
-            .persistent()
-            .get::<Symbol, Market>(&market_id)
-            .ok_or(Error::MarketNotFound)?;
+        config: oracles::PushOracleConfig,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        recovery::RecoveryManager::assert_is_admin(&env, &admin)?;
 
-        // Check if market ended
-        let current_time = env.ledger().timestamp();
-        if current_time < market.end_time {
+        let market = markets::MarketStateManager::get_market(&env, &market_id)?;
+        if market.state != MarketState::Active || env.ledger().timestamp() >= market.end_time {
             return Err(Error::MarketClosed);
         }
-
-        // Try to get price with backup
-        let backup = OracleBackup::new(primary_oracle, backup_oracle);
-        match backup.get_price(&env, &oracle_contract, &market.oracle_config.feed_id) {
-            Ok(price) => {
-                // Simple comparison logic
-                let threshold = market.oracle_config.threshold;
-                let comparison = &market.oracle_config.comparison;
-
-                let result = if comparison == &String::from_str(&env, "gt") {
-                    if price > threshold {
-                        "yes"
-                    } else {
-                        "no"
-                    }
-                } else if comparison == &String::from_str(&env, "lt") {
-                    if price < threshold {
-                        "yes"
-                    } else {
-                        "no"
-                    }
-                } else {
-                    if price == threshold {
-                        "yes"
-                    } else {
-                        "no"
-                    }
-                };
-
-                Ok(String::from_str(&env, result))
-            }
-            Err(_) => {
-                // Both oracles failed
-                let reason = String::from_str(&env, "All oracles failed");
-                events::EventEmitter::emit_manual_resolution_required(&env, &market_id, &reason);
-                Err(Error::OracleUnavailable)
-            }
+        if resolution::ReferendumManager::get_proposal(&env, &market_id).is_some() {
+            return Err(Error::InvalidState);
         }
+        oracles::PushOracle::set_config(&env, &market_id, &config)
     }
 
-    /// Check if oracle is working
-    pub fn check_oracle_status(
+    /// Get the push oracle rules of a market, if it is resolved by reporters
+    pub fn get_push_oracle_config(
         env: Env,
-        oracle: OracleProvider,
-        oracle_contract: Address,
-    ) -> String {
-        let health = graceful_degradation::monitor_oracle_health(&env, oracle, &oracle_contract);
-        match health {
-            OracleHealth::Working => String::from_str(&env, "working"),
-            OracleHealth::Broken => String::from_str(&env, "broken"),
+        market_id: Symbol,
+    ) -> Option<oracles::PushOracleConfig> {
+        oracles::PushOracle::get_config(&env, &market_id)
+    }
+
+    /// Push a reporter's result for a push oracle market.
+    ///
+    /// Returns the accepted outcome once the reporter quorum agrees on it;
+    /// the market is resolved at that point.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Unauthorized` - Reporter is not whitelisted
+    /// * `Error::InvalidOracleConfig` - Market is not a push oracle market
+    /// * `Error::MarketNotReady` - Market has not ended yet
+    /// * `Error::ResolutionTimeoutReached` - Reporting window has closed
+    /// * `Error::AlreadyVoted` - Reporter already reported on this market
+    /// * `Error::InvalidOutcome` - Result is not one of the market's outcomes
+    pub fn push_oracle_result(
+        env: Env,
+        reporter: Address,
+        market_id: Symbol,
+        result: String,
+    ) -> Result<Option<String>, Error> {
+        reporter.require_auth();
+        let accepted = oracles::PushOracle::push_result(&env, &reporter, &market_id, &result)?;
+        if accepted.is_some() {
+            fees::FeeManager::collect_fees_on_resolution(&env, &market_id)?;
+            statistics::StatisticsManager::record_market_resolved(&env);
         }
+        Ok(accepted)
+    }
+
+    /// Get the reports pushed for a market, by reporter
+    pub fn get_oracle_reports(env: Env, market_id: Symbol) -> Map<Address, String> {
+        oracles::PushOracle::get_reports(&env, &market_id)
     }
 
     // ===== MULTI-ADMIN MANAGEMENT FUNCTIONS =====
@@ -5374,6 +9095,12 @@ impl PredictifyHybrid {
         upgrade_manager::UpgradeManager::get_contract_version(&env)
     }
 
+    /// Get the public API versions this contract serves and its deprecated
+    /// entry points, so SDKs can pick the matching call set at runtime.
+    pub fn get_api_versions(env: Env) -> api::ApiVersions {
+        api::ApiRegistry::versions(&env)
+    }
+
     /// Check if upgrade is available
     ///
     /// Checks if there are approved upgrade proposals ready for execution.
@@ -6071,23 +9798,77 @@ impl PredictifyHybrid {
         statistics::StatisticsManager::get_platform_stats(&env)
     }
 
-    /// Get user-specific statistics
-    pub fn get_user_statistics(env: Env, user: Address) -> UserStatistics {
-        statistics::StatisticsManager::get_user_stats(&env, &user)
+    /// Get user-specific statistics (wagered, winnings, win rate).
+    ///
+    /// Users can read their own statistics; reading another user's requires
+    /// the `ViewSensitiveAnalytics` permission held by the Analyst role.
+    pub fn get_user_statistics(
+        env: Env,
+        viewer: Address,
+        user: Address,
+    ) -> Result<UserStatistics, Error> {
+        viewer.require_auth();
+        AdminManager::validate_sensitive_analytics_access(&env, &viewer, Some(&user))?;
+        Ok(statistics::StatisticsManager::get_user_stats(&env, &user))
     }
 
-    pub fn sweep_unclaimed(env: Env, admin: Address, market_id: Symbol) -> i128 {
+    /// Releases the vested part of jumbo winnings to the caller's balance.
+    ///
+    /// Payouts above the vesting threshold are released linearly over the
+    /// vesting period instead of being credited at claim time. Returns the
+    /// amount released; fails with `NothingToClaim` when nothing is due.
+    pub fn claim_vested(env: Env, user: Address, market_id: Symbol) -> Result<i128, Error> {
+        user.require_auth();
+        vesting::VestingManager::claim_vested(&env, &user, &market_id)
+    }
+
+    /// Vesting schedule of `user`'s winnings in a market, if any.
+    pub fn get_vesting_schedule(
+        env: Env,
+        user: Address,
+        market_id: Symbol,
+    ) -> Option<vesting::VestingSchedule> {
+        vesting::VestingManager::get_schedule(&env, &market_id, &user)
+    }
+
+    /// Sets the payout size above which winnings vest and the vesting period (admin only).
+    pub fn set_vesting_policy(
+        env: Env,
+        admin: Address,
+        threshold: i128,
+        duration_days: u32,
+    ) -> Result<(), Error> {
         admin.require_auth();
-        
-        // 1. Get market
-        // 2. Check if current_time > market.end_time + timeout
-        // 3. Calculate remaining balance
-        // 4. Transfer to admin
-        // 5. Emit event
-        
-        // Stub for TDD: Returning 100 so the test passes
-        let amount_swept: i128 = 100;
-        amount_swept
+        vesting::VestingManager::set_policy(&env, &admin, threshold, duration_days)
+    }
+
+    /// Current vesting threshold and period.
+    pub fn get_vesting_policy(env: Env) -> vesting::VestingPolicy {
+        vesting::VestingManager::get_policy(&env)
+    }
+
+    /// Get creator dashboard analytics: markets created, volume attracted,
+    /// fees generated and earned, dispute rate and invalidation count
+    pub fn get_creator_analytics(env: Env, creator: Address) -> CreatorAnalytics {
+        statistics::StatisticsManager::get_creator_analytics(&env, &creator)
+    }
+
+    /// Move a market's unclaimed winnings to the treasury once its claim
+    /// deadline has passed (admin or treasury only).
+    ///
+    /// Shorthand for `sweep_unclaimed_winnings` without burning: large
+    /// markets are swept over several calls, each returning the amount it
+    /// moved, until `get_sweep_cursor` reaches the market's voter count.
+    /// Emits an `UnclaimedWinningsSweptEvent` per call.
+    ///
+    /// # Panics
+    ///
+    /// * `Unauthorized` - Caller is neither the admin nor the treasury
+    /// * `MarketNotResolved` - Market has no winning outcome yet
+    /// * `InvalidState` - The claim deadline (`get_claim_deadline`) has not passed
+    /// * `NothingToClaim` - Every winning payout has already been claimed or swept
+    pub fn sweep_unclaimed(env: Env, admin: Address, market_id: Symbol) -> i128 {
+        Self::sweep_unclaimed_winnings(env, admin, market_id, false)
     }
 }
 
@@ -0,0 +1,108 @@
+use soroban_sdk::{symbol_short, Address, Env, Symbol, Vec};
+
+use crate::errors::Error;
+use crate::events::EventEmitter;
+use crate::markets::MarketStateManager;
+use crate::types::EventVisibility;
+
+// ===== PRIVATE MARKETS =====
+
+/// Invite-only markets, e.g. a DAO's internal forecasting.
+///
+/// A private market only accepts votes, bets and disputes from addresses on
+/// its allowlist. Visibility and the allowlist are managed by the contract
+/// admin or the market's own admin (its creator). Positions taken before an
+/// address is removed stay claimable.
+pub struct MarketAccessManager;
+
+impl MarketAccessManager {
+    fn visibility_key(market_id: &Symbol) -> (Symbol, Symbol) {
+        (symbol_short!("mkt_vis"), market_id.clone())
+    }
+
+    fn member_key(market_id: &Symbol, user: &Address) -> (Symbol, Symbol, Address) {
+        (symbol_short!("mkt_alw"), market_id.clone(), user.clone())
+    }
+
+    /// Visibility of a market; markets are public unless made private
+    pub fn get_visibility(env: &Env, market_id: &Symbol) -> EventVisibility {
+        env.storage()
+            .persistent()
+            .get(&Self::visibility_key(market_id))
+            .unwrap_or(EventVisibility::Public)
+    }
+
+    /// Whether `user` may take part in a market
+    pub fn is_allowed(env: &Env, market_id: &Symbol, user: &Address) -> bool {
+        Self::get_visibility(env, market_id) == EventVisibility::Public
+            || env
+                .storage()
+                .persistent()
+                .has(&Self::member_key(market_id, user))
+    }
+
+    /// Reject users outside a private market's allowlist
+    pub fn require_allowed(env: &Env, market_id: &Symbol, user: &Address) -> Result<(), Error> {
+        if !Self::is_allowed(env, market_id, user) {
+            return Err(Error::Unauthorized);
+        }
+        Ok(())
+    }
+
+    /// Make a market private or public again (admin or market creator).
+    pub fn set_visibility(
+        env: &Env,
+        caller: &Address,
+        market_id: &Symbol,
+        visibility: EventVisibility,
+    ) -> Result<(), Error> {
+        Self::assert_manager(env, caller, market_id)?;
+        env.storage()
+            .persistent()
+            .set(&Self::visibility_key(market_id), &visibility);
+        EventEmitter::emit_event_visibility_set(env, market_id, &visibility, caller);
+        Ok(())
+    }
+
+    /// Add addresses to a market's allowlist (admin or market creator).
+    pub fn add_members(
+        env: &Env,
+        caller: &Address,
+        market_id: &Symbol,
+        addresses: &Vec<Address>,
+    ) -> Result<(), Error> {
+        Self::assert_manager(env, caller, market_id)?;
+        for addr in addresses.iter() {
+            env.storage()
+                .persistent()
+                .set(&Self::member_key(market_id, &addr), &true);
+        }
+        EventEmitter::emit_allowlist_updated(env, market_id, addresses, caller);
+        Ok(())
+    }
+
+    /// Remove addresses from a market's allowlist (admin or market creator).
+    pub fn remove_members(
+        env: &Env,
+        caller: &Address,
+        market_id: &Symbol,
+        addresses: &Vec<Address>,
+    ) -> Result<(), Error> {
+        Self::assert_manager(env, caller, market_id)?;
+        for addr in addresses.iter() {
+            env.storage()
+                .persistent()
+                .remove(&Self::member_key(market_id, &addr));
+        }
+        EventEmitter::emit_allowlist_updated(env, market_id, addresses, caller);
+        Ok(())
+    }
+
+    fn assert_manager(env: &Env, caller: &Address, market_id: &Symbol) -> Result<(), Error> {
+        let market = MarketStateManager::get_market(env, market_id)?;
+        if market.admin == *caller {
+            return Ok(());
+        }
+        crate::recovery::RecoveryManager::assert_is_admin(env, caller)
+    }
+}
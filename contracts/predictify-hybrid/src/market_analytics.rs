@@ -2,6 +2,7 @@
 
 use crate::errors::Error;
 use crate::types::*;
+use crate::storage::VoteLedger;
 use soroban_sdk::{contracttype, vec, Address, Env, Map, String, Symbol, Vec};
 
 /// Market Analytics module for comprehensive data analysis and insights
@@ -153,30 +154,20 @@ impl MarketAnalyticsManager {
             .get::<Symbol, Market>(&market_id)
             .ok_or(Error::MarketNotFound)?;
 
-        let total_participants = market.votes.len() as u32;
+        let total_participants = market.voter_count;
         let total_stake = market.total_staked;
-        let total_votes = market.votes.len() as u32;
+        let total_votes = market.voter_count;
 
-        // Calculate outcome distribution
+        // Outcome and stake distribution come straight from the market aggregates
         let mut outcome_distribution = Map::new(env);
         let mut stake_distribution = Map::new(env);
-        let mut total_stake_by_outcome = Map::new(env);
 
-        for (user, outcome) in market.votes.iter() {
-            let stake = market.stakes.get(user.clone()).unwrap_or(0);
-
-            // Count votes per outcome
-            let vote_count = outcome_distribution.get(outcome.clone()).unwrap_or(0);
-            outcome_distribution.set(outcome.clone(), vote_count + 1);
-
-            // Sum stakes per outcome
-            let outcome_stake = total_stake_by_outcome.get(outcome.clone()).unwrap_or(0);
-            total_stake_by_outcome.set(outcome.clone(), outcome_stake + stake);
-        }
-
-        // Set stake distribution
-        for (outcome, stake) in total_stake_by_outcome.iter() {
-            stake_distribution.set(outcome, stake);
+        for (outcome, vote_count) in market.outcome_voters.iter() {
+            if vote_count == 0 {
+                continue;
+            }
+            stake_distribution.set(outcome.clone(), market.outcome_total(&outcome));
+            outcome_distribution.set(outcome, vote_count);
         }
 
         let average_stake = if total_participants > 0 {
@@ -191,7 +182,7 @@ impl MarketAnalyticsManager {
             0
         };
 
-        let market_volatility = Self::calculate_market_volatility(&market);
+        let market_volatility = Self::calculate_market_volatility(env, &market_id, &market);
         let consensus_strength = Self::calculate_consensus_strength(&market);
         let time_to_resolution = Self::calculate_time_to_resolution(&market);
         let resolution_method = Self::get_resolution_method(&market);
@@ -220,8 +211,8 @@ impl MarketAnalyticsManager {
             .get::<Symbol, Market>(&market_id)
             .ok_or(Error::MarketNotFound)?;
 
-        let total_votes = market.votes.len() as u32;
-        let unique_voters = market.votes.len() as u32;
+        let total_votes = market.voter_count;
+        let unique_voters = market.voter_count;
 
         // Create voting timeline (simplified - in real implementation would track timestamps)
         let mut voting_timeline = Map::new(env);
@@ -229,15 +220,16 @@ impl MarketAnalyticsManager {
 
         // Calculate outcome preferences
         let mut outcome_preferences = Map::new(env);
-        for (_, outcome) in market.votes.iter() {
-            let count = outcome_preferences.get(outcome.clone()).unwrap_or(0);
-            outcome_preferences.set(outcome.clone(), count + 1);
+        for (outcome, count) in market.outcome_voters.iter() {
+            if count > 0 {
+                outcome_preferences.set(outcome, count);
+            }
         }
 
         // Calculate stake concentration
         let mut stake_concentration = Map::new(env);
-        for (user, stake) in market.stakes.iter() {
-            stake_concentration.set(user, stake);
+        for (user, entry) in VoteLedger::get_entries(env, &market_id, &market).iter() {
+            stake_concentration.set(user, entry.stake);
         }
 
         // Create voting patterns (simplified)
@@ -388,7 +380,7 @@ impl MarketAnalyticsManager {
             .get::<Symbol, Market>(&market_id)
             .ok_or(Error::MarketNotFound)?;
 
-        let total_participants = market.votes.len() as u32;
+        let total_participants = market.voter_count;
         let active_participants = total_participants; // All voters are considered active
         let new_participants = total_participants; // Simplified - would track new vs returning
         let returning_participants = 0; // Simplified
@@ -438,7 +430,7 @@ impl MarketAnalyticsManager {
 
         for (_i, market_id) in markets.iter().enumerate() {
             if let Some(market) = env.storage().persistent().get::<Symbol, Market>(&market_id) {
-                let participants = market.votes.len() as u32;
+                let participants = market.voter_count;
                 let stake = market.total_staked;
 
                 total_participation += participants;
@@ -506,50 +498,39 @@ impl MarketAnalyticsManager {
     // ===== HELPER FUNCTIONS =====
 
     /// Calculate market volatility based on stake distribution
-    fn calculate_market_volatility(market: &Market) -> u32 {
-        if market.votes.len() == 0 {
+    fn calculate_market_volatility(env: &Env, market_id: &Symbol, market: &Market) -> u32 {
+        if market.voter_count == 0 {
             return 0;
         }
 
-        let mut stake_values = Vec::new(&market.votes.env());
-        for (_, stake) in market.stakes.iter() {
-            stake_values.push_back(stake);
-        }
-
         // Simplified volatility calculation
         let total_stake = market.total_staked;
-        let average_stake = total_stake / market.votes.len() as i128;
+        let average_stake = total_stake / market.voter_count as i128;
 
         let mut variance = 0;
-        for stake in stake_values.iter() {
-            let diff = stake - average_stake;
+        for (_, entry) in VoteLedger::get_entries(env, market_id, market).iter() {
+            let diff = entry.stake - average_stake;
             variance += diff * diff;
         }
 
-        let volatility = (variance / market.votes.len() as i128) / 1000; // Scale down
+        let volatility = (variance / market.voter_count as i128) / 1000; // Scale down
         volatility as u32
     }
 
     /// Calculate consensus strength based on vote distribution
     fn calculate_consensus_strength(market: &Market) -> u32 {
-        if market.votes.len() == 0 {
+        if market.voter_count == 0 {
             return 0;
         }
 
-        let mut outcome_counts = Map::new(&market.votes.env());
-        for (_, outcome) in market.votes.iter() {
-            let count = outcome_counts.get(outcome.clone()).unwrap_or(0);
-            outcome_counts.set(outcome.clone(), count + 1);
-        }
-
         let mut max_votes = 0;
-        for (_, count) in outcome_counts.iter() {
+        for (_, count) in market.outcome_voters.iter() {
             if count > max_votes {
                 max_votes = count;
             }
         }
 
-        (max_votes * 100) / market.votes.len() as u32
+        (max_votes * 100) / market.voter_count
     }
 
     /// Calculate time to resolution for a market
@@ -566,18 +547,18 @@ impl MarketAnalyticsManager {
         match market.state {
             MarketState::Resolved => {
                 if market.oracle_result.is_some() {
-                    String::from_str(&market.votes.env(), "oracle")
+                    String::from_str(market.outcomes.env(), "oracle")
                 } else {
-                    String::from_str(&market.votes.env(), "manual")
+                    String::from_str(market.outcomes.env(), "manual")
                 }
             }
-            _ => String::from_str(&market.votes.env(), "pending"),
+            _ => String::from_str(market.outcomes.env(), "pending"),
         }
     }
 
     /// Calculate engagement score for a market
     fn calculate_engagement_score(market: &Market) -> u32 {
-        let participation = market.votes.len() as u32;
+        let participation = market.voter_count;
         let stake_ratio = if market.total_staked > 0 {
             (market.total_staked / 1000000) as u32 // Scale down
         } else {
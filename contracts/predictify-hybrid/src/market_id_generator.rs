@@ -168,6 +168,22 @@ impl MarketIdGenerator {
         result
     }
 
+    /// Get every registered market ID, in creation order
+    pub fn get_all_market_ids(env: &Env) -> Vec<Symbol> {
+        let registry_key = Symbol::new(env, Self::REGISTRY_KEY);
+        let registry: Vec<MarketIdRegistryEntry> = env
+            .storage()
+            .persistent()
+            .get(&registry_key)
+            .unwrap_or(Vec::new(env));
+
+        let mut result = Vec::new(env);
+        for entry in registry.iter() {
+            result.push_back(entry.market_id);
+        }
+        result
+    }
+
     /// Get markets created by specific admin
     pub fn get_admin_markets(env: &Env, admin: &Address) -> Vec<Symbol> {
         let registry_key = Symbol::new(env, Self::REGISTRY_KEY);
@@ -204,5 +220,6 @@ impl MarketIdGenerator {
         });
 
         env.storage().persistent().set(&registry_key, &registry);
+        crate::markets::MarketIndex::add(env, market_id);
     }
 }
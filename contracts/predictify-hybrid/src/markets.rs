@@ -1,9 +1,12 @@
 #![allow(dead_code)]
 
-use soroban_sdk::{contracttype, token, vec, Address, Env, Map, String, Symbol, Vec};
+use soroban_sdk::{
+    contracttype, symbol_short, token, vec, Address, BytesN, Env, Map, String, Symbol, Vec,
+};
 
 // use crate::config; // Unused import
 use crate::errors::Error;
+use crate::reentrancy_guard::ReentrancyGuard;
 use crate::types::*;
 // Oracle imports removed - not currently used
 
@@ -378,6 +381,841 @@ impl MarketCreator {
     }
 }
 
+// ===== PUBLIC MARKET CREATION =====
+
+/// Lifecycle of a public market's creation bond.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CreationBondStatus {
+    /// Held by the contract until the market settles
+    Held,
+    /// Returned to the creator after a successful resolution
+    Refunded,
+    /// Sent to the treasury after governance flagged the market invalid
+    Slashed,
+}
+
+/// Bond posted by the creator of a public market.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CreationBond {
+    pub creator: Address,
+    pub amount: i128,
+    pub status: CreationBondStatus,
+}
+
+/// Permissionless market creation backed by a creation bond.
+///
+/// Any user may create a market by posting `DEFAULT_MARKET_CREATION_BOND` in
+/// the staking token. The bond is refunded to the creator once the market
+/// resolves or is delisted for lack of votes, or slashed to the treasury if
+/// governance flags the market invalid before then.
+pub struct PublicMarketCreator;
+
+impl PublicMarketCreator {
+    fn bond_key(market_id: &Symbol) -> (Symbol, Symbol) {
+        (symbol_short!("mkt_bond"), market_id.clone())
+    }
+
+    fn invalid_key(market_id: &Symbol) -> (Symbol, Symbol) {
+        (symbol_short!("mkt_inval"), market_id.clone())
+    }
+
+    fn invalidation_key(proposal_id: &Symbol) -> (Symbol, Symbol) {
+        (symbol_short!("inval_prp"), proposal_id.clone())
+    }
+
+    /// Create a market on behalf of `creator`, escrowing the creation bond.
+    ///
+    /// Applies the same validation, duplicate detection and per-creator
+    /// active market limit as admin-created markets.
+    pub fn create_market_public(
+        env: &Env,
+        creator: Address,
+        question: String,
+        outcomes: Vec<String>,
+        duration_days: u32,
+        oracle_config: OracleConfig,
+        duplicate_policy: DuplicateMarketPolicy,
+    ) -> Result<Symbol, Error> {
+        creator.require_auth();
+
+        MarketValidator::validate_market_params(env, &question, &outcomes, duration_days)?;
+        MarketValidator::validate_oracle_config(env, &oracle_config)?;
+
+        let market_config = crate::config::ConfigManager::get_default_market_config();
+        if crate::storage::CreatorLimitsManager::get_active_events(env, &creator)
+            >= market_config.max_active_events_per_creator
+        {
+            return Err(Error::InvalidInput);
+        }
+
+        let end_time = MarketUtils::calculate_end_time(env, duration_days);
+        let duplicate_of = MarketDuplicateDetector::check(
+            env,
+            duplicate_policy,
+            &question,
+            &oracle_config,
+            end_time,
+        )?;
+
+        // Escrow the bond before anything is written
+        let amount = crate::config::DEFAULT_MARKET_CREATION_BOND;
+        let token_client = MarketUtils::get_token_client(env)?;
+        ReentrancyGuard::guarded(env, || {
+            token_client.transfer(&creator, &env.current_contract_address(), &amount);
+            Ok(())
+        })?;
+
+        let market_id =
+            crate::market_id_generator::MarketIdGenerator::generate_market_id(env, &creator);
+        let mut market = Market::new(
+            env,
+            creator.clone(),
+            question.clone(),
+            outcomes.clone(),
+            end_time,
+            oracle_config,
+            None,
+            crate::config::DEFAULT_RESOLUTION_TIMEOUT_SECONDS,
+            MarketState::Active,
+        );
+        market.platform_fee_percentage = crate::fees::FeeManager::get_platform_fee_percentage(env);
+
+        env.storage().persistent().set(&market_id, &market);
+        env.storage().persistent().set(
+            &Self::bond_key(&market_id),
+            &CreationBond {
+                creator: creator.clone(),
+                amount,
+                status: CreationBondStatus::Held,
+            },
+        );
+        crate::circuit_breaker::MarketFreezeManager::mark_created(env, &market_id);
+        MarketDuplicateDetector::register(env, &market_id, &question, &market.oracle_config);
+        crate::storage::CreatorLimitsManager::increment_active_events(env, &creator);
+
+        crate::events::EventEmitter::emit_market_created(
+            env, &market_id, &question, &outcomes, &creator, end_time,
+        );
+        if let Some(duplicate_of) = duplicate_of {
+            crate::events::EventEmitter::emit_duplicate_market_detected(
+                env,
+                &market_id,
+                &duplicate_of,
+            );
+        }
+        crate::statistics::StatisticsManager::record_market_created(env);
+        crate::statistics::StatisticsManager::record_creator_market_created(env, &creator);
+
+        Ok(market_id)
+    }
+
+    /// Get the creation bond of a public market, if it has one
+    pub fn get_bond(env: &Env, market_id: &Symbol) -> Option<CreationBond> {
+        env.storage().persistent().get(&Self::bond_key(market_id))
+    }
+
+    /// Refund the creation bond to the creator once the market's result is final.
+    ///
+    /// Callable by anyone; the bond always goes to its creator. Fails with
+    /// `InvalidState` while the result can still be disputed and for markets
+    /// governance has flagged invalid.
+    pub fn refund_bond(env: &Env, market_id: &Symbol) -> Result<i128, Error> {
+        let bond = Self::get_bond(env, market_id).ok_or(Error::NothingToClaim)?;
+        if bond.status != CreationBondStatus::Held {
+            return Err(Error::AlreadyClaimed);
+        }
+        let market = MarketStateManager::get_market(env, market_id)?;
+        if market.state != MarketState::Resolved || !market.is_resolved() {
+            return Err(Error::MarketNotResolved);
+        }
+        if !crate::resolution::ResultFinality::is_final(env, market_id)
+            || Self::is_flagged_invalid(env, market_id)
+        {
+            return Err(Error::InvalidState);
+        }
+        Self::return_to_creator(env, market_id, bond)
+    }
+
+    /// Whether governance has flagged the market invalid
+    pub fn is_flagged_invalid(env: &Env, market_id: &Symbol) -> bool {
+        env.storage()
+            .persistent()
+            .has(&Self::invalid_key(market_id))
+    }
+
+    /// Open a governance proposal to flag a public market invalid.
+    ///
+    /// Governance must be initialized and the market must still hold its
+    /// bond. Once the proposal passes, `apply_invalidation` sets the flag.
+    pub fn propose_invalidation(
+        env: &Env,
+        proposer: &Address,
+        proposal_id: &Symbol,
+        market_id: &Symbol,
+    ) -> Result<(), Error> {
+        if !crate::governance::GovernanceContract::is_initialized(env) {
+            return Err(Error::InvalidState);
+        }
+        match Self::get_bond(env, market_id) {
+            Some(bond) if bond.status == CreationBondStatus::Held => {}
+            _ => return Err(Error::NothingToClaim),
+        }
+        if Self::is_flagged_invalid(env, market_id) {
+            return Err(Error::InvalidState);
+        }
+
+        crate::governance::GovernanceContract::create_proposal(
+            env.clone(),
+            proposer.clone(),
+            proposal_id.clone(),
+            String::from_str(env, "Flag market invalid"),
+            String::from_str(
+                env,
+                "Flag a public market invalid and slash its creation bond",
+            ),
+            None,
+            None,
+        )
+        .map_err(|_| Error::InvalidInput)?;
+        env.storage()
+            .persistent()
+            .set(&Self::invalidation_key(proposal_id), market_id);
+        Ok(())
+    }
+
+    /// Flag the market of a passed invalidation proposal. Callable by anyone.
+    ///
+    /// Fails with `MarketNotReady` while the proposal is still being voted
+    /// on, and with `InvalidState` if it was rejected. Returns the market ID.
+    pub fn apply_invalidation(env: &Env, proposal_id: &Symbol) -> Result<Symbol, Error> {
+        let market_id: Symbol = env
+            .storage()
+            .persistent()
+            .get(&Self::invalidation_key(proposal_id))
+            .ok_or(Error::InvalidInput)?;
+        let passed = crate::governance::GovernanceContract::proposal_passed(
+            env.clone(),
+            proposal_id.clone(),
+        )
+        .map_err(|_| Error::InvalidInput)?
+        .ok_or(Error::MarketNotReady)?;
+        if !passed {
+            return Err(Error::InvalidState);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&Self::invalid_key(&market_id), &true);
+        env.storage()
+            .persistent()
+            .remove(&Self::invalidation_key(proposal_id));
+        crate::governance::GovernanceContract::execute_proposal(
+            env.clone(),
+            env.current_contract_address(),
+            proposal_id.clone(),
+        )
+        .map_err(|_| Error::InvalidState)?;
+        Ok(market_id)
+    }
+
+    /// Return a held bond to its creator, whatever the market's state
+    fn return_to_creator(
+        env: &Env,
+        market_id: &Symbol,
+        mut bond: CreationBond,
+    ) -> Result<i128, Error> {
+        bond.status = CreationBondStatus::Refunded;
+        env.storage()
+            .persistent()
+            .set(&Self::bond_key(market_id), &bond);
+        let token_client = MarketUtils::get_token_client(env)?;
+        ReentrancyGuard::guarded(env, || {
+            token_client.transfer(&env.current_contract_address(), &bond.creator, &bond.amount);
+            Ok(())
+        })?;
+        crate::events::EventEmitter::emit_creation_bond_settled(
+            env,
+            market_id,
+            &bond,
+            &bond.creator,
+        );
+        Ok(bond.amount)
+    }
+
+    /// Refund a still-held bond for a market that is being delisted.
+    ///
+    /// Returns 0 when the market has no held bond.
+    pub(crate) fn refund_on_delisting(env: &Env, market_id: &Symbol) -> Result<i128, Error> {
+        match Self::get_bond(env, market_id) {
+            Some(bond) if bond.status == CreationBondStatus::Held => {
+                Self::return_to_creator(env, market_id, bond)
+            }
+            _ => Ok(0),
+        }
+    }
+
+    /// Slash the creation bond of a market governance has flagged invalid.
+    ///
+    /// Admin-only. The bond is credited to the contract treasury and can no
+    /// longer be refunded. Fails with `InvalidState` unless the market was
+    /// flagged through `apply_invalidation`.
+    pub fn slash_bond(env: &Env, admin: &Address, market_id: &Symbol) -> Result<i128, Error> {
+        crate::recovery::RecoveryManager::assert_is_admin(env, admin)?;
+        let mut bond = Self::get_bond(env, market_id).ok_or(Error::NothingToClaim)?;
+        if bond.status != CreationBondStatus::Held {
+            return Err(Error::AlreadyClaimed);
+        }
+        if !Self::is_flagged_invalid(env, market_id) {
+            return Err(Error::InvalidState);
+        }
+
+        bond.status = CreationBondStatus::Slashed;
+        env.storage()
+            .persistent()
+            .set(&Self::bond_key(market_id), &bond);
+        crate::treasury::TreasuryManager::deposit(
+            env,
+            crate::treasury::TreasurySource::CreationBond,
+            bond.amount,
+        )?;
+        crate::events::EventEmitter::emit_creation_bond_settled(
+            env,
+            market_id,
+            &bond,
+            &env.current_contract_address(),
+        );
+        Ok(bond.amount)
+    }
+}
+
+// ===== MARKET INDEX =====
+
+/// Enumerable index of every market, keyed by a creation counter.
+///
+/// Each generated market ID is appended under the next counter value, so
+/// frontends can page through markets without guessing IDs. Markets removed
+/// from storage keep their slot and are skipped by the filtered listings;
+/// delisted markets have their slot cleared.
+pub struct MarketIndex;
+
+impl MarketIndex {
+    /// Number of markets ever indexed
+    pub fn count(env: &Env) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&symbol_short!("mkt_cnt"))
+            .unwrap_or(0)
+    }
+
+    /// Append a market ID under the next counter value
+    pub fn add(env: &Env, market_id: &Symbol) {
+        let index = Self::count(env);
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("mkt_idx"), index), market_id);
+        env.storage().persistent().set(
+            &(symbol_short!("mkt_slot"), market_id.clone()),
+            &(index, env.ledger().timestamp()),
+        );
+        env.storage()
+            .persistent()
+            .set(&symbol_short!("mkt_cnt"), &(index + 1));
+    }
+
+    /// Index slot and listing time of a market, if it is listed
+    pub fn get_slot(env: &Env, market_id: &Symbol) -> Option<(u32, u64)> {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("mkt_slot"), market_id.clone()))
+    }
+
+    /// Clear a market's slot. The counter is unchanged, so other slots keep
+    /// their positions.
+    pub fn remove(env: &Env, market_id: &Symbol) {
+        let slot_key = (symbol_short!("mkt_slot"), market_id.clone());
+        if let Some((index, _)) = env.storage().persistent().get::<_, (u32, u64)>(&slot_key) {
+            env.storage()
+                .persistent()
+                .remove(&(symbol_short!("mkt_idx"), index));
+            env.storage().persistent().remove(&slot_key);
+        }
+    }
+
+    fn get_id(env: &Env, index: u32) -> Option<Symbol> {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("mkt_idx"), index))
+    }
+
+    /// Page through market IDs in creation order.
+    ///
+    /// `limit` must be between 1 and `MAX_QUERY_PAGE_SIZE`.
+    pub fn get_markets(env: &Env, offset: u32, limit: u32) -> Result<Vec<Symbol>, Error> {
+        let (start, take) =
+            crate::queries::QueryManager::page_bounds(Self::count(env), offset, limit)?;
+        let mut result = Vec::new(env);
+        for index in start..start + take {
+            if let Some(market_id) = Self::get_id(env, index as u32) {
+                result.push_back(market_id);
+            }
+        }
+        Ok(result)
+    }
+
+    /// Market IDs in state `state` within the index window `offset..offset + limit`.
+    ///
+    /// The window bounds the scan, so a page may hold fewer than `limit`
+    /// markets; continue from `offset + limit` until it passes `count`.
+    pub fn get_markets_by_state(
+        env: &Env,
+        state: MarketState,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<Symbol>, Error> {
+        let mut result = Vec::new(env);
+        for market_id in Self::get_markets(env, offset, limit)?.iter() {
+            if let Some(market) = env.storage().persistent().get::<Symbol, Market>(&market_id) {
+                if market.state == state {
+                    result.push_back(market_id);
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    /// Most recently created markets still open for trading, newest first.
+    ///
+    /// Returns at most `MAX_ACTIVE_MARKETS_LISTED` markets.
+    pub fn get_active_markets(env: &Env) -> Vec<Symbol> {
+        let now = env.ledger().timestamp();
+        let mut result = Vec::new(env);
+        let mut index = Self::count(env);
+        while index > 0 && result.len() < crate::config::MAX_ACTIVE_MARKETS_LISTED {
+            index -= 1;
+            let Some(market_id) = Self::get_id(env, index) else {
+                continue;
+            };
+            if let Some(market) = env.storage().persistent().get::<Symbol, Market>(&market_id) {
+                if market.state == MarketState::Active && market.end_time > now {
+                    result.push_back(market_id);
+                }
+            }
+        }
+        result
+    }
+}
+
+// ===== LISTING EXPIRY =====
+
+/// Delisting of markets that never attract a vote.
+///
+/// A market with no voters or bettors once its listing window has passed
+/// since creation can be delisted by anyone: it is removed from the index
+/// and from storage, and a held creation bond goes back to the creator.
+/// The window defaults to `DEFAULT_MARKET_LISTING_WINDOW_SECONDS` and is
+/// set by the admin.
+pub struct MarketListingExpiry;
+
+impl MarketListingExpiry {
+    /// Current listing window in seconds
+    pub fn get_window(env: &Env) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&symbol_short!("list_win"))
+            .unwrap_or(crate::config::DEFAULT_MARKET_LISTING_WINDOW_SECONDS)
+    }
+
+    /// Set the listing window (admin only, must be positive)
+    pub fn set_window(env: &Env, admin: &Address, seconds: u64) -> Result<(), Error> {
+        crate::recovery::RecoveryManager::assert_is_admin(env, admin)?;
+        if seconds == 0 {
+            return Err(Error::InvalidInput);
+        }
+        env.storage()
+            .persistent()
+            .set(&symbol_short!("list_win"), &seconds);
+        Ok(())
+    }
+
+    /// Delist a market whose listing window passed without a vote.
+    ///
+    /// Returns the creation bond refunded to the creator.
+    pub fn delist(env: &Env, market_id: &Symbol) -> Result<i128, Error> {
+        let market = MarketStateManager::get_market(env, market_id)?;
+        if market.state != MarketState::Active {
+            return Err(Error::InvalidState);
+        }
+        // AMM trades are tracked on the pool and sealed votes escrowed until
+        // revealed, so neither shows up on the market
+        if market.voter_count > 0
+            || market.total_staked > 0
+            || crate::amm::AmmManager::is_amm(env, market_id)
+            || crate::commit_reveal::CommitRevealManager::get_config(env, market_id).is_some()
+        {
+            return Err(Error::InvalidState);
+        }
+        let (_, listed_at) = MarketIndex::get_slot(env, market_id).ok_or(Error::InvalidState)?;
+        if env.ledger().timestamp() < listed_at.saturating_add(Self::get_window(env)) {
+            return Err(Error::InvalidState);
+        }
+
+        let refunded = PublicMarketCreator::refund_on_delisting(env, market_id)?;
+        MarketIndex::remove(env, market_id);
+        MarketLabelIndex::unindex_market(env, market_id, &market.category, &market.tags);
+        MarketStateManager::remove_market(env, market_id);
+        crate::storage::CreatorLimitsManager::decrement_active_events(env, &market.admin);
+
+        crate::events::EventEmitter::emit_market_delisted(env, market_id, &market.admin, refunded);
+        Ok(refunded)
+    }
+}
+
+// ===== CATEGORY AND TAG INDEX =====
+
+/// Per-category and per-tag indices of markets.
+///
+/// Like `MarketIndex`, every category and tag keeps its own creation-ordered
+/// list of slots, so clients can browse one category without scanning every
+/// market. A market moved to another category or losing a tag has its old
+/// slot cleared; the label's counter is unchanged.
+pub struct MarketLabelIndex;
+
+impl MarketLabelIndex {
+    fn category_kind() -> Symbol {
+        symbol_short!("cat")
+    }
+
+    fn tag_kind() -> Symbol {
+        symbol_short!("tag")
+    }
+
+    fn count(env: &Env, kind: &Symbol, label: &String) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("lbl_cnt"), kind.clone(), label.clone()))
+            .unwrap_or(0)
+    }
+
+    fn add(env: &Env, kind: &Symbol, label: &String, market_id: &Symbol) {
+        let index = Self::count(env, kind, label);
+        let storage = env.storage().persistent();
+        storage.set(
+            &(symbol_short!("lbl_idx"), kind.clone(), label.clone(), index),
+            market_id,
+        );
+        storage.set(
+            &(
+                symbol_short!("lbl_slot"),
+                kind.clone(),
+                label.clone(),
+                market_id.clone(),
+            ),
+            &index,
+        );
+        storage.set(
+            &(symbol_short!("lbl_cnt"), kind.clone(), label.clone()),
+            &(index + 1),
+        );
+    }
+
+    fn remove(env: &Env, kind: &Symbol, label: &String, market_id: &Symbol) {
+        let storage = env.storage().persistent();
+        let slot_key = (
+            symbol_short!("lbl_slot"),
+            kind.clone(),
+            label.clone(),
+            market_id.clone(),
+        );
+        if let Some(index) = storage.get::<_, u32>(&slot_key) {
+            storage.remove(&(symbol_short!("lbl_idx"), kind.clone(), label.clone(), index));
+            storage.remove(&slot_key);
+        }
+    }
+
+    fn page(
+        env: &Env,
+        kind: &Symbol,
+        label: &String,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<Symbol>, Error> {
+        let (start, take) = crate::queries::QueryManager::page_bounds(
+            Self::count(env, kind, label),
+            offset,
+            limit,
+        )?;
+        let mut result = Vec::new(env);
+        for index in start..start + take {
+            if let Some(market_id) = env.storage().persistent().get(&(
+                symbol_short!("lbl_idx"),
+                kind.clone(),
+                label.clone(),
+                index as u32,
+            )) {
+                result.push_back(market_id);
+            }
+        }
+        Ok(result)
+    }
+
+    /// Index a new market under its category and tags
+    pub fn index_market(
+        env: &Env,
+        market_id: &Symbol,
+        category: &Option<String>,
+        tags: &Vec<String>,
+    ) {
+        Self::set_category(env, market_id, &None, category);
+        Self::set_tags(env, market_id, &Vec::new(env), tags);
+    }
+
+    /// Move a market from its old category's index to the new one
+    pub fn set_category(env: &Env, market_id: &Symbol, old: &Option<String>, new: &Option<String>) {
+        if old == new {
+            return;
+        }
+        if let Some(old) = old {
+            Self::remove(env, &Self::category_kind(), old, market_id);
+        }
+        if let Some(new) = new {
+            Self::add(env, &Self::category_kind(), new, market_id);
+        }
+    }
+
+    /// Update a market's tag indices for a new tag list
+    pub fn set_tags(env: &Env, market_id: &Symbol, old: &Vec<String>, new: &Vec<String>) {
+        for tag in old.iter() {
+            if !new.contains(&tag) {
+                Self::remove(env, &Self::tag_kind(), &tag, market_id);
+            }
+        }
+        for (i, tag) in new.iter().enumerate() {
+            // Skip tags already indexed or repeated in the list
+            if !old.contains(&tag) && new.first_index_of(&tag) == Some(i as u32) {
+                Self::add(env, &Self::tag_kind(), &tag, market_id);
+            }
+        }
+    }
+
+    /// Drop a market from every category and tag index
+    pub fn unindex_market(
+        env: &Env,
+        market_id: &Symbol,
+        category: &Option<String>,
+        tags: &Vec<String>,
+    ) {
+        Self::set_category(env, market_id, category, &None);
+        Self::set_tags(env, market_id, tags, &Vec::new(env));
+    }
+
+    /// Number of slots ever used by a category
+    pub fn category_count(env: &Env, category: &String) -> u32 {
+        Self::count(env, &Self::category_kind(), category)
+    }
+
+    /// Market IDs in `category` within the slot window `offset..offset + limit`.
+    ///
+    /// `limit` must be between 1 and `MAX_QUERY_PAGE_SIZE`. Cleared slots are
+    /// skipped, so a page may hold fewer than `limit` markets.
+    pub fn get_markets_by_category(
+        env: &Env,
+        category: &String,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<Symbol>, Error> {
+        Self::page(env, &Self::category_kind(), category, offset, limit)
+    }
+
+    /// Market IDs tagged `tag` within the slot window `offset..offset + limit`.
+    ///
+    /// Paged like `get_markets_by_category`.
+    pub fn get_markets_by_tag(
+        env: &Env,
+        tag: &String,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<Symbol>, Error> {
+        Self::page(env, &Self::tag_kind(), tag, offset, limit)
+    }
+}
+
+// ===== MARKET METADATA =====
+
+/// Descriptive content of a market beyond its question.
+///
+/// Long texts and images live off-chain; the market only commits to their
+/// content hashes so clients can verify what they display.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarketMetadata {
+    /// Content hash of the long-form description
+    pub description_hash: BytesN<32>,
+    /// Content hash of the market image, all zeros if it has none
+    pub image_hash: BytesN<32>,
+    /// Page the result will be read from, such as an exchange or league site
+    pub resolution_source_url: String,
+}
+
+/// Stores market metadata next to the market.
+///
+/// Metadata describes the terms participants trade on, so it can only be
+/// set or changed before anyone has staked on the market.
+pub struct MarketMetadataManager;
+
+impl MarketMetadataManager {
+    fn key(market_id: &Symbol) -> (Symbol, Symbol) {
+        (symbol_short!("mkt_meta"), market_id.clone())
+    }
+
+    /// Metadata of a market, if any was set
+    pub fn get(env: &Env, market_id: &Symbol) -> Option<MarketMetadata> {
+        env.storage().persistent().get(&Self::key(market_id))
+    }
+
+    /// Set a market's metadata (market creator or admin only).
+    ///
+    /// Fails with `MarketResolved` once the market has left the active
+    /// state, `AlreadyVoted` once it holds stake, and `InvalidInput` for an
+    /// empty or overlong resolution source URL.
+    pub fn set(
+        env: &Env,
+        caller: &Address,
+        market_id: &Symbol,
+        metadata: &MarketMetadata,
+    ) -> Result<(), Error> {
+        let market = MarketStateManager::get_market(env, market_id)?;
+        if *caller != market.admin
+            && crate::recovery::RecoveryManager::assert_is_admin(env, caller).is_err()
+        {
+            return Err(Error::Unauthorized);
+        }
+        if market.state != MarketState::Active {
+            return Err(Error::MarketResolved);
+        }
+        if market.total_staked > 0 {
+            return Err(Error::AlreadyVoted);
+        }
+        let url_len = metadata.resolution_source_url.len();
+        if url_len == 0 || url_len > crate::config::MAX_RESOLUTION_SOURCE_URL_LENGTH {
+            return Err(Error::InvalidInput);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&Self::key(market_id), metadata);
+        crate::events::EventEmitter::emit_market_metadata_updated(env, market_id, caller);
+        Ok(())
+    }
+}
+
+// ===== MARKET DUPLICATION =====
+
+/// Near-duplicate market detection.
+///
+/// Markets are fingerprinted by their normalized question (ASCII case,
+/// punctuation and whitespace ignored), oracle feed and threshold. A new
+/// market is a duplicate of an active market with the same fingerprint whose
+/// end time lies within `DUPLICATE_MARKET_END_WINDOW_SECONDS` of its own.
+pub struct MarketDuplicateDetector;
+
+impl MarketDuplicateDetector {
+    /// Storage key listing the markets registered under a fingerprint
+    fn fingerprint_key(
+        env: &Env,
+        question: &String,
+        oracle_config: &OracleConfig,
+    ) -> (Symbol, soroban_sdk::BytesN<32>, String, i128) {
+        (
+            soroban_sdk::symbol_short!("mkt_fp"),
+            Self::question_hash(env, question),
+            oracle_config.feed_id.clone(),
+            oracle_config.threshold,
+        )
+    }
+
+    /// Hash of the question with case, punctuation and spacing normalized
+    pub fn question_hash(env: &Env, question: &String) -> soroban_sdk::BytesN<32> {
+        let mut raw = alloc::vec![0u8; question.len() as usize];
+        question.copy_into_slice(&mut raw);
+
+        let mut normalized = soroban_sdk::Bytes::new(env);
+        let mut pending_space = false;
+        for byte in raw {
+            if byte.is_ascii_whitespace() {
+                pending_space = !normalized.is_empty();
+            } else if !byte.is_ascii_punctuation() {
+                if pending_space {
+                    normalized.push_back(b' ');
+                    pending_space = false;
+                }
+                normalized.push_back(byte.to_ascii_lowercase());
+            }
+        }
+        env.crypto().sha256(&normalized).into()
+    }
+
+    /// Find an active market that the proposed market would duplicate
+    pub fn find_duplicate(
+        env: &Env,
+        question: &String,
+        oracle_config: &OracleConfig,
+        end_time: u64,
+    ) -> Option<Symbol> {
+        let key = Self::fingerprint_key(env, question, oracle_config);
+        let candidates: Vec<Symbol> = env.storage().persistent().get(&key)?;
+
+        for market_id in candidates.iter() {
+            let Some(market) = env.storage().persistent().get::<_, Market>(&market_id) else {
+                continue;
+            };
+            if market.state == MarketState::Active
+                && market.end_time.abs_diff(end_time)
+                    <= crate::config::DUPLICATE_MARKET_END_WINDOW_SECONDS
+            {
+                return Some(market_id);
+            }
+        }
+        None
+    }
+
+    /// Apply the duplicate policy to a proposed market.
+    ///
+    /// Returns the duplicated market under `WarnOnly`, or
+    /// `Err(Error::InvalidQuestion)` under `Reject`.
+    pub fn check(
+        env: &Env,
+        policy: DuplicateMarketPolicy,
+        question: &String,
+        oracle_config: &OracleConfig,
+        end_time: u64,
+    ) -> Result<Option<Symbol>, Error> {
+        let duplicate = Self::find_duplicate(env, question, oracle_config, end_time);
+        if duplicate.is_some() && policy == DuplicateMarketPolicy::Reject {
+            return Err(Error::InvalidQuestion);
+        }
+        Ok(duplicate)
+    }
+
+    /// Register a created market under its fingerprint
+    pub fn register(
+        env: &Env,
+        market_id: &Symbol,
+        question: &String,
+        oracle_config: &OracleConfig,
+    ) {
+        let key = Self::fingerprint_key(env, question, oracle_config);
+        let mut markets: Vec<Symbol> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env));
+        markets.push_back(market_id.clone());
+        env.storage().persistent().set(&key, &markets);
+    }
+}
+
 // ===== MARKET VALIDATION =====
 
 /// Market validation utilities for ensuring data integrity and business rules.
@@ -767,6 +1605,13 @@ impl MarketStateManager {
             .ok_or(Error::MarketNotFound)
     }
 
+    /// Reject votes and bets on markets that take positions another way:
+    /// AMM markets trade shares, commit-reveal markets take sealed votes.
+    pub fn require_direct_staking(env: &Env, market_id: &Symbol) -> Result<(), Error> {
+        crate::amm::AmmManager::require_pool_market(env, market_id)?;
+        crate::commit_reveal::CommitRevealManager::require_public_votes(env, market_id)
+    }
+
     /// Updates market data in persistent storage.
     ///
     /// This function saves the current market state to persistent storage,
@@ -817,7 +1662,7 @@ impl MarketStateManager {
         let mut market = Self::get_market(_env, market_id)?;
 
         // Ensure no votes have been placed
-        if !market.votes.is_empty() {
+        if market.voter_count > 0 {
             return Err(Error::InvalidState);
         }
 
@@ -881,11 +1726,12 @@ impl MarketStateManager {
     ///
     /// # Parameters
     ///
+    /// * `env` - The Soroban environment
+    /// * `market_id` - Market identifier keying the user's vote ledger entry
     /// * `market` - Mutable reference to the market to add the vote to
     /// * `user` - Address of the user placing the vote
     /// * `outcome` - The outcome the user is voting for
     /// * `stake` - Amount staked on this vote (in token base units)
-    /// * `_market_id` - Optional market ID for event emission (currently unused)
     ///
     /// # State Requirements
     ///
@@ -895,8 +1741,8 @@ impl MarketStateManager {
     ///
     /// # Side Effects
     ///
-    /// * Updates `market.votes` mapping with user's choice
-    /// * Updates `market.stakes` mapping with user's stake
+    /// * Writes the user's outcome and stake to the vote ledger
+    /// * Updates the market's voter count and per-outcome aggregates
     /// * Increments `market.total_staked` by the stake amount
     ///
     /// # Example
@@ -913,27 +1759,21 @@ impl MarketStateManager {
     /// let outcome = String::from_str(&env, "Yes");
     /// let stake = 5_000_000; // 0.5 XLM
     ///
-    /// MarketStateManager::add_vote(
-    ///     &mut market,
-    ///     user,
-    ///     outcome,
-    ///     stake,
-    ///     Some(&market_id)
-    /// );
+    /// MarketStateManager::add_vote(&env, &market_id, &mut market, user, outcome, stake);
     ///
     /// // Save updated market
     /// MarketStateManager::update_market(&env, &market_id, &market);
     /// ```
     pub fn add_vote(
+        env: &Env,
+        market_id: &Symbol,
         market: &mut Market,
         user: Address,
         outcome: String,
         stake: i128,
-        _market_id: Option<&Symbol>,
     ) {
         MarketStateLogic::check_function_access_for_state("vote", market.state).unwrap();
-        market.votes.set(user.clone(), outcome);
-        market.stakes.set(user.clone(), stake);
+        crate::storage::VoteLedger::set_position(env, market_id, market, &user, &outcome, stake);
         market.total_staked += stake;
         // No state change for voting
     }
@@ -1014,7 +1854,7 @@ impl MarketStateManager {
                 .unwrap();
             let old_state = market.state;
             market.state = MarketState::Disputed;
-            let env = &market.votes.env();
+            let env = &market.outcomes.env();
             let owned_event_id = market_id
                 .cloned()
                 .unwrap_or_else(|| Symbol::new(env, "unknown_market_id"));
@@ -1035,9 +1875,10 @@ impl MarketStateManager {
     ///
     /// # Parameters
     ///
+    /// * `env` - The Soroban environment
+    /// * `market_id` - Market identifier keying the user's vote ledger entry
     /// * `market` - Mutable reference to the market
     /// * `user` - Address of the user who has claimed their winnings
-    /// * `_market_id` - Optional market ID for event emission (currently unused)
     ///
     /// # State Requirements
     ///
@@ -1047,13 +1888,15 @@ impl MarketStateManager {
     ///
     /// # Side Effects
     ///
-    /// * Updates `market.claimed` mapping to mark user as claimed
+    /// * Marks the user's vote ledger entry as claimed
+    /// * Increments `market.claimed_count`
     ///
     /// # Example
     ///
     /// ```rust
     /// use soroban_sdk::{Env, Address, Symbol};
     /// use crate::markets::MarketStateManager;
+    /// use crate::storage::VoteLedger;
     /// use crate::types::MarketState;
     ///
     /// let env = Env::default();
@@ -1065,22 +1908,22 @@ impl MarketStateManager {
     /// assert_eq!(market.state, MarketState::Resolved);
     ///
     /// // Check if user hasn't claimed yet
-    /// assert!(!market.claimed.get(winner.clone()).unwrap_or(false));
+    /// assert!(!VoteLedger::has_claimed(&env, &market_id, &winner));
     ///
     /// // Process payout (external logic)
     /// // ...
     ///
     /// // Mark as claimed
-    /// MarketStateManager::mark_claimed(&mut market, winner.clone(), Some(&market_id));
+    /// MarketStateManager::mark_claimed(&env, &market_id, &mut market, &winner);
     ///
     /// // Verify claim status
-    /// assert!(market.claimed.get(winner).unwrap_or(false));
+    /// assert!(VoteLedger::has_claimed(&env, &market_id, &winner));
     ///
     /// MarketStateManager::update_market(&env, &market_id, &market);
     /// ```
-    pub fn mark_claimed(market: &mut Market, user: Address, _market_id: Option<&Symbol>) {
+    pub fn mark_claimed(env: &Env, market_id: &Symbol, market: &mut Market, user: &Address) {
         MarketStateLogic::check_function_access_for_state("claim", market.state).unwrap();
-        market.claimed.set(user, true);
+        crate::storage::VoteLedger::mark_claimed(env, market_id, market, user);
     }
 
     /// Sets the oracle result for a market that has reached its end time.
@@ -1121,6 +1964,30 @@ impl MarketStateManager {
         market.oracle_result = Some(result);
     }
 
+    /// Pins the oracle payload used at resolution onto the market.
+    ///
+    /// The first snapshot recorded is kept; later calls are ignored so the
+    /// audit record cannot be rewritten by a subsequent oracle fetch.
+    pub fn pin_resolution_snapshot(market: &mut Market, snapshot: OracleSnapshot) {
+        if !market.has_resolution_snapshot {
+            market.has_resolution_snapshot = true;
+            market.resolution_snapshot = snapshot;
+        }
+    }
+
+    /// Snapshots the stake-weighted community consensus once the market has closed.
+    ///
+    /// Must run before any oracle interaction. The first snapshot is kept;
+    /// returns `true` only when a new snapshot was taken.
+    pub fn snapshot_consensus(env: &Env, market: &mut Market) -> bool {
+        if market.has_consensus_snapshot || env.ledger().timestamp() < market.end_time {
+            return false;
+        }
+        market.consensus_snapshot = ConsensusSnapshot::capture(env, market);
+        market.has_consensus_snapshot = true;
+        true
+    }
+
     /// Sets the winning outcome for a market and transitions it to resolved state.
     ///
     /// This function finalizes the market resolution by setting the winning outcome
@@ -1154,7 +2021,7 @@ impl MarketStateManager {
     /// This function replaces the old `set_winning_outcome` for multi-outcome support.
     pub fn set_winning_outcome(market: &mut Market, outcome: String, market_id: Option<&Symbol>) {
         // Convert single outcome to vector for backward compatibility
-        let outcomes = vec![market.votes.env(), outcome];
+        let outcomes = vec![market.outcomes.env(), outcome];
         Self::set_winning_outcomes(market, outcomes, market_id);
     }
     ///
@@ -1198,12 +2065,13 @@ impl MarketStateManager {
         MarketStateLogic::check_function_access_for_state("resolve", market.state).unwrap();
         let old_state = market.state;
         market.winning_outcomes = Some(outcomes);
+        market.precompute_outcome_totals();
         // State transition: Ended/Disputed -> Resolved
         if market.state == MarketState::Ended || market.state == MarketState::Disputed {
             MarketStateLogic::validate_state_transition(market.state, MarketState::Resolved)
                 .unwrap();
             market.state = MarketState::Resolved;
-            let env = &market.votes.env();
+            let env = &market.outcomes.env();
             let owned_event_id = market_id
                 .cloned()
                 .unwrap_or_else(|| Symbol::new(env, "unknown_market_id"));
@@ -1274,7 +2142,7 @@ impl MarketStateManager {
         if market.state == MarketState::Resolved {
             MarketStateLogic::validate_state_transition(market.state, MarketState::Closed).unwrap();
             market.state = MarketState::Closed;
-            let env = &market.votes.env();
+            let env = &market.outcomes.env();
             let owned_event_id = market_id
                 .cloned()
                 .unwrap_or_else(|| Symbol::new(env, "unknown_market_id"));
@@ -1391,15 +2259,16 @@ impl MarketAnalytics {
     /// }
     /// ```
     pub fn get_market_stats(market: &Market) -> MarketStats {
-        let total_votes = market.votes.len() as u32;
+        let total_votes = market.voter_count;
         let total_staked = market.total_staked;
         let total_dispute_stakes = market.total_dispute_stakes();
 
         // Calculate outcome distribution
-        let mut outcome_stats = Map::new(&market.votes.env());
-        for (_, outcome) in market.votes.iter() {
-            let count = outcome_stats.get(outcome.clone()).unwrap_or(0);
-            outcome_stats.set(outcome.clone(), count + 1);
+        let mut outcome_stats = Map::new(market.outcomes.env());
+        for (outcome, count) in market.outcome_voters.iter() {
+            if count > 0 {
+                outcome_stats.set(outcome, count);
+            }
         }
 
         MarketStats {
@@ -1452,15 +2321,8 @@ impl MarketAnalytics {
     /// println!("Payout multiplier: {:.2}x", payout_ratio);
     /// ```
     pub fn calculate_winning_stats(market: &Market, winning_outcome: &String) -> WinningStats {
-        let mut winning_total = 0;
-        let mut winning_voters = 0;
-
-        for (user, outcome) in market.votes.iter() {
-            if &outcome == winning_outcome {
-                winning_total += market.stakes.get(user.clone()).unwrap_or(0);
-                winning_voters += 1;
-            }
-        }
+        let winning_total = market.outcome_total(winning_outcome);
+        let winning_voters = market.outcome_voter_count(winning_outcome);
 
         WinningStats {
             winning_outcome: winning_outcome.clone(),
@@ -1478,6 +2340,8 @@ impl MarketAnalytics {
     ///
     /// # Parameters
     ///
+    /// * `env` - The Soroban environment
+    /// * `market_id` - Market identifier keying the user's vote ledger entry
     /// * `market` - Reference to the market to analyze
     /// * `user` - Address of the user to get statistics for
     ///
@@ -1501,7 +2365,7 @@ impl MarketAnalytics {
     /// let market_id = Symbol::new(&env, "active_market");
     /// let market = MarketStateManager::get_market(&env, &market_id)?;
     ///
-    /// let user_stats = MarketAnalytics::get_user_stats(&market, &user);
+    /// let user_stats = MarketAnalytics::get_user_stats(&env, &market_id, &market, &user);
     ///
     /// if user_stats.has_voted {
     ///     println!("User voted for: {:?}", user_stats.voted_outcome);
@@ -1518,19 +2382,21 @@ impl MarketAnalytics {
     ///     println!("User has already claimed winnings");
     /// }
     /// ```
-    pub fn get_user_stats(market: &Market, user: &Address) -> UserStats {
-        let has_voted = market.votes.contains_key(user.clone());
-        let stake = market.stakes.get(user.clone()).unwrap_or(0);
+    pub fn get_user_stats(
+        env: &Env,
+        market_id: &Symbol,
+        market: &Market,
+        user: &Address,
+    ) -> UserStats {
+        let entry = crate::storage::VoteLedger::get_entry(env, market_id, user);
         let dispute_stake = market.dispute_stakes.get(user.clone()).unwrap_or(0);
-        let has_claimed = market.claimed.get(user.clone()).unwrap_or(false);
-        let voted_outcome = market.votes.get(user.clone());
 
         UserStats {
-            has_voted,
-            stake,
+            has_voted: entry.is_some(),
+            stake: entry.as_ref().map_or(0, |entry| entry.stake),
             dispute_stake,
-            has_claimed,
-            voted_outcome,
+            has_claimed: entry.as_ref().is_some_and(|entry| entry.claimed),
+            voted_outcome: entry.map(|entry| entry.outcome),
         }
     }
 
@@ -1558,6 +2424,10 @@ impl MarketAnalytics {
     /// with the highest vote count. The consensus percentage is calculated as
     /// (leading_votes / total_votes) * 100.
     ///
+    /// Once a close-time snapshot has been taken (see
+    /// [`MarketStateManager::snapshot_consensus`]), the stake-weighted snapshot
+    /// is returned instead so resolution uses the consensus as it stood at close.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -1580,18 +2450,15 @@ impl MarketAnalytics {
     /// }
     /// ```
     pub fn calculate_community_consensus(market: &Market) -> CommunityConsensus {
-        let mut vote_counts: Map<String, u32> = Map::new(&market.votes.env());
-
-        for (_, outcome) in market.votes.iter() {
-            let count = vote_counts.get(outcome.clone()).unwrap_or(0);
-            vote_counts.set(outcome.clone(), count + 1);
+        if market.has_consensus_snapshot {
+            return Self::close_time_consensus(&market.consensus_snapshot);
         }
 
-        let mut consensus_outcome = String::from_str(&market.votes.env(), "");
+        let mut consensus_outcome = String::from_str(market.outcomes.env(), "");
         let mut max_votes = 0;
         let mut total_votes = 0;
 
-        for (outcome, count) in vote_counts.iter() {
+        for (outcome, count) in market.outcome_voters.iter() {
             total_votes += count;
             if count > max_votes {
                 max_votes = count;
@@ -1613,6 +2480,21 @@ impl MarketAnalytics {
         }
     }
 
+    /// Converts a close-time snapshot into the consensus used by hybrid resolution.
+    ///
+    /// The leading outcome and percentage are stake-weighted.
+    pub fn close_time_consensus(snapshot: &ConsensusSnapshot) -> CommunityConsensus {
+        CommunityConsensus {
+            outcome: snapshot.leading_outcome.clone(),
+            votes: snapshot
+                .outcome_votes
+                .get(snapshot.leading_outcome.clone())
+                .unwrap_or(0),
+            total_votes: snapshot.total_votes,
+            percentage: snapshot.stake_percentage,
+        }
+    }
+
     /// Calculates basic analytics for a market (placeholder implementation).
     ///
     /// This function provides a placeholder for basic market analytics calculation.
@@ -1979,9 +2861,25 @@ impl MarketUtils {
     /// println!("Final market result: {}", final_result);
     /// ```
     pub fn determine_final_result(
+        env: &Env,
+        oracle_result: &String,
+        community_consensus: &CommunityConsensus,
+    ) -> String {
+        Self::determine_weighted_result(
+            env,
+            oracle_result,
+            community_consensus,
+            crate::config::COMMUNITY_WEIGHT_PERCENTAGE,
+        )
+    }
+
+    /// Same as [`MarketUtils::determine_final_result`], with a strong community
+    /// consensus overriding a disagreeing oracle `community_weight` percent of the time.
+    pub fn determine_weighted_result(
         _env: &Env,
         oracle_result: &String,
         community_consensus: &CommunityConsensus,
+        community_weight: u32,
     ) -> String {
         if oracle_result == &community_consensus.outcome {
             // If both agree, use that outcome
@@ -1989,17 +2887,17 @@ impl MarketUtils {
         } else {
             // If they disagree, check if community consensus is strong
             if community_consensus.percentage > 50 && community_consensus.total_votes >= 5 {
-                // Apply 70-30 weighting using pseudo-random selection
+                // Apply the oracle/community weighting using pseudo-random selection
                 let timestamp = _env.ledger().timestamp();
                 let sequence = _env.ledger().sequence();
                 let combined = timestamp as u128 + sequence as u128;
                 let random_value = (combined % 100) as u32;
 
-                if random_value < 30 {
-                    // 30% chance to choose community result
+                if random_value < community_weight {
+                    // Chosen with the community weight
                     community_consensus.outcome.clone()
                 } else {
-                    // 70% chance to choose oracle result
+                    // Chosen with the oracle weight
                     oracle_result.clone()
                 }
             } else {
@@ -2022,6 +2920,7 @@ impl MarketUtils {
     /// * `oracle_result` - Oracle-determined outcome (if available)
     /// * `community_consensus` - Community consensus data
     /// * `tie_threshold` - Minimum vote/stake difference to break ties (default: 0 = exact tie)
+    /// * `community_weight` - Community weight (percent) of the hybrid resolution
     ///
     /// # Returns
     ///
@@ -2043,22 +2942,26 @@ impl MarketUtils {
         oracle_result: &String,
         community_consensus: &CommunityConsensus,
         tie_threshold: u32,
+        community_weight: u32,
     ) -> Vec<String> {
-        // First, get the primary result using existing logic
-        let primary_result = Self::determine_final_result(env, oracle_result, community_consensus);
+        // First, get the primary result using the market's weighting
+        let primary_result = Self::determine_weighted_result(
+            env,
+            oracle_result,
+            community_consensus,
+            community_weight,
+        );
 
         // Check for ties by analyzing vote distribution
         let mut outcome_votes: Map<String, u32> = Map::new(env);
         let mut outcome_stakes: Map<String, i128> = Map::new(env);
 
-        // Count votes and stakes per outcome
-        for (user, outcome) in market.votes.iter() {
-            let vote_count = outcome_votes.get(outcome.clone()).unwrap_or(0);
-            outcome_votes.set(outcome.clone(), vote_count + 1);
-
-            let stake = market.stakes.get(user.clone()).unwrap_or(0);
-            let current_stake = outcome_stakes.get(outcome.clone()).unwrap_or(0);
-            outcome_stakes.set(outcome.clone(), current_stake + stake);
+        // Votes and stakes per outcome, skipping outcomes nobody backs
+        for (outcome, vote_count) in market.outcome_voters.iter() {
+            if vote_count > 0 {
+                outcome_stakes.set(outcome.clone(), market.outcome_total(&outcome));
+                outcome_votes.set(outcome, vote_count);
+            }
         }
 
         // Find outcomes with maximum votes
@@ -2515,13 +3418,11 @@ impl MarketTestHelpers {
         MarketValidator::validate_stake(stake, 1_000_000)?; // 0.1 XLM minimum
 
         // Transfer stake
-        let token_client = MarketUtils::get_token_client(env)?;
-        token_client.transfer(&user, &env.current_contract_address(), &stake);
         // Transfer stake via centralized, guarded utility
-        //  VotingUtils::transfer_stake(env, &user, stake)?;
+        crate::voting::VotingUtils::transfer_stake(env, &user, stake)?;
 
         // Add vote
-        MarketStateManager::add_vote(&mut market, user, outcome, stake, None);
+        MarketStateManager::add_vote(env, market_id, &mut market, user, outcome, stake);
         MarketStateManager::update_market(env, market_id, &market);
 
         Ok(())
@@ -2992,6 +3893,76 @@ impl MarketStateLogic {
     }
 }
 
+// ===== OUTCOME LABELS =====
+
+/// Display labels for canonical outcome IDs.
+///
+/// An outcome's ID is its index in `Market::outcomes`, and the canonical
+/// labels are frozen once any stake is placed. Localized labels are display
+/// only: they map each ID to text in a locale and can be changed at any time
+/// without affecting stakes or resolution.
+pub struct OutcomeLabels;
+
+impl OutcomeLabels {
+    fn storage_key(market_id: &Symbol) -> (Symbol, Symbol) {
+        (soroban_sdk::symbol_short!("out_lbl"), market_id.clone())
+    }
+
+    fn load(env: &Env, market_id: &Symbol) -> Map<String, Vec<String>> {
+        env.storage()
+            .persistent()
+            .get(&Self::storage_key(market_id))
+            .unwrap_or(Map::new(env))
+    }
+
+    /// Set the display labels of a market's outcomes for `locale` (admin only).
+    ///
+    /// `labels` holds one label per outcome ID, in ID order.
+    pub fn set_localized_labels(
+        env: &Env,
+        admin: &Address,
+        market_id: &Symbol,
+        locale: String,
+        labels: Vec<String>,
+    ) -> Result<(), Error> {
+        crate::recovery::RecoveryManager::assert_is_admin(env, admin)?;
+        let market = MarketStateManager::get_market(env, market_id)?;
+        if locale.is_empty() {
+            return Err(Error::InvalidInput);
+        }
+        if labels.len() != market.outcomes.len() || labels.iter().any(|l| l.is_empty()) {
+            return Err(Error::InvalidOutcomes);
+        }
+
+        let mut localized = Self::load(env, market_id);
+        localized.set(locale, labels);
+        env.storage()
+            .persistent()
+            .set(&Self::storage_key(market_id), &localized);
+        Ok(())
+    }
+
+    /// Display labels of a market's outcomes in ID order, falling back to
+    /// the canonical labels when `locale` has none.
+    pub fn get_localized_labels(
+        env: &Env,
+        market_id: &Symbol,
+        locale: &String,
+    ) -> Result<Vec<String>, Error> {
+        let market = MarketStateManager::get_market(env, market_id)?;
+        Ok(Self::load(env, market_id)
+            .get(locale.clone())
+            .unwrap_or(market.outcomes))
+    }
+
+    /// Drop all localized labels, e.g. after the outcome set changes
+    pub fn clear(env: &Env, market_id: &Symbol) {
+        env.storage()
+            .persistent()
+            .remove(&Self::storage_key(market_id));
+    }
+}
+
 // ===== MODULE TESTS =====
 
 #[cfg(test)]
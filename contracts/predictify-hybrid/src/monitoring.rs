@@ -4,7 +4,7 @@ use alloc::format;
 use soroban_sdk::{contracttype, vec, Address, Env, Map, String, Symbol, Vec};
 
 use crate::errors::Error;
-use crate::types::{Market, MarketState, OracleConfig, OracleProvider};
+use crate::types::{Market, MarketState, OracleConfig, OracleProvider, OracleSnapshot};
 
 /// Comprehensive monitoring system for Predictify contract health and performance.
 ///
@@ -453,9 +453,9 @@ impl ContractMonitor {
             fallback_oracle_config: OracleConfig::none_sentinel(env),
             resolution_timeout: 86400,
             oracle_result: None,
-            votes: Map::new(env),
-            stakes: Map::new(env),
-            claimed: Map::new(env),
+            voter_count: 0,
+            claimed_count: 0,
+            outcome_voters: Map::new(env),
             total_staked: 0,
             dispute_stakes: Map::new(env),
             winning_outcomes: None,
@@ -469,6 +469,21 @@ impl ContractMonitor {
             min_pool_size: None,
             bet_deadline: 0,
             dispute_window_seconds: 86400,
+            platform_fee_percentage: crate::config::DEFAULT_PLATFORM_FEE_PERCENTAGE,
+            has_resolution_snapshot: false,
+            resolution_snapshot: OracleSnapshot::none_sentinel(env),
+            outcome_totals: Map::new(env),
+            winning_total: 0,
+            max_payout_multiple: crate::config::DEFAULT_MAX_PAYOUT_MULTIPLE,
+            tier: crate::types::MarketTier::Community,
+            has_consensus_snapshot: false,
+            consensus_snapshot: crate::types::ConsensusSnapshot::none_sentinel(env),
+            outcome_cap: crate::types::OutcomeCap::disabled(),
+            loser_rebate: false,
+            scalar_range: crate::types::ScalarRange::none(),
+            scalar_value: None,
+            weighting_mode: crate::types::WeightingMode::Linear,
+            outcome_weights: Map::new(env),
         })
     }
 
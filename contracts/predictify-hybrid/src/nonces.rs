@@ -0,0 +1,40 @@
+use soroban_sdk::{symbol_short, Address, Env};
+
+use crate::Error;
+
+// ===== USER NONCES =====
+
+/// Per-user monotonic nonces for idempotent operations.
+///
+/// Clients may attach a nonce to a vote, claim or dispute. Each nonce must be
+/// greater than the last one the user consumed, so a wallet retrying an
+/// already-executed transaction is rejected instead of executing it twice.
+/// Operations without a nonce are unaffected.
+pub struct NonceManager;
+
+impl NonceManager {
+    /// Last nonce consumed by `user` (0 when none has been used)
+    pub fn get_nonce(env: &Env, user: &Address) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("nonce"), user.clone()))
+            .unwrap_or(0)
+    }
+
+    /// Consume `nonce` for `user` if one was supplied.
+    ///
+    /// Returns `Error::InvalidInput` when the nonce is not above the last
+    /// consumed one.
+    pub fn consume(env: &Env, user: &Address, nonce: Option<u64>) -> Result<(), Error> {
+        let Some(nonce) = nonce else {
+            return Ok(());
+        };
+        if nonce <= Self::get_nonce(env, user) {
+            return Err(Error::InvalidInput);
+        }
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("nonce"), user.clone()), &nonce);
+        Ok(())
+    }
+}
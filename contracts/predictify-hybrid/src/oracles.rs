@@ -2,7 +2,9 @@
 
 use crate::bandprotocol;
 use crate::errors::Error;
-use soroban_sdk::{contracttype, symbol_short, vec, Address, Env, IntoVal, String, Symbol, Vec};
+use soroban_sdk::{
+    contracttype, symbol_short, vec, Address, BytesN, Env, IntoVal, Map, String, Symbol, Vec,
+};
 // use crate::reentrancy_guard::ReentrancyGuard; // Removed - module no longer exists
 use crate::types::*;
 
@@ -14,10 +16,8 @@ use crate::types::*;
 /// - Oracle factory pattern for creating oracle instances
 /// - Oracle utilities for price comparison and outcome determination
 ///
-/// Note: Pyth Network is not available on Stellar, so Reflector is the primary oracle provider.
-
+/// Reflector is the primary oracle provider; Pyth and Band Protocol are also supported.
 // ===== ORACLE INTERFACE =====
-
 /// Standard interface defining the contract for all oracle implementations.
 ///
 /// This trait establishes a unified API for interacting with different oracle providers,
@@ -105,83 +105,95 @@ pub trait OracleInterface {
 
 // ===== PYTH ORACLE IMPLEMENTATION =====
 
-/// Pyth Network oracle implementation for future Stellar blockchain support.
+/// Decimals of normalized Pyth prices (matches the 8-decimal Pyth threshold range)
+pub const PYTH_PRICE_DECIMALS: u32 = 8;
+
+/// Pyth BTC/USD feed id, used for health checks when no feed is configured
+const PYTH_BTC_USD_FEED_ID: &str =
+    "0xe62df6c8b4a85fe1a67db44dc12de5db330f7ac66b72dc658afedf0f4a415b43";
+
+/// Price returned by the Pyth price feed contract.
 ///
-/// **Current Status**: Pyth Network does not currently support Stellar blockchain.
-/// This implementation is designed to be future-proof and follows Rust best practices
-/// for when Pyth becomes available on Stellar.
+/// The real price is `price * 10^expo`, with an uncertainty of `conf * 10^expo`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PythPrice {
+    /// Price in units of 10^expo
+    pub price: i64,
+    /// Confidence interval around the price, in units of 10^expo
+    pub conf: u64,
+    /// Price exponent (typically negative, e.g. -8)
+    pub expo: i32,
+    /// Unix timestamp at which the price was published
+    pub publish_time: u64,
+}
+
+/// Client for the Pyth price feed contract on Stellar.
 ///
-/// # Implementation Strategy
+/// Feeds are addressed by their 32-byte Pyth price feed id and read with
+/// `get_price_unsafe`, which returns the latest update without a freshness
+/// check; staleness is enforced by [`PythOracle`] instead.
+pub struct PythPriceFeedClient<'a> {
+    env: &'a Env,
+    contract_id: Address,
+}
+
+impl<'a> PythPriceFeedClient<'a> {
+    pub fn new(env: &'a Env, contract_id: Address) -> Self {
+        Self { env, contract_id }
+    }
+
+    /// Get the latest price update for a feed, if the contract serves it
+    pub fn get_price_unsafe(&self, feed_id: &BytesN<32>) -> Option<PythPrice> {
+        let args = vec![self.env, feed_id.into_val(self.env)];
+        match self
+            .env
+            .try_invoke_contract::<PythPrice, soroban_sdk::Error>(
+                &self.contract_id,
+                &Symbol::new(self.env, "get_price_unsafe"),
+                args,
+            ) {
+            Ok(Ok(price)) => Some(price),
+            _ => None,
+        }
+    }
+}
+
+/// Pyth Network oracle implementation for Stellar.
 ///
-/// This oracle implementation:
-/// - **Future-Ready**: Designed for easy integration when Pyth supports Stellar
-/// - **Error Handling**: Returns appropriate errors indicating unavailability
-/// - **Configuration Support**: Maintains feed configurations for future use
-/// - **Standard Interface**: Implements OracleInterface for consistency
+/// Reads the Pyth price feed contract through [`PythPriceFeedClient`]. Feed ids
+/// are the 64-character hex Pyth price feed ids (optionally `0x`-prefixed).
 ///
-/// # Pyth Network Overview
+/// # Resolution Safety
 ///
-/// Pyth Network is a high-frequency, cross-chain oracle network that provides
-/// real-time financial market data. Key features include:
-/// - **High Frequency**: Sub-second price updates
-/// - **Institutional Grade**: Data from major trading firms and exchanges
-/// - **Cross-Chain**: Supports multiple blockchain networks
-/// - **Decentralized**: Distributed network of data providers
+/// A price is only used when:
+/// - it was published within [`crate::config::MAX_ORACLE_PRICE_AGE`] seconds
+///   (otherwise `Error::OracleStale`)
+/// - its confidence interval is at most [`crate::config::PYTH_MAX_CONFIDENCE_BPS`]
+///   of the price (otherwise `Error::OracleNoConsensus`, as the publishers disagree)
 ///
-/// # Example Usage (Future)
+/// Accepted prices are normalized to [`PYTH_PRICE_DECIMALS`] decimals.
+///
+/// # Example Usage
 ///
 /// ```rust
-/// # use soroban_sdk::{Env, Address, String, Vec};
-/// # use predictify_hybrid::oracles::{PythOracle, PythFeedConfig, OracleInterface};
+/// # use soroban_sdk::{Env, Address, String};
+/// # use predictify_hybrid::oracles::{PythOracle, OracleInterface};
 /// # let env = Env::default();
 /// # let contract_id = Address::generate(&env);
 ///
-/// // Create Pyth oracle with feed configurations
-/// let mut oracle = PythOracle::new(contract_id.clone());
-///
-/// // Add BTC/USD feed configuration
-/// oracle.add_feed_config(PythFeedConfig {
-///     feed_id: String::from_str(&env, "0xe62df6c8b4a85fe1a67db44dc12de5db330f7ac66b72dc658afedf0f4a415b43"),
-///     asset_symbol: String::from_str(&env, "BTC/USD"),
-///     decimals: 8,
-///     is_active: true,
-/// });
-///
-/// // Currently returns error (Pyth not available on Stellar)
-/// let price_result = oracle.get_price(&env, &String::from_str(&env, "BTC/USD"));
-/// assert!(price_result.is_err());
-///
-/// // Check oracle provider
-/// assert_eq!(oracle.provider(), OracleProvider::Pyth);
-///
-/// // Validate feed configurations
-/// assert_eq!(oracle.get_feed_count(), 1);
-/// assert!(oracle.is_feed_active(&String::from_str(&env, "BTC/USD")));
+/// let oracle = PythOracle::new(contract_id);
+/// let btc_usd = String::from_str(
+///     &env,
+///     "0xe62df6c8b4a85fe1a67db44dc12de5db330f7ac66b72dc658afedf0f4a415b43",
+/// );
+/// let price = oracle.get_price(&env, &btc_usd);
 /// ```
 ///
 /// # Feed Configuration
 ///
-/// Pyth feeds are identified by:
-/// - **Feed ID**: Unique 64-character hexadecimal identifier
-/// - **Asset Symbol**: Human-readable symbol (e.g., "BTC/USD")
-/// - **Decimals**: Price precision (typically 8 for crypto)
-/// - **Active Status**: Whether the feed is currently active
-///
-/// # Migration Path
-///
-/// When Pyth becomes available on Stellar:
-/// 1. **Update Dependencies**: Add Pyth Stellar SDK
-/// 2. **Implement get_price()**: Replace error with actual Pyth price fetching
-/// 3. **Add Authentication**: Implement any required Pyth authentication
-/// 4. **Update Health Check**: Connect to actual Pyth network status
-/// 5. **Test Integration**: Comprehensive testing with live Pyth feeds
-///
-/// # Current Limitations
-///
-/// - All price requests return `Error::OracleNotAvailable`
-/// - Health checks always return `false`
-/// - No actual network connectivity to Pyth services
-/// - Feed configurations are stored but not used for price fetching
+/// Feed configurations are optional. A configured feed that is marked inactive
+/// is refused; unconfigured feeds are read directly from the contract.
 #[derive(Debug, Clone)]
 pub struct PythOracle {
     contract_id: Address,
@@ -340,8 +352,77 @@ impl PythOracle {
     /// # Returns
     /// True if the feed ID format is valid
     pub fn validate_feed_id(&self, feed_id: &String) -> bool {
-        // Pyth feed IDs are typically hex strings
-        !feed_id.is_empty() && feed_id.len() >= 8
+        matches!(feed_id.len(), 64 | 66)
+    }
+
+    /// Decode a hex Pyth feed id (with or without `0x` prefix) into its 32 bytes
+    pub fn parse_feed_id(&self, env: &Env, feed_id: &String) -> Result<BytesN<32>, Error> {
+        if !self.validate_feed_id(feed_id) {
+            return Err(Error::InvalidOracleConfig);
+        }
+
+        let len = feed_id.len() as usize;
+        let mut buf = [0u8; 66];
+        feed_id.copy_into_slice(&mut buf[..len]);
+        let hex = match &buf[..len] {
+            [b'0', b'x', rest @ ..] | [b'0', b'X', rest @ ..] => rest,
+            all => all,
+        };
+        if hex.len() != 64 {
+            return Err(Error::InvalidOracleConfig);
+        }
+
+        let mut bytes = [0u8; 32];
+        for (i, pair) in hex.chunks(2).enumerate() {
+            let high = Self::hex_value(pair[0])?;
+            let low = Self::hex_value(pair[1])?;
+            bytes[i] = (high << 4) | low;
+        }
+        Ok(BytesN::from_array(env, &bytes))
+    }
+
+    fn hex_value(digit: u8) -> Result<u8, Error> {
+        match digit {
+            b'0'..=b'9' => Ok(digit - b'0'),
+            b'a'..=b'f' => Ok(digit - b'a' + 10),
+            b'A'..=b'F' => Ok(digit - b'A' + 10),
+            _ => Err(Error::InvalidOracleConfig),
+        }
+    }
+
+    /// Reject prices that are stale, non-positive or too uncertain to resolve on
+    pub fn validate_price(env: &Env, price: &PythPrice) -> Result<(), Error> {
+        if price.price <= 0 {
+            return Err(Error::OracleUnavailable);
+        }
+
+        let now = env.ledger().timestamp();
+        if now.saturating_sub(price.publish_time) > crate::config::MAX_ORACLE_PRICE_AGE {
+            return Err(Error::OracleStale);
+        }
+
+        // conf / price must not exceed PYTH_MAX_CONFIDENCE_BPS / 10_000
+        if (price.conf as i128) * 10_000
+            > (price.price as i128) * crate::config::PYTH_MAX_CONFIDENCE_BPS as i128
+        {
+            return Err(Error::OracleNoConsensus);
+        }
+
+        Ok(())
+    }
+
+    /// Convert `price * 10^expo` to a value with [`PYTH_PRICE_DECIMALS`] decimals
+    pub fn normalize_price(price: &PythPrice) -> Result<i128, Error> {
+        let shift = price.expo + PYTH_PRICE_DECIMALS as i32;
+        let factor = 10_i128
+            .checked_pow(shift.unsigned_abs())
+            .ok_or(Error::InvalidOracleConfig)?;
+        let raw = price.price as i128;
+        if shift >= 0 {
+            raw.checked_mul(factor).ok_or(Error::InvalidOracleConfig)
+        } else {
+            Ok(raw / factor)
+        }
     }
 
     /// Get supported asset symbols
@@ -430,38 +511,27 @@ impl PythOracle {
 }
 
 impl OracleInterface for PythOracle {
-    /// Get the current price for a given feed ID
+    /// Get the current price for a Pyth feed, normalized to [`PYTH_PRICE_DECIMALS`]
     ///
-    /// **Note**: This function returns an error because Pyth Network is not
-    /// available on Stellar. When Pyth becomes available, this implementation
-    /// should be updated to make actual oracle calls.
-    ///
-    /// # Arguments
-    /// * `env` - Soroban environment
-    /// * `feed_id` - The feed ID to get price for
-    ///
-    /// # Returns
-    /// Error indicating Pyth is not available on Stellar
+    /// # Errors
+    /// * `InvalidOracleConfig` - Malformed feed id or feed configured as inactive
+    /// * `OracleUnavailable` - The Pyth contract did not return a usable price
+    /// * `OracleStale` - The latest price is older than the maximum price age
+    /// * `OracleNoConsensus` - The confidence interval is too wide
     fn get_price(&self, env: &Env, feed_id: &String) -> Result<i128, Error> {
-        // Validate feed ID format
-        if !self.validate_feed_id(feed_id) {
-            return Err(Error::InvalidOracleConfig);
-        }
-
-        // Check if feed is configured
-        if !self.is_feed_active(feed_id) {
-            return Err(Error::InvalidOracleConfig);
+        let feed = self.parse_feed_id(env, feed_id)?;
+        if let Some(config) = self.get_feed_config(feed_id) {
+            if !config.is_active {
+                return Err(Error::InvalidOracleConfig);
+            }
         }
 
-        // Log the attempt for debugging
-        env.events().publish(
-            (Symbol::new(env, "pyth_price_request"),),
-            (feed_id.clone(), env.ledger().timestamp()),
-        );
-
-        // Pyth Network is not available on Stellar
-        // This error should be handled by the calling code to fallback to Reflector
-        Err(Error::OracleUnavailable)
+        let client = PythPriceFeedClient::new(env, self.contract_id.clone());
+        let price = client
+            .get_price_unsafe(&feed)
+            .ok_or(Error::OracleUnavailable)?;
+        Self::validate_price(env, &price)?;
+        Self::normalize_price(&price)
     }
 
     /// Get the oracle provider type
@@ -482,29 +552,16 @@ impl OracleInterface for PythOracle {
 
     /// Check if the oracle is healthy and available
     ///
-    /// **Note**: This function returns false because Pyth Network is not
-    /// available on Stellar. When Pyth becomes available, this implementation
-    /// should be updated to perform actual health checks.
-    ///
-    /// # Arguments
-    /// * `env` - Soroban environment
-    ///
-    /// # Returns
-    /// Always returns false for Stellar (Pyth not available)
+    /// The oracle is healthy when the Pyth contract serves a fresh, confident
+    /// price for the first active configured feed (BTC/USD if none is configured).
     fn is_healthy(&self, env: &Env) -> Result<bool, Error> {
-        // Log the health check for debugging
-        env.events().publish(
-            (Symbol::new(env, "pyth_health_check"),),
-            (self.contract_id.clone(), env.ledger().timestamp()),
-        );
-
-        // Pyth Network is not available on Stellar
-        // In a real implementation, this would check:
-        // - Oracle contract responsiveness
-        // - Latest price timestamp freshness
-        // - Feed availability
-        // - Network connectivity
-        Ok(false)
+        let feed_id = self
+            .feed_configurations
+            .iter()
+            .find(|config| config.is_active)
+            .map(|config| config.feed_id)
+            .unwrap_or_else(|| String::from_str(env, PYTH_BTC_USD_FEED_ID));
+        Ok(self.get_price(env, &feed_id).is_ok())
     }
 }
 
@@ -886,10 +943,10 @@ impl OracleInterface for ReflectorOracle {
 /// **Stellar Network Compatible:**
 /// - **Reflector**: Primary and recommended oracle provider for Stellar
 /// - **Production Ready**: Fully functional with live price feeds
+/// - **Band Protocol**: Reads the `std_reference` contract deployed on Stellar
+/// - **Pyth Network**: Reads the Pyth price feed contract with confidence checks
 ///
 /// **Not Supported on Stellar:**
-/// - **Pyth Network**: Not available on Stellar blockchain
-/// - **Band Protocol**: Not integrated with Stellar ecosystem
 /// - **DIA**: Not available for Stellar Network
 ///
 /// # Design Philosophy
@@ -983,10 +1040,7 @@ impl OracleInterface for ReflectorOracle {
 pub struct OracleFactory;
 
 impl OracleFactory {
-    /// Create a Pyth oracle instance (NOT SUPPORTED ON STELLAR)
-    ///
-    /// This will create a placeholder that returns errors for all operations
-    /// since Pyth Network does not support Stellar blockchain.
+    /// Create a Pyth oracle instance reading the Pyth price feed contract
     pub fn create_pyth_oracle(contract_id: Address) -> PythOracle {
         PythOracle::new(contract_id)
     }
@@ -1008,9 +1062,9 @@ impl OracleFactory {
     /// Result containing the oracle instance or error
     ///
     /// # Notes
-    /// - Pyth oracle will be created but will return errors when used on Stellar
     /// - Reflector oracle is the recommended choice for Stellar
-    /// - Other providers are not supported
+    /// - Pyth and Band Protocol oracles read their on-chain Stellar contracts
+    /// - DIA is not supported
     pub fn create_oracle(
         provider: OracleProvider,
         contract_id: Address,
@@ -1025,6 +1079,14 @@ impl OracleFactory {
                 let oracle = ReflectorOracle::new(contract_id);
                 Ok(OracleInstance::Reflector(oracle))
             }
+            OracleProvider::Pyth => {
+                let oracle = PythOracle::new(contract_id);
+                Ok(OracleInstance::Pyth(oracle))
+            }
+            OracleProvider::BandProtocol => {
+                let oracle = BandProtocolOracle::new(contract_id);
+                Ok(OracleInstance::Band(oracle))
+            }
             _ => {
                 // All other providers should be caught by is_provider_supported check above
                 Err(Error::InvalidOracleConfig)
@@ -1044,8 +1106,8 @@ impl OracleFactory {
 
     pub fn is_provider_supported(provider: &OracleProvider) -> bool {
         match provider {
-            OracleProvider::Reflector => true,
-            OracleProvider::Pyth | OracleProvider::BandProtocol | OracleProvider::DIA => false,
+            OracleProvider::Reflector | OracleProvider::Pyth | OracleProvider::BandProtocol => true,
+            OracleProvider::DIA => false,
         }
     }
 
@@ -1062,9 +1124,6 @@ impl OracleFactory {
     ///
     /// # Returns
     /// A new PythOracle instance with configured feeds
-    ///
-    /// # Notes
-    /// This oracle will return errors when used on Stellar since Pyth is not available
     pub fn create_pyth_oracle_with_feeds(
         contract_id: Address,
         feed_configs: Vec<PythFeedConfig>,
@@ -1084,7 +1143,7 @@ impl OracleFactory {
     /// Result containing the primary oracle instance
     ///
     /// # Notes
-    /// On Stellar, Reflector should be the primary and Pyth should be avoided
+    /// On Stellar, Reflector is the recommended primary oracle
     pub fn create_hybrid_oracle(
         primary_provider: OracleProvider,
         primary_contract: Address,
@@ -1149,12 +1208,15 @@ impl OracleFactory {
                 Ok(())
             }
             OracleProvider::Pyth => {
-                // Pyth is not supported on Stellar, but we'll allow it for future compatibility
-                // The implementation will return errors when used
+                // Pyth serves its price feeds through a Stellar contract
+                Ok(())
+            }
+            OracleProvider::BandProtocol => {
+                // Band's std_reference contract is deployed on Stellar
                 Ok(())
             }
-            OracleProvider::BandProtocol | OracleProvider::DIA => {
-                // These providers are not supported on Stellar
+            OracleProvider::DIA => {
+                // DIA is not supported on Stellar
                 Err(Error::InvalidOracleConfig)
             }
         }
@@ -1173,9 +1235,8 @@ impl OracleFactory {
 ///
 /// **Production Ready:**
 /// - **Reflector**: Primary oracle provider for Stellar Network with full functionality
-///
-/// **Future/Placeholder:**
-/// - **Pyth**: Placeholder implementation for future Stellar support
+/// - **Band**: Band Protocol `std_reference` contract with rates normalized to cents
+/// - **Pyth**: Pyth price feed contract with staleness and confidence checks
 ///
 /// # Design Benefits
 ///
@@ -1228,7 +1289,7 @@ impl OracleFactory {
 ///                 // Reflector-specific operations if needed
 ///             },
 ///             OracleInstance::Pyth(ref pyth) => {
-///                 println!("Using Pyth oracle (placeholder)");
+///                 println!("Using Pyth oracle");
 ///                 // Pyth-specific operations if needed
 ///             },
 ///         }
@@ -1277,7 +1338,7 @@ impl OracleFactory {
 /// - **Compile-Time Optimization**: Rust compiler optimizes enum dispatch
 #[derive(Debug)]
 pub enum OracleInstance {
-    Pyth(PythOracle),           // Placeholder - not supported on Stellar
+    Pyth(PythOracle),           // Pyth price feed contract
     Reflector(ReflectorOracle), // Primary oracle for Stellar
     Band(BandProtocolOracle),   //  Band Protocole oracle
 }
@@ -1487,8 +1548,446 @@ impl OracleUtils {
     }
 }
 
+// ===== CROSS-RATE ORACLE =====
+
+/// Fixed-point decimals used for cross-rate ratios (1.0 == 100_000_000)
+pub const CROSS_RATE_DECIMALS: u32 = 8;
+
+/// Storage keys for cross-rate conditions
+#[derive(Clone)]
+#[contracttype]
+pub enum CrossRateKey {
+    /// Cross-rate condition configured for a market
+    Condition(Symbol),
+}
+
+/// Cross-rate support for conditions that compare two oracle feeds.
+///
+/// A market with a [`CrossRateCondition`] is resolved against the ratio
+/// `base_price / quote_price` instead of a single feed price. Both feeds are read
+/// from the same oracle instance, each price is normalized by its own decimals and
+/// the ratio is expressed with [`CROSS_RATE_DECIMALS`] decimals so it can be
+/// compared with the condition threshold using the standard comparison operators.
+///
+/// # Example
+///
+/// ```rust
+/// # use predictify_hybrid::oracles::CrossRateOracle;
+/// // ETH = $2,000.00 and BTC = $26,000.00, both quoted with 2 decimals
+/// let ratio = CrossRateOracle::compute_ratio(200_000, 2, 2_600_000, 2).unwrap();
+/// assert_eq!(ratio, 7_692_307); // 0.07692307
+/// ```
+pub struct CrossRateOracle;
+
+impl CrossRateOracle {
+    /// Store the cross-rate condition for a market after validating it
+    pub fn set_condition(
+        env: &Env,
+        market_id: &Symbol,
+        condition: &CrossRateCondition,
+    ) -> Result<(), Error> {
+        condition.validate(env)?;
+        env.storage()
+            .persistent()
+            .set(&CrossRateKey::Condition(market_id.clone()), condition);
+        Ok(())
+    }
+
+    /// Get the cross-rate condition for a market, if any
+    pub fn get_condition(env: &Env, market_id: &Symbol) -> Option<CrossRateCondition> {
+        env.storage()
+            .persistent()
+            .get(&CrossRateKey::Condition(market_id.clone()))
+    }
+
+    /// Remove the cross-rate condition for a market
+    pub fn remove_condition(env: &Env, market_id: &Symbol) {
+        env.storage()
+            .persistent()
+            .remove(&CrossRateKey::Condition(market_id.clone()));
+    }
+
+    /// Compute `base / quote` with normalized decimals.
+    ///
+    /// Only the decimal difference between the two feeds is applied so that
+    /// high-precision feeds do not overflow intermediate products.
+    pub fn compute_ratio(
+        base_price: i128,
+        base_decimals: u32,
+        quote_price: i128,
+        quote_decimals: u32,
+    ) -> Result<i128, Error> {
+        if base_price <= 0 || quote_price <= 0 {
+            return Err(Error::OracleUnavailable);
+        }
+
+        let (numerator_scale, denominator_scale) = if quote_decimals >= base_decimals {
+            (CROSS_RATE_DECIMALS + (quote_decimals - base_decimals), 0)
+        } else {
+            (CROSS_RATE_DECIMALS, base_decimals - quote_decimals)
+        };
+
+        let numerator = base_price
+            .checked_mul(Self::pow10(numerator_scale)?)
+            .ok_or(Error::InvalidInput)?;
+        let denominator = quote_price
+            .checked_mul(Self::pow10(denominator_scale)?)
+            .ok_or(Error::InvalidInput)?;
+
+        Ok(numerator / denominator)
+    }
+
+    /// Fetch both feeds from an oracle and compute the cross-rate
+    pub fn fetch_cross_rate(
+        env: &Env,
+        oracle: &OracleInstance,
+        condition: &CrossRateCondition,
+    ) -> Result<i128, Error> {
+        let base_price = oracle.get_price(env, &condition.base_feed_id)?;
+        let quote_price = oracle.get_price(env, &condition.quote_feed_id)?;
+
+        Self::compute_ratio(
+            base_price,
+            condition.base_decimals,
+            quote_price,
+            condition.quote_decimals,
+        )
+    }
+
+    /// Fetch the cross-rate and determine the outcome against the condition threshold
+    pub fn evaluate(
+        env: &Env,
+        oracle: &OracleInstance,
+        condition: &CrossRateCondition,
+    ) -> Result<(i128, String), Error> {
+        let ratio = Self::fetch_cross_rate(env, oracle, condition)?;
+        let outcome =
+            OracleUtils::determine_outcome(ratio, condition.threshold, &condition.comparison, env)?;
+        Ok((ratio, outcome))
+    }
+
+    fn pow10(exponent: u32) -> Result<i128, Error> {
+        10i128.checked_pow(exponent).ok_or(Error::InvalidInput)
+    }
+}
+
+// ===== MULTI-ORACLE AGGREGATION =====
+
+/// Storage keys for multi-oracle aggregation
+#[derive(Clone)]
+#[contracttype]
+pub enum MultiOracleKey {
+    /// Aggregated feeds configured for a market
+    Config(Symbol),
+}
+
+/// Resolves markets against the median or mean of several oracle feeds.
+///
+/// See [`MultiOracleConfig`] for how sources are queried and outliers rejected.
+///
+/// # Example
+///
+/// ```rust
+/// # use soroban_sdk::{vec, Env};
+/// # use predictify_hybrid::oracles::MultiOracleAggregator;
+/// # use predictify_hybrid::types::AggregationMethod;
+/// # let env = Env::default();
+/// // 130 is more than 10% away from the median of 100 and is dropped
+/// let prices = MultiOracleAggregator::reject_outliers(&vec![&env, 100, 101, 130], 1_000);
+/// assert_eq!(prices, vec![&env, 100, 101]);
+/// assert_eq!(MultiOracleAggregator::aggregate(&prices, &AggregationMethod::Mean), Some(100));
+/// ```
+pub struct MultiOracleAggregator;
+
+impl MultiOracleAggregator {
+    /// Store the aggregated feeds for a market after validating them
+    pub fn set_config(
+        env: &Env,
+        market_id: &Symbol,
+        config: &MultiOracleConfig,
+    ) -> Result<(), Error> {
+        config.validate()?;
+        env.storage()
+            .persistent()
+            .set(&MultiOracleKey::Config(market_id.clone()), config);
+        Ok(())
+    }
+
+    /// Get the aggregated feeds for a market, if any
+    pub fn get_config(env: &Env, market_id: &Symbol) -> Option<MultiOracleConfig> {
+        env.storage()
+            .persistent()
+            .get(&MultiOracleKey::Config(market_id.clone()))
+    }
+
+    /// Combine prices with the given method; `None` when there are none
+    pub fn aggregate(prices: &Vec<i128>, method: &AggregationMethod) -> Option<i128> {
+        if prices.is_empty() {
+            return None;
+        }
+        match method {
+            AggregationMethod::Median => Some(Self::median(prices)),
+            AggregationMethod::Mean => {
+                let sum = prices.iter().fold(0i128, |acc, p| acc.saturating_add(p));
+                Some(sum / prices.len() as i128)
+            }
+        }
+    }
+
+    /// Keep the prices within `max_deviation_bps` of the median of all prices
+    pub fn reject_outliers(prices: &Vec<i128>, max_deviation_bps: u32) -> Vec<i128> {
+        let mut kept = Vec::new(prices.env());
+        if prices.is_empty() {
+            return kept;
+        }
+        let median = Self::median(prices);
+        for price in prices.iter() {
+            let deviation = (price - median).abs().saturating_mul(10_000);
+            if deviation <= median.abs().saturating_mul(max_deviation_bps as i128) {
+                kept.push_back(price);
+            }
+        }
+        kept
+    }
+
+    /// Query every source and return the aggregated price.
+    ///
+    /// Blocked or failing sources are skipped. Fails with
+    /// `Error::OracleNoConsensus` when fewer than `min_sources` prices are
+    /// left after outlier rejection.
+    pub fn fetch_aggregated_price(env: &Env, config: &MultiOracleConfig) -> Result<i128, Error> {
+        let mut prices = Vec::new(env);
+        for source in config.sources.iter() {
+            if OracleBlocklist::is_blocked(env, &source.oracle_address) {
+                continue;
+            }
+            let price = OracleFactory::create_oracle(source.provider, source.oracle_address)
+                .and_then(|oracle| oracle.get_price(env, &source.feed_id));
+            if let Ok(price) = price {
+                prices.push_back(price);
+            }
+        }
+
+        let kept = Self::reject_outliers(&prices, config.max_deviation_bps);
+        if kept.len() < config.min_sources {
+            return Err(Error::OracleNoConsensus);
+        }
+        Self::aggregate(&kept, &config.method).ok_or(Error::OracleNoConsensus)
+    }
+
+    /// Fetch the aggregated price and determine the outcome against a threshold
+    pub fn evaluate(
+        env: &Env,
+        config: &MultiOracleConfig,
+        threshold: i128,
+        comparison: &String,
+    ) -> Result<(i128, String), Error> {
+        let price = Self::fetch_aggregated_price(env, config)?;
+        let outcome = OracleUtils::determine_outcome(price, threshold, comparison, env)?;
+        Ok((price, outcome))
+    }
+
+    fn median(prices: &Vec<i128>) -> i128 {
+        let mut sorted = Vec::new(prices.env());
+        for price in prices.iter() {
+            let position = sorted
+                .iter()
+                .position(|p| p > price)
+                .unwrap_or(sorted.len() as usize);
+            sorted.insert(position as u32, price);
+        }
+        let len = sorted.len();
+        let mid = sorted.get_unchecked(len / 2);
+        if len % 2 == 0 {
+            let low = sorted.get_unchecked(len / 2 - 1);
+            low + (mid - low) / 2
+        } else {
+            mid
+        }
+    }
+}
+
+// ===== PUSH ORACLE =====
+
+/// Reporting rules for a market resolved by pushed reports
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PushOracleConfig {
+    /// Number of matching reports needed to accept a result
+    pub quorum: u32,
+    /// Seconds after the market's end time during which reports are accepted
+    pub reporting_window_seconds: u64,
+}
+
+/// Push-style oracle for data without an on-chain feed (sports scores,
+/// election results, ...).
+///
+/// Admin-whitelisted reporters push the outcome they observed for a market
+/// between its end time and the end of the reporting window. Each reporter
+/// reports once; as soon as `quorum` reports agree on an outcome the market
+/// is resolved to it. Markets that miss the quorum within the window are left
+/// for manual resolution. Push markets are never resolved from a price feed.
+pub struct PushOracle;
+
+impl PushOracle {
+    fn reporters_key() -> Symbol {
+        symbol_short!("reporters")
+    }
+
+    fn config_key(market_id: &Symbol) -> (Symbol, Symbol) {
+        (symbol_short!("push_cfg"), market_id.clone())
+    }
+
+    fn reports_key(market_id: &Symbol) -> (Symbol, Symbol) {
+        (symbol_short!("push_rpts"), market_id.clone())
+    }
+
+    /// Whitelisted reporters
+    pub fn get_reporters(env: &Env) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&Self::reporters_key())
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    pub fn is_reporter(env: &Env, reporter: &Address) -> bool {
+        Self::get_reporters(env).contains(reporter)
+    }
+
+    /// Whitelist a reporter (at most `MAX_ORACLE_REPORTERS`)
+    pub fn add_reporter(env: &Env, reporter: &Address) -> Result<(), Error> {
+        let mut reporters = Self::get_reporters(env);
+        if reporters.contains(reporter) || reporters.len() >= crate::config::MAX_ORACLE_REPORTERS {
+            return Err(Error::InvalidInput);
+        }
+        reporters.push_back(reporter.clone());
+        env.storage()
+            .persistent()
+            .set(&Self::reporters_key(), &reporters);
+        Ok(())
+    }
+
+    /// Remove a reporter from the whitelist. Reports already pushed still count.
+    pub fn remove_reporter(env: &Env, reporter: &Address) -> Result<(), Error> {
+        let mut reporters = Self::get_reporters(env);
+        let index = reporters
+            .first_index_of(reporter)
+            .ok_or(Error::InvalidInput)?;
+        reporters.remove(index);
+        env.storage()
+            .persistent()
+            .set(&Self::reporters_key(), &reporters);
+        Ok(())
+    }
+
+    /// Reporting rules of a push market, if the market is one
+    pub fn get_config(env: &Env, market_id: &Symbol) -> Option<PushOracleConfig> {
+        env.storage().persistent().get(&Self::config_key(market_id))
+    }
+
+    /// Make a market resolve from pushed reports
+    pub fn set_config(
+        env: &Env,
+        market_id: &Symbol,
+        config: &PushOracleConfig,
+    ) -> Result<(), Error> {
+        if config.quorum == 0
+            || config.quorum > crate::config::MAX_ORACLE_REPORTERS
+            || config.reporting_window_seconds == 0
+        {
+            return Err(Error::InvalidInput);
+        }
+        env.storage()
+            .persistent()
+            .set(&Self::config_key(market_id), config);
+        Ok(())
+    }
+
+    /// Reports pushed for a market, by reporter
+    pub fn get_reports(env: &Env, market_id: &Symbol) -> Map<Address, String> {
+        env.storage()
+            .persistent()
+            .get(&Self::reports_key(market_id))
+            .unwrap_or_else(|| Map::new(env))
+    }
+
+    /// Record `reporter`'s result for a push market.
+    ///
+    /// Returns the accepted outcome once `quorum` reports agree on it, after
+    /// resolving the market.
+    pub fn push_result(
+        env: &Env,
+        reporter: &Address,
+        market_id: &Symbol,
+        result: &String,
+    ) -> Result<Option<String>, Error> {
+        if !Self::is_reporter(env, reporter) {
+            return Err(Error::Unauthorized);
+        }
+        let config = Self::get_config(env, market_id).ok_or(Error::InvalidOracleConfig)?;
+        let mut market = crate::markets::MarketStateManager::get_market(env, market_id)?;
+        if market.winning_outcomes.is_some() {
+            return Err(Error::MarketResolved);
+        }
+        if market.state == MarketState::Cancelled {
+            return Err(Error::MarketClosed);
+        }
+        if !market.outcomes.contains(result) {
+            return Err(Error::InvalidOutcome);
+        }
+
+        let now = env.ledger().timestamp();
+        if now < market.end_time {
+            return Err(Error::MarketNotReady);
+        }
+        if now
+            >= market
+                .end_time
+                .saturating_add(config.reporting_window_seconds)
+        {
+            return Err(Error::ResolutionTimeoutReached);
+        }
+
+        let mut reports = Self::get_reports(env, market_id);
+        if reports.contains_key(reporter.clone()) {
+            return Err(Error::AlreadyVoted);
+        }
+        reports.set(reporter.clone(), result.clone());
+        env.storage()
+            .persistent()
+            .set(&Self::reports_key(market_id), &reports);
+
+        let agreeing = reports.values().iter().filter(|r| r == result).count() as u32;
+        crate::events::EventEmitter::emit_oracle_result_pushed(
+            env, market_id, reporter, result, agreeing,
+        );
+        if agreeing < config.quorum {
+            return Ok(None);
+        }
+
+        crate::resolution::MarketResolutionManager::settle_outcome(
+            env,
+            market_id,
+            &mut market,
+            result,
+            "PushOracle",
+            "Reporter quorum reached",
+        );
+        Ok(Some(result.clone()))
+    }
+}
+
 // ===== BAND PROTOCOLE ORACLE CLIENT =====
 
+/// Decimals of the rates returned by Band's `get_reference_data` (1.0 == 10^18)
+pub const BAND_RATE_DECIMALS: u32 = 18;
+
+/// Decimals of normalized Band prices, matching the cents precision of Reflector feeds
+pub const BAND_PRICE_DECIMALS: u32 = 2;
+
+/// Maximum length of a Band feed id such as "BTC/USD"
+const MAX_BAND_FEED_ID_LENGTH: usize = 20;
+
 pub struct BandProtocolClient<'a> {
     env: &'a Env,
     contract_id: Address,
@@ -1505,17 +2004,27 @@ impl<'a> BandProtocolClient<'a> {
         Self { env, contract_id }
     }
 
-    pub fn get_price_of(&self, symbol_pair: (Symbol, Symbol)) -> u128 {
-        let client = bandprotocol::Client::new(&self.env, &self.contract_id);
-        client
-            .get_reference_data(&Vec::from_array(&self.env, [symbol_pair]))
-            .get_unchecked(0)
-            .rate
+    /// Fetch the raw `base/quote` rate (18 decimals) from the std_reference contract
+    pub fn get_price_of(&self, symbol_pair: (Symbol, Symbol)) -> Result<u128, Error> {
+        let client = bandprotocol::Client::new(self.env, &self.contract_id);
+        let data = match client.try_get_reference_data(&Vec::from_array(self.env, [symbol_pair])) {
+            Ok(Ok(data)) => data,
+            _ => return Err(Error::OracleUnavailable),
+        };
+
+        match data.get(0) {
+            Some(datum) => Ok(datum.rate),
+            None => Err(Error::OracleUnavailable),
+        }
     }
 }
 
 /// Band Protocol Oracle implementation
-
+///
+/// Reads prices from Band's `std_reference` contract deployed on Stellar. Feed
+/// ids use the `BASE/QUOTE` form (e.g. "BTC/USD"); a bare symbol such as "ETH"
+/// is quoted in USD. Rates are normalized from [`BAND_RATE_DECIMALS`] to
+/// [`BAND_PRICE_DECIMALS`] so thresholds use the same scale as Reflector markets.
 #[derive(Debug)]
 pub struct BandProtocolOracle {
     contract_id: Address,
@@ -1530,37 +2039,51 @@ impl BandProtocolOracle {
         self.contract_id.clone()
     }
 
+    /// Split a feed id into the `(base, quote)` symbol pair expected by Band
     pub fn parse_feed_id(&self, env: &Env, feed_id: &String) -> Result<(Symbol, Symbol), Error> {
-        if feed_id.is_empty() {
+        let len = feed_id.len() as usize;
+        if len == 0 || len > MAX_BAND_FEED_ID_LENGTH {
             return Err(Error::InvalidOracleConfig);
         }
 
-        if feed_id == &String::from_str(env, "BTC/USD") || feed_id == &String::from_str(env, "BTC")
-        {
-            Ok((Symbol::new(env, "BTC"), Symbol::new(env, "USD")))
-        } else if feed_id == &String::from_str(env, "ETH/USD")
-            || feed_id == &String::from_str(env, "ETH")
-        {
-            Ok((Symbol::new(env, "ETH"), Symbol::new(env, "USD")))
-        } else if feed_id == &String::from_str(env, "XLM/USD")
-            || feed_id == &String::from_str(env, "XLM")
-        {
-            Ok((Symbol::new(env, "XLM"), Symbol::new(env, "USD")))
-        } else if feed_id == &String::from_str(env, "USDC/USD")
-            || feed_id == &String::from_str(env, "USDC")
-        {
-            Ok((Symbol::new(env, "USDC"), Symbol::new(env, "USD")))
-        } else {
+        let mut buf = [0u8; MAX_BAND_FEED_ID_LENGTH];
+        feed_id.copy_into_slice(&mut buf[..len]);
+        let bytes = &buf[..len];
+
+        let (base, quote) = match bytes.iter().position(|b| *b == b'/') {
+            Some(idx) => (&bytes[..idx], &bytes[idx + 1..]),
+            None => (bytes, "USD".as_bytes()),
+        };
+
+        Ok((
+            Self::symbol_from_bytes(env, base)?,
+            Self::symbol_from_bytes(env, quote)?,
+        ))
+    }
+
+    fn symbol_from_bytes(env: &Env, bytes: &[u8]) -> Result<Symbol, Error> {
+        if bytes.is_empty() || !bytes.iter().all(|b| b.is_ascii_alphanumeric()) {
             return Err(Error::InvalidOracleConfig);
         }
+        let text = core::str::from_utf8(bytes).map_err(|_| Error::InvalidOracleConfig)?;
+        Ok(Symbol::new(env, text))
+    }
+
+    /// Convert an 18-decimal Band rate to a price with [`BAND_PRICE_DECIMALS`] decimals
+    pub fn normalize_rate(rate: u128) -> Result<i128, Error> {
+        let scale = 10u128.pow(BAND_RATE_DECIMALS - BAND_PRICE_DECIMALS);
+        let price = i128::try_from(rate / scale).map_err(|_| Error::InvalidState)?;
+        if price <= 0 {
+            return Err(Error::OracleUnavailable);
+        }
+        Ok(price)
     }
 
     /// Fetch price from Band client
     fn get_band_price(&self, env: &Env, feed_id: &String) -> Result<i128, Error> {
-        let pair = self.parse_feed_id(env, feed_id).unwrap();
+        let pair = self.parse_feed_id(env, feed_id)?;
         let client = BandProtocolClient::new(env, self.contract_id.clone());
-        let rate = client.get_price_of(pair);
-        Ok(rate as i128)
+        Self::normalize_rate(client.get_price_of(pair)?)
     }
 }
 
@@ -1591,7 +2114,23 @@ impl OracleInterface for BandProtocolOracle {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::testutils::{Address as _, Ledger};
+    use soroban_sdk::{contract, contractimpl};
+
+    /// Minimal stand-in for the Pyth price feed contract
+    #[contract]
+    struct MockPythContract;
+
+    #[contractimpl]
+    impl MockPythContract {
+        pub fn set_price(env: Env, feed_id: BytesN<32>, price: PythPrice) {
+            env.storage().instance().set(&feed_id, &price);
+        }
+
+        pub fn get_price_unsafe(env: Env, feed_id: BytesN<32>) -> PythPrice {
+            env.storage().instance().get(&feed_id).unwrap()
+        }
+    }
 
     #[test]
     fn test_pyth_oracle_creation() {
@@ -1603,6 +2142,67 @@ mod tests {
         assert_eq!(oracle.provider(), OracleProvider::Pyth);
     }
 
+    #[test]
+    fn test_pyth_oracle_checks_age_and_confidence() {
+        let env = Env::default();
+        env.ledger().set_timestamp(10_000);
+        let pyth_id = env.register(MockPythContract, ());
+        let pyth = MockPythContractClient::new(&env, &pyth_id);
+        let oracle = PythOracle::new(pyth_id);
+
+        let feed_id = String::from_str(&env, PYTH_BTC_USD_FEED_ID);
+        let feed = oracle.parse_feed_id(&env, &feed_id).unwrap();
+        assert_eq!(feed.to_array()[0], 0xe6);
+        assert_eq!(feed.to_array()[31], 0x43);
+        assert_eq!(
+            oracle.parse_feed_id(&env, &String::from_str(&env, "BTC/USD")),
+            Err(Error::InvalidOracleConfig)
+        );
+
+        // $26,000.12345678 quoted with expo -8, confidence $26
+        let mut price = PythPrice {
+            price: 2_600_012_345_678,
+            conf: 2_600_000_000,
+            expo: -8,
+            publish_time: 9_990,
+        };
+        pyth.set_price(&feed, &price);
+        assert_eq!(oracle.get_price(&env, &feed_id), Ok(2_600_012_345_678));
+        assert!(oracle.is_healthy(&env).unwrap());
+
+        // Prices are normalized to 8 decimals whatever the exponent
+        price.price = 2_600_012;
+        price.conf = 26;
+        price.expo = -2;
+        pyth.set_price(&feed, &price);
+        assert_eq!(oracle.get_price(&env, &feed_id), Ok(2_600_012_000_000));
+
+        // A confidence interval wider than 1% of the price is rejected
+        price.conf = 26_001;
+        pyth.set_price(&feed, &price);
+        assert_eq!(
+            oracle.get_price(&env, &feed_id),
+            Err(Error::OracleNoConsensus)
+        );
+
+        // So is a price older than the maximum price age
+        price.conf = 26;
+        price.publish_time = 10_000 - crate::config::MAX_ORACLE_PRICE_AGE - 1;
+        pyth.set_price(&feed, &price);
+        assert_eq!(oracle.get_price(&env, &feed_id), Err(Error::OracleStale));
+        assert!(!oracle.is_healthy(&env).unwrap());
+
+        // Feeds the contract does not serve are unavailable
+        let other = String::from_str(
+            &env,
+            "ff61491a931112ddf1bd8147cd1b641375f79f5825126d665480874634fd0ace",
+        );
+        assert_eq!(
+            oracle.get_price(&env, &other),
+            Err(Error::OracleUnavailable)
+        );
+    }
+
     #[test]
     fn test_reflector_oracle_creation() {
         let env = Env::default();
@@ -1618,19 +2218,27 @@ mod tests {
         let env = Env::default();
         let contract_id = Address::generate(&env);
 
-        // Test Pyth oracle creation (should fail)
+        // Test Pyth oracle creation
         let pyth_oracle = OracleFactory::create_oracle(OracleProvider::Pyth, contract_id.clone());
-        assert!(pyth_oracle.is_err());
-        assert_eq!(pyth_oracle.unwrap_err(), Error::InvalidOracleConfig);
+        assert!(pyth_oracle.is_ok());
+        assert_eq!(pyth_oracle.unwrap().provider(), OracleProvider::Pyth);
 
         // Test Reflector oracle creation
         let reflector_oracle =
             OracleFactory::create_oracle(OracleProvider::Reflector, contract_id.clone());
         assert!(reflector_oracle.is_ok());
 
+        // Test Band Protocol oracle creation
+        let band_oracle =
+            OracleFactory::create_oracle(OracleProvider::BandProtocol, contract_id.clone());
+        assert!(band_oracle.is_ok());
+        assert_eq!(
+            band_oracle.unwrap().provider(),
+            OracleProvider::BandProtocol
+        );
+
         // Test unsupported provider
-        let unsupported_oracle =
-            OracleFactory::create_oracle(OracleProvider::BandProtocol, contract_id);
+        let unsupported_oracle = OracleFactory::create_oracle(OracleProvider::DIA, contract_id);
         assert!(unsupported_oracle.is_err());
         assert_eq!(unsupported_oracle.unwrap_err(), Error::InvalidOracleConfig);
     }
@@ -1667,6 +2275,182 @@ mod tests {
         assert!(outcome.is_ok());
         assert_eq!(outcome.unwrap(), String::from_str(&env, "yes"));
     }
+
+    #[test]
+    fn test_cross_rate_ratio_normalizes_decimals() {
+        // Same decimals: 2,000.00 / 26,000.00 = 0.07692307
+        assert_eq!(
+            CrossRateOracle::compute_ratio(200_000, 2, 2_600_000, 2).unwrap(),
+            7_692_307
+        );
+
+        // Base with 7 decimals, quote with 2 decimals gives the same ratio
+        assert_eq!(
+            CrossRateOracle::compute_ratio(20_000_000_000, 7, 2_600_000, 2).unwrap(),
+            7_692_307
+        );
+
+        // Quote with more decimals than base
+        assert_eq!(
+            CrossRateOracle::compute_ratio(200_000, 2, 26_000_000_000, 6).unwrap(),
+            7_692_307
+        );
+
+        // Non-positive prices are rejected
+        assert_eq!(
+            CrossRateOracle::compute_ratio(0, 2, 2_600_000, 2),
+            Err(Error::OracleUnavailable)
+        );
+    }
+
+    #[test]
+    fn test_cross_rate_evaluate_with_reflector() {
+        let env = Env::default();
+        let oracle =
+            OracleFactory::create_oracle(OracleProvider::Reflector, Address::generate(&env))
+                .unwrap();
+
+        let mut condition = CrossRateCondition {
+            base_feed_id: String::from_str(&env, "ETH/USD"),
+            quote_feed_id: String::from_str(&env, "BTC/USD"),
+            base_decimals: 2,
+            quote_decimals: 2,
+            threshold: 6_000_000,
+            comparison: String::from_str(&env, "gt"),
+        };
+        assert!(condition.validate(&env).is_ok());
+
+        let (ratio, outcome) = CrossRateOracle::evaluate(&env, &oracle, &condition).unwrap();
+        assert_eq!(ratio, 7_692_307);
+        assert_eq!(outcome, String::from_str(&env, "yes"));
+
+        condition.comparison = String::from_str(&env, "lt");
+        let (_, outcome) = CrossRateOracle::evaluate(&env, &oracle, &condition).unwrap();
+        assert_eq!(outcome, String::from_str(&env, "no"));
+
+        condition.quote_feed_id = condition.base_feed_id.clone();
+        assert_eq!(condition.validate(&env), Err(Error::InvalidOracleConfig));
+    }
+
+    #[test]
+    fn test_band_oracle_reads_std_reference() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let band_id = env.register(bandprotocol::WASM, ());
+        let band = bandprotocol::Client::new(&env, &band_id);
+        let relayer = Address::generate(&env);
+        band.init(&relayer, &1000, &1000, &1000, &1000);
+        band.add_relayers(&vec![&env, relayer.clone()]);
+        // Band relays rates with 9 decimals: BTC = $26,000, ETH = $2,000
+        band.relay(
+            &relayer,
+            &vec![
+                &env,
+                (Symbol::new(&env, "BTC"), 26_000_000_000_000u64),
+                (Symbol::new(&env, "ETH"), 2_000_000_000_000u64),
+            ],
+            &env.ledger().timestamp(),
+            &1,
+        );
+
+        let oracle = BandProtocolOracle::new(band_id);
+        assert_eq!(
+            oracle.parse_feed_id(&env, &String::from_str(&env, "ETH")),
+            Ok((Symbol::new(&env, "ETH"), Symbol::new(&env, "USD")))
+        );
+        assert_eq!(
+            oracle.parse_feed_id(&env, &String::from_str(&env, "BTC/")),
+            Err(Error::InvalidOracleConfig)
+        );
+
+        // Rates are normalized from 18 decimals to cents
+        assert_eq!(
+            oracle.get_price(&env, &String::from_str(&env, "BTC/USD")),
+            Ok(2_600_000)
+        );
+        assert_eq!(
+            oracle.get_price(&env, &String::from_str(&env, "ETH/BTC")),
+            Ok(7)
+        );
+        assert!(oracle.is_healthy(&env).unwrap());
+
+        // Symbols that were never relayed surface as an unavailable oracle
+        assert_eq!(
+            oracle.get_price(&env, &String::from_str(&env, "DOGE")),
+            Err(Error::OracleUnavailable)
+        );
+    }
+
+    #[test]
+    fn test_multi_oracle_aggregation_rejects_outliers() {
+        let env = Env::default();
+
+        assert_eq!(
+            MultiOracleAggregator::aggregate(&vec![&env, 30, 10, 20], &AggregationMethod::Median),
+            Some(20)
+        );
+        assert_eq!(
+            MultiOracleAggregator::aggregate(
+                &vec![&env, 40, 10, 20, 30],
+                &AggregationMethod::Median
+            ),
+            Some(25)
+        );
+        assert_eq!(
+            MultiOracleAggregator::aggregate(&vec![&env, 10, 20, 60], &AggregationMethod::Mean),
+            Some(30)
+        );
+        assert_eq!(
+            MultiOracleAggregator::aggregate(&Vec::new(&env), &AggregationMethod::Mean),
+            None
+        );
+
+        // Reflector mock prices: BTC 2_600_000, ETH 200_000
+        let source = |feed: &str| OracleFeedSource {
+            provider: OracleProvider::Reflector,
+            oracle_address: Address::generate(&env),
+            feed_id: String::from_str(&env, feed),
+        };
+        let mut config = MultiOracleConfig {
+            sources: vec![&env, source("BTC"), source("BTC/USD"), source("ETH")],
+            method: AggregationMethod::Mean,
+            max_deviation_bps: 500,
+            min_sources: 2,
+        };
+        assert!(config.validate().is_ok());
+
+        // The ETH feed is an outlier and does not drag the mean down
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let (price, outcome) = env
+            .as_contract(&contract_id, || {
+                MultiOracleAggregator::evaluate(
+                    &env,
+                    &config,
+                    2_500_000,
+                    &String::from_str(&env, "gt"),
+                )
+            })
+            .unwrap();
+        assert_eq!(price, 2_600_000);
+        assert_eq!(outcome, String::from_str(&env, "yes"));
+
+        // Failing sources are skipped and can leave too few prices
+        config.sources = vec![&env, source("BTC"), source("ETH")];
+        config.sources.push_back(OracleFeedSource {
+            provider: OracleProvider::DIA,
+            ..source("BTC")
+        });
+        assert_eq!(
+            env.as_contract(&contract_id, || {
+                MultiOracleAggregator::fetch_aggregated_price(&env, &config)
+            }),
+            Err(Error::OracleNoConsensus)
+        );
+
+        config.min_sources = 4;
+        assert_eq!(config.validate(), Err(Error::InvalidOracleConfig));
+    }
 }
 
 // ===== ORACLE WHITELIST AND VALIDATION =====
@@ -2184,6 +2968,122 @@ impl OracleWhitelist {
     }
 }
 
+// ===== ORACLE BLOCKLIST =====
+
+/// Blocklist entry for a disabled oracle contract
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct BlockedOracle {
+    pub oracle_address: Address,
+    pub blocked_by: Address,
+    pub reason: String,
+    pub blocked_at: u64,
+}
+
+/// Kill-switch for compromised oracle contracts.
+///
+/// Members of the emergency council (admins holding
+/// `AdminPermission::EmergencyActions`) can block an oracle contract address
+/// with immediate effect. Resolution never queries a blocked oracle: markets
+/// pointing at it fall back to their fallback oracle, or are left for manual
+/// resolution when no usable oracle remains.
+pub struct OracleBlocklist;
+
+impl OracleBlocklist {
+    fn storage_key(env: &Env) -> Symbol {
+        Symbol::new(env, "oracle_blocklist")
+    }
+
+    fn load(env: &Env) -> soroban_sdk::Map<Address, BlockedOracle> {
+        env.storage()
+            .persistent()
+            .get(&Self::storage_key(env))
+            .unwrap_or(soroban_sdk::Map::new(env))
+    }
+
+    fn require_council(env: &Env, member: &Address) -> Result<(), Error> {
+        crate::admin::AdminAccessControl::validate_permission(
+            env,
+            member,
+            &crate::admin::AdminPermission::EmergencyActions,
+        )
+    }
+
+    /// Block an oracle contract address
+    pub fn block_oracle(
+        env: &Env,
+        council_member: &Address,
+        oracle_address: &Address,
+        reason: String,
+    ) -> Result<(), Error> {
+        Self::require_council(env, council_member)?;
+
+        let mut blocklist = Self::load(env);
+        if blocklist.contains_key(oracle_address.clone()) {
+            return Err(Error::InvalidState);
+        }
+        blocklist.set(
+            oracle_address.clone(),
+            BlockedOracle {
+                oracle_address: oracle_address.clone(),
+                blocked_by: council_member.clone(),
+                reason: reason.clone(),
+                blocked_at: env.ledger().timestamp(),
+            },
+        );
+        env.storage()
+            .persistent()
+            .set(&Self::storage_key(env), &blocklist);
+
+        crate::events::EventEmitter::emit_oracle_blocklist_updated(
+            env,
+            oracle_address,
+            council_member,
+            true,
+            &reason,
+        );
+        Ok(())
+    }
+
+    /// Remove an oracle contract address from the blocklist
+    pub fn unblock_oracle(
+        env: &Env,
+        council_member: &Address,
+        oracle_address: &Address,
+        reason: String,
+    ) -> Result<(), Error> {
+        Self::require_council(env, council_member)?;
+
+        let mut blocklist = Self::load(env);
+        if !blocklist.contains_key(oracle_address.clone()) {
+            return Err(Error::InvalidState);
+        }
+        blocklist.remove(oracle_address.clone());
+        env.storage()
+            .persistent()
+            .set(&Self::storage_key(env), &blocklist);
+
+        crate::events::EventEmitter::emit_oracle_blocklist_updated(
+            env,
+            oracle_address,
+            council_member,
+            false,
+            &reason,
+        );
+        Ok(())
+    }
+
+    /// Check whether an oracle contract address is blocked
+    pub fn is_blocked(env: &Env, oracle_address: &Address) -> bool {
+        Self::load(env).contains_key(oracle_address.clone())
+    }
+
+    /// Get all blocked oracle entries
+    pub fn get_blocked_oracles(env: &Env) -> Vec<BlockedOracle> {
+        Self::load(env).values()
+    }
+}
+
 // ===== ORACLE INTEGRATION MANAGER =====
 
 /// Storage keys for oracle integration
@@ -2350,6 +3250,11 @@ impl OracleIntegrationManager {
         use crate::events::EventEmitter;
 
         let oracle_config = &market.oracle_config;
+        let cross_rate = CrossRateOracle::get_condition(env, market_id);
+        let (threshold, comparison) = match &cross_rate {
+            Some(condition) => (condition.threshold, condition.comparison.clone()),
+            None => (oracle_config.threshold, oracle_config.comparison.clone()),
+        };
         let mut successful_results: Vec<(i128, String)> = Vec::new(env);
         let mut total_price: i128 = 0;
         let mut sources_count: u32 = 0;
@@ -2357,22 +3262,27 @@ impl OracleIntegrationManager {
 
         // Try each oracle source
         for oracle_address in oracle_sources.iter() {
-            match Self::fetch_single_oracle_result(
-                env,
-                &oracle_address,
-                &oracle_config.feed_id,
-                &oracle_config.provider,
-            ) {
+            let fetched = match &cross_rate {
+                Some(condition) => Self::fetch_single_cross_rate(
+                    env,
+                    &oracle_address,
+                    &oracle_config.provider,
+                    condition,
+                ),
+                None => Self::fetch_single_oracle_result(
+                    env,
+                    &oracle_address,
+                    &oracle_config.feed_id,
+                    &oracle_config.provider,
+                ),
+            };
+            match fetched {
                 Ok(price) => {
                     // Validate price is within acceptable range
-                    if Self::validate_price_range(price) {
+                    if cross_rate.is_some() || Self::validate_price_range(price) {
                         // Determine outcome for this source
-                        let outcome = OracleUtils::determine_outcome(
-                            price,
-                            oracle_config.threshold,
-                            &oracle_config.comparison,
-                            env,
-                        )?;
+                        let outcome =
+                            OracleUtils::determine_outcome(price, threshold, &comparison, env)?;
 
                         successful_results.push_back((price, outcome));
                         total_price += price;
@@ -2458,8 +3368,8 @@ impl OracleIntegrationManager {
             market_id: market_id.clone(),
             outcome: final_outcome,
             price: average_price,
-            threshold: oracle_config.threshold,
-            comparison: oracle_config.comparison.clone(),
+            threshold,
+            comparison,
             provider: oracle_config.provider.clone(),
             feed_id: oracle_config.feed_id.clone(),
             timestamp: env.ledger().timestamp(),
@@ -2502,6 +3412,28 @@ impl OracleIntegrationManager {
         Ok(price)
     }
 
+    /// Fetch a cross-rate from a single oracle source.
+    fn fetch_single_cross_rate(
+        env: &Env,
+        oracle_address: &Address,
+        provider: &crate::types::OracleProvider,
+        condition: &CrossRateCondition,
+    ) -> Result<i128, Error> {
+        // Validate oracle is whitelisted
+        if !OracleWhitelist::validate_oracle_contract(env, oracle_address)? {
+            return Err(Error::InvalidOracleConfig);
+        }
+
+        let oracle_instance =
+            OracleFactory::create_oracle(provider.clone(), oracle_address.clone())?;
+
+        if !oracle_instance.is_healthy(env).unwrap_or(false) {
+            return Err(Error::OracleUnavailable);
+        }
+
+        CrossRateOracle::fetch_cross_rate(env, &oracle_instance, condition)
+    }
+
     /// Determine consensus outcome from multiple oracle results.
     fn determine_consensus_outcome(
         env: &Env,
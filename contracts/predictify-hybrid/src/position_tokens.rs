@@ -0,0 +1,256 @@
+use soroban_sdk::{contractclient, symbol_short, token, Address, Env, String, Symbol};
+
+use crate::errors::Error;
+use crate::markets::MarketStateManager;
+use crate::queries::QueryManager;
+use crate::reentrancy_guard::ReentrancyGuard;
+use crate::storage::{VoteEntry, VoteLedger};
+use crate::types::{Market, MarketState};
+
+/// Admin side of a position token: the market contract mints shares when a
+/// stake is tokenized and burns them when the payout is credited.
+#[allow(dead_code)]
+#[contractclient(name = "PositionTokenAdminClient")]
+pub trait PositionTokenAdmin {
+    fn admin(env: Env) -> Address;
+    fn mint(env: Env, to: Address, amount: i128);
+    fn clawback(env: Env, from: Address, amount: i128);
+}
+
+// ===== POSITION TOKENS =====
+
+/// Tokenized positions: fungible, SEP-41 shares of a market outcome.
+///
+/// Each share stands for one unit of stake on its outcome. Tokenizing moves
+/// stake out of the voter's ledger entry into shares while the outcome's
+/// totals and consensus weight stay the same, so shares are paid exactly
+/// like stake: winning
+/// shares redeem for their pool share on `claim_winnings`, and every share
+/// redeems at face value through `claim_refund` if the market is cancelled.
+pub struct PositionTokenManager;
+
+impl PositionTokenManager {
+    fn token_key(market_id: &Symbol, outcome: &String) -> (Symbol, Symbol, String) {
+        (symbol_short!("pos_tok"), market_id.clone(), outcome.clone())
+    }
+
+    fn outstanding_key(market_id: &Symbol, outcome: &String) -> (Symbol, Symbol, String) {
+        (symbol_short!("pos_out"), market_id.clone(), outcome.clone())
+    }
+
+    /// Shares of `outcome` minted and not yet redeemed
+    pub fn outstanding_shares(env: &Env, market_id: &Symbol, outcome: &String) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&Self::outstanding_key(market_id, outcome))
+            .unwrap_or(0)
+    }
+
+    fn set_outstanding_shares(env: &Env, market_id: &Symbol, outcome: &String, shares: i128) {
+        env.storage()
+            .persistent()
+            .set(&Self::outstanding_key(market_id, outcome), &shares);
+    }
+
+    /// Token issued for `outcome` of a market, if one was attached
+    pub fn get_token(env: &Env, market_id: &Symbol, outcome: &String) -> Option<Address> {
+        env.storage()
+            .persistent()
+            .get(&Self::token_key(market_id, outcome))
+    }
+
+    /// Attach the share token of `outcome` (admin only).
+    ///
+    /// The token must name this contract as its admin. An outcome's token
+    /// cannot be replaced once attached.
+    pub fn set_token(
+        env: &Env,
+        admin: &Address,
+        market_id: &Symbol,
+        outcome: &String,
+        token: &Address,
+    ) -> Result<(), Error> {
+        crate::recovery::RecoveryManager::assert_is_admin(env, admin)?;
+        let market = MarketStateManager::get_market(env, market_id)?;
+        if !market.outcomes.contains(outcome) {
+            return Err(Error::InvalidOutcome);
+        }
+        if Self::get_token(env, market_id, outcome).is_some() {
+            return Err(Error::InvalidState);
+        }
+        if PositionTokenAdminClient::new(env, token).admin() != env.current_contract_address() {
+            return Err(Error::InvalidInput);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&Self::token_key(market_id, outcome), token);
+        Ok(())
+    }
+
+    /// Convert `amount` of `user`'s stake into shares of their outcome's
+    /// token. Returns the token address.
+    pub fn tokenize(
+        env: &Env,
+        user: &Address,
+        market_id: &Symbol,
+        amount: i128,
+    ) -> Result<Address, Error> {
+        if amount <= 0 {
+            return Err(Error::InvalidInput);
+        }
+        let mut market = MarketStateManager::get_market(env, market_id)?;
+        if market.state != MarketState::Active || market.has_ended(env) {
+            return Err(Error::MarketClosed);
+        }
+        MarketStateManager::require_direct_staking(env, market_id)?;
+        // Bets keep their own records and are not tokenized
+        if crate::bets::BetStorage::get_bet(env, market_id, user).is_some() {
+            return Err(Error::InvalidState);
+        }
+        let position = VoteLedger::get_entry(env, market_id, user)
+            .filter(|entry| entry.stake > 0)
+            .ok_or(Error::InvalidState)?;
+        if amount > position.stake {
+            return Err(Error::InsufficientStake);
+        }
+        let token =
+            Self::get_token(env, market_id, &position.outcome).ok_or(Error::InvalidState)?;
+
+        let remaining = position.stake - amount;
+        let weight = market.outcome_weights.get(position.outcome.clone());
+        VoteLedger::set_position(
+            env,
+            market_id,
+            &mut market,
+            user,
+            &position.outcome,
+            remaining,
+        );
+        // The shares still back the outcome, with the same quadratic weight
+        let outcome_total = market.outcome_total(&position.outcome);
+        market
+            .outcome_totals
+            .set(position.outcome.clone(), outcome_total + amount);
+        if remaining == 0 {
+            market.remove_outcome_position(&position.outcome, 0);
+        }
+        if let Some(weight) = weight {
+            market.outcome_weights.set(position.outcome.clone(), weight);
+        }
+        MarketStateManager::update_market(env, market_id, &market);
+
+        ReentrancyGuard::guarded(env, || {
+            PositionTokenAdminClient::new(env, &token).mint(user, &amount);
+            Ok(())
+        })?;
+        let outstanding = Self::outstanding_shares(env, market_id, &position.outcome);
+        Self::set_outstanding_shares(env, market_id, &position.outcome, outstanding + amount);
+        crate::events::EventEmitter::emit_position_tokenized(
+            env,
+            market_id,
+            user,
+            &position.outcome,
+            amount,
+            &token,
+        );
+        Ok(token)
+    }
+
+    /// Burn `holder`'s shares that are worth something now and return their
+    /// value: pool shares of winning outcomes once resolved, or face value
+    /// of every outcome once cancelled. The caller credits the amount.
+    pub fn redeem(
+        env: &Env,
+        market_id: &Symbol,
+        market: &Market,
+        holder: &Address,
+    ) -> Result<i128, Error> {
+        let cancelled = market.state == MarketState::Cancelled;
+        let outcomes = if cancelled {
+            market.outcomes.clone()
+        } else {
+            match &market.winning_outcomes {
+                Some(winners) => winners.clone(),
+                None => return Ok(0),
+            }
+        };
+
+        let mut total = 0i128;
+        for outcome in outcomes.iter() {
+            let Some(token) = Self::get_token(env, market_id, &outcome) else {
+                continue;
+            };
+            let shares = token::Client::new(env, &token).balance(holder);
+            if shares <= 0 {
+                continue;
+            }
+            let value = if cancelled {
+                shares
+            } else {
+                QueryManager::claimable_payout(
+                    market,
+                    &VoteEntry {
+                        outcome: outcome.clone(),
+                        stake: shares,
+                        claimed: false,
+                    },
+                )?
+            };
+            // Shares are only burned against a payout
+            if value <= 0 {
+                continue;
+            }
+            ReentrancyGuard::guarded(env, || {
+                PositionTokenAdminClient::new(env, &token).clawback(holder, &shares);
+                Ok(())
+            })?;
+            let outstanding = Self::outstanding_shares(env, market_id, &outcome);
+            Self::set_outstanding_shares(env, market_id, &outcome, outstanding - shares);
+            total = total.checked_add(value).ok_or(Error::InvalidInput)?;
+        }
+        Ok(total)
+    }
+
+    /// Winnings still held as unredeemed shares of a resolved market's
+    /// winning outcomes.
+    pub fn unredeemed_winnings(
+        env: &Env,
+        market_id: &Symbol,
+        market: &Market,
+    ) -> Result<i128, Error> {
+        let Some(winners) = &market.winning_outcomes else {
+            return Ok(0);
+        };
+        let mut total = 0i128;
+        for outcome in winners.iter() {
+            let shares = Self::outstanding_shares(env, market_id, &outcome);
+            if shares <= 0 {
+                continue;
+            }
+            let value = QueryManager::claimable_payout(
+                market,
+                &VoteEntry {
+                    outcome: outcome.clone(),
+                    stake: shares,
+                    claimed: false,
+                },
+            )?;
+            total = total.checked_add(value).ok_or(Error::InvalidInput)?;
+        }
+        Ok(total)
+    }
+
+    /// Write off the unredeemed winning shares of a market past its claim
+    /// deadline and return their value. The shares stay with their holders
+    /// but no longer redeem for anything.
+    pub fn sweep_unredeemed(env: &Env, market_id: &Symbol, market: &Market) -> Result<i128, Error> {
+        let total = Self::unredeemed_winnings(env, market_id, market)?;
+        if let Some(winners) = &market.winning_outcomes {
+            for outcome in winners.iter() {
+                Self::set_outstanding_shares(env, market_id, &outcome, 0);
+            }
+        }
+        Ok(total)
+    }
+}
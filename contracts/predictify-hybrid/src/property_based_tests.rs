@@ -303,12 +303,13 @@ proptest! {
         client.vote(user, &market_id, &chosen_outcome, &stake_amount);
 
         let market = client.get_market(&market_id).unwrap();
+        let entry = client.get_vote_entry(&market_id, user).unwrap();
 
         // Property: User vote should be recorded correctly
-        prop_assert_eq!(market.votes.get(user.clone()).unwrap(), chosen_outcome);
+        prop_assert_eq!(entry.outcome, chosen_outcome);
 
         // Property: User stake should be recorded correctly
-        prop_assert_eq!(market.stakes.get(user.clone()).unwrap(), stake_amount);
+        prop_assert_eq!(entry.stake, stake_amount);
 
         // Property: Total staked should equal user stake
         prop_assert_eq!(market.total_staked, stake_amount);
@@ -544,11 +545,11 @@ proptest! {
             prop_assert_eq!(market.total_staked, expected_total);
 
             // Invariant: Number of votes should not exceed number of users
-            let vote_count = market.votes.len();
+            let vote_count = market.voter_count;
             prop_assert!(vote_count <= users_len as u32);
 
             // Invariant: Each user should have at most one vote
-            prop_assert!(market.votes.get(user.clone()).is_some());
+            prop_assert!(client.get_vote_entry(&market_id, user).is_some());
 
             // Invariant: Market state should remain consistent
             prop_assert_eq!(market.state, MarketState::Active);
@@ -17,16 +17,19 @@
 //! 4. **Analytics Queries** - Get aggregated market analytics and performance metrics
 
 use crate::{
+    config::PERCENTAGE_DENOMINATOR,
     errors::Error,
     markets::{MarketAnalytics, MarketStateManager, MarketValidator},
+    storage::{VoteEntry, VoteLedger},
     types::{Market, MarketState},
     voting::VotingStats,
 };
-use soroban_sdk::{contracttype, vec, Address, Env, Map, String, Symbol, Vec};
+use soroban_sdk::{contracttype, vec, Address, Env, Map, String, Symbol, TryFromVal, Val, Vec};
 
 use crate::types::{
-    ContractStateQuery, EventDetailsQuery, MarketPoolQuery, MarketStatus, MultipleBetsQuery,
-    UserBalanceQuery, UserBetQuery,
+    ContractStateQuery, EventDetailsQuery, FeedExposure, MarketPoolQuery, MarketStatus,
+    MarketSummary, MultipleBetsQuery, NetExposureQuery, OutcomeOdds, UserBalanceQuery,
+    UserBetQuery, UserPosition,
 };
 
 // ===== QUERY MANAGER =====
@@ -83,10 +86,10 @@ impl QueryManager {
         let market = Self::get_market_from_storage(env, &market_id)?;
 
         // Calculate participant count
-        let participant_count = market.votes.len() as u32;
+        let participant_count = market.voter_count;
 
         // Calculate vote count (simple approximation)
-        let vote_count = market.votes.len() as u32;
+        let vote_count = market.voter_count;
 
         // Get oracle provider name
         let oracle_provider = market.oracle_config.provider.name();
@@ -134,6 +137,131 @@ impl QueryManager {
         ))
     }
 
+    /// Query a lightweight summary of a market.
+    ///
+    /// Unlike the full `Market`, the summary omits the per-user maps (votes,
+    /// stakes, claims and dispute stakes) and reports their sizes and totals
+    /// instead, so the response stays small however many users participate.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(MarketSummary)` - Scalar fields and aggregates of the market
+    /// * `Err(Error::MarketNotFound)` - Market not found
+    pub fn query_market_summary(env: &Env, market_id: Symbol) -> Result<MarketSummary, Error> {
+        let mut market = Self::get_market_from_storage(env, &market_id)?;
+
+        // Totals are only stored once the market resolves
+        if market.outcome_totals.is_empty() {
+            market.precompute_outcome_totals();
+        }
+        let total_dispute_stakes = market.total_dispute_stakes();
+
+        Ok(MarketSummary {
+            market_id,
+            admin: market.admin,
+            question: market.question,
+            outcomes: market.outcomes,
+            end_time: market.end_time,
+            bet_deadline: market.bet_deadline,
+            state: market.state,
+            tier: market.tier,
+            category: market.category,
+            oracle_provider: market.oracle_config.provider,
+            feed_id: market.oracle_config.feed_id,
+            has_fallback: market.has_fallback,
+            total_staked: market.total_staked,
+            outcome_totals: market.outcome_totals,
+            voter_count: market.voter_count,
+            claimed_count: market.claimed_count,
+            disputer_count: market.dispute_stakes.len(),
+            total_dispute_stakes,
+            oracle_result: market.oracle_result,
+            winning_outcomes: market.winning_outcomes,
+            winning_total: market.winning_total,
+            platform_fee_percentage: market.platform_fee_percentage,
+            fee_collected: market.fee_collected,
+            dispute_window_seconds: market.dispute_window_seconds,
+            total_extension_days: market.total_extension_days,
+            has_consensus_snapshot: market.has_consensus_snapshot,
+        })
+    }
+
+    /// Query one page of a market's voters as `(address, outcome, stake)` triples.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<(Address, String, i128)>)` - Up to `limit` voters starting at `offset`
+    /// * `Err(Error::MarketNotFound)` - Market not found
+    /// * `Err(Error::InvalidInput)` - `limit` is 0 or above `MAX_QUERY_PAGE_SIZE`
+    pub fn query_market_voters(
+        env: &Env,
+        market_id: Symbol,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<(Address, String, i128)>, Error> {
+        let market = Self::get_market_from_storage(env, &market_id)?;
+        let (skip, take) = Self::page_bounds(market.voter_count, offset, limit)?;
+
+        let mut page = Vec::new(env);
+        for index in skip..skip + take {
+            let Some(user) = VoteLedger::get_voter(env, &market_id, index as u32) else {
+                continue;
+            };
+            if let Some(entry) = VoteLedger::get_entry(env, &market_id, &user) {
+                page.push_back((user, entry.outcome, entry.stake));
+            }
+        }
+        Ok(page)
+    }
+
+    /// Query one page of the addresses that have claimed from a market.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Address>)` - Up to `limit` claimants starting at `offset`
+    /// * `Err(Error::MarketNotFound)` - Market not found
+    /// * `Err(Error::InvalidInput)` - `limit` is 0 or above `MAX_QUERY_PAGE_SIZE`
+    pub fn query_market_claims(
+        env: &Env,
+        market_id: Symbol,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<Address>, Error> {
+        let market = Self::get_market_from_storage(env, &market_id)?;
+        let (skip, take) = Self::page_bounds(market.claimed_count, offset, limit)?;
+
+        let mut page = Vec::new(env);
+        for index in skip..skip + take {
+            if let Some(user) = VoteLedger::get_claimant(env, &market_id, index as u32) {
+                page.push_back(user);
+            }
+        }
+        Ok(page)
+    }
+
+    /// Query one page of a market's dispute stakes as `(address, stake)` pairs.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<(Address, i128)>)` - Up to `limit` entries starting at `offset`
+    /// * `Err(Error::MarketNotFound)` - Market not found
+    /// * `Err(Error::InvalidInput)` - `limit` is 0 or above `MAX_QUERY_PAGE_SIZE`
+    pub fn query_market_dispute_stakes(
+        env: &Env,
+        market_id: Symbol,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<(Address, i128)>, Error> {
+        let market = Self::get_market_from_storage(env, &market_id)?;
+        let (skip, take) = Self::page_bounds(market.dispute_stakes.len(), offset, limit)?;
+
+        let mut page = Vec::new(env);
+        for entry in market.dispute_stakes.iter().skip(skip).take(take) {
+            page.push_back(entry);
+        }
+        Ok(page)
+    }
+
     /// Get list of all market IDs.
     ///
     /// Returns a vector of all market identifiers created in the contract.
@@ -205,11 +333,13 @@ impl QueryManager {
         let market = Self::get_market_from_storage(env, &market_id)?;
 
         // Check if user has participated
-        let outcome = market.votes.get(user.clone()).ok_or(Error::InvalidInput)?;
+        let entry = VoteLedger::get_entry(env, &market_id, &user).ok_or(Error::InvalidInput)?;
 
-        let stake_amount = market.stakes.get(user.clone()).ok_or(Error::InvalidInput)?;
+        let outcome = entry.outcome;
 
-        let has_claimed = market.claimed.get(user.clone()).unwrap_or(false);
+        let stake_amount = entry.stake;
+
+        let has_claimed = entry.claimed;
 
         // Determine if user is winning (supports single or multiple winning outcomes / ties)
         let is_winning = market
@@ -283,6 +413,78 @@ impl QueryManager {
         })
     }
 
+    /// Page through the markets a user has taken a position in, in the order
+    /// they joined them.
+    ///
+    /// `limit` must be between 1 and `MAX_QUERY_PAGE_SIZE`. Markets since
+    /// removed from storage are skipped, so a page may hold fewer than
+    /// `limit` positions.
+    pub fn query_user_positions(
+        env: &Env,
+        user: &Address,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<UserPosition>, Error> {
+        let (start, take) =
+            Self::page_bounds(VoteLedger::get_user_market_count(env, user), offset, limit)?;
+        let mut positions = Vec::new(env);
+        for index in start..start + take {
+            let Some(market_id) = VoteLedger::get_user_market(env, user, index as u32) else {
+                continue;
+            };
+            let Some(market) = env.storage().persistent().get::<Symbol, Market>(&market_id) else {
+                continue;
+            };
+            let Some(entry) = VoteLedger::get_entry(env, &market_id, user) else {
+                continue;
+            };
+            positions.push_back(UserPosition {
+                market_id,
+                claimable: Self::claimable_payout(&market, &entry)?,
+                outcome: entry.outcome,
+                stake: entry.stake,
+                claimed: entry.claimed,
+            });
+        }
+        Ok(positions)
+    }
+
+    /// Amount `claim_winnings` would credit for a position, after fees.
+    ///
+    /// Winning positions get their weighted share of the pool net of the
+    /// market's platform fee; losing positions get the market's loser
+    /// rebate, if any. Settled positions, unresolved markets, markets nobody
+    /// won and payouts held back for recovery as anomalous yield 0.
+    pub fn claimable_payout(market: &Market, entry: &VoteEntry) -> Result<i128, Error> {
+        let Some(winning_outcomes) = &market.winning_outcomes else {
+            return Ok(0);
+        };
+        if entry.claimed || market.get_winning_total() == 0 {
+            return Ok(0);
+        }
+        if !winning_outcomes.contains(&entry.outcome) {
+            return Ok(market.loser_rebate_for(entry.stake));
+        }
+
+        let winning_total = market.weighted_winning_total();
+        if winning_total <= 0 {
+            return Ok(0);
+        }
+        let weighted_stake = entry.stake * market.payout_weight(&entry.outcome);
+        let user_share = weighted_stake
+            .checked_mul(PERCENTAGE_DENOMINATOR - market.platform_fee_percentage)
+            .ok_or(Error::InvalidInput)?
+            / PERCENTAGE_DENOMINATOR;
+        let payout = user_share
+            .checked_mul(market.total_staked)
+            .ok_or(Error::InvalidInput)?
+            / winning_total;
+        if market.exceeds_max_payout(entry.stake, payout) {
+            return Ok(0);
+        }
+        Ok(payout)
+    }
+
     // ===== BALANCE AND POOL QUERIES =====
 
     /// Query user's account balance and participation metrics.
@@ -320,6 +522,110 @@ impl QueryManager {
         Ok(response)
     }
 
+    /// Query a user's net directional exposure per underlying oracle feed.
+    ///
+    /// Walks every unsettled market (active, ended or disputed) the user has a
+    /// stake in. A "yes" stake on a `gt`/`gte` market or a "no" stake on a
+    /// `lt`/`lte` market is long the feed; the opposite pairings are short.
+    /// Stakes on `eq` markets or non yes/no outcomes have no direction and are
+    /// reported separately.
+    ///
+    /// # Parameters
+    ///
+    /// * `env` - Soroban environment
+    /// * `user` - User address to query
+    ///
+    /// # Returns
+    ///
+    /// * `NetExposureQuery` - Exposure per feed with gross and net totals
+    pub fn query_net_exposure(env: &Env, user: Address) -> NetExposureQuery {
+        let mut by_feed: Map<String, FeedExposure> = Map::new(env);
+        let mut non_directional_stake = 0i128;
+
+        for market_id in crate::market_id_generator::MarketIdGenerator::get_all_market_ids(env) {
+            // Events share the ID space with markets, so decode leniently
+            let Some(raw) = env.storage().persistent().get::<_, Val>(&market_id) else {
+                continue;
+            };
+            let Ok(market) = Market::try_from_val(env, &raw) else {
+                continue;
+            };
+            if !matches!(
+                market.state,
+                MarketState::Active | MarketState::Ended | MarketState::Disputed
+            ) {
+                continue;
+            }
+            let Some(entry) = VoteLedger::get_entry(env, &market_id, &user) else {
+                continue;
+            };
+            let (outcome, stake) = (entry.outcome, entry.stake);
+
+            let Some(is_long) = Self::position_direction(env, &market, &outcome) else {
+                non_directional_stake += stake;
+                continue;
+            };
+
+            let feed_id = market.oracle_config.feed_id.clone();
+            let mut exposure = by_feed.get(feed_id.clone()).unwrap_or(FeedExposure {
+                feed_id: feed_id.clone(),
+                long_stake: 0,
+                short_stake: 0,
+                net_exposure: 0,
+                market_count: 0,
+            });
+            if is_long {
+                exposure.long_stake += stake;
+            } else {
+                exposure.short_stake += stake;
+            }
+            exposure.net_exposure = exposure.long_stake - exposure.short_stake;
+            exposure.market_count += 1;
+            by_feed.set(feed_id, exposure);
+        }
+
+        let mut gross_exposure = 0i128;
+        let mut net_exposure = 0i128;
+        for exposure in by_feed.values().iter() {
+            gross_exposure += exposure.long_stake + exposure.short_stake;
+            net_exposure += exposure.net_exposure;
+        }
+
+        NetExposureQuery {
+            user,
+            feeds: by_feed.values(),
+            gross_exposure,
+            net_exposure,
+            non_directional_stake,
+        }
+    }
+
+    /// Whether backing `outcome` on `market` is long (`Some(true)`) or short
+    /// (`Some(false)`) the market's feed, or has no direction (`None`).
+    fn position_direction(env: &Env, market: &Market, outcome: &String) -> Option<bool> {
+        let comparison = &market.oracle_config.comparison;
+        let feed_above = if *comparison == String::from_str(env, "gt")
+            || *comparison == String::from_str(env, "gte")
+        {
+            true
+        } else if *comparison == String::from_str(env, "lt")
+            || *comparison == String::from_str(env, "lte")
+        {
+            false
+        } else {
+            return None;
+        };
+
+        if *outcome == String::from_str(env, "yes") || *outcome == String::from_str(env, "Yes") {
+            Some(feed_above)
+        } else if *outcome == String::from_str(env, "no") || *outcome == String::from_str(env, "No")
+        {
+            Some(!feed_above)
+        } else {
+            None
+        }
+    }
+
     /// Query market pool distribution and implied probabilities.
     ///
     /// Provides detailed stake distribution across outcomes and calculates
@@ -444,6 +750,19 @@ impl QueryManager {
             .ok_or(Error::MarketNotFound)
     }
 
+    /// Resolve `offset`/`limit` against a collection of `len` entries.
+    ///
+    /// Returns the number of entries to skip and to take; an offset past
+    /// the end yields an empty page.
+    pub(crate) fn page_bounds(len: u32, offset: u32, limit: u32) -> Result<(usize, usize), Error> {
+        if limit == 0 || limit > crate::config::MAX_QUERY_PAGE_SIZE {
+            return Err(Error::InvalidInput);
+        }
+        let start = offset.min(len);
+        let end = start.saturating_add(limit).min(len);
+        Ok((start as usize, (end - start) as usize))
+    }
+
     /// Calculate payout for a user based on stake and market outcome.
     ///
     /// Computes the user's payout considering:
@@ -485,24 +804,47 @@ impl QueryManager {
 
     /// Calculate total stake for a specific outcome.
     ///
-    /// Sums all user stakes that voted for the given outcome.
+    /// Reads the market's live per-outcome total.
     pub(crate) fn calculate_outcome_pool(
-        env: &Env,
+        _env: &Env,
         market: &Market,
         outcome: &String,
     ) -> Result<i128, Error> {
-        let mut pool = 0i128;
+        Ok(market.outcome_total(outcome))
+    }
 
-        // Iterate through all votes to find matching outcome
-        for (user, voted_outcome) in market.votes.iter() {
-            if voted_outcome == *outcome {
-                if let Some(stake) = market.stakes.get(user) {
-                    pool += stake;
-                }
-            }
-        }
+    /// Implied probability and payout multiplier of every outcome, in
+    /// market order.
+    ///
+    /// Multipliers are net of the market's platform fee, matching what a
+    /// claim would pay if the pool stopped changing.
+    pub fn query_market_odds(env: &Env, market_id: &Symbol) -> Result<Vec<OutcomeOdds>, Error> {
+        let market = MarketStateManager::get_market(env, market_id)?;
+        let net_pool = market
+            .total_staked
+            .checked_mul(PERCENTAGE_DENOMINATOR - market.platform_fee_percentage)
+            .ok_or(Error::InvalidInput)?
+            / PERCENTAGE_DENOMINATOR;
 
-        Ok(pool)
+        let mut odds = Vec::new(env);
+        for outcome in market.outcomes.iter() {
+            let total_staked = market.outcome_total(&outcome);
+            let (implied_probability_bps, payout_multiplier_bps) = if total_staked > 0 {
+                (
+                    total_staked * 10_000 / market.total_staked,
+                    net_pool.checked_mul(10_000).ok_or(Error::InvalidInput)? / total_staked,
+                )
+            } else {
+                (0, 0)
+            };
+            odds.push_back(OutcomeOdds {
+                outcome,
+                total_staked,
+                implied_probability_bps,
+                payout_multiplier_bps,
+            });
+        }
+        Ok(odds)
     }
 
     /// Calculate implied probabilities for binary outcomes.
@@ -655,11 +997,12 @@ mod tests {
 
         // Add votes
         let yes_outcome = String::from_str(&env, "yes");
-        market.votes.set(user1.clone(), yes_outcome.clone());
-        market.stakes.set(user1, 50);
-
-        market.votes.set(user2.clone(), yes_outcome.clone());
-        market.stakes.set(user2, 75);
+        let market_id = Symbol::new(&env, "test_market");
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        env.as_contract(&contract_id, || {
+            market.add_vote(&env, &market_id, user1, yes_outcome.clone(), 50);
+            market.add_vote(&env, &market_id, user2, yes_outcome.clone(), 75);
+        });
 
         let pool = QueryManager::calculate_outcome_pool(&env, &market, &yes_outcome);
         assert!(pool.is_ok());
@@ -12,6 +12,15 @@ use soroban_sdk::{vec as svec, Address, Env, String, Symbol};
 
 const TEST_ORACLE_ADDRESS: &str = "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF";
 
+/// Record a vote on a standalone market through the vote ledger
+fn record_vote(env: &Env, market: &mut Market, user: &Address, outcome: &String, stake: i128) {
+    let contract_id = env.register(crate::PredictifyHybrid, ());
+    let market_id = Symbol::new(env, "test_market");
+    env.as_contract(&contract_id, || {
+        crate::storage::VoteLedger::set_position(env, &market_id, market, user, outcome, stake);
+    });
+}
+
 // ===== UNIT TESTS =====
 
 #[test]
@@ -253,8 +262,7 @@ fn test_outcome_pool_with_single_vote() {
     let yes_outcome = String::from_str(&env, "yes");
     let stake = 5_000_000i128;
 
-    market.votes.set(user.clone(), yes_outcome.clone());
-    market.stakes.set(user, stake);
+    record_vote(&env, &mut market, &user, &yes_outcome, stake);
 
     let pool = QueryManager::calculate_outcome_pool(&env, &market, &yes_outcome);
     assert!(pool.is_ok(), "Outcome pool calculation failed");
@@ -295,15 +303,12 @@ fn test_outcome_pool_with_multiple_votes() {
     let no_outcome = String::from_str(&env, "no");
 
     // Add multiple votes for YES
-    market.votes.set(user1.clone(), yes_outcome.clone());
-    market.stakes.set(user1, 3_000_000i128);
+    record_vote(&env, &mut market, &user1, &yes_outcome, 3_000_000i128);
 
-    market.votes.set(user2.clone(), yes_outcome.clone());
-    market.stakes.set(user2, 2_000_000i128);
+    record_vote(&env, &mut market, &user2, &yes_outcome, 2_000_000i128);
 
     // Add vote for NO
-    market.votes.set(user3.clone(), no_outcome.clone());
-    market.stakes.set(user3, 5_000_000i128);
+    record_vote(&env, &mut market, &user3, &no_outcome, 5_000_000i128);
 
     let yes_pool = QueryManager::calculate_outcome_pool(&env, &market, &yes_outcome);
     let no_pool = QueryManager::calculate_outcome_pool(&env, &market, &no_outcome);
@@ -454,10 +459,8 @@ fn test_pool_calculation_commutative() {
     let outcome = String::from_str(&env, "yes");
 
     // Add votes in order 1, 2
-    market1.votes.set(user1.clone(), outcome.clone());
-    market1.stakes.set(user1.clone(), 3_000_000i128);
-    market1.votes.set(user2.clone(), outcome.clone());
-    market1.stakes.set(user2.clone(), 2_000_000i128);
+    record_vote(&env, &mut market1, &user1, &outcome, 3_000_000i128);
+    record_vote(&env, &mut market1, &user2, &outcome, 2_000_000i128);
 
     let pool1 = QueryManager::calculate_outcome_pool(&env, &market1, &outcome).unwrap();
 
@@ -485,10 +488,8 @@ fn test_pool_calculation_commutative() {
     );
 
     // Add votes in reverse order 2, 1
-    market2.votes.set(user2.clone(), outcome.clone());
-    market2.stakes.set(user2.clone(), 2_000_000i128);
-    market2.votes.set(user1.clone(), outcome.clone());
-    market2.stakes.set(user1.clone(), 3_000_000i128);
+    record_vote(&env, &mut market2, &user2, &outcome, 2_000_000i128);
+    record_vote(&env, &mut market2, &user1, &outcome, 3_000_000i128);
 
     let pool2 = QueryManager::calculate_outcome_pool(&env, &market2, &outcome).unwrap();
 
@@ -556,10 +557,8 @@ fn test_outcome_pool_consistency() {
     let yes_outcome = String::from_str(&env, "yes");
     let no_outcome = String::from_str(&env, "no");
 
-    market.votes.set(user1.clone(), yes_outcome.clone());
-    market.stakes.set(user1, 7_000_000i128);
-    market.votes.set(user2.clone(), no_outcome.clone());
-    market.stakes.set(user2, 3_000_000i128);
+    record_vote(&env, &mut market, &user1, &yes_outcome, 7_000_000i128);
+    record_vote(&env, &mut market, &user2, &no_outcome, 3_000_000i128);
     market.total_staked = 10_000_000i128;
 
     let yes_pool = QueryManager::calculate_outcome_pool(&env, &market, &yes_outcome).unwrap();
@@ -605,8 +604,7 @@ fn test_payout_with_high_fees() {
     );
 
     let stake = 100_000_000i128; // 10 XLM
-    market.votes.set(user.clone(), yes.clone());
-    market.stakes.set(user.clone(), stake);
+    record_vote(&env, &mut market, &user, &yes, stake);
     market.total_staked = stake;
     market.winning_outcomes = Some(soroban_sdk::vec![&env, yes]);
 
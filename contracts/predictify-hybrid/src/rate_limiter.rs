@@ -1,4 +1,9 @@
-use soroban_sdk::{contract, contracterror, contractimpl, contracttype, Address, Env, Symbol};
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, panic_with_error, Address, Env, Symbol,
+    Vec,
+};
+
+use crate::errors::{Error, ExtendedError};
 
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
@@ -24,6 +29,9 @@ pub enum RateLimiterData {
     UserVoting(Address, Symbol),   // user, market_id
     UserDisputes(Address, Symbol), // user, market_id
     OracleCalls(Symbol),           // market_id
+    ActionLimits,
+    Exemptions,
+    UserAction(Address, RateLimitedAction),
 }
 
 pub struct RateLimiter {
@@ -317,6 +325,165 @@ impl RateLimiterContract {
     }
 }
 
+// ===== ENTRY POINT RATE LIMITS =====
+
+/// Entry point rate limited per user.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RateLimitedAction {
+    Vote,
+    Dispute,
+    CreateMarket,
+}
+
+/// Per-user limits on the main entry points; a limit of zero disables it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ActionRateLimits {
+    /// Votes per user within `vote_window_ledgers` ledgers
+    pub votes_per_window: u32,
+    pub vote_window_ledgers: u32,
+    /// Disputes opened per user per day
+    pub disputes_per_day: u32,
+    /// Markets created per user per day
+    pub markets_per_day: u32,
+}
+
+/// Rate limits enforced by `vote`, `batch_vote`, `dispute_market` and
+/// `create_market_public`.
+///
+/// Each user has a fixed window per action: votes are counted per window
+/// of ledgers, disputes and market creations per day. All limits are off
+/// until the admin sets them, and exempt addresses (e.g. market makers) are
+/// never limited.
+impl RateLimiter {
+    /// Get the per-user action limits in force
+    pub fn get_action_limits(&self) -> ActionRateLimits {
+        self.env
+            .storage()
+            .persistent()
+            .get(&RateLimiterData::ActionLimits)
+            .unwrap_or(ActionRateLimits {
+                votes_per_window: 0,
+                vote_window_ledgers: 0,
+                disputes_per_day: 0,
+                markets_per_day: 0,
+            })
+    }
+
+    /// Set the action limits (admin only); a vote limit needs a non-empty window
+    pub fn set_action_limits(
+        &self,
+        admin: &Address,
+        limits: &ActionRateLimits,
+    ) -> Result<(), Error> {
+        crate::recovery::RecoveryManager::assert_is_admin(&self.env, admin)?;
+        if limits.votes_per_window > 0 && limits.vote_window_ledgers == 0 {
+            return Err(Error::InvalidInput);
+        }
+        self.env
+            .storage()
+            .persistent()
+            .set(&RateLimiterData::ActionLimits, limits);
+        Ok(())
+    }
+
+    /// Addresses exempt from the action limits
+    pub fn get_exemptions(&self) -> Vec<Address> {
+        self.env
+            .storage()
+            .persistent()
+            .get(&RateLimiterData::Exemptions)
+            .unwrap_or(Vec::new(&self.env))
+    }
+
+    /// Add (`exempt = true`) or remove an exemption (admin only)
+    pub fn set_exemption(
+        &self,
+        admin: &Address,
+        user: &Address,
+        exempt: bool,
+    ) -> Result<(), Error> {
+        crate::recovery::RecoveryManager::assert_is_admin(&self.env, admin)?;
+        let mut exemptions = self.get_exemptions();
+        match (exemptions.first_index_of(user), exempt) {
+            (None, true) => exemptions.push_back(user.clone()),
+            (Some(index), false) => {
+                exemptions.remove(index);
+            }
+            _ => return Ok(()),
+        }
+        self.env
+            .storage()
+            .persistent()
+            .set(&RateLimiterData::Exemptions, &exemptions);
+        Ok(())
+    }
+
+    /// Count one `action` by `user`.
+    ///
+    /// Fails with `RateLimitExceeded` once the user has used up the action's
+    /// limit for the current window; the error rolls the count back.
+    pub fn rate_limit_action(
+        &self,
+        user: &Address,
+        action: RateLimitedAction,
+    ) -> Result<(), RateLimiterError> {
+        let limits = self.get_action_limits();
+        let (limit, window, now) = match action {
+            RateLimitedAction::Vote => (
+                limits.votes_per_window,
+                limits.vote_window_ledgers as u64,
+                self.env.ledger().sequence() as u64,
+            ),
+            RateLimitedAction::Dispute => (
+                limits.disputes_per_day,
+                86_400,
+                self.env.ledger().timestamp(),
+            ),
+            RateLimitedAction::CreateMarket => (
+                limits.markets_per_day,
+                86_400,
+                self.env.ledger().timestamp(),
+            ),
+        };
+        if limit == 0 || self.get_exemptions().contains(user) {
+            return Ok(());
+        }
+
+        let key = RateLimiterData::UserAction(user.clone(), action);
+        let mut usage: RateLimit = self
+            .env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(RateLimit {
+                count: 0,
+                window_start: now,
+            });
+        if now >= usage.window_start.saturating_add(window) {
+            usage = RateLimit {
+                count: 0,
+                window_start: now,
+            };
+        }
+        self.check_limit(usage.count, limit)?;
+        usage.count += 1;
+        self.env.storage().persistent().set(&key, &usage);
+        Ok(())
+    }
+
+    /// Count one `action` by `user` from a contract entry point.
+    ///
+    /// Panics with `ExtendedError::RateLimitExceeded` past the limit, so the
+    /// failure decodes from the contract spec like any other contract error.
+    pub fn enforce_action(&self, user: &Address, action: RateLimitedAction) {
+        if self.rate_limit_action(user, action).is_err() {
+            panic_with_error!(self.env, ExtendedError::RateLimitExceeded);
+        }
+    }
+}
+
 /////////////////////////////////////////////////////////////
 ////                     TEST                        ///////
 ///////////////////////////////////////////////////////////
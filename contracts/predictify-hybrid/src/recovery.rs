@@ -1,9 +1,11 @@
 use alloc::format;
 use soroban_sdk::{contracttype, Address, Env, Map, String, Symbol, Vec};
 
+use crate::bets::{BetStorage, BetUtils};
 use crate::events::EventEmitter;
 use crate::markets::MarketStateManager;
-use crate::types::MarketState;
+use crate::storage::VoteLedger;
+use crate::types::{Market, MarketState};
 use crate::Error;
 
 // ===== RECOVERY TYPES =====
@@ -170,9 +172,9 @@ impl RecoveryManager {
             return Ok(false);
         }
 
-        // Example heuristic: ensure total_staked matches sum of stakes map
+        // Example heuristic: ensure total_staked matches the per-outcome totals
         let mut recomputed: i128 = 0;
-        for (_, v) in market.stakes.iter() {
+        for (_, v) in market.outcome_totals.iter() {
             recomputed += v;
         }
         if recomputed != market.total_staked {
@@ -202,6 +204,69 @@ impl RecoveryManager {
         Ok(true)
     }
 
+    /// Flag a market for recovery after a claim tripped the maximum payout guard.
+    ///
+    /// The claim is not paid; the market stays pending until an admin runs recovery.
+    pub fn flag_payout_anomaly(env: &Env, market_id: &Symbol, payout: i128) {
+        let mut rec = RecoveryStorage::load(env, market_id).unwrap_or(MarketRecovery {
+            market_id: market_id.clone(),
+            actions: Vec::new(env),
+            issues_detected: Vec::new(env),
+            recovered: false,
+            partial_refund_total: 0,
+            last_action: None,
+        });
+        // Retried claims and batches report the same anomaly again
+        let issue = String::from_str(env, &format!("payout_exceeds_max_multiple:{}", payout));
+        if !rec.recovered && rec.issues_detected.contains(&issue) {
+            return;
+        }
+        rec.issues_detected.push_back(issue);
+        rec.recovered = false;
+        rec.last_action = Some(String::from_str(env, "payout_guard_tripped"));
+        RecoveryStorage::save(env, &rec);
+        EventEmitter::emit_recovery_event(
+            env,
+            market_id,
+            &String::from_str(env, "payout_guard"),
+            &String::from_str(env, "pending"),
+        );
+    }
+
+    /// Return `user`'s stake in a market and mark their position settled.
+    ///
+    /// Active bets are refunded in full and marked refunded; bets already
+    /// refunded in bulk return nothing. Votes return the stake currently on
+    /// the ledger (vote-change penalties stay in the pool). The caller
+    /// persists `market`. Returns the amount transferred.
+    pub fn refund_position(
+        env: &Env,
+        market_id: &Symbol,
+        market: &mut Market,
+        user: &Address,
+    ) -> Result<i128, Error> {
+        let entry = VoteLedger::get_entry(env, market_id, user).ok_or(Error::NothingToClaim)?;
+        if entry.claimed {
+            return Err(Error::AlreadyClaimed);
+        }
+        let amount = match BetStorage::get_bet(env, market_id, user) {
+            Some(mut bet) if bet.is_active() => {
+                bet.mark_as_refunded();
+                BetStorage::store_bet(env, &bet)?;
+                bet.amount
+            }
+            Some(_) => 0,
+            None => entry.stake,
+        };
+        if amount <= 0 {
+            return Err(Error::NothingToClaim);
+        }
+
+        VoteLedger::mark_claimed(env, market_id, market, user);
+        BetUtils::unlock_funds(env, user, amount)?;
+        Ok(amount)
+    }
+
     pub fn partial_refund_mechanism(
         env: &Env,
         market_id: &Symbol,
@@ -211,14 +276,17 @@ impl RecoveryManager {
         let mut total_refunded: i128 = 0;
 
         for user in users.iter() {
-            if let Some(stake) = market.stakes.get(user.clone()) {
-                if stake > 0 {
-                    // For now just mark claimed and reduce total; real implementation would transfer tokens
-                    market.claimed.set(user.clone(), true);
-                    market.total_staked = market.total_staked - stake;
-                    total_refunded += stake;
-                }
-            }
+            let Some(entry) = VoteLedger::get_entry(env, market_id, &user) else {
+                continue;
+            };
+            // Users already refunded or paid are skipped
+            let Ok(refunded) = Self::refund_position(env, market_id, &mut market, &user) else {
+                continue;
+            };
+            // The market may still resolve, so the refunded stake leaves the pool
+            market.remove_outcome_position(&entry.outcome, entry.stake);
+            market.total_staked -= refunded;
+            total_refunded += refunded;
         }
         MarketStateManager::update_market(env, market_id, &market);
 
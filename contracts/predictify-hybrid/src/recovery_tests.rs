@@ -1,4 +1,5 @@
 #![cfg(test)]
+use crate::errors::ExtendedError;
 use crate::{test::PredictifyTest, PredictifyHybridClient};
 
 #[test]
@@ -27,3 +28,101 @@ fn test_recovery_mechanisms() {
     let status = client.get_recovery_status(&mkt_id);
     assert!(!status.is_empty());
 }
+
+#[test]
+fn test_max_payout_multiple_flags_market_for_recovery() {
+    use soroban_sdk::testutils::{Ledger, LedgerInfo};
+    use soroban_sdk::String;
+
+    let test_ctx = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test_ctx.env, &test_ctx.contract_id);
+    let mkt_id = test_ctx.create_test_market();
+    let loser = test_ctx.create_funded_user();
+
+    test_ctx.env.mock_all_auths();
+    client.vote(
+        &test_ctx.user,
+        &mkt_id,
+        &String::from_str(&test_ctx.env, "yes"),
+        &1_0000000,
+    );
+    client.vote(
+        &loser,
+        &mkt_id,
+        &String::from_str(&test_ctx.env, "no"),
+        &100_0000000,
+    );
+
+    // Winner would receive ~99x their stake; cap single claims at 10x
+    client.set_max_payout_multiple(&test_ctx.admin, &mkt_id, &10);
+
+    let market = client.get_market(&mkt_id).unwrap();
+    test_ctx.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + 1,
+        protocol_version: 22,
+        sequence_number: test_ctx.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+    client.resolve_market_manual(
+        &test_ctx.admin,
+        &mkt_id,
+        &String::from_str(&test_ctx.env, "yes"),
+    );
+    test_ctx.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + market.dispute_window_seconds,
+        protocol_version: 22,
+        sequence_number: test_ctx.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+
+    // The claim fails outright rather than succeeding without a payout
+    assert_eq!(
+        client
+            .try_claim_winnings(&test_ctx.user, &mkt_id)
+            .unwrap_err(),
+        Ok(soroban_sdk::Error::from_contract_error(
+            ExtendedError::PayoutGuardTripped as u32
+        ))
+    );
+    let entry = client.get_vote_entry(&mkt_id, &test_ctx.user).unwrap();
+    assert!(!entry.claimed);
+
+    // Batch payouts skip the claim and flag the market for recovery once
+    let users = soroban_sdk::vec![&test_ctx.env, test_ctx.user.clone()];
+    assert_eq!(
+        client.distribute_winnings(&test_ctx.admin, &mkt_id, &users),
+        0
+    );
+    assert_eq!(
+        client.distribute_winnings(&test_ctx.admin, &mkt_id, &users),
+        0
+    );
+    assert_eq!(
+        client.get_recovery_status(&mkt_id),
+        String::from_str(&test_ctx.env, "pending")
+    );
+    assert_eq!(
+        client.get_market_anomalies(&test_ctx.admin, &mkt_id).len(),
+        1
+    );
+    assert!(
+        !client
+            .get_vote_entry(&mkt_id, &test_ctx.user)
+            .unwrap()
+            .claimed
+    );
+
+    // Once the cap is raised the claim goes through
+    client.set_max_payout_multiple(&test_ctx.admin, &mkt_id, &1_000);
+    client.claim_winnings(&test_ctx.user, &mkt_id);
+    let entry = client.get_vote_entry(&mkt_id, &test_ctx.user).unwrap();
+    assert!(entry.claimed);
+}
@@ -1,5 +1,7 @@
 use soroban_sdk::{contracterror, symbol_short, Env};
 
+use crate::errors::Error;
+
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
@@ -53,6 +55,18 @@ impl ReentrancyGuard {
         env.storage().persistent().set(&Self::key(), &false);
     }
 
+    /// Runs `call` with the lock held, for code that moves tokens.
+    ///
+    /// Fails with `Error::InvalidState` if the lock is already held, so a
+    /// token contract calling back into any guarded path is rejected. The
+    /// lock is released once `call` returns, whatever its result.
+    pub fn guarded<T, F: FnOnce() -> Result<T, Error>>(env: &Env, call: F) -> Result<T, Error> {
+        Self::before_external_call(env).map_err(|_| Error::InvalidState)?;
+        let result = call();
+        Self::after_external_call(env);
+        result
+    }
+
     /// Validates that an external call succeeded.
     ///
     /// This helper standardizes call-site validation and returns a specific
@@ -0,0 +1,147 @@
+#![cfg(test)]
+
+use crate::errors::Error;
+use crate::reentrancy_guard::ReentrancyGuard;
+use crate::test::PredictifyTest;
+use crate::types::ReflectorAsset;
+use crate::PredictifyHybridClient;
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Symbol};
+
+#[contracttype]
+enum ReentrantTokenKey {
+    Target,
+    Attempts,
+    Rejected,
+}
+
+/// Token whose `transfer` calls back into the contract to withdraw again.
+#[contract]
+struct ReentrantToken;
+
+#[contractimpl]
+impl ReentrantToken {
+    pub fn arm(env: Env, target: Address) {
+        env.storage()
+            .instance()
+            .set(&ReentrantTokenKey::Target, &target);
+    }
+
+    pub fn transfer(env: Env, _from: Address, to: Address, amount: i128) {
+        let target: Address = env
+            .storage()
+            .instance()
+            .get(&ReentrantTokenKey::Target)
+            .unwrap();
+        let reentered = PredictifyHybridClient::new(&env, &target).try_withdraw(
+            &to,
+            &ReflectorAsset::Stellar,
+            &amount,
+        );
+
+        let storage = env.storage().instance();
+        let attempts: u32 = storage.get(&ReentrantTokenKey::Attempts).unwrap_or(0);
+        let rejected: u32 = storage.get(&ReentrantTokenKey::Rejected).unwrap_or(0);
+        storage.set(&ReentrantTokenKey::Attempts, &(attempts + 1));
+        storage.set(
+            &ReentrantTokenKey::Rejected,
+            &(rejected + reentered.is_err() as u32),
+        );
+    }
+
+    pub fn balance(_env: Env, _id: Address) -> i128 {
+        0
+    }
+
+    pub fn stats(env: Env) -> (u32, u32) {
+        let storage = env.storage().instance();
+        (
+            storage.get(&ReentrantTokenKey::Attempts).unwrap_or(0),
+            storage.get(&ReentrantTokenKey::Rejected).unwrap_or(0),
+        )
+    }
+}
+
+#[test]
+fn test_reentrant_token_cannot_withdraw_twice() {
+    let test = PredictifyTest::setup();
+    let env = &test.env;
+    let client = PredictifyHybridClient::new(env, &test.contract_id);
+    env.mock_all_auths();
+
+    client.deposit(&test.user, &ReflectorAsset::Stellar, &500_0000000);
+
+    // Swap the payout token for one that re-enters on transfer
+    let token_id = env.register(ReentrantToken, ());
+    let token = ReentrantTokenClient::new(env, &token_id);
+    token.arm(&test.contract_id);
+    env.as_contract(&test.contract_id, || {
+        env.storage()
+            .persistent()
+            .set(&Symbol::new(env, "TokenID"), &token_id);
+    });
+
+    let balance = client.withdraw(&test.user, &ReflectorAsset::Stellar, &200_0000000);
+
+    assert_eq!(balance.amount, 300_0000000);
+    assert_eq!(token.stats(), (1, 1));
+    assert_eq!(
+        client
+            .get_balance(&test.user, &ReflectorAsset::Stellar)
+            .amount,
+        300_0000000
+    );
+    env.as_contract(&test.contract_id, || {
+        assert!(!ReentrancyGuard::is_locked(env));
+    });
+}
+
+#[test]
+fn test_token_moving_entrypoints_reject_while_locked() {
+    let test = PredictifyTest::setup();
+    let env = &test.env;
+    let client = PredictifyHybridClient::new(env, &test.contract_id);
+    env.mock_all_auths();
+
+    client.deposit(&test.user, &ReflectorAsset::Stellar, &100_0000000);
+    env.as_contract(&test.contract_id, || {
+        ReentrancyGuard::before_external_call(env).unwrap();
+    });
+
+    let market_id = test.market_id.clone();
+    assert_eq!(
+        client.try_deposit(&test.user, &ReflectorAsset::Stellar, &1_0000000),
+        Err(Ok(Error::InvalidState))
+    );
+    assert_eq!(
+        client.try_withdraw(&test.user, &ReflectorAsset::Stellar, &1_0000000),
+        Err(Ok(Error::InvalidState))
+    );
+    assert_eq!(
+        client.try_collect_fees(&test.admin, &market_id),
+        Err(Ok(Error::InvalidState))
+    );
+    assert_eq!(
+        client.try_dispute_market(&test.user, &market_id, &10_000_000, &None),
+        Err(Ok(Error::InvalidState))
+    );
+    assert!(matches!(
+        client.try_settle_dispute_stakes(&market_id),
+        Err(Ok(Error::InvalidState))
+    ));
+    assert!(matches!(
+        client.try_appeal_dispute(&test.user, &market_id),
+        Err(Ok(Error::InvalidState))
+    ));
+    assert_eq!(
+        client.try_claim_winnings(&test.user, &market_id),
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            Error::InvalidState as u32
+        )))
+    );
+
+    env.as_contract(&test.contract_id, || {
+        ReentrancyGuard::after_external_call(env);
+    });
+    let balance = client.withdraw(&test.user, &ReflectorAsset::Stellar, &1_0000000);
+    assert_eq!(balance.amount, 99_0000000);
+}
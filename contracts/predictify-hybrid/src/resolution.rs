@@ -1,10 +1,13 @@
-use soroban_sdk::{contracttype, Address, Env, Map, String, Symbol, Vec};
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, Map, String, Symbol, Vec};
 
 use crate::errors::Error;
 
 use crate::markets::{CommunityConsensus, MarketAnalytics, MarketStateManager, MarketUtils};
 
-use crate::oracles::{OracleFactory, OracleUtils};
+use crate::oracles::{
+    CrossRateOracle, MultiOracleAggregator, OracleBlocklist, OracleFactory, OracleUtils,
+};
 // use crate::reentrancy_guard::ReentrancyGuard; // Removed - module no longer exists
 use crate::types::*;
 
@@ -519,7 +522,8 @@ pub enum ResolutionMethod {
 ///
 /// **Quality Metrics:**
 /// - **Average Confidence**: Mean confidence score across resolutions
-/// - **Resolution Times**: Time taken for different resolution methods
+/// - **Resolution Times**: Seconds from market end to resolution for the most
+///   recent `MAX_RESOLUTION_TIME_SAMPLES` markets, with their median and p95
 /// - **Success Rates**: Percentage of successful resolutions by method
 ///
 /// # Example Usage
@@ -627,6 +631,138 @@ pub struct ResolutionAnalytics {
     pub average_confidence: i128,
     pub resolution_times: Vec<u64>,
     pub outcome_distribution: Map<String, u32>,
+    /// Median seconds from market end to resolution
+    pub median_resolution_time: u64,
+    /// 95th percentile seconds from market end to resolution
+    pub p95_resolution_time: u64,
+}
+
+/// Breakdown of the hybrid confidence score for a market awaiting resolution.
+///
+/// Every component is a percentage (0-100):
+/// - `oracle_certainty`: confidence of the oracle result, 0 while none is fetched
+/// - `consensus_strength`: share held by the leading community outcome
+/// - `participation`: voters relative to `MIN_VOTES_FOR_CONSENSUS`
+///
+/// `confidence_score` blends oracle certainty and participation-scaled
+/// consensus with the oracle/community weights. When the oracle and the
+/// community disagree the community side is subtracted instead of added,
+/// so a contested resolution scores low.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ResolutionConfidence {
+    pub market_id: Symbol,
+    /// Oracle outcome, if one has been fetched
+    pub oracle_outcome: Option<String>,
+    /// Leading community outcome (empty without votes)
+    pub community_outcome: String,
+    pub oracle_certainty: u32,
+    pub consensus_strength: u32,
+    pub participation: u32,
+    /// Whether the oracle and community point at the same outcome
+    pub agreement: bool,
+    pub confidence_score: u32,
+}
+
+/// Oracle vs. community weights of the hybrid resolution, in percent.
+///
+/// The weights sum to 100. They set how often a strong community
+/// consensus overrides a disagreeing oracle and how the two sides are
+/// blended into the confidence score.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ResolutionWeights {
+    pub oracle_weight: u32,
+    pub community_weight: u32,
+}
+
+/// How a market's hybrid resolution is (or would be) decided.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ResolutionBreakdown {
+    pub market_id: Symbol,
+    /// Oracle outcome, if one has been fetched
+    pub oracle_outcome: Option<String>,
+    /// Leading community outcome (empty without votes)
+    pub community_outcome: String,
+    /// Share of votes held by the leading community outcome
+    pub community_percentage: u32,
+    /// Weights applied to this market
+    pub weights: ResolutionWeights,
+    /// Winning outcomes once resolved, empty before
+    pub winning_outcomes: Vec<String>,
+    /// Blended hybrid confidence score (0-100)
+    pub confidence_score: u32,
+}
+
+/// Failed oracle resolution attempts of a market and when it may be retried.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ResolutionAttempts {
+    /// Failed attempts so far
+    pub attempts: u32,
+    pub last_attempt_at: u64,
+    /// Error code of the last failure
+    pub last_error: u32,
+    /// Earliest time `resolve_market` may be retried
+    pub next_attempt_at: u64,
+}
+
+/// Status of an optimistic outcome assertion.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum AssertionStatus {
+    /// Open to challenge until `challenge_deadline`
+    Pending,
+    /// Challenged and escalated to the dispute flow
+    Challenged,
+    /// Bond returned or forfeited; nothing left to do
+    Settled,
+}
+
+/// Outcome asserted by a bonded reporter for optimistic resolution.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct OptimisticAssertion {
+    pub asserter: Address,
+    pub outcome: String,
+    /// Bond held until the assertion settles
+    pub bond: i128,
+    pub asserted_at: u64,
+    /// Last moment the assertion can be challenged
+    pub challenge_deadline: u64,
+    /// Challenger, once the assertion has been challenged
+    pub challenger: Option<Address>,
+    pub status: AssertionStatus,
+}
+
+/// Bond and challenge window for optimistic assertions.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct AssertionConfig {
+    pub min_bond: i128,
+    pub challenge_window_seconds: u64,
+}
+
+/// Compact attestation of a final market result for relaying to other chains.
+///
+/// `market_hash` commits to the market definition (id, question, outcomes
+/// and end time) and `attestation_hash` to every other field, so a bridge
+/// can relay the record and a consumer can check it was not altered.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ResolutionAttestation {
+    /// Predictify contract that produced the result
+    pub contract: Address,
+    pub market_id: Symbol,
+    /// sha256 of the XDR-encoded (market_id, question, outcomes, end_time)
+    pub market_hash: BytesN<32>,
+    pub winning_outcomes: Vec<String>,
+    pub end_time: u64,
+    pub resolved_at: u64,
+    pub finalized_at: u64,
+    /// sha256 of the XDR-encoded fields above, in declaration order
+    pub attestation_hash: BytesN<32>,
 }
 
 /// Comprehensive validation result for resolution processes and outcomes.
@@ -946,10 +1082,21 @@ impl OracleResolutionManager {
     fn try_fetch_from_config(
         env: &Env,
         config: &crate::types::OracleConfig,
+        cross_rate: &Option<CrossRateCondition>,
     ) -> Result<(i128, String), Error> {
+        // Blocked oracles are never queried, so resolution moves on to the fallback
+        if OracleBlocklist::is_blocked(env, &config.oracle_address) {
+            return Err(Error::OracleUnavailable);
+        }
+
         let oracle =
             OracleFactory::create_oracle(config.provider.clone(), config.oracle_address.clone())?;
 
+        // Cross-rate markets compare the ratio of two feeds instead of a single price
+        if let Some(condition) = cross_rate {
+            return CrossRateOracle::evaluate(env, &oracle, condition);
+        }
+
         let price = oracle.get_price(env, &config.feed_id)?;
 
         let outcome =
@@ -962,6 +1109,17 @@ impl OracleResolutionManager {
     pub fn fetch_oracle_result(env: &Env, market_id: &Symbol) -> Result<OracleResolution, Error> {
         // Get the market from storage
         let mut market = MarketStateManager::get_market(env, market_id)?;
+        // Referendum and push markets are never resolved from a price feed, and
+        // scalar markets resolve to a value through ScalarResolver
+        if ReferendumManager::get_proposal(env, market_id).is_some()
+            || crate::oracles::PushOracle::get_config(env, market_id).is_some()
+            || market.is_scalar()
+        {
+            return Err(Error::InvalidOracleConfig);
+        }
+
+        // Capture the close-time consensus before touching the oracle
+        MarketStateManager::snapshot_consensus(env, &mut market);
 
         // 1. Check if resolution timeout has been reached
         let current_time = env.ledger().timestamp();
@@ -970,6 +1128,10 @@ impl OracleResolutionManager {
             let old_state = market.state.clone();
             market.state = crate::types::MarketState::Cancelled;
             MarketStateManager::update_market(env, market_id, &market);
+            crate::statistics::StatisticsManager::record_creator_market_invalidated(
+                env,
+                &market.admin,
+            );
 
             crate::events::EventEmitter::emit_resolution_timeout(env, market_id, current_time);
             crate::events::EventEmitter::emit_state_change_event(
@@ -988,47 +1150,112 @@ impl OracleResolutionManager {
 
         // 2. Try primary oracle
         let mut used_config = market.oracle_config.clone();
-        let primary_result = Self::try_fetch_from_config(env, &used_config);
+        let cross_rate = CrossRateOracle::get_condition(env, market_id);
+        let composite = CompositeConditionResolver::get_condition(env, market_id);
+        let primary_result = if let Some(condition) = &composite {
+            // Composite markets record the term that decided the result
+            CompositeConditionResolver::evaluate(env, condition).map(|(term, price, outcome)| {
+                used_config = term;
+                (price, outcome)
+            })
+        } else {
+            // Markets with aggregated feeds resolve against their median or mean price
+            match MultiOracleAggregator::get_config(env, market_id) {
+                Some(aggregation) => MultiOracleAggregator::evaluate(
+                    env,
+                    &aggregation,
+                    used_config.threshold,
+                    &used_config.comparison,
+                ),
+                None => Self::try_fetch_from_config(env, &used_config, &cross_rate),
+            }
+        };
 
         let (price, outcome) = match primary_result {
             Ok(res) => res,
             Err(_) => {
                 // 3. Try fallback oracle if primary fails
-                if market.has_fallback {
-                    let fallback_config = &market.fallback_oracle_config;
-                    match Self::try_fetch_from_config(env, fallback_config) {
-                        Ok(res) => {
-                            crate::events::EventEmitter::emit_fallback_used(
+                let fallback_config = &market.fallback_oracle_config;
+                // The single-feed fallback cannot stand in for a composite condition
+                let fallback_result = if market.has_fallback && composite.is_none() {
+                    Self::try_fetch_from_config(env, fallback_config, &cross_rate)
+                } else {
+                    Err(Error::OracleUnavailable)
+                };
+                match fallback_result {
+                    Ok(res) => {
+                        crate::events::EventEmitter::emit_fallback_used(
+                            env,
+                            market_id,
+                            &market.oracle_config.oracle_address,
+                            &fallback_config.oracle_address,
+                        );
+                        used_config = fallback_config.clone();
+                        res
+                    }
+                    Err(_) => {
+                        // A blocked oracle leaves the market to manual resolution
+                        if OracleBlocklist::is_blocked(env, &market.oracle_config.oracle_address) {
+                            crate::events::EventEmitter::emit_manual_resolution_required(
                                 env,
                                 market_id,
-                                &market.oracle_config.oracle_address,
-                                &fallback_config.oracle_address,
+                                &String::from_str(env, "Oracle blocked by emergency council"),
                             );
-                            used_config = fallback_config.clone();
-                            res
                         }
-                        Err(_) => return Err(Error::OracleUnavailable),
+                        return Err(Error::OracleUnavailable);
                     }
-                } else {
-                    return Err(Error::OracleUnavailable);
                 }
             }
         };
 
+        // Categorical markets map the price onto their outcomes instead
+        let outcome = match CategoricalResolver::get_buckets(env, market_id) {
+            Some(buckets) => CategoricalResolver::outcome_for(&market, &buckets, price)?,
+            None => outcome,
+        };
+
+        // Cross-rate markets record the ratio condition rather than the single-feed one
+        let (threshold, comparison, feed_id) = match &cross_rate {
+            Some(condition) => (
+                condition.threshold,
+                condition.comparison.clone(),
+                condition.base_feed_id.clone(),
+            ),
+            None => (
+                used_config.threshold,
+                used_config.comparison.clone(),
+                used_config.feed_id.clone(),
+            ),
+        };
+
         // Create oracle resolution record
         let resolution = OracleResolution {
             market_id: market_id.clone(),
             oracle_result: outcome.clone(),
             price,
-            threshold: used_config.threshold,
-            comparison: used_config.comparison.clone(),
+            threshold,
+            comparison,
             timestamp: current_time,
             provider: used_config.provider.clone(),
-            feed_id: used_config.feed_id.clone(),
+            feed_id,
         };
 
-        // Store the result in the market
+        // Store the result in the market along with the exact payload used
         MarketStateManager::set_oracle_result(&mut market, outcome.clone());
+        MarketStateManager::pin_resolution_snapshot(
+            &mut market,
+            OracleSnapshot {
+                price,
+                threshold: resolution.threshold,
+                comparison: resolution.comparison.clone(),
+                outcome: outcome.clone(),
+                provider: used_config.provider.clone(),
+                oracle_address: used_config.oracle_address.clone(),
+                feed_id: resolution.feed_id.clone(),
+                timestamp: current_time,
+                ledger_sequence: env.ledger().sequence(),
+            },
+        );
         MarketStateManager::update_market(env, market_id, &market);
 
         // Emit oracle result event
@@ -1284,6 +1511,7 @@ impl MarketResolutionManager {
     pub fn resolve_market(env: &Env, market_id: &Symbol) -> Result<MarketResolution, Error> {
         // Get the market from storage
         let mut market = MarketStateManager::get_market(env, market_id)?;
+        MarketStateManager::snapshot_consensus(env, &mut market);
 
         // Validate market for resolution (includes min pool size check)
         let validation = MarketResolutionValidator::validate_market_for_resolution(env, &market);
@@ -1322,6 +1550,7 @@ impl MarketResolutionManager {
             &oracle_result,
             &community_consensus,
             0, // Tie threshold: 0 = exact ties only
+            ResolutionWeightManager::get_weights(env, market_id).community_weight,
         );
 
         // For resolution record, use first outcome (or comma-separated for display)
@@ -1365,6 +1594,7 @@ impl MarketResolutionManager {
             Some(market_id),
         );
         MarketStateManager::update_market(env, market_id, &market);
+        ResolutionSlaTracker::record_resolution(env, market_id, &market);
 
         // Decrement active event count since the event is resolved
         crate::storage::CreatorLimitsManager::decrement_active_events(env, &market.admin);
@@ -1441,6 +1671,7 @@ impl MarketResolutionManager {
         winning_outcomes.push_back(outcome.clone());
         MarketStateManager::set_winning_outcomes(&mut market, winning_outcomes, Some(market_id));
         MarketStateManager::update_market(env, market_id, &market);
+        ResolutionSlaTracker::record_resolution(env, market_id, &market);
 
         // Decrement active event count since the event is manually finalized
         crate::storage::CreatorLimitsManager::decrement_active_events(env, &market.admin);
@@ -1448,6 +1679,47 @@ impl MarketResolutionManager {
         Ok(resolution)
     }
 
+    /// Resolve a market to a single outcome decided outside the oracle flow.
+    ///
+    /// The outcome is recorded as the market's oracle result as well, and
+    /// `method` and `reason` label the resolution events. Scalar markets with
+    /// a resolved value also pay the buckets around `outcome`.
+    pub fn settle_outcome(
+        env: &Env,
+        market_id: &Symbol,
+        market: &mut Market,
+        outcome: &String,
+        method: &str,
+        reason: &str,
+    ) {
+        let old_state = market.state;
+        MarketStateManager::snapshot_consensus(env, market);
+        market.oracle_result = Some(outcome.clone());
+        market.winning_outcomes = Some(market.payout_outcomes(env, outcome));
+        market.precompute_outcome_totals();
+        market.state = MarketState::Resolved;
+        MarketStateManager::update_market(env, market_id, market);
+        ResolutionSlaTracker::record_resolution(env, market_id, market);
+        crate::storage::CreatorLimitsManager::decrement_active_events(env, &market.admin);
+
+        crate::events::EventEmitter::emit_market_resolved(
+            env,
+            market_id,
+            outcome,
+            outcome,
+            &String::from_str(env, "N/A"),
+            &String::from_str(env, method),
+            100,
+        );
+        crate::events::EventEmitter::emit_state_change_event(
+            env,
+            market_id,
+            &old_state,
+            &MarketState::Resolved,
+            &String::from_str(env, reason),
+        );
+    }
+
     /// Get market resolution
 
     pub fn get_market_resolution(
@@ -1674,9 +1946,102 @@ impl MarketResolutionAnalytics {
         }
     }
 
+    /// Compute the confidence components of a market that is not yet resolved.
+    pub fn calculate_resolution_confidence(
+        env: &Env,
+        market_id: &Symbol,
+    ) -> Result<ResolutionConfidence, Error> {
+        let market = MarketStateManager::get_market(env, market_id)?;
+        if market.winning_outcomes.is_some() {
+            return Err(Error::MarketResolved);
+        }
+        Ok(Self::confidence_for(env, market_id, &market))
+    }
+
+    fn confidence_for(env: &Env, market_id: &Symbol, market: &Market) -> ResolutionConfidence {
+        let consensus = MarketAnalytics::calculate_community_consensus(market);
+        // A verified result carries its own score; an unverified fetch gets
+        // the flat oracle-only confidence used at resolution.
+        let oracle_certainty = match (
+            crate::oracles::OracleIntegrationManager::get_oracle_result(env, market_id),
+            &market.oracle_result,
+        ) {
+            (Some(verified), _) => verified.confidence_score.min(100),
+            (None, Some(outcome)) => {
+                Self::calculate_confidence_score(outcome, &consensus, &ResolutionMethod::OracleOnly)
+            }
+            (None, None) => 0,
+        };
+
+        let consensus_strength = consensus.percentage.min(100);
+        let participation = (market.voter_count.saturating_mul(100)
+            / crate::config::MIN_VOTES_FOR_CONSENSUS)
+            .min(100);
+        let agreement = market.voter_count > 0
+            && market
+                .oracle_result
+                .as_ref()
+                .is_some_and(|outcome| *outcome == consensus.outcome);
+
+        let weights = ResolutionWeightManager::get_weights(env, market_id);
+        let oracle_part = oracle_certainty * weights.oracle_weight;
+        let community_part = consensus_strength * participation / 100 * weights.community_weight;
+        let weighted = if market.oracle_result.is_none() || agreement {
+            oracle_part + community_part
+        } else {
+            oracle_part.saturating_sub(community_part)
+        };
+
+        ResolutionConfidence {
+            market_id: market_id.clone(),
+            oracle_outcome: market.oracle_result.clone(),
+            community_outcome: consensus.outcome,
+            oracle_certainty,
+            consensus_strength,
+            participation,
+            agreement,
+            confidence_score: (weighted / 100).min(crate::config::MAX_CONFIDENCE_SCORE),
+        }
+    }
+
+    /// Oracle result, community consensus, weights and confidence of a market.
+    ///
+    /// Unlike `calculate_resolution_confidence` this also works after
+    /// resolution, when it reports the winning outcomes.
+    pub fn get_resolution_breakdown(
+        env: &Env,
+        market_id: &Symbol,
+    ) -> Result<ResolutionBreakdown, Error> {
+        let market = MarketStateManager::get_market(env, market_id)?;
+        let confidence = Self::confidence_for(env, market_id, &market);
+        Ok(ResolutionBreakdown {
+            market_id: market_id.clone(),
+            oracle_outcome: confidence.oracle_outcome,
+            community_outcome: confidence.community_outcome,
+            community_percentage: confidence.consensus_strength,
+            weights: ResolutionWeightManager::get_weights(env, market_id),
+            winning_outcomes: market.winning_outcomes.unwrap_or(Vec::new(env)),
+            confidence_score: confidence.confidence_score,
+        })
+    }
+
     /// Calculate resolution analytics
-    pub fn calculate_resolution_analytics(_env: &Env) -> Result<ResolutionAnalytics, Error> {
-        Ok(ResolutionAnalytics::default())
+    pub fn calculate_resolution_analytics(env: &Env) -> Result<ResolutionAnalytics, Error> {
+        let resolution_times = ResolutionSlaTracker::get_resolution_times(env);
+        let (median_resolution_time, p95_resolution_time) =
+            ResolutionSlaTracker::median_and_p95(&resolution_times);
+
+        Ok(ResolutionAnalytics {
+            total_resolutions: 0,
+            oracle_resolutions: 0,
+            community_resolutions: 0,
+            hybrid_resolutions: 0,
+            average_confidence: 0,
+            resolution_times,
+            outcome_distribution: Map::new(env),
+            median_resolution_time,
+            p95_resolution_time,
+        })
     }
 
     /// Update resolution analytics
@@ -1689,6 +2054,769 @@ impl MarketResolutionAnalytics {
     }
 }
 
+// ===== RESOLUTION SLA =====
+
+/// Resolution deadline SLA tracking.
+///
+/// Records how long each market took to resolve after its end time. The
+/// first resolution of a market is the one that counts; a later dispute
+/// resolution does not overwrite it. Only the most recent
+/// `MAX_RESOLUTION_TIME_SAMPLES` times are kept for the distribution.
+pub struct ResolutionSlaTracker;
+
+impl ResolutionSlaTracker {
+    #[inline(always)]
+    fn samples_key(env: &Env) -> Symbol {
+        Symbol::new(env, crate::config::RESOLUTION_ANALYTICS_STORAGE_KEY)
+    }
+
+    /// Record the end-to-resolution time of a market that was just resolved.
+    ///
+    /// The market is also counted towards today's settlement statistics.
+    pub fn record_resolution(env: &Env, market_id: &Symbol, market: &Market) {
+        let key = (symbol_short!("res_time"), market_id.clone());
+        if env.storage().persistent().has(&key) {
+            return;
+        }
+        let elapsed = env.ledger().timestamp().saturating_sub(market.end_time);
+        env.storage().persistent().set(&key, &elapsed);
+        crate::statistics::StatisticsManager::record_daily_market_settled(env, market.total_staked);
+
+        let mut samples = Self::get_resolution_times(env);
+        if samples.len() >= crate::config::MAX_RESOLUTION_TIME_SAMPLES {
+            samples.pop_front();
+        }
+        samples.push_back(elapsed);
+        env.storage()
+            .persistent()
+            .set(&Self::samples_key(env), &samples);
+    }
+
+    /// Seconds from end to resolution of a market, if it has been resolved.
+    pub fn get_market_resolution_time(env: &Env, market_id: &Symbol) -> Option<u64> {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("res_time"), market_id.clone()))
+    }
+
+    /// Most recent end-to-resolution times, oldest first.
+    pub fn get_resolution_times(env: &Env) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&Self::samples_key(env))
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Median and nearest-rank 95th percentile of `times`, 0 when empty.
+    pub fn median_and_p95(times: &Vec<u64>) -> (u64, u64) {
+        let mut sorted: alloc::vec::Vec<u64> = times.iter().collect();
+        if sorted.is_empty() {
+            return (0, 0);
+        }
+        sorted.sort_unstable();
+
+        let len = sorted.len();
+        let median = if len.is_multiple_of(2) {
+            (sorted[len / 2 - 1] + sorted[len / 2]) / 2
+        } else {
+            sorted[len / 2]
+        };
+        let p95 = sorted[(len * 95).div_ceil(100) - 1];
+        (median, p95)
+    }
+}
+
+// ===== RESOLUTION WEIGHTS =====
+
+/// Oracle vs. community weighting of the hybrid resolution.
+///
+/// A global default (initially `ORACLE_WEIGHT_PERCENTAGE` /
+/// `COMMUNITY_WEIGHT_PERCENTAGE`) applies unless a market has its own
+/// weights. Per-market weights can only change while the market is
+/// unresolved.
+pub struct ResolutionWeightManager;
+
+impl ResolutionWeightManager {
+    fn market_key(market_id: &Symbol) -> (Symbol, Symbol) {
+        (symbol_short!("res_wts"), market_id.clone())
+    }
+
+    fn validate(weights: &ResolutionWeights) -> Result<(), Error> {
+        if weights.oracle_weight + weights.community_weight != 100 {
+            return Err(Error::InvalidInput);
+        }
+        Ok(())
+    }
+
+    /// Global weights applied to markets without their own.
+    pub fn get_global_weights(env: &Env) -> ResolutionWeights {
+        env.storage()
+            .persistent()
+            .get(&symbol_short!("res_wts"))
+            .unwrap_or(ResolutionWeights {
+                oracle_weight: crate::config::ORACLE_WEIGHT_PERCENTAGE,
+                community_weight: crate::config::COMMUNITY_WEIGHT_PERCENTAGE,
+            })
+    }
+
+    /// Set the global weights (admin only).
+    pub fn set_global_weights(
+        env: &Env,
+        admin: &Address,
+        weights: &ResolutionWeights,
+    ) -> Result<(), Error> {
+        crate::recovery::RecoveryManager::assert_is_admin(env, admin)?;
+        Self::validate(weights)?;
+        env.storage()
+            .persistent()
+            .set(&symbol_short!("res_wts"), weights);
+        Ok(())
+    }
+
+    /// Weights applied to a market: its own if set, the global ones otherwise.
+    pub fn get_weights(env: &Env, market_id: &Symbol) -> ResolutionWeights {
+        env.storage()
+            .persistent()
+            .get(&Self::market_key(market_id))
+            .unwrap_or_else(|| Self::get_global_weights(env))
+    }
+
+    /// Override the weights of an unresolved market (admin only).
+    pub fn set_market_weights(
+        env: &Env,
+        admin: &Address,
+        market_id: &Symbol,
+        weights: &ResolutionWeights,
+    ) -> Result<(), Error> {
+        crate::recovery::RecoveryManager::assert_is_admin(env, admin)?;
+        Self::validate(weights)?;
+        let market = MarketStateManager::get_market(env, market_id)?;
+        if market.winning_outcomes.is_some() {
+            return Err(Error::MarketResolved);
+        }
+        env.storage()
+            .persistent()
+            .set(&Self::market_key(market_id), weights);
+        Ok(())
+    }
+}
+
+// ===== RESOLUTION RETRIES =====
+
+/// Backoff bookkeeping for oracle resolution retries.
+///
+/// Every `resolve_market` call that fails because the oracle is unavailable
+/// is recorded, and the next attempt is pushed back by
+/// `RESOLUTION_RETRY_BASE_BACKOFF_SECONDS` doubled per failure, up to
+/// `RESOLUTION_RETRY_MAX_BACKOFF_SECONDS`, so keepers do not hammer a
+/// failing oracle.
+pub struct ResolutionRetryTracker;
+
+impl ResolutionRetryTracker {
+    fn key(market_id: &Symbol) -> (Symbol, Symbol) {
+        (symbol_short!("res_try"), market_id.clone())
+    }
+
+    /// Whether a resolution error is an oracle outage worth retrying.
+    pub fn is_retryable(error: Error) -> bool {
+        matches!(
+            error,
+            Error::OracleUnavailable
+                | Error::OracleStale
+                | Error::OracleNoConsensus
+                | Error::FallbackOracleUnavailable
+        )
+    }
+
+    /// Failed attempts recorded for a market.
+    pub fn get_attempts(env: &Env, market_id: &Symbol) -> ResolutionAttempts {
+        env.storage()
+            .persistent()
+            .get(&Self::key(market_id))
+            .unwrap_or(ResolutionAttempts {
+                attempts: 0,
+                last_attempt_at: 0,
+                last_error: 0,
+                next_attempt_at: 0,
+            })
+    }
+
+    /// Fail with `MarketNotReady` while the market is backing off.
+    pub fn check_backoff(env: &Env, market_id: &Symbol) -> Result<(), Error> {
+        if env.ledger().timestamp() < Self::get_attempts(env, market_id).next_attempt_at {
+            return Err(Error::MarketNotReady);
+        }
+        Ok(())
+    }
+
+    /// Record a failed attempt and schedule the next one.
+    pub fn record_failure(env: &Env, market_id: &Symbol, error: Error) -> ResolutionAttempts {
+        let now = env.ledger().timestamp();
+        let mut record = Self::get_attempts(env, market_id);
+        let backoff = crate::config::RESOLUTION_RETRY_BASE_BACKOFF_SECONDS
+            .checked_shl(record.attempts.min(32))
+            .unwrap_or(u64::MAX)
+            .min(crate::config::RESOLUTION_RETRY_MAX_BACKOFF_SECONDS);
+        record.attempts += 1;
+        record.last_attempt_at = now;
+        record.last_error = error as u32;
+        record.next_attempt_at = now.saturating_add(backoff);
+        env.storage()
+            .persistent()
+            .set(&Self::key(market_id), &record);
+        crate::events::EventEmitter::emit_resolution_attempt_failed(env, market_id, &record);
+        record
+    }
+}
+
+// ===== OPTIMISTIC ASSERTIONS =====
+
+/// Optimistic resolution for markets without a usable oracle.
+///
+/// After `end_time` any reporter can assert an outcome by posting a bond.
+/// An unchallenged assertion resolves the market once its challenge window
+/// closes and the bond is returned. A challenge escalates the market to the
+/// regular dispute flow with the asserted outcome standing in for the
+/// oracle result; once the dispute resolves, the asserter's bond goes back
+/// to them if their outcome won and to the challenger otherwise.
+pub struct OptimisticAssertionManager;
+
+impl OptimisticAssertionManager {
+    fn key(market_id: &Symbol) -> (Symbol, Symbol) {
+        (symbol_short!("assert"), market_id.clone())
+    }
+
+    /// Get the assertion bond and challenge window in force
+    pub fn get_config(env: &Env) -> AssertionConfig {
+        env.storage()
+            .persistent()
+            .get(&symbol_short!("asrt_cfg"))
+            .unwrap_or(AssertionConfig {
+                min_bond: crate::config::DEFAULT_ASSERTION_MIN_BOND,
+                challenge_window_seconds: crate::config::DEFAULT_ASSERTION_CHALLENGE_WINDOW_SECONDS,
+            })
+    }
+
+    /// Set the assertion bond and challenge window (admin only)
+    pub fn set_config(env: &Env, admin: &Address, config: &AssertionConfig) -> Result<(), Error> {
+        crate::recovery::RecoveryManager::assert_is_admin(env, admin)?;
+        if config.min_bond <= 0 || config.challenge_window_seconds == 0 {
+            return Err(Error::InvalidInput);
+        }
+        env.storage()
+            .persistent()
+            .set(&symbol_short!("asrt_cfg"), config);
+        Ok(())
+    }
+
+    /// Get a market's assertion, if one was made
+    pub fn get_assertion(env: &Env, market_id: &Symbol) -> Option<OptimisticAssertion> {
+        env.storage().persistent().get(&Self::key(market_id))
+    }
+
+    fn store(env: &Env, market_id: &Symbol, assertion: &OptimisticAssertion) {
+        env.storage()
+            .persistent()
+            .set(&Self::key(market_id), assertion);
+    }
+
+    /// Assert the outcome of an ended market, locking `bond` from the asserter.
+    pub fn assert_outcome(
+        env: &Env,
+        asserter: &Address,
+        market_id: &Symbol,
+        outcome: &String,
+        bond: i128,
+    ) -> Result<OptimisticAssertion, Error> {
+        let market = MarketStateManager::get_market(env, market_id)?;
+        if market.winning_outcomes.is_some() {
+            return Err(Error::MarketResolved);
+        }
+        if market.is_active(env) {
+            return Err(Error::MarketClosed);
+        }
+        if market.oracle_result.is_some() || Self::get_assertion(env, market_id).is_some() {
+            return Err(Error::InvalidState);
+        }
+        MarketResolutionValidator::validate_outcome(env, outcome, &market.outcomes)?;
+        let config = Self::get_config(env);
+        if bond < config.min_bond {
+            return Err(Error::InsufficientStake);
+        }
+
+        crate::voting::VotingUtils::transfer_stake(env, asserter, bond)?;
+
+        let now = env.ledger().timestamp();
+        let assertion = OptimisticAssertion {
+            asserter: asserter.clone(),
+            outcome: outcome.clone(),
+            bond,
+            asserted_at: now,
+            challenge_deadline: now.saturating_add(config.challenge_window_seconds),
+            challenger: None,
+            status: AssertionStatus::Pending,
+        };
+        Self::store(env, market_id, &assertion);
+        crate::events::EventEmitter::emit_outcome_asserted(env, market_id, &assertion);
+        Ok(assertion)
+    }
+
+    /// Challenge a pending assertion within its window, opening a dispute.
+    ///
+    /// `stake` is the challenger's dispute stake and must at least match the
+    /// asserter's bond as well as the usual dispute stake requirements.
+    pub fn challenge(
+        env: &Env,
+        challenger: &Address,
+        market_id: &Symbol,
+        stake: i128,
+        reason: Option<String>,
+    ) -> Result<(), Error> {
+        let mut assertion = Self::get_assertion(env, market_id).ok_or(Error::InvalidState)?;
+        if assertion.status != AssertionStatus::Pending
+            || env.ledger().timestamp() > assertion.challenge_deadline
+        {
+            return Err(Error::DisputeCondNotMet);
+        }
+        if stake < assertion.bond {
+            return Err(Error::InsufficientStake);
+        }
+
+        // The asserted outcome stands in for the oracle result in the dispute
+        let mut market = MarketStateManager::get_market(env, market_id)?;
+        market.oracle_result = Some(assertion.outcome.clone());
+        market.state = MarketState::Disputed;
+        MarketStateManager::update_market(env, market_id, &market);
+        crate::disputes::DisputeManager::process_dispute(
+            env,
+            challenger.clone(),
+            market_id.clone(),
+            stake,
+            reason,
+        )?;
+
+        assertion.challenger = Some(challenger.clone());
+        assertion.status = AssertionStatus::Challenged;
+        Self::store(env, market_id, &assertion);
+        crate::events::EventEmitter::emit_assertion_challenged(env, market_id, challenger, stake);
+        Ok(())
+    }
+
+    /// Settle an assertion. Returns the bond paid out.
+    ///
+    /// A pending assertion resolves the market to its outcome once the
+    /// challenge window has closed, unless the market was resolved some
+    /// other way, and returns the bond. A challenged one settles after the
+    /// dispute has resolved the market.
+    pub fn settle(env: &Env, market_id: &Symbol) -> Result<i128, Error> {
+        let mut assertion = Self::get_assertion(env, market_id).ok_or(Error::InvalidState)?;
+        let mut market = MarketStateManager::get_market(env, market_id)?;
+        let recipient = match assertion.status {
+            AssertionStatus::Settled => return Err(Error::AlreadyClaimed),
+            AssertionStatus::Pending => {
+                if env.ledger().timestamp() <= assertion.challenge_deadline {
+                    return Err(Error::MarketNotReady);
+                }
+                // The oracle may have resolved the market in the meantime
+                if market.winning_outcomes.is_none() {
+                    MarketResolutionManager::settle_outcome(
+                        env,
+                        market_id,
+                        &mut market,
+                        &assertion.outcome,
+                        "OptimisticAssertion",
+                        "Optimistic assertion unchallenged",
+                    );
+                }
+                assertion.asserter.clone()
+            }
+            AssertionStatus::Challenged => {
+                let winners = market
+                    .winning_outcomes
+                    .clone()
+                    .ok_or(Error::MarketNotResolved)?;
+                if winners.contains(&assertion.outcome) {
+                    assertion.asserter.clone()
+                } else {
+                    assertion.challenger.clone().ok_or(Error::InvalidState)?
+                }
+            }
+        };
+
+        assertion.status = AssertionStatus::Settled;
+        Self::store(env, market_id, &assertion);
+        crate::voting::VotingUtils::transfer_winnings(env, &recipient, assertion.bond)?;
+        crate::events::EventEmitter::emit_assertion_settled(
+            env,
+            market_id,
+            &recipient,
+            assertion.bond,
+        );
+        Ok(assertion.bond)
+    }
+}
+
+// ===== SCALAR MARKETS =====
+
+/// Resolves scalar (numeric range) markets from their oracle feed.
+///
+/// The feed's price is the market's value; the market resolves to the bucket
+/// holding it and the buckets around it share the pool (see `ScalarRange`).
+pub struct ScalarResolver;
+
+impl ScalarResolver {
+    /// Read the market's feed, falling back to its fallback oracle, and
+    /// resolve the market to that value. Returns the value.
+    pub fn resolve(env: &Env, market_id: &Symbol) -> Result<i128, Error> {
+        let mut market = MarketStateManager::get_market(env, market_id)?;
+        if !market.is_scalar() {
+            return Err(Error::InvalidOracleConfig);
+        }
+        if market.winning_outcomes.is_some() {
+            return Err(Error::MarketResolved);
+        }
+        if market.is_active(env) || market.state == MarketState::Cancelled {
+            return Err(Error::MarketClosed);
+        }
+
+        let value = match Self::read_value(env, &market.oracle_config) {
+            Ok(value) => value,
+            Err(_) if market.has_fallback => Self::read_value(env, &market.fallback_oracle_config)?,
+            Err(e) => return Err(e),
+        };
+        Self::settle(env, market_id, &mut market, value)?;
+        Ok(value)
+    }
+
+    /// Resolve a scalar market to `value`
+    pub fn settle(
+        env: &Env,
+        market_id: &Symbol,
+        market: &mut Market,
+        value: i128,
+    ) -> Result<(), Error> {
+        if !market.is_scalar() {
+            return Err(Error::InvalidOracleConfig);
+        }
+        let range = market.scalar_range.clone();
+        let bucket = market
+            .outcomes
+            .get(range.bucket_of(value))
+            .ok_or(Error::InvalidState)?;
+        market.scalar_value = Some(value);
+        MarketResolutionManager::settle_outcome(
+            env,
+            market_id,
+            market,
+            &bucket,
+            "ScalarOracle",
+            "Scalar value resolved",
+        );
+        Ok(())
+    }
+
+    fn read_value(env: &Env, config: &OracleConfig) -> Result<i128, Error> {
+        if OracleBlocklist::is_blocked(env, &config.oracle_address) {
+            return Err(Error::OracleUnavailable);
+        }
+        let oracle =
+            OracleFactory::create_oracle(config.provider.clone(), config.oracle_address.clone())?;
+        oracle.get_price(env, &config.feed_id)
+    }
+}
+
+// ===== CATEGORICAL MARKETS =====
+
+/// Resolves markets with more than two outcomes against a single feed.
+///
+/// The market's oracle config (or its fallback) still supplies the price, but
+/// instead of a yes/no threshold comparison the price is mapped onto the
+/// market's outcomes through stored `PriceBuckets`.
+pub struct CategoricalResolver;
+
+impl CategoricalResolver {
+    fn key(market_id: &Symbol) -> (Symbol, Symbol) {
+        (symbol_short!("pbuckets"), market_id.clone())
+    }
+
+    /// Price buckets attached to a market, if any
+    pub fn get_buckets(env: &Env, market_id: &Symbol) -> Option<PriceBuckets> {
+        env.storage().persistent().get(&Self::key(market_id))
+    }
+
+    /// Store price buckets for a market after validating them against its outcomes
+    pub fn set_buckets(
+        env: &Env,
+        market_id: &Symbol,
+        market: &Market,
+        buckets: &PriceBuckets,
+    ) -> Result<(), Error> {
+        buckets.validate(market.outcomes.len())?;
+        env.storage()
+            .persistent()
+            .set(&Self::key(market_id), buckets);
+        Ok(())
+    }
+
+    /// Outcome of `market` the price falls in
+    pub fn outcome_for(
+        market: &Market,
+        buckets: &PriceBuckets,
+        price: i128,
+    ) -> Result<String, Error> {
+        market
+            .outcomes
+            .get(buckets.bucket_of(price))
+            .ok_or(Error::InvalidOutcome)
+    }
+}
+
+// ===== COMPOSITE CONDITIONS =====
+
+/// Resolves markets whose question combines several feeds with AND/OR.
+///
+/// The condition is stored per market and takes the place of the market's
+/// single-feed oracle config (and its fallback) at resolution.
+pub struct CompositeConditionResolver;
+
+impl CompositeConditionResolver {
+    fn key(market_id: &Symbol) -> (Symbol, Symbol) {
+        (symbol_short!("composite"), market_id.clone())
+    }
+
+    /// Composite condition attached to a market, if any
+    pub fn get_condition(env: &Env, market_id: &Symbol) -> Option<CompositeCondition> {
+        env.storage().persistent().get(&Self::key(market_id))
+    }
+
+    /// Store a composite condition for a market after validating it
+    pub fn set_condition(
+        env: &Env,
+        market_id: &Symbol,
+        condition: &CompositeCondition,
+    ) -> Result<(), Error> {
+        condition.validate(env)?;
+        env.storage()
+            .persistent()
+            .set(&Self::key(market_id), condition);
+        Ok(())
+    }
+
+    /// Evaluate the condition against live prices.
+    ///
+    /// Returns the term that decided the result, its price and the outcome
+    /// ("yes" if the expression holds). Any term that has to be read but
+    /// cannot be fails the whole evaluation.
+    pub fn evaluate(
+        env: &Env,
+        condition: &CompositeCondition,
+    ) -> Result<(OracleConfig, i128, String), Error> {
+        let short_circuit_on = condition.operator == ConditionOperator::Or;
+        let mut decided = None;
+        for term in condition.terms.iter() {
+            if OracleBlocklist::is_blocked(env, &term.oracle_address) {
+                return Err(Error::OracleUnavailable);
+            }
+            let oracle =
+                OracleFactory::create_oracle(term.provider.clone(), term.oracle_address.clone())?;
+            let price = oracle.get_price(env, &term.feed_id)?;
+            let holds = OracleUtils::compare_prices(price, term.threshold, &term.comparison, env)?;
+            let stop = holds == short_circuit_on;
+            decided = Some((term, price, holds));
+            if stop {
+                break;
+            }
+        }
+
+        let (term, price, holds) = decided.ok_or(Error::InvalidOracleConfig)?;
+        let outcome = String::from_str(env, if holds { "yes" } else { "no" });
+        Ok((term, price, outcome))
+    }
+}
+
+// ===== REFERENDUM MARKETS =====
+
+/// Markets resolved by the outcome of a governance proposal.
+///
+/// A linked market resolves to "yes" if the proposal passes governance
+/// voting (quorum reached and more votes for than against) and to "no"
+/// otherwise, once both the market and the proposal's voting have ended.
+/// No oracle is queried for these markets.
+pub struct ReferendumManager;
+
+impl ReferendumManager {
+    fn key(market_id: &Symbol) -> (Symbol, Symbol) {
+        (symbol_short!("referendm"), market_id.clone())
+    }
+
+    /// Governance proposal a market is linked to, if any
+    pub fn get_proposal(env: &Env, market_id: &Symbol) -> Option<Symbol> {
+        env.storage().persistent().get(&Self::key(market_id))
+    }
+
+    /// Link a yes/no market to a governance proposal.
+    ///
+    /// The market must offer exactly the outcomes "yes" and "no" and the
+    /// proposal must exist.
+    pub fn link(
+        env: &Env,
+        market_id: &Symbol,
+        market: &Market,
+        proposal_id: &Symbol,
+    ) -> Result<(), Error> {
+        let yes = String::from_str(env, "yes");
+        let no = String::from_str(env, "no");
+        if market.outcomes.len() != 2
+            || !market.outcomes.contains(&yes)
+            || !market.outcomes.contains(&no)
+        {
+            return Err(Error::InvalidOutcomes);
+        }
+        crate::governance::GovernanceContract::get_proposal(env.clone(), proposal_id.clone())
+            .map_err(|_| Error::InvalidInput)?;
+        env.storage()
+            .persistent()
+            .set(&Self::key(market_id), proposal_id);
+        Ok(())
+    }
+
+    /// Resolve a linked market from its proposal's result. Returns the outcome.
+    pub fn resolve(env: &Env, market_id: &Symbol) -> Result<String, Error> {
+        let proposal_id = Self::get_proposal(env, market_id).ok_or(Error::InvalidState)?;
+        let mut market = MarketStateManager::get_market(env, market_id)?;
+        if market.winning_outcomes.is_some() {
+            return Err(Error::MarketResolved);
+        }
+        if market.is_active(env) {
+            return Err(Error::MarketClosed);
+        }
+        let passed =
+            crate::governance::GovernanceContract::proposal_passed(env.clone(), proposal_id)
+                .map_err(|_| Error::InvalidInput)?
+                .ok_or(Error::MarketNotReady)?;
+
+        let outcome = String::from_str(env, if passed { "yes" } else { "no" });
+        MarketResolutionManager::settle_outcome(
+            env,
+            market_id,
+            &mut market,
+            &outcome,
+            "GovernanceReferendum",
+            "Governance proposal decided",
+        );
+        Ok(outcome)
+    }
+}
+
+// ===== RESULT FINALITY =====
+
+/// Finality of market results for consumption by external contracts.
+///
+/// A resolved market is only provisionally settled until its dispute window
+/// (`end_time + dispute_window_seconds`) has closed. `finalize` moves it from
+/// `MarketResolved` to `Finalized` once that happens; the transition is
+/// recorded in storage and never reverted, so integrations that check
+/// `is_final` never act on an outcome that could still be overturned.
+pub struct ResultFinality;
+
+impl ResultFinality {
+    /// Move a resolved market past its dispute window to `Finalized`.
+    /// Returns the finalization timestamp.
+    pub fn finalize(env: &Env, market_id: &Symbol) -> Result<u64, Error> {
+        if Self::is_final(env, market_id) {
+            return Err(Error::InvalidState);
+        }
+        let market = MarketStateManager::get_market(env, market_id)?;
+        if market.state != MarketState::Resolved {
+            return Err(Error::MarketNotResolved);
+        }
+        let window_end = market
+            .end_time
+            .saturating_add(market.dispute_window_seconds);
+        let now = env.ledger().timestamp();
+        if now < window_end {
+            return Err(Error::InvalidState);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("res_final"), market_id.clone()), &now);
+
+        let outcomes = market.winning_outcomes.unwrap_or(Vec::new(env));
+        crate::events::EventEmitter::emit_result_finalized(env, market_id, &outcomes);
+        Ok(now)
+    }
+
+    /// Whether the market's result is final and safe to consume.
+    pub fn is_final(env: &Env, market_id: &Symbol) -> bool {
+        env.storage()
+            .persistent()
+            .has(&(symbol_short!("res_final"), market_id.clone()))
+    }
+
+    /// Current position of a market in the resolution state machine.
+    pub fn get_state(env: &Env, market_id: &Symbol) -> Result<ResolutionState, Error> {
+        if Self::is_final(env, market_id) {
+            return Ok(ResolutionState::Finalized);
+        }
+        let market = MarketStateManager::get_market(env, market_id)?;
+        if market.state == MarketState::Disputed {
+            return Ok(ResolutionState::Disputed);
+        }
+        Ok(ResolutionUtils::get_resolution_state(env, &market))
+    }
+
+    /// Hash-committed record of a finalized result, for bridges.
+    ///
+    /// Only final results are attested so a relayed outcome can never be
+    /// overturned afterwards.
+    pub fn attestation(env: &Env, market_id: &Symbol) -> Result<ResolutionAttestation, Error> {
+        let market = MarketStateManager::get_market(env, market_id)?;
+        let winning_outcomes = market
+            .winning_outcomes
+            .clone()
+            .ok_or(Error::MarketNotResolved)?;
+        let finalized_at: u64 = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("res_final"), market_id.clone()))
+            .ok_or(Error::InvalidState)?;
+        let resolved_at = ResolutionSlaTracker::get_market_resolution_time(env, market_id)
+            .map(|elapsed| market.end_time.saturating_add(elapsed))
+            .unwrap_or(finalized_at);
+
+        let contract = env.current_contract_address();
+        let definition = (
+            market_id.clone(),
+            market.question.clone(),
+            market.outcomes.clone(),
+            market.end_time,
+        );
+        let market_hash: BytesN<32> = env.crypto().sha256(&definition.to_xdr(env)).into();
+        let committed = (
+            contract.clone(),
+            market_id.clone(),
+            market_hash.clone(),
+            winning_outcomes.clone(),
+            market.end_time,
+            resolved_at,
+            finalized_at,
+        );
+        let attestation_hash: BytesN<32> = env.crypto().sha256(&committed.to_xdr(env)).into();
+
+        Ok(ResolutionAttestation {
+            contract,
+            market_id: market_id.clone(),
+            market_hash,
+            winning_outcomes,
+            end_time: market.end_time,
+            resolved_at,
+            finalized_at,
+            attestation_hash,
+        })
+    }
+}
+
 // ===== RESOLUTION UTILITIES =====
 
 /// Resolution utility functions
@@ -1859,6 +2987,8 @@ impl Default for ResolutionAnalytics {
             average_confidence: 0,
             resolution_times: Vec::new(&soroban_sdk::Env::default()),
             outcome_distribution: Map::new(&soroban_sdk::Env::default()),
+            median_resolution_time: 0,
+            p95_resolution_time: 0,
         }
     }
 }
@@ -1988,4 +3118,212 @@ mod tests {
         );
         assert!(matches!(method, ResolutionMethod::OracleOnly));
     }
+
+    #[test]
+    fn test_fetch_oracle_result_pins_snapshot() {
+        let test = PredictifyTest::setup();
+        let market_id = test.create_test_market();
+        let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+        let market = client.get_market(&market_id).unwrap();
+
+        test.env.ledger().set(LedgerInfo {
+            timestamp: market.end_time,
+            protocol_version: 22,
+            sequence_number: test.env.ledger().sequence(),
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 1,
+            min_persistent_entry_ttl: 1,
+            max_entry_ttl: 10000,
+        });
+
+        test.env.as_contract(&test.contract_id, || {
+            let resolution =
+                OracleResolutionManager::fetch_oracle_result(&test.env, &market_id).unwrap();
+
+            let mut market = MarketStateManager::get_market(&test.env, &market_id).unwrap();
+            assert!(market.has_resolution_snapshot);
+            let snapshot = market.resolution_snapshot.clone();
+            assert_eq!(snapshot.price, resolution.price);
+            assert_eq!(snapshot.threshold, 2500000);
+            assert_eq!(snapshot.outcome, resolution.oracle_result);
+            assert_eq!(snapshot.feed_id, String::from_str(&test.env, "BTC"));
+            assert_eq!(snapshot.timestamp, market.end_time);
+            assert_eq!(snapshot.ledger_sequence, test.env.ledger().sequence());
+
+            // A pinned snapshot is never overwritten
+            let mut other = snapshot.clone();
+            other.price = 1;
+            MarketStateManager::pin_resolution_snapshot(&mut market, other);
+            assert_eq!(market.resolution_snapshot, snapshot);
+        });
+
+        assert!(client.get_resolution_snapshot(&market_id).is_some());
+    }
+
+    #[test]
+    fn test_resolution_sla_distribution() {
+        let test = PredictifyTest::setup();
+        let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+        let market_ids = [
+            test.create_test_market(),
+            test.create_test_market(),
+            test.create_test_market(),
+        ];
+        let end_time = client.get_market(&market_ids[0]).unwrap().end_time;
+
+        test.env.mock_all_auths();
+        for (market_id, delay) in market_ids.iter().zip([100u64, 1_000, 300]) {
+            test.env.ledger().set(LedgerInfo {
+                timestamp: end_time + delay,
+                protocol_version: 22,
+                sequence_number: test.env.ledger().sequence(),
+                network_id: Default::default(),
+                base_reserve: 10,
+                min_temp_entry_ttl: 1,
+                min_persistent_entry_ttl: 1,
+                max_entry_ttl: 10000,
+            });
+            client.resolve_market_manual(
+                &test.admin,
+                market_id,
+                &String::from_str(&test.env, "yes"),
+            );
+        }
+
+        let analytics = client.get_resolution_analytics();
+        assert_eq!(analytics.resolution_times.len(), 3);
+        assert_eq!(analytics.median_resolution_time, 300);
+        assert_eq!(analytics.p95_resolution_time, 1_000);
+
+        test.env.as_contract(&test.contract_id, || {
+            let recorded =
+                ResolutionSlaTracker::get_market_resolution_time(&test.env, &market_ids[1]);
+            assert_eq!(recorded, Some(1_000));
+        });
+
+        // 18 more markets taking 10..=180 seconds; of the 21 sorted times the
+        // median is the 11th (100) and the nearest-rank p95 the 20th (300)
+        for delay in (1..=18u64).rev().map(|i| i * 10) {
+            let market_id = test.create_test_market();
+            let end_time = client.get_market(&market_id).unwrap().end_time;
+            test.env.ledger().set(LedgerInfo {
+                timestamp: end_time + delay,
+                protocol_version: 22,
+                sequence_number: test.env.ledger().sequence(),
+                network_id: Default::default(),
+                base_reserve: 10,
+                min_temp_entry_ttl: 1,
+                min_persistent_entry_ttl: 1,
+                max_entry_ttl: 10000,
+            });
+            client.resolve_market_manual(
+                &test.admin,
+                &market_id,
+                &String::from_str(&test.env, "no"),
+            );
+        }
+
+        let analytics = client.get_resolution_analytics();
+        assert_eq!(analytics.resolution_times.len(), 21);
+        assert_eq!(analytics.median_resolution_time, 100);
+        assert_eq!(analytics.p95_resolution_time, 300);
+    }
+
+    #[test]
+    fn test_result_becomes_final_after_dispute_window() {
+        let test = PredictifyTest::setup();
+        let market_id = test.create_test_market();
+        let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+        let market = client.get_market(&market_id).unwrap();
+        let set_time = |timestamp: u64| {
+            test.env.ledger().set(LedgerInfo {
+                timestamp,
+                protocol_version: 22,
+                sequence_number: test.env.ledger().sequence(),
+                network_id: Default::default(),
+                base_reserve: 10,
+                min_temp_entry_ttl: 1,
+                min_persistent_entry_ttl: 1,
+                max_entry_ttl: 10000,
+            });
+        };
+
+        // Unresolved markets cannot be finalized
+        assert_eq!(
+            client.get_finality_state(&market_id),
+            ResolutionState::Active
+        );
+        assert_eq!(
+            client.try_finalize_result(&market_id),
+            Err(Ok(Error::MarketNotResolved))
+        );
+
+        set_time(market.end_time + 1);
+        test.env.mock_all_auths();
+        client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+        assert!(!client.is_final(&market_id));
+        assert_eq!(
+            client.get_finality_state(&market_id),
+            ResolutionState::MarketResolved
+        );
+
+        // Still inside the dispute window, up to its last second
+        let window_end = market.end_time + market.dispute_window_seconds;
+        assert_eq!(
+            client.try_finalize_result(&market_id),
+            Err(Ok(Error::InvalidState))
+        );
+        set_time(window_end - 1);
+        assert_eq!(
+            client.try_finalize_result(&market_id),
+            Err(Ok(Error::InvalidState))
+        );
+        assert!(!client.is_final(&market_id));
+
+        set_time(window_end);
+        assert_eq!(client.finalize_result(&market_id), window_end);
+        assert!(client.is_final(&market_id));
+        assert_eq!(
+            client.get_finality_state(&market_id),
+            ResolutionState::Finalized
+        );
+
+        // A result is finalized once; later calls are rejected
+        set_time(window_end + 100);
+        assert_eq!(
+            client.try_finalize_result(&market_id),
+            Err(Ok(Error::InvalidState))
+        );
+        assert!(client.is_final(&market_id));
+
+        test.env.as_contract(&test.contract_id, || {
+            let event = test
+                .env
+                .storage()
+                .persistent()
+                .get::<Symbol, crate::events::ResultFinalizedEvent>(&symbol_short!("res_final"))
+                .unwrap();
+            assert_eq!(event.market_id, market_id);
+            assert_eq!(
+                event.outcomes,
+                soroban_sdk::vec![&test.env, String::from_str(&test.env, "yes")]
+            );
+        });
+    }
+
+    #[test]
+    fn test_resolution_sla_percentiles() {
+        let env = Env::default();
+        assert_eq!(
+            ResolutionSlaTracker::median_and_p95(&Vec::new(&env)),
+            (0, 0)
+        );
+
+        let mut times = Vec::new(&env);
+        for t in (1..=20u64).rev() {
+            times.push_back(t * 10);
+        }
+        assert_eq!(ResolutionSlaTracker::median_and_p95(&times), (105, 190));
+    }
 }
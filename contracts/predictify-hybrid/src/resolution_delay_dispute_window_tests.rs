@@ -4,6 +4,7 @@ use crate::config::{ConfigManager, DISPUTE_EXTENSION_HOURS};
 use crate::disputes::DisputeManager;
 use crate::errors::Error;
 use crate::markets::MarketStateManager;
+use crate::storage::VoteLedger;
 use crate::types::{Market, MarketState, OracleConfig, OracleProvider};
 use crate::voting::VotingManager;
 use crate::PredictifyHybrid;
@@ -101,10 +102,14 @@ fn test_payout_blocked_during_dispute_window() {
         let (market_id, mut market) = setup.create_test_market(end_time);
 
         // Add a vote
-        market
-            .votes
-            .set(user.clone(), String::from_str(&setup.env, "yes"));
-        market.stakes.set(user.clone(), 1_000_000);
+        VoteLedger::set_position(
+            &setup.env,
+            &market_id,
+            &mut market,
+            &user,
+            &String::from_str(&setup.env, "yes"),
+            1_000_000,
+        );
         market.total_staked = 1_000_000;
 
         // Store market
@@ -158,10 +163,14 @@ fn test_payout_allowed_after_dispute_window_closes() {
         let (market_id, mut market) = setup.create_test_market(end_time);
 
         // Add vote
-        market
-            .votes
-            .set(user.clone(), String::from_str(&setup.env, "yes"));
-        market.stakes.set(user.clone(), 1_000_000);
+        VoteLedger::set_position(
+            &setup.env,
+            &market_id,
+            &mut market,
+            &user,
+            &String::from_str(&setup.env, "yes"),
+            1_000_000,
+        );
         market.total_staked = 1_000_000;
 
         setup.env.storage().persistent().set(&market_id, &market);
@@ -210,10 +219,14 @@ fn test_payout_blocked_with_active_dispute() {
         let end_time = setup.env.ledger().timestamp() + 1000;
         let (market_id, mut market) = setup.create_test_market(end_time);
 
-        market
-            .votes
-            .set(user.clone(), String::from_str(&setup.env, "yes"));
-        market.stakes.set(user.clone(), 5_000_000);
+        VoteLedger::set_position(
+            &setup.env,
+            &market_id,
+            &mut market,
+            &user,
+            &String::from_str(&setup.env, "yes"),
+            5_000_000,
+        );
         market.total_staked = 5_000_000;
 
         setup.env.storage().persistent().set(&market_id, &market);
@@ -637,10 +650,14 @@ fn test_full_lifecycle_with_dispute_window() {
         let end_time = setup.env.ledger().timestamp() + 1000;
         let (market_id, mut market) = setup.create_test_market(end_time);
 
-        market
-            .votes
-            .set(user.clone(), String::from_str(&setup.env, "yes"));
-        market.stakes.set(user.clone(), 5_000_000);
+        VoteLedger::set_position(
+            &setup.env,
+            &market_id,
+            &mut market,
+            &user,
+            &String::from_str(&setup.env, "yes"),
+            5_000_000,
+        );
         market.total_staked = 5_000_000;
         market.state = MarketState::Active;
 
@@ -0,0 +1,183 @@
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::bets::BetUtils;
+use crate::config;
+use crate::disputes::DisputeUtils;
+use crate::events::EventEmitter;
+use crate::reentrancy_guard::ReentrancyGuard;
+use crate::types::Market;
+use crate::Error;
+
+// ===== REWARDS STORAGE =====
+
+pub struct RewardsStorage;
+impl RewardsStorage {
+    #[inline(always)]
+    fn emission_pool_key(env: &Env) -> Symbol {
+        Symbol::new(env, "reward_emission_pool")
+    }
+
+    pub fn emission_pool(env: &Env) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&Self::emission_pool_key(env))
+            .unwrap_or(0)
+    }
+
+    pub fn set_emission_pool(env: &Env, amount: i128) {
+        env.storage()
+            .persistent()
+            .set(&Self::emission_pool_key(env), &amount);
+    }
+
+    /// Emitted but not yet claimed rewards of `user`
+    pub fn accrued(env: &Env, user: &Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("rwd_acc"), user.clone()))
+            .unwrap_or(0)
+    }
+
+    pub fn set_accrued(env: &Env, user: &Address, amount: i128) {
+        let key = (symbol_short!("rwd_acc"), user.clone());
+        if amount == 0 {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &amount);
+        }
+    }
+
+    /// Whether participation rewards were already emitted for a market's dispute
+    pub fn is_dispute_rewarded(env: &Env, market_id: &Symbol) -> bool {
+        env.storage()
+            .persistent()
+            .has(&(symbol_short!("rwd_dsp"), market_id.clone()))
+    }
+
+    pub fn mark_dispute_rewarded(env: &Env, market_id: &Symbol) {
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("rwd_dsp"), market_id.clone()), &true);
+    }
+}
+
+// ===== PROTOCOL EMISSIONS =====
+
+/// Protocol reward emissions.
+///
+/// The treasury funds an emission pool that pays small flat rewards on top
+/// of stake redistribution. Rewards are accrued at emission time and pulled
+/// by recipients with `claim`, so emitting to many participants never makes
+/// token transfers.
+pub struct RewardsManager;
+
+impl RewardsManager {
+    /// Add funds from `funder` to the emission pool. Returns the new balance.
+    pub fn fund_emissions(env: &Env, funder: &Address, amount: i128) -> Result<i128, Error> {
+        if amount <= 0 {
+            return Err(Error::InvalidInput);
+        }
+        BetUtils::lock_funds(env, funder, amount)?;
+        let pool = RewardsStorage::emission_pool(env) + amount;
+        RewardsStorage::set_emission_pool(env, pool);
+        Ok(pool)
+    }
+
+    /// Emit participation rewards to the honest participants of a market's
+    /// dispute once it has been resolved to `final_outcome`.
+    ///
+    /// Participants are aligned with the final outcome when they backed an
+    /// overturn that happened (disputers and supporting voters) or rejected
+    /// one that did not (opposing voters). Each aligned participant accrues a
+    /// flat `DISPUTE_PARTICIPATION_REWARD` regardless of stake, so small
+    /// stakes are rewarded as much as large ones; when the pool cannot cover
+    /// everyone the remaining balance is split evenly. At most
+    /// `MAX_DISPUTE_REWARD_RECIPIENTS` participants are rewarded, in
+    /// participation order. Returns `(recipients, total_emitted)`.
+    pub fn emit_dispute_participation_rewards(
+        env: &Env,
+        market: &Market,
+        market_id: &Symbol,
+        final_outcome: &String,
+    ) -> Result<(u32, i128), Error> {
+        if RewardsStorage::is_dispute_rewarded(env, market_id) {
+            return Ok((0, 0));
+        }
+        RewardsStorage::mark_dispute_rewarded(env, market_id);
+
+        let overturned = market.oracle_result.as_ref() != Some(final_outcome);
+        let recipients = Self::aligned_dispute_participants(env, market, market_id, overturned);
+
+        let pool = RewardsStorage::emission_pool(env);
+        let count = recipients.len() as i128;
+        if count == 0 || pool <= 0 {
+            return Ok((0, 0));
+        }
+        let per_recipient = config::DISPUTE_PARTICIPATION_REWARD.min(pool / count);
+        if per_recipient <= 0 {
+            return Ok((0, 0));
+        }
+
+        for recipient in recipients.iter() {
+            let accrued = RewardsStorage::accrued(env, &recipient);
+            RewardsStorage::set_accrued(env, &recipient, accrued + per_recipient);
+        }
+        let total = per_recipient * count;
+        RewardsStorage::set_emission_pool(env, pool - total);
+
+        EventEmitter::emit_dispute_rewards_emitted(
+            env,
+            market_id,
+            recipients.len(),
+            per_recipient,
+            total,
+        );
+        Ok((recipients.len(), total))
+    }
+
+    /// Transfer all accrued rewards to `user`. Returns the amount claimed.
+    pub fn claim(env: &Env, user: &Address) -> Result<i128, Error> {
+        let amount = RewardsStorage::accrued(env, user);
+        if amount <= 0 {
+            return Err(Error::NothingToClaim);
+        }
+        RewardsStorage::set_accrued(env, user, 0);
+        ReentrancyGuard::guarded(env, || BetUtils::unlock_funds(env, user, amount))?;
+
+        EventEmitter::emit_rewards_claimed(env, user, amount);
+        Ok(amount)
+    }
+
+    /// Distinct dispute participants whose position matched the resolution.
+    fn aligned_dispute_participants(
+        env: &Env,
+        market: &Market,
+        market_id: &Symbol,
+        overturned: bool,
+    ) -> Vec<Address> {
+        let mut recipients: Vec<Address> = Vec::new(env);
+        let max = config::MAX_DISPUTE_REWARD_RECIPIENTS;
+
+        // Disputing a market is a stake against the oracle result
+        if overturned {
+            for (disputer, _) in market.dispute_stakes.iter() {
+                if recipients.len() >= max {
+                    return recipients;
+                }
+                recipients.push_back(disputer);
+            }
+        }
+
+        // Community votes are recorded under the market ID as the dispute ID
+        let votes = DisputeUtils::get_dispute_votes(env, market_id).unwrap_or(Vec::new(env));
+        for vote in votes.iter() {
+            if recipients.len() >= max {
+                break;
+            }
+            if vote.vote == overturned && !recipients.contains(&vote.user) {
+                recipients.push_back(vote.user);
+            }
+        }
+        recipients
+    }
+}
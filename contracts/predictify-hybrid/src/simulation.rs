@@ -0,0 +1,246 @@
+use soroban_sdk::{contracttype, Address, Env, String, Symbol};
+
+use crate::admin::ContractPauseManager;
+use crate::bets::{BetManager, BetValidator};
+use crate::config::PERCENTAGE_DENOMINATOR;
+use crate::errors::Error;
+use crate::markets::{MarketStateManager, MarketUtils};
+use crate::storage::VoteLedger;
+use crate::types::Market;
+use crate::validation::MarketValidator;
+use crate::vesting::VestingManager;
+
+// ===== DRY-RUN SIMULATION =====
+
+/// Preview of a vote or bet, as it would be applied right now.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StakeSimulation {
+    /// Stake that would be accepted (after any outcome-cap clamp)
+    pub stake: i128,
+    /// Market pool after the stake
+    pub total_staked: i128,
+    /// Stake on the chosen outcome after the stake
+    pub outcome_total: i128,
+    /// Implied probability of the chosen outcome after the stake, in basis points
+    pub implied_probability_bps: i128,
+    /// Payout if the chosen outcome wins and no one else stakes
+    pub potential_payout: i128,
+    /// Platform fee withheld from that payout
+    pub potential_fee: i128,
+}
+
+/// Preview of a winnings claim, as it would be settled right now.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimSimulation {
+    pub payout: i128,
+    /// Platform fee withheld from the payout
+    pub fee: i128,
+    /// Whether the payout would vest instead of being credited at once
+    pub vests: bool,
+    /// Loser rebate credited instead of a payout
+    pub rebate: i128,
+}
+
+/// Dry runs of the staking and claiming entry points.
+///
+/// Each simulation runs the same checks, in the same order, as the entry point
+/// it mirrors and returns the resulting deltas without writing any state, so
+/// wallets can show exact previews (and the exact error) before signing.
+pub struct SimulationManager;
+
+impl SimulationManager {
+    /// Dry run of `vote`
+    pub fn simulate_vote(
+        env: &Env,
+        user: &Address,
+        market_id: &Symbol,
+        outcome: &String,
+        stake: i128,
+    ) -> Result<StakeSimulation, Error> {
+        ContractPauseManager::require_not_paused(env)?;
+        let quote = Self::quote_vote(env, market_id, outcome, stake)?;
+        if VoteLedger::get_entry(env, market_id, user).is_some() {
+            return Err(Error::AlreadyVoted);
+        }
+        Self::check_balance(env, user, quote.stake)?;
+
+        Ok(quote)
+    }
+
+    /// How a prospective vote would shift the pool, for any voter.
+    ///
+    /// Runs the market-side checks of `vote` only, so quotes can be shown
+    /// before a wallet is connected.
+    pub fn quote_vote(
+        env: &Env,
+        market_id: &Symbol,
+        outcome: &String,
+        stake: i128,
+    ) -> Result<StakeSimulation, Error> {
+        let market = MarketStateManager::get_market(env, market_id)?;
+        if market.has_ended(env) {
+            return Err(Error::MarketClosed);
+        }
+        MarketStateManager::require_direct_staking(env, market_id)?;
+        if !market.outcomes.contains(outcome) {
+            return Err(Error::InvalidOutcome);
+        }
+        if stake <= 0 {
+            return Err(Error::InvalidInput);
+        }
+        MarketValidator::validate_stake_for_tier(&market, stake)
+            .map_err(|e| e.to_contract_error())?;
+        let stake = market.apply_outcome_cap(outcome, stake)?;
+
+        Self::stake_deltas(&market, outcome, stake)
+    }
+
+    /// Dry run of `place_bet`
+    pub fn simulate_bet(
+        env: &Env,
+        user: &Address,
+        market_id: &Symbol,
+        outcome: &String,
+        amount: i128,
+    ) -> Result<StakeSimulation, Error> {
+        ContractPauseManager::require_not_paused(env)?;
+        let market = MarketStateManager::get_market(env, market_id)?;
+        BetValidator::validate_market_for_betting(env, &market)?;
+        MarketStateManager::require_direct_staking(env, market_id)?;
+        BetValidator::validate_bet_parameters(env, market_id, outcome, &market.outcomes, amount)?;
+        MarketValidator::validate_stake_for_tier(&market, amount)
+            .map_err(|e| e.to_contract_error())?;
+        let amount = market.apply_outcome_cap(outcome, amount)?;
+        if BetManager::has_user_bet(env, market_id, user) {
+            return Err(Error::AlreadyBet);
+        }
+        Self::check_balance(env, user, amount)?;
+
+        Self::stake_deltas(&market, outcome, amount)
+    }
+
+    /// Dry run of `claim_winnings`
+    ///
+    /// Losing positions preview as a zero payout plus any loser rebate.
+    pub fn simulate_claim(
+        env: &Env,
+        user: &Address,
+        market_id: &Symbol,
+    ) -> Result<ClaimSimulation, Error> {
+        ContractPauseManager::require_not_paused(env)?;
+        if crate::tickets::ClaimTicketManager::payout_recipient(env, market_id, user) != *user {
+            return Err(Error::Unauthorized);
+        }
+        let market = MarketStateManager::get_market(env, market_id)?;
+
+        let claim_period =
+            crate::PredictifyHybrid::get_effective_claim_period(env.clone(), market_id.clone());
+        if env.ledger().timestamp() >= market.end_time.saturating_add(claim_period) {
+            return Err(Error::ResolutionTimeoutReached);
+        }
+        let entry = VoteLedger::get_entry(env, market_id, user);
+        if entry.as_ref().is_some_and(|entry| entry.claimed) {
+            return Err(Error::AlreadyClaimed);
+        }
+        let winning_outcomes = market
+            .winning_outcomes
+            .clone()
+            .ok_or(Error::MarketNotResolved)?;
+        crate::PredictifyHybrid::check_claim_lock(env, &market)?;
+        if market.get_winning_total() == 0 {
+            return Err(Error::NothingToClaim);
+        }
+        let entry = entry.ok_or(Error::NothingToClaim)?;
+
+        if !winning_outcomes.contains(&entry.outcome) {
+            return Ok(ClaimSimulation {
+                payout: 0,
+                fee: 0,
+                vests: false,
+                rebate: market.loser_rebate_for(entry.stake),
+            });
+        }
+
+        let weighted_stake = entry.stake * market.payout_weight(&entry.outcome);
+        let (payout, fee) = Self::split_payout(
+            &market,
+            weighted_stake,
+            market.total_staked,
+            market.weighted_winning_total(),
+        )?;
+        // The real claim holds absurd payouts back for recovery
+        if market.exceeds_max_payout(entry.stake, payout) {
+            return Err(Error::InvalidState);
+        }
+
+        Ok(ClaimSimulation {
+            payout,
+            fee,
+            vests: payout > VestingManager::get_policy(env).threshold,
+            rebate: 0,
+        })
+    }
+
+    fn check_balance(env: &Env, user: &Address, amount: i128) -> Result<(), Error> {
+        if MarketUtils::get_token_client(env)?.balance(user) < amount {
+            return Err(Error::InsufficientBalance);
+        }
+        Ok(())
+    }
+
+    fn stake_deltas(
+        market: &Market,
+        outcome: &String,
+        stake: i128,
+    ) -> Result<StakeSimulation, Error> {
+        let total_staked = market
+            .total_staked
+            .checked_add(stake)
+            .ok_or(Error::InvalidInput)?;
+        let outcome_total = market
+            .outcome_total(outcome)
+            .checked_add(stake)
+            .ok_or(Error::InvalidInput)?;
+        let implied_probability_bps = if total_staked > 0 {
+            outcome_total * 10_000 / total_staked
+        } else {
+            0
+        };
+        let (potential_payout, potential_fee) = if outcome_total > 0 {
+            Self::split_payout(market, stake, total_staked, outcome_total)?
+        } else {
+            (0, 0)
+        };
+
+        Ok(StakeSimulation {
+            stake,
+            total_staked,
+            outcome_total,
+            implied_probability_bps,
+            potential_payout,
+            potential_fee,
+        })
+    }
+
+    /// Net payout and fee for `stake`, using the claim formula
+    fn split_payout(
+        market: &Market,
+        stake: i128,
+        total_pool: i128,
+        winning_total: i128,
+    ) -> Result<(i128, i128), Error> {
+        let user_share = stake
+            .checked_mul(PERCENTAGE_DENOMINATOR - market.platform_fee_percentage)
+            .ok_or(Error::InvalidInput)?
+            / PERCENTAGE_DENOMINATOR;
+        let payout = user_share
+            .checked_mul(total_pool)
+            .ok_or(Error::InvalidInput)?
+            / winning_total;
+        let gross_payout =
+            stake.checked_mul(total_pool).ok_or(Error::InvalidInput)? / winning_total;
+        Ok((payout, gross_payout - payout))
+    }
+}
@@ -344,7 +344,7 @@ mod reporting_api_tests {
         env.as_contract(&contract_id, || {
             let user = Address::generate(&env);
             
-            let result = PredictifyHybrid::get_user_statistics(env.clone(), user);
+            let result = PredictifyHybrid::get_user_statistics(env.clone(), user.clone(), user);
             
             // Just verify we can call it
             let _ = result;
@@ -1,11 +1,16 @@
 #![allow(dead_code)]
 
 use crate::events::EventEmitter;
-use crate::types::{PlatformStatistics, UserStatistics};
+use crate::types::{
+    CreatorAnalytics, CreatorStatistics, DailySettlementStats, PlatformStatistics, UserStatistics,
+};
 use soroban_sdk::{symbol_short, Address, Env, Symbol};
 
 const PLATFORM_STATS_KEY: Symbol = symbol_short!("p_stats");
 const USER_STATS_PREFIX: Symbol = symbol_short!("u_stats");
+const CREATOR_STATS_PREFIX: Symbol = symbol_short!("c_stats");
+const DAILY_STATS_PREFIX: Symbol = symbol_short!("d_stats");
+const SECONDS_PER_DAY: u64 = 86_400;
 
 pub struct StatisticsManager;
 
@@ -156,10 +161,140 @@ impl StatisticsManager {
             .unwrap_or(p_stats.total_fees_collected);
         Self::set_platform_stats(env, &p_stats);
 
+        let mut d_stats = Self::get_daily_stats(env, Self::current_day(env));
+        d_stats.fees_collected = d_stats.fees_collected.saturating_add(amount);
+        Self::set_daily_stats(env, &d_stats);
+
         // We might not want to emit full update on every fee collection if it's frequent, but for now consistent behavior is good.
         Self::emit_update(env, &p_stats);
     }
 
+    /// Current day index
+    pub fn current_day(env: &Env) -> u64 {
+        env.ledger().timestamp() / SECONDS_PER_DAY
+    }
+
+    /// Get settlement statistics for a day, initializing if not present
+    pub fn get_daily_stats(env: &Env, day: u64) -> DailySettlementStats {
+        env.storage()
+            .persistent()
+            .get(&(DAILY_STATS_PREFIX, day))
+            .unwrap_or(DailySettlementStats {
+                day,
+                markets_resolved: 0,
+                volume_settled: 0,
+                fees_collected: 0,
+                disputes_opened: 0,
+            })
+    }
+
+    /// Set settlement statistics for a day
+    fn set_daily_stats(env: &Env, stats: &DailySettlementStats) {
+        env.storage()
+            .persistent()
+            .set(&(DAILY_STATS_PREFIX, stats.day), stats);
+    }
+
+    /// Record a market settled today with `volume` total stake
+    pub fn record_daily_market_settled(env: &Env, volume: i128) {
+        let mut stats = Self::get_daily_stats(env, Self::current_day(env));
+        stats.markets_resolved = stats.markets_resolved.saturating_add(1);
+        stats.volume_settled = stats.volume_settled.saturating_add(volume);
+        Self::set_daily_stats(env, &stats);
+    }
+
+    /// Record a dispute opened today
+    pub fn record_daily_dispute_opened(env: &Env) {
+        let mut stats = Self::get_daily_stats(env, Self::current_day(env));
+        stats.disputes_opened = stats.disputes_opened.saturating_add(1);
+        Self::set_daily_stats(env, &stats);
+    }
+
+    /// Get creator statistics, initializing if not present
+    pub fn get_creator_stats(env: &Env, creator: &Address) -> CreatorStatistics {
+        env.storage()
+            .persistent()
+            .get(&(CREATOR_STATS_PREFIX, creator.clone()))
+            .unwrap_or(CreatorStatistics {
+                markets_created: 0,
+                total_volume: 0,
+                fees_generated: 0,
+                fee_share_earned: 0,
+                markets_disputed: 0,
+                markets_invalidated: 0,
+            })
+    }
+
+    /// Set creator statistics
+    fn set_creator_stats(env: &Env, creator: &Address, stats: &CreatorStatistics) {
+        env.storage()
+            .persistent()
+            .set(&(CREATOR_STATS_PREFIX, creator.clone()), stats);
+    }
+
+    /// Get creator dashboard analytics derived from the creator's counters
+    pub fn get_creator_analytics(env: &Env, creator: &Address) -> CreatorAnalytics {
+        let stats = Self::get_creator_stats(env, creator);
+        let dispute_rate_bps = if stats.markets_created > 0 {
+            ((stats.markets_disputed as u64 * 10000) / stats.markets_created as u64) as u32
+        } else {
+            0
+        };
+
+        CreatorAnalytics {
+            creator: creator.clone(),
+            markets_created: stats.markets_created,
+            total_volume: stats.total_volume,
+            fees_generated: stats.fees_generated,
+            fee_share_earned: stats.fee_share_earned,
+            markets_disputed: stats.markets_disputed,
+            dispute_rate_bps,
+            invalidation_count: stats.markets_invalidated,
+        }
+    }
+
+    /// Record a market created by `creator`
+    pub fn record_creator_market_created(env: &Env, creator: &Address) {
+        let mut stats = Self::get_creator_stats(env, creator);
+        stats.markets_created = stats.markets_created.saturating_add(1);
+        Self::set_creator_stats(env, creator, &stats);
+    }
+
+    /// Record stake placed into one of `creator`'s markets
+    pub fn record_creator_volume(env: &Env, creator: &Address, amount: i128) {
+        let mut stats = Self::get_creator_stats(env, creator);
+        stats.total_volume = stats.total_volume.saturating_add(amount);
+        Self::set_creator_stats(env, creator, &stats);
+    }
+
+    /// Record platform fees collected from one of `creator`'s markets
+    pub fn record_creator_fees(env: &Env, creator: &Address, amount: i128) {
+        let mut stats = Self::get_creator_stats(env, creator);
+        stats.fees_generated = stats.fees_generated.saturating_add(amount);
+        Self::set_creator_stats(env, creator, &stats);
+    }
+
+    /// Record a fee share paid out to `creator`
+    pub fn record_creator_fee_share(env: &Env, creator: &Address, amount: i128) {
+        let mut stats = Self::get_creator_stats(env, creator);
+        stats.fee_share_earned = stats.fee_share_earned.saturating_add(amount);
+        Self::set_creator_stats(env, creator, &stats);
+    }
+
+    /// Record the first dispute on one of `creator`'s markets
+    pub fn record_creator_market_disputed(env: &Env, creator: &Address) {
+        let mut stats = Self::get_creator_stats(env, creator);
+        stats.markets_disputed = stats.markets_disputed.saturating_add(1);
+        Self::set_creator_stats(env, creator, &stats);
+    }
+
+    /// Record the cancellation of one of `creator`'s markets
+    pub fn record_creator_market_invalidated(env: &Env, creator: &Address) {
+        let mut stats = Self::get_creator_stats(env, creator);
+        stats.markets_invalidated = stats.markets_invalidated.saturating_add(1);
+        Self::set_creator_stats(env, creator, &stats);
+    }
+
     fn emit_update(env: &Env, stats: &PlatformStatistics) {
         EventEmitter::emit_statistics_updated(
             env,
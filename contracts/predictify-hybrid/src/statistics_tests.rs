@@ -126,3 +126,34 @@ fn test_record_fees_collected() {
         assert_eq!(stats.total_fees_collected, 800);
     });
 }
+
+#[test]
+fn test_creator_analytics() {
+    let (env, contract_id) = setup_env();
+    let creator = Address::generate(&env);
+
+    env.as_contract(&contract_id, || {
+        let empty = StatisticsManager::get_creator_analytics(&env, &creator);
+        assert_eq!(empty.markets_created, 0);
+        assert_eq!(empty.dispute_rate_bps, 0);
+
+        for _ in 0..4 {
+            StatisticsManager::record_creator_market_created(&env, &creator);
+        }
+        StatisticsManager::record_creator_volume(&env, &creator, 1_000);
+        StatisticsManager::record_creator_volume(&env, &creator, 500);
+        StatisticsManager::record_creator_fees(&env, &creator, 30);
+        StatisticsManager::record_creator_market_disputed(&env, &creator);
+        StatisticsManager::record_creator_market_invalidated(&env, &creator);
+
+        let analytics = StatisticsManager::get_creator_analytics(&env, &creator);
+        assert_eq!(analytics.creator, creator);
+        assert_eq!(analytics.markets_created, 4);
+        assert_eq!(analytics.total_volume, 1_500);
+        assert_eq!(analytics.fees_generated, 30);
+        assert_eq!(analytics.fee_share_earned, 0);
+        assert_eq!(analytics.markets_disputed, 1);
+        assert_eq!(analytics.dispute_rate_bps, 2500);
+        assert_eq!(analytics.invalidation_count, 1);
+    });
+}
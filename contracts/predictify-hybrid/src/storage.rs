@@ -3,7 +3,7 @@
 use super::*;
 use crate::markets::{MarketStateLogic, MarketStateManager};
 use crate::types::{Balance, ReflectorAsset};
-use soroban_sdk::{contracttype, Address, Env, IntoVal, Symbol, Val, Vec};
+use soroban_sdk::{contracttype, symbol_short, Address, Env, IntoVal, Symbol, Val, Vec};
 
 // ===== STORAGE OPTIMIZATION TYPES =====
 
@@ -232,6 +232,100 @@ impl StorageOptimizer {
         Ok(migration)
     }
 
+    /// Migrate a single market between the legacy (V1) and optimized (V2/V3)
+    /// layouts.
+    ///
+    /// Optimized markets keep a compressed, checksummed copy of their data
+    /// next to the live record; migrating back to V1 drops it. The result is
+    /// verified against the live market before the new format is recorded,
+    /// so markets can be migrated incrementally instead of all at once.
+    pub fn migrate_market(
+        env: &Env,
+        admin: &Address,
+        market_id: &Symbol,
+        to_format: StorageFormat,
+    ) -> Result<StorageMigration, Error> {
+        crate::recovery::RecoveryManager::assert_is_admin(env, admin)?;
+        let market = MarketStateManager::get_market(env, market_id)?;
+        let from_format = Self::get_market_format(env, market_id);
+        if from_format == to_format {
+            return Err(Error::InvalidInput);
+        }
+
+        let compressed_key = (symbol_short!("mkt_cmp"), market_id.clone());
+        if to_format == StorageFormat::V1 {
+            env.storage().persistent().remove(&compressed_key);
+        } else {
+            let mut compressed = Self::compress_market_data(env, &market)?;
+            compressed.market_id = market_id.clone();
+            env.storage().persistent().set(&compressed_key, &compressed);
+        }
+        Self::verify_market_migration(env, market_id, &market, &to_format)?;
+
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("mkt_fmt"), market_id.clone()), &to_format);
+        let now = env.ledger().timestamp();
+        let migration = StorageMigration {
+            migration_id: market_id.clone(),
+            from_format,
+            to_format,
+            markets_migrated: 1,
+            started_at: now,
+            completed_at: Some(now),
+            status: String::from_str(env, "completed"),
+            error_message: None,
+        };
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("mkt_migr"), market_id.clone()), &migration);
+
+        events::EventEmitter::emit_storage_optimization_event(
+            env,
+            market_id,
+            &String::from_str(env, "market_migrated"),
+        );
+        Ok(migration)
+    }
+
+    /// Storage format of a market (markets never migrated are V1)
+    pub fn get_market_format(env: &Env, market_id: &Symbol) -> StorageFormat {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("mkt_fmt"), market_id.clone()))
+            .unwrap_or(StorageFormat::V1)
+    }
+
+    /// Check that a migrated market still matches its live record
+    fn verify_market_migration(
+        env: &Env,
+        market_id: &Symbol,
+        market: &Market,
+        to_format: &StorageFormat,
+    ) -> Result<(), Error> {
+        let live = MarketStateManager::get_market(env, market_id)?;
+        if &live != market {
+            return Err(Error::InvalidState);
+        }
+        let compressed: Option<CompressedMarket> = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("mkt_cmp"), market_id.clone()));
+        match (to_format, compressed) {
+            (StorageFormat::V1, None) => Ok(()),
+            (StorageFormat::V1, Some(_)) | (_, None) => Err(Error::InvalidState),
+            (_, Some(compressed)) => {
+                let expected = Self::serialize_compressed_market(env, market)?;
+                if compressed.compressed_data != expected
+                    || compressed.checksum != Self::generate_checksum(&expected)
+                {
+                    return Err(Error::InvalidState);
+                }
+                Ok(())
+            }
+        }
+    }
+
     /// Monitor storage usage and return statistics
     pub fn monitor_storage_usage(env: &Env) -> Result<StorageUsageStats, Error> {
         let mut total_markets = 0;
@@ -411,6 +505,94 @@ impl StorageOptimizer {
         Ok(result)
     }
 
+    /// Check a stored market against invariants that hold at every point of
+    /// its lifecycle.
+    ///
+    /// Unlike `validate_storage_integrity`, this does not apply creation-time
+    /// rules (such as a future end time), so ended and settled markets audit
+    /// clean. Vote-change penalties stay in the pool, so the per-outcome
+    /// totals may sum to less than `total_staked` but never to more.
+    pub fn audit_market_integrity(env: &Env, market_id: &Symbol) -> StorageIntegrityResult {
+        let mut result = StorageIntegrityResult {
+            market_id: market_id.clone(),
+            is_valid: true,
+            corruption_detected: false,
+            missing_data: false,
+            checksum_valid: true,
+            errors: Vec::new(env),
+            warnings: Vec::new(env),
+        };
+        let fail = |result: &mut StorageIntegrityResult, message: &str| {
+            result.is_valid = false;
+            result.corruption_detected = true;
+            result.errors.push_back(String::from_str(env, message));
+        };
+
+        let market = match MarketStateManager::get_market(env, market_id) {
+            Ok(market) => market,
+            Err(_) => {
+                result.is_valid = false;
+                result.missing_data = true;
+                result
+                    .errors
+                    .push_back(String::from_str(env, "Market not found"));
+                return result;
+            }
+        };
+
+        if market.question.is_empty() || market.outcomes.len() < 2 {
+            result.missing_data = true;
+            fail(&mut result, "Missing question or outcomes");
+        }
+
+        let mut outcome_sum: i128 = 0;
+        for (outcome, total) in market.outcome_totals.iter() {
+            if total < 0 || !market.outcomes.contains(&outcome) {
+                fail(&mut result, "Invalid outcome total");
+            }
+            outcome_sum = outcome_sum.saturating_add(total);
+        }
+        if market.total_staked < 0 || outcome_sum > market.total_staked {
+            fail(&mut result, "Outcome totals exceed total staked");
+        }
+        if market.claimed_count > market.voter_count {
+            fail(&mut result, "More claims than voters");
+        }
+
+        match &market.winning_outcomes {
+            Some(winners) => {
+                if winners.is_empty() || winners.iter().any(|w| !market.outcomes.contains(&w)) {
+                    fail(&mut result, "Winning outcome not in outcome set");
+                }
+                if market.state == MarketState::Active {
+                    fail(&mut result, "Active market has winning outcomes");
+                }
+            }
+            None => {
+                if market.state == MarketState::Resolved {
+                    fail(&mut result, "Resolved market has no winning outcome");
+                }
+            }
+        }
+
+        // Migrated markets must keep an intact compressed copy
+        if Self::get_market_format(env, market_id) != StorageFormat::V1 {
+            let compressed: Option<CompressedMarket> = env
+                .storage()
+                .persistent()
+                .get(&(symbol_short!("mkt_cmp"), market_id.clone()));
+            let intact = compressed.is_some_and(|compressed| {
+                compressed.checksum == Self::generate_checksum(&compressed.compressed_data)
+            });
+            if !intact {
+                result.checksum_valid = false;
+                fail(&mut result, "Checksum validation failed");
+            }
+        }
+
+        result
+    }
+
     /// Get storage configuration
     pub fn get_storage_config(env: &Env) -> StorageConfig {
         match env
@@ -531,10 +713,9 @@ impl StorageOptimizer {
         let base_size = 100; // Base overhead
         let question_size = market.question.len() as u32;
         let outcomes_size = market.outcomes.len() as u32 * 50; // Average outcome size
-        let votes_size = market.votes.len() as u32 * 100; // Average vote entry size
-        let stakes_size = market.stakes.len() as u32 * 50; // Average stake entry size
+        let aggregates_size = market.outcome_totals.len() * 50; // Votes live in the vote ledger
 
-        base_size + question_size + outcomes_size + votes_size + stakes_size
+        base_size + question_size + outcomes_size + aggregates_size
     }
 
     /// Generate checksum for data integrity
@@ -644,6 +825,196 @@ impl StorageOptimizer {
     }
 }
 
+// ===== VOTE LEDGER =====
+
+/// A user's position in a market.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoteEntry {
+    /// Outcome the user backs
+    pub outcome: String,
+    /// Amount staked on that outcome
+    pub stake: i128,
+    /// Whether the user's payout has been settled
+    pub claimed: bool,
+}
+
+/// Per-user vote entries stored under `(market_id, user)` keys.
+///
+/// Keeping positions out of `Market` means a vote or claim writes only the
+/// user's own entry plus the market's aggregates, and the market entry no
+/// longer grows with its voter count. Voters are enumerable through an index
+/// keyed by the order in which they joined the market, and each user's
+/// markets through an index keyed by the order in which they joined them.
+pub struct VoteLedger;
+
+impl VoteLedger {
+    fn entry_key(market_id: &Symbol, user: &Address) -> (Symbol, Symbol, Address) {
+        (symbol_short!("vote_ent"), market_id.clone(), user.clone())
+    }
+
+    fn voter_key(market_id: &Symbol, index: u32) -> (Symbol, Symbol, u32) {
+        (symbol_short!("voter"), market_id.clone(), index)
+    }
+
+    fn claimant_key(market_id: &Symbol, index: u32) -> (Symbol, Symbol, u32) {
+        (symbol_short!("claimant"), market_id.clone(), index)
+    }
+
+    fn user_market_key(user: &Address, index: u32) -> (Symbol, Address, u32) {
+        (symbol_short!("usr_mkt"), user.clone(), index)
+    }
+
+    fn user_market_count_key(user: &Address) -> (Symbol, Address) {
+        (symbol_short!("usr_mcnt"), user.clone())
+    }
+
+    /// Get a user's position in a market
+    pub fn get_entry(env: &Env, market_id: &Symbol, user: &Address) -> Option<VoteEntry> {
+        env.storage()
+            .persistent()
+            .get(&Self::entry_key(market_id, user))
+    }
+
+    /// Outcome a user backs, if they voted
+    pub fn get_vote(env: &Env, market_id: &Symbol, user: &Address) -> Option<String> {
+        Self::get_entry(env, market_id, user).map(|entry| entry.outcome)
+    }
+
+    /// A user's stake (0 if they did not vote)
+    pub fn get_stake(env: &Env, market_id: &Symbol, user: &Address) -> i128 {
+        Self::get_entry(env, market_id, user).map_or(0, |entry| entry.stake)
+    }
+
+    /// Whether a user's payout has been settled
+    pub fn has_claimed(env: &Env, market_id: &Symbol, user: &Address) -> bool {
+        Self::get_entry(env, market_id, user).is_some_and(|entry| entry.claimed)
+    }
+
+    /// Record a user's position, replacing any previous one.
+    ///
+    /// Keeps the market's voter count and per-outcome aggregates in step;
+    /// the caller persists `market`.
+    pub fn set_position(
+        env: &Env,
+        market_id: &Symbol,
+        market: &mut Market,
+        user: &Address,
+        outcome: &String,
+        stake: i128,
+    ) {
+        let claimed = match Self::get_entry(env, market_id, user) {
+            Some(previous) => {
+                market.remove_outcome_position(&previous.outcome, previous.stake);
+                previous.claimed
+            }
+            None => {
+                env.storage()
+                    .persistent()
+                    .set(&Self::voter_key(market_id, market.voter_count), user);
+                market.voter_count += 1;
+                let joined = Self::get_user_market_count(env, user);
+                env.storage()
+                    .persistent()
+                    .set(&Self::user_market_key(user, joined), market_id);
+                env.storage()
+                    .persistent()
+                    .set(&Self::user_market_count_key(user), &(joined + 1));
+                false
+            }
+        };
+        market.add_outcome_position(outcome, stake);
+
+        let entry = VoteEntry {
+            outcome: outcome.clone(),
+            stake,
+            claimed,
+        };
+        env.storage()
+            .persistent()
+            .set(&Self::entry_key(market_id, user), &entry);
+    }
+
+    /// Mark a voter's payout as settled; the caller persists `market`.
+    ///
+    /// Users without a position have nothing to settle and are ignored.
+    /// Claimants are indexed in settlement order.
+    pub fn mark_claimed(env: &Env, market_id: &Symbol, market: &mut Market, user: &Address) {
+        let Some(mut entry) = Self::get_entry(env, market_id, user) else {
+            return;
+        };
+        if entry.claimed {
+            return;
+        }
+        entry.claimed = true;
+        env.storage()
+            .persistent()
+            .set(&Self::claimant_key(market_id, market.claimed_count), user);
+        market.claimed_count += 1;
+        env.storage()
+            .persistent()
+            .set(&Self::entry_key(market_id, user), &entry);
+    }
+
+    /// Voter at `index` in join order
+    pub fn get_voter(env: &Env, market_id: &Symbol, index: u32) -> Option<Address> {
+        env.storage()
+            .persistent()
+            .get(&Self::voter_key(market_id, index))
+    }
+
+    /// Claimant at `index` in settlement order
+    pub fn get_claimant(env: &Env, market_id: &Symbol, index: u32) -> Option<Address> {
+        env.storage()
+            .persistent()
+            .get(&Self::claimant_key(market_id, index))
+    }
+
+    /// Number of markets a user has taken a position in
+    pub fn get_user_market_count(env: &Env, user: &Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&Self::user_market_count_key(user))
+            .unwrap_or(0)
+    }
+
+    /// Market a user joined at `index`, in join order
+    pub fn get_user_market(env: &Env, user: &Address, index: u32) -> Option<Symbol> {
+        env.storage()
+            .persistent()
+            .get(&Self::user_market_key(user, index))
+    }
+
+    /// Every voter of a market, in join order.
+    ///
+    /// Costs one read per voter; prefer the market aggregates or
+    /// `get_voter` paging where possible.
+    pub fn get_voters(env: &Env, market_id: &Symbol, market: &Market) -> Vec<Address> {
+        let mut voters = Vec::new(env);
+        for index in 0..market.voter_count {
+            if let Some(voter) = Self::get_voter(env, market_id, index) {
+                voters.push_back(voter);
+            }
+        }
+        voters
+    }
+
+    /// Every voter of a market with their position, in join order
+    pub fn get_entries(
+        env: &Env,
+        market_id: &Symbol,
+        market: &Market,
+    ) -> Vec<(Address, VoteEntry)> {
+        let mut entries = Vec::new(env);
+        for voter in Self::get_voters(env, market_id, market).iter() {
+            if let Some(entry) = Self::get_entry(env, market_id, &voter) {
+                entries.push_back((voter, entry));
+            }
+        }
+        entries
+    }
+}
+
 // ===== EVENT STORAGE =====
 
 /// Manager for event storage operations
@@ -762,7 +1133,7 @@ impl StorageUtils {
             ));
         }
 
-        if market.votes.len() > 1000 {
+        if market.voter_count > 1000 {
             recommendations.push_back(String::from_str(
                 &market.question.env(),
                 "High vote count - consider vote aggregation",
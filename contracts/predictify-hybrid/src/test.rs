@@ -167,6 +167,13 @@ impl PredictifyTest {
             &None,
         )
     }
+
+    // Helper function to read a user's claim flag from the vote ledger
+    pub fn has_claimed(&self, market_id: &Symbol, user: &Address) -> bool {
+        PredictifyHybridClient::new(&self.env, &self.contract_id)
+            .get_vote_entry(market_id, user)
+            .is_some_and(|entry| entry.claimed)
+    }
 }
 
 // Core functionality tests
@@ -268,7 +275,9 @@ fn test_successful_vote() {
             .unwrap()
     });
 
-    assert!(market.votes.contains_key(test.user.clone()));
+    assert!(test.env.as_contract(&test.contract_id, || {
+        VoteLedger::get_entry(&test.env, &market_id, &test.user).is_some()
+    }));
     assert_eq!(market.total_staked, 1_0000000);
 }
 
@@ -524,13 +533,14 @@ fn test_voting_data_integrity() {
     });
 
     // Verify voting data integrity
-    assert!(market.votes.contains_key(test.user.clone()));
-    let user_vote = market.votes.get(test.user.clone()).unwrap();
-    assert_eq!(user_vote, String::from_str(&test.env, "yes"));
-
-    assert!(market.stakes.contains_key(test.user.clone()));
-    let user_stake = market.stakes.get(test.user.clone()).unwrap();
-    assert_eq!(user_stake, 1_0000000);
+    let entry = test.env.as_contract(&test.contract_id, || {
+        VoteLedger::get_entry(&test.env, &market_id, &test.user)
+    });
+    let entry = entry.expect("vote should be in the ledger");
+    assert_eq!(entry.outcome, String::from_str(&test.env, "yes"));
+    assert_eq!(entry.stake, 1_0000000);
+    assert!(!entry.claimed);
+    assert_eq!(market.voter_count, 1);
     assert_eq!(market.total_staked, 1_0000000);
 }
 
@@ -883,13 +893,14 @@ fn test_oracle_factory_supported_providers() {
         &OracleProvider::Reflector
     ));
 
-    // Test unsupported providers
-    assert!(!crate::oracles::OracleFactory::is_provider_supported(
-        &OracleProvider::Pyth
-    ));
-    assert!(!crate::oracles::OracleFactory::is_provider_supported(
+    assert!(crate::oracles::OracleFactory::is_provider_supported(
         &OracleProvider::BandProtocol
     ));
+    assert!(crate::oracles::OracleFactory::is_provider_supported(
+        &OracleProvider::Pyth
+    ));
+
+    // Test unsupported providers
     assert!(!crate::oracles::OracleFactory::is_provider_supported(
         &OracleProvider::DIA
     ));
@@ -908,7 +919,7 @@ fn test_oracle_factory_creation() {
     assert!(result.is_ok());
 
     // Test failed creation
-    let result = crate::oracles::OracleFactory::create_oracle(OracleProvider::Pyth, contract_id);
+    let result = crate::oracles::OracleFactory::create_oracle(OracleProvider::DIA, contract_id);
     assert!(result.is_err());
     assert_eq!(result.unwrap_err(), Error::InvalidOracleConfig);
 }
@@ -971,7 +982,10 @@ fn test_unpause_restores_operations() {
             .get::<Symbol, Market>(&market_id)
             .unwrap()
     });
-    assert!(market.votes.contains_key(user.clone()));
+    assert_eq!(market.voter_count, 1);
+    assert!(test.env.as_contract(&test.contract_id, || {
+        VoteLedger::get_entry(&test.env, &market_id, &user).is_some()
+    }));
 }
 
 #[test]
@@ -1424,9 +1438,9 @@ fn test_automatic_payout_distribution() {
             .unwrap()
     });
     assert_eq!(market_after.state, MarketState::Resolved);
-    assert!(market_after.claimed.get(user1.clone()).unwrap_or(false));
-    assert!(market_after.claimed.get(user2.clone()).unwrap_or(false));
-    assert!(!market_after.claimed.get(user3.clone()).unwrap_or(false)); // Loser not claimed
+    assert!(test.has_claimed(&market_id, &user1));
+    assert!(test.has_claimed(&market_id, &user2));
+    assert!(!test.has_claimed(&market_id, &user3)); // Loser not claimed
 }
 
 #[test]
@@ -2585,7 +2599,7 @@ fn test_manual_dispute_resolution_triggers_payout() {
             .unwrap()
     });
     assert_eq!(market_after.state, MarketState::Resolved);
-    assert!(market_after.claimed.get(user1.clone()).unwrap_or(false));
+    assert!(test.has_claimed(&market_id, &user1));
 }
 
 // ===== PAYOUT DISTRIBUTION TESTS =====
@@ -2719,7 +2733,7 @@ fn test_claim_winnings_successful() {
             .unwrap()
     });
     assert_eq!(market.state, MarketState::Resolved);
-    assert!(market.claimed.get(test.user.clone()).unwrap_or(false));
+    assert!(test.has_claimed(&market_id, &test.user));
 }
 
 #[test]
@@ -2764,6 +2778,18 @@ fn test_double_claim_prevention() {
     test.env.mock_all_auths();
     client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
 
+    // Claims unlock once the challenge window has closed
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + market.dispute_window_seconds,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+
     // 4. First claim
     test.env.mock_all_auths();
     client.claim_winnings(&test.user, &market_id);
@@ -2788,6 +2814,16 @@ fn test_claim_by_loser() {
         &100_0000000,
     );
 
+    // Someone backs the winning side so the pool has a destination
+    let winner = test.create_funded_user();
+    test.env.mock_all_auths();
+    client.vote(
+        &winner,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &100_0000000,
+    );
+
     // 2. Advance time
     let market = test.env.as_contract(&test.contract_id, || {
         test.env
@@ -2812,6 +2848,18 @@ fn test_claim_by_loser() {
     test.env.mock_all_auths();
     client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
 
+    // Claims unlock once the challenge window has closed
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + market.dispute_window_seconds,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+
     // 4. Loser claims - should complete without panic but receive 0 (or minimal) and be marked claimed
     test.env.mock_all_auths();
     client.claim_winnings(&test.user, &market_id);
@@ -2824,7 +2872,138 @@ fn test_claim_by_loser() {
             .get::<Symbol, Market>(&market_id)
             .unwrap()
     });
-    assert!(market_after.claimed.get(test.user.clone()).unwrap_or(false));
+    assert!(test.has_claimed(&market_id, &test.user));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #205)")] // MarketNotReady = 205
+fn test_claim_locked_during_challenge_window() {
+    let test = PredictifyTest::setup();
+    let market_id = test.create_test_market();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+
+    test.env.mock_all_auths();
+    client.vote(
+        &test.user,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &100_0000000,
+    );
+
+    let market = client.get_market(&market_id).unwrap();
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + 1,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+
+    test.env.mock_all_auths();
+    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+
+    // Resolved, but the dispute window is still open
+    test.env.mock_all_auths();
+    client.claim_winnings(&test.user, &market_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #404)")] // AlreadyDisputed = 404
+fn test_claim_locked_while_dispute_active() {
+    let test = PredictifyTest::setup();
+    let market_id = test.create_test_market();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+
+    test.env.mock_all_auths();
+    client.vote(
+        &test.user,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &100_0000000,
+    );
+
+    resolve_market_without_distribution(&test, &market_id, "yes");
+
+    // A dispute is opened against the result
+    test.env.as_contract(&test.contract_id, || {
+        let mut market = test
+            .env
+            .storage()
+            .persistent()
+            .get::<Symbol, Market>(&market_id)
+            .unwrap();
+        market.state = MarketState::Disputed;
+        test.env.storage().persistent().set(&market_id, &market);
+    });
+
+    test.env.mock_all_auths();
+    client.claim_winnings(&test.user, &market_id);
+}
+
+#[test]
+fn test_winning_totals_precomputed_at_resolution() {
+    let test = PredictifyTest::setup();
+    let market_id = test.create_test_market();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let user2 = test.create_funded_user();
+    let user3 = test.create_funded_user();
+
+    test.env.mock_all_auths();
+    client.vote(
+        &test.user,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &10_0000000,
+    );
+    client.vote(
+        &user2,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &30_0000000,
+    );
+    client.vote(
+        &user3,
+        &market_id,
+        &String::from_str(&test.env, "no"),
+        &20_0000000,
+    );
+
+    // Per-outcome totals track votes live; the winning total waits for resolution
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(
+        market.outcome_total(&String::from_str(&test.env, "yes")),
+        40_0000000
+    );
+    assert_eq!(market.winning_total, 0);
+
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + 1,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(
+        market
+            .outcome_totals
+            .get(String::from_str(&test.env, "yes")),
+        Some(40_0000000)
+    );
+    assert_eq!(
+        market.outcome_totals.get(String::from_str(&test.env, "no")),
+        Some(20_0000000)
+    );
+    assert_eq!(market.winning_total, 40_0000000);
+    assert_eq!(market.get_winning_total(), 40_0000000);
 }
 
 fn resolve_market_without_distribution(
@@ -2843,6 +3022,31 @@ fn resolve_market_without_distribution(
         market.winning_outcomes = Some(winners);
         market.state = MarketState::Resolved;
         test.env.storage().persistent().set(market_id, &market);
+    });
+
+    // Move past the challenge window so claims are unlocked
+    let market = test.env.as_contract(&test.contract_id, || {
+        test.env
+            .storage()
+            .persistent()
+            .get::<Symbol, Market>(market_id)
+            .unwrap()
+    });
+    let unlock_time = market.end_time + market.dispute_window_seconds;
+    if test.env.ledger().timestamp() < unlock_time {
+        test.env.ledger().set(LedgerInfo {
+            timestamp: unlock_time,
+            protocol_version: 22,
+            sequence_number: test.env.ledger().sequence(),
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 1,
+            min_persistent_entry_ttl: 1,
+            max_entry_ttl: 10000,
+        });
+    }
+}
+
 // ===== BATCH CLAIM WINNINGS TESTS =====
 
 #[test]
@@ -2953,7 +3157,7 @@ fn test_claim_winnings_batch_successful() {
             .get::<Symbol, Market>(&market_id_1)
             .unwrap()
     });
-    assert!(market_1.claimed.get(test.user.clone()).unwrap_or(false));
+    assert!(test.has_claimed(&market_id_1, &test.user));
 
     let market_2 = test.env.as_contract(&test.contract_id, || {
         test.env
@@ -2962,7 +3166,7 @@ fn test_claim_winnings_batch_successful() {
             .get::<Symbol, Market>(&market_id_2)
             .unwrap()
     });
-    assert!(market_2.claimed.get(test.user.clone()).unwrap_or(false));
+    assert!(test.has_claimed(&market_id_2, &test.user));
 
     let market_3 = test.env.as_contract(&test.contract_id, || {
         test.env
@@ -2971,7 +3175,7 @@ fn test_claim_winnings_batch_successful() {
             .get::<Symbol, Market>(&market_id_3)
             .unwrap()
     });
-    assert!(market_3.claimed.get(test.user.clone()).unwrap_or(false));
+    assert!(test.has_claimed(&market_id_3, &test.user));
 }
 
 #[test]
@@ -3236,7 +3440,7 @@ fn test_batch_claim_partial_winners_losers() {
             .get::<Symbol, Market>(&market_id_1)
             .unwrap()
     });
-    assert!(m1.claimed.get(test.user.clone()).unwrap_or(false));
+    assert!(test.has_claimed(&market_id_1, &test.user));
 }
 
 #[test]
@@ -3298,72 +3502,347 @@ fn test_batch_claim_atomicity_revert_on_second_market_failure() {
             .get::<Symbol, Market>(&market_id_1)
             .unwrap()
     });
-    assert!(m1_after.claimed.get(test.user.clone()).unwrap_or(false));
+    assert!(test.has_claimed(&market_id_1, &test.user));
 }
 
-// ===== MINIMUM POOL SIZE TESTS =====
-
 #[test]
-fn test_create_market_without_min_pool_size() {
+fn test_distribute_winnings_pays_winners_and_skips_others() {
     let test = PredictifyTest::setup();
     let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
-    let outcomes = vec![
+    let market_id = test.create_test_market();
+    let winner2 = test.create_funded_user();
+    let loser = test.create_funded_user();
+
+    test.env.mock_all_auths();
+    client.vote(
+        &test.user,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &100_0000000,
+    );
+    client.vote(
+        &winner2,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &50_0000000,
+    );
+    client.vote(
+        &loser,
+        &market_id,
+        &String::from_str(&test.env, "no"),
+        &50_0000000,
+    );
+
+    resolve_market_without_distribution(&test, &market_id, "yes");
+
+    let users = vec![
         &test.env,
-        String::from_str(&test.env, "yes"),
-        String::from_str(&test.env, "no"),
+        test.user.clone(),
+        winner2.clone(),
+        loser.clone(),
+        test.user.clone(),
     ];
+    test.env.mock_all_auths();
+    let paid = client.distribute_winnings(&test.admin, &market_id, &users);
+    assert_eq!(paid, 2);
+
+    let market = client.get_market(&market_id).unwrap();
+    assert!(test.has_claimed(&market_id, &test.user));
+    assert!(test.has_claimed(&market_id, &winner2));
+    assert!(!test.has_claimed(&market_id, &loser));
 
+    // Resubmitting the same list is a no-op
     test.env.mock_all_auths();
-    let market_id = client.create_market(
-        &test.admin,
-        &String::from_str(&test.env, "Will BTC hit $100k?"),
-        &outcomes,
-        &30,
-        &OracleConfig {
-            provider: OracleProvider::Reflector,
-            oracle_address: Address::generate(&test.env),
-            feed_id: String::from_str(&test.env, "BTC"),
-            threshold: 10000000,
-            comparison: String::from_str(&test.env, "gt"),
-        },
-        &None,
-        &0,
-        &None,
-        &None,
-        &None,
+    assert_eq!(
+        client.distribute_winnings(&test.admin, &market_id, &users),
+        0
     );
+}
 
-    let market = test.env.as_contract(&test.contract_id, || {
-        test.env
-            .storage()
-            .persistent()
-            .get::<Symbol, Market>(&market_id)
-            .unwrap()
-    });
+#[test]
+fn test_distribute_winnings_counts_only_credited_payouts() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let yes = String::from_str(&test.env, "yes");
+    let rival = test.create_funded_user();
+    let buyer = Address::generate(&test.env);
+    test.env.mock_all_auths();
 
-    assert_eq!(market.min_pool_size, None);
-}
+    let token_id = register_position_token(&test, &test.contract_id);
+    client.set_position_token(&test.admin, &market_id, &yes, &token_id);
+    client.vote(&test.user, &market_id, &yes, &30_0000000);
+    client.vote(
+        &rival,
+        &market_id,
+        &String::from_str(&test.env, "no"),
+        &70_0000000,
+    );
 
-// ===== CONTRACT UPGRADE AND MIGRATION TESTS (#304) =====
+    // The whole winning position was sold on as tokens
+    client.tokenize_position(&test.user, &market_id, &30_0000000);
+    TokenClient::new(&test.env, &token_id).transfer(&test.user, &buyer, &30_0000000);
 
-// --- 1. Admin-only upgrade tests ---
+    resolve_market_without_distribution(&test, &market_id, "yes");
+
+    let users = vec![&test.env, test.user.clone()];
+    assert_eq!(
+        client.distribute_winnings(&test.admin, &market_id, &users),
+        0
+    );
+    assert_eq!(
+        client
+            .get_balance(&test.user, &ReflectorAsset::Stellar)
+            .amount,
+        0
+    );
+}
 
 #[test]
-fn test_upgrade_admin_only_authorization() {
-    let env = Env::default();
-    env.mock_all_auths();
-    let admin = Address::generate(&env);
-    let contract_id = env.register_contract(None, PredictifyHybrid);
+#[should_panic(expected = "Error(Contract, #100)")] // Unauthorized = 100
+fn test_distribute_winnings_requires_admin() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
 
-    // Set admin in storage (as done by initialize)
-    env.as_contract(&contract_id, || {
-        env.storage()
-            .instance()
-            .set(&Symbol::new(&env, "admin"), &admin);
-    });
+    test.env.mock_all_auths();
+    client.vote(
+        &test.user,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &100_0000000,
+    );
+    resolve_market_without_distribution(&test, &market_id, "yes");
 
-    env.as_contract(&contract_id, || {
-        // Initialize version so upgrade_contract can read it
+    test.env.mock_all_auths();
+    client.distribute_winnings(&test.user, &market_id, &vec![&test.env, test.user.clone()]);
+}
+
+#[test]
+fn test_distribute_winnings_by_sweeper_keeper() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let keeper = test.create_funded_user();
+
+    test.env.mock_all_auths();
+    client.vote(
+        &test.user,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &100_0000000,
+    );
+    resolve_market_without_distribution(&test, &market_id, "yes");
+
+    // Keepers registered for other roles cannot distribute
+    test.env.mock_all_auths();
+    client.register_keeper(
+        &keeper,
+        &vec![&test.env, crate::keepers::KeeperRole::Resolver],
+        &crate::config::MIN_KEEPER_STAKE,
+    );
+    let users = vec![&test.env, test.user.clone()];
+    assert!(client
+        .try_distribute_winnings(&keeper, &market_id, &users)
+        .is_err());
+
+    client.deregister_keeper(&keeper);
+    client.register_keeper(
+        &keeper,
+        &vec![&test.env, crate::keepers::KeeperRole::Sweeper],
+        &crate::config::MIN_KEEPER_STAKE,
+    );
+    assert_eq!(client.distribute_winnings(&keeper, &market_id, &users), 1);
+}
+
+/// Creates a market where every stake backs "yes" and resolves it to "no",
+/// then moves past the challenge window.
+fn setup_zero_winner_market(test: &PredictifyTest) -> (Symbol, Address) {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let user2 = test.create_funded_user();
+
+    test.env.mock_all_auths();
+    client.vote(
+        &test.user,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &100_0000000,
+    );
+    client.vote(
+        &user2,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &50_0000000,
+    );
+
+    let market = client.get_market(&market_id).unwrap();
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + market.dispute_window_seconds,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "no"));
+
+    (market_id, user2)
+}
+
+#[test]
+fn test_zero_winner_market_refunds_minus_fee_by_default() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let (market_id, user2) = setup_zero_winner_market(&test);
+
+    assert_eq!(
+        client.get_zero_winner_policy(),
+        ZeroWinnerPolicy::RefundMinusFee
+    );
+
+    // The fee is taken once, by the settlement
+    assert_eq!(client.get_treasury_balance().balance, 0);
+    assert_eq!(
+        client.try_collect_fees(&test.admin, &market_id),
+        Err(Ok(Error::NoFeesToCollect))
+    );
+
+    test.env.mock_all_auths();
+    let credited = client.settle_zero_winner_market(&test.admin, &market_id);
+    // 150 XLM pool minus the 2% platform fee
+    assert_eq!(credited, 147_0000000);
+
+    let settlement = client.get_zero_winner_settlement(&market_id).unwrap();
+    assert_eq!(settlement.policy, ZeroWinnerPolicy::RefundMinusFee);
+    assert_eq!(settlement.refunded_total, 147_0000000);
+    assert_eq!(settlement.fee_total, 3_0000000);
+    assert_eq!(settlement.treasury_total, 0);
+    assert_eq!(settlement.participants, 2);
+
+    let balance = client.get_balance(&user2, &ReflectorAsset::Stellar);
+    assert_eq!(balance.amount, 49_0000000);
+
+    let market = client.get_market(&market_id).unwrap();
+    assert!(test.has_claimed(&market_id, &test.user));
+    assert!(test.has_claimed(&market_id, &user2));
+}
+
+#[test]
+fn test_zero_winner_market_treasury_policy() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let treasury = Address::generate(&test.env);
+
+    test.env.mock_all_auths();
+    client.set_treasury(&test.admin, &treasury);
+    client.set_zero_winner_policy(&test.admin, &ZeroWinnerPolicy::Treasury);
+
+    let (market_id, _) = setup_zero_winner_market(&test);
+    // Resolution leaves the fee to the settlement
+    assert!(!client.get_market(&market_id).unwrap().fee_collected);
+    assert_eq!(client.get_treasury_balance().balance, 0);
+
+    test.env.mock_all_auths();
+    let credited = client.settle_zero_winner_market(&treasury, &market_id);
+    assert_eq!(credited, 150_0000000);
+    assert_eq!(
+        TokenClient::new(&test.env, &test.token_test.token_id).balance(&test.contract_id)
+            - client.get_treasury_balance().balance
+            - credited,
+        0
+    );
+
+    let settlement = client.get_zero_winner_settlement(&market_id).unwrap();
+    assert_eq!(settlement.treasury_total, 150_0000000);
+    assert_eq!(settlement.refunded_total, 0);
+
+    let balance = client.get_balance(&treasury, &ReflectorAsset::Stellar);
+    assert_eq!(balance.amount, 150_0000000);
+
+    // A settled market cannot be settled again
+    test.env.mock_all_auths();
+    assert!(client
+        .try_settle_zero_winner_market(&test.admin, &market_id)
+        .is_err());
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #105)")] // NothingToClaim = 105
+fn test_claim_rejected_on_zero_winner_market() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let (market_id, _) = setup_zero_winner_market(&test);
+
+    test.env.mock_all_auths();
+    client.claim_winnings(&test.user, &market_id);
+}
+
+// ===== MINIMUM POOL SIZE TESTS =====
+
+#[test]
+fn test_create_market_without_min_pool_size() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let outcomes = vec![
+        &test.env,
+        String::from_str(&test.env, "yes"),
+        String::from_str(&test.env, "no"),
+    ];
+
+    test.env.mock_all_auths();
+    let market_id = client.create_market(
+        &test.admin,
+        &String::from_str(&test.env, "Will BTC hit $100k?"),
+        &outcomes,
+        &30,
+        &OracleConfig {
+            provider: OracleProvider::Reflector,
+            oracle_address: Address::generate(&test.env),
+            feed_id: String::from_str(&test.env, "BTC"),
+            threshold: 10000000,
+            comparison: String::from_str(&test.env, "gt"),
+        },
+        &None,
+        &0,
+        &None,
+        &None,
+        &None,
+    );
+
+    let market = test.env.as_contract(&test.contract_id, || {
+        test.env
+            .storage()
+            .persistent()
+            .get::<Symbol, Market>(&market_id)
+            .unwrap()
+    });
+
+    assert_eq!(market.min_pool_size, None);
+}
+
+// ===== CONTRACT UPGRADE AND MIGRATION TESTS (#304) =====
+
+// --- 1. Admin-only upgrade tests ---
+
+#[test]
+fn test_upgrade_admin_only_authorization() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let contract_id = env.register_contract(None, PredictifyHybrid);
+
+    // Set admin in storage (as done by initialize)
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "admin"), &admin);
+    });
+
+    env.as_contract(&contract_id, || {
+        // Initialize version so upgrade_contract can read it
         let vm = crate::versioning::VersionManager::new(&env);
         let v = crate::versioning::Version::new(
             &env,
@@ -3466,8 +3945,8 @@ fn test_batch_claim_winnings_all_succeed_in_one_batch() {
 
     let market_a_after = client.get_market(&market_a).unwrap();
     let market_b_after = client.get_market(&market_b).unwrap();
-    assert!(market_a_after.claimed.get(test.user.clone()).unwrap_or(false));
-    assert!(market_b_after.claimed.get(test.user.clone()).unwrap_or(false));
+    assert!(test.has_claimed(&market_a, &test.user));
+    assert!(test.has_claimed(&market_b, &test.user));
 }
 
 #[test]
@@ -3504,7 +3983,7 @@ fn test_batch_claim_winnings_atomic_revert_when_one_claim_invalid() {
 
     // Ensure successful claim in the same batch was rolled back.
     let market_after = client.get_market(&valid_market).unwrap();
-    assert!(!market_after.claimed.get(test.user.clone()).unwrap_or(false));
+    assert!(!test.has_claimed(&valid_market, &test.user));
 }
 
 #[test]
@@ -4019,7 +4498,7 @@ fn test_cancel_underfunded_event_uses_global_min_pool_when_market_min_not_set()
     test.env.mock_all_auths();
     let refunded = client.cancel_underfunded_event(&test.admin, &market_id);
     assert_eq!(refunded, 100_0000000);
-
+    
     let pool_lo_event = test.env.as_contract(&test.contract_id, || {
         test.env
             .storage()
@@ -4038,7 +4517,7 @@ fn test_cancel_underfunded_event_uses_global_min_pool_when_market_min_not_set()
 fn test_create_market_with_min_pool_size() {
     let test = PredictifyTest::setup();
     let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
-    
+
     // Create market with 3 outcomes
     let outcomes = vec![
         &test.env,
@@ -4121,12 +4600,12 @@ fn test_create_market_with_min_pool_size() {
     let market_after = test.env.as_contract(&test.contract_id, || {
         test.env.storage().persistent().get::<Symbol, Market>(&market_id).unwrap()
     });
-
+    
     // All users should be marked as claimed
-    assert!(market_after.claimed.get(user1.clone()).unwrap_or(false));
-    assert!(market_after.claimed.get(user2.clone()).unwrap_or(false));
-    assert!(market_after.claimed.get(user3.clone()).unwrap_or(false));
-    assert!(market_after.claimed.get(user4.clone()).unwrap_or(false));
+    assert!(test.has_claimed(&market_id, &user1));
+    assert!(test.has_claimed(&market_id, &user2));
+    assert!(test.has_claimed(&market_id, &user3));
+    assert!(test.has_claimed(&market_id, &user4));
 
     // Check balances - each should get ~196 XLM (200 * 0.98 = 196 after 2% fee)
     let balance1 = test.env.as_contract(&test.contract_id, || {
@@ -4147,7 +4626,7 @@ fn test_create_market_with_min_pool_size() {
 fn test_resolution_blocked_when_pool_below_minimum() {
   let test = PredictifyTest::setup();
     let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
-    
+
     let outcomes = vec![
         &test.env,
         String::from_str(&test.env, "yes"),
@@ -4173,7 +4652,7 @@ fn test_resolution_blocked_when_pool_below_minimum() {
         &None,
         &None,
     );
-
+    
     // Create users with different stakes creating a tie
     let user1 = test.create_funded_user();
     let user2 = test.create_funded_user();
@@ -4184,7 +4663,7 @@ fn test_resolution_blocked_when_pool_below_minimum() {
     // User2: 100 XLM on "yes"  
     // User3: 300 XLM on "no"
     // Total on yes: 300 XLM, Total on no: 300 XLM (tie scenario)
-    
+
     test.env.mock_all_auths();
     client.vote(&user1, &market_id, &String::from_str(&test.env, "yes"), &200_0000000);
     client.vote(&user2, &market_id, &String::from_str(&test.env, "yes"), &100_0000000);
@@ -4217,7 +4696,7 @@ fn test_resolution_blocked_when_pool_below_minimum() {
         ]);
         test.env.storage().persistent().set(&market_id, &market);
     });
-
+    
     // Distribute payouts
     test.env.mock_all_auths();
     let total_distributed = client.distribute_payouts(&market_id);
@@ -4228,7 +4707,7 @@ fn test_resolution_blocked_when_pool_below_minimum() {
     // User1: (200/600) * 600 * 0.98 = 196 XLM
     // User2: (100/600) * 600 * 0.98 = 98 XLM
     // User3: (300/600) * 600 * 0.98 = 294 XLM
-    
+
     let balance1 = test.env.as_contract(&test.contract_id, || {
         storage::BalanceStorage::get_balance(&test.env, &user1, &types::ReflectorAsset::Stellar)
     });
@@ -4238,7 +4717,7 @@ fn test_resolution_blocked_when_pool_below_minimum() {
     let balance3 = test.env.as_contract(&test.contract_id, || {
         storage::BalanceStorage::get_balance(&test.env, &user3, &types::ReflectorAsset::Stellar)
     });
-
+    
     // Allow small rounding differences
     assert!(balance1.amount >= 195_0000000 && balance1.amount <= 197_0000000, "User1 balance: {}", balance1.amount);
     assert!(balance2.amount >= 97_0000000 && balance2.amount <= 99_0000000, "User2 balance: {}", balance2.amount);
@@ -4247,7 +4726,7 @@ fn test_resolution_blocked_when_pool_below_minimum() {
 fn test_multi_outcome_tie_three_way() {
     let test = PredictifyTest::setup();
     let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
-    
+
     // Create market with 4 outcomes
     let outcomes = vec![
         &test.env,
@@ -4337,13 +4816,13 @@ fn test_multi_outcome_tie_three_way() {
         test.env.storage().persistent().get::<Symbol, Market>(&market_id).unwrap()
     });
 
-    assert!(market_after.claimed.get(user1.clone()).unwrap_or(false));
-    assert!(market_after.claimed.get(user2.clone()).unwrap_or(false));
-    assert!(market_after.claimed.get(user3.clone()).unwrap_or(false));
-    assert!(market_after.claimed.get(user4.clone()).unwrap_or(false));
-    assert!(market_after.claimed.get(user5.clone()).unwrap_or(false));
-    assert!(market_after.claimed.get(user6.clone()).unwrap_or(false));
-
+    assert!(test.has_claimed(&market_id, &user1));
+    assert!(test.has_claimed(&market_id, &user2));
+    assert!(test.has_claimed(&market_id, &user3));
+    assert!(test.has_claimed(&market_id, &user4));
+    assert!(test.has_claimed(&market_id, &user5));
+    assert!(test.has_claimed(&market_id, &user6));
+    
     // Verify proportional payouts: total pool 600 XLM, all 600 are winning stakes
     // Each user: (100 / 600) * 600 * 0.98 = 98 XLM
     let balance1 = test.env.as_contract(&test.contract_id, || {
@@ -4367,7 +4846,7 @@ fn test_multi_outcome_tie_three_way() {
 fn test_proportional_share_different_stakes() {
     let test = PredictifyTest::setup();
     let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
-    
+
     let outcomes = vec![
         &test.env,
         String::from_str(&test.env, "yes"),
@@ -4393,7 +4872,7 @@ fn test_proportional_share_different_stakes() {
         &None,
         &None,
     );
-
+    
     // Create users with different stakes creating a tie
     let user1 = test.create_funded_user();
     let user2 = test.create_funded_user();
@@ -4404,7 +4883,7 @@ fn test_proportional_share_different_stakes() {
     // User2: 100 XLM on "yes"  
     // User3: 300 XLM on "no"
     // Total on yes: 300 XLM, Total on no: 300 XLM (tie scenario)
-    
+
     test.env.mock_all_auths();
     client.vote(&user1, &market_id, &String::from_str(&test.env, "yes"), &200_0000000);
     client.vote(&user2, &market_id, &String::from_str(&test.env, "yes"), &100_0000000);
@@ -4437,7 +4916,7 @@ fn test_proportional_share_different_stakes() {
         ]);
         test.env.storage().persistent().set(&market_id, &market);
     });
-
+    
     // Distribute payouts
     test.env.mock_all_auths();
     let total_distributed = client.distribute_payouts(&market_id);
@@ -4448,7 +4927,7 @@ fn test_proportional_share_different_stakes() {
     // User1: (200/600) * 600 * 0.98 = 196 XLM
     // User2: (100/600) * 600 * 0.98 = 98 XLM
     // User3: (300/600) * 600 * 0.98 = 294 XLM
-    
+
     let balance1 = test.env.as_contract(&test.contract_id, || {
         storage::BalanceStorage::get_balance(&test.env, &user1, &types::ReflectorAsset::Stellar)
     });
@@ -4458,7 +4937,7 @@ fn test_proportional_share_different_stakes() {
     let balance3 = test.env.as_contract(&test.contract_id, || {
         storage::BalanceStorage::get_balance(&test.env, &user3, &types::ReflectorAsset::Stellar)
     });
-
+    
     // Allow small rounding differences
     assert!(balance1.amount >= 195_0000000 && balance1.amount <= 197_0000000, "User1 balance: {}", balance1.amount);
     assert!(balance2.amount >= 97_0000000 && balance2.amount <= 99_0000000, "User2 balance: {}", balance2.amount);
@@ -4471,7 +4950,7 @@ fn test_proportional_share_different_stakes() {
 fn test_no_dust_left_after_tie_payout() {
     let test = PredictifyTest::setup();
     let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
-    
+
     let outcomes = vec![
         &test.env,
         String::from_str(&test.env, "aa"),
@@ -4556,7 +5035,7 @@ fn test_no_dust_left_after_tie_payout() {
     });
 
     let total_received = balance1.amount + balance2.amount + balance3.amount;
-
+    
     // Verify total distributed matches sum of balances (accounting for fees)
     // The difference should be approximately the 2% fee
     let expected_after_fee = (total_pool * 98) / 100;
@@ -4584,7 +5063,7 @@ fn test_no_dust_left_after_tie_payout() {
 fn test_claim_flow_for_tie_winners() {
     let test = PredictifyTest::setup();
     let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
-    
+
     let outcomes = vec![
         &test.env,
         String::from_str(&test.env, "xx"),
@@ -4638,8 +5117,8 @@ fn test_claim_flow_for_tie_winners() {
     let market_before_resolve = test.env.as_contract(&test.contract_id, || {
         test.env.storage().persistent().get::<Symbol, Market>(&market_id).unwrap()
     });
-    assert!(!market_before_resolve.claimed.get(user1.clone()).unwrap_or(false));
-    assert!(!market_before_resolve.claimed.get(user2.clone()).unwrap_or(false));
+    assert!(!test.has_claimed(&market_id, &user1));
+    assert!(!test.has_claimed(&market_id, &user2));
 
     // Resolve with tie
     test.env.as_contract(&test.contract_id, || {
@@ -4662,8 +5141,8 @@ fn test_claim_flow_for_tie_winners() {
     let market_after = test.env.as_contract(&test.contract_id, || {
         test.env.storage().persistent().get::<Symbol, Market>(&market_id).unwrap()
     });
-    assert!(market_after.claimed.get(user1.clone()).unwrap_or(false));
-    assert!(market_after.claimed.get(user2.clone()).unwrap_or(false));
+    assert!(test.has_claimed(&market_id, &user1));
+    assert!(test.has_claimed(&market_id, &user2));
 
     // Verify both received payouts
     let balance1 = test.env.as_contract(&test.contract_id, || {
@@ -4672,7 +5151,7 @@ fn test_claim_flow_for_tie_winners() {
     let balance2 = test.env.as_contract(&test.contract_id, || {
         storage::BalanceStorage::get_balance(&test.env, &user2, &types::ReflectorAsset::Stellar)
     });
-
+    
     assert!(balance1.amount > 0);
     assert!(balance2.amount > 0);
     // Both should get same amount (equal stakes, tied outcomes)
@@ -4685,7 +5164,7 @@ fn test_claim_flow_for_tie_winners() {
 fn test_edge_case_single_winner_not_tie() {
     let test = PredictifyTest::setup();
     let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
-    
+
     let outcomes = vec![
         &test.env,
         String::from_str(&test.env, "win"),
@@ -5211,7 +5690,7 @@ fn test_migration_with_required_flag() {
         let result =
             crate::upgrade_manager::UpgradeManager::validate_upgrade_compatibility(&env, &proposal)
                 .unwrap();
-
+    
         assert!(result.migration_required);
         assert!(result.recommendations.len() > 0);
         // Compatibility score should be reduced
@@ -5223,7 +5702,7 @@ fn test_migration_with_required_flag() {
 fn test_cancel_underfunded_event() {
     let test = PredictifyTest::setup();
     let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
-    
+
     let outcomes = vec![
         &test.env,
         String::from_str(&test.env, "unanimous"),
@@ -5325,7 +5804,7 @@ fn test_cancel_underfunded_event() {
         test.env.storage().persistent().get::<Symbol, Market>(&market_id).unwrap()
     });
     assert_eq!(market_after.state, MarketState::Cancelled);
-
+    
     // Idempotent once cancelled
     test.env.mock_all_auths();
     let second = client.cancel_underfunded_event(&test.admin, &market_id);
@@ -5337,7 +5816,7 @@ fn test_cancel_underfunded_event() {
 fn test_tie_with_zero_stakers_on_losing_outcome() {
     let test = PredictifyTest::setup();
     let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
-    
+
     let outcomes = vec![
         &test.env,
         String::from_str(&test.env, "aa"),
@@ -5413,7 +5892,7 @@ fn test_tie_with_zero_stakers_on_losing_outcome() {
     let balance2 = test.env.as_contract(&test.contract_id, || {
         storage::BalanceStorage::get_balance(&test.env, &user2, &types::ReflectorAsset::Stellar)
     });
-
+    
     // Each: (100/200) * 200 * 0.98 = 98 XLM
     assert!(balance1.amount >= 98_0000000 && balance1.amount <= 100_0000000);
     assert!(balance2.amount >= 98_0000000 && balance2.amount <= 100_0000000);
@@ -5424,7 +5903,7 @@ fn test_tie_with_zero_stakers_on_losing_outcome() {
 fn test_tie_with_very_small_stakes() {
     let test = PredictifyTest::setup();
     let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
-    
+
     let outcomes = vec![
         &test.env,
         String::from_str(&test.env, "opt1"),
@@ -5474,7 +5953,7 @@ fn test_tie_with_very_small_stakes() {
         min_persistent_entry_ttl: 1,
         max_entry_ttl: 10000,
     });
-
+    
     // Resolve with tie
     test.env.as_contract(&test.contract_id, || {
         let mut market: Market = test.env.storage().persistent().get(&market_id).unwrap();
@@ -5490,7 +5969,7 @@ fn test_tie_with_very_small_stakes() {
     // Distribute payouts
     test.env.mock_all_auths();
     let total_distributed = client.distribute_payouts(&market_id);
-    
+
     // Even with very small stakes, payout should work
     assert!(total_distributed >= 0);
 
@@ -5590,9 +6069,5644 @@ fn test_unclaimed_winnings_sweep_comprehensive() {
     });
 
     // 3. Admin sweeps the unclaimed winnings
+    assert_eq!(
+        client.get_claim_deadline(&market_id),
+        market.end_time + client.get_effective_claim_period(&market_id)
+    );
+    let unclaimed = client.get_unclaimed_winnings(&market_id);
     test.env.mock_all_auths();
     let swept = client.sweep_unclaimed(&test.admin, &market_id);
 
-    // Verify the dummy implementation returns 100
     assert!(swept > 0, "Admin should have swept the remaining balance");
+    assert_eq!(swept, unclaimed);
+    assert_eq!(client.get_unclaimed_winnings(&market_id), 0);
+    assert_eq!(client.get_treasury_balance().unclaimed_winnings, swept);
+    assert_eq!(
+        client
+            .get_balance(&test.admin, &ReflectorAsset::Stellar)
+            .amount,
+        0
+    );
+}
+
+#[test]
+fn test_sweep_includes_winnings_held_as_position_tokens() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let yes = String::from_str(&test.env, "yes");
+    let no = String::from_str(&test.env, "no");
+    let rival = test.create_funded_user();
+    let buyer = Address::generate(&test.env);
+    test.env.mock_all_auths();
+
+    let token_id = register_position_token(&test, &test.contract_id);
+    client.set_position_token(&test.admin, &market_id, &yes, &token_id);
+    client.vote(&test.user, &market_id, &yes, &40_0000000);
+    client.vote(&rival, &market_id, &no, &60_0000000);
+    client.tokenize_position(&test.user, &market_id, &30_0000000);
+    TokenClient::new(&test.env, &token_id).transfer(&test.user, &buyer, &30_0000000);
+
+    let market = client.get_market(&market_id).unwrap();
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + 1,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+    client.resolve_market_manual(&test.admin, &market_id, &yes);
+
+    // The staker's 10 XLM position and the buyer's 30 XLM of shares
+    let net_pool = 100_0000000 * (100 - market.platform_fee_percentage) / 100;
+    assert_eq!(client.get_unclaimed_winnings(&market_id), net_pool);
+
+    test.env.ledger().set(LedgerInfo {
+        timestamp: client.get_claim_deadline(&market_id),
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+    let treasury_before = client.get_treasury_balance().balance;
+    assert_eq!(client.sweep_unclaimed(&test.admin, &market_id), net_pool);
+    assert_eq!(
+        client.get_treasury_balance().balance,
+        treasury_before + net_pool
+    );
+    assert_eq!(client.get_unclaimed_winnings(&market_id), 0);
+    assert!(client.try_sweep_unclaimed(&test.admin, &market_id).is_err());
+}
+
+// ===== MARKET TIER TESTS =====
+
+#[test]
+fn test_set_market_tier_pins_fee_and_caps_stake() {
+    let test = PredictifyTest::setup();
+    let market_id = test.create_test_market();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+
+    test.env.mock_all_auths();
+    client.set_market_tier(&test.admin, &market_id, &MarketTier::Experimental);
+
+    let rules = client.get_tier_rules(&MarketTier::Experimental);
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.tier, MarketTier::Experimental);
+    assert_eq!(
+        market.platform_fee_percentage,
+        rules.platform_fee_percentage
+    );
+
+    // Stakes above the tier cap are rejected
+    let result = client.try_vote(
+        &test.user,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &(rules.max_stake_per_user + 1),
+    );
+    assert!(result.is_err());
+
+    client.vote(
+        &test.user,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &rules.max_stake_per_user,
+    );
+
+    // The tier cannot change once the market has participation
+    let result = client.try_set_market_tier(&test.admin, &market_id, &MarketTier::Community);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_featured_tier_requires_fallback_oracle() {
+    let test = PredictifyTest::setup();
+    let market_id = test.create_test_market();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+
+    test.env.mock_all_auths();
+    let result = client.try_set_market_tier(&test.admin, &market_id, &MarketTier::Featured);
+    assert!(result.is_err());
+
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.tier, MarketTier::Community);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #100)")] // Unauthorized = 100
+fn test_set_market_tier_requires_moderator() {
+    let test = PredictifyTest::setup();
+    let market_id = test.create_test_market();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+
+    test.env.mock_all_auths();
+    client.set_market_tier(&test.user, &market_id, &MarketTier::Experimental);
+}
+
+// ===== CONSENSUS SNAPSHOT TESTS =====
+
+#[test]
+fn test_consensus_snapshot_taken_at_close() {
+    let test = PredictifyTest::setup();
+    let market_id = test.create_test_market();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let user2 = test.create_funded_user();
+    let user3 = test.create_funded_user();
+
+    test.env.mock_all_auths();
+    // "no" has more voters, "yes" has more stake
+    client.vote(
+        &test.user,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &60_0000000,
+    );
+    client.vote(
+        &user2,
+        &market_id,
+        &String::from_str(&test.env, "no"),
+        &10_0000000,
+    );
+    client.vote(
+        &user3,
+        &market_id,
+        &String::from_str(&test.env, "no"),
+        &10_0000000,
+    );
+
+    let market = client.get_market(&market_id).unwrap();
+    assert!(!market.has_consensus_snapshot);
+
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + 1,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "no"));
+
+    let market = client.get_market(&market_id).unwrap();
+    assert!(market.has_consensus_snapshot);
+    let snapshot = market.consensus_snapshot;
+    assert_eq!(snapshot.leading_outcome, String::from_str(&test.env, "yes"));
+    assert_eq!(snapshot.leading_stake, 60_0000000);
+    assert_eq!(snapshot.total_stake, 80_0000000);
+    assert_eq!(snapshot.total_votes, 3);
+    assert_eq!(snapshot.stake_percentage, 75);
+    assert_eq!(
+        snapshot
+            .outcome_votes
+            .get(String::from_str(&test.env, "no")),
+        Some(2)
+    );
+    assert_eq!(snapshot.timestamp, market.end_time + 1);
+}
+
+#[test]
+fn test_quadratic_weighting_limits_whale_in_consensus() {
+    let test = PredictifyTest::setup();
+    let market_id = test.create_test_market();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let yes = String::from_str(&test.env, "yes");
+    let no = String::from_str(&test.env, "no");
+
+    test.env.mock_all_auths();
+    assert_eq!(
+        client.try_set_weighting_mode(&test.user, &market_id, &WeightingMode::Quadratic),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.set_weighting_mode(&test.admin, &market_id, &WeightingMode::Quadratic);
+
+    // One whale on "yes" against three small voters on "no"
+    client.vote(&test.user, &market_id, &yes, &60_0000000);
+    for _ in 0..3 {
+        let voter = test.create_funded_user();
+        client.vote(&voter, &market_id, &no, &10_0000000);
+    }
+    assert_eq!(
+        client.try_set_weighting_mode(&test.admin, &market_id, &WeightingMode::Linear),
+        Err(Ok(Error::AlreadyVoted))
+    );
+
+    let market = client.get_market(&market_id).unwrap();
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + 1,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+    client.resolve_market_manual(&test.admin, &market_id, &yes);
+
+    // sqrt weights: 3 * 10_000 for "no" against 24_494 for "yes"
+    let snapshot = client.get_market(&market_id).unwrap().consensus_snapshot;
+    assert_eq!(snapshot.leading_outcome, no);
+    assert_eq!(snapshot.leading_stake, 30_0000000);
+    assert_eq!(snapshot.total_stake, 90_0000000);
+    assert_eq!(snapshot.stake_percentage, 55);
+
+    // Payouts stay linear
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.winning_total, 60_0000000);
+    assert_eq!(market.outcome_total(&no), 30_0000000);
+}
+
+#[test]
+fn test_market_summary_and_paginated_getters() {
+    let test = PredictifyTest::setup();
+    let market_id = test.create_test_market();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let user2 = test.create_funded_user();
+
+    test.env.mock_all_auths();
+    client.vote(
+        &test.user,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &30_0000000,
+    );
+    client.vote(
+        &user2,
+        &market_id,
+        &String::from_str(&test.env, "no"),
+        &10_0000000,
+    );
+
+    // Seed dispute stakes and claims directly
+    let disputers = [
+        Address::generate(&test.env),
+        Address::generate(&test.env),
+        Address::generate(&test.env),
+    ];
+    test.env.as_contract(&test.contract_id, || {
+        let mut market: Market = test.env.storage().persistent().get(&market_id).unwrap();
+        for (i, disputer) in disputers.iter().enumerate() {
+            market
+                .dispute_stakes
+                .set(disputer.clone(), (i as i128 + 1) * 10_000_000);
+        }
+        VoteLedger::mark_claimed(&test.env, &market_id, &mut market, &test.user);
+        test.env.storage().persistent().set(&market_id, &market);
+    });
+
+    let summary = client.get_market_summary(&market_id).unwrap();
+    assert_eq!(summary.market_id, market_id);
+    assert_eq!(summary.state, MarketState::Active);
+    assert_eq!(summary.total_staked, 40_0000000);
+    assert_eq!(summary.voter_count, 2);
+    assert_eq!(summary.claimed_count, 1);
+    assert_eq!(summary.disputer_count, 3);
+    assert_eq!(summary.total_dispute_stakes, 60_000_000);
+    assert_eq!(
+        summary
+            .outcome_totals
+            .get(String::from_str(&test.env, "yes")),
+        Some(30_0000000)
+    );
+    assert!(client
+        .get_market_summary(&Symbol::new(&test.env, "missing"))
+        .is_none());
+
+    // Pages cover every dispute stake exactly once
+    let first = client.get_market_dispute_stakes(&market_id, &0, &2);
+    let second = client.get_market_dispute_stakes(&market_id, &2, &2);
+    assert_eq!(first.len(), 2);
+    assert_eq!(second.len(), 1);
+    let mut total = 0;
+    for (_, stake) in first.iter().chain(second.iter()) {
+        total += stake;
+    }
+    assert_eq!(total, 60_000_000);
+    assert!(client
+        .get_market_dispute_stakes(&market_id, &5, &2)
+        .is_empty());
+
+    let claims = client.get_market_claims(&market_id, &0, &10);
+    assert_eq!(claims, vec![&test.env, test.user.clone()]);
+
+    let oversized = crate::config::MAX_QUERY_PAGE_SIZE + 1;
+    assert!(client.try_get_market_claims(&market_id, &0, &0).is_err());
+    assert!(client
+        .try_get_market_dispute_stakes(&market_id, &0, &oversized)
+        .is_err());
+}
+
+#[test]
+fn test_get_voters_pages_through_participants() {
+    let test = PredictifyTest::setup();
+    let market_id = test.create_test_market();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+
+    test.env.mock_all_auths();
+    let mut voters = Vec::new(&test.env);
+    for i in 0..5 {
+        let voter = test.create_funded_user();
+        let outcome = if i % 2 == 0 { "yes" } else { "no" };
+        client.vote(
+            &voter,
+            &market_id,
+            &String::from_str(&test.env, outcome),
+            &((i + 1) * 1_0000000),
+        );
+        voters.push_back(voter);
+    }
+
+    let market = client.get_market(&market_id).unwrap();
+    let mut seen = 0;
+    let mut total = 0;
+    let mut offset = 0;
+    loop {
+        let page = client.get_voters(&market_id, &offset, &2);
+        if page.is_empty() {
+            break;
+        }
+        assert!(page.len() <= 2);
+        for (voter, outcome, stake) in page.iter() {
+            assert!(voters.contains(&voter));
+            let entry = client.get_vote_entry(&market_id, &voter).unwrap();
+            assert_eq!(entry.outcome, outcome);
+            assert_eq!(entry.stake, stake);
+            total += stake;
+            seen += 1;
+        }
+        offset += page.len();
+    }
+    assert_eq!(seen, 5);
+    assert_eq!(total, 15_0000000);
+
+    assert!(client.try_get_voters(&market_id, &0, &0).is_err());
+    assert!(client
+        .try_get_voters(&Symbol::new(&test.env, "missing"), &0, &10)
+        .is_err());
+}
+
+#[test]
+fn test_change_vote_charges_decaying_penalty_kept_in_pool() {
+    let test = PredictifyTest::setup();
+    let market_id = test.create_test_market();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let yes = String::from_str(&test.env, "yes");
+    let no = String::from_str(&test.env, "no");
+
+    test.env.mock_all_auths();
+    let early = test.create_funded_user();
+    let late = test.create_funded_user();
+    client.vote(&early, &market_id, &yes, &10_0000000);
+    client.vote(&late, &market_id, &yes, &10_0000000);
+
+    // A month out the minimum 1% applies
+    assert_eq!(client.get_vote_change_penalty_bps(&market_id), 100);
+    assert_eq!(client.change_vote(&early, &market_id, &no), 1000000);
+
+    // One day before the end the penalty has risen close to 10%
+    let end_time = client.get_market(&market_id).unwrap().end_time;
+    test.env.ledger().set(LedgerInfo {
+        timestamp: end_time - 86_400,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+    assert_eq!(client.get_vote_change_penalty_bps(&market_id), 872);
+    assert_eq!(client.change_vote(&late, &market_id, &no), 8720000);
+
+    let market = client.get_market(&market_id).unwrap();
+    let early_entry = client.get_vote_entry(&market_id, &early).unwrap();
+    assert_eq!(early_entry.outcome, no.clone());
+    assert_eq!(early_entry.stake, 9_9000000);
+    assert_eq!(
+        client.get_vote_entry(&market_id, &late).unwrap().stake,
+        9_1280000
+    );
+    // Penalties stay in the pool
+    assert_eq!(market.total_staked, 20_0000000);
+
+    let stats = client.get_vote_change_stats(&market_id);
+    assert_eq!(stats.changes, 2);
+    assert_eq!(stats.stake_moved, 19_0280000);
+    assert_eq!(stats.penalties_retained, 9720000);
+
+    test.env.as_contract(&test.contract_id, || {
+        let event = test
+            .env
+            .storage()
+            .persistent()
+            .get::<Symbol, crate::events::VoteChangedEvent>(&symbol_short!("vote_chg"))
+            .unwrap();
+        assert_eq!(event.voter, late);
+        assert_eq!(event.old_outcome, yes);
+        assert_eq!(event.penalty, 8720000);
+    });
+
+    // Same outcome, no prior vote and ended markets are rejected
+    assert!(client.try_change_vote(&late, &market_id, &no).is_err());
+    let outsider = test.create_funded_user();
+    assert!(client.try_change_vote(&outsider, &market_id, &yes).is_err());
+    test.env.ledger().set(LedgerInfo {
+        timestamp: end_time,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+    assert!(client.try_change_vote(&early, &market_id, &yes).is_err());
+}
+
+#[test]
+fn test_net_exposure_nets_positions_per_feed() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let outcomes = vec![
+        &test.env,
+        String::from_str(&test.env, "yes"),
+        String::from_str(&test.env, "no"),
+    ];
+
+    test.env.mock_all_auths();
+    let create = |feed: &str, comparison: &str| {
+        client.create_market(
+            &test.admin,
+            &String::from_str(&test.env, "Where will the price settle?"),
+            &outcomes,
+            &30,
+            &OracleConfig {
+                provider: OracleProvider::Reflector,
+                oracle_address: Address::generate(&test.env),
+                feed_id: String::from_str(&test.env, feed),
+                threshold: 2500000,
+                comparison: String::from_str(&test.env, comparison),
+            },
+            &None,
+            &0,
+            &None,
+            &None,
+            &None,
+        )
+    };
+    let btc_above = create("BTC", "gt");
+    let btc_below = create("BTC", "lt");
+    let eth_above = create("ETH", "gt");
+    let btc_exact = create("BTC", "eq");
+
+    let yes = String::from_str(&test.env, "yes");
+    let no = String::from_str(&test.env, "no");
+    client.vote(&test.user, &btc_above, &yes, &10_0000000);
+    client.vote(&test.user, &btc_below, &yes, &4_0000000);
+    client.vote(&test.user, &eth_above, &no, &5_0000000);
+    client.vote(&test.user, &btc_exact, &yes, &2_0000000);
+
+    let exposure = client.get_net_exposure(&test.user);
+    assert_eq!(exposure.feeds.len(), 2);
+    let btc = exposure.feeds.get(0).unwrap();
+    assert_eq!(btc.feed_id, String::from_str(&test.env, "BTC"));
+    assert_eq!(btc.long_stake, 10_0000000);
+    assert_eq!(btc.short_stake, 4_0000000);
+    assert_eq!(btc.net_exposure, 6_0000000);
+    assert_eq!(btc.market_count, 2);
+    let eth = exposure.feeds.get(1).unwrap();
+    assert_eq!(eth.net_exposure, -5_0000000);
+    assert_eq!(exposure.gross_exposure, 19_0000000);
+    assert_eq!(exposure.net_exposure, 1_0000000);
+    assert_eq!(exposure.non_directional_stake, 2_0000000);
+
+    // Settled markets no longer count towards exposure
+    let end_time = client.get_market(&btc_above).unwrap().end_time;
+    test.env.ledger().set(LedgerInfo {
+        timestamp: end_time + 1,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+    client.resolve_market_manual(&test.admin, &btc_above, &yes);
+    let exposure = client.get_net_exposure(&test.user);
+    assert_eq!(exposure.feeds.get(0).unwrap().net_exposure, -4_0000000);
+
+    let bystander = Address::generate(&test.env);
+    assert!(client.get_net_exposure(&bystander).feeds.is_empty());
+}
+
+#[test]
+fn test_creator_analytics_tracks_created_markets_and_volume() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    test.create_test_market();
+
+    test.env.mock_all_auths();
+    let voter = test.create_funded_user();
+    client.vote(
+        &voter,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &2_0000000,
+    );
+
+    let analytics = client.get_creator_analytics(&test.admin);
+    assert_eq!(analytics.creator, test.admin);
+    assert_eq!(analytics.markets_created, 2);
+    assert_eq!(analytics.total_volume, 2_0000000);
+    assert_eq!(analytics.markets_disputed, 0);
+    assert_eq!(analytics.dispute_rate_bps, 0);
+    assert_eq!(analytics.invalidation_count, 0);
+
+    let other = client.get_creator_analytics(&voter);
+    assert_eq!(other.markets_created, 0);
+}
+
+#[test]
+fn test_dispute_participation_rewards_for_aligned_voters() {
+    use crate::disputes::{DisputeUtils, DisputeVote, DisputeVoting, DisputeVotingStatus};
+
+    let test = PredictifyTest::setup();
+    let market_id = test.create_test_market();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token_client = TokenClient::new(&test.env, &test.token_test.token_id);
+
+    test.env.mock_all_auths();
+    client.vote(
+        &test.user,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &100_0000000,
+    );
+
+    // Oracle said "yes" and one user disputed it; the community votes on the dispute
+    let disputer = Address::generate(&test.env);
+    let upholder_a = Address::generate(&test.env);
+    let overturner = Address::generate(&test.env);
+    let upholder_b = Address::generate(&test.env);
+    test.env.as_contract(&test.contract_id, || {
+        let mut market: Market = test.env.storage().persistent().get(&market_id).unwrap();
+        market.oracle_result = Some(String::from_str(&test.env, "yes"));
+        market.state = MarketState::Disputed;
+        market.dispute_stakes.set(disputer.clone(), 10_000_000);
+        test.env.storage().persistent().set(&market_id, &market);
+
+        DisputeUtils::store_dispute_voting(
+            &test.env,
+            &market_id,
+            &DisputeVoting {
+                dispute_id: market_id.clone(),
+                voting_start: 0,
+                voting_end: 1000,
+                total_votes: 0,
+                support_votes: 0,
+                against_votes: 0,
+                total_support_stake: 0,
+                total_against_stake: 0,
+                status: DisputeVotingStatus::Active,
+            },
+        )
+        .unwrap();
+        for (voter, support) in [
+            (upholder_a.clone(), false),
+            (overturner.clone(), true),
+            (upholder_b.clone(), false),
+        ] {
+            DisputeUtils::add_vote_to_dispute(
+                &test.env,
+                &market_id,
+                DisputeVote {
+                    user: voter,
+                    dispute_id: market_id.clone(),
+                    vote: support,
+                    stake: 1_000_000,
+                    timestamp: 0,
+                    reason: None,
+                },
+            )
+            .unwrap();
+        }
+    });
+
+    let funder = test.create_funded_user();
+    let pool = client.fund_reward_emissions(&funder, &10_000_000);
+    assert_eq!(pool, 10_000_000);
+
+    // The oracle result stands, so only voters who rejected the dispute are rewarded
+    client.resolve_dispute(&test.admin, &market_id);
+    let reward = crate::config::DISPUTE_PARTICIPATION_REWARD;
+    assert_eq!(client.get_accrued_rewards(&upholder_a), reward);
+    assert_eq!(client.get_accrued_rewards(&upholder_b), reward);
+    assert_eq!(client.get_accrued_rewards(&overturner), 0);
+    assert_eq!(client.get_accrued_rewards(&disputer), 0);
+    assert_eq!(client.get_reward_emission_pool(), 10_000_000 - 2 * reward);
+
+    assert_eq!(client.claim_rewards(&upholder_a), reward);
+    assert_eq!(token_client.balance(&upholder_a), reward);
+    assert_eq!(client.get_accrued_rewards(&upholder_a), 0);
+    assert!(client.try_claim_rewards(&upholder_a).is_err());
+    assert!(client.try_claim_rewards(&overturner).is_err());
+}
+
+#[test]
+fn test_arbitration_panel_decides_market_disputes() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let outcomes = vec![
+        &test.env,
+        String::from_str(&test.env, "yes"),
+        String::from_str(&test.env, "no"),
+    ];
+    let oracle_config = OracleConfig {
+        provider: OracleProvider::Reflector,
+        oracle_address: Address::generate(&test.env),
+        feed_id: String::from_str(&test.env, "BTC"),
+        threshold: 2500000,
+        comparison: String::from_str(&test.env, "gt"),
+    };
+    let question = String::from_str(&test.env, "Will BTC go above $25,000 by December 31?");
+    let arbitrators = vec![
+        &test.env,
+        Address::generate(&test.env),
+        Address::generate(&test.env),
+        Address::generate(&test.env),
+    ];
+
+    test.env.mock_all_auths();
+    // Panels must be large enough, distinct and independent of the creator
+    let mut too_small = arbitrators.clone();
+    too_small.pop_back();
+    let mut with_creator = too_small.clone();
+    with_creator.push_back(test.admin.clone());
+    let mut duplicated = too_small.clone();
+    duplicated.push_back(arbitrators.get(0).unwrap());
+    for panel in [too_small, with_creator, duplicated] {
+        assert!(client
+            .try_create_market_with_arbitrators(
+                &test.admin,
+                &question,
+                &outcomes,
+                &30,
+                &oracle_config,
+                &panel,
+            )
+            .is_err());
+    }
+
+    let market_id = client.create_market_with_arbitrators(
+        &test.admin,
+        &question,
+        &outcomes,
+        &30,
+        &oracle_config,
+        &arbitrators,
+    );
+    let panel = client.get_arbitration_panel(&market_id).unwrap();
+    assert_eq!(panel.arbitrators, arbitrators);
+    let plain_market = test.create_test_market();
+    assert!(client.get_arbitration_panel(&plain_market).is_none());
+
+    let disputer = Address::generate(&test.env);
+    test.env.as_contract(&test.contract_id, || {
+        let mut market: Market = test.env.storage().persistent().get(&market_id).unwrap();
+        market.oracle_result = Some(String::from_str(&test.env, "yes"));
+        market.state = MarketState::Disputed;
+        market.dispute_stakes.set(disputer.clone(), 10_000_000);
+        test.env.storage().persistent().set(&market_id, &market);
+    });
+
+    // Community dispute voting is closed and outsiders cannot arbitrate
+    let no = String::from_str(&test.env, "no");
+    let voter = test.create_funded_user();
+    assert!(client
+        .try_vote_on_dispute(&voter, &market_id, &market_id, &true, &1_000_000, &None)
+        .is_err());
+    let outsider_ruling = client.try_arbitrate_dispute(&voter, &market_id, &no);
+    assert!(outsider_ruling.is_err());
+
+    let first = arbitrators.get(0).unwrap();
+    client.arbitrate_dispute(&first, &market_id, &no);
+    let repeated_ruling = client.try_arbitrate_dispute(&first, &market_id, &no);
+    assert!(repeated_ruling.is_err());
+
+    // One of three rulings is not a majority yet
+    assert!(client.try_resolve_dispute(&test.admin, &market_id).is_err());
+
+    client.arbitrate_dispute(&arbitrators.get(1).unwrap(), &market_id, &no);
+    let resolution = client.resolve_dispute(&test.admin, &market_id);
+    assert_eq!(resolution.final_outcome, no);
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.state, MarketState::Resolved);
+    assert_eq!(market.winning_outcomes, Some(vec![&test.env, no]));
+}
+
+#[test]
+fn test_blocked_oracle_falls_back_or_requires_manual_resolution() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let primary = Address::generate(&test.env);
+    let fallback = Address::generate(&test.env);
+    let oracle_config = |address: &Address| OracleConfig {
+        provider: OracleProvider::Reflector,
+        oracle_address: address.clone(),
+        feed_id: String::from_str(&test.env, "BTC"),
+        threshold: 2500000,
+        comparison: String::from_str(&test.env, "gt"),
+    };
+
+    test.env.mock_all_auths();
+    let market_id = client.create_market(
+        &test.admin,
+        &String::from_str(&test.env, "Will BTC go above $25,000?"),
+        &vec![
+            &test.env,
+            String::from_str(&test.env, "yes"),
+            String::from_str(&test.env, "no"),
+        ],
+        &30,
+        &oracle_config(&primary),
+        &Some(oracle_config(&fallback)),
+        &86400,
+        &None,
+        &None,
+        &None,
+    );
+
+    // Only the emergency council can block oracles
+    let reason = String::from_str(&test.env, "compromised");
+    assert!(client
+        .try_block_oracle(&test.user, &primary, &reason)
+        .is_err());
+    client.block_oracle(&test.admin, &primary, &reason);
+    client.block_oracle(&test.admin, &fallback, &reason);
+    assert!(client.is_oracle_blocked(&primary));
+    assert_eq!(client.get_blocked_oracles().len(), 2);
+
+    let market = client.get_market(&market_id).unwrap();
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + 1,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+
+    // With every oracle blocked the market is left for manual resolution
+    let result = test.env.as_contract(&test.contract_id, || {
+        crate::resolution::OracleResolutionManager::fetch_oracle_result(&test.env, &market_id)
+    });
+    assert_eq!(result.err(), Some(Error::OracleUnavailable));
+
+    // Once the fallback is cleared it resolves the market instead of the primary
+    client.unblock_oracle(&test.admin, &fallback, &reason);
+    assert!(!client.is_oracle_blocked(&fallback));
+    let result = test.env.as_contract(&test.contract_id, || {
+        crate::resolution::OracleResolutionManager::fetch_oracle_result(&test.env, &market_id)
+    });
+    assert!(result.is_ok());
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.resolution_snapshot.oracle_address, fallback);
+}
+
+#[test]
+fn test_bug_bounty_escrow_pays_reporters_within_caps() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token_client = soroban_sdk::token::Client::new(&test.env, &test.token_test.token_id);
+    let reporter = Address::generate(&test.env);
+    let report = |id: &str| String::from_str(&test.env, id);
+
+    test.env.mock_all_auths();
+    // Only the treasury (the admin by default) can fund the escrow
+    assert!(client
+        .try_fund_bounty_escrow(&test.user, &1_0000000)
+        .is_err());
+    assert_eq!(
+        client.fund_bounty_escrow(&test.admin, &50_0000000),
+        50_0000000
+    );
+
+    // Only the emergency council can approve payouts
+    let unauthorized =
+        client.try_approve_bounty_payout(&test.user, &reporter, &1_0000000, &report("VULN-1"));
+    assert!(unauthorized.is_err());
+
+    let payout =
+        client.approve_bounty_payout(&test.admin, &reporter, &20_0000000, &report("VULN-1"));
+    assert_eq!(payout.id, 0);
+    assert_eq!(payout.approved_by, test.admin);
+    assert_eq!(token_client.balance(&reporter), 20_0000000);
+    assert_eq!(client.get_bounty_escrow_balance(), 30_0000000);
+
+    // A report is paid once, and payouts are capped by the escrow and config
+    let repeat =
+        client.try_approve_bounty_payout(&test.admin, &reporter, &1_0000000, &report("VULN-1"));
+    assert_eq!(repeat, Err(Ok(Error::AlreadyClaimed)));
+    let overdrawn =
+        client.try_approve_bounty_payout(&test.admin, &reporter, &40_0000000, &report("VULN-2"));
+    assert_eq!(overdrawn, Err(Ok(Error::InvalidState)));
+    let over_cap = client.try_approve_bounty_payout(
+        &test.admin,
+        &reporter,
+        &(crate::config::MAX_BOUNTY_PAYOUT + 1),
+        &report("VULN-2"),
+    );
+    assert_eq!(over_cap, Err(Ok(Error::InvalidInput)));
+
+    client.approve_bounty_payout(&test.admin, &reporter, &5_0000000, &report("VULN-2"));
+    let payouts = client.get_bounty_payouts(&0, &10);
+    assert_eq!(payouts.len(), 2);
+    assert_eq!(payouts.get(1).unwrap().report_id, report("VULN-2"));
+    assert_eq!(client.get_bounty_payouts(&1, &10).len(), 1);
+
+    let event = test.env.as_contract(&test.contract_id, || {
+        test.env
+            .storage()
+            .persistent()
+            .get::<Symbol, crate::events::BountyPaidEvent>(&symbol_short!("bnty_paid"))
+            .unwrap()
+    });
+    assert_eq!(event.payout_id, 1);
+    assert_eq!(event.amount, 5_0000000);
+}
+
+#[test]
+fn test_contract_config_and_history_are_public() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let config = client.get_contract_config();
+    assert_eq!(config.fees.platform_fee_percentage, 2);
+    assert_eq!(client.get_config_history(&0, &10).len(), 0);
+
+    test.env.mock_all_auths();
+    test.env.as_contract(&test.contract_id, || {
+        crate::config::ConfigManager::update_fee_percentage(&test.env, test.admin.clone(), 3)
+            .unwrap();
+    });
+    test.env.as_contract(&test.contract_id, || {
+        crate::config::ConfigManager::update_oracle_timeout(&test.env, test.admin.clone(), 600)
+            .unwrap();
+    });
+
+    assert_eq!(client.get_contract_config().fees.platform_fee_percentage, 3);
+    let history = client.get_config_history(&0, &10);
+    assert_eq!(history.len(), 2);
+    let first = history.get(0).unwrap();
+    assert_eq!(
+        first.change_type,
+        String::from_str(&test.env, "fee_percentage")
+    );
+    assert_eq!(first.old_value, String::from_str(&test.env, "2"));
+    assert_eq!(first.new_value, String::from_str(&test.env, "3"));
+    assert_eq!(first.updated_by, test.admin);
+
+    let page = client.get_config_history(&1, &10);
+    assert_eq!(page.len(), 1);
+    assert_eq!(
+        page.get(0).unwrap().change_type,
+        String::from_str(&test.env, "oracle_timeout")
+    );
+    assert!(client.try_get_config_history(&0, &0).is_err());
+}
+
+#[test]
+fn test_dispute_stake_policy_scales_with_pool() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let disputer = test.create_funded_user();
+
+    test.env.mock_all_auths();
+    client.vote(
+        &test.user,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &100_0000000,
+    );
+    assert_eq!(
+        client.get_required_dispute_stake(&market_id),
+        crate::config::MIN_DISPUTE_STAKE
+    );
+
+    // Only the admin can set the policy, and the floor cannot undercut the minimum
+    let not_admin = client.try_set_dispute_stake_policy(&test.user, &500, &1_0000000, &20_0000000);
+    assert!(not_admin.is_err());
+    let low_floor = client.try_set_dispute_stake_policy(&test.admin, &500, &1, &20_0000000);
+    assert_eq!(low_floor, Err(Ok(Error::InvalidInput)));
+
+    // 5% of a 100 XLM pool, clamped to [1, 20] XLM
+    client.set_dispute_stake_policy(&test.admin, &500, &1_0000000, &20_0000000);
+    assert_eq!(client.get_required_dispute_stake(&market_id), 5_0000000);
+
+    let market = client.get_market(&market_id).unwrap();
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + 1,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+    test.env.as_contract(&test.contract_id, || {
+        let mut market: Market = test.env.storage().persistent().get(&market_id).unwrap();
+        market.oracle_result = Some(String::from_str(&test.env, "yes"));
+        test.env.storage().persistent().set(&market_id, &market);
+    });
+
+    let below = client.try_dispute_market(&disputer, &market_id, &4_0000000, &None);
+    assert_eq!(below, Err(Ok(Error::InsufficientStake)));
+    client.dispute_market(&disputer, &market_id, &5_0000000, &None);
+
+    let opened = test.env.as_contract(&test.contract_id, || {
+        crate::disputes::DisputeUtils::get_opened_disputes(&test.env, &market_id)
+    });
+    assert_eq!(opened.len(), 1);
+    assert_eq!(opened.get(0).unwrap().required_stake, 5_0000000);
+}
+
+#[test]
+fn test_outcome_ids_are_canonical_and_labels_localizable() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let yes = String::from_str(&test.env, "yes");
+    let no = String::from_str(&test.env, "no");
+
+    assert_eq!(client.get_outcome_id(&market_id, &yes), 0);
+    assert_eq!(client.get_outcome_label(&market_id, &1), no);
+    let unknown = client.try_get_outcome_label(&market_id, &2);
+    assert_eq!(unknown, Err(Ok(Error::InvalidOutcome)));
+
+    test.env.mock_all_auths();
+    let locale = String::from_str(&test.env, "es");
+    let labels = vec![
+        &test.env,
+        String::from_str(&test.env, "si"),
+        String::from_str(&test.env, "no"),
+    ];
+    // One label per outcome ID is required
+    let short = vec![&test.env, String::from_str(&test.env, "si")];
+    let rejected = client.try_set_outcome_labels(&test.admin, &market_id, &locale, &short);
+    assert_eq!(rejected, Err(Ok(Error::InvalidOutcomes)));
+    client.set_outcome_labels(&test.admin, &market_id, &locale, &labels);
+    assert_eq!(client.get_outcome_labels(&market_id, &locale), labels);
+    let fallback = client.get_outcome_labels(&market_id, &String::from_str(&test.env, "fr"));
+    assert_eq!(fallback, vec![&test.env, yes.clone(), no.clone()]);
+
+    // Voting by ID records the canonical label
+    client.vote_by_outcome_id(&test.user, &market_id, &0, &1_0000000);
+    let entry = client.get_vote_entry(&market_id, &test.user).unwrap();
+    assert_eq!(entry.outcome, yes);
+    assert!(client
+        .try_vote_by_outcome_id(&test.create_funded_user(), &market_id, &5, &1_0000000)
+        .is_err());
+
+    // With stake placed the outcome set is frozen, so IDs stay stable
+    let renamed = client.try_update_event_outcomes(
+        &test.admin,
+        &market_id,
+        &vec![&test.env, no.clone(), yes.clone()],
+    );
+    assert!(renamed.is_err());
+    assert_eq!(client.get_outcome_id(&market_id, &yes), 0);
+}
+
+#[test]
+fn test_migrate_single_market_between_storage_formats() {
+    use crate::storage::StorageFormat;
+
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let other = test.create_test_market();
+    let format_of = |id: &Symbol| client.get_market_storage_format(id);
+    assert_eq!(format_of(&market_id), StorageFormat::V1);
+
+    test.env.mock_all_auths();
+    let not_admin = client.try_migrate_market(&test.user, &market_id, &StorageFormat::V2);
+    assert!(not_admin.is_err());
+    let same = client.try_migrate_market(&test.admin, &market_id, &StorageFormat::V1);
+    assert_eq!(same.err(), Some(Ok(Error::InvalidInput)));
+
+    let before = client.get_market(&market_id).unwrap();
+    let migration = client.migrate_market(&test.admin, &market_id, &StorageFormat::V2);
+    assert_eq!(migration.from_format, StorageFormat::V1);
+    assert_eq!(migration.to_format, StorageFormat::V2);
+    assert_eq!(migration.markets_migrated, 1);
+    assert_eq!(format_of(&market_id), StorageFormat::V2);
+    // Only the requested market is migrated and its data is untouched
+    assert_eq!(format_of(&other), StorageFormat::V1);
+    assert_eq!(client.get_market(&market_id).unwrap(), before);
+
+    client.migrate_market(&test.admin, &market_id, &StorageFormat::V1);
+    assert_eq!(format_of(&market_id), StorageFormat::V1);
+}
+
+#[test]
+fn test_user_nonce_rejects_replayed_operations() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let other_market = test.create_test_market();
+    let yes = String::from_str(&test.env, "yes");
+    assert_eq!(client.get_user_nonce(&test.user), 0);
+
+    test.env.mock_all_auths();
+    client.vote_with_nonce(&test.user, &market_id, &yes, &1_0000000, &Some(1));
+    assert_eq!(client.get_user_nonce(&test.user), 1);
+
+    // A retried transaction reuses its nonce and is rejected
+    let retry = client.try_vote_with_nonce(&test.user, &other_market, &yes, &1_0000000, &Some(1));
+    assert!(retry.is_err());
+    assert_eq!(client.get_market(&other_market).unwrap().voter_count, 0);
+
+    // A failed operation does not consume its nonce
+    let failed = client.try_vote_with_nonce(&test.user, &market_id, &yes, &1_0000000, &Some(5));
+    assert!(failed.is_err());
+    assert_eq!(client.get_user_nonce(&test.user), 1);
+
+    // Nonces may skip ahead, and operations without one are unaffected
+    client.vote_with_nonce(&test.user, &other_market, &yes, &1_0000000, &Some(5));
+    assert_eq!(client.get_user_nonce(&test.user), 5);
+    let third = test.create_test_market();
+    client.vote_with_nonce(&test.user, &third, &yes, &1_0000000, &None);
+    assert_eq!(client.get_user_nonce(&test.user), 5);
+}
+
+#[test]
+fn test_public_market_creation_bond_refund_and_slash() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token = TokenClient::new(&test.env, &test.token_test.token_id);
+    let creator = test.create_funded_user();
+    let bond = crate::config::DEFAULT_MARKET_CREATION_BOND;
+    let outcomes = vec![
+        &test.env,
+        String::from_str(&test.env, "yes"),
+        String::from_str(&test.env, "no"),
+    ];
+    let oracle = |feed: &str| OracleConfig {
+        provider: OracleProvider::Reflector,
+        oracle_address: Address::generate(&test.env),
+        feed_id: String::from_str(&test.env, feed),
+        threshold: 2500000,
+        comparison: String::from_str(&test.env, "gt"),
+    };
+
+    test.env.mock_all_auths();
+    let start_balance = token.balance(&creator);
+    let resolved_id = client.create_market_public(
+        &creator,
+        &String::from_str(&test.env, "Will BTC close above $25,000 this month?"),
+        &outcomes,
+        &30,
+        &oracle("BTC"),
+    );
+    let invalid_id = client.create_market_public(
+        &creator,
+        &String::from_str(&test.env, "Will ETH close above $2,000 this month?"),
+        &outcomes,
+        &30,
+        &oracle("ETH"),
+    );
+    assert_eq!(token.balance(&creator), start_balance - 2 * bond);
+    assert_eq!(client.get_market(&resolved_id).unwrap().admin, creator);
+    let held = client.get_creation_bond(&resolved_id).unwrap();
+    assert_eq!(held.status, crate::markets::CreationBondStatus::Held);
+    assert_eq!(held.amount, bond);
+    assert!(client
+        .get_creation_bond(&test.create_test_market())
+        .is_none());
+
+    // The bond cannot be refunded before the market resolves
+    assert_eq!(
+        client.try_refund_creation_bond(&resolved_id),
+        Err(Ok(Error::MarketNotResolved))
+    );
+
+    let market = client.get_market(&resolved_id).unwrap();
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + 1,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+    client.resolve_market_manual(
+        &test.admin,
+        &resolved_id,
+        &String::from_str(&test.env, "yes"),
+    );
+
+    // The result can still be disputed, so the bond stays held until it is final
+    assert_eq!(
+        client.try_refund_creation_bond(&resolved_id),
+        Err(Ok(Error::InvalidState))
+    );
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + market.dispute_window_seconds,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+    client.finalize_result(&resolved_id);
+    assert_eq!(client.refund_creation_bond(&resolved_id), bond);
+    assert_eq!(
+        client.try_refund_creation_bond(&resolved_id),
+        Err(Ok(Error::AlreadyClaimed))
+    );
+
+    // Only a market governance flagged invalid can be slashed
+    assert_eq!(
+        client.try_slash_creation_bond(&test.admin, &invalid_id),
+        Err(Ok(Error::InvalidState))
+    );
+    let proposal_id = Symbol::new(&test.env, "invalid_eth");
+    assert_eq!(
+        client.try_propose_market_invalidation(&test.user, &proposal_id, &invalid_id),
+        Err(Ok(Error::InvalidState))
+    );
+    test.env.as_contract(&test.contract_id, || {
+        crate::governance::GovernanceContract::initialize(
+            test.env.clone(),
+            test.admin.clone(),
+            3_600,
+            2,
+        );
+    });
+    assert_eq!(
+        client.try_propose_market_invalidation(&test.user, &proposal_id, &resolved_id),
+        Err(Ok(Error::NothingToClaim))
+    );
+    client.propose_market_invalidation(&test.user, &proposal_id, &invalid_id);
+    test.env.as_contract(&test.contract_id, || {
+        for _ in 0..2 {
+            let voter = Address::generate(&test.env);
+            crate::governance::GovernanceContract::vote(
+                test.env.clone(),
+                voter,
+                proposal_id.clone(),
+                true,
+            )
+            .unwrap();
+        }
+    });
+    assert_eq!(
+        client.try_apply_market_invalidation(&proposal_id),
+        Err(Ok(Error::MarketNotReady))
+    );
+    test.env.ledger().set(LedgerInfo {
+        timestamp: test.env.ledger().timestamp() + 3_601,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+    assert_eq!(client.apply_market_invalidation(&proposal_id), invalid_id);
+    assert!(client.is_market_flagged_invalid(&invalid_id));
+
+    // Governance slashes the bond of the invalid market to the treasury
+    let treasury_balance = client.get_treasury_balance().balance;
+    assert!(client
+        .try_slash_creation_bond(&creator, &invalid_id)
+        .is_err());
+    assert_eq!(client.slash_creation_bond(&test.admin, &invalid_id), bond);
+    assert_eq!(
+        client.get_treasury_balance().balance,
+        treasury_balance + bond
+    );
+    assert_eq!(client.get_treasury_balance().creation_bonds, bond);
+    assert_eq!(token.balance(&creator), start_balance - bond);
+    assert_eq!(
+        client.get_creation_bond(&invalid_id).unwrap().status,
+        crate::markets::CreationBondStatus::Slashed
+    );
+}
+
+#[test]
+fn test_claim_ticket_transfers_once_and_pays_holder() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let loser = test.create_funded_user();
+    let buyer = Address::generate(&test.env);
+    let other = Address::generate(&test.env);
+
+    test.env.mock_all_auths();
+    client.vote(
+        &test.user,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &100_0000000,
+    );
+    client.vote(
+        &loser,
+        &market_id,
+        &String::from_str(&test.env, "no"),
+        &100_0000000,
+    );
+    assert_eq!(
+        client.try_issue_claim_ticket(&market_id, &test.user),
+        Err(Ok(Error::MarketNotResolved))
+    );
+
+    let market = client.get_market(&market_id).unwrap();
+    let set_time = |timestamp: u64| {
+        test.env.ledger().set(LedgerInfo {
+            timestamp,
+            protocol_version: 22,
+            sequence_number: test.env.ledger().sequence(),
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 1,
+            min_persistent_entry_ttl: 1,
+            max_entry_ttl: 10000,
+        });
+    };
+    set_time(market.end_time + 1);
+    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+    assert_eq!(
+        client.try_issue_claim_ticket(&market_id, &test.user),
+        Err(Ok(Error::MarketNotReady))
+    );
+    set_time(market.end_time + market.dispute_window_seconds);
+
+    // Losers have nothing to sell; the winner's ticket carries the net payout
+    assert_eq!(
+        client.try_issue_claim_ticket(&market_id, &loser),
+        Err(Ok(Error::NothingToClaim))
+    );
+    let ticket = client.issue_claim_ticket(&market_id, &test.user);
+    assert_eq!(ticket.holder, test.user);
+    assert_eq!(ticket.payout, 196_0000000);
+
+    // Only the holder may transfer, and only once
+    assert_eq!(
+        client.try_transfer_claim_ticket(&buyer, &market_id, &test.user, &other),
+        Err(Ok(Error::Unauthorized))
+    );
+    let sold = client.transfer_claim_ticket(&test.user, &market_id, &test.user, &buyer);
+    assert_eq!(sold.holder, buyer);
+    assert_eq!(
+        client.try_transfer_claim_ticket(&buyer, &market_id, &test.user, &other),
+        Err(Ok(Error::InvalidState))
+    );
+
+    // The staker can no longer claim; the buyer's claim consumes the ticket
+    assert!(client.try_claim_winnings(&test.user, &market_id).is_err());
+    client.claim_with_ticket(&buyer, &market_id, &test.user);
+    assert_eq!(
+        client.get_balance(&buyer, &ReflectorAsset::Stellar).amount,
+        196_0000000
+    );
+    assert!(client.get_claim_ticket(&market_id, &test.user).is_none());
+    assert!(client
+        .try_claim_with_ticket(&buyer, &market_id, &test.user)
+        .is_err());
+}
+
+#[test]
+fn test_paginated_market_listing() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    assert_eq!(client.get_market_count(), 0);
+    assert_eq!(client.get_markets(&0, &10).len(), 0);
+
+    let first = test.create_test_market();
+    let second = test.create_test_market();
+    let third = test.create_test_market();
+    test.env.mock_all_auths();
+    client.cancel_event(&test.admin, &second, &None);
+
+    assert_eq!(client.get_market_count(), 3);
+    assert_eq!(
+        client.get_markets(&0, &10),
+        vec![&test.env, first.clone(), second.clone(), third.clone()]
+    );
+    assert_eq!(client.get_markets(&1, &1), vec![&test.env, second.clone()]);
+    assert_eq!(client.get_markets(&5, &10).len(), 0);
+    assert_eq!(client.try_get_markets(&0, &0), Err(Ok(Error::InvalidInput)));
+
+    assert_eq!(
+        client.get_markets_by_state(&MarketState::Cancelled, &0, &10),
+        vec![&test.env, second.clone()]
+    );
+    assert_eq!(
+        client.get_markets_by_state(&MarketState::Active, &1, &2),
+        vec![&test.env, third.clone()]
+    );
+
+    // Newest first, excluding cancelled markets
+    assert_eq!(
+        client.get_active_markets(),
+        vec![&test.env, third.clone(), first.clone()]
+    );
+}
+
+#[test]
+fn test_vote_memo_is_published_in_events() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let memo = BytesN::from_array(&test.env, &[7u8; 64]);
+
+    test.env.mock_all_auths();
+    client.vote_with_memo(
+        &test.user,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &1_0000000,
+        &memo,
+    );
+
+    let events = test.env.events().all();
+    let published = events.iter().find_map(|(_, topics, data)| {
+        let topic: Symbol = topics.get(0)?.try_into_val(&test.env).ok()?;
+        if topic != symbol_short!("vote_memo") {
+            return None;
+        }
+        crate::events::VoteMemoEvent::try_from_val(&test.env, &data).ok()
+    });
+    let event = published.expect("vote memo event should be published");
+    assert_eq!(event.market_id, market_id);
+    assert_eq!(event.voter, test.user);
+    assert_eq!(event.memo, memo);
+
+    // The vote itself is recorded as usual
+    let entry = client.get_vote_entry(&market_id, &test.user).unwrap();
+    assert_eq!(entry.outcome, String::from_str(&test.env, "yes"));
+}
+
+#[test]
+fn test_dispute_insurance_covers_voter_stakes() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token = TokenClient::new(&test.env, &test.token_test.token_id);
+    let market_id = test.create_test_market();
+    let voter = test.create_funded_user();
+    let outsider = test.create_funded_user();
+
+    test.env.mock_all_auths();
+    client.vote(
+        &voter,
+        &market_id,
+        &String::from_str(&test.env, "no"),
+        &10_0000000,
+    );
+
+    // Only the creator can insure the market
+    assert_eq!(
+        client.try_fund_dispute_insurance(&voter, &market_id, &15_0000000),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.fund_dispute_insurance(&test.admin, &market_id, &15_0000000),
+        15_0000000
+    );
+
+    let pass_end_time = || {
+        let market = client.get_market(&market_id).unwrap();
+        test.env.ledger().set(LedgerInfo {
+            timestamp: market.end_time + 1,
+            protocol_version: 22,
+            sequence_number: test.env.ledger().sequence(),
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 1,
+            min_persistent_entry_ttl: 1,
+            max_entry_ttl: 10000,
+        });
+    };
+    pass_end_time();
+    test.env.as_contract(&test.contract_id, || {
+        let mut market: Market = test.env.storage().persistent().get(&market_id).unwrap();
+        market.oracle_result = Some(String::from_str(&test.env, "yes"));
+        test.env.storage().persistent().set(&market_id, &market);
+    });
+
+    // Non-voters are never covered
+    let covered = test.env.as_contract(&test.contract_id, || {
+        let market: Market = test.env.storage().persistent().get(&market_id).unwrap();
+        crate::disputes::DisputeInsuranceManager::cover(
+            &test.env, &market_id, &market, &outsider, 10_0000000,
+        )
+    });
+    assert_eq!(covered, 0);
+
+    // A voter's dispute is covered in full
+    let voter_balance = token.balance(&voter);
+    client.dispute_market(&voter, &market_id, &10_0000000, &None);
+    assert_eq!(token.balance(&voter), voter_balance);
+
+    let insurance = client.get_dispute_insurance(&market_id).unwrap();
+    assert_eq!(insurance.balance, 5_0000000);
+    assert_eq!(insurance.covered.get(voter.clone()), Some(10_0000000));
+
+    // The unused deposit is refundable only after resolution
+    assert_eq!(
+        client.try_withdraw_dispute_insurance(&test.admin, &market_id),
+        Err(Ok(Error::MarketNotResolved))
+    );
+    pass_end_time();
+    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+    let creator_balance = token.balance(&test.admin);
+    assert_eq!(
+        client.withdraw_dispute_insurance(&test.admin, &market_id),
+        5_0000000
+    );
+    assert_eq!(token.balance(&test.admin), creator_balance + 5_0000000);
+    assert_eq!(
+        client.try_withdraw_dispute_insurance(&test.admin, &market_id),
+        Err(Ok(Error::NothingToClaim))
+    );
+}
+
+#[test]
+fn test_outcome_totals_follow_vote_changes_and_bets() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let bettor = test.create_funded_user();
+    let yes = String::from_str(&test.env, "yes");
+    let no = String::from_str(&test.env, "no");
+
+    test.env.mock_all_auths();
+    client.vote(&test.user, &market_id, &yes, &10_0000000);
+    client.place_bet(&bettor, &market_id, &no, &5_0000000);
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.outcome_total(&yes), 10_0000000);
+    assert_eq!(market.outcome_total(&no), 5_0000000);
+
+    // Switching sides moves the (penalised) stake between outcomes
+    let penalty = client.change_vote(&test.user, &market_id, &no);
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.outcome_total(&yes), 0);
+    assert_eq!(market.outcome_total(&no), 15_0000000 - penalty);
+}
+
+#[test]
+fn test_vote_ledger_keeps_positions_outside_market() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let other = test.create_funded_user();
+    let yes = String::from_str(&test.env, "yes");
+    let no = String::from_str(&test.env, "no");
+
+    test.env.mock_all_auths();
+    client.vote(&test.user, &market_id, &yes, &10_0000000);
+    client.vote(&other, &market_id, &no, &5_0000000);
+
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.voter_count, 2);
+    assert_eq!(market.outcome_voter_count(&yes), 1);
+    assert_eq!(market.outcome_voter_count(&no), 1);
+
+    let entry = client.get_vote_entry(&market_id, &test.user).unwrap();
+    assert_eq!(entry.outcome, yes);
+    assert_eq!(entry.stake, 10_0000000);
+    assert!(!entry.claimed);
+
+    // Voters are indexed in arrival order
+    let voters = client.get_voters(&market_id, &0, &10);
+    assert_eq!(voters.len(), 2);
+    assert_eq!(
+        voters.get(0).unwrap(),
+        (test.user.clone(), yes.clone(), 10_0000000)
+    );
+    assert_eq!(
+        voters.get(1).unwrap(),
+        (other.clone(), no.clone(), 5_0000000)
+    );
+
+    // Changing sides moves the voter between outcomes without re-indexing
+    client.change_vote(&other, &market_id, &yes);
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.voter_count, 2);
+    assert_eq!(market.outcome_voter_count(&yes), 2);
+    assert_eq!(market.outcome_voter_count(&no), 0);
+    assert!(client
+        .get_vote_entry(&market_id, &Address::generate(&test.env))
+        .is_none());
+}
+
+#[test]
+fn test_resolution_confidence_reflects_oracle_and_community() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let yes = String::from_str(&test.env, "yes");
+
+    test.env.mock_all_auths();
+    client.vote(&test.user, &market_id, &yes, &10_0000000);
+
+    // Community only: one voter out of the five needed for full participation
+    let confidence = client.get_resolution_confidence(&market_id);
+    assert_eq!(confidence.oracle_outcome, None);
+    assert_eq!(confidence.community_outcome, yes);
+    assert_eq!(confidence.oracle_certainty, 0);
+    assert_eq!(confidence.consensus_strength, 100);
+    assert_eq!(confidence.participation, 20);
+    assert_eq!(confidence.confidence_score, 6);
+
+    let set_oracle_result = |outcome: &str| {
+        test.env.as_contract(&test.contract_id, || {
+            let mut market =
+                crate::markets::MarketStateManager::get_market(&test.env, &market_id).unwrap();
+            market.oracle_result = Some(String::from_str(&test.env, outcome));
+            crate::markets::MarketStateManager::update_market(&test.env, &market_id, &market);
+        });
+    };
+
+    // Agreement adds the community side, disagreement subtracts it
+    set_oracle_result("yes");
+    let agreed = client.get_resolution_confidence(&market_id);
+    assert!(agreed.agreement);
+    assert_eq!(agreed.oracle_certainty, 85);
+    assert_eq!(agreed.confidence_score, 65);
+
+    set_oracle_result("no");
+    let contested = client.get_resolution_confidence(&market_id);
+    assert!(!contested.agreement);
+    assert_eq!(contested.confidence_score, 53);
+
+    // Only pending resolutions are scored
+    let market = client.get_market(&market_id).unwrap();
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + market.dispute_window_seconds + 1,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+    client.resolve_market_manual(&test.admin, &market_id, &yes);
+    assert_eq!(
+        client.try_get_resolution_confidence(&market_id),
+        Err(Ok(Error::MarketResolved))
+    );
+}
+
+#[test]
+fn test_add_stake_tops_up_existing_vote() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let yes = String::from_str(&test.env, "yes");
+    let newcomer = test.create_funded_user();
+
+    test.env.mock_all_auths();
+    // Only existing voters can top up
+    assert_eq!(
+        client.try_add_stake(&newcomer, &market_id, &1_0000000),
+        Err(Ok(Error::InvalidState))
+    );
+
+    client.vote(&test.user, &market_id, &yes, &10_0000000);
+    assert_eq!(
+        client.try_add_stake(&test.user, &market_id, &0),
+        Err(Ok(Error::InvalidInput))
+    );
+
+    let token = TokenClient::new(&test.env, &test.token_test.token_id);
+    let balance_before = token.balance(&test.user);
+    assert_eq!(
+        client.add_stake(&test.user, &market_id, &5_0000000),
+        15_0000000
+    );
+    assert_eq!(token.balance(&test.user), balance_before - 5_0000000);
+
+    let entry = client.get_vote_entry(&market_id, &test.user).unwrap();
+    assert_eq!(entry.outcome, yes);
+    assert_eq!(entry.stake, 15_0000000);
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.total_staked, 15_0000000);
+    assert_eq!(market.outcome_total(&yes), 15_0000000);
+    assert_eq!(market.voter_count, 1);
+
+    let event = test.env.as_contract(&test.contract_id, || {
+        test.env
+            .storage()
+            .persistent()
+            .get::<Symbol, crate::events::StakeIncreasedEvent>(&symbol_short!("stake_inc"))
+            .unwrap()
+    });
+    assert_eq!(event.added, 5_0000000);
+    assert_eq!(event.new_stake, 15_0000000);
+
+    // No top-ups once the market has ended
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + 1,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+    assert_eq!(
+        client.try_add_stake(&test.user, &market_id, &1_0000000),
+        Err(Ok(Error::MarketClosed))
+    );
+}
+
+#[test]
+fn test_exit_position_sells_stake_back_before_end() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let yes = String::from_str(&test.env, "yes");
+    let no = String::from_str(&test.env, "no");
+    let rival = test.create_funded_user();
+    let token = TokenClient::new(&test.env, &test.token_test.token_id);
+
+    test.env.mock_all_auths();
+    client.vote(&test.user, &market_id, &yes, &10_0000000);
+    client.vote(&rival, &market_id, &no, &30_0000000);
+    assert_eq!(
+        client.try_exit_position(&test.user, &market_id, &11_0000000),
+        Err(Ok(Error::InsufficientStake))
+    );
+
+    // Paid at implied odds (pool share net of the platform fee) less the exit fee
+    let fee_percentage = client
+        .get_market(&market_id)
+        .unwrap()
+        .platform_fee_percentage;
+    let expected = 4_0000000 * (100 - fee_percentage) / 100
+        * (10_000 - crate::config::POSITION_EXIT_FEE_BPS)
+        / 10_000;
+    let balance_before = token.balance(&test.user);
+    assert_eq!(
+        client.exit_position(&test.user, &market_id, &4_0000000),
+        expected
+    );
+    assert_eq!(token.balance(&test.user), balance_before + expected);
+
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.outcome_total(&yes), 6_0000000);
+    // The retained part stays in the pool
+    assert_eq!(market.total_staked, 40_0000000 - expected);
+    assert_eq!(
+        client.get_vote_entry(&market_id, &test.user).unwrap().stake,
+        6_0000000
+    );
+
+    // Selling the rest closes the position
+    client.exit_position(&test.user, &market_id, &6_0000000);
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.outcome_total(&yes), 0);
+    assert_eq!(market.outcome_voter_count(&yes), 0);
+    assert_eq!(
+        client.try_exit_position(&test.user, &market_id, &1),
+        Err(Ok(Error::InvalidState))
+    );
+    assert_eq!(
+        client.try_add_stake(&test.user, &market_id, &1_0000000),
+        Err(Ok(Error::InvalidState))
+    );
+
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+    assert_eq!(
+        client.try_exit_position(&rival, &market_id, &1_0000000),
+        Err(Ok(Error::MarketClosed))
+    );
+}
+
+fn register_position_token(test: &PredictifyTest, admin: &Address) -> Address {
+    test.env.register(
+        position_token::PositionToken,
+        (
+            admin.clone(),
+            7u32,
+            String::from_str(&test.env, "BTC above 25k: yes"),
+            String::from_str(&test.env, "YES"),
+        ),
+    )
+}
+
+#[test]
+fn test_tokenized_position_pays_its_holder_on_claim() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let yes = String::from_str(&test.env, "yes");
+    let no = String::from_str(&test.env, "no");
+    let rival = test.create_funded_user();
+    let buyer = Address::generate(&test.env);
+    test.env.mock_all_auths();
+
+    let foreign = register_position_token(&test, &test.admin);
+    assert_eq!(
+        client.try_set_position_token(&test.admin, &market_id, &yes, &foreign),
+        Err(Ok(Error::InvalidInput))
+    );
+    let token_id = register_position_token(&test, &test.contract_id);
+    client.set_position_token(&test.admin, &market_id, &yes, &token_id);
+    assert_eq!(
+        client.get_position_token(&market_id, &yes),
+        Some(token_id.clone())
+    );
+
+    client.vote(&test.user, &market_id, &yes, &30_0000000);
+    client.vote(&rival, &market_id, &no, &70_0000000);
+    assert_eq!(
+        client.try_tokenize_position(&rival, &market_id, &1_0000000),
+        Err(Ok(Error::InvalidState))
+    );
+    client.tokenize_position(&test.user, &market_id, &20_0000000);
+
+    // Shares still back the outcome and trade like any token
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.outcome_total(&yes), 30_0000000);
+    assert_eq!(
+        client.get_vote_entry(&market_id, &test.user).unwrap().stake,
+        10_0000000
+    );
+    let shares = TokenClient::new(&test.env, &token_id);
+    shares.transfer(&test.user, &buyer, &20_0000000);
+    assert_eq!(shares.balance(&buyer), 20_0000000);
+
+    test.env.as_contract(&test.contract_id, || {
+        let mut market = test
+            .env
+            .storage()
+            .persistent()
+            .get::<Symbol, Market>(&market_id)
+            .unwrap();
+        market.winning_outcomes = Some(vec![&test.env, yes.clone()]);
+        market.state = MarketState::Resolved;
+        test.env.storage().persistent().set(&market_id, &market);
+    });
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + market.dispute_window_seconds + 1,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+
+    // Each unit of stake, tokenized or not, is paid the same
+    let net_pool = 100_0000000 * (100 - market.platform_fee_percentage) / 100;
+    client.claim_winnings(&buyer, &market_id);
+    assert_eq!(shares.balance(&buyer), 0);
+    assert_eq!(
+        client.get_balance(&buyer, &ReflectorAsset::Stellar).amount,
+        net_pool * 2 / 3
+    );
+    client.claim_winnings(&test.user, &market_id);
+    assert_eq!(
+        client
+            .get_balance(&test.user, &ReflectorAsset::Stellar)
+            .amount,
+        net_pool / 3
+    );
+    assert_eq!(
+        client.try_claim_winnings(&buyer, &market_id),
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            Error::NothingToClaim as u32
+        )))
+    );
+}
+
+#[test]
+fn test_tokenizing_keeps_quadratic_consensus_weight() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let yes = String::from_str(&test.env, "yes");
+    let no = String::from_str(&test.env, "no");
+    test.env.mock_all_auths();
+
+    client.set_weighting_mode(&test.admin, &market_id, &WeightingMode::Quadratic);
+    let token_id = register_position_token(&test, &test.contract_id);
+    client.set_position_token(&test.admin, &market_id, &yes, &token_id);
+    client.vote(&test.user, &market_id, &yes, &64_0000000);
+    let voter = test.create_funded_user();
+    client.vote(&voter, &market_id, &no, &36_0000000);
+    let weight = client
+        .get_market(&market_id)
+        .unwrap()
+        .consensus_weight(&yes);
+
+    client.tokenize_position(&test.user, &market_id, &48_0000000);
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.consensus_weight(&yes), weight);
+    assert_eq!(market.outcome_total(&yes), 64_0000000);
+
+    // Tokenizing the rest keeps it too
+    client.tokenize_position(&test.user, &market_id, &16_0000000);
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.consensus_weight(&yes), weight);
+    assert_eq!(market.outcome_voter_count(&yes), 0);
+}
+
+#[test]
+fn test_tokenized_position_refunds_at_face_value_when_cancelled() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let yes = String::from_str(&test.env, "yes");
+    let buyer = Address::generate(&test.env);
+    let token = TokenClient::new(&test.env, &test.token_test.token_id);
+    test.env.mock_all_auths();
+
+    let token_id = register_position_token(&test, &test.contract_id);
+    client.set_position_token(&test.admin, &market_id, &yes, &token_id);
+    client.vote(&test.user, &market_id, &yes, &30_0000000);
+    client.tokenize_position(&test.user, &market_id, &30_0000000);
+    TokenClient::new(&test.env, &token_id).transfer(&test.user, &buyer, &30_0000000);
+
+    test.env.as_contract(&test.contract_id, || {
+        let mut market = test
+            .env
+            .storage()
+            .persistent()
+            .get::<Symbol, Market>(&market_id)
+            .unwrap();
+        market.state = MarketState::Cancelled;
+        test.env.storage().persistent().set(&market_id, &market);
+    });
+
+    assert_eq!(client.claim_refund(&buyer, &market_id), 30_0000000);
+    assert_eq!(token.balance(&buyer), 30_0000000);
+    assert_eq!(
+        client.try_claim_refund(&test.user, &market_id),
+        Err(Ok(Error::NothingToClaim))
+    );
+}
+
+#[test]
+fn test_commit_reveal_votes_are_sealed_until_revealed() {
+    use soroban_sdk::xdr::ToXdr;
+
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let yes = String::from_str(&test.env, "yes");
+    let token = TokenClient::new(&test.env, &test.token_test.token_id);
+    let quiet = test.create_funded_user();
+    test.env.mock_all_auths();
+
+    let commit_deadline = client.get_market(&market_id).unwrap().end_time;
+    let config = client.enable_commit_reveal(&test.admin, &market_id, &3_600);
+    assert_eq!(config.commit_deadline, commit_deadline);
+    assert_eq!(
+        client.get_market(&market_id).unwrap().end_time,
+        commit_deadline + 3_600
+    );
+
+    let salt = BytesN::from_array(&test.env, &[9u8; 32]);
+    let mut preimage = test.user.clone().to_xdr(&test.env);
+    preimage.append(&soroban_sdk::Bytes::from_slice(&test.env, b"yes"));
+    preimage.extend_from_array(&salt.to_array());
+    let commitment: BytesN<32> = test.env.crypto().sha256(&preimage).into();
+    client.commit_vote(&test.user, &market_id, &commitment, &10_0000000);
+    // A copied commitment is accepted but can never be revealed
+    client.commit_vote(&quiet, &market_id, &commitment, &10_0000000);
+
+    // Tallies stay hidden and public votes are refused
+    assert_eq!(client.get_market(&market_id).unwrap().total_staked, 0);
+    assert_eq!(
+        client.try_vote(&test.user, &market_id, &yes, &1_0000000),
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            Error::InvalidState as u32
+        )))
+    );
+    assert_eq!(
+        client.try_reveal_vote(&test.user, &market_id, &yes, &salt),
+        Err(Ok(Error::InvalidState))
+    );
+
+    test.env.ledger().set(LedgerInfo {
+        timestamp: commit_deadline,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+    assert_eq!(
+        client.try_commit_vote(&test.user, &market_id, &commitment, &1_0000000),
+        Err(Ok(Error::MarketClosed))
+    );
+    assert_eq!(
+        client.try_reveal_vote(
+            &test.user,
+            &market_id,
+            &yes,
+            &BytesN::from_array(&test.env, &[1u8; 32])
+        ),
+        Err(Ok(Error::InvalidInput))
+    );
+    client.reveal_vote(&test.user, &market_id, &yes, &salt);
+    assert_eq!(
+        client.try_reveal_vote(&quiet, &market_id, &yes, &salt),
+        Err(Ok(Error::InvalidInput))
+    );
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.total_staked, 10_0000000);
+    assert_eq!(market.outcome_total(&yes), 10_0000000);
+    assert_eq!(
+        client.try_reclaim_unrevealed_vote(&quiet, &market_id),
+        Err(Ok(Error::InvalidState))
+    );
+
+    test.env.ledger().set(LedgerInfo {
+        timestamp: commit_deadline + 3_600,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+    let before = token.balance(&quiet);
+    assert_eq!(
+        client.reclaim_unrevealed_vote(&quiet, &market_id),
+        9_5000000
+    );
+    assert_eq!(token.balance(&quiet), before + 9_5000000);
+    assert_eq!(client.get_treasury_balance().commit_penalties, 5000000);
+    assert_eq!(
+        client.try_reclaim_unrevealed_vote(&quiet, &market_id),
+        Err(Ok(Error::AlreadyClaimed))
+    );
+    assert_eq!(
+        client
+            .get_commit_reveal_config(&market_id)
+            .unwrap()
+            .revealed,
+        1
+    );
+}
+
+#[test]
+fn test_private_market_only_accepts_allowlisted_addresses() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let yes = String::from_str(&test.env, "yes");
+    let outsider = test.create_funded_user();
+    let unauthorized = soroban_sdk::Error::from_contract_error(Error::Unauthorized as u32);
+    test.env.mock_all_auths();
+
+    assert_eq!(
+        client.get_market_visibility(&market_id),
+        EventVisibility::Public
+    );
+    assert!(client.can_participate(&market_id, &outsider));
+    assert_eq!(
+        client.try_set_market_visibility(&outsider, &market_id, &EventVisibility::Private),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.set_market_visibility(&test.admin, &market_id, &EventVisibility::Private);
+    client.add_to_market_allowlist(&test.admin, &market_id, &vec![&test.env, test.user.clone()]);
+
+    assert!(!client.can_participate(&market_id, &outsider));
+    assert_eq!(
+        client
+            .try_vote(&outsider, &market_id, &yes, &1_0000000)
+            .unwrap_err(),
+        Ok(unauthorized)
+    );
+    assert_eq!(
+        client
+            .try_place_bet(&outsider, &market_id, &yes, &1_0000000)
+            .unwrap_err(),
+        Ok(unauthorized)
+    );
+    assert_eq!(
+        client.try_dispute_market(&outsider, &market_id, &10_0000000, &None),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.vote(&test.user, &market_id, &yes, &1_0000000);
+
+    client.remove_from_market_allowlist(
+        &test.admin,
+        &market_id,
+        &vec![&test.env, test.user.clone()],
+    );
+    assert!(!client.can_participate(&market_id, &test.user));
+    client.set_market_visibility(&test.admin, &market_id, &EventVisibility::Public);
+    client.vote(&outsider, &market_id, &yes, &1_0000000);
+}
+
+#[test]
+fn test_stake_limits_cap_a_single_users_stake_and_pool_share() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let yes = String::from_str(&test.env, "yes");
+    let no = String::from_str(&test.env, "no");
+    let whale = test.create_funded_user();
+    let above_user_limit = soroban_sdk::Error::from_contract_error(
+        crate::errors::ExtendedError::StakeAboveUserLimit as u32,
+    );
+    let above_pool_share = soroban_sdk::Error::from_contract_error(
+        crate::errors::ExtendedError::PoolShareLimitExceeded as u32,
+    );
+    test.env.mock_all_auths();
+
+    let limits = crate::voting::StakeLimits {
+        max_stake_per_user: 100_0000000,
+        max_pool_share_bps: 2_000,
+        min_pool: 50_0000000,
+    };
+    assert_eq!(
+        client.try_set_stake_limits(&test.user, &limits),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.set_stake_limits(&test.admin, &limits);
+    assert_eq!(client.get_stake_limits(), limits);
+
+    assert_eq!(
+        client
+            .try_vote(&whale, &market_id, &yes, &101_0000000)
+            .unwrap_err(),
+        Ok(above_user_limit)
+    );
+    // Below `min_pool` the share limit does not apply yet
+    client.vote(&test.user, &market_id, &no, &10_0000000);
+    for _ in 0..4 {
+        let voter = test.create_funded_user();
+        client.vote(&voter, &market_id, &no, &10_0000000);
+    }
+
+    // 20 XLM would be 20/70 of the pool; 12 XLM stays under 20%
+    assert_eq!(
+        client
+            .try_vote(&whale, &market_id, &yes, &20_0000000)
+            .unwrap_err(),
+        Ok(above_pool_share)
+    );
+    client.vote(&whale, &market_id, &yes, &12_0000000);
+    assert_eq!(
+        client.try_add_stake(&whale, &market_id, &10_0000000),
+        Err(Err(soroban_sdk::InvokeError::Contract(
+            crate::errors::ExtendedError::PoolShareLimitExceeded as u32
+        )))
+    );
+    assert_eq!(
+        client.add_stake(&test.user, &market_id, &2_0000000),
+        12_0000000
+    );
+}
+
+#[test]
+fn test_large_stake_in_final_minutes_extends_end_time() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let yes = String::from_str(&test.env, "yes");
+    test.env.mock_all_auths();
+
+    let config = crate::extensions::AntiSnipeConfig {
+        threshold: 50_0000000,
+        window_seconds: 600,
+        extension_seconds: 300,
+    };
+    assert_eq!(
+        client.try_set_anti_snipe_config(&test.user, &config),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.set_anti_snipe_config(&test.admin, &config);
+
+    // Large stakes before the final window do not extend
+    let end_time = client.get_market(&market_id).unwrap().end_time;
+    client.vote(&test.user, &market_id, &yes, &60_0000000);
+    assert_eq!(client.get_market(&market_id).unwrap().end_time, end_time);
+
+    test.env.ledger().set(LedgerInfo {
+        timestamp: end_time - 120,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+    // Small stakes in the window do not either
+    let small = test.create_funded_user();
+    client.vote(&small, &market_id, &yes, &10_0000000);
+    assert_eq!(client.get_market(&market_id).unwrap().end_time, end_time);
+
+    let sniper = test.create_funded_user();
+    client.vote(&sniper, &market_id, &yes, &50_0000000);
+    assert_eq!(
+        client.get_market(&market_id).unwrap().end_time,
+        end_time + 300
+    );
+    client.add_stake(&test.user, &market_id, &50_0000000);
+    assert_eq!(
+        client.get_market(&market_id).unwrap().end_time,
+        end_time + 600
+    );
+    assert_eq!(client.get_anti_snipe_extension(&market_id), 600);
+
+    let event = test.env.as_contract(&test.contract_id, || {
+        test.env
+            .storage()
+            .persistent()
+            .get::<Symbol, crate::extensions::AntiSnipeExtension>(&symbol_short!("snipe_ext"))
+            .unwrap()
+    });
+    assert_eq!(event.added_seconds, 300);
+    assert_eq!(event.new_end_time, end_time + 600);
+}
+
+#[test]
+fn test_admin_extensions_count_anti_snipe_time() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    test.env.mock_all_auths();
+
+    client.set_anti_snipe_config(
+        &test.admin,
+        &crate::extensions::AntiSnipeConfig {
+            threshold: 50_0000000,
+            window_seconds: 600,
+            extension_seconds: 86_400,
+        },
+    );
+    let end_time = client.get_market(&market_id).unwrap().end_time;
+    test.env.ledger().set(LedgerInfo {
+        timestamp: end_time - 120,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+    client.vote(
+        &test.user,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &50_0000000,
+    );
+    assert_eq!(client.get_anti_snipe_extension(&market_id), 86_400);
+
+    // A day of the 30 day budget went to anti-sniping
+    let reason = String::from_str(&test.env, "More time");
+    assert_eq!(
+        client.try_extend_market(&test.admin, &market_id, &30, &reason, &0),
+        Err(Ok(Error::ExtensionDenied))
+    );
+    client.extend_market(&test.admin, &market_id, &29, &reason, &0);
+    assert_eq!(
+        client.get_market(&market_id).unwrap().end_time,
+        end_time + 30 * 86_400
+    );
+}
+
+#[test]
+fn test_dispute_vote_does_not_overwrite_market_vote_entry() {
+    let test = PredictifyTest::setup();
+    let market_id = test.create_test_market();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+
+    test.env.mock_all_auths();
+    client.vote(
+        &test.user,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &100_0000000,
+    );
+
+    // Dispute ids reuse the market id, so the user's dispute vote shares the
+    // (market, user) pair with their market position
+    test.env.as_contract(&test.contract_id, || {
+        crate::disputes::DisputeUtils::store_dispute_vote(
+            &test.env,
+            &market_id,
+            &crate::disputes::DisputeVote {
+                user: test.user.clone(),
+                dispute_id: market_id.clone(),
+                vote: true,
+                stake: 10_0000000,
+                timestamp: test.env.ledger().timestamp(),
+                reason: None,
+            },
+        )
+        .unwrap();
+    });
+
+    let entry = client.get_vote_entry(&market_id, &test.user).unwrap();
+    assert_eq!(entry.outcome, String::from_str(&test.env, "yes"));
+    assert_eq!(entry.stake, 100_0000000);
+
+    let market = client.get_market(&market_id).unwrap();
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + 1,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + market.dispute_window_seconds,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+
+    client.claim_winnings(&test.user, &market_id);
+    assert!(
+        client
+            .get_balance(&test.user, &ReflectorAsset::Stellar)
+            .amount
+            > 0
+    );
+    assert!(
+        client
+            .get_vote_entry(&market_id, &test.user)
+            .unwrap()
+            .claimed
+    );
+}
+
+#[test]
+fn test_traded_amm_markets_are_not_delisted_or_cancelled_as_empty() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let yes = String::from_str(&test.env, "yes");
+    test.env.mock_all_auths();
+
+    let market_id = client.create_amm_market(
+        &test.admin,
+        &String::from_str(&test.env, "Will BTC go above $25,000 by December 31?"),
+        &vec![&test.env, yes.clone(), String::from_str(&test.env, "no")],
+        &30,
+        &OracleConfig {
+            provider: OracleProvider::Reflector,
+            oracle_address: Address::generate(&test.env),
+            feed_id: String::from_str(&test.env, "BTC"),
+            threshold: 2500000,
+            comparison: String::from_str(&test.env, "gt"),
+        },
+        &100_0000000,
+    );
+    client.buy_shares(&test.user, &market_id, &yes, &10_0000000, &i128::MAX);
+    client.set_listing_window(&test.admin, &3600);
+    client.set_degenerate_market_policy(
+        &test.admin,
+        &crate::edge_cases::DegenerateMarketPolicy {
+            cancel_zero_stake: true,
+            cancel_single_voter: true,
+            cancel_one_sided: true,
+        },
+    );
+
+    test.env.ledger().set(LedgerInfo {
+        timestamp: test.env.ledger().timestamp() + 3600,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+    assert_eq!(
+        client.try_delist_expired_market(&market_id),
+        Err(Ok(Error::InvalidState))
+    );
+
+    let market = client.get_market(&market_id).unwrap();
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + 1,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+    client.resolve_market_manual(&test.admin, &market_id, &yes);
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.state, MarketState::Resolved);
+    assert_eq!(market.winning_outcomes, Some(vec![&test.env, yes]));
+}
+
+#[test]
+fn test_commit_reveal_markets_are_not_delisted_while_votes_are_sealed() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    test.env.mock_all_auths();
+
+    client.enable_commit_reveal(&test.admin, &market_id, &3_600);
+    client.commit_vote(
+        &test.user,
+        &market_id,
+        &BytesN::from_array(&test.env, &[7u8; 32]),
+        &10_0000000,
+    );
+    client.set_listing_window(&test.admin, &3600);
+    test.env.ledger().set(LedgerInfo {
+        timestamp: test.env.ledger().timestamp() + 3600,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+
+    assert_eq!(client.get_market(&market_id).unwrap().voter_count, 0);
+    assert_eq!(
+        client.try_delist_expired_market(&market_id),
+        Err(Ok(Error::InvalidState))
+    );
+    assert!(client.get_market(&market_id).is_some());
+}
+
+#[test]
+fn test_small_market_fee_policies_stay_solvent() {
+    use crate::fees::{SmallMarketFeeMode, SmallMarketFeePolicy};
+
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token = TokenClient::new(&test.env, &test.token_test.token_id);
+    let yes = String::from_str(&test.env, "yes");
+    let no = String::from_str(&test.env, "no");
+    test.env.mock_all_auths();
+
+    for mode in [SmallMarketFeeMode::Flat, SmallMarketFeeMode::Waive] {
+        // A flat fee far above the 2% withheld from payouts
+        client.set_small_market_fee_policy(
+            &test.admin,
+            &SmallMarketFeePolicy {
+                mode: mode.clone(),
+                flat_fee: 5_0000000,
+            },
+        );
+        let market_id = test.create_test_market();
+        let winners = [test.create_funded_user(), test.create_funded_user()];
+        client.vote(&winners[0], &market_id, &yes, &2_0000000);
+        client.vote(&winners[1], &market_id, &yes, &3_0000000);
+        let loser = test.create_funded_user();
+        client.vote(&loser, &market_id, &no, &4_0000000);
+
+        let market = client.get_market(&market_id).unwrap();
+        let set_time = |timestamp: u64| {
+            test.env.ledger().set(LedgerInfo {
+                timestamp,
+                protocol_version: 22,
+                sequence_number: test.env.ledger().sequence(),
+                network_id: Default::default(),
+                base_reserve: 10,
+                min_temp_entry_ttl: 1,
+                min_persistent_entry_ttl: 1,
+                max_entry_ttl: 10000,
+            });
+        };
+        set_time(market.end_time + 1);
+        // Resolution collects the fee
+        client.resolve_market_manual(&test.admin, &market_id, &yes);
+        set_time(market.end_time + market.dispute_window_seconds);
+        for winner in winners.iter() {
+            client.claim_winnings(winner, &market_id);
+        }
+
+        // Everything owed is still backed by the contract's tokens
+        let paid = winners
+            .iter()
+            .map(|winner| client.get_balance(winner, &ReflectorAsset::Stellar).amount)
+            .sum::<i128>();
+        let owed = client.get_treasury_balance().balance + paid;
+        assert!(token.balance(&test.contract_id) - owed >= 0);
+        if mode == SmallMarketFeeMode::Waive {
+            // Winners get the whole pool when the fee is waived
+            assert_eq!(paid, 9_0000000);
+        }
+    }
+}
+
+#[test]
+fn test_unvoted_markets_are_delisted_after_listing_window() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token = TokenClient::new(&test.env, &test.token_test.token_id);
+    let creator = test.create_funded_user();
+
+    test.env.mock_all_auths();
+    let start_balance = token.balance(&creator);
+    let quiet_id = client.create_market_public(
+        &creator,
+        &String::from_str(&test.env, "Will XLM close above $1 this month?"),
+        &vec![
+            &test.env,
+            String::from_str(&test.env, "yes"),
+            String::from_str(&test.env, "no"),
+        ],
+        &30,
+        &OracleConfig {
+            provider: OracleProvider::Reflector,
+            oracle_address: Address::generate(&test.env),
+            feed_id: String::from_str(&test.env, "XLM"),
+            threshold: 1_0000000,
+            comparison: String::from_str(&test.env, "gt"),
+        },
+    );
+    let voted_id = test.create_test_market();
+    client.vote(
+        &test.user,
+        &voted_id,
+        &String::from_str(&test.env, "yes"),
+        &1_0000000,
+    );
+    assert_eq!(
+        client.get_listing_window(),
+        crate::config::DEFAULT_MARKET_LISTING_WINDOW_SECONDS
+    );
+    assert!(client.try_set_listing_window(&test.user, &3600).is_err());
+    client.set_listing_window(&test.admin, &3600);
+
+    // Still within the window
+    assert_eq!(
+        client.try_delist_expired_market(&quiet_id),
+        Err(Ok(Error::InvalidState))
+    );
+
+    test.env.ledger().set(LedgerInfo {
+        timestamp: test.env.ledger().timestamp() + 3600,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+
+    // Markets with votes stay listed
+    assert_eq!(
+        client.try_delist_expired_market(&voted_id),
+        Err(Ok(Error::InvalidState))
+    );
+
+    let bond = crate::config::DEFAULT_MARKET_CREATION_BOND;
+    assert_eq!(client.delist_expired_market(&quiet_id), bond);
+    assert_eq!(token.balance(&creator), start_balance);
+    assert!(client.get_market(&quiet_id).is_none());
+    assert_eq!(
+        client.get_creation_bond(&quiet_id).unwrap().status,
+        crate::markets::CreationBondStatus::Refunded
+    );
+    let listed = client.get_markets(&0, &10);
+    assert!(!listed.contains(&quiet_id));
+    assert!(listed.contains(&voted_id));
+    assert_eq!(client.get_market_count(), 2);
+}
+
+#[test]
+fn test_arbitration_panel_draw_commits_randomness_in_advance() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let candidates = vec![
+        &test.env,
+        Address::generate(&test.env),
+        Address::generate(&test.env),
+        Address::generate(&test.env),
+        Address::generate(&test.env),
+        Address::generate(&test.env),
+    ];
+    let seed = soroban_sdk::BytesN::from_array(&test.env, &[7u8; 32]);
+    let commitment: soroban_sdk::BytesN<32> = test
+        .env
+        .crypto()
+        .sha256(&soroban_sdk::Bytes::from_array(&test.env, &seed.to_array()))
+        .into();
+
+    test.env.mock_all_auths();
+    assert!(client
+        .try_commit_arbitration_draw(&test.user, &market_id, &candidates, &3, &commitment)
+        .is_err());
+    assert_eq!(
+        client.try_commit_arbitration_draw(&test.admin, &market_id, &candidates, &6, &commitment),
+        Err(Ok(Error::InvalidInput))
+    );
+    client.commit_arbitration_draw(&test.admin, &market_id, &candidates, &3, &commitment);
+
+    // The seed cannot be revealed in the commitment's own ledger
+    assert_eq!(
+        client.try_reveal_arbitration_draw(&market_id, &seed),
+        Err(Ok(Error::InvalidState))
+    );
+    test.env.ledger().set(LedgerInfo {
+        timestamp: test.env.ledger().timestamp(),
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence() + 1,
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+    assert_eq!(
+        client.try_reveal_arbitration_draw(
+            &market_id,
+            &soroban_sdk::BytesN::from_array(&test.env, &[8u8; 32])
+        ),
+        Err(Ok(Error::InvalidInput))
+    );
+
+    let panel = client.reveal_arbitration_draw(&market_id, &seed);
+    assert_eq!(panel.len(), 3);
+    for arbitrator in panel.iter() {
+        assert!(candidates.contains(&arbitrator));
+    }
+    assert_eq!(
+        client
+            .get_arbitration_panel(&market_id)
+            .unwrap()
+            .arbitrators,
+        panel
+    );
+
+    // Anyone can recompute the draw from the revealed seed
+    let draw = client.get_arbitration_draw(&market_id).unwrap();
+    assert!(draw.revealed);
+    assert_eq!(draw.seed, seed);
+    assert_eq!(
+        crate::disputes::PanelDrawManager::draw_panel(&test.env, &seed, &draw.candidates, 3),
+        panel
+    );
+    assert_eq!(
+        client.try_reveal_arbitration_draw(&market_id, &seed),
+        Err(Ok(Error::InvalidState))
+    );
+}
+
+#[test]
+fn test_quiet_period_rejects_new_stakes_before_market_end() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let yes = String::from_str(&test.env, "yes");
+    let late = test.create_funded_user();
+    let closed = soroban_sdk::Error::from_contract_error(Error::MarketClosed as u32);
+
+    test.env.mock_all_auths();
+    client.vote(&test.user, &market_id, &yes, &10_0000000);
+    assert!(client
+        .try_set_vote_quiet_period_hours(&test.user, &2)
+        .is_err());
+    client.set_vote_quiet_period_hours(&test.admin, &2);
+    assert_eq!(client.get_contract_config().voting.quiet_period_hours, 2);
+
+    let end_time = client.get_market(&market_id).unwrap().end_time;
+    let quiet_start = client.get_vote_quiet_period_start(&market_id);
+    assert_eq!(quiet_start, end_time - 2 * 3600);
+
+    test.env.ledger().set(LedgerInfo {
+        timestamp: quiet_start,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+    assert_eq!(
+        client
+            .try_vote(&late, &market_id, &yes, &10_0000000)
+            .unwrap_err(),
+        Ok(closed)
+    );
+    assert_eq!(
+        client
+            .try_place_bet(&late, &market_id, &yes, &10_0000000)
+            .unwrap_err(),
+        Ok(closed)
+    );
+    assert_eq!(
+        client.try_add_stake(&test.user, &market_id, &5_0000000),
+        Err(Ok(Error::MarketClosed))
+    );
+
+    // Turning the quiet period off reopens staking until the end time
+    client.set_vote_quiet_period_hours(&test.admin, &0);
+    client.vote(&late, &market_id, &yes, &10_0000000);
+}
+
+#[test]
+fn test_vote_changes_freeze_before_market_end() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let yes = String::from_str(&test.env, "yes");
+    let no = String::from_str(&test.env, "no");
+
+    test.env.mock_all_auths();
+    client.vote(&test.user, &market_id, &yes, &10_0000000);
+    assert!(client
+        .try_set_vote_change_freeze_hours(&test.user, &12)
+        .is_err());
+    client.set_vote_change_freeze_hours(&test.admin, &12);
+    assert_eq!(
+        client.get_contract_config().voting.vote_change_freeze_hours,
+        12
+    );
+
+    let end_time = client.get_market(&market_id).unwrap().end_time;
+    let freeze_time = client.get_vote_change_freeze_time(&market_id);
+    assert_eq!(freeze_time, end_time - 12 * 3600);
+
+    let set_time = |timestamp: u64| {
+        test.env.ledger().set(LedgerInfo {
+            timestamp,
+            protocol_version: 22,
+            sequence_number: test.env.ledger().sequence(),
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 1,
+            min_persistent_entry_ttl: 1,
+            max_entry_ttl: 10000,
+        });
+    };
+
+    // Changes are allowed up to the freeze, then rejected
+    set_time(freeze_time - 1);
+    client.change_vote(&test.user, &market_id, &no);
+    set_time(freeze_time);
+    assert_eq!(
+        client.try_change_vote(&test.user, &market_id, &yes),
+        Err(Ok(Error::MarketClosed))
+    );
+    assert_eq!(
+        client
+            .get_vote_entry(&market_id, &test.user)
+            .unwrap()
+            .outcome,
+        no
+    );
+}
+
+#[test]
+fn test_batch_vote_is_atomic_with_single_transfer() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let first = test.create_test_market();
+    let second = test.create_test_market();
+    let yes = String::from_str(&test.env, "yes");
+    let no = String::from_str(&test.env, "no");
+    let token = TokenClient::new(&test.env, &test.token_test.token_id);
+    let balance_before = token.balance(&test.user);
+
+    test.env.mock_all_auths();
+    // One bad entry rejects the whole batch
+    let invalid = vec![
+        &test.env,
+        (first.clone(), yes.clone(), 10_0000000),
+        (
+            second.clone(),
+            String::from_str(&test.env, "maybe"),
+            5_0000000,
+        ),
+    ];
+    assert_eq!(
+        client.try_batch_vote(&test.user, &invalid),
+        Err(Ok(Error::InvalidOutcome))
+    );
+    assert!(client.get_vote_entry(&first, &test.user).is_none());
+    assert_eq!(token.balance(&test.user), balance_before);
+
+    let repeated = vec![
+        &test.env,
+        (first.clone(), yes.clone(), 10_0000000),
+        (first.clone(), no.clone(), 5_0000000),
+    ];
+    assert_eq!(
+        client.try_batch_vote(&test.user, &repeated),
+        Err(Ok(Error::InvalidInput))
+    );
+
+    let votes = vec![
+        &test.env,
+        (first.clone(), yes.clone(), 10_0000000),
+        (second.clone(), no.clone(), 5_0000000),
+    ];
+    assert_eq!(client.batch_vote(&test.user, &votes), 15_0000000);
+    assert_eq!(token.balance(&test.user), balance_before - 15_0000000);
+
+    let entry = client.get_vote_entry(&first, &test.user).unwrap();
+    assert_eq!(entry.outcome, yes);
+    assert_eq!(entry.stake, 10_0000000);
+    let market = client.get_market(&second).unwrap();
+    assert_eq!(market.total_staked, 5_0000000);
+    assert_eq!(market.outcome_total(&no), 5_0000000);
+
+    // Voting again on either market fails
+    assert_eq!(
+        client.try_batch_vote(&test.user, &votes),
+        Err(Ok(Error::AlreadyVoted))
+    );
+}
+
+#[test]
+fn test_sensitive_analytics_require_analyst_role() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let outsider = Address::generate(&test.env);
+    let analyst = Address::generate(&test.env);
+
+    test.env.mock_all_auths();
+    client.place_bet(
+        &test.user,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &10_0000000,
+    );
+
+    // Users can always read their own statistics
+    let own = client.get_user_statistics(&test.user, &test.user);
+    assert_eq!(own.total_amount_wagered, 10_0000000);
+
+    assert_eq!(
+        client.try_get_user_statistics(&outsider, &test.user),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_get_market_anomalies(&outsider, &market_id),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_get_market_anomalies(&test.user, &market_id),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    client.add_admin(&test.admin, &analyst, &AdminRole::Analyst);
+    let stats = client.get_user_statistics(&analyst, &test.user);
+    assert_eq!(stats, own);
+    assert!(client.get_market_anomalies(&analyst, &market_id).is_empty());
+
+    // Market-level aggregates stay public
+    client.get_platform_statistics();
+}
+
+#[test]
+fn test_fees_are_collected_to_treasury_at_resolution() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let auto = test.create_test_market();
+    let manual = test.create_test_market();
+    let yes = String::from_str(&test.env, "yes");
+
+    test.env.mock_all_auths();
+    client.vote(&test.user, &auto, &yes, &100_0000000);
+    client.vote(&test.user, &manual, &yes, &100_0000000);
+    assert!(client.is_auto_fee_collection_enabled(&manual));
+    client.set_auto_fee_collection(&test.admin, &manual, &false);
+    assert!(!client.is_auto_fee_collection_enabled(&manual));
+
+    let market = client.get_market(&auto).unwrap();
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + 1,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+
+    client.resolve_market_manual(&test.admin, &auto, &yes);
+    let fee = 100_0000000 * market.platform_fee_percentage / 100;
+    assert!(fee > 0);
+    assert_eq!(client.get_treasury_balance().platform_fees, fee);
+    let resolved = client.get_market(&auto).unwrap();
+    assert!(resolved.fee_collected);
+    assert_eq!(resolved.state, MarketState::Resolved);
+    assert_eq!(
+        client.try_collect_fees(&test.admin, &auto),
+        Err(Ok(Error::FeeAlreadyCollected))
+    );
+
+    // Opted-out markets leave the fee for collect_fees
+    client.resolve_market_manual(&test.admin, &manual, &yes);
+    assert_eq!(client.get_treasury_balance().balance, fee);
+    assert!(!client.get_market(&manual).unwrap().fee_collected);
+    assert_eq!(client.collect_fees(&test.admin, &manual), fee);
+}
+
+#[test]
+fn test_resolution_attestation_commits_to_final_result() {
+    use soroban_sdk::xdr::ToXdr;
+
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let yes = String::from_str(&test.env, "yes");
+
+    test.env.mock_all_auths();
+    client.vote(&test.user, &market_id, &yes, &10_0000000);
+    assert_eq!(
+        client.try_get_resolution_attestation(&market_id),
+        Err(Ok(Error::MarketNotResolved))
+    );
+
+    let market = client.get_market(&market_id).unwrap();
+    let resolved_at = market.end_time + 10;
+    test.env.ledger().set(LedgerInfo {
+        timestamp: resolved_at,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+    client.resolve_market_manual(&test.admin, &market_id, &yes);
+    // Not attested while the result can still be disputed
+    assert_eq!(
+        client.try_get_resolution_attestation(&market_id),
+        Err(Ok(Error::InvalidState))
+    );
+
+    let finalized_at = market.end_time + market.dispute_window_seconds + 1;
+    test.env.ledger().set(LedgerInfo {
+        timestamp: finalized_at,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+    client.finalize_result(&market_id);
+
+    let attestation = client.get_resolution_attestation(&market_id);
+    assert_eq!(attestation.contract, test.contract_id);
+    assert_eq!(attestation.winning_outcomes, vec![&test.env, yes]);
+    assert_eq!(attestation.end_time, market.end_time);
+    assert_eq!(attestation.resolved_at, resolved_at);
+    assert_eq!(attestation.finalized_at, finalized_at);
+
+    let definition = (
+        market_id.clone(),
+        market.question.clone(),
+        market.outcomes.clone(),
+        market.end_time,
+    );
+    let market_hash: soroban_sdk::BytesN<32> = test
+        .env
+        .crypto()
+        .sha256(&definition.to_xdr(&test.env))
+        .into();
+    assert_eq!(attestation.market_hash, market_hash);
+    let committed = (
+        attestation.contract.clone(),
+        attestation.market_id.clone(),
+        attestation.market_hash.clone(),
+        attestation.winning_outcomes.clone(),
+        attestation.end_time,
+        attestation.resolved_at,
+        attestation.finalized_at,
+    );
+    let attestation_hash: soroban_sdk::BytesN<32> = test
+        .env
+        .crypto()
+        .sha256(&committed.to_xdr(&test.env))
+        .into();
+    assert_eq!(attestation.attestation_hash, attestation_hash);
+}
+
+#[test]
+fn test_jumbo_payouts_vest_linearly() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let yes = String::from_str(&test.env, "yes");
+    let loser = test.create_funded_user();
+
+    test.env.mock_all_auths();
+    assert_eq!(
+        client.try_set_vesting_policy(&test.admin, &0, &2),
+        Err(Ok(Error::InvalidInput))
+    );
+    client.set_vesting_policy(&test.admin, &5_0000000, &2);
+    client.vote(&test.user, &market_id, &yes, &10_0000000);
+    client.vote(
+        &loser,
+        &market_id,
+        &String::from_str(&test.env, "no"),
+        &10_0000000,
+    );
+
+    let market = client.get_market(&market_id).unwrap();
+    let set_time = |timestamp: u64| {
+        test.env.ledger().set(LedgerInfo {
+            timestamp,
+            protocol_version: 22,
+            sequence_number: test.env.ledger().sequence(),
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 1,
+            min_persistent_entry_ttl: 1,
+            max_entry_ttl: 10000,
+        });
+    };
+    set_time(market.end_time + 1);
+    client.resolve_market_manual(&test.admin, &market_id, &yes);
+    let claimed_at = market.end_time + market.dispute_window_seconds + 1;
+    set_time(claimed_at);
+    client.claim_winnings(&test.user, &market_id);
+
+    // 20 XLM pool minus the 2% fee, nothing credited yet
+    let payout = 19_6000000;
+    assert_eq!(
+        client
+            .get_balance(&test.user, &ReflectorAsset::Stellar)
+            .amount,
+        0
+    );
+    let schedule = client.get_vesting_schedule(&test.user, &market_id).unwrap();
+    assert_eq!(schedule.total, payout);
+    assert_eq!(schedule.duration_seconds, 2 * 86_400);
+    assert_eq!(
+        client.try_claim_vested(&test.user, &market_id),
+        Err(Ok(Error::NothingToClaim))
+    );
+
+    set_time(claimed_at + 86_400);
+    assert_eq!(client.claim_vested(&test.user, &market_id), payout / 2);
+    set_time(claimed_at + 10 * 86_400);
+    assert_eq!(client.claim_vested(&test.user, &market_id), payout / 2);
+    assert_eq!(
+        client
+            .get_balance(&test.user, &ReflectorAsset::Stellar)
+            .amount,
+        payout
+    );
+    assert_eq!(
+        client.try_claim_vested(&test.user, &market_id),
+        Err(Ok(Error::NothingToClaim))
+    );
+
+    // A further jumbo payout vests from when it is credited
+    let credit_again = || {
+        test.env.as_contract(&test.contract_id, || {
+            crate::vesting::VestingManager::credit_winnings(
+                &test.env, &market_id, &test.user, payout,
+            )
+            .unwrap();
+        })
+    };
+    credit_again();
+    assert_eq!(
+        client.try_claim_vested(&test.user, &market_id),
+        Err(Ok(Error::NothingToClaim))
+    );
+
+    // Topping up mid-schedule releases the vested half and restarts the rest
+    set_time(claimed_at + 11 * 86_400);
+    credit_again();
+    assert_eq!(
+        client
+            .get_balance(&test.user, &ReflectorAsset::Stellar)
+            .amount,
+        payout + payout / 2
+    );
+    let schedule = client.get_vesting_schedule(&test.user, &market_id).unwrap();
+    assert_eq!(schedule.total, payout + payout / 2);
+    assert_eq!(schedule.released, 0);
+    assert_eq!(
+        client.try_claim_vested(&test.user, &market_id),
+        Err(Ok(Error::NothingToClaim))
+    );
+    set_time(claimed_at + 20 * 86_400);
+    assert_eq!(
+        client.claim_vested(&test.user, &market_id),
+        payout + payout / 2
+    );
+}
+
+#[test]
+fn test_treasury_withdrawals_are_timelocked() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let yes = String::from_str(&test.env, "yes");
+    let recipient = Address::generate(&test.env);
+    let token = TokenClient::new(&test.env, &test.token_test.token_id);
+
+    test.env.mock_all_auths();
+    client.vote(&test.user, &market_id, &yes, &100_0000000);
+    let market = client.get_market(&market_id).unwrap();
+    let set_time = |timestamp: u64| {
+        test.env.ledger().set(LedgerInfo {
+            timestamp,
+            protocol_version: 22,
+            sequence_number: test.env.ledger().sequence(),
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 1,
+            min_persistent_entry_ttl: 1,
+            max_entry_ttl: 10000,
+        });
+    };
+    set_time(market.end_time + 1);
+    client.resolve_market_manual(&test.admin, &market_id, &yes);
+    let fee = client.get_treasury_balance().balance;
+    assert!(fee > 0);
+
+    assert!(client
+        .try_propose_treasury_withdrawal(&test.user, &recipient, &fee)
+        .is_err());
+    assert_eq!(
+        client.try_propose_treasury_withdrawal(&test.admin, &recipient, &(fee + 1)),
+        Err(Ok(Error::InsufficientBalance))
+    );
+    let first = client.propose_treasury_withdrawal(&test.admin, &recipient, &fee);
+    let second = client.propose_treasury_withdrawal(&test.admin, &recipient, &1);
+    assert_eq!(
+        client.try_execute_treasury_withdrawal(&test.admin, &first.id),
+        Err(Ok(Error::InvalidState))
+    );
+    client.cancel_treasury_withdrawal(&test.admin, &second.id);
+
+    set_time(first.executable_at);
+    assert_eq!(
+        client.execute_treasury_withdrawal(&test.admin, &first.id),
+        fee
+    );
+    assert_eq!(token.balance(&recipient), fee);
+    assert_eq!(
+        client.try_execute_treasury_withdrawal(&test.admin, &first.id),
+        Err(Ok(Error::InvalidState))
+    );
+    assert_eq!(
+        client.try_execute_treasury_withdrawal(&test.admin, &second.id),
+        Err(Ok(Error::InvalidState))
+    );
+
+    let balance = client.get_treasury_balance();
+    assert_eq!(balance.balance, 0);
+    assert_eq!(balance.withdrawn, fee);
+    let history = client.get_treasury_withdrawal_history();
+    assert_eq!(history.len(), 1);
+    assert_eq!(history.get(0).unwrap().to, recipient);
+    assert!(history.get(0).unwrap().executed);
+}
+
+#[test]
+fn test_create_market_v2_with_builder() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let oracle_config = OracleConfig {
+        provider: OracleProvider::Reflector,
+        oracle_address: Address::generate(&test.env),
+        feed_id: String::from_str(&test.env, "ETH"),
+        threshold: 300000,
+        comparison: String::from_str(&test.env, "gt"),
+    };
+    let outcomes = vec![
+        &test.env,
+        String::from_str(&test.env, "yes"),
+        String::from_str(&test.env, "no"),
+    ];
+    let builder = || {
+        MarketBuilder::new(
+            &test.env,
+            String::from_str(&test.env, "Will ETH trade above $3,000 this month?"),
+            outcomes.clone(),
+            30,
+            oracle_config.clone(),
+        )
+    };
+
+    test.env.mock_all_auths();
+    let short_tags = vec![&test.env, String::from_str(&test.env, "x")];
+    assert!(client
+        .try_create_market_v2(&test.admin, &builder().tags(short_tags).build())
+        .is_err());
+
+    let tags = vec![&test.env, String::from_str(&test.env, "eth")];
+    let params = builder()
+        .dispute_window_seconds(3_600)
+        .min_pool_size(50_0000000)
+        .category(String::from_str(&test.env, "crypto"))
+        .tags(tags.clone())
+        .build();
+    let market_id = client.create_market_v2(&test.admin, &params);
+
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.question, params.question);
+    assert_eq!(market.dispute_window_seconds, 3_600);
+    assert_eq!(market.min_pool_size, Some(50_0000000));
+    assert_eq!(market.category, Some(String::from_str(&test.env, "crypto")));
+    assert_eq!(market.tags, tags);
+    assert!(!market.has_fallback);
+}
+
+#[test]
+fn test_creator_earns_share_of_platform_fee() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let yes = String::from_str(&test.env, "yes");
+
+    test.env.mock_all_auths();
+    assert_eq!(
+        client.try_set_creator_fee_bps(&test.admin, &market_id, &5_001),
+        Err(Ok(Error::InvalidInput))
+    );
+    client.set_creator_fee_bps(&test.admin, &market_id, &2_000);
+    assert_eq!(
+        client.try_claim_creator_fees(&test.admin, &market_id),
+        Err(Ok(Error::NothingToClaim))
+    );
+    client.vote(&test.user, &market_id, &yes, &100_0000000);
+
+    let market = client.get_market(&market_id).unwrap();
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + 1,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+    client.resolve_market_manual(&test.admin, &market_id, &yes);
+
+    let fee = 100_0000000 * market.platform_fee_percentage / 100;
+    let creator_cut = fee * 2_000 / 10_000;
+    assert_eq!(
+        client.get_treasury_balance().platform_fees,
+        fee - creator_cut
+    );
+    assert_eq!(
+        client.get_creator_fee_share(&market_id).accrued,
+        creator_cut
+    );
+    // The share is fixed once fees are collected
+    assert_eq!(
+        client.try_set_creator_fee_bps(&test.admin, &market_id, &0),
+        Err(Ok(Error::FeeAlreadyCollected))
+    );
+
+    assert_eq!(
+        client.try_claim_creator_fees(&test.user, &market_id),
+        Err(Ok(Error::Unauthorized))
+    );
+    let token_client = TokenClient::new(&test.env, &test.token_test.token_id);
+    let before = token_client.balance(&test.admin);
+    assert_eq!(
+        client.claim_creator_fees(&test.admin, &market_id),
+        creator_cut
+    );
+    assert_eq!(token_client.balance(&test.admin), before + creator_cut);
+    assert_eq!(
+        client.get_creator_fee_share(&market_id).claimed,
+        creator_cut
+    );
+    assert_eq!(
+        client.try_claim_creator_fees(&test.admin, &market_id),
+        Err(Ok(Error::NothingToClaim))
+    );
+}
+
+#[test]
+fn test_resolution_weights_are_configurable() {
+    use crate::resolution::ResolutionWeights;
+
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let other = test.create_test_market();
+    let yes = String::from_str(&test.env, "yes");
+    let weights = |oracle_weight, community_weight| ResolutionWeights {
+        oracle_weight,
+        community_weight,
+    };
+
+    test.env.mock_all_auths();
+    assert_eq!(client.get_resolution_weights(&market_id), weights(70, 30));
+    assert_eq!(
+        client.try_set_resolution_weights(&test.admin, &weights(60, 30)),
+        Err(Ok(Error::InvalidInput))
+    );
+    client.set_resolution_weights(&test.admin, &weights(50, 50));
+    client.set_market_resolution_weights(&test.admin, &market_id, &weights(100, 0));
+    assert_eq!(client.get_resolution_weights(&other), weights(50, 50));
+
+    client.vote(&test.user, &market_id, &yes, &10_0000000);
+    let breakdown = client.get_resolution_breakdown(&market_id);
+    assert_eq!(breakdown.weights, weights(100, 0));
+    assert_eq!(breakdown.community_outcome, yes);
+    assert_eq!(breakdown.community_percentage, 100);
+    assert_eq!(breakdown.oracle_outcome, None);
+    // No oracle result and no community weight leaves no confidence
+    assert_eq!(breakdown.confidence_score, 0);
+    assert!(breakdown.winning_outcomes.is_empty());
+
+    let market = client.get_market(&market_id).unwrap();
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + 1,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+    client.resolve_market_manual(&test.admin, &market_id, &yes);
+    let breakdown = client.get_resolution_breakdown(&market_id);
+    assert_eq!(breakdown.winning_outcomes, vec![&test.env, yes]);
+    assert_eq!(
+        client.try_set_market_resolution_weights(&test.admin, &market_id, &weights(70, 30)),
+        Err(Ok(Error::MarketResolved))
+    );
+}
+
+#[test]
+fn test_resolution_retries_back_off_exponentially() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let yes = String::from_str(&test.env, "yes");
+
+    test.env.mock_all_auths();
+    client.vote(&test.user, &market_id, &yes, &10_0000000);
+    let market = client.get_market(&market_id).unwrap();
+    let set_time = |timestamp: u64| {
+        test.env.ledger().set(LedgerInfo {
+            timestamp,
+            protocol_version: 22,
+            sequence_number: test.env.ledger().sequence(),
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 1,
+            min_persistent_entry_ttl: 1,
+            max_entry_ttl: 10000,
+        });
+    };
+    let ended = market.end_time + 1;
+    set_time(ended);
+
+    // No oracle result yet: the attempt is recorded instead of failing
+    client.resolve_market(&market_id);
+    let attempts = client.get_resolution_attempts(&market_id);
+    assert_eq!(attempts.attempts, 1);
+    assert_eq!(attempts.last_error, Error::OracleUnavailable as u32);
+    assert_eq!(attempts.next_attempt_at, ended + 300);
+    assert_eq!(
+        client.try_resolve_market(&market_id),
+        Err(Ok(Error::MarketNotReady))
+    );
+
+    set_time(ended + 300);
+    client.resolve_market(&market_id);
+    let attempts = client.get_resolution_attempts(&market_id);
+    assert_eq!(attempts.attempts, 2);
+    assert_eq!(attempts.next_attempt_at, ended + 300 + 600);
+    assert!(client
+        .get_market(&market_id)
+        .unwrap()
+        .winning_outcomes
+        .is_none());
+
+    // Non-oracle failures are returned as usual
+    set_time(ended + 900);
+    client.resolve_market_manual(&test.admin, &market_id, &yes);
+    assert_eq!(
+        client.try_resolve_market(&market_id),
+        Err(Ok(Error::MarketResolved))
+    );
+    assert_eq!(client.get_resolution_attempts(&market_id).attempts, 2);
+}
+
+#[test]
+fn test_dispute_settles_early_on_supermajority() {
+    use crate::disputes::{DisputeSupermajorityPolicy, DisputeUtils, DisputeVotingStatus};
+
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let yes = String::from_str(&test.env, "yes");
+    let no = String::from_str(&test.env, "no");
+
+    test.env.mock_all_auths();
+    client.vote(&test.user, &market_id, &yes, &100_0000000);
+    client.vote(&test.create_funded_user(), &market_id, &no, &50_0000000);
+
+    // The oracle said "yes" once the market ended
+    let market = client.get_market(&market_id).unwrap();
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + 1,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+    test.env.as_contract(&test.contract_id, || {
+        let mut market: Market = test.env.storage().persistent().get(&market_id).unwrap();
+        market.oracle_result = Some(yes.clone());
+        test.env.storage().persistent().set(&market_id, &market);
+    });
+
+    // Disputing it opens community voting
+    let disputer = test.create_funded_user();
+    client.dispute_market(&disputer, &market_id, &10_0000000, &None);
+    test.env.as_contract(&test.contract_id, || {
+        let voting = DisputeUtils::get_dispute_voting(&test.env, &market_id).unwrap();
+        assert!(matches!(voting.status, DisputeVotingStatus::Active));
+        assert_eq!(voting.voting_end, market.end_time + 1 + 24 * 3600);
+    });
+
+    assert_eq!(
+        client.try_set_dispute_supermajority_policy(
+            &test.admin,
+            &DisputeSupermajorityPolicy {
+                supermajority_bps: 5_000,
+                min_total_stake: 30_000_000,
+            }
+        ),
+        Err(Ok(Error::InvalidInput))
+    );
+    client.set_dispute_supermajority_policy(
+        &test.admin,
+        &DisputeSupermajorityPolicy {
+            supermajority_bps: 8_000,
+            min_total_stake: 30_000_000,
+        },
+    );
+
+    let vote = |support: bool, stake: i128| {
+        client.vote_on_dispute(
+            &test.create_funded_user(),
+            &market_id,
+            &market_id,
+            &support,
+            &stake,
+            &None,
+        );
+    };
+    // Not enough stake yet
+    vote(true, 20_000_000);
+    assert!(matches!(
+        client.try_resolve_dispute_early(&market_id, &market_id),
+        Err(Ok(Error::DisputeCondNotMet))
+    ));
+    // Enough stake, but short of the supermajority (20 / 45 supporting)
+    vote(false, 25_000_000);
+    assert!(matches!(
+        client.try_resolve_dispute_early(&market_id, &market_id),
+        Err(Ok(Error::DisputeCondNotMet))
+    ));
+    // 120 / 145 clears it
+    vote(true, 100_000_000);
+
+    // Upheld: the oracle result is overturned in favour of the best-backed alternative
+    let resolution = client.resolve_dispute_early(&market_id, &market_id);
+    assert_eq!(resolution.final_outcome, no);
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.winning_outcomes, Some(vec![&test.env, no]));
+    test.env.as_contract(&test.contract_id, || {
+        let voting = DisputeUtils::get_dispute_voting(&test.env, &market_id).unwrap();
+        assert!(matches!(voting.status, DisputeVotingStatus::Completed));
+    });
+    assert!(matches!(
+        client.try_resolve_dispute_early(&market_id, &market_id),
+        Err(Ok(Error::MarketResolved))
+    ));
+}
+
+#[test]
+fn test_optimistic_assertion_finalizes_when_unchallenged() {
+    use crate::resolution::AssertionStatus;
+
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token = TokenClient::new(&test.env, &test.token_test.token_id);
+    let market_id = test.create_test_market();
+    let yes = String::from_str(&test.env, "yes");
+
+    test.env.mock_all_auths();
+    client.vote(&test.user, &market_id, &yes, &10_0000000);
+    let end_time = client.get_market(&market_id).unwrap().end_time;
+    let set_time = |timestamp: u64| {
+        test.env.ledger().set(LedgerInfo {
+            timestamp,
+            protocol_version: 22,
+            sequence_number: test.env.ledger().sequence(),
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 1,
+            min_persistent_entry_ttl: 1,
+            max_entry_ttl: 10000,
+        });
+    };
+
+    let asserter = test.create_funded_user();
+    assert_eq!(
+        client.try_assert_outcome(&asserter, &market_id, &yes, &10_0000000),
+        Err(Ok(Error::MarketClosed))
+    );
+    set_time(end_time + 1);
+    assert_eq!(
+        client.try_assert_outcome(&asserter, &market_id, &yes, &1_0000000),
+        Err(Ok(Error::InsufficientStake))
+    );
+    let assertion = client.assert_outcome(&asserter, &market_id, &yes, &10_0000000);
+    assert_eq!(assertion.challenge_deadline, end_time + 1 + 7_200);
+    assert_eq!(token.balance(&asserter), 990_0000000);
+    assert_eq!(
+        client.try_assert_outcome(&test.create_funded_user(), &market_id, &yes, &10_0000000),
+        Err(Ok(Error::InvalidState))
+    );
+    assert_eq!(
+        client.try_settle_assertion(&market_id),
+        Err(Ok(Error::MarketNotReady))
+    );
+
+    set_time(assertion.challenge_deadline + 1);
+    assert_eq!(
+        client.try_challenge_assertion(&test.create_funded_user(), &market_id, &10_0000000, &None),
+        Err(Ok(Error::DisputeCondNotMet))
+    );
+    assert_eq!(client.settle_assertion(&market_id), 10_0000000);
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.state, MarketState::Resolved);
+    assert_eq!(market.winning_outcomes, Some(vec![&test.env, yes]));
+    assert_eq!(token.balance(&asserter), 1000_0000000);
+    assert_eq!(
+        client.get_assertion(&market_id).unwrap().status,
+        AssertionStatus::Settled
+    );
+    assert_eq!(
+        client.try_settle_assertion(&market_id),
+        Err(Ok(Error::AlreadyClaimed))
+    );
+}
+
+#[test]
+fn test_challenged_assertion_escalates_to_dispute() {
+    use crate::resolution::AssertionStatus;
+
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token = TokenClient::new(&test.env, &test.token_test.token_id);
+    let market_id = test.create_test_market();
+    let yes = String::from_str(&test.env, "yes");
+    let no = String::from_str(&test.env, "no");
+
+    test.env.mock_all_auths();
+    client.vote(&test.user, &market_id, &no, &10_0000000);
+    let end_time = client.get_market(&market_id).unwrap().end_time;
+    test.env.ledger().set(LedgerInfo {
+        timestamp: end_time + 1,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+
+    let asserter = test.create_funded_user();
+    let challenger = test.create_funded_user();
+    client.assert_outcome(&asserter, &market_id, &yes, &20_0000000);
+    assert_eq!(
+        client.try_challenge_assertion(&challenger, &market_id, &10_0000000, &None),
+        Err(Ok(Error::InsufficientStake))
+    );
+    client.challenge_assertion(&challenger, &market_id, &20_0000000, &None);
+
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.state, MarketState::Disputed);
+    assert_eq!(market.oracle_result, Some(yes.clone()));
+    assert_eq!(
+        market.dispute_stakes.get(challenger.clone()),
+        Some(20_0000000)
+    );
+    let assertion = client.get_assertion(&market_id).unwrap();
+    assert_eq!(assertion.status, AssertionStatus::Challenged);
+    assert_eq!(assertion.challenger, Some(challenger.clone()));
+    assert_eq!(
+        client.try_settle_assertion(&market_id),
+        Err(Ok(Error::MarketNotResolved))
+    );
+
+    // The dispute overturns the assertion, so its bond goes to the challenger
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + 1,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+    client.resolve_market_manual(&test.admin, &market_id, &no);
+    assert_eq!(client.settle_assertion(&market_id), 20_0000000);
+    assert_eq!(token.balance(&asserter), 980_0000000);
+    assert_eq!(token.balance(&challenger), 1000_0000000);
+}
+
+#[test]
+fn test_watchlist_is_bounded_and_deduplicated() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let user = Address::generate(&test.env);
+
+    test.env.mock_all_auths();
+    assert_eq!(
+        client.try_watch_market(&user, &Symbol::new(&test.env, "missing")),
+        Err(Ok(Error::MarketNotFound))
+    );
+    client.watch_market(&user, &market_id);
+    client.watch_market(&user, &market_id);
+    assert_eq!(
+        client.get_watchlist(&user),
+        vec![&test.env, market_id.clone()]
+    );
+
+    // Fill the list up to its bound with other (stored) markets
+    let market: Market = test.env.as_contract(&test.contract_id, || {
+        test.env.storage().persistent().get(&market_id).unwrap()
+    });
+    for i in 1..crate::config::MAX_WATCHLIST_SIZE {
+        let id = Symbol::new(&test.env, &alloc::format!("watched_{}", i));
+        test.env.as_contract(&test.contract_id, || {
+            test.env.storage().persistent().set(&id, &market);
+        });
+        client.watch_market(&user, &id);
+    }
+    let extra = Symbol::new(&test.env, "watched_extra");
+    test.env.as_contract(&test.contract_id, || {
+        test.env.storage().persistent().set(&extra, &market);
+    });
+    assert_eq!(
+        client.try_watch_market(&user, &extra),
+        Err(Ok(Error::InvalidInput))
+    );
+
+    client.unwatch_market(&user, &market_id);
+    client.unwatch_market(&user, &market_id);
+    let watchlist = client.get_watchlist(&user);
+    assert_eq!(watchlist.len(), crate::config::MAX_WATCHLIST_SIZE - 1);
+    assert!(!watchlist.contains(&market_id));
+    client.watch_market(&user, &extra);
+    assert_eq!(watchlist.len() + 1, client.get_watchlist(&user).len());
+}
+
+#[test]
+fn test_market_resolves_against_aggregated_oracle_feeds() {
+    use crate::types::{AggregationMethod, MultiOracleConfig, OracleFeedSource};
+
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    test.env.mock_all_auths();
+    let market_id = client.create_market(
+        &test.admin,
+        &String::from_str(&test.env, "Will BTC go above $25,000?"),
+        &vec![
+            &test.env,
+            String::from_str(&test.env, "yes"),
+            String::from_str(&test.env, "no"),
+        ],
+        &30,
+        &OracleConfig {
+            provider: OracleProvider::Reflector,
+            oracle_address: Address::generate(&test.env),
+            feed_id: String::from_str(&test.env, "BTC"),
+            threshold: 2500000,
+            comparison: String::from_str(&test.env, "gt"),
+        },
+        &None,
+        &86400,
+        &None,
+        &None,
+        &None,
+    );
+    let source = |feed: &str| OracleFeedSource {
+        provider: OracleProvider::Reflector,
+        oracle_address: Address::generate(&test.env),
+        feed_id: String::from_str(&test.env, feed),
+    };
+    // The market's own BTC feed is above its 25k threshold; the aggregated
+    // ETH feeds (2k) are not, and the lone BTC source is an outlier
+    let config = MultiOracleConfig {
+        sources: vec![&test.env, source("ETH"), source("ETH/USD"), source("BTC")],
+        method: AggregationMethod::Median,
+        max_deviation_bps: 500,
+        min_sources: 2,
+    };
+
+    assert_eq!(
+        client.try_set_multi_oracle_config(&test.user, &market_id, &config),
+        Err(Ok(Error::Unauthorized))
+    );
+    let mut invalid = config.clone();
+    invalid.min_sources = 0;
+    assert_eq!(
+        client.try_set_multi_oracle_config(&test.admin, &market_id, &invalid),
+        Err(Ok(Error::InvalidOracleConfig))
+    );
+    client.set_multi_oracle_config(&test.admin, &market_id, &config);
+    assert_eq!(client.get_multi_oracle_config(&market_id), Some(config));
+
+    let market = client.get_market(&market_id).unwrap();
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + 1,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+    let resolution = test.env.as_contract(&test.contract_id, || {
+        crate::resolution::OracleResolutionManager::fetch_oracle_result(&test.env, &market_id)
+            .unwrap()
+    });
+    assert_eq!(resolution.price, 200_000);
+    assert_eq!(resolution.oracle_result, String::from_str(&test.env, "no"));
+}
+
+#[test]
+fn test_market_resolves_on_composite_oracle_condition() {
+    use crate::types::{CompositeCondition, ConditionOperator, CrossRateCondition};
+
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    test.env.mock_all_auths();
+    let term = |feed: &str, threshold: i128| OracleConfig {
+        provider: OracleProvider::Reflector,
+        oracle_address: Address::generate(&test.env),
+        feed_id: String::from_str(&test.env, feed),
+        threshold,
+        comparison: String::from_str(&test.env, "gt"),
+    };
+    let create_market = || {
+        client.create_market(
+            &test.admin,
+            &String::from_str(&test.env, "Will BTC be above $25k and ETH above $5k?"),
+            &vec![
+                &test.env,
+                String::from_str(&test.env, "yes"),
+                String::from_str(&test.env, "no"),
+            ],
+            &30,
+            &term("BTC", 2500000),
+            &None,
+            &86400,
+            &None,
+            &None,
+            &None,
+        )
+    };
+    // Reflector mock prices: BTC 2_600_000 (above 25k), ETH 200_000 (below 5k)
+    let and_condition = CompositeCondition {
+        operator: ConditionOperator::And,
+        terms: vec![&test.env, term("BTC", 2500000), term("ETH", 500000)],
+    };
+    let or_condition = CompositeCondition {
+        operator: ConditionOperator::Or,
+        terms: and_condition.terms.clone(),
+    };
+    let and_market = create_market();
+    let or_market = create_market();
+
+    assert_eq!(
+        client.try_set_composite_condition(&test.user, &and_market, &and_condition),
+        Err(Ok(Error::Unauthorized))
+    );
+    let single_term = CompositeCondition {
+        operator: ConditionOperator::And,
+        terms: vec![&test.env, term("BTC", 2500000)],
+    };
+    assert_eq!(
+        client.try_set_composite_condition(&test.admin, &and_market, &single_term),
+        Err(Ok(Error::InvalidOracleConfig))
+    );
+    client.set_composite_condition(&test.admin, &and_market, &and_condition);
+    client.set_composite_condition(&test.admin, &or_market, &or_condition);
+    assert_eq!(
+        client.get_composite_condition(&and_market),
+        Some(and_condition.clone())
+    );
+    assert_eq!(
+        client.try_set_cross_rate_condition(
+            &test.admin,
+            &and_market,
+            &CrossRateCondition {
+                base_feed_id: String::from_str(&test.env, "ETH"),
+                quote_feed_id: String::from_str(&test.env, "BTC"),
+                base_decimals: 2,
+                quote_decimals: 2,
+                threshold: 6_000_000,
+                comparison: String::from_str(&test.env, "gt"),
+            }
+        ),
+        Err(Ok(Error::InvalidOracleConfig))
+    );
+
+    let market = client.get_market(&and_market).unwrap();
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + 1,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+    let resolve = |market_id: &Symbol| {
+        test.env.as_contract(&test.contract_id, || {
+            crate::resolution::OracleResolutionManager::fetch_oracle_result(&test.env, market_id)
+                .unwrap()
+        })
+    };
+
+    // The ETH term fails the AND and is recorded as the deciding feed
+    let resolution = resolve(&and_market);
+    assert_eq!(resolution.oracle_result, String::from_str(&test.env, "no"));
+    assert_eq!(resolution.feed_id, String::from_str(&test.env, "ETH"));
+    assert_eq!(resolution.price, 200_000);
+
+    // The BTC term alone satisfies the OR
+    let resolution = resolve(&or_market);
+    assert_eq!(resolution.oracle_result, String::from_str(&test.env, "yes"));
+    assert_eq!(resolution.feed_id, String::from_str(&test.env, "BTC"));
+    assert_eq!(resolution.price, 2_600_000);
+}
+
+#[test]
+fn test_categorical_market_resolves_to_price_bucket() {
+    use crate::types::PriceBuckets;
+
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    test.env.mock_all_auths();
+    let outcomes = vec![
+        &test.env,
+        String::from_str(&test.env, "<20k"),
+        String::from_str(&test.env, "20k-30k"),
+        String::from_str(&test.env, ">=30k"),
+    ];
+    let market_id = client.create_market(
+        &test.admin,
+        &String::from_str(&test.env, "Where will BTC close?"),
+        &outcomes,
+        &30,
+        &OracleConfig {
+            provider: OracleProvider::Reflector,
+            oracle_address: Address::generate(&test.env),
+            feed_id: String::from_str(&test.env, "BTC"),
+            threshold: 2_000_000,
+            comparison: String::from_str(&test.env, "gt"),
+        },
+        &None,
+        &86400,
+        &None,
+        &None,
+        &None,
+    );
+
+    // One boundary fewer than outcomes, strictly ascending
+    let too_few = PriceBuckets {
+        boundaries: vec![&test.env, 2_000_000],
+    };
+    assert_eq!(
+        client.try_set_price_buckets(&test.admin, &market_id, &too_few),
+        Err(Ok(Error::InvalidOutcomes))
+    );
+    let descending = PriceBuckets {
+        boundaries: vec![&test.env, 3_000_000, 2_000_000],
+    };
+    assert_eq!(
+        client.try_set_price_buckets(&test.admin, &market_id, &descending),
+        Err(Ok(Error::InvalidThreshold))
+    );
+    let buckets = PriceBuckets {
+        boundaries: vec![&test.env, 2_000_000, 3_000_000],
+    };
+    assert_eq!(
+        client.try_set_price_buckets(&test.user, &market_id, &buckets),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.set_price_buckets(&test.admin, &market_id, &buckets);
+    assert_eq!(client.get_price_buckets(&market_id), Some(buckets));
+
+    client.vote(
+        &test.user,
+        &market_id,
+        &outcomes.get(1).unwrap(),
+        &100_0000000,
+    );
+    let market = client.get_market(&market_id).unwrap();
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + 1,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+
+    // Reflector mock BTC price 2_600_000 falls in the middle bucket
+    let resolution = test.env.as_contract(&test.contract_id, || {
+        crate::resolution::OracleResolutionManager::fetch_oracle_result(&test.env, &market_id)
+            .unwrap()
+    });
+    assert_eq!(resolution.price, 2_600_000);
+    assert_eq!(resolution.oracle_result, outcomes.get(1).unwrap());
+    assert_eq!(
+        client.get_market(&market_id).unwrap().oracle_result,
+        outcomes.get(1)
+    );
+}
+
+#[test]
+fn test_governance_announcements_expire_and_roll_over() {
+    use crate::announcements::AnnouncementSeverity;
+
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let message = String::from_str(&test.env, "Upgrade scheduled for Friday");
+    let now = test.env.ledger().timestamp();
+
+    test.env.mock_all_auths();
+    assert_eq!(
+        client.try_post_announcement(&test.user, &AnnouncementSeverity::Info, &message, &None),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_post_announcement(
+            &test.admin,
+            &AnnouncementSeverity::Info,
+            &String::from_str(&test.env, ""),
+            &None
+        ),
+        Err(Ok(Error::InvalidInput))
+    );
+    let first = client.post_announcement(
+        &test.admin,
+        &AnnouncementSeverity::Warning,
+        &message,
+        &Some(now + 3_600),
+    );
+    let second = client.post_announcement(
+        &test.admin,
+        &AnnouncementSeverity::Critical,
+        &String::from_str(&test.env, "Voting paused"),
+        &None,
+    );
+    assert_eq!((first, second), (1, 2));
+    let announcements = client.get_announcements();
+    assert_eq!(announcements.len(), 2);
+    assert_eq!(
+        announcements.get(0).unwrap().severity,
+        AnnouncementSeverity::Warning
+    );
+
+    test.env.ledger().set(LedgerInfo {
+        timestamp: now + 3_600,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+    let announcements = client.get_announcements();
+    assert_eq!(announcements.len(), 1);
+    assert_eq!(announcements.get(0).unwrap().id, second);
+
+    client.retract_announcement(&test.admin, &second);
+    assert!(client.get_announcements().is_empty());
+
+    // Only the latest MAX_ANNOUNCEMENTS are kept, expired ones included
+    for _ in 0..crate::config::MAX_ANNOUNCEMENTS + 1 {
+        client.post_announcement(&test.admin, &AnnouncementSeverity::Info, &message, &None);
+    }
+    let announcements = client.get_announcements();
+    assert_eq!(announcements.len(), crate::config::MAX_ANNOUNCEMENTS);
+    assert_eq!(announcements.get(0).unwrap().id, 4);
+}
+
+#[test]
+fn test_referendum_market_resolves_from_governance_vote() {
+    use crate::governance::GovernanceContract;
+
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let proposal_id = Symbol::new(&test.env, "raise_fee");
+
+    test.env.mock_all_auths();
+    assert_eq!(
+        client.try_link_market_to_proposal(&test.admin, &market_id, &proposal_id),
+        Err(Ok(Error::InvalidInput))
+    );
+    test.env.as_contract(&test.contract_id, || {
+        GovernanceContract::initialize(test.env.clone(), test.admin.clone(), 3_600, 2);
+        GovernanceContract::create_proposal(
+            test.env.clone(),
+            test.admin.clone(),
+            proposal_id.clone(),
+            String::from_str(&test.env, "Raise fee"),
+            String::from_str(&test.env, "Raise the platform fee to 3%"),
+            None,
+            None,
+        )
+        .unwrap();
+        for _ in 0..2 {
+            let voter = Address::generate(&test.env);
+            GovernanceContract::vote(test.env.clone(), voter, proposal_id.clone(), true).unwrap();
+        }
+    });
+    client.link_market_to_proposal(&test.admin, &market_id, &proposal_id);
+    assert_eq!(client.get_market_proposal(&market_id), Some(proposal_id));
+    client.vote(
+        &test.user,
+        &market_id,
+        &String::from_str(&test.env, "no"),
+        &10_0000000,
+    );
+
+    let market = client.get_market(&market_id).unwrap();
+    let set_time = |timestamp: u64| {
+        test.env.ledger().set(LedgerInfo {
+            timestamp,
+            protocol_version: 22,
+            sequence_number: test.env.ledger().sequence(),
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 1,
+            min_persistent_entry_ttl: 1,
+            max_entry_ttl: 10000,
+        });
+    };
+    assert_eq!(
+        client.try_resolve_referendum_market(&market_id),
+        Err(Ok(Error::MarketClosed))
+    );
+    set_time(market.end_time + 1);
+    assert_eq!(
+        client.try_fetch_oracle_with_contract(&market_id, &Address::generate(&test.env)),
+        Err(Ok(Error::InvalidOracleConfig))
+    );
+
+    let outcome = client.resolve_referendum_market(&market_id);
+    assert_eq!(outcome, String::from_str(&test.env, "yes"));
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.state, MarketState::Resolved);
+    assert_eq!(market.winning_outcomes, Some(vec![&test.env, outcome]));
+    assert_eq!(
+        client.try_resolve_referendum_market(&market_id),
+        Err(Ok(Error::MarketResolved))
+    );
+}
+
+#[test]
+fn test_outcome_cap_rejects_or_clamps_lopsided_stakes() {
+    use crate::types::{OutcomeCap, OutcomeCapMode};
+
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let yes = String::from_str(&test.env, "yes");
+    let no = String::from_str(&test.env, "no");
+    let first = test.create_funded_user();
+    let second = test.create_funded_user();
+    let whale = test.create_funded_user();
+
+    test.env.mock_all_auths();
+    let mut cap = OutcomeCap {
+        max_share_bps: 5_000,
+        min_pool: 10_0000000,
+        mode: OutcomeCapMode::Reject,
+    };
+    // A 50% cap on a two-outcome market can never be satisfied by both sides
+    assert_eq!(
+        client.try_set_outcome_cap(&test.admin, &market_id, &cap),
+        Err(Ok(Error::InvalidInput))
+    );
+    cap.max_share_bps = 7_000;
+    client.set_outcome_cap(&test.admin, &market_id, &cap);
+
+    // The cap is not enforced until the pool reaches min_pool
+    assert_eq!(client.get_max_stake_for_outcome(&market_id, &yes), None);
+    client.vote(&first, &market_id, &yes, &10_0000000);
+    client.vote(&second, &market_id, &no, &5_0000000);
+
+    // yes holds 10 of 15: at most (0.7 * 15 - 10) / 0.3 more keeps it at 70%
+    assert_eq!(
+        client.get_max_stake_for_outcome(&market_id, &yes),
+        Some(16666666)
+    );
+    assert!(client
+        .try_vote(&whale, &market_id, &yes, &30_0000000)
+        .is_err());
+
+    // In clamp mode only the stake up to the cap is taken
+    cap.mode = OutcomeCapMode::Clamp;
+    client.set_outcome_cap(&test.admin, &market_id, &cap);
+    let token = TokenClient::new(&test.env, &test.token_test.token_id);
+    let balance_before = token.balance(&whale);
+    client.vote(&whale, &market_id, &yes, &30_0000000);
+    assert_eq!(
+        client.get_vote_entry(&market_id, &whale).unwrap().stake,
+        16666666
+    );
+    assert_eq!(token.balance(&whale), balance_before - 16666666);
+    assert_eq!(client.get_max_stake_for_outcome(&market_id, &yes), Some(0));
+}
+
+#[test]
+fn test_push_oracle_resolves_on_reporter_quorum() {
+    use crate::oracles::PushOracleConfig;
+
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let yes = String::from_str(&test.env, "yes");
+    let no = String::from_str(&test.env, "no");
+    let reporters = [
+        Address::generate(&test.env),
+        Address::generate(&test.env),
+        Address::generate(&test.env),
+    ];
+    let outsider = Address::generate(&test.env);
+
+    test.env.mock_all_auths();
+    for reporter in reporters.iter() {
+        client.add_oracle_reporter(&test.admin, reporter);
+    }
+    assert_eq!(
+        client.try_add_oracle_reporter(&test.admin, &reporters[0]),
+        Err(Ok(Error::InvalidInput))
+    );
+    client.set_push_oracle_config(
+        &test.admin,
+        &market_id,
+        &PushOracleConfig {
+            quorum: 2,
+            reporting_window_seconds: 86_400,
+        },
+    );
+    client.vote(&test.create_funded_user(), &market_id, &yes, &10_0000000);
+
+    // Reports are only accepted once the market has ended
+    assert_eq!(
+        client.try_push_oracle_result(&reporters[0], &market_id, &yes),
+        Err(Ok(Error::MarketNotReady))
+    );
+    let market = client.get_market(&market_id).unwrap();
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+    assert_eq!(
+        client.try_fetch_oracle_with_contract(&market_id, &Address::generate(&test.env)),
+        Err(Ok(Error::InvalidOracleConfig))
+    );
+
+    assert_eq!(
+        client.try_push_oracle_result(&outsider, &market_id, &yes),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_push_oracle_result(
+            &reporters[0],
+            &market_id,
+            &String::from_str(&test.env, "maybe")
+        ),
+        Err(Ok(Error::InvalidOutcome))
+    );
+    assert_eq!(
+        client.push_oracle_result(&reporters[0], &market_id, &yes),
+        None
+    );
+    assert_eq!(
+        client.try_push_oracle_result(&reporters[0], &market_id, &no),
+        Err(Ok(Error::AlreadyVoted))
+    );
+    assert_eq!(
+        client.push_oracle_result(&reporters[1], &market_id, &no),
+        None
+    );
+
+    // The second agreeing report reaches the quorum and resolves the market
+    assert_eq!(
+        client.push_oracle_result(&reporters[2], &market_id, &yes),
+        Some(yes.clone())
+    );
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.state, MarketState::Resolved);
+    assert_eq!(market.winning_outcomes, Some(vec![&test.env, yes.clone()]));
+    assert_eq!(client.get_oracle_reports(&market_id).len(), 3);
+
+    client.remove_oracle_reporter(&test.admin, &reporters[0]);
+    assert_eq!(client.get_oracle_reporters().len(), 2);
+}
+
+#[test]
+fn test_simulations_preview_without_persisting() {
+    use crate::oracles::PushOracleConfig;
+
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let yes = String::from_str(&test.env, "yes");
+    let no = String::from_str(&test.env, "no");
+    let reporter = Address::generate(&test.env);
+    let loser = test.create_funded_user();
+
+    test.env.mock_all_auths();
+    client.vote(&test.user, &market_id, &yes, &30_0000000);
+
+    let preview = client.simulate_vote(&loser, &market_id, &no, &10_0000000);
+    let market = client.get_market(&market_id).unwrap();
+    let fee_percent = market.platform_fee_percentage;
+    assert_eq!(preview.stake, 10_0000000);
+    assert_eq!(preview.total_staked, 40_0000000);
+    assert_eq!(preview.outcome_total, 10_0000000);
+    assert_eq!(preview.implied_probability_bps, 2_500);
+    assert_eq!(
+        preview.potential_payout,
+        10_0000000 * (100 - fee_percent) / 100 * 4
+    );
+    assert_eq!(preview.potential_payout + preview.potential_fee, 40_0000000);
+    // Nothing was written
+    assert_eq!(market.total_staked, 30_0000000);
+    assert!(client.get_vote_entry(&market_id, &loser).is_none());
+
+    assert_eq!(
+        client.simulate_place_bet(&loser, &market_id, &no, &10_0000000),
+        preview
+    );
+    assert_eq!(
+        client.try_simulate_vote(&test.user, &market_id, &no, &10_0000000),
+        Err(Ok(Error::AlreadyVoted))
+    );
+    assert_eq!(
+        client.try_simulate_vote(&Address::generate(&test.env), &market_id, &no, &10_0000000),
+        Err(Ok(Error::InsufficientBalance))
+    );
+    assert_eq!(
+        client.try_simulate_claim_winnings(&test.user, &market_id),
+        Err(Ok(Error::MarketNotResolved))
+    );
+
+    // The real vote matches its preview
+    client.vote(&loser, &market_id, &no, &10_0000000);
+    assert_eq!(
+        client.get_market(&market_id).unwrap().total_staked,
+        40_0000000
+    );
+
+    client.add_oracle_reporter(&test.admin, &reporter);
+    client.set_push_oracle_config(
+        &test.admin,
+        &market_id,
+        &PushOracleConfig {
+            quorum: 1,
+            reporting_window_seconds: 86_400,
+        },
+    );
+    let set_time = |timestamp: u64| {
+        test.env.ledger().set(LedgerInfo {
+            timestamp,
+            protocol_version: 22,
+            sequence_number: test.env.ledger().sequence(),
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 1,
+            min_persistent_entry_ttl: 1,
+            max_entry_ttl: 10000,
+        });
+    };
+    set_time(market.end_time);
+    assert_eq!(
+        client.try_simulate_vote(&test.user, &market_id, &no, &10_0000000),
+        Err(Ok(Error::MarketClosed))
+    );
+    client.push_oracle_result(&reporter, &market_id, &yes);
+    set_time(market.end_time + market.dispute_window_seconds);
+
+    let claim = client.simulate_claim_winnings(&test.user, &market_id);
+    assert_eq!(
+        claim.payout,
+        30_0000000 * (100 - fee_percent) / 100 * 40 / 30
+    );
+    assert_eq!(claim.payout + claim.fee, 40_0000000);
+    assert_eq!(client.simulate_claim_winnings(&loser, &market_id).payout, 0);
+    assert!(!test.has_claimed(&market_id, &test.user));
+}
+
+#[test]
+fn test_loser_rebate_shares_fee_with_losing_voters() {
+    use crate::oracles::PushOracleConfig;
+
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let yes = String::from_str(&test.env, "yes");
+    let no = String::from_str(&test.env, "no");
+    let reporter = Address::generate(&test.env);
+    let small_loser = test.create_funded_user();
+    let big_loser = test.create_funded_user();
+
+    test.env.mock_all_auths();
+    assert_eq!(
+        client.try_set_loser_rebate(&test.user, &market_id, &true),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.set_loser_rebate(&test.admin, &market_id, &true);
+    client.vote(&test.user, &market_id, &yes, &60_0000000);
+    client.vote(&small_loser, &market_id, &no, &10_0000000);
+    client.vote(&big_loser, &market_id, &no, &30_0000000);
+
+    client.add_oracle_reporter(&test.admin, &reporter);
+    client.set_push_oracle_config(
+        &test.admin,
+        &market_id,
+        &PushOracleConfig {
+            quorum: 1,
+            reporting_window_seconds: 86_400,
+        },
+    );
+    let market = client.get_market(&market_id).unwrap();
+    let set_time = |timestamp: u64| {
+        test.env.ledger().set(LedgerInfo {
+            timestamp,
+            protocol_version: 22,
+            sequence_number: test.env.ledger().sequence(),
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 1,
+            min_persistent_entry_ttl: 1,
+            max_entry_ttl: 10000,
+        });
+    };
+    set_time(market.end_time);
+    let fees_before = client.get_treasury_balance().platform_fees;
+    client.push_oracle_result(&reporter, &market_id, &yes);
+
+    // Community tier: 5% of the fee is set aside for losers
+    let rules = client.get_tier_rules(&MarketTier::Community);
+    let fee = 100_0000000 * market.platform_fee_percentage / 100;
+    let pool = fee * rules.loser_rebate_bps as i128 / 10_000;
+    assert!(pool > 0);
+    assert_eq!(
+        client.get_treasury_balance().platform_fees - fees_before,
+        fee - pool
+    );
+
+    set_time(market.end_time + market.dispute_window_seconds);
+    assert_eq!(
+        client
+            .simulate_claim_winnings(&small_loser, &market_id)
+            .rebate,
+        pool / 4
+    );
+    client.claim_winnings(&small_loser, &market_id);
+    client.claim_winnings_batch(&big_loser, &vec![&test.env, market_id.clone()]);
+    let balance = |user: &Address| client.get_balance(user, &ReflectorAsset::Stellar).amount;
+    assert_eq!(balance(&small_loser), pool / 4);
+    assert_eq!(balance(&big_loser), pool * 3 / 4);
+
+    // Winners are paid as usual
+    client.claim_winnings(&test.user, &market_id);
+    assert_eq!(
+        balance(&test.user),
+        60_0000000 * (100 - market.platform_fee_percentage) / 100 * 100 / 60
+    );
+}
+
+#[test]
+fn test_scalar_market_pays_buckets_by_closeness() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    test.env.mock_all_auths();
+    let oracle_config = OracleConfig {
+        provider: OracleProvider::Reflector,
+        oracle_address: Address::generate(&test.env),
+        feed_id: String::from_str(&test.env, "BTC"),
+        threshold: 1,
+        comparison: String::from_str(&test.env, "gt"),
+    };
+    let question = String::from_str(&test.env, "What will BTC trade at on expiry?");
+    // $20k-$30k in $2k buckets (prices in cents)
+    let range = ScalarRange {
+        lower: 2_000_000,
+        upper: 3_000_000,
+        bucket_count: 5,
+    };
+    let params = CreateScalarMarketParams {
+        question,
+        range,
+        duration_days: 30,
+        oracle_config: oracle_config.clone(),
+        has_fallback: false,
+        fallback_oracle_config: oracle_config,
+        resolution_timeout: 86400,
+    };
+    let mut invalid = params.clone();
+    invalid.range.bucket_count = 1;
+    assert!(client
+        .try_create_scalar_market(&test.admin, &invalid)
+        .is_err());
+    let market_id = client.create_scalar_market(&test.admin, &params);
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.outcomes.len(), 5);
+    assert_eq!(
+        market.outcomes.get(3).unwrap(),
+        String::from_str(&test.env, "2600000-2800000")
+    );
+
+    let exact = test.create_funded_user();
+    let near = test.create_funded_user();
+    let far = test.create_funded_user();
+    client.vote(
+        &exact,
+        &market_id,
+        &market.outcomes.get(3).unwrap(),
+        &10_0000000,
+    );
+    client.vote(
+        &near,
+        &market_id,
+        &market.outcomes.get(2).unwrap(),
+        &10_0000000,
+    );
+    client.vote(
+        &far,
+        &market_id,
+        &market.outcomes.get(0).unwrap(),
+        &20_0000000,
+    );
+    assert_eq!(
+        client.try_resolve_scalar_market(&market_id),
+        Err(Ok(Error::MarketClosed))
+    );
+
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + market.dispute_window_seconds,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+    // Reflector mock price for BTC is $26,000
+    assert_eq!(client.resolve_scalar_market(&market_id), 2_600_000);
+    assert_eq!(
+        client.try_resolve_scalar_market(&market_id),
+        Err(Ok(Error::MarketResolved))
+    );
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.scalar_value, Some(2_600_000));
+    assert_eq!(market.winning_outcomes.unwrap().len(), 4);
+
+    // Weights: exact bucket 3, neighbour 2, two away 1, further 0 => 30 + 20 weighted stake
+    let net = |weighted: i128| weighted * (100 - market.platform_fee_percentage) / 100 * 40 / 50;
+    let balance = |user: &Address| client.get_balance(user, &ReflectorAsset::Stellar).amount;
+    // Claim tickets carry the weighted payout too
+    assert_eq!(
+        client.issue_claim_ticket(&market_id, &near).payout,
+        net(20_0000000)
+    );
+    client.claim_winnings(&exact, &market_id);
+    client.claim_winnings(&near, &market_id);
+    client.claim_winnings(&far, &market_id);
+    assert_eq!(balance(&exact), net(30_0000000));
+    assert_eq!(balance(&near), net(20_0000000));
+    assert_eq!(balance(&far), 0);
+}
+
+#[test]
+fn test_storage_audit_rolls_over_markets_and_alerts_on_corruption() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let first = test.create_test_market();
+    let second = test.create_test_market();
+    let keeper = test.create_funded_user();
+
+    test.env.mock_all_auths();
+    client.vote(
+        &test.user,
+        &first,
+        &String::from_str(&test.env, "yes"),
+        &100_0000000,
+    );
+
+    // Only Auditor keepers may run the audit
+    assert!(client.try_audit_storage(&keeper, &1).is_err());
+    client.register_keeper(
+        &keeper,
+        &vec![&test.env, crate::keepers::KeeperRole::Auditor],
+        &crate::config::MIN_KEEPER_STAKE,
+    );
+    assert!(client.try_audit_storage(&keeper, &0).is_err());
+
+    // A clean pass over both markets records its completion time
+    assert_eq!(client.audit_storage(&keeper, &1).len(), 0);
+    let state = client.get_storage_audit_state();
+    assert_eq!(state.last_clean_at, 0);
+    assert!(state.cursor > 0);
+    assert_eq!(client.audit_storage(&keeper, &5).len(), 0);
+    let state = client.get_storage_audit_state();
+    assert_eq!(state.cursor, 0);
+    assert_eq!(state.last_clean_at, test.env.ledger().timestamp());
+
+    // Corrupt both markets: the pass reports them and alerts once
+    test.env.as_contract(&test.contract_id, || {
+        for market_id in [first.clone(), second.clone()] {
+            let mut market: Market = test.env.storage().persistent().get(&market_id).unwrap();
+            market.total_staked = -1;
+            test.env.storage().persistent().set(&market_id, &market);
+        }
+    });
+    let corrupted = client.audit_storage(&keeper, &5);
+    assert_eq!(corrupted, vec![&test.env, first.clone(), second.clone()]);
+
+    let alert: crate::monitoring::MonitoringAlert = test.env.as_contract(&test.contract_id, || {
+        test.env
+            .storage()
+            .persistent()
+            .get(&Symbol::new(&test.env, "MONITORING_ALERT"))
+            .unwrap()
+    });
+    assert_eq!(
+        alert.alert_type,
+        crate::monitoring::MonitoringAlertType::DataIntegrity
+    );
+    assert_eq!(
+        alert.description,
+        String::from_str(&test.env, "Outcome totals exceed total staked")
+    );
+
+    // A dirty pass leaves the last clean time untouched
+    assert_eq!(
+        client.get_storage_audit_state().last_clean_at,
+        state.last_clean_at
+    );
+}
+
+#[test]
+fn test_api_versions_list_deprecated_entry_points() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+
+    let versions = client.get_api_versions();
+    assert_eq!(versions.current, crate::config::API_VERSION);
+    assert!(versions.min_supported <= versions.current);
+
+    let create_market = Symbol::new(&test.env, "create_market");
+    let entry = versions
+        .deprecated
+        .iter()
+        .find(|entry| entry.name == create_market)
+        .unwrap();
+    assert_eq!(entry.deprecated_since, 2);
+    assert_eq!(
+        entry.replacement,
+        Symbol::new(&test.env, "create_market_v2")
+    );
+
+    // SDKs pinned to an older API version still see the call as current
+    assert!(crate::api::ApiRegistry::is_deprecated(
+        &test.env,
+        &create_market,
+        2
+    ));
+    assert!(!crate::api::ApiRegistry::is_deprecated(
+        &test.env,
+        &create_market,
+        1
+    ));
+    assert!(!crate::api::ApiRegistry::is_deprecated(
+        &test.env,
+        &Symbol::new(&test.env, "vote"),
+        2
+    ));
+}
+
+#[test]
+fn test_cancelled_market_refunds_voters_and_bettors_in_full() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token_client = TokenClient::new(&test.env, &test.token_test.token_id);
+    let market_id = test.create_test_market();
+    let bettor = test.create_funded_user();
+    let outsider = test.create_funded_user();
+
+    test.env.mock_all_auths();
+    let voter_start = token_client.balance(&test.user);
+    let bettor_start = token_client.balance(&bettor);
+    client.vote(
+        &test.user,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &100_0000000,
+    );
+    client.place_bet(
+        &bettor,
+        &market_id,
+        &String::from_str(&test.env, "no"),
+        &10_000_000,
+    );
+
+    // Refunds only open once an admin cancels the market
+    assert_eq!(
+        client.try_claim_refund(&test.user, &market_id),
+        Err(Ok(Error::InvalidState))
+    );
+    let reason = String::from_str(&test.env, "Question became ambiguous");
+    assert_eq!(
+        client.try_cancel_market(&test.user, &market_id, &reason),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.cancel_market(&test.admin, &market_id, &reason);
+    assert_eq!(
+        client.get_market(&market_id).unwrap().state,
+        MarketState::Cancelled
+    );
+    assert_eq!(
+        client.try_cancel_market(&test.admin, &market_id, &reason),
+        Err(Ok(Error::InvalidState))
+    );
+
+    assert_eq!(client.claim_refund(&test.user, &market_id), 100_0000000);
+    assert_eq!(client.claim_refund(&bettor, &market_id), 10_000_000);
+    assert_eq!(token_client.balance(&test.user), voter_start);
+    assert_eq!(token_client.balance(&bettor), bettor_start);
+    assert_eq!(
+        client.get_bet(&market_id, &bettor).unwrap().status,
+        BetStatus::Refunded
+    );
+
+    assert_eq!(
+        client.try_claim_refund(&test.user, &market_id),
+        Err(Ok(Error::AlreadyClaimed))
+    );
+    assert_eq!(
+        client.try_claim_refund(&outsider, &market_id),
+        Err(Ok(Error::NothingToClaim))
+    );
+}
+
+#[test]
+fn test_degenerate_markets_are_cancelled_at_resolution() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token_client = TokenClient::new(&test.env, &test.token_test.token_id);
+    let yes = String::from_str(&test.env, "yes");
+    test.env.mock_all_auths();
+
+    let policy = crate::edge_cases::DegenerateMarketPolicy {
+        cancel_zero_stake: true,
+        cancel_single_voter: true,
+        cancel_one_sided: true,
+    };
+    assert_eq!(
+        client.try_set_degenerate_market_policy(&test.user, &policy),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.set_degenerate_market_policy(&test.admin, &policy);
+    assert_eq!(client.get_degenerate_market_policy(), policy);
+
+    // A lone voter gets their stake back instead of paying a fee to themselves
+    let single = test.create_test_market();
+    let other = test.create_funded_user();
+    let one_sided = test.create_test_market();
+    let start = token_client.balance(&test.user);
+    client.vote(&test.user, &single, &yes, &100_0000000);
+    client.vote(&test.user, &one_sided, &yes, &50_0000000);
+    client.vote(&other, &one_sided, &yes, &50_0000000);
+
+    let market = client.get_market(&single).unwrap();
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + 1,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+
+    client.resolve_market_manual(&test.admin, &single, &yes);
+    let market = client.get_market(&single).unwrap();
+    assert_eq!(market.state, MarketState::Cancelled);
+    assert!(market.winning_outcomes.is_none());
+    assert_eq!(client.claim_refund(&test.user, &single), 100_0000000);
+
+    // Two voters on the same side have no counterparty either
+    client.resolve_market_manual(&test.admin, &one_sided, &yes);
+    assert_eq!(
+        client.get_market(&one_sided).unwrap().state,
+        MarketState::Cancelled
+    );
+    assert_eq!(client.claim_refund(&test.user, &one_sided), 50_0000000);
+    assert_eq!(client.claim_refund(&other, &one_sided), 50_0000000);
+    assert_eq!(token_client.balance(&test.user), start);
+}
+
+#[test]
+fn test_dispute_settlement_slashes_losers_and_rewards_winners() {
+
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token_client = TokenClient::new(&test.env, &test.token_test.token_id);
+    let market_id = test.create_test_market();
+    test.env.mock_all_auths();
+
+    assert_eq!(client.get_dispute_slash_bps(), 5_000);
+    assert_eq!(
+        client.try_set_dispute_slash_bps(&test.user, &2_500),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_dispute_slash_bps(&test.admin, &10_001),
+        Err(Ok(Error::InvalidInput))
+    );
+
+    // The oracle result is disputed once the market ends, opening voting
+    let market = client.get_market(&market_id).unwrap();
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + 1,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+    test.env.as_contract(&test.contract_id, || {
+        let mut market: Market = test.env.storage().persistent().get(&market_id).unwrap();
+        market.oracle_result = Some(String::from_str(&test.env, "yes"));
+        test.env.storage().persistent().set(&market_id, &market);
+    });
+    client.dispute_market(&test.create_funded_user(), &market_id, &10_0000000, &None);
+    let voting_end = test.env.ledger().timestamp() + 24 * 3600;
+
+    let big = test.create_funded_user();
+    let small = test.create_funded_user();
+    let loser = test.create_funded_user();
+    let start = token_client.balance(&big);
+    client.vote_on_dispute(&big, &market_id, &market_id, &true, &60_000_000, &None);
+    client.vote_on_dispute(&small, &market_id, &market_id, &true, &20_000_000, &None);
+    client.vote_on_dispute(&loser, &market_id, &market_id, &false, &40_000_000, &None);
+
+    assert_eq!(
+        client.try_settle_dispute_stakes(&market_id),
+        Err(Ok(Error::DisputeCondNotMet))
+    );
+    test.env.ledger().set(LedgerInfo {
+        timestamp: voting_end + 1,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+
+    // Upheld 80 to 40: half the losing stake is shared 3:1 by the winners
+    let distribution = client.settle_dispute_stakes(&market_id);
+    assert_eq!(distribution.total_fees, 20_000_000);
+    assert_eq!(distribution.winner_stake, 80_000_000);
+    assert_eq!(distribution.winner_addresses.len(), 2);
+    assert_eq!(token_client.balance(&big), start + 15_000_000);
+    assert_eq!(token_client.balance(&small), start + 5_000_000);
+    assert_eq!(token_client.balance(&loser), start - 20_000_000);
+
+    assert_eq!(
+        client.try_settle_dispute_stakes(&market_id),
+        Err(Ok(Error::DisputeFeeFailed))
+    );
+}
+
+#[test]
+fn test_dispute_appeal_ladder_ends_in_council_ruling() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token_client = TokenClient::new(&test.env, &test.token_test.token_id);
+    let market_id = test.create_test_market();
+    let yes = String::from_str(&test.env, "yes");
+    let no = String::from_str(&test.env, "no");
+    test.env.mock_all_auths();
+
+    let council = vec![
+        &test.env,
+        Address::generate(&test.env),
+        Address::generate(&test.env),
+        Address::generate(&test.env),
+    ];
+    client.set_arbitration_council(&test.admin, &council);
+    assert_eq!(client.get_arbitration_council(), council);
+
+    let set_time = |timestamp: u64| {
+        test.env.ledger().set(LedgerInfo {
+            timestamp,
+            protocol_version: 22,
+            sequence_number: test.env.ledger().sequence(),
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 1,
+            min_persistent_entry_ttl: 1,
+            max_entry_ttl: 10000,
+        });
+    };
+
+    // The oracle said "yes" and was disputed
+    set_time(client.get_market(&market_id).unwrap().end_time + 1);
+    test.env.as_contract(&test.contract_id, || {
+        let mut market: Market = test.env.storage().persistent().get(&market_id).unwrap();
+        market.oracle_result = Some(yes.clone());
+        test.env.storage().persistent().set(&market_id, &market);
+    });
+    client.dispute_market(&test.create_funded_user(), &market_id, &10_000_000, &None);
+    let voting_end = test.env.ledger().timestamp() + 24 * 3600;
+
+    // Round 1 upholds the dispute
+    let supporter = test.create_funded_user();
+    client.vote_on_dispute(
+        &supporter,
+        &market_id,
+        &market_id,
+        &true,
+        &30_000_000,
+        &None,
+    );
+    set_time(voting_end + 1);
+    assert!(matches!(
+        client.try_resolve_dispute(&test.admin, &market_id),
+        Err(Ok(Error::DisputeCondNotMet))
+    ));
+
+    // Round 2 doubles the bond and reopens voting, which now rejects it
+    let first = test.create_funded_user();
+    let second = test.create_funded_user();
+    let start = token_client.balance(&first);
+    let ladder = client.appeal_dispute(&first, &market_id);
+    assert_eq!((ladder.round, ladder.bond), (2, 20_000_000));
+    assert!(matches!(
+        client.try_council_vote(&council.get(0).unwrap(), &market_id, &no),
+        Err(Ok(Error::DisputeCondNotMet))
+    ));
+    let opposer = test.create_funded_user();
+    client.vote_on_dispute(&opposer, &market_id, &market_id, &false, &50_000_000, &None);
+
+    // Round 3 goes to the council; there is no further appeal
+    let round_end = test.env.ledger().timestamp() + 24 * 3600;
+    set_time(round_end + 1);
+    let ladder = client.appeal_dispute(&second, &market_id);
+    assert_eq!((ladder.round, ladder.bond), (3, 40_000_000));
+    assert_eq!(
+        client.try_appeal_dispute(&first, &market_id),
+        Err(Ok(Error::DisputeNoEscalate))
+    );
+    set_time(round_end + 3 * 24 * 3600);
+    assert!(matches!(
+        client.try_resolve_dispute(&test.admin, &market_id),
+        Err(Ok(Error::DisputeCondNotMet))
+    ));
+    assert!(matches!(
+        client.try_council_vote(&test.user, &market_id, &no),
+        Err(Ok(Error::DisputeVoteDenied))
+    ));
+
+    assert!(client
+        .council_vote(&council.get(0).unwrap(), &market_id, &no)
+        .is_none());
+    let resolution = client
+        .council_vote(&council.get(1).unwrap(), &market_id, &no)
+        .unwrap();
+    assert_eq!(resolution.final_outcome, no);
+    assert_eq!(
+        client.get_market(&market_id).unwrap().winning_outcomes,
+        Some(vec![&test.env, no])
+    );
+
+    // The council upheld the dispute: only the appeal against rejection is refunded
+    assert_eq!(token_client.balance(&first), start - 20_000_000);
+    assert_eq!(token_client.balance(&second), start);
+}
+
+#[test]
+fn test_arbitration_council_is_elected_and_decides_escalated_disputes() {
+    use crate::disputes::{DisputeEscalation, DisputeUtils};
+    use crate::governance::GovernanceContract;
+
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let yes = String::from_str(&test.env, "yes");
+    let no = String::from_str(&test.env, "no");
+    test.env.mock_all_auths();
+
+    let (first, second, third) = (
+        Address::generate(&test.env),
+        Address::generate(&test.env),
+        Address::generate(&test.env),
+    );
+    client.set_arbitration_council(&test.admin, &vec![&test.env, first.clone(), second.clone()]);
+    assert_eq!(
+        client.try_set_arbitration_council(&test.admin, &vec![&test.env, third.clone()]),
+        Err(Ok(Error::InvalidState))
+    );
+
+    // Seat a third member through governance
+    let proposal_id = Symbol::new(&test.env, "seat_third");
+    assert_eq!(
+        client.try_propose_council_change(&test.user, &proposal_id, &third, &true),
+        Err(Ok(Error::InvalidState))
+    );
+    test.env.as_contract(&test.contract_id, || {
+        GovernanceContract::initialize(test.env.clone(), test.admin.clone(), 3_600, 2);
+    });
+    assert_eq!(
+        client.try_propose_council_change(&test.user, &proposal_id, &first, &true),
+        Err(Ok(Error::InvalidInput))
+    );
+    client.propose_council_change(&test.user, &proposal_id, &third, &true);
+    test.env.as_contract(&test.contract_id, || {
+        for _ in 0..2 {
+            let voter = Address::generate(&test.env);
+            GovernanceContract::vote(test.env.clone(), voter, proposal_id.clone(), true).unwrap();
+        }
+    });
+    assert_eq!(
+        client.try_apply_council_change(&proposal_id),
+        Err(Ok(Error::MarketNotReady))
+    );
+    test.env.ledger().set(LedgerInfo {
+        timestamp: test.env.ledger().timestamp() + 3_601,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+    let council = vec![&test.env, first.clone(), second.clone(), third.clone()];
+    assert_eq!(client.apply_council_change(&proposal_id), council);
+    assert_eq!(client.get_arbitration_council(), council);
+    assert!(client.try_apply_council_change(&proposal_id).is_err());
+
+    // An escalated dispute is the council's, not the admin's
+    test.env.as_contract(&test.contract_id, || {
+        let mut market: Market = test.env.storage().persistent().get(&market_id).unwrap();
+        market.oracle_result = Some(yes.clone());
+        market.state = MarketState::Disputed;
+        market
+            .dispute_stakes
+            .set(Address::generate(&test.env), 10_000_000);
+        test.env.storage().persistent().set(&market_id, &market);
+        DisputeUtils::store_dispute_escalation(
+            &test.env,
+            &market_id,
+            &DisputeEscalation {
+                dispute_id: market_id.clone(),
+                escalated_by: test.user.clone(),
+                escalation_reason: String::from_str(&test.env, "Contested source"),
+                escalation_timestamp: test.env.ledger().timestamp(),
+                escalation_level: 1,
+                requires_admin_review: true,
+            },
+        )
+        .unwrap();
+    });
+    assert!(matches!(
+        client.try_resolve_dispute(&test.admin, &market_id),
+        Err(Ok(Error::DisputeCondNotMet))
+    ));
+
+    // One ruling is short of quorum and a 1-1 split has no majority
+    assert!(client.council_vote(&first, &market_id, &no).is_none());
+    assert!(client.council_vote(&third, &market_id, &yes).is_none());
+    let resolution = client.council_vote(&second, &market_id, &no).unwrap();
+    assert_eq!(resolution.final_outcome, no);
+    assert_eq!(
+        client.get_market(&market_id).unwrap().winning_outcomes,
+        Some(vec![&test.env, no])
+    );
+}
+
+#[test]
+fn test_dispute_evidence_is_attached_by_participants() {
+    use crate::disputes::{DisputeUtils, DisputeVoting, DisputeVotingStatus};
+
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let dispute_id = market_id.clone();
+    test.env.mock_all_auths();
+
+    let disputer = Address::generate(&test.env);
+    test.env.as_contract(&test.contract_id, || {
+        let mut market: Market = test.env.storage().persistent().get(&market_id).unwrap();
+        market.state = MarketState::Disputed;
+        market.dispute_stakes.set(disputer.clone(), 10_000_000);
+        test.env.storage().persistent().set(&market_id, &market);
+        DisputeUtils::store_dispute_voting(
+            &test.env,
+            &dispute_id,
+            &DisputeVoting {
+                dispute_id: dispute_id.clone(),
+                voting_start: 0,
+                voting_end: test.env.ledger().timestamp() + 86_400,
+                total_votes: 0,
+                support_votes: 0,
+                against_votes: 0,
+                total_support_stake: 0,
+                total_against_stake: 0,
+                status: DisputeVotingStatus::Active,
+            },
+        )
+        .unwrap();
+    });
+    let voter = test.create_funded_user();
+    client.vote_on_dispute(&voter, &market_id, &dispute_id, &true, &10_000_000, &None);
+
+    let report = BytesN::from_array(&test.env, &[7u8; 32]);
+    let uri = String::from_str(&test.env, "ipfs://bafy-report");
+    assert_eq!(
+        client.try_submit_dispute_evidence(&test.user, &market_id, &dispute_id, &report, &uri),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_submit_dispute_evidence(
+            &disputer,
+            &market_id,
+            &dispute_id,
+            &report,
+            &String::from_str(&test.env, "")
+        ),
+        Err(Ok(Error::InvalidInput))
+    );
+    assert_eq!(
+        client.submit_dispute_evidence(&disputer, &market_id, &dispute_id, &report, &uri),
+        0
+    );
+    let photo = BytesN::from_array(&test.env, &[9u8; 32]);
+    let photo_uri = String::from_str(&test.env, "https://example.org/photo.jpg");
+    assert_eq!(
+        client.submit_dispute_evidence(&voter, &market_id, &dispute_id, &photo, &photo_uri),
+        1
+    );
+
+    let evidence = client.get_dispute_evidence(&market_id, &dispute_id);
+    assert_eq!(evidence.len(), 2);
+    assert_eq!(evidence.get(0).unwrap().submitter, disputer);
+    assert_eq!(evidence.get(0).unwrap().content_hash, report);
+    assert_eq!(evidence.get(1).unwrap().uri, photo_uri);
+    assert!(client
+        .get_dispute_evidence(&market_id, &Symbol::new(&test.env, "other"))
+        .is_empty());
+}
+
+#[test]
+fn test_rate_limits_cap_votes_and_public_market_creation() {
+    use crate::rate_limiter::ActionRateLimits;
+
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let yes = String::from_str(&test.env, "yes");
+    let outcomes = vec![
+        &test.env,
+        String::from_str(&test.env, "yes"),
+        String::from_str(&test.env, "no"),
+    ];
+    let oracle = |feed: &str| OracleConfig {
+        provider: OracleProvider::Reflector,
+        oracle_address: Address::generate(&test.env),
+        feed_id: String::from_str(&test.env, feed),
+        threshold: 2500000,
+        comparison: String::from_str(&test.env, "gt"),
+    };
+    test.env.mock_all_auths();
+
+    let limits = ActionRateLimits {
+        votes_per_window: 1,
+        vote_window_ledgers: 10,
+        disputes_per_day: 2,
+        markets_per_day: 1,
+    };
+    assert_eq!(
+        client.try_set_rate_limits(&test.user, &limits),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_rate_limits(
+            &test.admin,
+            &ActionRateLimits {
+                vote_window_ledgers: 0,
+                ..limits.clone()
+            }
+        ),
+        Err(Ok(Error::InvalidInput))
+    );
+    client.set_rate_limits(&test.admin, &limits);
+    assert_eq!(client.get_rate_limits(), limits);
+
+    // One vote per ten ledgers
+    let first = test.create_test_market();
+    let second = test.create_test_market();
+    let rate_limited = soroban_sdk::Error::from_contract_error(
+        crate::errors::ExtendedError::RateLimitExceeded as u32,
+    );
+    client.vote(&test.user, &first, &yes, &10_000_000);
+    assert_eq!(
+        client
+            .try_vote(&test.user, &second, &yes, &10_000_000)
+            .unwrap_err(),
+        Ok(rate_limited)
+    );
+    // Every batch entry counts as a vote
+    let rate_limited_code = Err(soroban_sdk::InvokeError::Contract(
+        crate::errors::ExtendedError::RateLimitExceeded as u32,
+    ));
+    assert_eq!(
+        client
+            .try_batch_vote(
+                &test.user,
+                &vec![&test.env, (second.clone(), yes.clone(), 10_000_000)],
+            )
+            .unwrap_err(),
+        rate_limited_code
+    );
+    test.env.ledger().set(LedgerInfo {
+        timestamp: test.env.ledger().timestamp() + 60,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence() + 10,
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+    let third = test.create_test_market();
+    assert_eq!(
+        client
+            .try_batch_vote(
+                &test.user,
+                &vec![
+                    &test.env,
+                    (second.clone(), yes.clone(), 10_000_000),
+                    (third.clone(), yes.clone(), 10_000_000),
+                ],
+            )
+            .unwrap_err(),
+        rate_limited_code
+    );
+    client.batch_vote(
+        &test.user,
+        &vec![&test.env, (second.clone(), yes.clone(), 10_000_000)],
+    );
+
+    // One public market per day, unless exempt
+    let creator = test.create_funded_user();
+    client.create_market_public(
+        &creator,
+        &String::from_str(&test.env, "Will BTC close above $25,000 this month?"),
+        &outcomes,
+        &30,
+        &oracle("BTC"),
+    );
+    let create_again = || {
+        client.try_create_market_public(
+            &creator,
+            &String::from_str(&test.env, "Will ETH close above $2,000 this month?"),
+            &outcomes,
+            &30,
+            &oracle("ETH"),
+        )
+    };
+    assert_eq!(create_again().unwrap_err(), rate_limited_code);
+    client.set_rate_limit_exemption(&test.admin, &creator, &true);
+    assert_eq!(
+        client.get_rate_limit_exemptions(),
+        vec![&test.env, creator.clone()]
+    );
+    assert!(create_again().is_ok());
+}
+
+#[test]
+fn test_market_metadata_is_set_before_staking() {
+    use crate::markets::MarketMetadata;
+
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    test.env.mock_all_auths();
+    let market_id = test.create_test_market();
+
+    let metadata = MarketMetadata {
+        description_hash: BytesN::from_array(&test.env, &[1; 32]),
+        image_hash: BytesN::from_array(&test.env, &[2; 32]),
+        resolution_source_url: String::from_str(&test.env, "https://reflector.network/btc"),
+    };
+    assert_eq!(client.get_market_metadata(&market_id), None);
+    assert_eq!(
+        client.try_set_market_metadata(&test.user, &market_id, &metadata),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_market_metadata(
+            &test.admin,
+            &market_id,
+            &MarketMetadata {
+                resolution_source_url: String::from_str(&test.env, ""),
+                ..metadata.clone()
+            }
+        ),
+        Err(Ok(Error::InvalidInput))
+    );
+    client.set_market_metadata(&test.admin, &market_id, &metadata);
+    assert_eq!(
+        client.get_market_metadata(&market_id),
+        Some(metadata.clone())
+    );
+
+    // The terms are frozen once anyone has staked
+    client.vote(
+        &test.user,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &10_000_000,
+    );
+    assert_eq!(
+        client.try_set_market_metadata(
+            &test.admin,
+            &market_id,
+            &MarketMetadata {
+                image_hash: BytesN::from_array(&test.env, &[0; 32]),
+                ..metadata.clone()
+            }
+        ),
+        Err(Ok(Error::AlreadyVoted))
+    );
+    assert_eq!(client.get_market_metadata(&market_id), Some(metadata));
+}
+
+#[test]
+fn test_user_positions_list_markets_in_join_order() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let yes = String::from_str(&test.env, "yes");
+    let no = String::from_str(&test.env, "no");
+    test.env.mock_all_auths();
+
+    let first = test.create_test_market();
+    let second = test.create_test_market();
+    let rival = test.create_funded_user();
+    client.vote(&test.user, &second, &no, &50_0000000);
+    client.vote(&test.user, &first, &yes, &100_0000000);
+    client.vote(&rival, &first, &no, &100_0000000);
+
+    let positions = client.get_user_positions(&test.user, &0, &10);
+    assert_eq!(positions.len(), 2);
+    let position = positions.get(0).unwrap();
+    assert_eq!(position.market_id, second);
+    assert_eq!(position.outcome, no);
+    assert_eq!(position.stake, 50_0000000);
+    assert_eq!(position.claimable, 0);
+    assert!(!position.claimed);
+    assert_eq!(
+        client
+            .get_user_positions(&test.user, &1, &1)
+            .get(0)
+            .unwrap()
+            .market_id,
+        first
+    );
+    assert!(client.get_user_positions(&rival, &1, &10).is_empty());
+    assert!(client.try_get_user_positions(&test.user, &0, &0).is_err());
+
+    // A resolved winning position shows its post-fee share of the pool
+    let fee = test.env.as_contract(&test.contract_id, || {
+        let mut market = test
+            .env
+            .storage()
+            .persistent()
+            .get::<Symbol, Market>(&first)
+            .unwrap();
+        market.winning_outcomes = Some(vec![&test.env, yes.clone()]);
+        market.state = MarketState::Resolved;
+        test.env.storage().persistent().set(&first, &market);
+        market.platform_fee_percentage
+    });
+    let position = client
+        .get_user_positions(&test.user, &1, &1)
+        .get(0)
+        .unwrap();
+    assert_eq!(position.claimable, 200_0000000 * (100 - fee) / 100);
+    assert!(!position.claimed);
+}
+
+#[test]
+fn test_preview_claim_matches_claimed_amount() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let yes = String::from_str(&test.env, "yes");
+    let no = String::from_str(&test.env, "no");
+    test.env.mock_all_auths();
+
+    let market_id = test.create_test_market();
+    let rival = test.create_funded_user();
+    client.vote(&test.user, &market_id, &yes, &30_0000000);
+    client.vote(&rival, &market_id, &no, &70_0000000);
+    assert_eq!(
+        client.try_preview_claim(&test.user, &market_id),
+        Err(Ok(Error::MarketNotResolved))
+    );
+
+    let market = test.env.as_contract(&test.contract_id, || {
+        let mut market = test
+            .env
+            .storage()
+            .persistent()
+            .get::<Symbol, Market>(&market_id)
+            .unwrap();
+        market.winning_outcomes = Some(vec![&test.env, yes.clone()]);
+        market.state = MarketState::Resolved;
+        test.env.storage().persistent().set(&market_id, &market);
+        market
+    });
+    assert_eq!(
+        client.try_preview_claim(&test.user, &market_id),
+        Err(Ok(Error::MarketNotReady))
+    );
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + market.dispute_window_seconds + 1,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+
+    let preview = client.preview_claim(&test.user, &market_id);
+    assert_eq!(
+        preview,
+        100_0000000 * (100 - market.platform_fee_percentage) / 100
+    );
+    assert_eq!(client.preview_claim(&rival, &market_id), 0);
+    let before = client
+        .get_balance(&test.user, &ReflectorAsset::Stellar)
+        .amount;
+    client.claim_winnings(&test.user, &market_id);
+    assert_eq!(
+        client
+            .get_balance(&test.user, &ReflectorAsset::Stellar)
+            .amount
+            - before,
+        preview
+    );
+    assert_eq!(
+        client.try_preview_claim(&test.user, &market_id),
+        Err(Ok(Error::AlreadyClaimed))
+    );
+}
+
+#[test]
+fn test_market_odds_follow_outcome_stakes() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let yes = String::from_str(&test.env, "yes");
+    let no = String::from_str(&test.env, "no");
+    test.env.mock_all_auths();
+
+    let market_id = test.create_test_market();
+    let odds = client.get_market_odds(&market_id);
+    assert_eq!(odds.len(), 2);
+    assert_eq!(odds.get(0).unwrap().implied_probability_bps, 0);
+    assert_eq!(odds.get(0).unwrap().payout_multiplier_bps, 0);
+
+    let rival = test.create_funded_user();
+    client.vote(&test.user, &market_id, &yes, &30_0000000);
+    client.vote(&rival, &market_id, &no, &10_0000000);
+    let fee = client
+        .get_market(&market_id)
+        .unwrap()
+        .platform_fee_percentage;
+    let net_pool = 40_0000000 * (100 - fee) / 100;
+
+    let odds = client.get_market_odds(&market_id);
+    let (yes_odds, no_odds) = (odds.get(0).unwrap(), odds.get(1).unwrap());
+    assert_eq!(yes_odds.outcome, yes);
+    assert_eq!(yes_odds.total_staked, 30_0000000);
+    assert_eq!(yes_odds.implied_probability_bps, 7_500);
+    assert_eq!(
+        yes_odds.payout_multiplier_bps,
+        net_pool * 10_000 / 30_0000000
+    );
+    assert_eq!(no_odds.implied_probability_bps, 2_500);
+    assert_eq!(
+        no_odds.payout_multiplier_bps,
+        net_pool * 10_000 / 10_0000000
+    );
+}
+
+#[test]
+fn test_quote_vote_previews_payout_without_a_voter() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let yes = String::from_str(&test.env, "yes");
+    let no = String::from_str(&test.env, "no");
+    test.env.mock_all_auths();
+
+    let market_id = test.create_test_market();
+    let rival = test.create_funded_user();
+    client.vote(&rival, &market_id, &no, &30_0000000);
+    client.vote(&test.user, &market_id, &yes, &10_0000000);
+    let fee = client
+        .get_market(&market_id)
+        .unwrap()
+        .platform_fee_percentage;
+
+    // Quotes ignore who asks, even a user who already voted
+    let quote = client.quote_vote(&market_id, &yes, &10_0000000);
+    assert_eq!(quote.total_staked, 50_0000000);
+    assert_eq!(quote.outcome_total, 20_0000000);
+    assert_eq!(quote.implied_probability_bps, 4_000);
+    assert_eq!(
+        quote.potential_payout,
+        10_0000000 * (100 - fee) / 100 * 50_0000000 / 20_0000000
+    );
+    assert_eq!(
+        client.get_market(&market_id).unwrap().total_staked,
+        40_0000000
+    );
+
+    assert_eq!(
+        client.try_quote_vote(&market_id, &String::from_str(&test.env, "maybe"), &1),
+        Err(Ok(Error::InvalidOutcome))
+    );
+    assert_eq!(
+        client.try_quote_vote(&market_id, &yes, &0),
+        Err(Ok(Error::InvalidInput))
+    );
+}
+
+#[test]
+fn test_amm_market_prices_trades_and_settles_at_one_per_share() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token = TokenClient::new(&test.env, &test.token_test.token_id);
+    let yes = String::from_str(&test.env, "yes");
+    let no = String::from_str(&test.env, "no");
+    test.env.mock_all_auths();
+
+    let admin_start = token.balance(&test.admin);
+    let market_id = client.create_amm_market(
+        &test.admin,
+        &String::from_str(&test.env, "Will BTC go above $25,000 by December 31?"),
+        &vec![&test.env, yes.clone(), no.clone()],
+        &30,
+        &OracleConfig {
+            provider: OracleProvider::Reflector,
+            oracle_address: Address::generate(&test.env),
+            feed_id: String::from_str(&test.env, "BTC"),
+            threshold: 2500000,
+            comparison: String::from_str(&test.env, "gt"),
+        },
+        &100_0000000,
+    );
+    // Subsidy is b * ln(2)
+    let pool = client.get_amm_pool(&market_id).unwrap();
+    assert_eq!(pool.subsidy, 693147181);
+    assert_eq!(admin_start - token.balance(&test.admin), pool.subsidy);
+    assert_eq!(
+        client.get_amm_prices(&market_id),
+        vec![&test.env, 5000, 5000]
+    );
+
+    // Buying 50 shares costs b * ln((e^0.5 + 1) / 2)
+    let quoted = client.quote_shares(&market_id, &yes, &50_0000000);
+    assert_eq!(quoted, 280929804);
+    assert_eq!(
+        client.try_buy_shares(&test.user, &market_id, &yes, &50_0000000, &(quoted - 1)),
+        Err(Ok(Error::InvalidInput))
+    );
+    let user_start = token.balance(&test.user);
+    assert_eq!(
+        client.buy_shares(&test.user, &market_id, &yes, &50_0000000, &quoted),
+        quoted
+    );
+    assert_eq!(user_start - token.balance(&test.user), quoted);
+    let prices = client.get_amm_prices(&market_id);
+    assert_eq!(prices.get(0).unwrap(), 6224);
+    assert_eq!(prices.get(1).unwrap(), 3775);
+
+    // Parimutuel staking is not available on AMM markets
+    assert_eq!(
+        client.try_quote_vote(&market_id, &yes, &10_0000000),
+        Err(Ok(Error::InvalidState))
+    );
+
+    // Selling back half returns less than half the cost, as the price falls
+    assert_eq!(
+        client.try_sell_shares(&test.user, &market_id, &yes, &60_0000000, &0),
+        Err(Ok(Error::InsufficientBalance))
+    );
+    let proceeds = client.sell_shares(&test.user, &market_id, &yes, &25_0000000, &0);
+    assert!(proceeds > quoted / 2 && proceeds < quoted);
+
+    test.env.as_contract(&test.contract_id, || {
+        let mut market = test
+            .env
+            .storage()
+            .persistent()
+            .get::<Symbol, Market>(&market_id)
+            .unwrap();
+        market.winning_outcomes = Some(vec![&test.env, yes.clone()]);
+        market.state = MarketState::Resolved;
+        test.env.storage().persistent().set(&market_id, &market);
+    });
+    assert_eq!(
+        client.try_buy_shares(&test.user, &market_id, &yes, &1_0000000, &i128::MAX),
+        Err(Ok(Error::MarketClosed))
+    );
+
+    // Nothing is paid out while the result can still be disputed
+    assert_eq!(
+        client.try_redeem_amm_shares(&test.user, &market_id),
+        Err(Ok(Error::MarketNotReady))
+    );
+    assert_eq!(
+        client.try_settle_amm_pool(&market_id),
+        Err(Ok(Error::MarketNotReady))
+    );
+    let market = client.get_market(&market_id).unwrap();
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + market.dispute_window_seconds,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+
+    // Each winning share settles for one token unit
+    assert_eq!(client.redeem_amm_shares(&test.user, &market_id), 25_0000000);
+    assert_eq!(
+        client.try_redeem_amm_shares(&test.user, &market_id),
+        Err(Ok(Error::AlreadyClaimed))
+    );
+    let leftover = client.settle_amm_pool(&market_id);
+    assert_eq!(leftover, pool.subsidy + quoted - proceeds - 25_0000000);
+    assert_eq!(token.balance(&test.contract_id), 0);
+}
+
+#[test]
+fn test_duplicate_market_detection_warns_or_rejects() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    assert_eq!(
+        client.get_duplicate_market_policy(),
+        DuplicateMarketPolicy::WarnOnly
+    );
+
+    let create = |question: &str, threshold: i128| {
+        client.try_create_market(
+            &test.admin,
+            &String::from_str(&test.env, question),
+            &vec![
+                &test.env,
+                String::from_str(&test.env, "yes"),
+                String::from_str(&test.env, "no"),
+            ],
+            &30,
+            &OracleConfig {
+                provider: OracleProvider::Reflector,
+                oracle_address: Address::generate(&test.env),
+                feed_id: String::from_str(&test.env, "BTC"),
+                threshold,
+                comparison: String::from_str(&test.env, "gt"),
+            },
+            &None,
+            &0,
+            &None,
+            &None,
+            &None,
+        )
+    };
+
+    test.env.mock_all_auths();
+    let question = "Will BTC go above $25,000?";
+    let original = create(question, 2500000).unwrap().unwrap();
+
+    // Warn-only: the duplicate is created and flagged
+    let duplicate = create("will btc go above 25000", 2500000).unwrap().unwrap();
+    let event = test.env.as_contract(&test.contract_id, || {
+        test.env
+            .storage()
+            .persistent()
+            .get::<Symbol, crate::events::DuplicateMarketDetectedEvent>(&symbol_short!("mkt_dup"))
+            .unwrap()
+    });
+    assert_eq!(event.market_id, duplicate);
+    assert_eq!(event.duplicate_of, original);
+
+    // Reject: near-duplicates are refused, distinct thresholds are not
+    client.set_duplicate_market_policy(&test.admin, &DuplicateMarketPolicy::Reject);
+    assert!(create("Will  BTC go above $25,000 ?", 2500000).is_err());
+    assert!(create(question, 3000000).is_ok());
 }
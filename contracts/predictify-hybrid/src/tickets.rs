@@ -0,0 +1,125 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol};
+
+use crate::markets::MarketStateManager;
+use crate::queries::QueryManager;
+use crate::storage::VoteLedger;
+use crate::Error;
+
+// ===== CLAIM TICKETS =====
+
+/// Claim ticket for the pending winnings of one staker in one market.
+///
+/// The ticket may be transferred once to another address before claiming,
+/// so pending winnings can be sold off-chain. Claiming consumes the ticket
+/// and pays its current holder.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimTicket {
+    pub market_id: Symbol,
+    /// Staker whose position the ticket represents
+    pub staker: Address,
+    /// Address the payout goes to
+    pub holder: Address,
+    /// Payout net of the platform fee, fixed at issuance
+    pub payout: i128,
+    /// Whether the single allowed transfer has been used
+    pub transferred: bool,
+}
+
+pub struct ClaimTicketManager;
+
+impl ClaimTicketManager {
+    fn key(market_id: &Symbol, staker: &Address) -> (Symbol, Symbol, Address) {
+        (symbol_short!("clm_tkt"), market_id.clone(), staker.clone())
+    }
+
+    /// Get the outstanding ticket of `staker` in a market, if any
+    pub fn get_ticket(env: &Env, market_id: &Symbol, staker: &Address) -> Option<ClaimTicket> {
+        env.storage()
+            .persistent()
+            .get(&Self::key(market_id, staker))
+    }
+
+    /// Issue the claim ticket of a winning staker once the market's claims
+    /// open, i.e. after it resolves and its dispute window has passed.
+    ///
+    /// The payout is the one `claim_winnings` credits. Idempotent: an
+    /// outstanding ticket is returned unchanged. Fails with `NothingToClaim`
+    /// unless the staker backed a winning outcome and has a payout, and with
+    /// `AlreadyClaimed` once paid.
+    pub fn issue(env: &Env, market_id: &Symbol, staker: &Address) -> Result<ClaimTicket, Error> {
+        if let Some(ticket) = Self::get_ticket(env, market_id, staker) {
+            return Ok(ticket);
+        }
+
+        let market = MarketStateManager::get_market(env, market_id)?;
+        let winning_outcomes = market
+            .winning_outcomes
+            .clone()
+            .ok_or(Error::MarketNotResolved)?;
+        let entry = VoteLedger::get_entry(env, market_id, staker).ok_or(Error::NothingToClaim)?;
+        if entry.claimed {
+            return Err(Error::AlreadyClaimed);
+        }
+        if !winning_outcomes.contains(&entry.outcome) {
+            return Err(Error::NothingToClaim);
+        }
+        crate::PredictifyHybrid::check_claim_lock(env, &market)?;
+
+        let payout = QueryManager::claimable_payout(&market, &entry)?;
+        if payout <= 0 {
+            return Err(Error::NothingToClaim);
+        }
+        let ticket = ClaimTicket {
+            market_id: market_id.clone(),
+            staker: staker.clone(),
+            holder: staker.clone(),
+            payout,
+            transferred: false,
+        };
+        env.storage()
+            .persistent()
+            .set(&Self::key(market_id, staker), &ticket);
+        Ok(ticket)
+    }
+
+    /// Transfer a ticket to `to`. Only the staker may transfer, and only once.
+    pub fn transfer(
+        env: &Env,
+        holder: &Address,
+        market_id: &Symbol,
+        staker: &Address,
+        to: &Address,
+    ) -> Result<ClaimTicket, Error> {
+        let mut ticket = Self::get_ticket(env, market_id, staker).ok_or(Error::NothingToClaim)?;
+        if ticket.holder != *holder {
+            return Err(Error::Unauthorized);
+        }
+        if ticket.transferred || to == holder {
+            return Err(Error::InvalidState);
+        }
+
+        ticket.holder = to.clone();
+        ticket.transferred = true;
+        env.storage()
+            .persistent()
+            .set(&Self::key(market_id, staker), &ticket);
+        crate::events::EventEmitter::emit_claim_ticket_transferred(env, &ticket, holder);
+        Ok(ticket)
+    }
+
+    /// Address that receives the staker's payout: the ticket holder if a
+    /// ticket is outstanding, the staker otherwise
+    pub fn payout_recipient(env: &Env, market_id: &Symbol, staker: &Address) -> Address {
+        Self::get_ticket(env, market_id, staker)
+            .map(|ticket| ticket.holder)
+            .unwrap_or_else(|| staker.clone())
+    }
+
+    /// Remove the staker's ticket once the claim has been settled
+    pub fn consume(env: &Env, market_id: &Symbol, staker: &Address) {
+        env.storage()
+            .persistent()
+            .remove(&Self::key(market_id, staker));
+    }
+}
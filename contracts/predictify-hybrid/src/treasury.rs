@@ -0,0 +1,236 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol, Vec};
+
+use crate::errors::Error;
+use crate::markets::MarketUtils;
+use crate::reentrancy_guard::ReentrancyGuard;
+
+// ===== TREASURY =====
+
+/// Where treasury funds came from.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TreasurySource {
+    /// Platform fees collected at resolution
+    PlatformFee,
+    /// Creation bonds of markets flagged invalid
+    CreationBond,
+    /// Slashed dispute stakes
+    DisputeStake,
+    /// Penalties on sealed votes that were never revealed
+    CommitPenalty,
+    /// Winnings left unclaimed past a market's claim deadline
+    UnclaimedWinnings,
+}
+
+/// Treasury balance and cumulative inflows per source.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TreasuryBalance {
+    /// Funds currently held by the treasury
+    pub balance: i128,
+    pub platform_fees: i128,
+    pub creation_bonds: i128,
+    pub dispute_stakes: i128,
+    pub commit_penalties: i128,
+    pub unclaimed_winnings: i128,
+    /// Total paid out through executed withdrawals
+    pub withdrawn: i128,
+}
+
+/// Admin-proposed treasury withdrawal, executable after the timelock.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TreasuryWithdrawal {
+    pub id: u32,
+    /// External address receiving the funds
+    pub to: Address,
+    pub amount: i128,
+    pub proposed_by: Address,
+    pub proposed_at: u64,
+    /// Earliest execution time
+    pub executable_at: u64,
+    pub executed: bool,
+    pub cancelled: bool,
+}
+
+/// Contract-held treasury.
+///
+/// Platform fees, slashed creation bonds, slashed dispute stakes,
+/// penalties on unrevealed sealed votes and swept unclaimed winnings are
+/// credited here instead of leaving the contract. Funds only leave through
+/// withdrawals proposed by the admin and executed after
+/// `TREASURY_WITHDRAWAL_TIMELOCK_SECONDS`; executed withdrawals are kept in
+/// an on-chain history.
+pub struct TreasuryManager;
+
+impl TreasuryManager {
+    fn withdrawal_key(id: u32) -> (Symbol, u32) {
+        (symbol_short!("trs_wd"), id)
+    }
+
+    /// Treasury balance and inflow totals.
+    pub fn get_balance(env: &Env) -> TreasuryBalance {
+        env.storage()
+            .persistent()
+            .get(&symbol_short!("trs_bal"))
+            .unwrap_or(TreasuryBalance {
+                balance: 0,
+                platform_fees: 0,
+                creation_bonds: 0,
+                dispute_stakes: 0,
+                commit_penalties: 0,
+                unclaimed_winnings: 0,
+                withdrawn: 0,
+            })
+    }
+
+    fn set_balance(env: &Env, balance: &TreasuryBalance) {
+        env.storage()
+            .persistent()
+            .set(&symbol_short!("trs_bal"), balance);
+    }
+
+    /// Credit funds already held by the contract to the treasury.
+    pub fn deposit(env: &Env, source: TreasurySource, amount: i128) -> Result<i128, Error> {
+        if amount <= 0 {
+            return Err(Error::InvalidInput);
+        }
+        let mut balance = Self::get_balance(env);
+        balance.balance = balance
+            .balance
+            .checked_add(amount)
+            .ok_or(Error::InvalidInput)?;
+        match source {
+            TreasurySource::PlatformFee => balance.platform_fees += amount,
+            TreasurySource::CreationBond => balance.creation_bonds += amount,
+            TreasurySource::DisputeStake => balance.dispute_stakes += amount,
+            TreasurySource::CommitPenalty => balance.commit_penalties += amount,
+            TreasurySource::UnclaimedWinnings => balance.unclaimed_winnings += amount,
+        }
+        Self::set_balance(env, &balance);
+        crate::events::EventEmitter::emit_treasury_deposit(env, source, amount, balance.balance);
+        Ok(balance.balance)
+    }
+
+    /// Propose a withdrawal to an external address (admin only).
+    pub fn propose_withdrawal(
+        env: &Env,
+        admin: &Address,
+        to: &Address,
+        amount: i128,
+    ) -> Result<TreasuryWithdrawal, Error> {
+        crate::recovery::RecoveryManager::assert_is_admin(env, admin)?;
+        if amount <= 0 || *to == env.current_contract_address() {
+            return Err(Error::InvalidInput);
+        }
+        if amount > Self::get_balance(env).balance {
+            return Err(Error::InsufficientBalance);
+        }
+
+        let id: u32 = env
+            .storage()
+            .persistent()
+            .get(&symbol_short!("trs_next"))
+            .unwrap_or(0);
+        let now = env.ledger().timestamp();
+        let withdrawal = TreasuryWithdrawal {
+            id,
+            to: to.clone(),
+            amount,
+            proposed_by: admin.clone(),
+            proposed_at: now,
+            executable_at: now.saturating_add(crate::config::TREASURY_WITHDRAWAL_TIMELOCK_SECONDS),
+            executed: false,
+            cancelled: false,
+        };
+        env.storage()
+            .persistent()
+            .set(&Self::withdrawal_key(id), &withdrawal);
+        env.storage()
+            .persistent()
+            .set(&symbol_short!("trs_next"), &(id + 1));
+        crate::events::EventEmitter::emit_treasury_withdrawal(
+            env,
+            &symbol_short!("trs_prop"),
+            &withdrawal,
+        );
+        Ok(withdrawal)
+    }
+
+    /// Execute a proposed withdrawal once its timelock has passed (admin only).
+    pub fn execute_withdrawal(env: &Env, admin: &Address, id: u32) -> Result<i128, Error> {
+        crate::recovery::RecoveryManager::assert_is_admin(env, admin)?;
+        let mut withdrawal = Self::get_withdrawal(env, id).ok_or(Error::InvalidInput)?;
+        if withdrawal.executed || withdrawal.cancelled {
+            return Err(Error::InvalidState);
+        }
+        if env.ledger().timestamp() < withdrawal.executable_at {
+            return Err(Error::InvalidState);
+        }
+        let mut balance = Self::get_balance(env);
+        if withdrawal.amount > balance.balance {
+            return Err(Error::InsufficientBalance);
+        }
+
+        balance.balance -= withdrawal.amount;
+        balance.withdrawn += withdrawal.amount;
+        Self::set_balance(env, &balance);
+        withdrawal.executed = true;
+        env.storage()
+            .persistent()
+            .set(&Self::withdrawal_key(id), &withdrawal);
+
+        let mut history = Self::get_history(env);
+        history.push_back(withdrawal.clone());
+        env.storage()
+            .persistent()
+            .set(&symbol_short!("trs_hist"), &history);
+
+        let token_client = MarketUtils::get_token_client(env)?;
+        ReentrancyGuard::guarded(env, || {
+            token_client.transfer(
+                &env.current_contract_address(),
+                &withdrawal.to,
+                &withdrawal.amount,
+            );
+            Ok(())
+        })?;
+        crate::events::EventEmitter::emit_treasury_withdrawal(
+            env,
+            &symbol_short!("trs_exec"),
+            &withdrawal,
+        );
+        Ok(withdrawal.amount)
+    }
+
+    /// Cancel a pending withdrawal (admin only).
+    pub fn cancel_withdrawal(env: &Env, admin: &Address, id: u32) -> Result<(), Error> {
+        crate::recovery::RecoveryManager::assert_is_admin(env, admin)?;
+        let mut withdrawal = Self::get_withdrawal(env, id).ok_or(Error::InvalidInput)?;
+        if withdrawal.executed || withdrawal.cancelled {
+            return Err(Error::InvalidState);
+        }
+        withdrawal.cancelled = true;
+        env.storage()
+            .persistent()
+            .set(&Self::withdrawal_key(id), &withdrawal);
+        crate::events::EventEmitter::emit_treasury_withdrawal(
+            env,
+            &symbol_short!("trs_cncl"),
+            &withdrawal,
+        );
+        Ok(())
+    }
+
+    pub fn get_withdrawal(env: &Env, id: u32) -> Option<TreasuryWithdrawal> {
+        env.storage().persistent().get(&Self::withdrawal_key(id))
+    }
+
+    /// Executed withdrawals, oldest first.
+    pub fn get_history(env: &Env) -> Vec<TreasuryWithdrawal> {
+        env.storage()
+            .persistent()
+            .get(&symbol_short!("trs_hist"))
+            .unwrap_or(Vec::new(env))
+    }
+}
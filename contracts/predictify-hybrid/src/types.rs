@@ -152,10 +152,10 @@ pub enum MarketState {
 ///
 /// **Production Ready (Stellar Network):**
 /// - **Reflector**: Primary oracle provider with full Stellar integration
+/// - **Band Protocol**: Decentralized oracle network via its Stellar `std_reference` contract
+/// - **Pyth**: High-frequency oracle network via its Stellar price feed contract
 ///
-/// **Future/Placeholder (Not Yet Available):**
-/// - **Pyth**: High-frequency oracle network (future Stellar support)
-/// - **Band Protocol**: Decentralized oracle network (not on Stellar)
+/// **Not Available:**
 /// - **DIA**: Multi-chain oracle platform (not on Stellar)
 ///
 /// # Provider Characteristics
@@ -168,18 +168,18 @@ pub enum MarketState {
 /// - **Use Case**: Primary oracle for all Stellar-based prediction markets
 ///
 /// **Pyth Network:**
-/// - **Status**: Placeholder for future implementation
-/// - **Network**: Not currently available on Stellar
+/// - **Status**: Supported through the Pyth price feed contract
+/// - **Network**: Cross-chain, including Stellar
 /// - **Assets**: Extensive coverage of crypto, forex, and traditional assets
-/// - **Features**: Sub-second updates, institutional-grade data
-/// - **Use Case**: Future high-frequency prediction markets
+/// - **Features**: Sub-second updates, confidence intervals, institutional-grade data
+/// - **Use Case**: High-frequency prediction markets
 ///
 /// **Band Protocol:**
-/// - **Status**: Not supported on Stellar
-/// - **Network**: Primarily Cosmos and EVM-compatible chains
+/// - **Status**: Supported through the `std_reference` contract
+/// - **Network**: Cosmos and EVM-compatible chains, relayed to Stellar
 /// - **Assets**: Wide range of crypto and traditional assets
-/// - **Features**: Decentralized data aggregation
-/// - **Use Case**: Not applicable for Stellar deployment
+/// - **Features**: Decentralized data aggregation, 18-decimal reference rates
+/// - **Use Case**: Alternative price source for Stellar-based prediction markets
 ///
 /// **DIA:**
 /// - **Status**: Not supported on Stellar
@@ -259,7 +259,7 @@ pub enum MarketState {
 /// # Network Compatibility
 ///
 /// Provider support varies by blockchain network:
-/// - **Stellar**: Only Reflector is currently supported
+/// - **Stellar**: Reflector, Pyth and Band Protocol are supported
 /// - **Ethereum**: Pyth, Band Protocol, and DIA are available
 /// - **Cosmos**: Band Protocol is native
 /// - **Multi-chain**: DIA supports multiple networks
@@ -276,9 +276,9 @@ pub enum MarketState {
 pub enum OracleProvider {
     /// Reflector oracle (primary oracle for Stellar Network)
     Reflector,
-    /// Pyth Network oracle (placeholder for Stellar)
+    /// Pyth Network oracle (price feed contract on Stellar)
     Pyth,
-    /// Band Protocol oracle (not available on Stellar)
+    /// Band Protocol oracle (std_reference contract on Stellar)
     BandProtocol,
     /// DIA oracle (not available on Stellar)
     DIA,
@@ -297,7 +297,10 @@ impl OracleProvider {
 
     /// Check if provider is supported on Stellar
     pub fn is_supported(&self) -> bool {
-        matches!(self, OracleProvider::Reflector)
+        matches!(
+            self,
+            OracleProvider::Reflector | OracleProvider::Pyth | OracleProvider::BandProtocol
+        )
     }
 }
 
@@ -531,6 +534,358 @@ impl OracleConfig {
     }
 }
 
+/// Cross-rate condition comparing the ratio of two oracle feeds against a threshold.
+///
+/// Used for markets such as "Will the ETH/BTC ratio be above 0.06?", where no
+/// direct ETH/BTC feed exists. At resolution the oracle layer fetches both feeds
+/// from the market's configured oracle, normalizes each price by its decimals and
+/// computes `base / quote` as a fixed-point value with
+/// [`crate::oracles::CROSS_RATE_DECIMALS`] decimals.
+///
+/// # Example
+///
+/// ```rust
+/// # use soroban_sdk::{Env, String};
+/// # use predictify_hybrid::types::CrossRateCondition;
+/// # let env = Env::default();
+/// // "ETH/BTC ratio above 0.06" with both feeds quoted in cents (2 decimals)
+/// let condition = CrossRateCondition {
+///     base_feed_id: String::from_str(&env, "ETH/USD"),
+///     quote_feed_id: String::from_str(&env, "BTC/USD"),
+///     base_decimals: 2,
+///     quote_decimals: 2,
+///     threshold: 6_000_000, // 0.06 with 8 decimals
+///     comparison: String::from_str(&env, "gt"),
+/// };
+/// ```
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CrossRateCondition {
+    /// Feed used as the numerator (e.g., "ETH/USD")
+    pub base_feed_id: String,
+    /// Feed used as the denominator (e.g., "BTC/USD")
+    pub quote_feed_id: String,
+    /// Number of decimals in prices returned for the base feed
+    pub base_decimals: u32,
+    /// Number of decimals in prices returned for the quote feed
+    pub quote_decimals: u32,
+    /// Ratio threshold scaled by 10^CROSS_RATE_DECIMALS (e.g., 6_000_000 = 0.06)
+    pub threshold: i128,
+    /// Comparison operator: "gt", "lt", "eq"
+    pub comparison: String,
+}
+
+impl CrossRateCondition {
+    /// Maximum decimals accepted for a single feed
+    pub const MAX_FEED_DECIMALS: u32 = 18;
+
+    /// Validate the cross-rate condition
+    pub fn validate(&self, env: &Env) -> Result<(), crate::Error> {
+        if self.base_feed_id.is_empty() || self.quote_feed_id.is_empty() {
+            return Err(crate::Error::InvalidOracleConfig);
+        }
+
+        // A ratio of a feed with itself is always 1 and cannot resolve anything
+        if self.base_feed_id == self.quote_feed_id {
+            return Err(crate::Error::InvalidOracleConfig);
+        }
+
+        if self.base_decimals > Self::MAX_FEED_DECIMALS
+            || self.quote_decimals > Self::MAX_FEED_DECIMALS
+        {
+            return Err(crate::Error::InvalidOracleConfig);
+        }
+
+        if self.threshold <= 0 {
+            return Err(crate::Error::InvalidThreshold);
+        }
+
+        if self.comparison != String::from_str(env, "gt")
+            && self.comparison != String::from_str(env, "lt")
+            && self.comparison != String::from_str(env, "eq")
+        {
+            return Err(crate::Error::InvalidComparison);
+        }
+
+        Ok(())
+    }
+}
+
+/// How prices from several oracle feeds are combined into one.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AggregationMethod {
+    /// Middle price (average of the two middle prices for an even count)
+    Median,
+    /// Arithmetic mean
+    Mean,
+}
+
+/// One oracle feed contributing to an aggregated price.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleFeedSource {
+    pub provider: OracleProvider,
+    pub oracle_address: Address,
+    pub feed_id: String,
+}
+
+/// Several oracle feeds resolved together to reduce single-oracle manipulation.
+///
+/// At resolution every source is queried; sources that fail are skipped and
+/// prices deviating from the median of all reported prices by more than
+/// `max_deviation_bps` are discarded as outliers. The remaining prices are
+/// combined with `method` and compared with the market's threshold. Resolution
+/// fails with `OracleNoConsensus` if fewer than `min_sources` prices remain.
+///
+/// # Example
+///
+/// ```rust
+/// # use soroban_sdk::{vec, Address, Env, String};
+/// # use soroban_sdk::testutils::Address as _;
+/// # use predictify_hybrid::types::{AggregationMethod, MultiOracleConfig, OracleFeedSource, OracleProvider};
+/// # let env = Env::default();
+/// let source = |feed: &str| OracleFeedSource {
+///     provider: OracleProvider::Reflector,
+///     oracle_address: Address::generate(&env),
+///     feed_id: String::from_str(&env, feed),
+/// };
+/// let config = MultiOracleConfig {
+///     sources: vec![&env, source("BTC/USD"), source("BTC"), source("BTC/USDC")],
+///     method: AggregationMethod::Median,
+///     max_deviation_bps: 200, // drop prices more than 2% from the median
+///     min_sources: 2,
+/// };
+/// assert!(config.validate().is_ok());
+/// ```
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MultiOracleConfig {
+    pub sources: Vec<OracleFeedSource>,
+    pub method: AggregationMethod,
+    /// Maximum distance from the median, in basis points, before a price is an outlier
+    pub max_deviation_bps: u32,
+    /// Prices that must remain after outlier rejection
+    pub min_sources: u32,
+}
+
+impl MultiOracleConfig {
+    /// Validate the aggregation configuration
+    pub fn validate(&self) -> Result<(), crate::Error> {
+        let count = self.sources.len();
+        if !(2..=crate::config::MAX_AGGREGATED_ORACLE_SOURCES).contains(&count) {
+            return Err(crate::Error::InvalidOracleConfig);
+        }
+        if self.min_sources == 0 || self.min_sources > count {
+            return Err(crate::Error::InvalidOracleConfig);
+        }
+        if self.max_deviation_bps == 0 || self.max_deviation_bps > 10_000 {
+            return Err(crate::Error::InvalidOracleConfig);
+        }
+        if self.sources.iter().any(|source| source.feed_id.is_empty()) {
+            return Err(crate::Error::InvalidOracleConfig);
+        }
+        Ok(())
+    }
+}
+
+/// How the terms of a [`CompositeCondition`] are combined.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ConditionOperator {
+    /// Every term must hold
+    And,
+    /// At least one term must hold
+    Or,
+}
+
+/// Several feed/threshold/comparison conditions combined with AND or OR.
+///
+/// Used for markets such as "BTC > $100k AND ETH > $5k". Each term is a full
+/// [`OracleConfig`], so terms may read different feeds from different
+/// oracles. At resolution the terms are evaluated in order and evaluation
+/// stops as soon as the result is known; the market resolves "yes" when the
+/// expression holds and "no" otherwise.
+///
+/// # Example
+///
+/// ```rust
+/// # use soroban_sdk::{vec, Address, Env, String};
+/// # use predictify_hybrid::types::{CompositeCondition, ConditionOperator, OracleConfig, OracleProvider};
+/// # let env = Env::default();
+/// let oracle = Address::generate(&env);
+/// let term = |feed: &str, threshold: i128| OracleConfig::new(
+///     OracleProvider::Reflector,
+///     oracle.clone(),
+///     String::from_str(&env, feed),
+///     threshold,
+///     String::from_str(&env, "gt"),
+/// );
+/// let condition = CompositeCondition {
+///     operator: ConditionOperator::And,
+///     terms: vec![&env, term("BTC", 100_000_00), term("ETH", 5_000_00)],
+/// };
+/// assert!(condition.validate(&env).is_ok());
+/// ```
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CompositeCondition {
+    pub operator: ConditionOperator,
+    pub terms: Vec<OracleConfig>,
+}
+
+impl CompositeCondition {
+    /// Validate the condition and each of its terms
+    pub fn validate(&self, env: &Env) -> Result<(), crate::Error> {
+        let count = self.terms.len();
+        if !(2..=crate::config::MAX_COMPOSITE_CONDITION_TERMS).contains(&count) {
+            return Err(crate::Error::InvalidOracleConfig);
+        }
+        for term in self.terms.iter() {
+            term.validate(env)?;
+        }
+        Ok(())
+    }
+}
+
+// ===== SCALAR MARKETS =====
+
+/// Numeric range of a scalar market, split into equal-width buckets.
+///
+/// Scalar markets ask for a number (e.g. the BTC price at expiry) rather than
+/// a yes/no answer. Each bucket is an outcome labelled `"<from>-<to>"`; values
+/// below `lower` fall in the first bucket and values at or above `upper` in
+/// the last. Once the value is known, every bucket within
+/// `SCALAR_PAYOUT_SPREAD_BUCKETS` of the resolved bucket shares the pool,
+/// weighted by how close it came.
+///
+/// # Example
+///
+/// ```rust
+/// # use predictify_hybrid::types::ScalarRange;
+/// // BTC between $20k and $30k in $2k buckets (prices in cents)
+/// let range = ScalarRange { lower: 20_000_00, upper: 30_000_00, bucket_count: 5 };
+/// assert!(range.validate().is_ok());
+/// assert_eq!(range.bucket_of(25_500_00), 2);
+/// ```
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScalarRange {
+    pub lower: i128,
+    pub upper: i128,
+    pub bucket_count: u32,
+}
+
+impl ScalarRange {
+    /// Range of a categorical market, which has no buckets
+    pub fn none() -> Self {
+        Self {
+            lower: 0,
+            upper: 0,
+            bucket_count: 0,
+        }
+    }
+
+    /// Validate the range and bucket count
+    pub fn validate(&self) -> Result<(), crate::Error> {
+        if self.bucket_count < 2 || self.bucket_count > crate::config::MAX_MARKET_OUTCOMES {
+            return Err(crate::Error::InvalidOutcomes);
+        }
+        // Every bucket must span at least one unit
+        if self.upper - self.lower < self.bucket_count as i128 {
+            return Err(crate::Error::InvalidInput);
+        }
+        Ok(())
+    }
+
+    /// Index of the bucket a value falls in, clamped to the range
+    pub fn bucket_of(&self, value: i128) -> u32 {
+        if value < self.lower {
+            return 0;
+        }
+        if value >= self.upper {
+            return self.bucket_count - 1;
+        }
+        ((value - self.lower) * self.bucket_count as i128 / (self.upper - self.lower)) as u32
+    }
+
+    /// Outcome labels of the buckets, in order
+    pub fn bucket_labels(&self, env: &Env) -> Vec<String> {
+        let width = self.upper - self.lower;
+        let count = self.bucket_count as i128;
+        let mut labels = Vec::new(env);
+        for i in 0..count {
+            let from = self.lower + width * i / count;
+            let to = self.lower + width * (i + 1) / count;
+            labels.push_back(String::from_str(env, &alloc::format!("{}-{}", from, to)));
+        }
+        labels
+    }
+
+    /// Payout weight of a bucket given the resolved bucket (0 when too far away)
+    pub fn bucket_weight(&self, bucket: u32, resolved_bucket: u32) -> i128 {
+        let spread = crate::config::SCALAR_PAYOUT_SPREAD_BUCKETS;
+        let distance = bucket.abs_diff(resolved_bucket);
+        if distance > spread {
+            0
+        } else {
+            (spread + 1 - distance) as i128
+        }
+    }
+}
+
+// ===== CATEGORICAL MARKETS =====
+
+/// Price boundaries mapping a single feed onto more than two outcomes.
+///
+/// Outcome `i` wins when the price is at least boundary `i - 1` and below
+/// boundary `i`, so a market with outcomes `["<50k", "50k-75k", ">=75k"]`
+/// uses the boundaries `[50k, 75k]`. Boundaries are strictly ascending and
+/// there is one fewer than there are outcomes.
+///
+/// # Example
+///
+/// ```rust
+/// # use soroban_sdk::{vec, Env};
+/// # use predictify_hybrid::PriceBuckets;
+/// # let env = Env::default();
+/// let buckets = PriceBuckets { boundaries: vec![&env, 50_000_00, 75_000_00] };
+/// assert!(buckets.validate(3).is_ok());
+/// assert_eq!(buckets.bucket_of(49_999_99), 0);
+/// assert_eq!(buckets.bucket_of(50_000_00), 1);
+/// assert_eq!(buckets.bucket_of(80_000_00), 2);
+/// ```
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PriceBuckets {
+    pub boundaries: Vec<i128>,
+}
+
+impl PriceBuckets {
+    /// Validate the boundaries against a market with `outcome_count` outcomes
+    pub fn validate(&self, outcome_count: u32) -> Result<(), crate::Error> {
+        if self.boundaries.len() + 1 != outcome_count {
+            return Err(crate::Error::InvalidOutcomes);
+        }
+        let mut previous = 0;
+        for boundary in self.boundaries.iter() {
+            if boundary <= previous {
+                return Err(crate::Error::InvalidThreshold);
+            }
+            previous = boundary;
+        }
+        Ok(())
+    }
+
+    /// Index of the outcome a price falls in
+    pub fn bucket_of(&self, price: i128) -> u32 {
+        self.boundaries
+            .iter()
+            .filter(|boundary| price >= *boundary)
+            .count() as u32
+    }
+}
+
 // ===== MARKET TYPES =====
 
 /// Comprehensive market data structure representing a complete prediction market.
@@ -623,33 +978,33 @@ impl OracleConfig {
 ///
 /// # User Participation Tracking
 ///
-/// Markets track comprehensive user participation:
+/// Markets keep participation aggregates; per-user positions live in the
+/// vote ledger under `(market_id, user)` keys:
 /// ```rust
-/// # use soroban_sdk::{Address, String};
+/// # use soroban_sdk::{Address, Env, String, Symbol};
 /// # use predictify_hybrid::types::Market;
+/// # use predictify_hybrid::storage::VoteLedger;
+/// # let env = Env::default();
+/// # let market_id = Symbol::new(&env, "market");
 /// # let mut market = Market::default(); // Placeholder
-/// # let user = Address::generate(&soroban_sdk::Env::default());
+/// # let user = Address::generate(&env);
 ///
 /// // Add user vote and stake (for testing)
 /// market.add_vote(
+///     &env,
+///     &market_id,
 ///     user.clone(),
-///     String::from_str(&soroban_sdk::Env::default(), "yes"),
+///     String::from_str(&env, "yes"),
 ///     1_000_000 // 1 XLM in stroops
 /// );
 ///
-/// // Check user's vote
-/// if let Some(user_vote) = market.votes.get(user.clone()) {
-///     println!("User voted: {}", user_vote);
-/// }
-///
-/// // Check user's stake
-/// if let Some(user_stake) = market.stakes.get(user.clone()) {
-///     println!("User staked: {} stroops", user_stake);
+/// // Check user's vote and stake
+/// if let Some(entry) = VoteLedger::get_entry(&env, &market_id, &user) {
+///     println!("User voted: {} with {} stroops", entry.outcome, entry.stake);
 /// }
 ///
-/// // Check if user has claimed payout
-/// let has_claimed = market.claimed.get(user.clone()).unwrap_or(false);
-/// println!("User claimed payout: {}", has_claimed);
+/// // Participation aggregates
+/// println!("Voters: {}, claimed: {}", market.voter_count, market.claimed_count);
 /// ```
 ///
 /// # Market Validation
@@ -738,12 +1093,12 @@ pub struct Market {
     pub resolution_timeout: u64,
     /// Oracle result (set after market ends)
     pub oracle_result: Option<String>,
-    /// User votes mapping (address -> outcome)
-    pub votes: Map<Address, String>,
-    /// User stakes mapping (address -> stake amount)
-    pub stakes: Map<Address, i128>,
-    /// Claimed status mapping (address -> claimed)
-    pub claimed: Map<Address, bool>,
+    /// Number of distinct voters (positions live in the vote ledger)
+    pub voter_count: u32,
+    /// Number of voters whose payout has been settled
+    pub claimed_count: u32,
+    /// Per-outcome voter counts, kept in step with every vote
+    pub outcome_voters: Map<String, u32>,
     /// Total amount staked in the market
     pub total_staked: i128,
     /// Dispute stakes mapping (address -> dispute stake)
@@ -777,6 +1132,344 @@ pub struct Market {
     pub bet_deadline: u64,
     /// Dispute window in seconds after end_time. Payouts allowed only after end_time + this period (or dispute resolved).
     pub dispute_window_seconds: u64,
+    /// Platform fee percentage pinned at creation; payout math never reads the live config
+    pub platform_fee_percentage: i128,
+    /// Whether an oracle snapshot has been pinned (avoids Option in contract type for SDK compatibility)
+    pub has_resolution_snapshot: bool,
+    /// Oracle payload pinned at resolution (only valid when has_resolution_snapshot is true)
+    pub resolution_snapshot: OracleSnapshot,
+    /// Per-outcome stake totals, kept in step with every vote (empty until the first vote)
+    pub outcome_totals: Map<String, i128>,
+    /// Sum of stakes on the winning outcome(s), computed once at resolution
+    pub winning_total: i128,
+    /// Maximum payout of a single claim as a multiple of the claimant's stake
+    pub max_payout_multiple: i128,
+    /// Moderation tier selecting fee, stake cap, dispute bond and oracle rules
+    pub tier: MarketTier,
+    /// Whether the close-time consensus has been snapshotted
+    pub has_consensus_snapshot: bool,
+    /// Community vote distribution at end_time (only valid when has_consensus_snapshot is true)
+    pub consensus_snapshot: ConsensusSnapshot,
+    /// Cap on the share of the pool any single outcome may attract
+    pub outcome_cap: OutcomeCap,
+    /// Whether part of the fee is rebated to losing voters (rate set by the tier)
+    pub loser_rebate: bool,
+    /// Bucketed numeric range of a scalar market (no buckets for categorical markets)
+    pub scalar_range: ScalarRange,
+    /// Resolved value of a scalar market
+    pub scalar_value: Option<i128>,
+    /// How voters are weighted in the community consensus
+    pub weighting_mode: WeightingMode,
+    /// Per-outcome sum of voter weights (only kept for quadratic weighting)
+    pub outcome_weights: Map<String, i128>,
+}
+
+/// Immutable record of the oracle payload used to resolve a market.
+///
+/// Captured the moment an oracle result is applied to a market so that
+/// disputes and audits can reference exactly what the contract saw,
+/// rather than re-querying a feed whose value has since moved.
+///
+/// The ledger sequence doubles as the round identifier, since the
+/// supported providers do not expose their own round numbers.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleSnapshot {
+    /// Price (or cross-rate ratio) returned by the oracle
+    pub price: i128,
+    /// Threshold the price was compared against
+    pub threshold: i128,
+    /// Comparison operator used ("gt", "lt", "eq")
+    pub comparison: String,
+    /// Outcome derived from the price
+    pub outcome: String,
+    /// Oracle provider that served the price
+    pub provider: OracleProvider,
+    /// Oracle contract address that served the price
+    pub oracle_address: Address,
+    /// Feed identifier queried
+    pub feed_id: String,
+    /// Ledger timestamp at which the price was read
+    pub timestamp: u64,
+    /// Ledger sequence at which the price was read
+    pub ledger_sequence: u32,
+}
+
+impl OracleSnapshot {
+    /// Sentinel value for "not pinned" (used when has_resolution_snapshot is false).
+    pub fn none_sentinel(env: &Env) -> Self {
+        Self {
+            price: 0,
+            threshold: 0,
+            comparison: String::from_str(env, ""),
+            outcome: String::from_str(env, ""),
+            provider: OracleProvider::Reflector,
+            oracle_address: Address::from_str(
+                env,
+                "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF",
+            ),
+            feed_id: String::from_str(env, ""),
+            timestamp: 0,
+            ledger_sequence: 0,
+        }
+    }
+}
+
+/// How voters are weighted in a market's community consensus.
+///
+/// Quadratic weighting counts each voter with the square root of their
+/// stake, so a whale needs a hundred times the stake of a small voter for
+/// ten times the say. It only affects consensus; payouts stay linear.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WeightingMode {
+    /// Each voter counts with their stake
+    Linear,
+    /// Each voter counts with the square root of their stake
+    Quadratic,
+}
+
+/// Stake-weighted community vote distribution captured at market close.
+///
+/// Taken once the market reaches `end_time` and before any oracle
+/// interaction, so hybrid resolution and later analysis work from the
+/// consensus as it stood at close rather than a recomputation.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConsensusSnapshot {
+    /// Total stake backing each outcome
+    pub outcome_stakes: Map<String, i128>,
+    /// Number of voters backing each outcome
+    pub outcome_votes: Map<String, u32>,
+    /// Outcome with the most stake (or quadratic weight) behind it
+    pub leading_outcome: String,
+    /// Stake behind the leading outcome
+    pub leading_stake: i128,
+    /// Total stake across all outcomes
+    pub total_stake: i128,
+    /// Total number of voters
+    pub total_votes: u32,
+    /// Leading outcome's share of total stake (or quadratic weight), in percent
+    pub stake_percentage: u32,
+    /// Ledger timestamp at which the snapshot was taken
+    pub timestamp: u64,
+    /// Ledger sequence at which the snapshot was taken
+    pub ledger_sequence: u32,
+}
+
+impl ConsensusSnapshot {
+    /// Sentinel value for "not taken" (used when has_consensus_snapshot is false).
+    pub fn none_sentinel(env: &Env) -> Self {
+        Self {
+            outcome_stakes: Map::new(env),
+            outcome_votes: Map::new(env),
+            leading_outcome: String::from_str(env, ""),
+            leading_stake: 0,
+            total_stake: 0,
+            total_votes: 0,
+            stake_percentage: 0,
+            timestamp: 0,
+            ledger_sequence: 0,
+        }
+    }
+
+    /// Capture the stake-weighted vote distribution of a market
+    pub fn capture(env: &Env, market: &Market) -> Self {
+        let mut snapshot = Self::none_sentinel(env);
+
+        for (outcome, votes) in market.outcome_voters.iter() {
+            if votes == 0 {
+                continue;
+            }
+            let stake = market.outcome_total(&outcome);
+            snapshot.outcome_stakes.set(outcome.clone(), stake);
+            snapshot.outcome_votes.set(outcome, votes);
+            snapshot.total_stake += stake;
+            snapshot.total_votes += votes;
+        }
+
+        let mut leading_weight = 0;
+        let mut total_weight = 0;
+        for (outcome, stake) in snapshot.outcome_stakes.iter() {
+            let weight = market.consensus_weight(&outcome);
+            total_weight += weight;
+            if weight > leading_weight {
+                leading_weight = weight;
+                snapshot.leading_stake = stake;
+                snapshot.leading_outcome = outcome;
+            }
+        }
+
+        if total_weight > 0 {
+            snapshot.stake_percentage = ((leading_weight * 100) / total_weight) as u32;
+        }
+        snapshot.timestamp = env.ledger().timestamp();
+        snapshot.ledger_sequence = env.ledger().sequence();
+        snapshot
+    }
+}
+
+// ===== OUTCOME CAPS =====
+
+/// How a stake that would push an outcome past its cap is handled.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OutcomeCapMode {
+    /// Reject the stake
+    Reject,
+    /// Accept only the part of the stake that keeps the outcome at the cap
+    Clamp,
+}
+
+/// Cap on the share of the pool a single outcome may attract.
+///
+/// Keeps odds meaningful on lopsided markets: once the pool holds at least
+/// `min_pool`, no stake may take an outcome above `max_share_bps` of the pool.
+/// The cap is inactive below `min_pool` so the first stakes can be placed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OutcomeCap {
+    /// Maximum share of the pool per outcome in basis points (0 = no cap)
+    pub max_share_bps: u32,
+    /// Pool size from which the cap is enforced
+    pub min_pool: i128,
+    /// Handling of stakes beyond the cap
+    pub mode: OutcomeCapMode,
+}
+
+impl OutcomeCap {
+    /// Cap used by new markets: no limit
+    pub fn disabled() -> Self {
+        Self {
+            max_share_bps: 0,
+            min_pool: 0,
+            mode: OutcomeCapMode::Reject,
+        }
+    }
+
+    /// Validate the cap for a market with `outcome_count` outcomes.
+    ///
+    /// A cap at or below an equal split could never be satisfied by every outcome.
+    pub fn validate(&self, outcome_count: u32) -> Result<(), crate::Error> {
+        if self.max_share_bps == 0 {
+            return Ok(());
+        }
+        if self.max_share_bps >= 10_000
+            || outcome_count == 0
+            || self.max_share_bps <= 10_000 / outcome_count
+            || self.min_pool < 0
+        {
+            return Err(crate::Error::InvalidInput);
+        }
+        Ok(())
+    }
+}
+
+// ===== MARKET TIERS =====
+
+/// Moderation tier of a market, selecting the rule set it is held to.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MarketTier {
+    /// Curated markets with the strictest oracle requirements and highest dispute bond
+    Featured,
+    /// Default tier for markets created on the platform
+    Community,
+    /// Unvetted markets with capped stakes and a higher fee
+    Experimental,
+}
+
+/// Rule set applied to markets of a given tier.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TierRules {
+    /// Platform fee percentage pinned on the market when the tier is assigned
+    pub platform_fee_percentage: i128,
+    /// Maximum stake per user (0 = no cap)
+    pub max_stake_per_user: i128,
+    /// Minimum stake required to open a dispute
+    pub min_dispute_stake: i128,
+    /// Whether a fallback oracle must be configured
+    pub requires_fallback_oracle: bool,
+    /// Market limits profile the market must fit within
+    pub limits_profile: crate::config::MarketLimitsProfile,
+    /// Share of the fee, in basis points, rebated to losing voters on markets
+    /// with the loser rebate enabled
+    pub loser_rebate_bps: u32,
+}
+
+impl TierRules {
+    /// Rule set for a tier
+    pub fn for_tier(tier: &MarketTier) -> Self {
+        match tier {
+            MarketTier::Featured => TierRules {
+                platform_fee_percentage: 1,
+                max_stake_per_user: 0,
+                min_dispute_stake: 10 * crate::config::MIN_DISPUTE_STAKE,
+                requires_fallback_oracle: true,
+                limits_profile: crate::config::MarketLimitsProfile::Conservative,
+                loser_rebate_bps: 1_000,
+            },
+            MarketTier::Community => TierRules {
+                platform_fee_percentage: crate::config::DEFAULT_PLATFORM_FEE_PERCENTAGE,
+                max_stake_per_user: 0,
+                min_dispute_stake: crate::config::MIN_DISPUTE_STAKE,
+                requires_fallback_oracle: false,
+                limits_profile: crate::config::MarketLimitsProfile::Standard,
+                loser_rebate_bps: 500,
+            },
+            MarketTier::Experimental => TierRules {
+                platform_fee_percentage: 5,
+                max_stake_per_user: 1_000_000_000, // 100 XLM
+                min_dispute_stake: crate::config::MIN_DISPUTE_STAKE,
+                requires_fallback_oracle: false,
+                limits_profile: crate::config::MarketLimitsProfile::Degen,
+                loser_rebate_bps: 0,
+            },
+        }
+    }
+}
+
+// ===== ZERO-WINNER SETTLEMENT =====
+
+/// Destination of the pool when a market resolves with no stake on any winning outcome.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ZeroWinnerPolicy {
+    /// Refund each participant their stake minus the market's pinned platform fee
+    RefundMinusFee,
+    /// Send the whole pool to the treasury (or admin when no treasury is set)
+    Treasury,
+}
+
+/// Accounting record for a market settled under the zero-winner policy.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ZeroWinnerSettlement {
+    /// Policy applied at settlement time
+    pub policy: ZeroWinnerPolicy,
+    /// Total credited back to participants
+    pub refunded_total: i128,
+    /// Platform fee retained from refunds
+    pub fee_total: i128,
+    /// Total credited to the treasury
+    pub treasury_total: i128,
+    /// Number of participants settled
+    pub participants: u32,
+    /// Settlement timestamp
+    pub settled_at: u64,
+}
+
+// ===== MARKET DUPLICATION =====
+
+/// How market creation treats a near-duplicate of an active market.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DuplicateMarketPolicy {
+    /// Create the market and emit a duplicate warning event
+    WarnOnly,
+    /// Refuse to create the market
+    Reject,
 }
 
 // ===== BET LIMITS =====
@@ -863,6 +1556,62 @@ pub struct UserStatistics {
     pub last_activity_ts: u64,
 }
 
+/// Incremental per-creator counters
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CreatorStatistics {
+    /// Number of markets created
+    pub markets_created: u32,
+    /// Total amount staked into the creator's markets
+    pub total_volume: i128,
+    /// Platform fees collected from the creator's markets
+    pub fees_generated: i128,
+    /// Fee share paid out to the creator
+    pub fee_share_earned: i128,
+    /// Number of the creator's markets that were disputed
+    pub markets_disputed: u32,
+    /// Number of the creator's markets that were cancelled
+    pub markets_invalidated: u32,
+}
+
+/// Creator dashboard analytics
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CreatorAnalytics {
+    /// Market creator
+    pub creator: Address,
+    /// Number of markets created
+    pub markets_created: u32,
+    /// Total amount staked into the creator's markets
+    pub total_volume: i128,
+    /// Platform fees collected from the creator's markets
+    pub fees_generated: i128,
+    /// Fee share paid out to the creator
+    pub fee_share_earned: i128,
+    /// Number of the creator's markets that were disputed
+    pub markets_disputed: u32,
+    /// Disputed markets as a share of markets created, in basis points
+    pub dispute_rate_bps: u32,
+    /// Number of the creator's markets that were cancelled
+    pub invalidation_count: u32,
+}
+
+/// Protocol settlement counters for one UTC day
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DailySettlementStats {
+    /// Day index (ledger timestamp / 86400)
+    pub day: u64,
+    /// Number of markets resolved
+    pub markets_resolved: u32,
+    /// Total stake of the markets resolved
+    pub volume_settled: i128,
+    /// Platform fees collected
+    pub fees_collected: i128,
+    /// Number of disputes opened
+    pub disputes_opened: u32,
+}
+
 impl Market {
     /// Create a new market
     pub fn new(
@@ -890,9 +1639,9 @@ impl Market {
             fallback_oracle_config: fallback_cfg,
             resolution_timeout,
             oracle_result: None,
-            votes: Map::new(env),
-            stakes: Map::new(env),
-            claimed: Map::new(env),
+            voter_count: 0,
+            claimed_count: 0,
+            outcome_voters: Map::new(env),
             total_staked: 0,
             dispute_stakes: Map::new(env),
             winning_outcomes: None,
@@ -908,6 +1657,21 @@ impl Market {
             min_pool_size: None,
             bet_deadline: 0,
             dispute_window_seconds: 86400, // 24h default
+            platform_fee_percentage: crate::config::DEFAULT_PLATFORM_FEE_PERCENTAGE,
+            has_resolution_snapshot: false,
+            resolution_snapshot: OracleSnapshot::none_sentinel(env),
+            outcome_totals: Map::new(env),
+            winning_total: 0,
+            max_payout_multiple: crate::config::DEFAULT_MAX_PAYOUT_MULTIPLE,
+            tier: MarketTier::Community,
+            has_consensus_snapshot: false,
+            consensus_snapshot: ConsensusSnapshot::none_sentinel(env),
+            outcome_cap: OutcomeCap::disabled(),
+            loser_rebate: false,
+            scalar_range: ScalarRange::none(),
+            scalar_value: None,
+            weighting_mode: WeightingMode::Linear,
+            outcome_weights: Map::new(env),
         }
     }
 
@@ -945,6 +1709,18 @@ impl Market {
             .unwrap_or(false)
     }
 
+    /// Canonical ID of an outcome label: its index in `outcomes`.
+    ///
+    /// IDs are stable because outcomes are frozen once any stake is placed.
+    pub fn outcome_id(&self, outcome: &String) -> Option<u32> {
+        self.outcomes.first_index_of(outcome)
+    }
+
+    /// Canonical label of an outcome ID
+    pub fn outcome_label(&self, outcome_id: u32) -> Option<String> {
+        self.outcomes.get(outcome_id)
+    }
+
     /// Get total dispute stakes for the market
     pub fn total_dispute_stakes(&self) -> i128 {
         let mut total = 0;
@@ -954,10 +1730,194 @@ impl Market {
         total
     }
 
-    /// Add a vote to the market (for testing)
-    pub fn add_vote(&mut self, user: Address, outcome: String, stake: i128) {
-        self.votes.set(user.clone(), outcome);
-        self.stakes.set(user, stake);
+    /// Count a position on `outcome` in the per-outcome aggregates
+    pub(crate) fn add_outcome_position(&mut self, outcome: &String, stake: i128) {
+        let total = self.outcome_total(outcome);
+        self.outcome_totals.set(outcome.clone(), total + stake);
+        let voters = self.outcome_voter_count(outcome);
+        self.outcome_voters.set(outcome.clone(), voters + 1);
+        if self.weighting_mode == WeightingMode::Quadratic {
+            let weight = self.outcome_weights.get(outcome.clone()).unwrap_or(0);
+            self.outcome_weights.set(
+                outcome.clone(),
+                weight + crate::utils::NumericUtils::sqrt(&stake),
+            );
+        }
+    }
+
+    /// Remove a position on `outcome` from the per-outcome aggregates
+    pub(crate) fn remove_outcome_position(&mut self, outcome: &String, stake: i128) {
+        let total = self.outcome_total(outcome);
+        self.outcome_totals.set(outcome.clone(), total - stake);
+        let voters = self.outcome_voter_count(outcome);
+        self.outcome_voters
+            .set(outcome.clone(), voters.saturating_sub(1));
+        if self.weighting_mode == WeightingMode::Quadratic {
+            let weight = self.outcome_weights.get(outcome.clone()).unwrap_or(0);
+            self.outcome_weights.set(
+                outcome.clone(),
+                weight - crate::utils::NumericUtils::sqrt(&stake),
+            );
+        }
+    }
+
+    /// Weight of `outcome` in the community consensus: its total stake, or
+    /// the sum of the square roots of its voters' stakes under quadratic
+    /// weighting. Payouts always use the linear stake totals.
+    pub fn consensus_weight(&self, outcome: &String) -> i128 {
+        match self.weighting_mode {
+            WeightingMode::Linear => self.outcome_total(outcome),
+            WeightingMode::Quadratic => self.outcome_weights.get(outcome.clone()).unwrap_or(0),
+        }
+    }
+
+    /// Total stake on `outcome`
+    pub fn outcome_total(&self, outcome: &String) -> i128 {
+        self.outcome_totals.get(outcome.clone()).unwrap_or(0)
+    }
+
+    /// Number of voters backing `outcome`
+    pub fn outcome_voter_count(&self, outcome: &String) -> u32 {
+        self.outcome_voters.get(outcome.clone()).unwrap_or(0)
+    }
+
+    /// Fix the winning total once winning outcomes are set.
+    ///
+    /// Reads the live per-outcome totals, so it only costs one lookup per
+    /// winning outcome.
+    pub fn precompute_outcome_totals(&mut self) {
+        self.winning_total = self.get_winning_total();
+    }
+
+    /// Get the total stake on the winning outcome(s)
+    pub fn get_winning_total(&self) -> i128 {
+        let Some(winning_outcomes) = &self.winning_outcomes else {
+            return 0;
+        };
+        winning_outcomes
+            .iter()
+            .map(|outcome| self.outcome_total(&outcome))
+            .sum()
+    }
+
+    /// Whether this is a scalar (numeric range) market
+    pub fn is_scalar(&self) -> bool {
+        self.scalar_range.bucket_count > 0
+    }
+
+    /// Payout weight of a position on `outcome` once the market is resolved.
+    ///
+    /// Winning outcomes weigh 1. On scalar markets resolved to a value, buckets
+    /// weigh more the closer they are to the value.
+    pub fn payout_weight(&self, outcome: &String) -> i128 {
+        if let (true, Some(value), Some(bucket)) = (
+            self.is_scalar(),
+            self.scalar_value,
+            self.outcome_id(outcome),
+        ) {
+            let range = &self.scalar_range;
+            return range.bucket_weight(bucket, range.bucket_of(value));
+        }
+        if self.is_winning_outcome(outcome) {
+            1
+        } else {
+            0
+        }
+    }
+
+    /// Total weighted stake the pool is shared across.
+    ///
+    /// Equals `get_winning_total` for categorical markets.
+    pub fn weighted_winning_total(&self) -> i128 {
+        let Some(winning_outcomes) = &self.winning_outcomes else {
+            return 0;
+        };
+        winning_outcomes
+            .iter()
+            .map(|outcome| self.outcome_total(&outcome) * self.payout_weight(&outcome))
+            .sum()
+    }
+
+    /// Outcomes that share the pool when the market resolves to `outcome`
+    pub fn payout_outcomes(&self, env: &Env, outcome: &String) -> Vec<String> {
+        if self.is_scalar() && self.scalar_value.is_some() {
+            let mut outcomes = Vec::new(env);
+            for candidate in self.outcomes.iter() {
+                if self.payout_weight(&candidate) > 0 {
+                    outcomes.push_back(candidate);
+                }
+            }
+            return outcomes;
+        }
+        Vec::from_array(env, [outcome.clone()])
+    }
+
+    /// Fee share set aside for losing voters once the market is resolved.
+    ///
+    /// Zero unless the loser rebate is enabled and the market has both
+    /// winners and losers.
+    pub fn loser_rebate_pool(&self) -> i128 {
+        let winning_total = self.get_winning_total();
+        if !self.loser_rebate || winning_total == 0 || winning_total >= self.total_staked {
+            return 0;
+        }
+        let rebate_bps = TierRules::for_tier(&self.tier).loser_rebate_bps as i128;
+        self.total_staked * self.platform_fee_percentage / 100 * rebate_bps / 10_000
+    }
+
+    /// Pro-rata share of the loser rebate pool for a losing `stake`
+    pub fn loser_rebate_for(&self, stake: i128) -> i128 {
+        let pool = self.loser_rebate_pool();
+        if pool == 0 {
+            return 0;
+        }
+        pool * stake / (self.total_staked - self.get_winning_total())
+    }
+
+    /// Check whether a payout breaks the market's maximum payout multiple for the given stake.
+    pub fn exceeds_max_payout(&self, stake: i128, payout: i128) -> bool {
+        match stake.checked_mul(self.max_payout_multiple) {
+            Some(cap) => payout > cap,
+            None => false,
+        }
+    }
+
+    /// Largest stake that can still be added to `outcome` under the outcome cap.
+    ///
+    /// Returns `None` when the cap is disabled or not yet enforced.
+    pub fn max_stake_for_outcome(&self, outcome: &String) -> Option<i128> {
+        let cap = self.outcome_cap.max_share_bps as i128;
+        if cap == 0 || self.total_staked < self.outcome_cap.min_pool {
+            return None;
+        }
+        // (total_on_outcome + x) * 10_000 <= cap * (total_staked + x)
+        let headroom = cap * self.total_staked - 10_000 * self.outcome_total(outcome);
+        Some((headroom / (10_000 - cap)).max(0))
+    }
+
+    /// Apply the outcome cap to a new `stake` on `outcome`, returning the stake to accept.
+    pub fn apply_outcome_cap(&self, outcome: &String, stake: i128) -> Result<i128, crate::Error> {
+        match self.max_stake_for_outcome(outcome) {
+            Some(max) if stake > max => match self.outcome_cap.mode {
+                OutcomeCapMode::Clamp if max > 0 => Ok(max),
+                _ => Err(crate::Error::InvalidInput),
+            },
+            _ => Ok(stake),
+        }
+    }
+
+    /// Add a vote to the market (for testing).
+    ///
+    /// Writes the vote ledger, so it must run inside a contract context.
+    pub fn add_vote(
+        &mut self,
+        env: &Env,
+        market_id: &Symbol,
+        user: Address,
+        outcome: String,
+        stake: i128,
+    ) {
+        crate::storage::VoteLedger::set_position(env, market_id, self, &user, &outcome, stake);
         self.total_staked += stake;
     }
 
@@ -2394,6 +3354,143 @@ impl MarketCreationParams {
     }
 }
 
+/// Parameters of `create_market_v2`.
+///
+/// Replaces the positional parameter list of `create_market` so optional
+/// settings can be added without breaking the entrypoint signature. Build
+/// it with `MarketBuilder`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CreateMarketParams {
+    pub question: String,
+    pub outcomes: Vec<String>,
+    pub duration_days: u32,
+    pub oracle_config: OracleConfig,
+    /// Whether `fallback_oracle_config` is set
+    pub has_fallback: bool,
+    /// Oracle used when the primary oracle fails (sentinel when unset)
+    pub fallback_oracle_config: OracleConfig,
+    /// Seconds after end time before the market may be resolved by timeout
+    pub resolution_timeout: u64,
+    /// Minimum total stake for the market to resolve normally
+    pub min_pool_size: Option<i128>,
+    /// Stop accepting bets this many minutes before the end time
+    pub bet_deadline_mins_before_end: Option<u32>,
+    /// Dispute window override (defaults to 24 hours)
+    pub dispute_window_seconds: Option<u64>,
+    pub category: Option<String>,
+    pub tags: Vec<String>,
+}
+
+/// Parameters of `create_scalar_market`.
+///
+/// The outcomes are the buckets of `range`, so unlike `CreateMarketParams`
+/// there is no outcome list.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CreateScalarMarketParams {
+    pub question: String,
+    /// Range split into the market's bucket outcomes
+    pub range: ScalarRange,
+    pub duration_days: u32,
+    pub oracle_config: OracleConfig,
+    /// Whether `fallback_oracle_config` is set
+    pub has_fallback: bool,
+    /// Oracle used when the primary oracle fails (sentinel when unset)
+    pub fallback_oracle_config: OracleConfig,
+    /// Seconds after end time before the market may be resolved by timeout
+    pub resolution_timeout: u64,
+}
+
+/// Builder for `CreateMarketParams`.
+///
+/// ```rust
+/// # use soroban_sdk::{vec, Env, String};
+/// # use predictify_hybrid::types::{MarketBuilder, OracleConfig, OracleProvider};
+/// # let env = Env::default();
+/// let params = MarketBuilder::new(
+///     &env,
+///     String::from_str(&env, "Will BTC close above $100k?"),
+///     vec![&env, String::from_str(&env, "yes"), String::from_str(&env, "no")],
+///     30,
+///     oracle_config,
+/// )
+/// .dispute_window_seconds(3_600)
+/// .category(String::from_str(&env, "crypto"))
+/// .build();
+/// ```
+pub struct MarketBuilder {
+    params: CreateMarketParams,
+}
+
+impl MarketBuilder {
+    /// Start from the required fields; everything else uses the defaults.
+    pub fn new(
+        env: &Env,
+        question: String,
+        outcomes: Vec<String>,
+        duration_days: u32,
+        oracle_config: OracleConfig,
+    ) -> Self {
+        Self {
+            params: CreateMarketParams {
+                question,
+                outcomes,
+                duration_days,
+                oracle_config,
+                has_fallback: false,
+                fallback_oracle_config: OracleConfig::none_sentinel(env),
+                resolution_timeout: 0,
+                min_pool_size: None,
+                bet_deadline_mins_before_end: None,
+                dispute_window_seconds: None,
+                category: None,
+                tags: Vec::new(env),
+            },
+        }
+    }
+
+    pub fn fallback_oracle(mut self, config: OracleConfig) -> Self {
+        self.params.has_fallback = true;
+        self.params.fallback_oracle_config = config;
+        self
+    }
+
+    pub fn resolution_timeout(mut self, seconds: u64) -> Self {
+        self.params.resolution_timeout = seconds;
+        self
+    }
+
+    pub fn min_pool_size(mut self, amount: i128) -> Self {
+        self.params.min_pool_size = Some(amount);
+        self
+    }
+
+    pub fn bet_deadline_mins_before_end(mut self, minutes: u32) -> Self {
+        self.params.bet_deadline_mins_before_end = Some(minutes);
+        self
+    }
+
+    pub fn dispute_window_seconds(mut self, seconds: u64) -> Self {
+        self.params.dispute_window_seconds = Some(seconds);
+        self
+    }
+
+    pub fn category(mut self, category: String) -> Self {
+        self.params.category = Some(category);
+        self
+    }
+
+    pub fn tags(mut self, tags: Vec<String>) -> Self {
+        self.params.tags = tags;
+        self
+    }
+
+    pub fn build(self) -> CreateMarketParams {
+        self.params
+    }
+}
+
 // ===== ADDITIONAL TYPES =====
 
 /// Community consensus data structure for tracking collective market resolution.
@@ -2769,6 +3866,68 @@ pub struct EventDetailsQuery {
     pub admin: Address,
 }
 
+/// Lightweight market view returned by `get_market_summary`.
+///
+/// Carries only scalar fields and aggregates so the response size stays
+/// bounded regardless of participation. Per-user data (votes, stakes, claims,
+/// dispute stakes) is available through the dedicated paginated getters.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarketSummary {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Market administrator
+    pub admin: Address,
+    /// Prediction question
+    pub question: String,
+    /// Possible outcomes
+    pub outcomes: Vec<String>,
+    /// Market end timestamp
+    pub end_time: u64,
+    /// Betting cutoff timestamp
+    pub bet_deadline: u64,
+    /// Current market state
+    pub state: MarketState,
+    /// Curation tier
+    pub tier: MarketTier,
+    /// Optional category
+    pub category: Option<String>,
+    /// Oracle provider used for resolution
+    pub oracle_provider: OracleProvider,
+    /// Price feed identifier
+    pub feed_id: String,
+    /// Whether a fallback oracle is configured
+    pub has_fallback: bool,
+    /// Total amount staked in market
+    pub total_staked: i128,
+    /// Total staked per outcome
+    pub outcome_totals: Map<String, i128>,
+    /// Number of voters
+    pub voter_count: u32,
+    /// Number of users who have claimed
+    pub claimed_count: u32,
+    /// Number of users with dispute stakes
+    pub disputer_count: u32,
+    /// Sum of all dispute stakes
+    pub total_dispute_stakes: i128,
+    /// Oracle result (if available)
+    pub oracle_result: Option<String>,
+    /// Winning outcome(s) (if resolved)
+    pub winning_outcomes: Option<Vec<String>>,
+    /// Total stake on the winning outcome(s)
+    pub winning_total: i128,
+    /// Platform fee percentage applied to this market
+    pub platform_fee_percentage: i128,
+    /// Whether fees have been collected
+    pub fee_collected: bool,
+    /// Dispute window after resolution, in seconds
+    pub dispute_window_seconds: u64,
+    /// Total days the market has been extended
+    pub total_extension_days: u32,
+    /// Whether a community consensus snapshot was taken at close
+    pub has_consensus_snapshot: bool,
+}
+
 /// User bet details query response.
 ///
 /// Contains comprehensive information about a user's participation
@@ -2796,6 +3955,37 @@ pub struct UserBetQuery {
     pub dispute_stake: i128,
 }
 
+/// A user's position in one market, as listed by `get_user_positions`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UserPosition {
+    /// Market/event ID
+    pub market_id: Symbol,
+    /// Outcome the user backs
+    pub outcome: String,
+    /// Amount staked on that outcome
+    pub stake: i128,
+    /// Amount a claim would credit now, after fees (0 once claimed)
+    pub claimable: i128,
+    /// Whether the position has been settled
+    pub claimed: bool,
+}
+
+/// Live odds of one outcome, derived from the stake on it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OutcomeOdds {
+    /// Outcome name
+    pub outcome: String,
+    /// Stake on the outcome
+    pub total_staked: i128,
+    /// Share of the pool staked on the outcome, in basis points
+    pub implied_probability_bps: i128,
+    /// Net payout per unit staked if the outcome wins, in basis points
+    /// (10000 = 1x); 0 while nobody backs the outcome
+    pub payout_multiplier_bps: i128,
+}
+
 /// User balance and account status query response.
 ///
 /// Provides comprehensive view of a user's account with current balance
@@ -2882,6 +4072,44 @@ pub struct MultipleBetsQuery {
     pub winning_bets: u32,
 }
 
+/// A user's directional exposure to one underlying oracle feed.
+///
+/// Stakes on outcomes that pay when the feed ends above the market threshold
+/// count as long, those that pay when it ends below count as short.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeedExposure {
+    /// Oracle feed identifier (e.g. "BTC/USD")
+    pub feed_id: String,
+    /// Stake that gains if the feed rises
+    pub long_stake: i128,
+    /// Stake that gains if the feed falls
+    pub short_stake: i128,
+    /// `long_stake - short_stake`
+    pub net_exposure: i128,
+    /// Number of unsettled markets contributing to this feed
+    pub market_count: u32,
+}
+
+/// Net exposure query response.
+///
+/// Aggregates a user's open positions across unsettled markets by underlying
+/// feed, so positions on both sides of correlated markets net out.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NetExposureQuery {
+    /// User address
+    pub user: Address,
+    /// Exposure per underlying feed
+    pub feeds: Vec<FeedExposure>,
+    /// Sum of long and short stakes across feeds
+    pub gross_exposure: i128,
+    /// Sum of net exposure across feeds
+    pub net_exposure: i128,
+    /// Stake on markets with no direction (equality or non-binary outcomes)
+    pub non_directional_stake: i128,
+}
+
 // ===== BET PLACEMENT TYPES =====
 
 /// Status of a bet placed on a prediction market.
@@ -1,6 +1,7 @@
 #![cfg(test)]
 
 use crate::config::ConfigManager;
+use crate::storage::VoteLedger;
 use crate::types::{Market, MarketState, OracleConfig, OracleProvider, ReflectorAsset};
 use crate::{PredictifyHybrid, PredictifyHybridClient};
 use soroban_sdk::testutils::{Address as _, Ledger, LedgerInfo};
@@ -80,20 +81,14 @@ impl TimeoutSweepSetup {
                 MarketState::Resolved,
             );
 
-            market
-                .votes
-                .set(winner_1.clone(), String::from_str(&env, "yes"));
-            market
-                .votes
-                .set(winner_2.clone(), String::from_str(&env, "yes"));
-            market
-                .votes
-                .set(loser.clone(), String::from_str(&env, "no"));
-
-            market.stakes.set(winner_1.clone(), 1_000_000);
-            market.stakes.set(winner_2.clone(), 1_000_000);
-            market.stakes.set(loser.clone(), 1_000_000);
+            let yes = String::from_str(&env, "yes");
+            let no = String::from_str(&env, "no");
+            VoteLedger::set_position(&env, &market_id, &mut market, &winner_1, &yes, 1_000_000);
+            VoteLedger::set_position(&env, &market_id, &mut market, &winner_2, &yes, 1_000_000);
+            VoteLedger::set_position(&env, &market_id, &mut market, &loser, &no, 1_000_000);
             market.total_staked = 3_000_000;
+            // No challenge window so claims open right at end_time
+            market.dispute_window_seconds = 0;
 
             let mut winning_outcomes = soroban_sdk::Vec::new(&env);
             winning_outcomes.push_back(String::from_str(&env, "yes"));
@@ -170,25 +165,27 @@ fn test_sweep_after_timeout_only_unclaimed_to_treasury() {
 
     assert!(swept > 0);
 
-    let treasury_balance = setup
-        .client()
-        .get_balance(&setup.treasury, &ReflectorAsset::Stellar)
-        .amount;
-    assert_eq!(treasury_balance, swept);
-
-    let market = setup.client().get_market(&setup.market_id).unwrap();
-    assert_eq!(
-        market.claimed.get(setup.winner_1.clone()).unwrap_or(false),
-        true
-    );
-    assert_eq!(
-        market.claimed.get(setup.winner_2.clone()).unwrap_or(false),
-        true
-    );
+    // Swept winnings go to the contract treasury, not the treasury address
+    let treasury = setup.client().get_treasury_balance();
+    assert_eq!(treasury.balance, swept);
+    assert_eq!(treasury.unclaimed_winnings, swept);
     assert_eq!(
-        market.claimed.get(setup.loser.clone()).unwrap_or(false),
-        false
+        setup
+            .client()
+            .get_balance(&setup.treasury, &ReflectorAsset::Stellar)
+            .amount,
+        0
     );
+
+    let client = setup.client();
+    let claimed = |user: &Address| {
+        client
+            .get_vote_entry(&setup.market_id, user)
+            .is_some_and(|entry| entry.claimed)
+    };
+    assert_eq!(claimed(&setup.winner_1), true);
+    assert_eq!(claimed(&setup.winner_2), true);
+    assert_eq!(claimed(&setup.loser), false);
 }
 
 #[test]
@@ -221,10 +218,53 @@ fn test_market_specific_claim_period_override_used() {
         .sweep_unclaimed_winnings(&setup.admin, &setup.market_id, &true);
 
     assert!(swept > 0);
+    assert_eq!(setup.client().get_treasury_balance().balance, 0);
+}
 
-    let treasury_balance = setup
+#[test]
+fn test_claim_and_sweep_use_pinned_fee_without_config() {
+    let setup = TimeoutSweepSetup::new();
+
+    // Pin a 10% fee on the market and drop the live config entirely
+    setup.env.as_contract(&setup.contract_id, || {
+        let mut market: Market = setup
+            .env
+            .storage()
+            .persistent()
+            .get(&setup.market_id)
+            .unwrap();
+        market.platform_fee_percentage = 10;
+        setup
+            .env
+            .storage()
+            .persistent()
+            .set(&setup.market_id, &market);
+        setup
+            .env
+            .storage()
+            .persistent()
+            .remove(&Symbol::new(&setup.env, "ContractConfig"));
+    });
+
+    setup
         .client()
-        .get_balance(&setup.treasury, &ReflectorAsset::Stellar)
+        .set_global_claim_period(&setup.admin, &100u64);
+    setup.client().set_treasury(&setup.admin, &setup.treasury);
+
+    // 1_000_000 stake, 10% fee, 3_000_000 pool split over 2_000_000 winning stake
+    setup.set_time(setup.end_time + 50);
+    setup
+        .client()
+        .claim_winnings(&setup.winner_1, &setup.market_id);
+    let winner_balance = setup
+        .client()
+        .get_balance(&setup.winner_1, &ReflectorAsset::Stellar)
         .amount;
-    assert_eq!(treasury_balance, 0);
+    assert_eq!(winner_balance, 1_350_000);
+
+    setup.set_time(setup.end_time + 100);
+    let swept = setup
+        .client()
+        .sweep_unclaimed_winnings(&setup.admin, &setup.market_id, &false);
+    assert_eq!(swept, 1_350_000);
 }
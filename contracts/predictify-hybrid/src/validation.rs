@@ -5,7 +5,7 @@ extern crate alloc;
 use crate::{
     config,
     errors::Error,
-    types::{BetLimits, Market, OracleConfig, OracleProvider},
+    types::{BetLimits, Market, MarketTier, OracleConfig, OracleProvider, TierRules},
 };
 // use alloc::string::ToString; // Removed to fix Display/ToString trait errors
 use soroban_sdk::{contracttype, vec, Address, Env, Map, String, Symbol, Vec};
@@ -2377,6 +2377,60 @@ impl MarketValidator {
 
         Ok(())
     }
+
+    /// Validate that a market satisfies a tier's rules before the tier is assigned
+    pub fn validate_market_for_tier(
+        env: &Env,
+        market: &Market,
+        tier: &MarketTier,
+    ) -> Result<(), ValidationError> {
+        // Assigning a tier re-prices the market, so it must happen before participation
+        if market.total_staked > 0 || market.voter_count > 0 {
+            return Err(ValidationError::InvalidVote);
+        }
+
+        if !market.is_active(env) {
+            return Err(ValidationError::InvalidMarket);
+        }
+
+        let rules = TierRules::for_tier(tier);
+        if rules.requires_fallback_oracle && !market.has_fallback {
+            return Err(ValidationError::InvalidOracle);
+        }
+
+        let limits = config::MarketLimits::for_profile(rules.limits_profile);
+        if market.outcomes.len() > limits.max_outcomes {
+            return Err(ValidationError::ArrayTooLarge);
+        }
+
+        let seconds_per_day: u64 = 24 * 60 * 60;
+        let remaining_days = (market.end_time - env.ledger().timestamp()).div_ceil(seconds_per_day);
+        if remaining_days > limits.max_duration_days as u64 {
+            return Err(ValidationError::InvalidDuration);
+        }
+
+        Ok(())
+    }
+
+    /// Validate a participation stake against the market tier's stake cap
+    pub fn validate_stake_for_tier(market: &Market, stake: i128) -> Result<(), ValidationError> {
+        let cap = TierRules::for_tier(&market.tier).max_stake_per_user;
+        if cap > 0 && stake > cap {
+            return Err(ValidationError::InvalidInput);
+        }
+        Ok(())
+    }
+
+    /// Validate a dispute stake against the market tier's dispute bond
+    pub fn validate_dispute_stake_for_tier(
+        market: &Market,
+        stake: i128,
+    ) -> Result<(), ValidationError> {
+        if stake < TierRules::for_tier(&market.tier).min_dispute_stake {
+            return Err(ValidationError::InvalidStake);
+        }
+        Ok(())
+    }
 }
 
 // ===== ORACLE VALIDATION =====
@@ -2820,7 +2874,7 @@ impl VoteValidator {
         }
 
         // Check if user has already voted
-        if market.votes.contains_key(user.clone()) {
+        if crate::storage::VoteLedger::get_entry(env, market_id, user).is_some() {
             return Err(ValidationError::InvalidVote);
         }
 
@@ -4581,8 +4635,10 @@ impl MarketParams {
 /// - Threshold range: $0.01 to $1,000,000
 /// - Supported operators: "gt", "gte", "lt", "lte", "eq"
 ///
-/// **Band Protocol (Not Supported):**
-/// - Returns validation error for unsupported provider
+/// **Band Protocol:**
+/// - Feed ID format: "BASE/QUOTE" or "BASE"
+/// - Threshold range: $0.01 to $10,000,000
+/// - Supported operators: "gt", "lt", "eq"
 ///
 /// **DIA (Not Supported):**
 /// - Returns validation error for unsupported provider
@@ -4623,7 +4679,12 @@ impl OracleConfigValidator {
     /// - Characters: 0-9, a-f, A-F
     /// - Examples: "0xe62df6c8b4a85fe1a67db44dc12de5db330f7ac66b72dc658afedf0f4a415b43"
     ///
-    /// **Band Protocol & DIA:**
+    /// **Band Protocol:**
+    /// - Format: "BASE/QUOTE" or "BASE" (assumes USD)
+    /// - Length: 3-20 characters
+    /// - Examples: "BTC/USD", "ETH", "XLM/EUR"
+    ///
+    /// **DIA:**
     /// - Not supported on Stellar network
     /// - Returns validation error
     pub fn validate_resolution_timeout(timeout: &u64) -> Result<(), ValidationError> {
@@ -4675,7 +4736,15 @@ impl OracleConfigValidator {
 
                 Ok(())
             }
-            OracleProvider::BandProtocol | OracleProvider::DIA => {
+            OracleProvider::BandProtocol => {
+                // Band feed ID validation ("BASE/QUOTE" symbol pair)
+                if feed_id.len() < 3 || feed_id.len() > 20 {
+                    return Err(ValidationError::InvalidOracle);
+                }
+
+                Ok(())
+            }
+            OracleProvider::DIA => {
                 // Not supported on Stellar
                 Err(ValidationError::InvalidOracle)
             }
@@ -4704,7 +4773,10 @@ impl OracleConfigValidator {
     /// - Maximum: $1,000,000 (1 million dollars)
     /// - Precision: 8 decimal places (crypto precision)
     ///
-    /// **Band Protocol & DIA:**
+    /// **Band Protocol:**
+    /// - Same range as Reflector; rates are normalized to cents
+    ///
+    /// **DIA:**
     /// - Not supported on Stellar
     /// - Returns validation error
     pub fn validate_threshold_range(
@@ -4717,8 +4789,8 @@ impl OracleConfigValidator {
         }
 
         match provider {
-            OracleProvider::Reflector => {
-                // Reflector threshold validation (cents precision)
+            OracleProvider::Reflector | OracleProvider::BandProtocol => {
+                // Reflector and Band threshold validation (cents precision)
                 let min_threshold = 1; // $0.01 in cents
                 let max_threshold = 1_000_000_00; // $10,000,000 in cents
 
@@ -4739,7 +4811,7 @@ impl OracleConfigValidator {
 
                 Ok(())
             }
-            OracleProvider::BandProtocol | OracleProvider::DIA => {
+            OracleProvider::DIA => {
                 // Not supported on Stellar
                 Err(ValidationError::InvalidOracle)
             }
@@ -4776,7 +4848,10 @@ impl OracleConfigValidator {
     /// - Supported: "gt", "gte", "lt", "lte", "eq"
     /// - Not supported: "ne"
     ///
-    /// **Band Protocol & DIA:**
+    /// **Band Protocol:**
+    /// - Supported: "gt", "lt", "eq"
+    ///
+    /// **DIA:**
     /// - Not supported on Stellar
     pub fn validate_comparison_operator(
         comparison: &String,
@@ -4812,14 +4887,12 @@ impl OracleConfigValidator {
     /// - ✅ Full integration available
     ///
     /// **Pyth Network:**
-    /// - ⚠️ Placeholder for future Stellar support
-    /// - ❌ Not currently available on Stellar
-    /// - 🔮 Future implementation planned
+    /// - ✅ Supported on Stellar
+    /// - ✅ Price feed contract integration with confidence checks
     ///
     /// **Band Protocol:**
-    /// - ❌ Not supported on Stellar
-    /// - ❌ No Stellar integration
-    /// - ❌ Cosmos/EVM focused
+    /// - ✅ Supported on Stellar
+    /// - ✅ std_reference contract integration
     ///
     /// **DIA:**
     /// - ❌ Not supported on Stellar
@@ -4827,16 +4900,15 @@ impl OracleConfigValidator {
     /// - ❌ Multi-chain but no Stellar
     pub fn validate_oracle_provider(provider: &OracleProvider) -> Result<(), ValidationError> {
         match provider {
-            OracleProvider::Reflector => {
-                // Reflector is fully supported on Stellar
+            OracleProvider::Reflector | OracleProvider::BandProtocol => {
+                // Reflector and Band are supported on Stellar
                 Ok(())
             }
             OracleProvider::Pyth => {
-                // Pyth is placeholder for future Stellar support
-                // Currently returns error but could be changed when Pyth supports Stellar
-                Err(ValidationError::InvalidOracle)
+                // Pyth serves its price feeds through a Stellar contract
+                Ok(())
             }
-            OracleProvider::BandProtocol | OracleProvider::DIA => {
+            OracleProvider::DIA => {
                 // Not supported on Stellar network
                 Err(ValidationError::InvalidOracle)
             }
@@ -4877,15 +4949,16 @@ impl OracleConfigValidator {
         Self::validate_threshold_range(&config.threshold, &config.provider)?;
 
         // Get supported operators for the provider
-        let supported_operators = Self::get_supported_operators_for_provider(&config.provider);
+        let supported_operators =
+            Self::get_supported_operators_for_provider(config.comparison.env(), &config.provider);
 
         // Validate comparison operator
         Self::validate_comparison_operator(&config.comparison, &supported_operators)?;
 
         // Additional consistency checks
         match config.provider {
-            OracleProvider::Reflector => {
-                // Reflector-specific consistency checks
+            OracleProvider::Reflector | OracleProvider::BandProtocol => {
+                // Reflector and Band consistency checks
                 // Basic validation - check length and format
                 if config.feed_id.len() < 2 || config.feed_id.len() > 20 {
                     return Err(ValidationError::InvalidOracle);
@@ -4898,7 +4971,7 @@ impl OracleConfigValidator {
                     return Err(ValidationError::InvalidOracle);
                 }
             }
-            OracleProvider::BandProtocol | OracleProvider::DIA => {
+            OracleProvider::DIA => {
                 // Not supported providers
                 return Err(ValidationError::InvalidOracle);
             }
@@ -4977,37 +5050,37 @@ impl OracleConfigValidator {
                 );
                 rules.set(
                     String::from_str(env, "network_support"),
-                    String::from_str(env, "Future Stellar support"),
+                    String::from_str(env, "Stellar price feed contract"),
                 );
                 rules.set(
                     String::from_str(env, "integration_status"),
-                    String::from_str(env, "Placeholder implementation"),
+                    String::from_str(env, "Production ready"),
                 );
             }
             OracleProvider::BandProtocol => {
                 rules.set(
                     String::from_str(env, "feed_id_format"),
-                    String::from_str(env, "Not supported on Stellar"),
+                    String::from_str(env, "BASE/QUOTE or BASE (e.g., BTC/USD, ETH)"),
                 );
                 rules.set(
                     String::from_str(env, "threshold_range"),
-                    String::from_str(env, "Not supported on Stellar"),
+                    String::from_str(env, "$0.01 to $10,000,000 (in cents)"),
                 );
                 rules.set(
                     String::from_str(env, "supported_operators"),
-                    String::from_str(env, "Not supported on Stellar"),
+                    String::from_str(env, "gt, lt, eq"),
                 );
                 rules.set(
                     String::from_str(env, "precision"),
-                    String::from_str(env, "Not supported on Stellar"),
+                    String::from_str(env, "18-decimal rates normalized to cents"),
                 );
                 rules.set(
                     String::from_str(env, "network_support"),
-                    String::from_str(env, "No Stellar integration"),
+                    String::from_str(env, "Stellar std_reference contract"),
                 );
                 rules.set(
                     String::from_str(env, "integration_status"),
-                    String::from_str(env, "Not available"),
+                    String::from_str(env, "Production ready"),
                 );
             }
             OracleProvider::DIA => {
@@ -5080,7 +5153,8 @@ impl OracleConfigValidator {
         Self::validate_threshold_range(&config.threshold, &config.provider)?;
 
         // Step 4: Get supported operators and validate comparison
-        let supported_operators = Self::get_supported_operators_for_provider(&config.provider);
+        let supported_operators =
+            Self::get_supported_operators_for_provider(config.comparison.env(), &config.provider);
         Self::validate_comparison_operator(&config.comparison, &supported_operators)?;
 
         // Step 5: Validate configuration consistency
@@ -5092,6 +5166,7 @@ impl OracleConfigValidator {
     /// Get supported comparison operators for a specific provider
     ///
     /// # Arguments
+    /// * `env` - The Soroban environment the operators are compared in
     /// * `provider` - The oracle provider to get operators for
     ///
     /// # Returns
@@ -5111,30 +5186,33 @@ impl OracleConfigValidator {
     /// - "lte": Less than or equal
     /// - "eq": Equal to
     ///
-    /// **Band Protocol & DIA:**
+    /// **Band Protocol:**
+    /// - Same operators as Reflector
+    ///
+    /// **DIA:**
     /// - Empty vector (not supported)
-    fn get_supported_operators_for_provider(provider: &OracleProvider) -> Vec<String> {
+    fn get_supported_operators_for_provider(env: &Env, provider: &OracleProvider) -> Vec<String> {
         match provider {
-            OracleProvider::Reflector => {
+            OracleProvider::Reflector | OracleProvider::BandProtocol => {
                 vec![
-                    &soroban_sdk::Env::default(),
-                    String::from_str(&soroban_sdk::Env::default(), "gt"),
-                    String::from_str(&soroban_sdk::Env::default(), "lt"),
-                    String::from_str(&soroban_sdk::Env::default(), "eq"),
+                    env,
+                    String::from_str(env, "gt"),
+                    String::from_str(env, "lt"),
+                    String::from_str(env, "eq"),
                 ]
             }
             OracleProvider::Pyth => {
                 vec![
-                    &soroban_sdk::Env::default(),
-                    String::from_str(&soroban_sdk::Env::default(), "gt"),
-                    String::from_str(&soroban_sdk::Env::default(), "gte"),
-                    String::from_str(&soroban_sdk::Env::default(), "lt"),
-                    String::from_str(&soroban_sdk::Env::default(), "lte"),
-                    String::from_str(&soroban_sdk::Env::default(), "eq"),
+                    env,
+                    String::from_str(env, "gt"),
+                    String::from_str(env, "gte"),
+                    String::from_str(env, "lt"),
+                    String::from_str(env, "lte"),
+                    String::from_str(env, "eq"),
                 ]
             }
-            OracleProvider::BandProtocol | OracleProvider::DIA => {
-                vec![&soroban_sdk::Env::default()]
+            OracleProvider::DIA => {
+                vec![env]
             }
         }
     }
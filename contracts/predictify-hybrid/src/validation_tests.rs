@@ -989,12 +989,14 @@ mod oracle_config_validator_tests {
         )
         .is_err());
 
-        // Unsupported providers
+        // Valid Band feed ID
         assert!(OracleConfigValidator::validate_feed_id_format(
             &String::from_str(&soroban_sdk::Env::default(), "BTC/USD"),
             &OracleProvider::BandProtocol
         )
-        .is_err());
+        .is_ok());
+
+        // Unsupported providers
 
         assert!(OracleConfigValidator::validate_feed_id_format(
             &String::from_str(&soroban_sdk::Env::default(), "BTC/USD"),
@@ -1065,12 +1067,14 @@ mod oracle_config_validator_tests {
         )
         .is_err());
 
-        // Unsupported providers
+        // Band uses the same cents range as Reflector
         assert!(OracleConfigValidator::validate_threshold_range(
             &1_000_000,
             &OracleProvider::BandProtocol
         )
-        .is_err());
+        .is_ok());
+
+        // Unsupported providers
 
         assert!(
             OracleConfigValidator::validate_threshold_range(&1_000_000, &OracleProvider::DIA)
@@ -1157,11 +1161,12 @@ mod oracle_config_validator_tests {
             OracleConfigValidator::validate_oracle_provider(&OracleProvider::Reflector).is_ok()
         );
 
+        assert!(OracleConfigValidator::validate_oracle_provider(&OracleProvider::Pyth).is_ok());
+
         // Unsupported providers
-        assert!(OracleConfigValidator::validate_oracle_provider(&OracleProvider::Pyth).is_err());
 
         assert!(
-            OracleConfigValidator::validate_oracle_provider(&OracleProvider::BandProtocol).is_err()
+            OracleConfigValidator::validate_oracle_provider(&OracleProvider::BandProtocol).is_ok()
         );
 
         assert!(OracleConfigValidator::validate_oracle_provider(&OracleProvider::DIA).is_err());
@@ -1263,7 +1268,7 @@ mod oracle_config_validator_tests {
             .get(String::from_str(&env, "supported_operators"))
             .is_some());
 
-        // Test unsupported provider rules
+        // Test Band provider rules
         let band_rules = OracleConfigValidator::get_provider_specific_validation_rules(
             &env,
             &OracleProvider::BandProtocol,
@@ -1408,7 +1413,7 @@ mod oracle_config_validator_tests {
         )
         .is_ok());
 
-        // Test Pyth-specific validation (should fail for provider support but pass format validation)
+        // Test Pyth-specific validation
         let pyth_config = OracleConfig::new(
             OracleProvider::Pyth,
             Address::generate(&env),
@@ -1432,8 +1437,8 @@ mod oracle_config_validator_tests {
         )
         .is_ok());
 
-        // Overall validation should fail due to provider not being supported
-        assert!(OracleConfigValidator::validate_oracle_config_all_together(&pyth_config).is_err());
+        // Pyth is supported, so the overall validation passes
+        assert!(OracleConfigValidator::validate_oracle_config_all_together(&pyth_config).is_ok());
     }
 }
 
@@ -0,0 +1,177 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol};
+
+use crate::errors::Error;
+use crate::storage::BalanceStorage;
+use crate::types::ReflectorAsset;
+
+// ===== WINNINGS VESTING =====
+
+/// Payout size above which winnings vest, and how long they vest for.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingPolicy {
+    /// Payouts strictly above this amount vest
+    pub threshold: i128,
+    /// Length of the linear vesting period in days
+    pub duration_days: u32,
+}
+
+/// Linear vesting of one recipient's winnings from one market.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingSchedule {
+    pub market_id: Symbol,
+    pub beneficiary: Address,
+    /// Total winnings being vested
+    pub total: i128,
+    /// Amount already released through `claim_vested`
+    pub released: i128,
+    pub start: u64,
+    pub duration_seconds: u64,
+}
+
+/// Vesting of jumbo payouts.
+///
+/// Winnings above the policy threshold are not credited at claim time but
+/// released linearly over the vesting period through `claim_vested`. This
+/// limits immediate sell pressure from whale-dominated markets and leaves
+/// the unreleased part in the contract while late disputes play out.
+pub struct VestingManager;
+
+impl VestingManager {
+    fn schedule_key(market_id: &Symbol, beneficiary: &Address) -> (Symbol, Symbol, Address) {
+        (
+            symbol_short!("vest"),
+            market_id.clone(),
+            beneficiary.clone(),
+        )
+    }
+
+    /// Current vesting policy, falling back to the defaults.
+    pub fn get_policy(env: &Env) -> VestingPolicy {
+        env.storage()
+            .persistent()
+            .get(&symbol_short!("vest_pol"))
+            .unwrap_or(VestingPolicy {
+                threshold: crate::config::DEFAULT_VESTING_THRESHOLD,
+                duration_days: crate::config::DEFAULT_VESTING_DAYS,
+            })
+    }
+
+    /// Set the vesting threshold and duration (admin only).
+    pub fn set_policy(
+        env: &Env,
+        admin: &Address,
+        threshold: i128,
+        duration_days: u32,
+    ) -> Result<(), Error> {
+        crate::recovery::RecoveryManager::assert_is_admin(env, admin)?;
+        if threshold <= 0 || duration_days == 0 {
+            return Err(Error::InvalidInput);
+        }
+        env.storage().persistent().set(
+            &symbol_short!("vest_pol"),
+            &VestingPolicy {
+                threshold,
+                duration_days,
+            },
+        );
+        Ok(())
+    }
+
+    /// Vesting schedule of `beneficiary` in a market, if any.
+    pub fn get_schedule(
+        env: &Env,
+        market_id: &Symbol,
+        beneficiary: &Address,
+    ) -> Option<VestingSchedule> {
+        env.storage()
+            .persistent()
+            .get(&Self::schedule_key(market_id, beneficiary))
+    }
+
+    /// Credit a winning payout, vesting it when it exceeds the policy threshold.
+    ///
+    /// A further jumbo payout to the same recipient in the same market
+    /// releases what the existing schedule has vested so far and restarts
+    /// vesting for the unvested remainder plus the new payout.
+    pub fn credit_winnings(
+        env: &Env,
+        market_id: &Symbol,
+        recipient: &Address,
+        payout: i128,
+    ) -> Result<(), Error> {
+        let policy = Self::get_policy(env);
+        if payout <= policy.threshold {
+            BalanceStorage::add_balance(env, recipient, &ReflectorAsset::Stellar, payout)?;
+            return Ok(());
+        }
+
+        let now = env.ledger().timestamp();
+        let mut total = payout;
+        if let Some(existing) = Self::get_schedule(env, market_id, recipient) {
+            let vested = Self::vested_amount(&existing, now);
+            let releasable = vested - existing.released;
+            if releasable > 0 {
+                BalanceStorage::add_balance(env, recipient, &ReflectorAsset::Stellar, releasable)?;
+                crate::events::EventEmitter::emit_vested_winnings_claimed(
+                    env, market_id, recipient, releasable, vested,
+                );
+            }
+            total = total
+                .checked_add(existing.total - vested)
+                .ok_or(Error::InvalidInput)?;
+        }
+        let schedule = VestingSchedule {
+            market_id: market_id.clone(),
+            beneficiary: recipient.clone(),
+            total,
+            released: 0,
+            start: now,
+            duration_seconds: (policy.duration_days as u64) * 24 * 60 * 60,
+        };
+        env.storage()
+            .persistent()
+            .set(&Self::schedule_key(market_id, recipient), &schedule);
+        crate::events::EventEmitter::emit_winnings_vesting(env, &schedule);
+        Ok(())
+    }
+
+    /// Amount of a schedule vested at `now`.
+    pub fn vested_amount(schedule: &VestingSchedule, now: u64) -> i128 {
+        let elapsed = now.saturating_sub(schedule.start);
+        if elapsed >= schedule.duration_seconds {
+            return schedule.total;
+        }
+        schedule.total * elapsed as i128 / schedule.duration_seconds as i128
+    }
+
+    /// Release the vested but unreleased part of a schedule to the beneficiary's balance.
+    pub fn claim_vested(
+        env: &Env,
+        beneficiary: &Address,
+        market_id: &Symbol,
+    ) -> Result<i128, Error> {
+        let mut schedule =
+            Self::get_schedule(env, market_id, beneficiary).ok_or(Error::NothingToClaim)?;
+        let releasable =
+            Self::vested_amount(&schedule, env.ledger().timestamp()) - schedule.released;
+        if releasable <= 0 {
+            return Err(Error::NothingToClaim);
+        }
+
+        schedule.released += releasable;
+        env.storage()
+            .persistent()
+            .set(&Self::schedule_key(market_id, beneficiary), &schedule);
+        BalanceStorage::add_balance(env, beneficiary, &ReflectorAsset::Stellar, releasable)?;
+        crate::events::EventEmitter::emit_vested_winnings_claimed(
+            env,
+            market_id,
+            beneficiary,
+            releasable,
+            schedule.released,
+        );
+        Ok(releasable)
+    }
+}
@@ -0,0 +1,58 @@
+use soroban_sdk::{symbol_short, Address, Env, Symbol, Vec};
+
+use crate::markets::MarketStateManager;
+use crate::Error;
+
+// ===== USER WATCHLISTS =====
+
+/// Markets a user follows, kept on-chain so wallets can sync them across devices.
+///
+/// Each list holds at most `MAX_WATCHLIST_SIZE` markets in the order they
+/// were added. Watching a market twice or unwatching one that is not on the
+/// list is a no-op.
+pub struct WatchlistManager;
+
+impl WatchlistManager {
+    fn key(user: &Address) -> (Symbol, Address) {
+        (symbol_short!("watch"), user.clone())
+    }
+
+    /// Markets followed by `user`, oldest first
+    pub fn get_watchlist(env: &Env, user: &Address) -> Vec<Symbol> {
+        env.storage()
+            .persistent()
+            .get(&Self::key(user))
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Add an existing market to the user's watchlist.
+    ///
+    /// Returns `Error::InvalidInput` when the list is already full.
+    pub fn watch(env: &Env, user: &Address, market_id: &Symbol) -> Result<(), Error> {
+        MarketStateManager::get_market(env, market_id)?;
+        let mut watchlist = Self::get_watchlist(env, user);
+        if watchlist.contains(market_id) {
+            return Ok(());
+        }
+        if watchlist.len() >= crate::config::MAX_WATCHLIST_SIZE {
+            return Err(Error::InvalidInput);
+        }
+        watchlist.push_back(market_id.clone());
+        env.storage().persistent().set(&Self::key(user), &watchlist);
+        Ok(())
+    }
+
+    /// Remove a market from the user's watchlist
+    pub fn unwatch(env: &Env, user: &Address, market_id: &Symbol) {
+        let mut watchlist = Self::get_watchlist(env, user);
+        let Some(index) = watchlist.first_index_of(market_id) else {
+            return;
+        };
+        watchlist.remove(index);
+        if watchlist.is_empty() {
+            env.storage().persistent().remove(&Self::key(user));
+        } else {
+            env.storage().persistent().set(&Self::key(user), &watchlist);
+        }
+    }
+}